@@ -0,0 +1,201 @@
+//! Classify wasted files by what they are for, to tell crate authors why something is waste.
+use super::{AggregateFileInfo, Dict, WastedFile};
+use serde_derive::{Deserialize, Serialize};
+
+/// What a wasted file is for, as derived from its path
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Category {
+    Tests,
+    Benchmarks,
+    Examples,
+    Documentation,
+    Media,
+    Config,
+    Vcs,
+    Generated,
+    Other,
+}
+
+const VCS_DIRS: &[&str] = &[".git", ".hg", ".svn", ".bzr"];
+const VCS_FILES: &[&str] = &[
+    ".gitignore",
+    ".gitattributes",
+    ".gitmodules",
+    ".hgignore",
+    ".hgtags",
+    ".mailmap",
+    ".ds_store",
+    "thumbs.db",
+];
+const VCS_EXTENSIONS: &[&str] = &["orig", "rej", "bak", "swp", "swo"];
+
+const CONFIG_DIRS: &[&str] = &[
+    "ci",
+    ".github",
+    ".gitlab",
+    ".circleci",
+    ".buildkite",
+    ".devcontainer",
+    ".vscode",
+    ".idea",
+];
+const CONFIG_FILES: &[&str] = &[
+    ".travis.yml",
+    ".gitlab-ci.yml",
+    "appveyor.yml",
+    ".appveyor.yml",
+    "azure-pipelines.yml",
+    ".cirrus.yml",
+    "codecov.yml",
+    ".codecov.yml",
+    "bors.toml",
+    ".editorconfig",
+    "rustfmt.toml",
+    ".rustfmt.toml",
+    "clippy.toml",
+    ".clippy.toml",
+    "deny.toml",
+    "rust-toolchain",
+    "rust-toolchain.toml",
+    ".pre-commit-config.yaml",
+    "renovate.json",
+    ".dockerignore",
+    "dockerfile",
+];
+
+const GENERATED_DIRS: &[&str] = &["target", "node_modules", "__pycache__"];
+const GENERATED_EXTENSIONS: &[&str] = &[
+    "o", "a", "so", "dylib", "dll", "exe", "lib", "pdb", "rlib", "rmeta", "pyc", "class", "crate", "zip", "gz", "tgz",
+    "xz", "bz2", "7z",
+];
+
+const MEDIA_EXTENSIONS: &[&str] = &[
+    "png", "jpg", "jpeg", "gif", "svg", "ico", "bmp", "webp", "tif", "tiff", "psd", "xcf", "mp3", "wav", "ogg", "flac",
+    "mp4", "webm", "mov", "avi", "mkv", "ttf", "otf", "woff", "woff2",
+];
+const DOCUMENTATION_EXTENSIONS: &[&str] = &["md", "markdown", "rst", "adoc", "asciidoc", "txt", "pdf", "html", "htm"];
+
+/// The category of files within a directory called `name`, if it implies one
+fn category_of_directory(name: &str) -> Option<Category> {
+    use Category::*;
+    Some(match name {
+        "test" | "tests" | "testdata" | "test-data" | "test_data" | "testing" | "fixtures" | "spec" | "specs" => Tests,
+        "bench" | "benches" | "benchmark" | "benchmarks" => Benchmarks,
+        "example" | "examples" | "sample" | "samples" | "demo" | "demos" => Examples,
+        "doc" | "docs" | "documentation" | "book" | "man" => Documentation,
+        "assets" | "media" | "images" | "img" => Media,
+        name if name.ends_with("_test") || name.ends_with("_tests") || name.ends_with("_spec") => Tests,
+        _ => return None,
+    })
+}
+
+impl Category {
+    /// All categories, in the order they are presented in
+    pub const ALL: &'static [Category] = &[
+        Category::Tests,
+        Category::Benchmarks,
+        Category::Examples,
+        Category::Documentation,
+        Category::Media,
+        Category::Config,
+        Category::Vcs,
+        Category::Generated,
+        Category::Other,
+    ];
+
+    /// Classify the file at the crate relative `path`.
+    ///
+    /// Leftovers of version control, CI and editor configuration and generated files are recognized by their name
+    /// wherever they are. Otherwise the outermost directory implying a category wins, so that images used by tests
+    /// count as tests, and only then the file name and extension are considered.
+    pub fn of(path: &str) -> Category {
+        let path = path.to_lowercase();
+        let mut components: Vec<_> = path.split(['/', '\\']).filter(|c| !c.is_empty()).collect();
+        let file_name = components.pop().unwrap_or_default();
+        let directories = components;
+        let (stem, extension) = match file_name.rfind('.') {
+            Some(pos) if pos > 0 => (&file_name[..pos], &file_name[pos + 1..]),
+            _ => (file_name, ""),
+        };
+
+        if directories.iter().any(|d| VCS_DIRS.contains(d))
+            || VCS_FILES.contains(&file_name)
+            || VCS_EXTENSIONS.contains(&extension)
+            || file_name.ends_with('~')
+        {
+            return Category::Vcs;
+        }
+        if directories.iter().any(|d| CONFIG_DIRS.contains(d)) || CONFIG_FILES.contains(&file_name) {
+            return Category::Config;
+        }
+        if directories.iter().any(|d| GENERATED_DIRS.contains(d)) || GENERATED_EXTENSIONS.contains(&extension) {
+            return Category::Generated;
+        }
+        if let Some(category) = directories.iter().find_map(|d| category_of_directory(d)) {
+            return category;
+        }
+        if stem.ends_with("_test") || stem.ends_with("_tests") || stem.ends_with("_spec") {
+            Category::Tests
+        } else if stem.ends_with("_bench") {
+            Category::Benchmarks
+        } else if MEDIA_EXTENSIONS.contains(&extension) {
+            Category::Media
+        } else if DOCUMENTATION_EXTENSIONS.contains(&extension) {
+            Category::Documentation
+        } else {
+            Category::Other
+        }
+    }
+
+    /// The key of the category in aggregates and JSON documents
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Category::Tests => "tests",
+            Category::Benchmarks => "benchmarks",
+            Category::Examples => "examples",
+            Category::Documentation => "documentation",
+            Category::Media => "media",
+            Category::Config => "config",
+            Category::Vcs => "vcs",
+            Category::Generated => "generated",
+            Category::Other => "other",
+        }
+    }
+
+    /// A human readable description of the category
+    pub fn description(self) -> &'static str {
+        match self {
+            Category::Tests => "Tests and test data",
+            Category::Benchmarks => "Benchmarks",
+            Category::Examples => "Examples",
+            Category::Documentation => "Documentation",
+            Category::Media => "Images and other media",
+            Category::Config => "CI and editor configuration",
+            Category::Vcs => "Version control leftovers and backups",
+            Category::Generated => "Generated artifacts and archives",
+            Category::Other => "Other files",
+        }
+    }
+
+    /// Return the category whose key is `name`, as returned by `as_str()`.
+    pub fn from_key(name: &str) -> Option<Category> {
+        Category::ALL.iter().copied().find(|c| c.as_str() == name)
+    }
+}
+
+impl std::fmt::Display for Category {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.description())
+    }
+}
+
+/// Add the size and count of all `files` to the aggregate of their category in `initial`.
+pub fn into_map_by_category(initial: Dict<AggregateFileInfo>, files: &[WastedFile]) -> Dict<AggregateFileInfo> {
+    files.iter().fold(initial, |mut m, (path, size)| {
+        let entry = m.entry(Category::of(path).as_str().to_owned()).or_default();
+        entry.total_bytes += size;
+        entry.total_files += 1;
+        m
+    })
+}
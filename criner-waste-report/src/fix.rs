@@ -0,0 +1,92 @@
+//! Turn a `Fix` into something crate authors can apply right away: the TOML to paste into `Cargo.toml`,
+//! or a unified diff against the manifest they published.
+use super::{Fix, Patterns, TarHeader};
+use toml_edit::{Array, Document, Item, Value};
+
+/// The manifests to apply fixes to, the original one as written by the author first.
+const MANIFEST_NAMES: &[&str] = &["Cargo.toml.orig", "Cargo.toml"];
+
+fn patterns_to_array(patterns: &Patterns) -> Array {
+    let mut array: Array = patterns
+        .iter()
+        .map(|p| Value::from(p.as_str()).decorated("\n    ", ""))
+        .collect();
+    array.set_trailing_comma(true);
+    array.set_trailing("\n");
+    array
+}
+
+impl Fix {
+    /// The patterns to set as `include` or `exclude` in the `package` section, or `None` if `include` is to be kept and `exclude` is to be removed.
+    pub(crate) fn directives(&self) -> (Option<&Patterns>, Option<&Patterns>) {
+        match self {
+            Fix::ImprovedInclude { include, .. }
+            | Fix::NewInclude { include, .. }
+            | Fix::RemoveExcludeAndUseInclude { include, .. } => (Some(include), None),
+            Fix::EnrichedExclude { exclude, .. } => (None, Some(exclude)),
+            Fix::RemoveExclude => (None, None),
+        }
+    }
+
+    /// The `package` section to paste into `Cargo.toml`, replacing the existing `include` and `exclude` directives.
+    pub fn to_toml(&self) -> String {
+        let mut doc = Document::new();
+        let mut package = toml_edit::Table::new();
+        let (include, exclude) = self.directives();
+        if let Some(include) = include {
+            package.insert("include", Item::Value(patterns_to_array(include).into()));
+        }
+        if let Some(exclude) = exclude {
+            package.insert("exclude", Item::Value(patterns_to_array(exclude).into()));
+        }
+        doc.insert("package", Item::Table(package));
+        let mut toml = doc.to_string();
+        if exclude.is_none() {
+            toml.push_str("# remove the 'exclude' directive, if present\n");
+        }
+        toml
+    }
+
+    /// Apply the fix to the `manifest` text while keeping its formatting and comments,
+    /// or return `None` if it can't be parsed or has no `package` section.
+    pub fn apply(&self, manifest: &str) -> Option<String> {
+        let mut doc: Document = manifest.parse().ok()?;
+        let package = doc.get_mut("package")?.as_table_like_mut()?;
+        let (include, exclude) = self.directives();
+        if let Some(include) = include {
+            package.insert("include", Item::Value(patterns_to_array(include).into()));
+        }
+        match exclude {
+            Some(exclude) => {
+                package.insert("exclude", Item::Value(patterns_to_array(exclude).into()));
+            }
+            None => {
+                package.remove("exclude");
+            }
+        }
+        Some(doc.to_string())
+    }
+
+    /// A unified diff applying the fix to the manifest found in `entries`, preferring the original manifest as written
+    /// by the crate author over the one normalized by cargo.
+    /// Returns `None` if no manifest is present or it can't be parsed.
+    pub fn to_manifest_diff(&self, entries: &[(TarHeader, Vec<u8>)]) -> Option<String> {
+        let manifest = MANIFEST_NAMES.iter().find_map(|name| {
+            entries
+                .iter()
+                .find(|(h, _)| crate::tar_path_to_utf8_str(&h.path) == *name)
+                .and_then(|(_, content)| std::str::from_utf8(content).ok())
+        })?;
+        let fixed = self.apply(manifest)?;
+        Some(unified_diff(manifest, &fixed, "Cargo.toml"))
+    }
+}
+
+/// Produce a unified diff of the `original` file at `path` to the `modified` one, with three lines of context.
+pub fn unified_diff(original: &str, modified: &str, path: &str) -> String {
+    similar::TextDiff::from_lines(original, modified)
+        .unified_diff()
+        .context_radius(3)
+        .header(&format!("a/{}", path), &format!("b/{}", path))
+        .to_string()
+}
@@ -1,4 +1,9 @@
-use super::{AggregateFileInfo, Dict, Fix, Report, VersionInfo};
+use super::{
+    add_optional_aggregate,
+    category::{into_map_by_category, Category},
+    verify::Verification,
+    AggregateFileInfo, CompressedSize, Dict, Fix, Report, VersionInfo,
+};
 use bytesize::ByteSize;
 use dia_semver::Semver;
 use horrorshow::{box_html, helper::doctype, html, Render, RenderBox, RenderOnce, TemplateBuffer};
@@ -52,6 +57,13 @@ fn potential_savings(info_by_crate: &Dict<VersionInfo>) -> Option<AggregateFileI
     }
 }
 
+/// The compressed size of all children whose `.crate` file size is known
+fn compressed_size(info_by_child: &Dict<VersionInfo>) -> Option<CompressedSize> {
+    info_by_child
+        .values()
+        .fold(None, |s, e| add_optional_aggregate(s, e.compressed.clone()))
+}
+
 fn total_section(bytes: u64, files: u64) -> Box<dyn Render> {
     box_html! {
         section(id="total-bytes") {
@@ -65,6 +77,25 @@ fn total_section(bytes: u64, files: u64) -> Box<dyn Render> {
     }
 }
 
+fn compressed_section(compressed: Option<CompressedSize>) -> Box<dyn Render> {
+    box_html! {
+        @ if let Some(compressed) = compressed.as_ref() {
+            section(id="total-compressed-bytes") {
+                h3: "total compressed bytes";
+                p: format!("{}", ByteSize(compressed.total_bytes))
+            }
+            section(id="compressed-waste") {
+                h3: "estimated compressed waste";
+                p: format!(
+                    "{} ({:.1}%)",
+                    ByteSize(compressed.wasted_bytes),
+                    percentage(compressed.wasted_bytes, compressed.total_bytes)
+                )
+            }
+        }
+    }
+}
+
 fn savings_section(d: Option<AggregateFileInfo>) -> Box<dyn Render> {
     box_html! {
         @ if let Some(all) = d.as_ref() {
@@ -99,6 +130,8 @@ fn info_section(name: String, info: VersionInfo) -> Box<dyn RenderBox> {
         waste,
         potential_gains,
         waste_latest_version,
+        waste_by_category: _,
+        compressed,
     } = info;
     box_html! {
         section(id="child-total") {
@@ -109,6 +142,16 @@ fn info_section(name: String, info: VersionInfo) -> Box<dyn RenderBox> {
             h3: "Waste in all versions";
             p: format!("{} wasted in {} files", ByteSize(waste.total_bytes), waste.total_files);
         }
+        @ if let Some(compressed) = compressed {
+            section(id="child-compressed-waste") {
+                h3: "Compressed waste in all versions";
+                p: format!(
+                    "an estimated {} of {} downloaded",
+                    ByteSize(compressed.wasted_bytes),
+                    ByteSize(compressed.total_bytes)
+                );
+            }
+        }
         @ if let Some((child_name, info)) = waste_latest_version {
             section(id="child-waste-latest-version") {
                 h3 {
@@ -127,6 +170,33 @@ fn info_section(name: String, info: VersionInfo) -> Box<dyn RenderBox> {
     }
 }
 
+fn verification_section(verification: Verification) -> Box<dyn RenderBox> {
+    box_html! {
+        section {
+            @ if verification.is_ok() {
+                p: "Verified by applying the fix with the rules cargo uses for packaging";
+            } else {
+                h3: "Verification failed";
+                p: "Applying the fix with the rules cargo uses for packaging doesn't yield the expected result";
+                dl {
+                    @ if !verification.removed_needed_files.is_empty() {
+                        dt: "needed files which would be removed";
+                        dd: verification.removed_needed_files.join(", ");
+                    }
+                    @ if !verification.retained_waste.is_empty() {
+                        dt: "wasted files which would be kept";
+                        dd: verification.retained_waste.join(", ");
+                    }
+                    @ if !verification.invalid_patterns.is_empty() {
+                        dt: "invalid patterns";
+                        dd: verification.invalid_patterns.join(", ");
+                    }
+                }
+            }
+        }
+    }
+}
+
 fn page_footer() -> impl Render {
     html! {
         footer {
@@ -232,6 +302,40 @@ fn by_extension_section(wasted_by_extension: Dict<AggregateFileInfo>) -> Box<dyn
     }
 }
 
+fn percentage(part: u64, total: u64) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        part as f64 * 100.0 / total as f64
+    }
+}
+
+fn by_category_section(wasted_by_category: Dict<AggregateFileInfo>) -> Box<dyn RenderBox> {
+    let total_bytes: u64 = wasted_by_category.values().map(|e| e.total_bytes).sum();
+    let mut sorted: Vec<_> = wasted_by_category.into_iter().collect();
+    sorted.sort_by_key(|(_, e)| e.total_bytes);
+    box_html! {
+        @ if !sorted.is_empty() {
+            section {
+                h1: "Waste by Category";
+                ol {
+                    @ for (name, info) in sorted.into_iter().rev() {
+                        li {
+                            h3: Category::from_key(&name).map(Category::description).unwrap_or(&name);
+                            p: format!(
+                                "{} waste in {} files ({:.1}%)",
+                                ByteSize(info.total_bytes),
+                                info.total_files,
+                                percentage(info.total_bytes, total_bytes)
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
 impl RenderOnce for Report {
     fn render_once(self, tmpl: &mut TemplateBuffer<'_>)
     where
@@ -246,8 +350,19 @@ impl RenderOnce for Report {
                 total_size_in_bytes,
                 mut wasted_files,
                 suggested_fix,
+                cargo_toml_diff,
+                fix_verification,
+                compressed_size_in_bytes,
             } => {
                 wasted_files.sort_by_key(|(_, s)| *s);
+                let compressed = compressed_size_in_bytes.map(|compressed_bytes| {
+                    CompressedSize::estimate(
+                        compressed_bytes,
+                        total_size_in_bytes,
+                        wasted_files.iter().map(|(_, s)| *s).sum(),
+                    )
+                });
+                let wasted_by_category = into_map_by_category(Default::default(), &wasted_files);
                 let title = format!("{}:{}", crate_name, crate_version);
                 tmpl << html! {
                     : doctype::HTML;
@@ -257,24 +372,36 @@ impl RenderOnce for Report {
                             article {
                                 : title_section(title);
                                 : total_section(total_size_in_bytes, total_files);
+                                : compressed_section(compressed);
                                 : savings_section(fix_to_wasted_files_aggregate(suggested_fix.clone()));
                                 @ if let Some(suggested_fix) = suggested_fix {
                                     section {
                                         h3: "Fix";
                                         section {
-                                            |t| write!(t, "{:#?}", suggested_fix)
+                                            p: "Replace the include and exclude directives in Cargo.toml with";
+                                            pre: suggested_fix.to_toml();
+                                        }
+                                        @ if let Some(diff) = cargo_toml_diff {
+                                            section {
+                                                p: "or apply this patch to Cargo.toml";
+                                                pre: diff;
+                                            }
+                                        }
+                                        @ if let Some(verification) = fix_verification {
+                                            : verification_section(verification);
                                         }
                                     }
                                 } else {
                                     p: "Perfectly lean!"
                                 }
+                                : by_category_section(wasted_by_category);
                                 @ if !wasted_files.is_empty() {
                                     section {
                                         h3: format!("{} wasted files", wasted_files.len());
                                         p: format!("total waste: {}", ByteSize(wasted_files.iter().map(|(_, s)| *s).sum::<u64>()));
                                         ol {
                                             @ for (path, size) in wasted_files.into_iter().rev() {
-                                                li : format_args!("{} : {}", path, ByteSize(size))
+                                                li : format_args!("{} : {} ({})", path, ByteSize(size), Category::of(&path))
                                             }
                                         }
                                     }
@@ -291,8 +418,10 @@ impl RenderOnce for Report {
                 total_files,
                 info_by_version,
                 wasted_by_extension,
+                wasted_by_category,
             } => {
                 let gains = potential_savings(&info_by_version);
+                let compressed = compressed_size(&info_by_version);
                 let no_prefix = String::new();
                 tmpl << html! {
                     : doctype::HTML;
@@ -302,7 +431,9 @@ impl RenderOnce for Report {
                             article {
                                 : title_section(crate_name.clone());
                                 : total_section(total_size_in_bytes, total_files);
+                                : compressed_section(compressed);
                                 : savings_section(gains);
+                                : by_category_section(wasted_by_category);
                                 : by_extension_section(wasted_by_extension);
                                 : child_items_section("Versions", info_by_version, no_prefix, ".html", SortOrder::Semver);
                             }
@@ -316,11 +447,13 @@ impl RenderOnce for Report {
                 total_files,
                 info_by_crate,
                 wasted_by_extension,
+                wasted_by_category,
             } => {
                 let title = "Crates.io Waste Report";
                 let no_prefix = String::new();
                 let no_suffix = String::new();
                 let gains = potential_savings(&info_by_crate);
+                let compressed = compressed_size(&info_by_crate);
                 let (waste_in_bytes, wasted_files_count) = wasted_by_extension
                     .iter()
                     .fold((0, 0), |(waste_bytes, waste_files), e| {
@@ -337,7 +470,9 @@ impl RenderOnce for Report {
                                 section {
                                     h3: format!("{} wasted in {} files", ByteSize(waste_in_bytes), wasted_files_count);
                                 }
+                                : compressed_section(compressed);
                                 : savings_section(gains);
+                                : by_category_section(wasted_by_category);
                                 : by_extension_section(wasted_by_extension);
                                 : child_items_section("Crates", info_by_crate, no_prefix, no_suffix, SortOrder::Waste);
                             }
@@ -0,0 +1,358 @@
+//! A stable, machine-readable representation of waste reports, meant to be serialized as JSON.
+//!
+//! Every document is an object with the `schema_version` and a `kind`, which is one of
+//!
+//! * `version` - a single crate version, with all `wasted_files` and the `suggested_fix` to avoid them, if any
+//! * `crate` - all versions of a crate, with a summary per version in `versions`
+//! * `crate_collection` - all crates, with a summary per crate in `crates`
+//!
+//! Wasted files have a `category` telling what they are for, like `tests`, `documentation` or `media`, by which
+//! waste is also summed up in `wasted_by_category` on every page.
+//!
+//! Where the size of the gzip compressed `.crate` files is known, `compressed` has it along with an estimate of
+//! how much of it is waste, attributing the compressed size to files in proportion to their uncompressed size.
+//!
+//! All sizes are in bytes. Fields are only ever added within the same `schema_version`, which is
+//! incremented if fields are removed, renamed or change their meaning.
+use crate::{
+    add_optional_aggregate,
+    category::{into_map_by_category, Category},
+    verify::Verification,
+    AggregateFileInfo, CompressedSize, Dict, Fix, Patterns, PotentialWaste, Report, VersionInfo,
+};
+use serde_derive::{Deserialize, Serialize};
+
+/// The version of the schema of all documents, see the module documentation.
+pub const SCHEMA_VERSION: u32 = 1;
+
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+pub struct Document {
+    pub schema_version: u32,
+    #[serde(flatten)]
+    pub page: Page,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+#[allow(clippy::large_enum_variant)] // created once per page, size does not matter
+pub enum Page {
+    Version {
+        crate_name: String,
+        crate_version: String,
+        total_size_in_bytes: u64,
+        total_files: u64,
+        /// The sum of all wasted files
+        waste: FileStats,
+        /// Files which aren't needed to build the crate, the largest first
+        wasted_files: Vec<File>,
+        /// Wasted files by their category
+        wasted_by_category: Dict<FileStats>,
+        /// The size of the `.crate` file and its estimated waste, if known
+        compressed: Option<CompressedStats>,
+        /// How to change `Cargo.toml` to avoid the wasted files
+        suggested_fix: Option<SuggestedFix>,
+        /// The `package` section with the `include` or `exclude` directive of `suggested_fix`, as TOML to paste
+        suggested_fix_toml: Option<String>,
+        /// A unified diff applying `suggested_fix` to the published `Cargo.toml`, if it could be parsed
+        cargo_toml_diff: Option<String>,
+        /// The outcome of applying `suggested_fix` with the rules cargo uses for packaging
+        fix_verification: Option<Verification>,
+    },
+    Crate {
+        crate_name: String,
+        total_size_in_bytes: u64,
+        total_files: u64,
+        versions: Dict<Summary>,
+        /// Wasted files of all versions by their file extension
+        wasted_by_extension: Dict<FileStats>,
+        /// Wasted files of all versions by their category
+        wasted_by_category: Dict<FileStats>,
+        /// The size of the `.crate` files of all versions and their estimated waste, where known
+        compressed: Option<CompressedStats>,
+    },
+    CrateCollection {
+        total_size_in_bytes: u64,
+        total_files: u64,
+        crates: Dict<Summary>,
+        /// Wasted files of all crates by their file extension
+        wasted_by_extension: Dict<FileStats>,
+        /// Wasted files of all crates by their category
+        wasted_by_category: Dict<FileStats>,
+        /// The size of the `.crate` files of all crates and their estimated waste, where known
+        compressed: Option<CompressedStats>,
+    },
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+pub struct File {
+    pub path: String,
+    pub size_in_bytes: u64,
+    /// What the file is for, as derived from its path
+    pub category: Category,
+}
+
+#[derive(Default, Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+pub struct FileStats {
+    pub total_bytes: u64,
+    pub total_files: u64,
+}
+
+/// The size of gzip compressed `.crate` files
+#[derive(Default, Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+pub struct CompressedStats {
+    pub total_bytes: u64,
+    /// The estimated share of wasted files in `total_bytes`
+    pub wasted_bytes: u64,
+}
+
+/// The waste of a crate version, or of all versions of a crate
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+pub struct Summary {
+    /// All files
+    pub all: FileStats,
+    /// All wasted files
+    pub waste: FileStats,
+    /// The waste of the most recent version, if this summarizes multiple versions
+    pub waste_latest_version: Option<LatestVersion>,
+    /// Files which might be wasted, but are not part of `waste`
+    pub potential_gains: Option<FileStats>,
+    /// All wasted files by their category
+    pub waste_by_category: Dict<FileStats>,
+    /// The size of the `.crate` files and their estimated waste, where known
+    pub compressed: Option<CompressedStats>,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+pub struct LatestVersion {
+    pub version: String,
+    pub waste: FileStats,
+}
+
+/// Files which might be wasted if the fix is applied
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+pub struct PotentialFiles {
+    pub patterns_to_fix: Patterns,
+    pub files: Vec<File>,
+}
+
+/// A change to the `include` or `exclude` directives of the `package` section in `Cargo.toml`
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SuggestedFix {
+    /// Replace the existing `include` patterns
+    ImprovedInclude {
+        include: Patterns,
+        include_removed: Patterns,
+        potential: Option<PotentialFiles>,
+        has_build_script: bool,
+    },
+    /// Replace the existing `exclude` patterns
+    EnrichedExclude {
+        exclude: Patterns,
+        exclude_added: Patterns,
+        has_build_script: bool,
+    },
+    /// Add `include` patterns where there were none
+    NewInclude { include: Patterns, has_build_script: bool },
+    /// Remove the `exclude` patterns in favor of the given `include` patterns
+    RemoveExcludeAndUseInclude {
+        include_added: Patterns,
+        include: Patterns,
+        include_removed: Patterns,
+    },
+    /// Remove the `exclude` patterns
+    RemoveExclude,
+}
+
+impl From<&AggregateFileInfo> for FileStats {
+    fn from(v: &AggregateFileInfo) -> Self {
+        FileStats {
+            total_bytes: v.total_bytes,
+            total_files: v.total_files,
+        }
+    }
+}
+
+impl From<&CompressedSize> for CompressedStats {
+    fn from(v: &CompressedSize) -> Self {
+        CompressedStats {
+            total_bytes: v.total_bytes,
+            wasted_bytes: v.wasted_bytes,
+        }
+    }
+}
+
+impl From<&VersionInfo> for Summary {
+    fn from(v: &VersionInfo) -> Self {
+        Summary {
+            all: (&v.all).into(),
+            waste: (&v.waste).into(),
+            waste_latest_version: v.waste_latest_version.as_ref().map(|(version, waste)| LatestVersion {
+                version: version.clone(),
+                waste: waste.into(),
+            }),
+            potential_gains: v.potential_gains.as_ref().map(Into::into),
+            waste_by_category: file_stats(&v.waste_by_category),
+            compressed: v.compressed.as_ref().map(Into::into),
+        }
+    }
+}
+
+impl From<&PotentialWaste> for PotentialFiles {
+    fn from(v: &PotentialWaste) -> Self {
+        PotentialFiles {
+            patterns_to_fix: v.patterns_to_fix.clone(),
+            files: v
+                .potential_waste
+                .iter()
+                .map(|e| {
+                    let path = crate::tar_path_to_utf8_str(&e.path);
+                    File {
+                        category: Category::of(&path),
+                        path: path.into(),
+                        size_in_bytes: e.size,
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<&Fix> for SuggestedFix {
+    fn from(v: &Fix) -> Self {
+        match v.clone() {
+            Fix::ImprovedInclude {
+                include,
+                include_removed,
+                potential,
+                has_build_script,
+            } => SuggestedFix::ImprovedInclude {
+                include,
+                include_removed,
+                potential: potential.as_ref().map(Into::into),
+                has_build_script,
+            },
+            Fix::EnrichedExclude {
+                exclude,
+                exclude_added,
+                has_build_script,
+            } => SuggestedFix::EnrichedExclude {
+                exclude,
+                exclude_added,
+                has_build_script,
+            },
+            Fix::NewInclude {
+                include,
+                has_build_script,
+            } => SuggestedFix::NewInclude {
+                include,
+                has_build_script,
+            },
+            Fix::RemoveExcludeAndUseInclude {
+                include_added,
+                include,
+                include_removed,
+            } => SuggestedFix::RemoveExcludeAndUseInclude {
+                include_added,
+                include,
+                include_removed,
+            },
+            Fix::RemoveExclude => SuggestedFix::RemoveExclude,
+        }
+    }
+}
+
+fn file_stats(v: &Dict<AggregateFileInfo>) -> Dict<FileStats> {
+    v.iter().map(|(k, v)| (k.clone(), v.into())).collect()
+}
+
+fn summaries(v: &Dict<VersionInfo>) -> Dict<Summary> {
+    v.iter().map(|(k, v)| (k.clone(), v.into())).collect()
+}
+
+fn compressed_stats(v: &Dict<VersionInfo>) -> Option<CompressedStats> {
+    v.values()
+        .fold(None, |s, e| add_optional_aggregate(s, e.compressed.clone()))
+        .as_ref()
+        .map(Into::into)
+}
+
+impl From<&Report> for Document {
+    fn from(v: &Report) -> Self {
+        let page = match v {
+            Report::Version {
+                crate_name,
+                crate_version,
+                total_size_in_bytes,
+                total_files,
+                wasted_files,
+                suggested_fix,
+                cargo_toml_diff,
+                fix_verification,
+                compressed_size_in_bytes: _,
+            } => {
+                let wasted_by_category = file_stats(&into_map_by_category(Default::default(), wasted_files));
+                let mut wasted_files: Vec<_> = wasted_files
+                    .iter()
+                    .map(|(path, size)| File {
+                        path: path.clone(),
+                        size_in_bytes: *size,
+                        category: Category::of(path),
+                    })
+                    .collect();
+                wasted_files.sort_by(|a, b| b.size_in_bytes.cmp(&a.size_in_bytes).then_with(|| a.path.cmp(&b.path)));
+                Page::Version {
+                    crate_name: crate_name.clone(),
+                    crate_version: crate_version.clone(),
+                    total_size_in_bytes: *total_size_in_bytes,
+                    total_files: *total_files,
+                    waste: FileStats {
+                        total_bytes: wasted_files.iter().map(|f| f.size_in_bytes).sum(),
+                        total_files: wasted_files.len() as u64,
+                    },
+                    wasted_by_category,
+                    compressed: v.compressed_size().as_ref().map(Into::into),
+                    wasted_files,
+                    suggested_fix: suggested_fix.as_ref().map(Into::into),
+                    suggested_fix_toml: suggested_fix.as_ref().map(Fix::to_toml),
+                    cargo_toml_diff: cargo_toml_diff.clone(),
+                    fix_verification: fix_verification.clone(),
+                }
+            }
+            Report::Crate {
+                crate_name,
+                total_size_in_bytes,
+                total_files,
+                info_by_version,
+                wasted_by_extension,
+                wasted_by_category,
+            } => Page::Crate {
+                crate_name: crate_name.clone(),
+                total_size_in_bytes: *total_size_in_bytes,
+                total_files: *total_files,
+                versions: summaries(info_by_version),
+                wasted_by_extension: file_stats(wasted_by_extension),
+                wasted_by_category: file_stats(wasted_by_category),
+                compressed: compressed_stats(info_by_version),
+            },
+            Report::CrateCollection {
+                total_size_in_bytes,
+                total_files,
+                info_by_crate,
+                wasted_by_extension,
+                wasted_by_category,
+            } => Page::CrateCollection {
+                total_size_in_bytes: *total_size_in_bytes,
+                total_files: *total_files,
+                crates: summaries(info_by_crate),
+                wasted_by_extension: file_stats(wasted_by_extension),
+                wasted_by_category: file_stats(wasted_by_category),
+                compressed: compressed_stats(info_by_crate),
+            },
+        };
+        Document {
+            schema_version: SCHEMA_VERSION,
+            page,
+        }
+    }
+}
@@ -3,9 +3,17 @@
 #[macro_use]
 extern crate lazy_static;
 
+pub mod category;
+pub mod fix;
 #[cfg(feature = "html")]
 pub mod html;
+pub mod json;
+#[cfg(feature = "cli")]
+pub mod package;
+pub mod policy;
+pub mod references;
 pub mod result;
+pub mod verify;
 
 #[cfg(test)]
 mod test;
@@ -13,7 +21,8 @@ mod test;
 use serde_derive::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
-pub use result::{globset_from_patterns, tar_path_to_utf8_str};
+pub use policy::{stable_hash, WastePolicy};
+pub use result::{globset_from_patterns, path_bytes_to_str, tar_path_to_utf8_str};
 
 pub type Patterns = Vec<String>;
 
@@ -26,6 +35,9 @@ pub struct TarHeader {
     pub size: u64,
     /// The type of entry, to be analyzed with tar::EntryType
     pub entry_type: u8,
+    /// The blake3 hash of the entire content of regular files, if it was computed
+    #[serde(default)]
+    pub hash: Option<[u8; 32]>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
@@ -102,6 +114,9 @@ pub struct CargoConfig {
     pub package: Option<PackageSection>,
     pub lib: Option<SectionWithPath>,
     pub bin: Option<Vec<SectionWithPath>>,
+    pub example: Option<Vec<SectionWithPath>>,
+    pub test: Option<Vec<SectionWithPath>>,
+    pub bench: Option<Vec<SectionWithPath>>,
 }
 
 impl CargoConfig {
@@ -123,6 +138,37 @@ impl CargoConfig {
             .map(|l| l.iter().filter_map(|s| s.path.as_deref()).collect())
             .unwrap_or_else(|| vec!["src/main.rs"])
     }
+    /// The paths of all `[[example]]`, `[[test]]` and `[[bench]]` targets declared in `Cargo.toml`, which cargo expects
+    /// to be present. Targets declared without a path are in one of the places cargo looks at, which are all returned.
+    pub fn declared_target_paths(&self) -> Vec<String> {
+        [
+            (&self.example, "examples"),
+            (&self.test, "tests"),
+            (&self.bench, "benches"),
+        ]
+        .iter()
+        .filter_map(|(sections, dir)| sections.as_ref().map(|s| (s, dir)))
+        .flat_map(|(sections, dir)| {
+            sections
+                .iter()
+                .flat_map(move |section| match (&section.path, &section.name) {
+                    (Some(path), _) => vec![path.clone()],
+                    (None, Some(name)) => vec![format!("{}/{}.rs", dir, name), format!("{}/{}/main.rs", dir, name)],
+                    (None, None) => Vec::new(),
+                })
+        })
+        .collect()
+    }
+    /// The paths of the root source files of all targets, including the build script, which may not all exist.
+    pub fn target_paths(&self) -> Vec<String> {
+        let mut paths = vec![
+            self.actual_or_expected_build_script_path().to_owned(),
+            self.lib_path().to_owned(),
+        ];
+        paths.extend(self.bin_paths().into_iter().map(ToOwned::to_owned));
+        paths.extend(self.declared_target_paths());
+        paths
+    }
 }
 
 impl From<&str> for CargoConfig {
@@ -133,6 +179,7 @@ impl From<&str> for CargoConfig {
 
 #[derive(Default, Deserialize)]
 pub struct SectionWithPath {
+    pub name: Option<String>,
     pub path: Option<String>,
 }
 
@@ -168,6 +215,41 @@ impl std::ops::AddAssign for AggregateFileInfo {
     }
 }
 
+/// Sizes as downloaded, in the gzip compressed `.crate` file
+#[derive(Default, Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+pub struct CompressedSize {
+    pub total_bytes: u64,
+    /// The estimated share of wasted files in `total_bytes`
+    pub wasted_bytes: u64,
+}
+
+impl CompressedSize {
+    /// Estimate the compressed size of wasted files by attributing `compressed_bytes` to all files in proportion
+    /// to their uncompressed size, as `total_bytes` of which `wasted_bytes` are waste.
+    pub fn estimate(compressed_bytes: u64, total_bytes: u64, wasted_bytes: u64) -> CompressedSize {
+        let wasted_bytes = if total_bytes == 0 {
+            0
+        } else {
+            (u128::from(compressed_bytes) * u128::from(wasted_bytes.min(total_bytes)) / u128::from(total_bytes)) as u64
+        };
+        CompressedSize {
+            total_bytes: compressed_bytes,
+            wasted_bytes,
+        }
+    }
+}
+
+impl std::ops::AddAssign for CompressedSize {
+    fn add_assign(&mut self, rhs: Self) {
+        let Self {
+            total_bytes,
+            wasted_bytes,
+        } = rhs;
+        self.total_bytes += total_bytes;
+        self.wasted_bytes += wasted_bytes;
+    }
+}
+
 impl std::ops::AddAssign for VersionInfo {
     fn add_assign(&mut self, rhs: Self) {
         let Self {
@@ -175,10 +257,16 @@ impl std::ops::AddAssign for VersionInfo {
             waste,
             potential_gains,
             waste_latest_version,
+            waste_by_category,
+            compressed,
         } = rhs;
         self.all += all;
         self.waste += waste;
+        for (category, info) in waste_by_category {
+            *self.waste_by_category.entry(category).or_default() += info;
+        }
         self.potential_gains = add_optional_aggregate(self.potential_gains.clone(), potential_gains);
+        self.compressed = add_optional_aggregate(self.compressed.take(), compressed);
         self.waste_latest_version =
             add_named_optional_aggregate(self.waste_latest_version.clone(), waste_latest_version);
     }
@@ -197,10 +285,7 @@ fn add_named_optional_aggregate(
     })
 }
 
-pub fn add_optional_aggregate(
-    lhs: Option<AggregateFileInfo>,
-    rhs: Option<AggregateFileInfo>,
-) -> Option<AggregateFileInfo> {
+pub fn add_optional_aggregate<T: std::ops::AddAssign>(lhs: Option<T>, rhs: Option<T>) -> Option<T> {
     Some(match (lhs, rhs) {
         (Some(mut lhs), Some(rhs)) => {
             lhs += rhs;
@@ -218,6 +303,12 @@ pub struct VersionInfo {
     pub waste: AggregateFileInfo,
     pub waste_latest_version: Option<(String, AggregateFileInfo)>,
     pub potential_gains: Option<AggregateFileInfo>,
+    /// The waste by the key of its `category::Category`
+    #[serde(default)]
+    pub waste_by_category: Dict<AggregateFileInfo>,
+    /// The size of the downloaded crate files, if known, and the estimated compressed size of their waste
+    #[serde(default)]
+    pub compressed: Option<CompressedSize>,
 }
 
 pub type AggregateVersionInfo = VersionInfo;
@@ -233,6 +324,15 @@ pub enum Report {
         total_files: u64,
         wasted_files: Vec<WastedFile>,
         suggested_fix: Option<Fix>,
+        /// A unified diff applying `suggested_fix` to the published `Cargo.toml`
+        #[serde(default)]
+        cargo_toml_diff: Option<String>,
+        /// The outcome of applying `suggested_fix` with cargo's rules
+        #[serde(default)]
+        fix_verification: Option<verify::Verification>,
+        /// The size of the gzip compressed `.crate` file, if known
+        #[serde(default)]
+        compressed_size_in_bytes: Option<u64>,
     },
     Crate {
         crate_name: String,
@@ -240,12 +340,18 @@ pub enum Report {
         total_files: u64,
         info_by_version: Dict<VersionInfo>,
         wasted_by_extension: Dict<AggregateFileInfo>,
+        /// Wasted files by the key of their `category::Category`
+        #[serde(default)]
+        wasted_by_category: Dict<AggregateFileInfo>,
     },
     CrateCollection {
         total_size_in_bytes: u64,
         total_files: u64,
         info_by_crate: Dict<AggregateVersionInfo>,
         wasted_by_extension: Dict<AggregateFileInfo>,
+        /// Wasted files by the key of their `category::Category`
+        #[serde(default)]
+        wasted_by_category: Dict<AggregateFileInfo>,
     },
 }
 
@@ -257,6 +363,7 @@ fn remove_implicit_entries(entries: &mut Vec<TarHeader>) {
 }
 
 impl Report {
+    /// Compute the waste of a crate version from its `package`, deciding what is waste according to `policy`.
     pub fn from_package(
         crate_name: &str,
         crate_version: &str,
@@ -264,31 +371,41 @@ impl Report {
             mut entries_meta_data,
             entries,
         }: TarPackage,
+        policy: &WastePolicy,
     ) -> Report {
+        let rules = policy.rules_for(crate_name);
         remove_implicit_entries(&mut entries_meta_data);
         let total_size_in_bytes = entries_meta_data.iter().map(|e| e.size).sum();
         let total_files = entries_meta_data.len() as u64;
         let cargo_config = Self::cargo_config_from_entries(&entries);
+        let needed_files = verify::needed_files(&cargo_config, &entries, &entries_meta_data);
+        let all_entries = entries_meta_data.clone();
         let (includes, excludes, compile_time_includes, build_script_name) =
-            Self::cargo_config_into_includes_excludes(cargo_config, &entries, &entries_meta_data);
+            Self::cargo_config_into_includes_excludes(cargo_config, &entries, &entries_meta_data, &rules);
         let (suggested_fix, wasted_files) = match (includes, excludes, build_script_name, compile_time_includes) {
             (Some(includes), Some(excludes), _presence_of_build_script_not_relevant, _) => {
                 Self::compute_includes_from_includes_and_excludes(entries_meta_data, includes, excludes)
             }
             (Some(includes), None, build_script_name, _) => {
-                Self::enrich_includes(entries_meta_data, includes, build_script_name.is_some())
+                Self::enrich_includes(entries_meta_data, includes, build_script_name.is_some(), &rules)
             }
             (None, Some(excludes), build_script_name, compile_time_includes) => Self::enrich_excludes(
                 entries_meta_data,
                 excludes,
                 compile_time_includes,
+                &needed_files,
                 build_script_name.is_some(),
+                &rules,
             ),
             (None, None, build_script_name, compile_time_includes) => {
-                Self::standard_includes(entries_meta_data, build_script_name, compile_time_includes)
+                Self::standard_includes(entries_meta_data, build_script_name, compile_time_includes, &rules)
             }
         };
         let wasted_files = Self::convert_to_wasted_files(wasted_files);
+        let cargo_toml_diff = suggested_fix.as_ref().and_then(|fix| fix.to_manifest_diff(&entries));
+        let fix_verification = suggested_fix
+            .as_ref()
+            .map(|fix| verify::verify(fix, &all_entries, &needed_files, &wasted_files));
         Report::Version {
             crate_name: crate_name.into(),
             crate_version: crate_version.into(),
@@ -296,6 +413,39 @@ impl Report {
             total_files,
             wasted_files,
             suggested_fix,
+            cargo_toml_diff,
+            fix_verification,
+            compressed_size_in_bytes: None,
+        }
+    }
+
+    /// Set the size of the `.crate` file of a version report, to estimate how much of it is wasted.
+    pub fn with_compressed_size(mut self, size_in_bytes: Option<u64>) -> Report {
+        if let Report::Version {
+            compressed_size_in_bytes,
+            ..
+        } = &mut self
+        {
+            *compressed_size_in_bytes = size_in_bytes;
+        }
+        self
+    }
+
+    /// The compressed size of a version report and the estimated compressed size of its waste, if the size of its
+    /// `.crate` file is known.
+    pub fn compressed_size(&self) -> Option<CompressedSize> {
+        match self {
+            Report::Version {
+                total_size_in_bytes,
+                wasted_files,
+                compressed_size_in_bytes: Some(compressed_bytes),
+                ..
+            } => Some(CompressedSize::estimate(
+                *compressed_bytes,
+                *total_size_in_bytes,
+                wasted_files.iter().map(|(_, size)| size).sum(),
+            )),
+            _ => None,
         }
     }
 }
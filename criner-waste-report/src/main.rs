@@ -0,0 +1,234 @@
+use bytesize::ByteSize;
+use clap::Parser;
+use criner_waste_report::{
+    category::{into_map_by_category, Category},
+    json,
+    package::LocalPackage,
+    Report, WastePolicy,
+};
+use std::{
+    fs::File,
+    io,
+    io::{BufReader, Write},
+    path::PathBuf,
+};
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+enum Format {
+    /// A summary of the waste, all wasted files and the suggested fix
+    Text,
+    /// The report as JSON document, as written by criner
+    Json,
+    /// The `package` section with the suggested fix to paste into Cargo.toml, if there is one
+    Toml,
+}
+
+#[derive(Debug, clap::Parser)]
+#[clap(about = "Find files in a crate which aren't needed to build it, and suggest how to avoid publishing them")]
+struct Args {
+    /// A directory containing Cargo.toml, a .crate file, or `name@version` of a crate downloaded by criner
+    source: String,
+
+    /// The criner database to find `name@version` in
+    #[clap(long, default_value = "criner.db")]
+    db_path: PathBuf,
+
+    /// A TOML file with the patterns deciding what is waste, instead of the built-in ones
+    #[clap(long, value_name = "FILE")]
+    policy: Option<PathBuf>,
+
+    /// How to print the report
+    #[clap(long, short = 'f', value_enum, default_value = "text")]
+    format: Format,
+
+    /// Exit with a non-zero code if more than this percentage of all bytes are wasted
+    #[clap(long, value_name = "PERCENT")]
+    max_waste_percent: Option<f64>,
+
+    /// Exit with a non-zero code if more than this amount of bytes are wasted
+    #[clap(long, value_name = "BYTES")]
+    max_waste_bytes: Option<u64>,
+}
+
+/// Load the package along with the size of its `.crate` file, which directories don't have.
+fn load(args: &Args) -> io::Result<(LocalPackage, Option<u64>)> {
+    let path = PathBuf::from(&args.source);
+    if path.is_dir() {
+        Ok((LocalPackage::from_directory(path)?, None))
+    } else if path.is_file() {
+        load_crate_file(path)
+    } else {
+        match args.source.split_once('@') {
+            Some((name, version)) => load_crate_file(criner_waste_report::package::crate_file_in_db(
+                &args.db_path,
+                name,
+                version,
+            )?),
+            None => Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("'{}' is neither a directory, a file nor name@version", args.source),
+            )),
+        }
+    }
+}
+
+fn load_crate_file(path: PathBuf) -> io::Result<(LocalPackage, Option<u64>)> {
+    let file = File::open(path)?;
+    let compressed_size = file.metadata()?.len();
+    Ok((
+        LocalPackage::from_crate_file(BufReader::new(file))?,
+        Some(compressed_size),
+    ))
+}
+
+fn policy(args: &Args) -> io::Result<WastePolicy> {
+    match &args.policy {
+        Some(path) => WastePolicy::from_toml(&std::fs::read_to_string(path)?)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err)),
+        None => Ok(WastePolicy::default()),
+    }
+}
+
+fn text(report: &Report) -> String {
+    let mut out = String::new();
+    if let Report::Version {
+        crate_name,
+        crate_version,
+        total_size_in_bytes,
+        total_files,
+        wasted_files,
+        suggested_fix,
+        fix_verification,
+        ..
+    } = report
+    {
+        let wasted_bytes: u64 = wasted_files.iter().map(|(_, size)| size).sum();
+        out.push_str(&format!(
+            "{} {}: {} files, {} in total\n",
+            crate_name,
+            crate_version,
+            total_files,
+            ByteSize(*total_size_in_bytes)
+        ));
+        out.push_str(&format!(
+            "Wasted: {} files, {} ({:.1}%)\n",
+            wasted_files.len(),
+            ByteSize(wasted_bytes),
+            percentage(wasted_bytes, *total_size_in_bytes)
+        ));
+        if let Some(compressed) = report.compressed_size() {
+            out.push_str(&format!(
+                "Compressed: {} in total, an estimated {} ({:.1}%) wasted\n",
+                ByteSize(compressed.total_bytes),
+                ByteSize(compressed.wasted_bytes),
+                percentage(compressed.wasted_bytes, compressed.total_bytes)
+            ));
+        }
+        let mut by_category: Vec<_> = into_map_by_category(Default::default(), wasted_files)
+            .into_iter()
+            .collect();
+        by_category.sort_by(|(ln, l), (rn, r)| r.total_bytes.cmp(&l.total_bytes).then_with(|| ln.cmp(rn)));
+        for (name, info) in by_category {
+            out.push_str(&format!(
+                "{:>12} {} ({} files)\n",
+                ByteSize(info.total_bytes).to_string(),
+                Category::from_key(&name).map(Category::description).unwrap_or(&name),
+                info.total_files
+            ));
+        }
+        if !wasted_files.is_empty() {
+            out.push('\n');
+        }
+        let mut wasted_files = wasted_files.clone();
+        wasted_files.sort_by(|(lp, ls), (rp, rs)| rs.cmp(ls).then_with(|| lp.cmp(rp)));
+        for (path, size) in wasted_files {
+            out.push_str(&format!("{:>12} {}\n", ByteSize(size).to_string(), path));
+        }
+        match suggested_fix {
+            Some(fix) => {
+                out.push_str("\nSuggested fix for Cargo.toml:\n\n");
+                out.push_str(&fix.to_toml());
+            }
+            None => out.push_str("\nNo changes to Cargo.toml needed\n"),
+        }
+        if let Some(verification) = fix_verification.as_ref().filter(|v| !v.is_ok()) {
+            out.push_str(
+                "\nApplying the fix with the rules cargo uses for packaging doesn't yield the expected result:\n",
+            );
+            for (title, paths) in &[
+                (
+                    "needed files which would be removed",
+                    &verification.removed_needed_files,
+                ),
+                ("wasted files which would be kept", &verification.retained_waste),
+                ("invalid patterns", &verification.invalid_patterns),
+            ] {
+                if !paths.is_empty() {
+                    out.push_str(&format!("  {}: {}\n", title, paths.join(", ")));
+                }
+            }
+        }
+    }
+    out
+}
+
+fn percentage(part: u64, total: u64) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        part as f64 * 100.0 / total as f64
+    }
+}
+
+fn main() -> io::Result<()> {
+    let args = Args::parse();
+    let policy = policy(&args)?;
+    let (
+        LocalPackage {
+            crate_name,
+            crate_version,
+            package,
+        },
+        compressed_size,
+    ) = load(&args)?;
+    let report =
+        Report::from_package(&crate_name, &crate_version, package, &policy).with_compressed_size(compressed_size);
+
+    let output = match args.format {
+        Format::Text => text(&report),
+        Format::Json => {
+            serde_json::to_string_pretty(&json::Document::from(&report)).expect("reports to always serialize") + "\n"
+        }
+        Format::Toml => match &report {
+            Report::Version {
+                suggested_fix: Some(fix),
+                ..
+            } => fix.to_toml(),
+            _ => String::new(),
+        },
+    };
+    io::stdout().write_all(output.as_bytes())?;
+
+    if let Report::Version {
+        total_size_in_bytes,
+        wasted_files,
+        ..
+    } = &report
+    {
+        let wasted_bytes: u64 = wasted_files.iter().map(|(_, size)| size).sum();
+        let wasted_percent = percentage(wasted_bytes, *total_size_in_bytes);
+        let exceeds_bytes = args.max_waste_bytes.is_some_and(|max| wasted_bytes > max);
+        let exceeds_percent = args.max_waste_percent.is_some_and(|max| wasted_percent > max);
+        if exceeds_bytes || exceeds_percent {
+            eprintln!(
+                "{} {} wastes {} ({:.1}%), which is more than allowed",
+                crate_name,
+                crate_version,
+                ByteSize(wasted_bytes),
+                wasted_percent
+            );
+            std::process::exit(1);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,208 @@
+//! Obtain packages from where crates are found outside of criner: a crate directory on disk, a `.crate` file,
+//! or the downloads of a criner database.
+use super::{verify::Packager, CargoConfig, TarHeader, TarPackage};
+use std::{
+    fs, io,
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+/// A crate version along with its package, ready to be turned into a report
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalPackage {
+    pub crate_name: String,
+    pub crate_version: String,
+    pub package: TarPackage,
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Files whose content is needed to compute a report: manifests and all Rust sources
+fn is_needed_for_analysis(path: &str) -> bool {
+    path == "Cargo.toml" || path == "Cargo.toml.orig" || path.ends_with(".rs")
+}
+
+/// Return the name and version from the `package` section of `manifest`. The version is `0.0.0` if it isn't set
+/// explicitly, like when it's inherited from the workspace.
+fn name_and_version(manifest: &str) -> io::Result<(String, String)> {
+    let manifest: toml::Value =
+        toml::from_str(manifest).map_err(|err| invalid_data(format!("Could not parse Cargo.toml: {}", err)))?;
+    let package = manifest.get("package");
+    let field = |name: &str| package.and_then(|p| p.get(name)).and_then(|v| v.as_str());
+    Ok((
+        field("name")
+            .ok_or_else(|| invalid_data("Cargo.toml has no package name".into()))?
+            .to_owned(),
+        field("version").unwrap_or("0.0.0").to_owned(),
+    ))
+}
+
+fn tar_header(crate_name: &str, crate_version: &str, path: &str, size: u64) -> TarHeader {
+    TarHeader {
+        path: format!("{}-{}/{}", crate_name, crate_version, path).into_bytes(),
+        size,
+        entry_type: b'0',
+        hash: None,
+    }
+}
+
+impl LocalPackage {
+    /// Read the package from a `.crate` file, a gzip compressed tar archive as uploaded to crates.io.
+    pub fn from_crate_file(file: impl Read) -> io::Result<LocalPackage> {
+        let mut archive = tar::Archive::new(libflate::gzip::Decoder::new(file)?);
+        let mut entries_meta_data = Vec::new();
+        let mut entries = Vec::new();
+        for e in archive.entries()? {
+            let mut e = e?;
+            let header = TarHeader {
+                path: e.path_bytes().to_vec(),
+                size: e.header().size()?,
+                entry_type: e.header().entry_type().as_byte(),
+                hash: None,
+            };
+            if e.header().entry_type().is_file() && is_needed_for_analysis(&crate::tar_path_to_utf8_str(&header.path)) {
+                let mut content = Vec::new();
+                e.read_to_end(&mut content)?;
+                entries.push((header.clone(), content));
+            }
+            entries_meta_data.push(header);
+        }
+        let manifest = entries
+            .iter()
+            .find(|(h, _)| crate::tar_path_to_utf8_str(&h.path) == "Cargo.toml")
+            .and_then(|(_, content)| std::str::from_utf8(content).ok())
+            .ok_or_else(|| invalid_data("The crate file contains no Cargo.toml".into()))?;
+        let (crate_name, crate_version) = name_and_version(manifest)?;
+        Ok(LocalPackage {
+            crate_name,
+            crate_version,
+            package: TarPackage {
+                entries_meta_data,
+                entries,
+            },
+        })
+    }
+
+    /// Collect the files cargo would package for the crate in `directory` like `cargo package --list` does, honoring the
+    /// `include` and `exclude` directives in its manifest, and `.gitignore` files unless `include` is set.
+    pub fn from_directory(directory: impl AsRef<Path>) -> io::Result<LocalPackage> {
+        let directory = directory.as_ref();
+        let manifest = fs::read_to_string(directory.join("Cargo.toml"))?;
+        let (crate_name, crate_version) = name_and_version(&manifest)?;
+        let package_section = CargoConfig::from(manifest.as_str()).package.unwrap_or_default();
+        let mut invalid_patterns = Vec::new();
+        let packager = Packager::from_directives(
+            package_section.include.as_ref(),
+            package_section.exclude.as_ref(),
+            &mut invalid_patterns,
+        );
+        if !invalid_patterns.is_empty() {
+            return Err(invalid_data(format!(
+                "Cargo.toml contains invalid patterns: {}",
+                invalid_patterns.join(", ")
+            )));
+        }
+
+        let use_git_ignores = !packager.has_include();
+        let mut paths = Vec::new();
+        for entry in ignore::WalkBuilder::new(directory)
+            .hidden(false)
+            .ignore(false)
+            .git_ignore(use_git_ignores)
+            .git_exclude(use_git_ignores)
+            .git_global(use_git_ignores)
+            .filter_entry(|e| {
+                let is_dir = e.file_type().is_some_and(|t| t.is_dir());
+                // Like cargo, skip the git repository, the build directory and nested packages
+                !(e.depth() > 0
+                    && is_dir
+                    && (e.file_name() == ".git"
+                        || (e.depth() == 1 && e.file_name() == "target")
+                        || e.path().join("Cargo.toml").is_file()))
+            })
+            .build()
+        {
+            let entry = entry.map_err(io::Error::other)?;
+            if !entry.file_type().is_some_and(|t| t.is_file()) {
+                continue;
+            }
+            let path = entry
+                .path()
+                .strip_prefix(directory)
+                .expect("walked paths to be in the walked directory")
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            if packager.is_packaged(&path) {
+                paths.push((path, entry.into_path()));
+            }
+        }
+        paths.sort();
+
+        let mut entries_meta_data = Vec::with_capacity(paths.len());
+        let mut entries = Vec::new();
+        for (path, file) in paths {
+            let header = tar_header(&crate_name, &crate_version, &path, fs::metadata(&file)?.len());
+            if is_needed_for_analysis(&path) {
+                entries.push((header.clone(), fs::read(&file)?));
+            }
+            entries_meta_data.push(header);
+        }
+        Ok(LocalPackage {
+            crate_name,
+            crate_version,
+            package: TarPackage {
+                entries_meta_data,
+                entries,
+            },
+        })
+    }
+}
+
+/// The directory criner downloads a crate to within its `assets_dir`, sharded by the beginning of its name.
+fn crate_dir(assets_dir: &Path, crate_name: &str) -> PathBuf {
+    let crate_path = match crate_name.len() {
+        1 => Path::new("1").join(crate_name),
+        2 => Path::new("2").join(crate_name),
+        3 => Path::new("3").join(&crate_name[..1]).join(&crate_name[1..]),
+        _ => Path::new(&crate_name[..2]).join(&crate_name[2..4]).join(crate_name),
+    };
+    assets_dir.join(crate_path)
+}
+
+/// Find the `.crate` file of `crate_name` at `crate_version` among the downloads of the criner database at `db_path`.
+pub fn crate_file_in_db(db_path: impl AsRef<Path>, crate_name: &str, crate_version: &str) -> io::Result<PathBuf> {
+    if !crate_name.is_ascii() || crate_name.is_empty() {
+        return Err(invalid_data(format!("'{}' is not a valid crate name", crate_name)));
+    }
+    let dir = crate_dir(&db_path.as_ref().join("assets"), crate_name);
+    let prefix = format!("{}-download:", crate_version);
+    let mut candidates = Vec::new();
+    if dir.is_dir() {
+        for entry in fs::read_dir(&dir)? {
+            let path = entry?.path();
+            let is_download = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n.starts_with(&prefix) && n.ends_with(".crate"));
+            if is_download {
+                candidates.push(path);
+            }
+        }
+    }
+    candidates.sort();
+    candidates.pop().ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            format!(
+                "{}@{} wasn't downloaded into the criner database at '{}'",
+                crate_name,
+                crate_version,
+                db_path.as_ref().display()
+            ),
+        )
+    })
+}
@@ -0,0 +1,197 @@
+//! Decide which files are waste with patterns of your own, loaded from TOML, instead of the built-in ones.
+//!
+//! ```toml
+//! # Files needed to build crates, replacing the built-in patterns. Optional.
+//! include = ["src/**/*", "Cargo.*", "README.*"]
+//! # Files usually not needed to build crates, replacing the built-in patterns. Optional.
+//! exclude = ["**/tests/**/*", "**/benches/**/*"]
+//! # Files which are never waste, even if they match an exclude pattern.
+//! allow = ["**/testdata/**/*.json"]
+//!
+//! # Patterns to add for a crate by its name
+//! [crate.my-game]
+//! allow = ["assets/**/*"]
+//! ```
+use super::{
+    result::{make_glob, standard_exclude_patterns, standard_include_patterns},
+    Patterns,
+};
+use serde_derive::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{Arc, Mutex},
+};
+
+/// The hash of a policy, and the name of a crate if the policy has overrides for it
+type RulesKey = (u64, Option<String>);
+
+lazy_static! {
+    /// Rules by the policy and crate they are compiled for
+    static ref RULES: Mutex<HashMap<RulesKey, Arc<Rules>>> = Default::default();
+}
+
+/// Patterns to add to those of the policy for a single crate
+#[derive(Default, Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct CratePolicy {
+    #[serde(default)]
+    pub include: Patterns,
+    #[serde(default)]
+    pub exclude: Patterns,
+    #[serde(default)]
+    pub allow: Patterns,
+}
+
+/// The patterns deciding which files are needed to build a crate, and which files are waste.
+#[derive(Default, Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct WastePolicy {
+    /// Files needed to build crates, or the built-in patterns if unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include: Option<Patterns>,
+    /// Files not needed to build crates, or the built-in patterns if unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exclude: Option<Patterns>,
+    /// Files which are never waste, even if they match an `exclude` pattern
+    #[serde(default)]
+    pub allow: Patterns,
+    /// Additional patterns by crate name
+    #[serde(default, rename = "crate")]
+    pub crates: BTreeMap<String, CratePolicy>,
+    /// The hash of all of the above, computed once by `from_toml()`
+    #[serde(skip)]
+    pub(crate) hash: Option<u64>,
+}
+
+/// Policies are compared by their patterns, as the hash is derived from them.
+impl PartialEq for WastePolicy {
+    fn eq(&self, other: &Self) -> bool {
+        self.include == other.include
+            && self.exclude == other.exclude
+            && self.allow == other.allow
+            && self.crates == other.crates
+    }
+}
+
+impl Eq for WastePolicy {}
+
+/// An error when loading a `WastePolicy`
+#[derive(Debug)]
+pub enum PolicyError {
+    Toml(toml::de::Error),
+    Pattern(globset::Error),
+}
+
+impl std::fmt::Display for PolicyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PolicyError::Toml(err) => write!(f, "Could not parse the waste policy: {}", err),
+            PolicyError::Pattern(err) => write!(f, "Invalid pattern in the waste policy: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for PolicyError {}
+
+/// The compiled patterns of a policy, as they apply to a crate
+pub(crate) struct Rules {
+    pub include_patterns: Patterns,
+    pub include_globs: Vec<globset::Glob>,
+    pub include_matchers: Vec<(String, globset::GlobMatcher)>,
+    pub exclude_globset: globset::GlobSet,
+    pub exclude_matchers: Vec<(String, globset::GlobMatcher)>,
+    pub allow: Patterns,
+    pub allow_globset: globset::GlobSet,
+}
+
+impl Rules {
+    fn new(include_patterns: Patterns, exclude_patterns: Patterns, allow: Patterns) -> Rules {
+        let matchers = |patterns: &Patterns| {
+            patterns
+                .iter()
+                .map(|p| (p.clone(), make_glob(p).compile_matcher()))
+                .collect()
+        };
+        Rules {
+            include_globs: include_patterns.iter().map(|p| make_glob(p)).collect(),
+            include_matchers: matchers(&include_patterns),
+            exclude_globset: crate::globset_from_patterns(&exclude_patterns),
+            exclude_matchers: matchers(&exclude_patterns),
+            include_patterns,
+            allow_globset: crate::globset_from_patterns(&allow),
+            allow,
+        }
+    }
+}
+
+fn to_patterns(patterns: &[&str]) -> Patterns {
+    patterns.iter().map(|p| p.to_string()).collect()
+}
+
+/// FNV-1a, which is stable across platforms and releases unlike the hashers of the standard library
+pub fn stable_hash(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, b| {
+        (hash ^ u64::from(*b)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+impl WastePolicy {
+    /// Parse a policy from `toml` and assure all of its patterns are valid.
+    pub fn from_toml(toml: &str) -> Result<WastePolicy, PolicyError> {
+        let mut policy: WastePolicy = toml::from_str(toml).map_err(PolicyError::Toml)?;
+        let crate_patterns = policy
+            .crates
+            .values()
+            .flat_map(|c| c.include.iter().chain(c.exclude.iter()).chain(c.allow.iter()));
+        for pattern in policy
+            .include
+            .iter()
+            .flatten()
+            .chain(policy.exclude.iter().flatten())
+            .chain(policy.allow.iter())
+            .chain(crate_patterns)
+        {
+            globset::Glob::new(pattern).map_err(PolicyError::Pattern)?;
+        }
+        if policy != WastePolicy::default() {
+            policy.hash = Some(stable_hash(policy.to_toml().as_bytes()));
+        }
+        Ok(policy)
+    }
+
+    /// The policy as TOML, as it can be read by `from_toml()`.
+    pub fn to_toml(&self) -> String {
+        toml::to_string(self).expect("policies to always serialize")
+    }
+
+    /// A hash of all patterns, which changes whenever the policy does, for use in versions of reports.
+    /// It is `None` for the default policy.
+    pub fn hash(&self) -> Option<u64> {
+        self.hash
+    }
+
+    pub(crate) fn rules_for(&self, crate_name: &str) -> Arc<Rules> {
+        let overrides = self.crates.get(crate_name);
+        let key = (self.hash().unwrap_or(0), overrides.map(|_| crate_name.to_owned()));
+        if let Some(rules) = RULES.lock().expect("no panics while locked").get(&key) {
+            return rules.clone();
+        }
+        let mut include = self
+            .include
+            .clone()
+            .unwrap_or_else(|| to_patterns(standard_include_patterns()));
+        let mut exclude = self
+            .exclude
+            .clone()
+            .unwrap_or_else(|| to_patterns(standard_exclude_patterns()));
+        let mut allow = self.allow.clone();
+        if let Some(overrides) = overrides {
+            include.extend(overrides.include.iter().cloned());
+            exclude.extend(overrides.exclude.iter().cloned());
+            allow.extend(overrides.allow.iter().cloned());
+        }
+        let rules = Arc::new(Rules::new(include, exclude, allow));
+        RULES.lock().expect("no panics while locked").insert(key, rules.clone());
+        rules
+    }
+}
@@ -0,0 +1,185 @@
+//! Find the files a crate needs to compile by following `mod` declarations and `include!`-like macros
+//! through its Rust sources, starting at the targets declared in `Cargo.toml`.
+//!
+//! This is a textual analysis, so it errs on the side of finding too many files: modules behind `cfg` attributes or in
+//! comments are followed just like all others.
+use std::collections::{BTreeSet, VecDeque};
+
+lazy_static! {
+    static ref MODULE_DECLARATION: regex::bytes::Regex = regex::bytes::Regex::new(
+        r##"(?P<attributes>(?:#\[[^\]]*\]\s*)*)(?:pub(?:\s*\([^)]*\))?\s+)?mod\s+(?:r#)?(?P<name>[A-Za-z_][A-Za-z0-9_]*)\s*;"##
+    )
+    .expect("valid statically known regex");
+    static ref PATH_ATTRIBUTE: regex::bytes::Regex =
+        regex::bytes::Regex::new(r##"\bpath\s*=\s*"(?P<path>[^"]+)""##).expect("valid statically known regex");
+    static ref INCLUDE: regex::bytes::Regex = regex::bytes::Regex::new(
+        r##"\binclude(?P<kind>_str|_bytes)?!\s*\(\s*(?:"(?P<path>[^"]+)"|concat!\s*\(\s*env!\s*\(\s*"CARGO_MANIFEST_DIR"\s*\)\s*(?P<parts>(?:,\s*"[^"]*"\s*)+),?\s*\))"##
+    )
+    .expect("valid statically known regex");
+    static ref STRING_LITERAL: regex::bytes::Regex =
+        regex::bytes::Regex::new(r##""(?P<content>[^"]*)""##).expect("valid statically known regex");
+}
+
+/// A Rust source file to analyse, along with the directories relative to which its references are resolved
+struct Source {
+    /// The crate relative path of the file
+    path: String,
+    /// The directory containing the files of modules declared with `mod name;`
+    module_dir: String,
+    /// The directory relative to which `#[path = "…"]` attributes are resolved
+    path_attribute_dir: String,
+}
+
+/// A file referenced by a Rust source file
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Reference {
+    /// A module, whose file is the first of the candidates that exists
+    Module {
+        candidates: Vec<String>,
+        has_path_attribute: bool,
+    },
+    /// A file included by `include!`, `include_str!` or `include_bytes!`, with `is_source` being true for the former
+    Include { path: String, is_source: bool },
+}
+
+fn parent_of(path: &str) -> &str {
+    path.rfind('/').map(|pos| &path[..pos]).unwrap_or("")
+}
+
+/// Join `relative` to `dir`, resolving `.` and `..`. Returns `None` for absolute paths or those leaving the crate.
+fn join(dir: &str, relative: &str) -> Option<String> {
+    if relative.starts_with('/') || relative.starts_with('\\') {
+        return None;
+    }
+    let mut components: Vec<&str> = dir.split('/').filter(|c| !c.is_empty()).collect();
+    for component in relative.split(['/', '\\']) {
+        match component {
+            "" | "." => {}
+            ".." => {
+                components.pop()?;
+            }
+            component => components.push(component),
+        }
+    }
+    Some(components.join("/"))
+}
+
+impl Source {
+    /// A file compiled as crate root, or loaded like one, which keeps its modules next to it.
+    fn root(path: String) -> Self {
+        let dir = parent_of(&path).to_owned();
+        Source {
+            path,
+            module_dir: dir.clone(),
+            path_attribute_dir: dir,
+        }
+    }
+
+    fn module(path: String) -> Self {
+        let is_mod_rs = path == "mod.rs" || path.ends_with("/mod.rs");
+        let dir = parent_of(&path).to_owned();
+        Source {
+            module_dir: if is_mod_rs {
+                dir.clone()
+            } else {
+                path.trim_end_matches(".rs").to_owned()
+            },
+            path_attribute_dir: dir,
+            path,
+        }
+    }
+
+    fn references(&self, content: &[u8]) -> Vec<Reference> {
+        let mut references = Vec::new();
+        for cap in MODULE_DECLARATION.captures_iter(content) {
+            let path_attribute = PATH_ATTRIBUTE
+                .captures(&cap["attributes"])
+                .map(|path| String::from_utf8_lossy(&path["path"]).into_owned());
+            let (candidates, has_path_attribute) = match path_attribute {
+                Some(path) => (join(&self.path_attribute_dir, &path).into_iter().collect(), true),
+                None => {
+                    let name = String::from_utf8_lossy(&cap["name"]);
+                    (
+                        [format!("{}.rs", name), format!("{}/mod.rs", name)]
+                            .iter()
+                            .filter_map(|file| join(&self.module_dir, file))
+                            .collect(),
+                        false,
+                    )
+                }
+            };
+            references.push(Reference::Module {
+                candidates,
+                has_path_attribute,
+            });
+        }
+        for cap in INCLUDE.captures_iter(content) {
+            let path = match (cap.name("path"), cap.name("parts")) {
+                (Some(path), _) => join(parent_of(&self.path), &String::from_utf8_lossy(path.as_bytes())),
+                (None, Some(parts)) => {
+                    let path: String = STRING_LITERAL
+                        .captures_iter(parts.as_bytes())
+                        .map(|part| String::from_utf8_lossy(&part["content"]).into_owned())
+                        .collect();
+                    join("", path.trim_start_matches(['/', '\\']))
+                }
+                (None, None) => None,
+            };
+            if let Some(path) = path {
+                references.push(Reference::Include {
+                    path,
+                    is_source: cap.name("kind").is_none(),
+                });
+            }
+        }
+        references
+    }
+}
+
+/// Follow all references of the Rust sources at the crate relative `roots` transitively, and return the crate relative
+/// paths of all files they reference, including the roots themselves, as long as they `exist`.
+/// References are found in the `content` of Rust sources, which may not be available for all of them.
+pub fn resolve<'a, C: AsRef<[u8]>>(
+    roots: impl IntoIterator<Item = &'a str>,
+    exists: impl Fn(&str) -> bool,
+    mut content: impl FnMut(&str) -> Option<C>,
+) -> BTreeSet<String> {
+    let mut found = BTreeSet::new();
+    let mut queue: VecDeque<_> = roots.into_iter().map(|root| Source::root(root.to_owned())).collect();
+    while let Some(source) = queue.pop_front() {
+        if !exists(&source.path) || !found.insert(source.path.clone()) {
+            continue;
+        }
+        let references = match content(&source.path) {
+            Some(content) => source.references(content.as_ref()),
+            None => continue,
+        };
+        for reference in references {
+            match reference {
+                Reference::Module {
+                    candidates,
+                    has_path_attribute,
+                } => {
+                    if let Some(path) = candidates.into_iter().find(|path| exists(path)) {
+                        queue.push_back(if has_path_attribute {
+                            Source::root(path)
+                        } else {
+                            Source::module(path)
+                        });
+                    }
+                }
+                Reference::Include { path, is_source: true } => queue.push_back(Source {
+                    path,
+                    module_dir: source.module_dir.clone(),
+                    path_attribute_dir: source.path_attribute_dir.clone(),
+                }),
+                Reference::Include { path, is_source: false } => {
+                    if exists(&path) {
+                        found.insert(path);
+                    }
+                }
+            }
+        }
+    }
+    found
+}
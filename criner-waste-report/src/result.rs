@@ -1,42 +1,37 @@
-use super::{CargoConfig, Fix, Patterns, PotentialWaste, Report, TarHeader, WastedFile};
-use std::{collections::BTreeSet, path::Path, path::PathBuf};
+use super::{policy::Rules, references, CargoConfig, Fix, Patterns, PotentialWaste, Report, TarHeader, WastedFile};
+use std::{borrow::Cow, collections::BTreeSet, fmt::Write, path::Path};
 
 lazy_static! {
-    static ref COMPILE_TIME_INCLUDE: regex::bytes::Regex =
-        regex::bytes::Regex::new(r##"include_(str|bytes)!\("(?P<include>.+?)"\)"##)
-            .expect("valid statically known regex");
     static ref BUILD_SCRIPT_PATHS: regex::bytes::Regex =
         regex::bytes::Regex::new(r##""cargo:rerun-if-changed=(?P<path>.+?)"|"(?P<path_like>.+?)""##)
             .expect("valid statically known regex");
-    static ref STANDARD_EXCLUDES_GLOBSET: globset::GlobSet = globset_from_patterns(standard_exclude_patterns());
-    static ref STANDARD_EXCLUDE_MATCHERS: Vec<(&'static str, globset::GlobMatcher)> = standard_exclude_patterns()
-        .iter()
-        .cloned()
-        .map(|p| (p, make_glob(p).compile_matcher()))
-        .collect();
-    static ref STANDARD_INCLUDE_GLOBS: Vec<globset::Glob> =
-        standard_include_patterns().iter().map(|p| make_glob(p)).collect();
-    static ref STANDARD_INCLUDE_MATCHERS: Vec<(&'static str, globset::GlobMatcher)> = standard_include_patterns()
-        .iter()
-        .cloned()
-        .map(|p| (p, make_glob(p).compile_matcher()))
-        .collect();
 }
 
-pub fn tar_path_to_utf8_str(mut bytes: &[u8]) -> &str {
+/// Convert a path as found in a tar archive into a string without losing information, as archives may contain anything.
+/// Valid UTF-8 is returned as is, and all other bytes are escaped as `\xNN`.
+pub fn path_bytes_to_str(bytes: &[u8]) -> Cow<'_, str> {
+    match std::str::from_utf8(bytes) {
+        Ok(path) => Cow::Borrowed(path),
+        Err(_) => {
+            let mut path = String::with_capacity(bytes.len() * 2);
+            for chunk in bytes.utf8_chunks() {
+                path.push_str(chunk.valid());
+                for byte in chunk.invalid() {
+                    write!(path, "\\x{:02x}", byte).expect("writing to a string never fails");
+                }
+            }
+            Cow::Owned(path)
+        }
+    }
+}
+
+/// Return the crate relative path of the tar archive entry path `bytes`, see `path_bytes_to_str()`.
+pub fn tar_path_to_utf8_str(mut bytes: &[u8]) -> Cow<'_, str> {
     // Tar paths include the parent directory, cut it to crate relative paths
     if let Some(pos) = bytes.iter().position(|b| *b == b'/' || *b == b'\\') {
         bytes = bytes.get(pos + 1..).unwrap_or(bytes);
     }
-    std::str::from_utf8(bytes).expect("valid utf8 paths in crate archive")
-}
-
-fn tar_path_to_path(bytes: &[u8]) -> &Path {
-    Path::new(tar_path_to_utf8_str(bytes))
-}
-
-fn tar_path_to_path_no_strip(bytes: &[u8]) -> &Path {
-    Path::new(std::str::from_utf8(bytes).expect("valid utf8 paths in crate archive"))
+    path_bytes_to_str(bytes)
 }
 
 // NOTE: Actually there only seem to be files in these archives, but let's be safe
@@ -54,7 +49,7 @@ fn split_to_matched_and_unmatched(
     let matched = entries
         .into_iter()
         .filter_map(|e| {
-            if globset.is_match(tar_path_to_utf8_str(&e.path)) {
+            if globset.is_match(&*tar_path_to_utf8_str(&e.path)) {
                 Some(e)
             } else {
                 unmatched.push(e);
@@ -69,8 +64,8 @@ fn directories_of(entries: &[TarHeader]) -> Vec<TarHeader> {
     let mut directories = BTreeSet::new();
     for e in entries {
         if entry_is_file(e.entry_type) {
-            if let Some(parent) = tar_path_to_path_no_strip(&e.path).parent() {
-                directories.insert(parent);
+            if let Some(parent) = Path::new(&*path_bytes_to_str(&e.path)).parent() {
+                directories.insert(parent.to_owned());
             }
         }
     }
@@ -81,11 +76,12 @@ fn directories_of(entries: &[TarHeader]) -> Vec<TarHeader> {
             path: k.to_str().expect("utf8 paths").as_bytes().to_owned(),
             size: 0,
             entry_type: tar_directory_entry,
+            hash: None,
         })
         .collect()
 }
 
-fn standard_exclude_patterns() -> &'static [&'static str] {
+pub(crate) fn standard_exclude_patterns() -> &'static [&'static str] {
     &[
         "**/*.jpg",
         "**/*.jpeg",
@@ -128,7 +124,7 @@ fn standard_exclude_patterns() -> &'static [&'static str] {
     ]
 }
 
-fn standard_include_patterns() -> &'static [&'static str] {
+pub(crate) fn standard_include_patterns() -> &'static [&'static str] {
     &[
         "src/**/*",
         "Cargo.*",
@@ -230,19 +226,19 @@ fn find_include_patterns_that_incorporate_exclude_patterns(
         let include = glob.compile_matcher();
         if entries_to_exclude
             .iter()
-            .any(|e| include.is_match(tar_path_to_path(&e.path)))
+            .any(|e| include.is_match(&*tar_path_to_utf8_str(&e.path)))
         {
             let added_includes = turn_file_paths_into_patterns(
                 entries_to_include
                     .iter()
-                    .filter(|e| include.is_match(tar_path_to_path(&e.path)))
+                    .filter(|e| include.is_match(&*tar_path_to_utf8_str(&e.path)))
                     .map(|e| tar_path_to_utf8_str(&e.path).to_string())
                     .collect(),
                 &pattern,
             );
             removed_include_patterns.push(pattern);
-            added_include_patterns.extend(added_includes.clone().into_iter());
-            all_include_patterns.extend(added_includes.into_iter());
+            added_include_patterns.extend(added_includes.clone());
+            all_include_patterns.extend(added_includes);
         } else {
             all_include_patterns.push(pattern);
         }
@@ -252,7 +248,7 @@ fn find_include_patterns_that_incorporate_exclude_patterns(
     (all_include_patterns, added_include_patterns, removed_include_patterns)
 }
 
-fn make_glob(pattern: &str) -> globset::Glob {
+pub(crate) fn make_glob(pattern: &str) -> globset::Glob {
     globset::GlobBuilder::new(pattern)
         .literal_separator(false)
         .case_insensitive(false)
@@ -262,7 +258,7 @@ fn make_glob(pattern: &str) -> globset::Glob {
 }
 
 fn simplify_includes<'a>(
-    include_patterns_and_matchers: impl Iterator<Item = &'a (&'a str, globset::GlobMatcher)>,
+    include_patterns_and_matchers: impl Iterator<Item = &'a (String, globset::GlobMatcher)>,
     mut entries: Vec<TarHeader>,
 ) -> Patterns {
     let mut out_patterns = Vec::new();
@@ -272,7 +268,7 @@ fn simplify_includes<'a>(
         matched.extend(
             entries
                 .iter()
-                .filter(|e| glob.is_match(tar_path_to_utf8_str(&e.path)))
+                .filter(|e| glob.is_match(&*tar_path_to_utf8_str(&e.path)))
                 .map(|e| tar_path_to_utf8_str(&e.path).to_string()),
         );
         match matched.len() {
@@ -284,13 +280,13 @@ fn simplify_includes<'a>(
                 out_patterns.push((*pattern).to_string());
             }
         }
-        entries.retain(|e| !matched.iter().any(|p| p == tar_path_to_utf8_str(&e.path)));
+        entries.retain(|e| !matched.iter().any(|p| *p == tar_path_to_utf8_str(&e.path)));
     }
     remove_implicit_includes(&mut out_patterns, Vec::new());
     out_patterns
 }
 
-fn find_in_entries<'buffer>(
+pub(crate) fn find_in_entries<'buffer>(
     entries_with_buffer: &'buffer [(TarHeader, Vec<u8>)],
     entries: &[TarHeader],
     name: &str,
@@ -315,17 +311,17 @@ fn find_in_entries<'buffer>(
         })
 }
 
-fn matches_in_set_a_but_not_in_set_b(
+fn matches_in_set_a_but_not_in_set_b<'a>(
     mut patterns_to_amend: Patterns,
-    set_a: &[(&str, globset::GlobMatcher)],
+    set_a: impl IntoIterator<Item = &'a (String, globset::GlobMatcher)>,
     set_b: &globset::GlobSet,
     mut entries: Vec<TarHeader>,
 ) -> (Vec<TarHeader>, Patterns, Patterns) {
     let set_a_len = patterns_to_amend.len();
     let all_entries = entries.clone();
     for (pattern_a, glob_a) in set_a {
-        if entries.iter().any(|e| glob_a.is_match(tar_path_to_utf8_str(&e.path))) {
-            entries.retain(|e| !glob_a.is_match(tar_path_to_utf8_str(&e.path)));
+        if entries.iter().any(|e| glob_a.is_match(&*tar_path_to_utf8_str(&e.path))) {
+            entries.retain(|e| !glob_a.is_match(&*tar_path_to_utf8_str(&e.path)));
             if entries.is_empty() {
                 break;
             }
@@ -334,7 +330,7 @@ fn matches_in_set_a_but_not_in_set_b(
                 continue;
             }
 
-            if entries.iter().any(|e| set_b.is_match(tar_path_to_utf8_str(&e.path))) {
+            if entries.iter().any(|e| set_b.is_match(&*tar_path_to_utf8_str(&e.path))) {
                 patterns_to_amend.push((*pattern_a).to_string());
             }
         }
@@ -355,72 +351,37 @@ fn matches_in_set_a_but_not_in_set_b(
     )
 }
 
-/// Takes something like "src/deep/lib.rs" and "../data/foo.bin" and turns it into "src/data/foo.bin", replicating
-/// the way include_str/bytes interprets include paths. Thus it makes these paths relative to the crate, instead of
-/// relative to the source file they are included in.
-fn to_crate_relative_path(source_file_path: impl AsRef<Path>, relative_path: impl AsRef<Path>) -> String {
-    use std::path::Component::*;
-    let relative_path = relative_path.as_ref();
-    let source_path = source_file_path
-        .as_ref()
-        .parent()
-        .expect("directory containing the file");
-    let leading_parent_path_components = relative_path
-        .components()
-        .take_while(|c| matches!(c, ParentDir | CurDir))
-        .filter(|c| matches!(c, ParentDir))
-        .count();
-    let components_to_take_from_relative_path = relative_path
-        .components()
-        .filter(|c| !matches!(c, CurDir))
-        .skip(leading_parent_path_components);
-    let components_to_take = source_path
-        .components()
-        .count()
-        .saturating_sub(leading_parent_path_components);
-    source_path
-        .components()
-        .take(components_to_take)
-        .chain(components_to_take_from_relative_path)
-        .fold(PathBuf::new(), |mut p, c| {
-            p.push(c);
-            p
-        })
-        .to_str()
-        .expect("utf8 only")
-        .to_string()
-}
-
-fn simplify_standard_excludes_and_match_against_standard_includes(
+fn simplify_standard_excludes_and_match_against_standard_includes<'a>(
     potential_waste: Vec<TarHeader>,
     existing_exclude: Patterns,
     compile_time_include: Option<Patterns>,
+    rules: &Rules,
+    exclude_matchers: impl IntoIterator<Item = &'a (String, globset::GlobMatcher)>,
 ) -> (Vec<TarHeader>, Patterns, Patterns) {
     let compile_time_include = compile_time_include.unwrap_or_default();
     let include_iter =
-        globset_from_globs_and_patterns(&STANDARD_INCLUDE_GLOBS, compile_time_include.iter().map(|s| s.as_str()));
-    matches_in_set_a_but_not_in_set_b(
-        existing_exclude,
-        &STANDARD_EXCLUDE_MATCHERS,
-        &include_iter,
-        potential_waste,
-    )
+        globset_from_globs_and_patterns(&rules.include_globs, compile_time_include.iter().map(|s| s.as_str()));
+    matches_in_set_a_but_not_in_set_b(existing_exclude, exclude_matchers, &include_iter, potential_waste)
 }
 
-fn included_paths_of(file: Option<(TarHeader, Option<&[u8]>)>) -> Vec<String> {
-    file.and_then(|(header, maybe_data)| maybe_data.map(|d| (header, d)))
-        .map(|(header, data)| {
-            COMPILE_TIME_INCLUDE
-                .captures_iter(data)
-                .map(|cap| {
-                    to_crate_relative_path(
-                        tar_path_to_utf8_str(&header.path),
-                        std::str::from_utf8(&cap["include"]).expect("utf8 path"),
-                    )
-                })
-                .collect()
-        })
-        .unwrap_or_default()
+/// All files referenced by the sources of all targets in `config`, including the target sources themselves.
+pub(crate) fn referenced_files(
+    config: &CargoConfig,
+    entries_with_buffer: &[(TarHeader, Vec<u8>)],
+    entries: &[TarHeader],
+) -> BTreeSet<String> {
+    let present: BTreeSet<_> = entries_with_buffer
+        .iter()
+        .map(|(e, _)| e)
+        .chain(entries.iter())
+        .map(|e| tar_path_to_utf8_str(&e.path))
+        .collect();
+    let roots = config.target_paths();
+    references::resolve(
+        roots.iter().map(String::as_str),
+        |path| present.contains(path),
+        |path| find_in_entries(entries_with_buffer, &[], path).and_then(|(_, data)| data),
+    )
 }
 
 /// HELP WANTED find the largest common ancestors (e.g. curl/* for curl/foo/* and curl/bar/*) and return these
@@ -436,14 +397,13 @@ fn find_paths_mentioned_in_build_script(build: Option<(TarHeader, Option<&[u8]>)
             let mut v: Vec<_> = BUILD_SCRIPT_PATHS
                 .captures_iter(data)
                 .map(|cap| {
-                    std::str::from_utf8(
+                    String::from_utf8_lossy(
                         cap.name("path")
                             .or_else(|| cap.name("path_like"))
                             .expect("one of the two matches")
                             .as_bytes(),
                     )
-                    .expect("valid utf8")
-                    .to_string()
+                    .into_owned()
                 })
                 .filter(|p| {
                     !(p.contains('{')
@@ -479,9 +439,32 @@ fn find_paths_mentioned_in_build_script(build: Option<(TarHeader, Option<&[u8]>)
         .unwrap_or_default()
 }
 
-fn potential_negated_includes(entries: Vec<TarHeader>, patters_to_avoid: globset::GlobSet) -> Option<PotentialWaste> {
+/// The exclude patterns of `rules` which don't match any of the `entries` that are allowed, as excluding those
+/// would make allowed files waste after all.
+fn exclude_matchers_sparing_allowed<'a>(
+    rules: &'a Rules,
+    entries: &[TarHeader],
+) -> Vec<&'a (String, globset::GlobMatcher)> {
+    let allowed: Vec<_> = entries
+        .iter()
+        .map(|e| tar_path_to_utf8_str(&e.path))
+        .filter(|p| rules.allow_globset.is_match(&**p))
+        .collect();
+    rules
+        .exclude_matchers
+        .iter()
+        .filter(|(_, glob)| !allowed.iter().any(|p| glob.is_match(&**p)))
+        .collect()
+}
+
+fn potential_negated_includes(
+    entries: Vec<TarHeader>,
+    patters_to_avoid: globset::GlobSet,
+    rules: &Rules,
+) -> Option<PotentialWaste> {
+    let exclude_matchers = exclude_matchers_sparing_allowed(rules, &entries);
     let (entries_we_would_remove, patterns, _) =
-        matches_in_set_a_but_not_in_set_b(Vec::new(), &STANDARD_EXCLUDE_MATCHERS, &patters_to_avoid, entries);
+        matches_in_set_a_but_not_in_set_b(Vec::new(), exclude_matchers, &patters_to_avoid, entries);
     let negated_patterns: Vec<_> = patterns.into_iter().map(|s| format!("!{}", s)).collect();
     if negated_patterns.is_empty() {
         None
@@ -493,9 +476,11 @@ fn potential_negated_includes(entries: Vec<TarHeader>, patters_to_avoid: globset
     }
 }
 
-fn add_to_includes_if_non_default(file_path: &str, include: &mut Patterns) {
+fn add_to_includes_if_non_default(file_path: &str, include: &mut Patterns, rules: &Rules) {
     let recursive_pattern = Path::new(file_path).parent().expect("file path as input").join("**");
-    if !standard_include_patterns().contains(&recursive_pattern.join("*").to_str().expect("utf8 only")) {
+    let recursive_pattern_str = recursive_pattern.join("*");
+    let recursive_pattern_str = recursive_pattern_str.to_str().expect("utf8 only");
+    if !rules.include_patterns.iter().any(|p| p == recursive_pattern_str) {
         include.push(recursive_pattern.join("*.rs").to_str().expect("utf 8 only").to_string())
     }
 }
@@ -516,7 +501,7 @@ impl Report {
     pub(crate) fn convert_to_wasted_files(entries: Vec<TarHeader>) -> Vec<WastedFile> {
         entries
             .into_iter()
-            .map(|e| (tar_path_to_utf8_str(&e.path).to_owned(), e.size))
+            .map(|e| (tar_path_to_utf8_str(&e.path).into_owned(), e.size))
             .collect()
     }
 
@@ -524,6 +509,7 @@ impl Report {
         entries: Vec<TarHeader>,
         build_script_name: Option<String>,
         compile_time_include: Option<Patterns>,
+        rules: &Rules,
     ) -> (Option<Fix>, Vec<TarHeader>) {
         let mut compile_time_include = compile_time_include.unwrap_or_default();
         let has_build_script = match build_script_name {
@@ -534,15 +520,16 @@ impl Report {
             None => false,
         };
         let include_globs =
-            globset_from_globs_and_patterns(&STANDARD_INCLUDE_GLOBS, compile_time_include.iter().map(|s| s.as_str()));
+            globset_from_globs_and_patterns(&rules.include_globs, compile_time_include.iter().map(|s| s.as_str()));
         let (included_entries, excluded_entries) = split_to_matched_and_unmatched(entries, &include_globs);
 
         let compile_time_include_matchers: Vec<_> = compile_time_include
             .iter()
-            .map(|s| (s.as_str(), make_glob(s).compile_matcher()))
+            .map(|s| (s.clone(), make_glob(s).compile_matcher()))
             .collect();
         let include_patterns = simplify_includes(
-            STANDARD_INCLUDE_MATCHERS
+            rules
+                .include_matchers
                 .iter()
                 .chain(compile_time_include_matchers.iter()),
             included_entries.clone(),
@@ -550,6 +537,7 @@ impl Report {
         let potential = potential_negated_includes(
             included_entries,
             globset_from_patterns(non_greedy_patterns(&compile_time_include)),
+            rules,
         );
 
         if excluded_entries.is_empty() && potential.is_none() {
@@ -578,7 +566,7 @@ impl Report {
             split_to_matched_and_unmatched(directories, &exclude_globs);
         let (entries_that_should_be_excluded_by_directory, remaining_entries) =
             split_by_matching_directories(remaining_entries, &directories_that_should_be_excluded);
-        entries_that_should_be_excluded.extend(entries_that_should_be_excluded_by_directory.into_iter());
+        entries_that_should_be_excluded.extend(entries_that_should_be_excluded_by_directory);
 
         let fix = if entries_that_should_be_excluded.is_empty() {
             Some(Fix::RemoveExclude)
@@ -607,6 +595,7 @@ impl Report {
         entries: Vec<TarHeader>,
         mut include: Patterns,
         has_build_script: bool,
+        rules: &Rules,
     ) -> (Option<Fix>, Vec<TarHeader>) {
         let mut include_removed = Vec::new();
         remove_implicit_includes(&mut include, &mut include_removed);
@@ -616,7 +605,7 @@ impl Report {
                 None
             } else {
                 let potential =
-                    potential_negated_includes(entries, globset_from_patterns(non_greedy_patterns(&include)));
+                    potential_negated_includes(entries, globset_from_patterns(non_greedy_patterns(&include)), rules);
                 Some(Fix::ImprovedInclude {
                     include,
                     include_removed,
@@ -632,13 +621,22 @@ impl Report {
         entries: Vec<TarHeader>,
         exclude: Patterns,
         compile_time_include: Option<Patterns>,
+        needed_files: &BTreeSet<String>,
         has_build_script: bool,
+        rules: &Rules,
     ) -> (Option<Fix>, Vec<TarHeader>) {
-        let (potential_waste, _remaining) = split_to_matched_and_unmatched(entries, &STANDARD_EXCLUDES_GLOBSET);
+        let exclude_matchers = exclude_matchers_sparing_allowed(rules, &entries);
+        let (_allowed, entries) = split_to_matched_and_unmatched(entries, &rules.allow_globset);
+        let (mut potential_waste, _remaining) = split_to_matched_and_unmatched(entries, &rules.exclude_globset);
+        // Files referenced by the sources are never waste, and leaving them in would make excludes look as if they
+        // spared included files, which turns the paths mentioned in build scripts into waste.
+        potential_waste.retain(|e| !needed_files.contains(&*tar_path_to_utf8_str(&e.path)));
         let (wasted_files, exclude, exclude_added) = simplify_standard_excludes_and_match_against_standard_includes(
             potential_waste,
             exclude,
             compile_time_include,
+            rules,
+            exclude_matchers,
         );
         if wasted_files.is_empty() {
             (None, Vec::new())
@@ -662,30 +660,27 @@ impl Report {
         config: CargoConfig,
         entries_with_buffer: &[(TarHeader, Vec<u8>)],
         entries: &[TarHeader],
+        rules: &Rules,
     ) -> (Option<Patterns>, Option<Patterns>, Option<Patterns>, Option<String>) {
         let mut maybe_build_script_path = config.build_script_path().map(|s| s.to_owned());
         let compile_time_includes = {
             let mut includes_parsed_from_files = Vec::new();
-            includes_parsed_from_files.extend(included_paths_of(find_in_entries(
-                entries_with_buffer,
-                entries,
-                config.lib_path(),
-            )));
-            add_to_includes_if_non_default(config.lib_path(), &mut includes_parsed_from_files);
+            add_to_includes_if_non_default(config.lib_path(), &mut includes_parsed_from_files, rules);
             for path in config.bin_paths() {
-                includes_parsed_from_files.extend(included_paths_of(find_in_entries(
-                    entries_with_buffer,
-                    entries,
-                    path,
-                )));
-                add_to_includes_if_non_default(path, &mut includes_parsed_from_files);
+                add_to_includes_if_non_default(path, &mut includes_parsed_from_files, rules);
             }
 
             let build_script_name = config.actual_or_expected_build_script_path();
+            includes_parsed_from_files.extend(
+                referenced_files(&config, entries_with_buffer, entries)
+                    .into_iter()
+                    .filter(|p| p != build_script_name && globset::Glob::new(p).is_ok()),
+            );
             let maybe_data = find_in_entries(entries_with_buffer, entries, build_script_name);
             maybe_build_script_path =
                 maybe_build_script_path.or_else(|| maybe_data.as_ref().map(|_| build_script_name.to_owned()));
             includes_parsed_from_files.extend(find_paths_mentioned_in_build_script(maybe_data));
+            includes_parsed_from_files.extend(rules.allow.iter().cloned());
 
             if includes_parsed_from_files.is_empty() {
                 None
@@ -0,0 +1,70 @@
+use super::super::{category::into_map_by_category, category::Category, AggregateFileInfo};
+
+#[test]
+fn files_are_classified_by_their_path() {
+    use Category::*;
+    for (path, category) in &[
+        ("tests/it.rs", Tests),
+        ("src/parser_test.rs", Tests),
+        ("tests/images/logo.png", Tests),
+        ("crates/core/testdata/input.json", Tests),
+        ("benches/parse.rs", Benchmarks),
+        ("src/parse_bench.rs", Benchmarks),
+        ("examples/demo.rs", Examples),
+        ("docs/guide.md", Documentation),
+        ("HACKING.md", Documentation),
+        ("assets/logo.png", Media),
+        ("logo.SVG", Media),
+        ("fonts/regular.woff2", Media),
+        (".github/workflows/ci.yml", Config),
+        (".travis.yml", Config),
+        ("ci/script.sh", Config),
+        ("rustfmt.toml", Config),
+        (".gitignore", Vcs),
+        ("src/lib.rs.orig", Vcs),
+        ("src/lib.rs~", Vcs),
+        ("docs/.DS_Store", Vcs),
+        ("target/debug/build/out.o", Generated),
+        ("tests/fixtures/data.tar.gz", Generated),
+        ("libfoo.so", Generated),
+        ("scripts/release.py", Other),
+        ("Makefile", Other),
+    ] {
+        assert_eq!(Category::of(path), *category, "{}", path);
+    }
+}
+
+#[test]
+fn categories_round_trip_through_their_key() {
+    for category in Category::ALL {
+        assert_eq!(Category::from_key(category.as_str()), Some(*category));
+        assert_eq!(
+            serde_json::to_value(category).unwrap(),
+            serde_json::Value::String(category.as_str().into())
+        );
+    }
+    assert_eq!(Category::from_key("unknown"), None);
+}
+
+#[test]
+fn wasted_files_are_aggregated_by_category() {
+    let info = |total_files, total_bytes| AggregateFileInfo {
+        total_files,
+        total_bytes,
+    };
+    let initial = std::iter::once(("tests".to_string(), info(1, 5))).collect();
+    let files = vec![
+        ("tests/a.rs".to_string(), 10),
+        ("tests/b.rs".to_string(), 20),
+        ("README.md".to_string(), 3),
+    ];
+    assert_eq!(
+        into_map_by_category(initial, &files),
+        vec![
+            ("documentation".to_string(), info(1, 3)),
+            ("tests".to_string(), info(3, 35)),
+        ]
+        .into_iter()
+        .collect()
+    );
+}
@@ -0,0 +1,83 @@
+use super::super::{CompressedSize, Report};
+
+#[test]
+fn compressed_waste_is_proportional_to_uncompressed_waste() {
+    assert_eq!(
+        CompressedSize::estimate(300, 1000, 250),
+        CompressedSize {
+            total_bytes: 300,
+            wasted_bytes: 75
+        }
+    );
+    assert_eq!(
+        CompressedSize::estimate(300, 0, 0).wasted_bytes,
+        0,
+        "empty crates waste nothing"
+    );
+    assert_eq!(
+        CompressedSize::estimate(u64::MAX, u64::MAX, u64::MAX / 2).wasted_bytes,
+        u64::MAX / 2,
+        "large sizes don't overflow"
+    );
+}
+
+#[test]
+fn only_versions_with_known_crate_file_size_have_a_compressed_size() {
+    let report = Report::Version {
+        crate_name: "a".into(),
+        crate_version: "1.0.0".into(),
+        total_size_in_bytes: 100,
+        total_files: 3,
+        wasted_files: vec![("tests/a".into(), 10), ("docs/b".into(), 30)],
+        suggested_fix: None,
+        cargo_toml_diff: None,
+        fix_verification: None,
+        compressed_size_in_bytes: None,
+    };
+    assert_eq!(report.compressed_size(), None);
+    assert_eq!(
+        report.with_compressed_size(Some(50)).compressed_size(),
+        Some(CompressedSize {
+            total_bytes: 50,
+            wasted_bytes: 20
+        })
+    );
+}
+
+#[test]
+fn version_reports_stored_before_fixes_were_diffed_and_verified_can_be_read() {
+    #[derive(serde_derive::Serialize)]
+    enum OldReport {
+        Version {
+            crate_name: String,
+            crate_version: String,
+            total_size_in_bytes: u64,
+            total_files: u64,
+            wasted_files: Vec<(String, u64)>,
+            suggested_fix: Option<()>,
+        },
+    }
+    let stored = rmp_serde::to_vec(&OldReport::Version {
+        crate_name: "a".into(),
+        crate_version: "1.0.0".into(),
+        total_size_in_bytes: 100,
+        total_files: 3,
+        wasted_files: vec![("tests/a".into(), 10)],
+        suggested_fix: None,
+    })
+    .unwrap();
+    assert_eq!(
+        rmp_serde::from_slice::<Report>(&stored).unwrap(),
+        Report::Version {
+            crate_name: "a".into(),
+            crate_version: "1.0.0".into(),
+            total_size_in_bytes: 100,
+            total_files: 3,
+            wasted_files: vec![("tests/a".into(), 10)],
+            suggested_fix: None,
+            cargo_toml_diff: None,
+            fix_verification: None,
+            compressed_size_in_bytes: None,
+        }
+    );
+}
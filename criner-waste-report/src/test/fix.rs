@@ -0,0 +1,106 @@
+use super::super::{Fix, TarHeader};
+
+const MANIFEST: &str = r#"# the manifest of a
+[package]
+name = "a"
+version = "1.0.0"
+exclude = ["tests/*"] # keep tests out
+
+[dependencies]
+b = "1"
+"#;
+
+fn entry(path: &str, content: &str) -> (TarHeader, Vec<u8>) {
+    (
+        TarHeader {
+            path: path.as_bytes().to_vec(),
+            size: content.len() as u64,
+            entry_type: 0,
+            hash: None,
+        },
+        content.as_bytes().to_vec(),
+    )
+}
+
+fn new_include() -> Fix {
+    Fix::NewInclude {
+        include: vec!["src/**/*".into(), "Cargo.toml".into()],
+        has_build_script: false,
+    }
+}
+
+#[test]
+fn fixes_render_as_package_section() {
+    assert_eq!(
+        Fix::EnrichedExclude {
+            exclude: vec!["tests/*".into(), "benches/*".into()],
+            exclude_added: vec!["benches/*".into()],
+            has_build_script: false,
+        }
+        .to_toml(),
+        "[package]\nexclude = [\n    \"tests/*\",\n    \"benches/*\",\n]\n"
+    );
+    assert_eq!(
+        Fix::RemoveExclude.to_toml(),
+        "[package]\n# remove the 'exclude' directive, if present\n"
+    );
+}
+
+#[test]
+fn applying_a_fix_keeps_comments_and_formatting() {
+    assert_eq!(
+        new_include().apply(MANIFEST).unwrap(),
+        r#"# the manifest of a
+[package]
+name = "a"
+version = "1.0.0"
+include = [
+    "src/**/*",
+    "Cargo.toml",
+]
+
+[dependencies]
+b = "1"
+"#
+    );
+    assert_eq!(
+        new_include().apply("not toml ["),
+        None,
+        "unparseable manifests can't be fixed"
+    );
+    assert_eq!(new_include().apply("[lib]\n"), None, "there must be a package section");
+}
+
+#[test]
+fn the_diff_is_against_the_original_manifest_if_present() {
+    let normalized = "[package]\nname = \"a\"\n";
+    let diff = new_include()
+        .to_manifest_diff(&[
+            entry("a-1.0.0/Cargo.toml", normalized),
+            entry("a-1.0.0/Cargo.toml.orig", MANIFEST),
+        ])
+        .unwrap();
+    assert_eq!(
+        diff,
+        r#"--- a/Cargo.toml
++++ b/Cargo.toml
+@@ -2,7 +2,10 @@
+ [package]
+ name = "a"
+ version = "1.0.0"
+-exclude = ["tests/*"] # keep tests out
++include = [
++    "src/**/*",
++    "Cargo.toml",
++]
+ 
+ [dependencies]
+ b = "1"
+"#
+    );
+    assert!(new_include()
+        .to_manifest_diff(&[entry("a-1.0.0/Cargo.toml", normalized)])
+        .unwrap()
+        .contains("+include = [\n"));
+    assert_eq!(new_include().to_manifest_diff(&[]), None);
+}
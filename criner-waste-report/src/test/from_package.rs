@@ -1,4 +1,4 @@
-use super::super::{Fix, Report, TarPackage};
+use super::super::{verify::Verification, Fix, Report, TarPackage, WastePolicy};
 use std::path::Path;
 
 fn tar_package(file_name: &str) -> TarPackage {
@@ -14,16 +14,60 @@ fn tar_package(file_name: &str) -> TarPackage {
     .unwrap()
 }
 
+/// Like `Report::from_package()`, but assures a manifest diff and a verification are present exactly if there is a fix,
+/// and removes them.
+fn from_package(crate_name: &str, crate_version: &str, package: TarPackage) -> Report {
+    match Report::from_package(crate_name, crate_version, package, &WastePolicy::default()) {
+        Report::Version {
+            crate_name,
+            crate_version,
+            total_size_in_bytes,
+            total_files,
+            wasted_files,
+            suggested_fix,
+            cargo_toml_diff,
+            fix_verification,
+            compressed_size_in_bytes,
+        } => {
+            assert_eq!(
+                suggested_fix.is_some(),
+                cargo_toml_diff.is_some_and(|diff| diff.starts_with("--- a/Cargo.toml\n+++ b/Cargo.toml\n")),
+                "there is a manifest diff if there is a fix"
+            );
+            assert_eq!(
+                suggested_fix.is_some(),
+                fix_verification.is_some(),
+                "fixes are always verified"
+            );
+            Report::Version {
+                crate_name,
+                crate_version,
+                total_size_in_bytes,
+                total_files,
+                wasted_files,
+                suggested_fix,
+                cargo_toml_diff: None,
+                fix_verification: None,
+                compressed_size_in_bytes,
+            }
+        }
+        _ => unreachable!("always a version report"),
+    }
+}
+
 #[test]
 fn ripgrep_perfectly_lean_which_is_unexpected_actually() {
     assert_eq!(
-        Report::from_package("a", "1", tar_package("ripgrep-12.0.0-extract_crate-1.0.0")),
+        from_package("a", "1", tar_package("ripgrep-12.0.0-extract_crate-1.0.0")),
         Report::Version {
             crate_name: "a".into(),
             crate_version: "1".to_string(),
             total_size_in_bytes: 1369472,
             total_files: 89,
             wasted_files: vec![],
+            cargo_toml_diff: None,
+            fix_verification: None,
+            compressed_size_in_bytes: None,
             suggested_fix: None
         }
     );
@@ -32,7 +76,7 @@ fn ripgrep_perfectly_lean_which_is_unexpected_actually() {
 #[test]
 fn avr_libc_missing_build_script_even_though_it_is_there() {
     assert_eq!(
-        Report::from_package("a", "1", tar_package("avr_libc-0.1.3extract_crate-1.0.0")),
+        from_package("a", "1", tar_package("avr_libc-0.1.3extract_crate-1.0.0")),
         Report::Version {
             crate_name: "a".into(),
             crate_version: "1".to_string(),
@@ -481,8 +525,11 @@ fn avr_libc_missing_build_script_even_though_it_is_there() {
             .iter()
             .map(|(p, s)| (p.to_string(), *s))
             .collect(),
+            cargo_toml_diff: None,
+            fix_verification: None,
+            compressed_size_in_bytes: None,
             suggested_fix: Some(Fix::NewInclude {
-                include: vec![
+                include: [
                     "src/**/*",
                     "LICENSE",
                     "README.md",
@@ -490,7 +537,7 @@ fn avr_libc_missing_build_script_even_though_it_is_there() {
                     "build.rs",
                     "!**/*.jpg",
                     "!**/doc/**/*",
-                    "!**/tests/**/*",
+                    "!**/tests/**/*"
                 ]
                 .iter()
                 .map(|s| s.to_string())
@@ -504,7 +551,7 @@ fn avr_libc_missing_build_script_even_though_it_is_there() {
 #[test]
 fn fermium_build_script() {
     assert_eq!(
-        Report::from_package("a", "1", tar_package("fermium-20.12.0-alpha2-extract_crate-1.0.0")),
+        from_package("a", "1", tar_package("fermium-20.12.0-alpha2-extract_crate-1.0.0")),
         Report::Version {
             crate_name: "a".into(),
             crate_version: "1".to_string(),
@@ -819,6 +866,9 @@ fn fermium_build_script() {
             .iter()
             .map(|(p, s)| (p.to_string(), *s))
             .collect(),
+            cargo_toml_diff: None,
+            fix_verification: None,
+            compressed_size_in_bytes: None,
             suggested_fix: Some(Fix::NewInclude {
                 include: vec![
                     "src/**/*",
@@ -848,7 +898,7 @@ fn fermium_build_script() {
 #[test]
 fn grpcio_sys_lean_with_potential() {
     assert_eq!(
-        Report::from_package("a", "1", tar_package("grpcio-sys-0.5.0_extract_crate-1.0.0")),
+        from_package("a", "1", tar_package("grpcio-sys-0.5.0_extract_crate-1.0.0")),
         Report::Version {
             crate_name: "a".into(),
             crate_version: "1".to_string(),
@@ -1829,6 +1879,9 @@ fn grpcio_sys_lean_with_potential() {
             .iter()
             .map(|(p, s)| (p.to_string(), *s))
             .collect(),
+            cargo_toml_diff: None,
+            fix_verification: None,
+            compressed_size_in_bytes: None,
             suggested_fix: Some(Fix::EnrichedExclude {
                 exclude: [
                     "grpc/doc/*",
@@ -1906,7 +1959,7 @@ fn grpcio_sys_lean_with_potential() {
 #[test]
 fn openblas_provider_even_more_info_from_build_script_and_something_to_optimize_for() {
     assert_eq!(
-        Report::from_package("a", "1", tar_package("openblas_provider-0.4.0-extract_crate-1.0.0")),
+        from_package("a", "1", tar_package("openblas_provider-0.4.0-extract_crate-1.0.0")),
         Report::Version {
             crate_name: "a".into(),
             crate_version: "1".to_string(),
@@ -2071,8 +2124,11 @@ fn openblas_provider_even_more_info_from_build_script_and_something_to_optimize_
             .iter()
             .map(|(p, s)| (p.to_string(), *s))
             .collect(),
+            cargo_toml_diff: None,
+            fix_verification: None,
+            compressed_size_in_bytes: None,
             suggested_fix: Some(Fix::NewInclude {
-                include: vec![
+                include: [
                     "src/lib.rs",
                     "LICENSE.md",
                     "README.md",
@@ -2098,7 +2154,7 @@ fn openblas_provider_even_more_info_from_build_script_and_something_to_optimize_
 #[test]
 fn curl_sys_get_more_info_from_build_script() {
     assert_eq!(
-        Report::from_package("a", "1", tar_package("curl_sys-0.4.27-extract_crate-1.0.0")),
+        from_package("a", "1", tar_package("curl_sys-0.4.27-extract_crate-1.0.0")),
         Report::Version {
             crate_name: "a".into(),
             crate_version: "1".to_string(),
@@ -4608,6 +4664,9 @@ fn curl_sys_get_more_info_from_build_script() {
             .iter()
             .map(|(p, s)| (p.to_string(), *s))
             .collect(),
+            cargo_toml_diff: None,
+            fix_verification: None,
+            compressed_size_in_bytes: None,
             suggested_fix: Some(Fix::NewInclude {
                 include: vec![
                     "LICENSE".into(),
@@ -4625,7 +4684,7 @@ fn curl_sys_get_more_info_from_build_script() {
 #[test]
 fn threed_ice_sys_build_script_includes() {
     assert_eq!(
-        Report::from_package("a", "1", tar_package("threed-ice-sys-0.3.0-extract_crate-1.0.0")),
+        from_package("a", "1", tar_package("threed-ice-sys-0.3.0-extract_crate-1.0.0")),
         Report::Version {
             crate_name: "a".into(),
             crate_version: "1".to_string(),
@@ -4702,6 +4761,9 @@ fn threed_ice_sys_build_script_includes() {
             .iter()
             .map(|(p, s)| (p.to_string(), *s))
             .collect(),
+            cargo_toml_diff: None,
+            fix_verification: None,
+            compressed_size_in_bytes: None,
             suggested_fix: Some(Fix::NewInclude {
                 include: vec![
                     "src/**/*".into(),
@@ -4722,7 +4784,7 @@ fn threed_ice_sys_build_script_includes() {
 #[test]
 fn lw_webdriver_with_big_binaries() {
     assert_eq!(
-        Report::from_package("a", "1", tar_package("lw_webdriver-0.4.1-extract_crate-1.0.0")),
+        from_package("a", "1", tar_package("lw_webdriver-0.4.1-extract_crate-1.0.0")),
         Report::Version {
             crate_name: "a".into(),
             crate_version: "1".to_string(),
@@ -4737,6 +4799,9 @@ fn lw_webdriver_with_big_binaries() {
             .iter()
             .map(|(p, s)| (p.to_string(), *s))
             .collect(),
+            cargo_toml_diff: None,
+            fix_verification: None,
+            compressed_size_in_bytes: None,
             suggested_fix: Some(Fix::NewInclude {
                 include: vec!["src/**/*".into(), "README.md".into()],
                 has_build_script: false
@@ -4748,7 +4813,7 @@ fn lw_webdriver_with_big_binaries() {
 #[test]
 fn falcon_raptor_typescript_with_false_positive_potential_waste() {
     assert_eq!(
-        Report::from_package(
+        from_package(
             "a",
             "1",
             tar_package("falcon_raptor-0.4.9-extract_crate-1.0.0")
@@ -4759,6 +4824,9 @@ fn falcon_raptor_typescript_with_false_positive_potential_waste() {
             total_size_in_bytes: 339867,
             total_files: 62,
             wasted_files: vec![(".gitignore".into(), 24)],
+            cargo_toml_diff: None,
+            fix_verification: None,
+            compressed_size_in_bytes: None,
             suggested_fix: Some(Fix::NewInclude {
                 include: vec!["README.md".into(), "lib/**/*.rs".into()],
                 has_build_script: false
@@ -4771,13 +4839,16 @@ fn falcon_raptor_typescript_with_false_positive_potential_waste() {
 #[test]
 fn deno_typescript() {
     assert_eq!(
-        Report::from_package("a", "1", tar_package("deno_typescript-0.36.0-extract_crate-1.0.0")),
+        from_package("a", "1", tar_package("deno_typescript-0.36.0-extract_crate-1.0.0")),
         Report::Version {
             crate_name: "a".into(),
             crate_version: "1".to_string(),
             total_size_in_bytes: 50090820,
             total_files: 189,
             wasted_files: vec![],
+            cargo_toml_diff: None,
+            fix_verification: None,
+            compressed_size_in_bytes: None,
             suggested_fix: None
         }
     );
@@ -4786,13 +4857,16 @@ fn deno_typescript() {
 #[test]
 fn cookie_factory() {
     assert_eq!(
-        Report::from_package("a", "1", tar_package("cookie_factory-0.3.1-extract_crate-0.3.1")),
+        from_package("a", "1", tar_package("cookie_factory-0.3.1-extract_crate-0.3.1")),
         Report::Version {
             crate_name: "a".into(),
             crate_version: "1".to_string(),
             total_size_in_bytes: 73285,
             total_files: 13,
             wasted_files: vec![],
+            cargo_toml_diff: None,
+            fix_verification: None,
+            compressed_size_in_bytes: None,
             suggested_fix: Some(Fix::ImprovedInclude {
                 include: [
                     "LICENSE",
@@ -4816,13 +4890,16 @@ fn cookie_factory() {
 #[test]
 fn gnir() {
     assert_eq!(
-        Report::from_package("a", "1", tar_package("gnir-0.14.0-alpha3-extract_crate-1.0.0")),
+        from_package("a", "1", tar_package("gnir-0.14.0-alpha3-extract_crate-1.0.0")),
         Report::Version {
             crate_name: "a".into(),
             crate_version: "1".to_string(),
             total_size_in_bytes: 15205881,
             total_files: 381,
             wasted_files: [("pregenerated/tmp/aes-586-win32n.asm", 25423u64), ("pregenerated/tmp/aes-x86_64-nasm.asm", 25697), ("pregenerated/tmp/aesni-gcm-x86_64-nasm.asm", 22260), ("pregenerated/tmp/aesni-x86-win32n.asm", 13074), ("pregenerated/tmp/aesni-x86_64-nasm.asm", 24852), ("pregenerated/tmp/chacha-x86-win32n.asm", 18916), ("pregenerated/tmp/chacha-x86_64-nasm.asm", 40140), ("pregenerated/tmp/ecp_nistz256-x86-win32n.asm", 31016), ("pregenerated/tmp/ghash-x86-win32n.asm", 19662), ("pregenerated/tmp/ghash-x86_64-nasm.asm", 39583), ("pregenerated/tmp/p256-x86_64-asm-nasm.asm", 82748), ("pregenerated/tmp/p256_beeu-x86_64-asm-nasm.asm", 4358), ("pregenerated/tmp/poly1305-x86-win32n.asm", 25445), ("pregenerated/tmp/poly1305-x86_64-nasm.asm", 39449), ("pregenerated/tmp/sha256-586-win32n.asm", 91985), ("pregenerated/tmp/sha256-x86_64-nasm.asm", 90321), ("pregenerated/tmp/sha512-586-win32n.asm", 38150), ("pregenerated/tmp/sha512-x86_64-nasm.asm", 70857), ("pregenerated/tmp/vpaes-x86-win32n.asm", 8142), ("pregenerated/tmp/vpaes-x86_64-nasm.asm", 10157), ("pregenerated/tmp/x86-mont-win32n.asm", 4312), ("pregenerated/tmp/x86_64-mont-nasm.asm", 23026), ("pregenerated/tmp/x86_64-mont5-nasm.asm", 64107)].iter().map(|(p, s)| (p.to_string(), *s)).collect(),
+            cargo_toml_diff: None,
+            fix_verification: None,
+            compressed_size_in_bytes: None,
             suggested_fix: Some(Fix::RemoveExcludeAndUseInclude {
                 include_added: ["pregenerated/aes-586-elf.S", "pregenerated/aes-586-macosx.S", "pregenerated/aes-586-win32n.obj", "pregenerated/aes-armv4-ios32.S", "pregenerated/aes-armv4-linux32.S", "pregenerated/aes-x86_64-elf.S", "pregenerated/aes-x86_64-macosx.S", "pregenerated/aes-x86_64-nasm.obj", "pregenerated/aesni-gcm-x86_64-elf.S", "pregenerated/aesni-gcm-x86_64-macosx.S", "pregenerated/aesni-gcm-x86_64-nasm.obj", "pregenerated/aesni-x86-elf.S", "pregenerated/aesni-x86-macosx.S", "pregenerated/aesni-x86-win32n.obj", "pregenerated/aesni-x86_64-elf.S", "pregenerated/aesni-x86_64-macosx.S", "pregenerated/aesni-x86_64-nasm.obj", "pregenerated/aesv8-armx-ios32.S", "pregenerated/aesv8-armx-ios64.S", "pregenerated/aesv8-armx-linux32.S", "pregenerated/aesv8-armx-linux64.S", "pregenerated/armv4-mont-ios32.S", "pregenerated/armv4-mont-linux32.S", "pregenerated/armv8-mont-ios64.S", "pregenerated/armv8-mont-linux64.S", "pregenerated/bsaes-armv7-ios32.S", "pregenerated/bsaes-armv7-linux32.S", "pregenerated/chacha-armv4-ios32.S", "pregenerated/chacha-armv4-linux32.S", "pregenerated/chacha-armv8-ios64.S", "pregenerated/chacha-armv8-linux64.S", "pregenerated/chacha-x86-elf.S", "pregenerated/chacha-x86-macosx.S", "pregenerated/chacha-x86-win32n.obj", "pregenerated/chacha-x86_64-elf.S", "pregenerated/chacha-x86_64-macosx.S", "pregenerated/chacha-x86_64-nasm.obj", "pregenerated/ecp_nistz256-armv4-ios32.S", "pregenerated/ecp_nistz256-armv4-linux32.S", "pregenerated/ecp_nistz256-armv8-ios64.S", "pregenerated/ecp_nistz256-armv8-linux64.S", "pregenerated/ecp_nistz256-x86-elf.S", "pregenerated/ecp_nistz256-x86-macosx.S", "pregenerated/ecp_nistz256-x86-win32n.obj", "pregenerated/ghash-armv4-ios32.S", "pregenerated/ghash-armv4-linux32.S", "pregenerated/ghash-x86-elf.S", "pregenerated/ghash-x86-macosx.S", "pregenerated/ghash-x86-win32n.obj", "pregenerated/ghash-x86_64-elf.S", "pregenerated/ghash-x86_64-macosx.S", "pregenerated/ghash-x86_64-nasm.obj", "pregenerated/ghashv8-armx-ios32.S", "pregenerated/ghashv8-armx-ios64.S", "pregenerated/ghashv8-armx-linux32.S", "pregenerated/ghashv8-armx-linux64.S", "pregenerated/p256-x86_64-asm-elf.S", "pregenerated/p256-x86_64-asm-macosx.S", "pregenerated/p256-x86_64-asm-nasm.obj", "pregenerated/p256_beeu-x86_64-asm-elf.S", "pregenerated/p256_beeu-x86_64-asm-macosx.S", "pregenerated/p256_beeu-x86_64-asm-nasm.obj", "pregenerated/poly1305-armv4-ios32.S", "pregenerated/poly1305-armv4-linux32.S", "pregenerated/poly1305-armv8-ios64.S", "pregenerated/poly1305-armv8-linux64.S", "pregenerated/poly1305-x86-elf.S", "pregenerated/poly1305-x86-macosx.S", "pregenerated/poly1305-x86-win32n.obj", "pregenerated/poly1305-x86_64-elf.S", "pregenerated/poly1305-x86_64-macosx.S", "pregenerated/poly1305-x86_64-nasm.obj", "pregenerated/sha256-586-elf.S", "pregenerated/sha256-586-macosx.S", "pregenerated/sha256-586-win32n.obj", "pregenerated/sha256-armv4-ios32.S", "pregenerated/sha256-armv4-linux32.S", "pregenerated/sha256-armv8-ios64.S", "pregenerated/sha256-armv8-linux64.S", "pregenerated/sha256-x86_64-elf.S", "pregenerated/sha256-x86_64-macosx.S", "pregenerated/sha256-x86_64-nasm.obj", "pregenerated/sha512-586-elf.S", "pregenerated/sha512-586-macosx.S", "pregenerated/sha512-586-win32n.obj", "pregenerated/sha512-armv4-ios32.S", "pregenerated/sha512-armv4-linux32.S", "pregenerated/sha512-armv8-ios64.S", "pregenerated/sha512-armv8-linux64.S", "pregenerated/sha512-x86_64-elf.S", "pregenerated/sha512-x86_64-macosx.S", "pregenerated/sha512-x86_64-nasm.obj", "pregenerated/vpaes-x86-elf.S", "pregenerated/vpaes-x86-macosx.S", "pregenerated/vpaes-x86-win32n.obj", "pregenerated/vpaes-x86_64-elf.S", "pregenerated/vpaes-x86_64-macosx.S", "pregenerated/vpaes-x86_64-nasm.obj", "pregenerated/x86-mont-elf.S", "pregenerated/x86-mont-macosx.S", "pregenerated/x86-mont-win32n.obj", "pregenerated/x86_64-mont-elf.S", "pregenerated/x86_64-mont-macosx.S", "pregenerated/x86_64-mont-nasm.obj", "pregenerated/x86_64-mont5-elf.S", "pregenerated/x86_64-mont5-macosx.S", "pregenerated/x86_64-mont5-nasm.obj"].iter().map(|s| s.to_string()).collect(),
                 include: ["LICENSE", "pregenerated/aes-586-elf.S", "pregenerated/aes-586-macosx.S", "pregenerated/aes-586-win32n.obj", "pregenerated/aes-armv4-ios32.S", "pregenerated/aes-armv4-linux32.S", "pregenerated/aes-x86_64-elf.S", "pregenerated/aes-x86_64-macosx.S", "pregenerated/aes-x86_64-nasm.obj", "pregenerated/aesni-gcm-x86_64-elf.S", "pregenerated/aesni-gcm-x86_64-macosx.S", "pregenerated/aesni-gcm-x86_64-nasm.obj", "pregenerated/aesni-x86-elf.S", "pregenerated/aesni-x86-macosx.S", "pregenerated/aesni-x86-win32n.obj", "pregenerated/aesni-x86_64-elf.S", "pregenerated/aesni-x86_64-macosx.S", "pregenerated/aesni-x86_64-nasm.obj", "pregenerated/aesv8-armx-ios32.S", "pregenerated/aesv8-armx-ios64.S", "pregenerated/aesv8-armx-linux32.S", "pregenerated/aesv8-armx-linux64.S", "pregenerated/armv4-mont-ios32.S", "pregenerated/armv4-mont-linux32.S", "pregenerated/armv8-mont-ios64.S", "pregenerated/armv8-mont-linux64.S", "pregenerated/bsaes-armv7-ios32.S", "pregenerated/bsaes-armv7-linux32.S", "pregenerated/chacha-armv4-ios32.S", "pregenerated/chacha-armv4-linux32.S", "pregenerated/chacha-armv8-ios64.S", "pregenerated/chacha-armv8-linux64.S", "pregenerated/chacha-x86-elf.S", "pregenerated/chacha-x86-macosx.S", "pregenerated/chacha-x86-win32n.obj", "pregenerated/chacha-x86_64-elf.S", "pregenerated/chacha-x86_64-macosx.S", "pregenerated/chacha-x86_64-nasm.obj", "pregenerated/ecp_nistz256-armv4-ios32.S", "pregenerated/ecp_nistz256-armv4-linux32.S", "pregenerated/ecp_nistz256-armv8-ios64.S", "pregenerated/ecp_nistz256-armv8-linux64.S", "pregenerated/ecp_nistz256-x86-elf.S", "pregenerated/ecp_nistz256-x86-macosx.S", "pregenerated/ecp_nistz256-x86-win32n.obj", "pregenerated/ghash-armv4-ios32.S", "pregenerated/ghash-armv4-linux32.S", "pregenerated/ghash-x86-elf.S", "pregenerated/ghash-x86-macosx.S", "pregenerated/ghash-x86-win32n.obj", "pregenerated/ghash-x86_64-elf.S", "pregenerated/ghash-x86_64-macosx.S", "pregenerated/ghash-x86_64-nasm.obj", "pregenerated/ghashv8-armx-ios32.S", "pregenerated/ghashv8-armx-ios64.S", "pregenerated/ghashv8-armx-linux32.S", "pregenerated/ghashv8-armx-linux64.S", "pregenerated/p256-x86_64-asm-elf.S", "pregenerated/p256-x86_64-asm-macosx.S", "pregenerated/p256-x86_64-asm-nasm.obj", "pregenerated/p256_beeu-x86_64-asm-elf.S", "pregenerated/p256_beeu-x86_64-asm-macosx.S", "pregenerated/p256_beeu-x86_64-asm-nasm.obj", "pregenerated/poly1305-armv4-ios32.S", "pregenerated/poly1305-armv4-linux32.S", "pregenerated/poly1305-armv8-ios64.S", "pregenerated/poly1305-armv8-linux64.S", "pregenerated/poly1305-x86-elf.S", "pregenerated/poly1305-x86-macosx.S", "pregenerated/poly1305-x86-win32n.obj", "pregenerated/poly1305-x86_64-elf.S", "pregenerated/poly1305-x86_64-macosx.S", "pregenerated/poly1305-x86_64-nasm.obj", "pregenerated/sha256-586-elf.S", "pregenerated/sha256-586-macosx.S", "pregenerated/sha256-586-win32n.obj", "pregenerated/sha256-armv4-ios32.S", "pregenerated/sha256-armv4-linux32.S", "pregenerated/sha256-armv8-ios64.S", "pregenerated/sha256-armv8-linux64.S", "pregenerated/sha256-x86_64-elf.S", "pregenerated/sha256-x86_64-macosx.S", "pregenerated/sha256-x86_64-nasm.obj", "pregenerated/sha512-586-elf.S", "pregenerated/sha512-586-macosx.S", "pregenerated/sha512-586-win32n.obj", "pregenerated/sha512-armv4-ios32.S", "pregenerated/sha512-armv4-linux32.S", "pregenerated/sha512-armv8-ios64.S", "pregenerated/sha512-armv8-linux64.S", "pregenerated/sha512-x86_64-elf.S", "pregenerated/sha512-x86_64-macosx.S", "pregenerated/sha512-x86_64-nasm.obj", "pregenerated/vpaes-x86-elf.S", "pregenerated/vpaes-x86-macosx.S", "pregenerated/vpaes-x86-win32n.obj", "pregenerated/vpaes-x86_64-elf.S", "pregenerated/vpaes-x86_64-macosx.S", "pregenerated/vpaes-x86_64-nasm.obj", "pregenerated/x86-mont-elf.S", "pregenerated/x86-mont-macosx.S", "pregenerated/x86-mont-win32n.obj", "pregenerated/x86_64-mont-elf.S", "pregenerated/x86_64-mont-macosx.S", "pregenerated/x86_64-mont-nasm.obj", "pregenerated/x86_64-mont5-elf.S", "pregenerated/x86_64-mont5-macosx.S", "pregenerated/x86_64-mont5-nasm.obj", "build.rs", "crypto/block.c", "crypto/block.h", "crypto/chacha/asm/chacha-armv4.pl", "crypto/chacha/asm/chacha-armv8.pl", "crypto/chacha/asm/chacha-x86.pl", "crypto/chacha/asm/chacha-x86_64.pl", "crypto/cipher_extra/asm/aes128gcmsiv-x86_64.pl", "crypto/cipher_extra/test/aes_128_gcm_siv_tests.txt", "crypto/cipher_extra/test/aes_256_gcm_siv_tests.txt", "crypto/constant_time_test.c", "crypto/cpu-aarch64-linux.c", "crypto/cpu-arm-linux.c", "crypto/cpu-arm.c", "crypto/cpu-intel.c", "crypto/crypto.c", "crypto/curve25519/asm/x25519-asm-arm.S", "crypto/fipsmodule/aes/aes.c", "crypto/fipsmodule/aes/asm/aes-586.pl", "crypto/fipsmodule/aes/asm/aes-armv4.pl", "crypto/fipsmodule/aes/asm/aes-x86_64.pl", "crypto/fipsmodule/aes/asm/aesni-x86.pl", "crypto/fipsmodule/aes/asm/aesni-x86_64.pl", "crypto/fipsmodule/aes/asm/aesv8-armx.pl", "crypto/fipsmodule/aes/asm/bsaes-armv7.pl", "crypto/fipsmodule/aes/asm/bsaes-x86_64.pl", "crypto/fipsmodule/aes/asm/vpaes-x86.pl", "crypto/fipsmodule/aes/asm/vpaes-x86_64.pl", "crypto/fipsmodule/aes/internal.h", "crypto/fipsmodule/bn/asm/armv4-mont.pl", "crypto/fipsmodule/bn/asm/armv8-mont.pl", "crypto/fipsmodule/bn/asm/x86-mont.pl", "crypto/fipsmodule/bn/asm/x86_64-mont.pl", "crypto/fipsmodule/bn/asm/x86_64-mont5.pl", "crypto/fipsmodule/bn/generic.c", "crypto/fipsmodule/bn/internal.h", "crypto/fipsmodule/bn/montgomery.c", "crypto/fipsmodule/bn/montgomery_inv.c", "crypto/fipsmodule/cipher/e_aes.c", "crypto/fipsmodule/ec/asm/ecp_nistz256-armv4.pl", "crypto/fipsmodule/ec/asm/ecp_nistz256-armv8.pl", "crypto/fipsmodule/ec/asm/ecp_nistz256-x86.pl", "crypto/fipsmodule/ec/asm/p256-x86_64-asm.pl", "crypto/fipsmodule/ec/ecp_nistz.c", "crypto/fipsmodule/ec/ecp_nistz.h", "crypto/fipsmodule/ec/ecp_nistz256.c", "crypto/fipsmodule/ec/ecp_nistz256.h", "crypto/fipsmodule/ec/ecp_nistz256_table.inl", "crypto/fipsmodule/ec/ecp_nistz384.h", "crypto/fipsmodule/ec/ecp_nistz384.inl", "crypto/fipsmodule/ec/gfp_p256.c", "crypto/fipsmodule/ec/gfp_p384.c", "crypto/fipsmodule/ecdsa/ecdsa_verify_tests.txt", "crypto/fipsmodule/modes/asm/aesni-gcm-x86_64.pl", "crypto/fipsmodule/modes/asm/ghash-armv4.pl", "crypto/fipsmodule/modes/asm/ghash-x86.pl", "crypto/fipsmodule/modes/asm/ghash-x86_64.pl", "crypto/fipsmodule/modes/asm/ghashv8-armx.pl", "crypto/fipsmodule/modes/gcm.c", "crypto/fipsmodule/modes/internal.h", "crypto/fipsmodule/sha/asm/sha256-586.pl", "crypto/fipsmodule/sha/asm/sha256-armv4.pl", "crypto/fipsmodule/sha/asm/sha512-586.pl", "crypto/fipsmodule/sha/asm/sha512-armv4.pl", "crypto/fipsmodule/sha/asm/sha512-armv8.pl", "crypto/fipsmodule/sha/asm/sha512-x86_64.pl", "crypto/internal.h", "crypto/limbs/limbs.c", "crypto/limbs/limbs.h", "crypto/limbs/limbs.inl", "crypto/mem.c", "crypto/perlasm/arm-xlate.pl", "crypto/perlasm/x86asm.pl", "crypto/perlasm/x86gas.pl", "crypto/perlasm/x86nasm.pl", "crypto/perlasm/x86_64-xlate.pl", "crypto/poly1305/asm/poly1305-armv4.pl", "crypto/poly1305/asm/poly1305-armv8.pl", "crypto/poly1305/asm/poly1305-x86.pl", "crypto/poly1305/asm/poly1305-x86_64.pl", "examples/checkdigest.rs", "include/GFp/aes.h", "include/GFp/arm_arch.h", "include/GFp/base.h", "include/GFp/cpu.h", "include/GFp/mem.h", "include/GFp/type_check.h", "src/aead.rs", "src/aead/aes.rs", "src/aead/aes_gcm.rs", "src/aead/aes_tests.txt", "src/aead/block.rs", "src/aead/chacha.rs", "src/aead/chacha_tests.txt", "src/aead/chacha20_poly1305.rs", "src/aead/chacha20_poly1305_openssh.rs", "src/aead/gcm.rs", "src/aead/nonce.rs", "src/aead/poly1305.rs", "src/aead/poly1305_test.txt", "src/aead/shift.rs", "src/agreement.rs", "src/arithmetic.rs", "src/arithmetic/montgomery.rs", "src/array.rs", "src/bits.rs", "src/bssl.rs", "src/c.rs", "src/constant_time.rs", "src/cpu.rs", "src/data/alg-rsa-encryption.der", "src/debug.rs", "src/digest.rs", "src/digest/sha1.rs", "src/ec/curve25519/ed25519/digest.rs", "src/ec/curve25519/ed25519.rs", "src/ec/curve25519/ed25519/signing.rs", "src/ec/curve25519/ed25519/verification.rs", "src/ec/curve25519/ed25519/ed25519_pkcs8_v2_template.der", "src/ec/curve25519.rs", "src/ec/curve25519/ops.rs", "src/ec/curve25519/x25519.rs", "src/ec.rs", "src/ec/keys.rs", "src/ec/suite_b/curve.rs", "src/ec/suite_b/ecdh.rs", "src/ec/suite_b/ecdsa/digest_scalar.rs", "src/ec/suite_b/ecdsa.rs", "src/ec/suite_b/ecdsa/signing.rs", "src/ec/suite_b/ecdsa/verification.rs", "src/ec/suite_b/ecdsa/ecdsa_digest_scalar_tests.txt", "src/ec/suite_b/ecdsa/ecPublicKey_p256_pkcs8_v1_template.der", "src/ec/suite_b/ecdsa/ecPublicKey_p384_pkcs8_v1_template.der", "src/ec/suite_b/ecdsa/ecdsa_sign_asn1_tests.txt", "src/ec/suite_b/ecdsa/ecdsa_sign_fixed_tests.txt", "src/ec/suite_b.rs", "src/ec/suite_b/ops/elem.rs", "src/ec/suite_b/ops.rs", "src/ec/suite_b/ops/p256.rs", "src/ec/suite_b/ops/p256_elem_mul_tests.txt", "src/ec/suite_b/ops/p256_elem_neg_tests.txt", "src/ec/suite_b/ops/p256_elem_sum_tests.txt", "src/ec/suite_b/ops/p256_point_double_tests.txt", "src/ec/suite_b/ops/p256_point_mul_base_tests.txt", "src/ec/suite_b/ops/p256_point_mul_serialized_tests.txt", "src/ec/suite_b/ops/p256_point_mul_tests.txt", "src/ec/suite_b/ops/p256_point_sum_mixed_tests.txt", "src/ec/suite_b/ops/p256_point_sum_tests.txt", "src/ec/suite_b/ops/p256_scalar_mul_tests.txt", "src/ec/suite_b/ops/p256_scalar_square_tests.txt", "src/ec/suite_b/ops/p384.rs", "src/ec/suite_b/ops/p384_elem_div_by_2_tests.txt", "src/ec/suite_b/ops/p384_elem_mul_tests.txt", "src/ec/suite_b/ops/p384_elem_neg_tests.txt", "src/ec/suite_b/ops/p384_elem_sum_tests.txt", "src/ec/suite_b/ops/p384_point_double_tests.txt", "src/ec/suite_b/ops/p384_point_mul_base_tests.txt", "src/ec/suite_b/ops/p384_point_mul_tests.txt", "src/ec/suite_b/ops/p384_point_sum_tests.txt", "src/ec/suite_b/ops/p384_scalar_mul_tests.txt", "src/ec/suite_b/private_key.rs", "src/ec/suite_b/public_key.rs", "src/ec/suite_b/suite_b_public_key_tests.txt", "src/endian.rs", "src/error.rs", "src/hkdf.rs", "src/hmac.rs", "src/hmac_generate_serializable_tests.txt", "src/io.rs", "src/io/der.rs", "src/io/der_writer.rs", "src/io/writer.rs", "src/lib.rs", "src/limb.rs", "src/endian.rs", "src/pbkdf2.rs", "src/pkcs8.rs", "src/polyfill.rs", "src/polyfill/convert.rs", "src/rand.rs", "src/rsa/bigint.rs", "src/rsa/bigint_elem_exp_consttime_tests.txt", "src/rsa/bigint_elem_exp_vartime_tests.txt", "src/rsa/bigint_elem_mul_tests.txt", "src/rsa/bigint_elem_reduced_once_tests.txt", "src/rsa/bigint_elem_reduced_tests.txt", "src/rsa/bigint_elem_squared_tests.txt", "src/rsa/convert_nist_rsa_test_vectors.py", "src/rsa.rs", "src/rsa/padding.rs", "src/rsa/random.rs", "src/rsa/rsa_pss_padding_tests.txt", "src/rsa/signature_rsa_example_private_key.der", "src/rsa/signature_rsa_example_public_key.der", "src/rsa/signing.rs", "src/rsa/verification.rs", "src/signature.rs", "src/test.rs", "src/test_1_syntax_error_tests.txt", "src/test_1_tests.txt", "src/test_3_tests.txt", "tests/aead_aes_128_gcm_tests.txt", "tests/aead_aes_256_gcm_tests.txt", "tests/aead_chacha20_poly1305_tests.txt", "tests/aead_chacha20_poly1305_openssh_tests.txt", "tests/aead_tests.rs", "tests/agreement_tests.rs", "tests/agreement_tests.txt", "tests/digest_tests.rs", "tests/digest_tests.txt", "tests/ecdsa_from_pkcs8_tests.txt", "tests/ecdsa_tests.rs", "tests/ecdsa_sign_asn1_tests.txt", "tests/ecdsa_sign_fixed_tests.txt", "tests/ecdsa_verify_asn1_tests.txt", "tests/ecdsa_verify_fixed_tests.txt", "tests/ed25519_from_pkcs8_tests.txt", "tests/ed25519_from_pkcs8_unchecked_tests.txt", "tests/ed25519_tests.rs", "tests/ed25519_tests.txt", "tests/ed25519_test_private_key.bin", "tests/ed25519_test_public_key.bin", "tests/hkdf_tests.rs", "tests/hkdf_tests.txt", "tests/hmac_tests.rs", "tests/hmac_tests.txt", "tests/pbkdf2_tests.rs", "tests/pbkdf2_tests.txt", "tests/rsa_from_pkcs8_tests.txt", "tests/rsa_pkcs1_sign_tests.txt", "tests/rsa_pkcs1_verify_tests.txt", "tests/rsa_primitive_verify_tests.txt", "tests/rsa_pss_sign_tests.txt", "tests/rsa_pss_verify_tests.txt", "tests/rsa_tests.rs", "tests/signature_tests.rs", "third_party/fiat/curve25519.c", "third_party/fiat/curve25519_tables.h", "third_party/fiat/internal.h", "third_party/fiat/LICENSE", "third_party/fiat/make_curve25519_tables.py", "third_party/NIST/SHAVS/SHA1LongMsg.rsp", "third_party/NIST/SHAVS/SHA1Monte.rsp", "third_party/NIST/SHAVS/SHA1ShortMsg.rsp", "third_party/NIST/SHAVS/SHA224LongMsg.rsp", "third_party/NIST/SHAVS/SHA224Monte.rsp", "third_party/NIST/SHAVS/SHA224ShortMsg.rsp", "third_party/NIST/SHAVS/SHA256LongMsg.rsp", "third_party/NIST/SHAVS/SHA256Monte.rsp", "third_party/NIST/SHAVS/SHA256ShortMsg.rsp", "third_party/NIST/SHAVS/SHA384LongMsg.rsp", "third_party/NIST/SHAVS/SHA384Monte.rsp", "third_party/NIST/SHAVS/SHA384ShortMsg.rsp", "third_party/NIST/SHAVS/SHA512LongMsg.rsp", "third_party/NIST/SHAVS/SHA512Monte.rsp", "third_party/NIST/SHAVS/SHA512ShortMsg.rsp"].iter().map(|s| s.to_string()).collect(),
@@ -4836,13 +4913,16 @@ fn gnir() {
 #[test]
 fn sovrin_client() {
     assert_eq!(
-        Report::from_package("a", "1", tar_package("sovrin-client.0.1.0-179-extract_crate-1.0.0")),
+        from_package("a", "1", tar_package("sovrin-client.0.1.0-179-extract_crate-1.0.0")),
         Report::Version {
             crate_name: "a".into(),
             crate_version: "1".to_string(),
             total_size_in_bytes: 20283032,
             total_files: 479,
             wasted_files: [(".gitignore", 40u64), ("Jenkinsfile", 3735), ("Specs/libsovrin-core/0.0.1/libsovrin-core.podspec.json", 529), ("Specs/libsovrin-core/0.0.2/libsovrin-core.podspec.json", 529), ("Specs/libzmq/4.2.2/libzmq.podspec.json", 1545), ("Specs/libzmq/4.2.3/libzmq.podspec.json", 1555), ("Specs/milagro/3.0.0/milagro.podspec.json", 963), ("build-libsovrin-core-ios.sh", 1368), ("ci/amazon.dockerfile", 1492), ("ci/sovrin-pool.dockerfile", 2678), ("ci/ubuntu.dockerfile", 847), ("ci/update-package-version.sh", 175), ("doc/ios-build.md", 1514), ("doc/libsovrin-agent-2-agent.puml", 2168), ("doc/libsovrin-anoncreds.puml", 3558), ("doc/mac-build.md", 597), ("docker-compose.yml", 323), ("include/sovrin_agent.h", 9229), ("include/sovrin_anoncreds.h", 11254), ("include/sovrin_core.h", 265), ("include/sovrin_ledger.h", 15319), ("include/sovrin_mod.h", 2887), ("include/sovrin_pool.h", 2209), ("include/sovrin_signus.h", 11643), ("include/sovrin_types.h", 218), ("include/sovrin_wallet.h", 8548), ("tests/agent.rs", 13726), ("tests/anoncreds.rs", 229549), ("tests/demo.rs", 41150), ("tests/ledger.rs", 55607), ("tests/pool.rs", 16640), ("tests/signus.rs", 26106), ("tests/utils/agent.rs", 5569), ("tests/utils/anoncreds.rs", 27339), ("tests/utils/callback.rs", 45981), ("tests/utils/ledger.rs", 14993), ("tests/utils/mod.rs", 338), ("tests/utils/pool.rs", 8532), ("tests/utils/signus.rs", 8135), ("tests/utils/types.rs", 5085), ("tests/utils/wallet.rs", 7290), ("tests/wallet.rs", 10892), ("wrappers/ios/Tests/libzmq-ios-test-app/ZeroMQ_Test/.gitignore", 607), ("wrappers/ios/Tests/libzmq-ios-test-app/ZeroMQ_Test/Podfile", 1490), ("wrappers/ios/Tests/libzmq-ios-test-app/ZeroMQ_Test/ZeroMQ_Test.xcodeproj/project.pbxproj", 17063), ("wrappers/ios/Tests/libzmq-ios-test-app/ZeroMQ_Test/ZeroMQ_Test.xcodeproj/project.xcworkspace/contents.xcworkspacedata", 156), ("wrappers/ios/Tests/libzmq-ios-test-app/ZeroMQ_Test/ZeroMQ_Test.xcworkspace/contents.xcworkspacedata", 229), ("wrappers/ios/Tests/libzmq-ios-test-app/ZeroMQ_Test/ZeroMQ_Test/AppDelegate.h", 293), ("wrappers/ios/Tests/libzmq-ios-test-app/ZeroMQ_Test/ZeroMQ_Test/AppDelegate.m", 2106), ("wrappers/ios/Tests/libzmq-ios-test-app/ZeroMQ_Test/ZeroMQ_Test/Assets.xcassets/AppIcon.appiconset/Contents.json", 1077), ("wrappers/ios/Tests/libzmq-ios-test-app/ZeroMQ_Test/ZeroMQ_Test/Base.lproj/LaunchScreen.storyboard", 1740), ("wrappers/ios/Tests/libzmq-ios-test-app/ZeroMQ_Test/ZeroMQ_Test/Base.lproj/Main.storyboard", 1689), ("wrappers/ios/Tests/libzmq-ios-test-app/ZeroMQ_Test/ZeroMQ_Test/Info.plist", 1442), ("wrappers/ios/Tests/libzmq-ios-test-app/ZeroMQ_Test/ZeroMQ_Test/ViewController.h", 231), ("wrappers/ios/Tests/libzmq-ios-test-app/ZeroMQ_Test/ZeroMQ_Test/ViewController.m", 512), ("wrappers/ios/Tests/libzmq-ios-test-app/ZeroMQ_Test/ZeroMQ_Test/ZeroMQTests.h", 243), ("wrappers/ios/Tests/libzmq-ios-test-app/ZeroMQ_Test/ZeroMQ_Test/ZeroMQTests.mm", 307742), ("wrappers/ios/Tests/libzmq-ios-test-app/ZeroMQ_Test/ZeroMQ_Test/main.m", 350), ("wrappers/ios/Tests/libzmq-ios-test-app/ZeroMQ_Test/ZeroMQ_Test/zhelpers.h", 5216), ("wrappers/ios/Tests/milagro-ios-test-app/.gitignore", 607), ("wrappers/ios/Tests/milagro-ios-test-app/Podfile", 789), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app.xcodeproj/project.pbxproj", 36318), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app.xcodeproj/project.xcworkspace/contents.xcworkspacedata", 161), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app.xcworkspace/contents.xcworkspacedata", 234), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/AppDelegate.h", 143), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/AppDelegate.m", 1956), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/Assets.xcassets/AppIcon.appiconset/Contents.json", 1077), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/Base.lproj/LaunchScreen.storyboard", 1740), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/Base.lproj/Main.storyboard", 1689), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/Info.plist", 1442), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/MilagroTest.h", 248), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/MilagroTest.m", 7913), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/ViewController.h", 78), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/ViewController.m", 359), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/main.m", 355), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/aes/CBCMMT128.rsp", 9523), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/aes/CBCMMT256.rsp", 10163), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/aes/CFB8MMT128.rsp", 3055), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/aes/CFB8MMT256.rsp", 3695), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/aes/ECBMMT128.rsp", 8763), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/aes/ECBMMT256.rsp", 9403), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/aes/amcl_CTRMCL128.rsp", 9171), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/aes/amcl_CTRMCL256.rsp", 9810), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/big/test_vector_big.txt", 5510), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecdh/C25519/KAS_ECC_CDH_PrimitiveTest.txt", 4851), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecdh/P-256/KAS_ECC_CDH_PrimitiveTest.txt", 11248), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecdh/P-384/KAS_ECC_CDH_PrimitiveTest.txt", 16048), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecdh/P-521/KAS_ECC_CDH_PrimitiveTest.txt", 21448), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecdsa/P-256/KeyPair.rsp", 2167), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecdsa/P-256/sha256Sign.rsp", 10216), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecdsa/P-256/sha256Verify.rsp", 8536), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecdsa/P-256/sha512Sign.rsp", 10217), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecdsa/P-256/sha512Verify.rsp", 8536), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecdsa/P-384/KeyPair.rsp", 3126), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecdsa/P-384/sha256Sign.rsp", 13096), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecdsa/P-384/sha256Verify.rsp", 10456), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecdsa/P-384/sha384Sign.rsp", 13096), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecdsa/P-384/sha384Verify.rsp", 10456), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecdsa/P-384/sha512Sign.rsp", 13096), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecdsa/P-384/sha512Verify.rsp", 10456), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecdsa/P-521/KeyPair.rsp", 4207), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecdsa/P-521/sha256Sign.rsp", 16336), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecdsa/P-521/sha256Verify.rsp", 12616), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecdsa/P-521/sha512Sign.rsp", 16336), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecdsa/P-521/sha512Verify.rsp", 12616), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecp/test_vector_ANSSI_WEIERSTRASS.txt", 18696), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecp/test_vector_BLS383_WEIERSTRASS.txt", 27103), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecp/test_vector_BLS455_WEIERSTRASS.txt", 31787), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecp/test_vector_BN254_CX_WEIERSTRASS.txt", 18682), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecp/test_vector_BN254_T2_WEIERSTRASS.txt", 18700), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecp/test_vector_BN254_T_WEIERSTRASS.txt", 18694), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecp/test_vector_BN254_WEIERSTRASS.txt", 18706), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecp/test_vector_BN454_WEIERSTRASS.txt", 31793), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecp/test_vector_BN646_WEIERSTRASS.txt", 44357), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecp/test_vector_BRAINPOOL_WEIERSTRASS.txt", 18708), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecp/test_vector_C25519_EDWARDS.txt", 18691), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecp/test_vector_C25519_MONTGOMERY.txt", 5001), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecp/test_vector_C41417_EDWARDS.txt", 29173), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecp/test_vector_GOLDILOCKS_EDWARDS.txt", 31282), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecp/test_vector_HIFIVE_EDWARDS.txt", 23942), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecp/test_vector_MF254_EDWARDS.txt", 18694), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecp/test_vector_MF254_MONTGOMERY.txt", 4997), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecp/test_vector_MF254_WEIERSTRASS.txt", 18696), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecp/test_vector_MF256_EDWARDS.txt", 18704), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecp/test_vector_MF256_MONTGOMERY.txt", 5000), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecp/test_vector_MF256_WEIERSTRASS.txt", 18701), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecp/test_vector_MS255_EDWARDS.txt", 18706), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecp/test_vector_MS255_MONTGOMERY.txt", 5002), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecp/test_vector_MS255_WEIERSTRASS.txt", 18702), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecp/test_vector_MS256_EDWARDS.txt", 18701), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecp/test_vector_MS256_MONTGOMERY.txt", 4997), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecp/test_vector_MS256_WEIERSTRASS.txt", 18700), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecp/test_vector_NIST256_WEIERSTRASS.txt", 18704), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecp/test_vector_NIST384_WEIERSTRASS.txt", 27086), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecp/test_vector_NIST521_WEIERSTRASS.txt", 36254), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecp2/test_vector_BLS383_WEIERSTRASS.txt", 56679), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecp2/test_vector_BLS455_WEIERSTRASS.txt", 66793), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecp2/test_vector_BN254_CX_WEIERSTRASS.txt", 38670), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecp2/test_vector_BN254_T2_WEIERSTRASS.txt", 38669), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecp2/test_vector_BN254_T_WEIERSTRASS.txt", 38690), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecp2/test_vector_BN254_WEIERSTRASS.txt", 38431), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecp2/test_vector_BN454_WEIERSTRASS.txt", 66748), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/ecp2/test_vector_BN646_WEIERSTRASS.txt", 93741), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/fp/test_vector_ANSSI.txt", 7076), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/fp/test_vector_BLS383.txt", 10065), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/fp/test_vector_BLS455.txt", 11767), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/fp/test_vector_BN254.txt", 7269), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/fp/test_vector_BN254_CX.txt", 7094), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/fp/test_vector_BN254_T.txt", 6899), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/fp/test_vector_BN254_T2.txt", 6954), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/fp/test_vector_BN454.txt", 11201), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/fp/test_vector_BN646.txt", 16866), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/fp/test_vector_BRAINPOOL.txt", 7379), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/fp/test_vector_C25519.txt", 6965), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/fp/test_vector_C41417.txt", 11149), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/fp/test_vector_GOLDILOCKS.txt", 11983), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/fp/test_vector_HIFIVE.txt", 8645), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/fp/test_vector_MF254.txt", 6950), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/fp/test_vector_MF256.txt", 7161), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/fp/test_vector_MS255.txt", 6886), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/fp/test_vector_MS256.txt", 6994), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/fp/test_vector_NIST256.txt", 7100), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/fp/test_vector_NIST384.txt", 9807), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/fp/test_vector_NIST521.txt", 12986), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/fp2/test_vector_BLS383.txt", 32074), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/fp2/test_vector_BLS455.txt", 37672), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/fp2/test_vector_BN254.txt", 22121), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/fp2/test_vector_BN254_CX.txt", 22096), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/fp2/test_vector_BN254_T.txt", 22095), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/fp2/test_vector_BN254_T2.txt", 22112), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/fp2/test_vector_BN454.txt", 37658), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/fp2/test_vector_BN646.txt", 52589), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/fp4/test_vector_BLS383.txt", 79724), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/fp4/test_vector_BLS455.txt", 93832), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/fp4/test_vector_BN254.txt", 54617), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/fp4/test_vector_BN254_CX.txt", 54611), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/fp4/test_vector_BN254_T.txt", 54614), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/fp4/test_vector_BN254_T2.txt", 54609), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/fp4/test_vector_BN454.txt", 93798), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/fp4/test_vector_BN646.txt", 131433), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/gcm/gcmDecrypt128.rsp", 2682450), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/gcm/gcmDecrypt256.rsp", 2935620), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/gcm/gcmEncryptExtIV128.rsp", 2864783), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/gcm/gcmEncryptExtIV256.rsp", 3116783), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/mpin/BN254_CX.json", 992434), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/mpin/BN254_CX.txt", 917744), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/mpin/BN254_CXOnePass.json", 958264), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_ANSSI_WEIERSTRASS_32.txt", 968), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_ANSSI_WEIERSTRASS_64.txt", 956), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_BLS383_WEIERSTRASS_32.txt", 4769), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_BLS383_WEIERSTRASS_64.txt", 4712), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_BLS455_WEIERSTRASS_32.txt", 5567), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_BLS455_WEIERSTRASS_64.txt", 5472), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_BN254_CX_WEIERSTRASS_16.txt", 3516), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_BN254_CX_WEIERSTRASS_32.txt", 3392), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_BN254_CX_WEIERSTRASS_64.txt", 3346), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_BN254_T2_WEIERSTRASS_16.txt", 3512), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_BN254_T2_WEIERSTRASS_32.txt", 3399), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_BN254_T2_WEIERSTRASS_64.txt", 3343), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_BN254_T_WEIERSTRASS_16.txt", 3373), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_BN254_T_WEIERSTRASS_32.txt", 3424), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_BN254_T_WEIERSTRASS_64.txt", 3377), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_BN254_WEIERSTRASS_16.txt", 3503), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_BN254_WEIERSTRASS_32.txt", 3393), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_BN254_WEIERSTRASS_64.txt", 3346), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_BN454_WEIERSTRASS_32.txt", 5484), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_BN454_WEIERSTRASS_64.txt", 5477), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_BN646_WEIERSTRASS_32.txt", 7666), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_BN646_WEIERSTRASS_64.txt", 7509), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_BRAINPOOL_WEIERSTRASS_32.txt", 964), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_BRAINPOOL_WEIERSTRASS_64.txt", 956), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_C25519_EDWARDS_16.txt", 1005), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_C25519_EDWARDS_32.txt", 969), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_C25519_EDWARDS_64.txt", 956), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_C25519_MONTGOMERY_32.txt", 839), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_C25519_MONTGOMERY_64.txt", 826), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_C41417_EDWARDS_32.txt", 1310), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_C41417_EDWARDS_64.txt", 1282), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_GOLDILOCKS_EDWARDS_32.txt", 1377), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_GOLDILOCKS_EDWARDS_64.txt", 1349), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_HIFIVE_EDWARDS_32.txt", 1134), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_HIFIVE_EDWARDS_64.txt", 1111), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_MF254_EDWARDS_32.txt", 970), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_MF254_EDWARDS_64.txt", 956), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_MF254_MONTGOMERY_32.txt", 840), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_MF254_MONTGOMERY_64.txt", 826), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_MF254_WEIERSTRASS_32.txt", 970), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_MF254_WEIERSTRASS_64.txt", 956), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_MF256_EDWARDS_32.txt", 967), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_MF256_EDWARDS_64.txt", 956), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_MF256_MONTGOMERY_32.txt", 837), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_MF256_MONTGOMERY_64.txt", 1007), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_MF256_WEIERSTRASS_32.txt", 967), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_MF256_WEIERSTRASS_64.txt", 956), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_MS255_EDWARDS_32.txt", 971), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_MS255_EDWARDS_64.txt", 956), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_MS255_MONTGOMERY_32.txt", 841), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_MS255_MONTGOMERY_64.txt", 826), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_MS255_WEIERSTRASS_32.txt", 971), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_MS255_WEIERSTRASS_64.txt", 956), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_MS256_EDWARDS_32.txt", 969), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_MS256_EDWARDS_64.txt", 956), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_MS256_MONTGOMERY_32.txt", 1020), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_MS256_MONTGOMERY_64.txt", 1007), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_MS256_WEIERSTRASS_32.txt", 969), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_MS256_WEIERSTRASS_64.txt", 956), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_NIST256_WEIERSTRASS_16.txt", 1006), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_NIST256_WEIERSTRASS_32.txt", 969), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_NIST256_WEIERSTRASS_64.txt", 955), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_NIST384_WEIERSTRASS_32.txt", 1233), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_NIST384_WEIERSTRASS_64.txt", 1217), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_NIST521_WEIERSTRASS_32.txt", 1518), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/output/test_vector_NIST521_WEIERSTRASS_64.txt", 1494), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/rsa/2048/pkcs-vect.txt", 10614), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/rsa/3072/pkcs-vect.txt", 18450), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/rsa/4096/pkcs-vect.txt", 22362), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/sha/256/SHA256ShortMsg.rsp", 10030), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/sha/384/SHA384ShortMsg.rsp", 12088), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/sha/512/SHA512ShortMsg.rsp", 36275), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/x509/2048_P256/pkits-vect.txt", 296267), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/x509/2048_P256/x509-vect.txt", 22330), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/x509/3072_P384/x509-vect.txt", 27205), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/x509/4096/x509-vect.txt", 12040), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/testVectors/x509/P521/x509-vect.txt", 5635), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/tests/test_aes_decrypt.c", 6597), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/tests/test_aes_encrypt.c", 6577), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/tests/test_big_arithmetics.c", 10901), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/tests/test_big_consistency.c", 6418), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/tests/test_ecc.c", 6132), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/tests/test_ecdh.c", 8189), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/tests/test_ecdsa_keypair.c", 4459), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/tests/test_ecdsa_sign.c", 8252), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/tests/test_ecdsa_verify.c", 6672), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/tests/test_ecp2_arithmetics.c", 13226), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/tests/test_ecp_arithmetics.c", 14398), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/tests/test_fp2_arithmetics.c", 14205), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/tests/test_fp4_arithmetics.c", 16334), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/tests/test_fp_arithmetics.c", 14807), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/tests/test_gcm_decrypt.c", 7700), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/tests/test_gcm_encrypt.c", 6928), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/tests/test_hash.c", 4785), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/tests/test_mpin.c", 7398), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/tests/test_mpin_bad_pin.c", 7919), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/tests/test_mpin_bad_token.c", 8210), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/tests/test_mpin_expired_tp.c", 8192), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/tests/test_mpin_good.c", 7889), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/tests/test_mpin_random.c", 9633), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/tests/test_mpin_sign.c", 13197), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/tests/test_mpin_tp.c", 8532), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/tests/test_mpin_vectors.c", 17027), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/tests/test_mpinfull.c", 10703), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/tests/test_mpinfull_onepass.c", 8951), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/tests/test_mpinfull_random.c", 11622), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/tests/test_octet_consistency.c", 3724), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/tests/test_output_functions.c", 15843), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/tests/test_rsa.c", 2961), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/tests/test_rsa_sign.c", 7831), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/tests/test_utils.c", 5104), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/tests/test_version.c", 1095), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/tests/test_wcc.c", 5893), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/tests/test_wcc_gcm.c", 3163), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/tests/test_wcc_random.c", 14494), ("wrappers/ios/Tests/milagro-ios-test-app/milagro-test-app/tests/test_x509.c", 29293), ("wrappers/ios/libsovrin-pod/.gitignore", 608), ("wrappers/ios/libsovrin-pod/Podfile", 1917), ("wrappers/ios/libsovrin-pod/Podfile.lock", 586), ("wrappers/ios/libsovrin-pod/libsovrin-demo.xcodeproj/project.pbxproj", 35155), ("wrappers/ios/libsovrin-pod/libsovrin-demo/AppDelegate.h", 296), ("wrappers/ios/libsovrin-pod/libsovrin-demo/AppDelegate.mm", 2090), ("wrappers/ios/libsovrin-pod/libsovrin-demo/Assets.xcassets/AppIcon.appiconset/Contents.json", 1495), ("wrappers/ios/libsovrin-pod/libsovrin-demo/Base.lproj/LaunchScreen.storyboard", 1740), ("wrappers/ios/libsovrin-pod/libsovrin-demo/Base.lproj/Main.storyboard", 1689), ("wrappers/ios/libsovrin-pod/libsovrin-demo/Info.plist", 1442), ("wrappers/ios/libsovrin-pod/libsovrin-demo/ViewController.h", 234), ("wrappers/ios/libsovrin-pod/libsovrin-demo/ViewController.m", 515), ("wrappers/ios/libsovrin-pod/libsovrin-demo/main.m", 353), ("wrappers/ios/libsovrin-pod/libsovrin-demoTests/Anoncreds.m", 49009), ("wrappers/ios/libsovrin-pod/libsovrin-demoTests/AnoncredsDemo.mm", 13923), ("wrappers/ios/libsovrin-pod/libsovrin-demoTests/AnoncredsUtils.h", 3940), ("wrappers/ios/libsovrin-pod/libsovrin-demoTests/AnoncredsUtils.m", 16538), ("wrappers/ios/libsovrin-pod/libsovrin-demoTests/Environment.h", 228), ("wrappers/ios/libsovrin-pod/libsovrin-demoTests/Environment.m", 212), ("wrappers/ios/libsovrin-pod/libsovrin-demoTests/Info.plist", 680), ("wrappers/ios/libsovrin-pod/libsovrin-demoTests/Ledger.mm", 90279), ("wrappers/ios/libsovrin-pod/libsovrin-demoTests/Ledger/LedgerAttribRequest.mm", 10207), ("wrappers/ios/libsovrin-pod/libsovrin-demoTests/Ledger/LedgerNodeRequest.mm", 11129), ("wrappers/ios/libsovrin-pod/libsovrin-demoTests/Ledger/LedgerNymRequest.mm", 23339), ("wrappers/ios/libsovrin-pod/libsovrin-demoTests/Ledger/LedgerSchemaRequest.mm", 9727), ("wrappers/ios/libsovrin-pod/libsovrin-demoTests/LedgerDemo.mm", 15548), ("wrappers/ios/libsovrin-pod/libsovrin-demoTests/LedgerUtils.h", 3611), ("wrappers/ios/libsovrin-pod/libsovrin-demoTests/LedgerUtils.mm", 13262), ("wrappers/ios/libsovrin-pod/libsovrin-demoTests/NSDictionary+JSON.h", 245), ("wrappers/ios/libsovrin-pod/libsovrin-demoTests/NSDictionary+JSON.m", 1610), ("wrappers/ios/libsovrin-pod/libsovrin-demoTests/Pool.mm", 2186), ("wrappers/ios/libsovrin-pod/libsovrin-demoTests/PoolUtils.h", 963), ("wrappers/ios/libsovrin-pod/libsovrin-demoTests/PoolUtils.m", 8153), ("wrappers/ios/libsovrin-pod/libsovrin-demoTests/Signus/SignusHighCases.m", 35826), ("wrappers/ios/libsovrin-pod/libsovrin-demoTests/Signus/SignusMediumCases.m", 23718), ("wrappers/ios/libsovrin-pod/libsovrin-demoTests/SignusDemo.mm", 7436), ("wrappers/ios/libsovrin-pod/libsovrin-demoTests/SignusUtils.h", 1610), ("wrappers/ios/libsovrin-pod/libsovrin-demoTests/SignusUtils.mm", 6146), ("wrappers/ios/libsovrin-pod/libsovrin-demoTests/TestUtils.h", 426), ("wrappers/ios/libsovrin-pod/libsovrin-demoTests/TestUtils.m", 960), ("wrappers/ios/libsovrin-pod/libsovrin-demoTests/Wallet/WalletHighCases.m", 7371), ("wrappers/ios/libsovrin-pod/libsovrin-demoTests/Wallet/WalletMediumCases.m", 7672), ("wrappers/ios/libsovrin-pod/libsovrin-demoTests/WalletUtils.h", 1067), ("wrappers/ios/libsovrin-pod/libsovrin-demoTests/WalletUtils.m", 6899), ("wrappers/ios/libsovrin-pod/libsovrin.xcodeproj/project.pbxproj", 24209), ("wrappers/ios/libsovrin-pod/libsovrin/Info.plist", 753), ("wrappers/ios/libsovrin-pod/libsovrin/NSError+SovrinError.h", 204), ("wrappers/ios/libsovrin-pod/libsovrin/NSError+SovrinError.m", 333), ("wrappers/ios/libsovrin-pod/libsovrin/SovrinAgent.h", 1617), ("wrappers/ios/libsovrin-pod/libsovrin/SovrinAgent.mm", 4993), ("wrappers/ios/libsovrin-pod/libsovrin/SovrinAnoncreds.h", 5008), ("wrappers/ios/libsovrin-pod/libsovrin/SovrinAnoncreds.mm", 14121), ("wrappers/ios/libsovrin-pod/libsovrin/SovrinCallbacks.h", 4177), ("wrappers/ios/libsovrin-pod/libsovrin/SovrinCallbacks.mm", 22778), ("wrappers/ios/libsovrin-pod/libsovrin/SovrinErrors.h", 2978), ("wrappers/ios/libsovrin-pod/libsovrin/SovrinLedger.h", 4144), ("wrappers/ios/libsovrin-pod/libsovrin/SovrinLedger.mm", 11919), ("wrappers/ios/libsovrin-pod/libsovrin/SovrinPool.h", 927), ("wrappers/ios/libsovrin-pod/libsovrin/SovrinPool.mm", 3709), ("wrappers/ios/libsovrin-pod/libsovrin/SovrinSignus.h", 2304), ("wrappers/ios/libsovrin-pod/libsovrin/SovrinSignus.mm", 6504), ("wrappers/ios/libsovrin-pod/libsovrin/SovrinTypes.h", 68), ("wrappers/ios/libsovrin-pod/libsovrin/SovrinWallet.h", 2286), ("wrappers/ios/libsovrin-pod/libsovrin/SovrinWallet.mm", 4658), ("wrappers/ios/libsovrin-pod/libsovrin/libsovrin.h", 763), ("wrappers/java/README.md", 7162), ("wrappers/java/lib/.gitignore", 14), ("wrappers/java/pom.xml", 2602), ("wrappers/java/src/main/java/org/hyperledger/indy/sdk/ErrorCode.java", 3447), ("wrappers/java/src/main/java/org/hyperledger/indy/sdk/LibSovrin.java", 6356), ("wrappers/java/src/main/java/org/hyperledger/indy/sdk/SovrinConstants.java", 609), ("wrappers/java/src/main/java/org/hyperledger/indy/sdk/SovrinException.java", 477), ("wrappers/java/src/main/java/org/hyperledger/indy/sdk/SovrinJava.java", 3443), ("wrappers/java/src/main/java/org/hyperledger/indy/sdk/anoncreds/Anoncreds.java", 6373), ("wrappers/java/src/main/java/org/hyperledger/indy/sdk/anoncreds/AnoncredsJSONParameters.java", 165), ("wrappers/java/src/main/java/org/hyperledger/indy/sdk/anoncreds/AnoncredsResults.java", 3606), ("wrappers/java/src/main/java/org/hyperledger/indy/sdk/ledger/Ledger.java", 10800), ("wrappers/java/src/main/java/org/hyperledger/indy/sdk/ledger/LedgerJSONParameters.java", 156), ("wrappers/java/src/main/java/org/hyperledger/indy/sdk/ledger/LedgerResults.java", 3335), ("wrappers/java/src/main/java/org/hyperledger/indy/sdk/pool/Pool.java", 4980), ("wrappers/java/src/main/java/org/hyperledger/indy/sdk/pool/PoolJSONParameters.java", 877), ("wrappers/java/src/main/java/org/hyperledger/indy/sdk/pool/PoolResults.java", 831), ("wrappers/java/src/main/java/org/hyperledger/indy/sdk/signus/Signus.java", 6514), ("wrappers/java/src/main/java/org/hyperledger/indy/sdk/signus/SignusJSONParameters.java", 616), ("wrappers/java/src/main/java/org/hyperledger/indy/sdk/signus/SignusResults.java", 1852), ("wrappers/java/src/main/java/org/hyperledger/indy/sdk/wallet/Wallet.java", 4960), ("wrappers/java/src/main/java/org/hyperledger/indy/sdk/wallet/WalletJSONParameters.java", 162), ("wrappers/java/src/main/java/org/hyperledger/indy/sdk/wallet/WalletResults.java", 811), ("wrappers/java/src/test/java/org/hyperledger/indy/sdk/LedgerTest.java", 1561), ("wrappers/java/src/test/java/org/hyperledger/indy/sdk/PoolTest.java", 1221), ("wrappers/java/src/test/java/org/hyperledger/indy/sdk/SignusTest.java", 2088), ("wrappers/java/src/test/java/org/hyperledger/indy/sdk/WalletTest.java", 1666), ("wrappers/python/sovrin/__init__.py", 205), ("wrappers/python/sovrin/anoncreds.py", 3917), ("wrappers/python/sovrin/error.py", 253), ("wrappers/python/sovrin/ledger.py", 3508), ("wrappers/python/sovrin/pool.py", 904), ("wrappers/python/sovrin/signus.py", 1828), ("wrappers/python/sovrin/wallet.py", 706), ("wrappers/python/tests/test_wallet.py", 216)].iter().map(|(p, s)| (p.to_string(), *s)).collect(),
+            cargo_toml_diff: None,
+            fix_verification: None,
+            compressed_size_in_bytes: None,
             suggested_fix: Some(Fix::NewInclude {
                 include: vec!["src/**/*".into(), "LICENSE".into(), "README.md".into(), "build.rs".into()],
                 has_build_script: true,
@@ -4855,13 +4935,16 @@ fn sovrin_client() {
 #[test]
 fn mozjs() {
     assert_eq!(
-        Report::from_package("a", "1", tar_package("mozjs_sys-0.67.1-extract_crate-1.0.0")),
+        from_package("a", "1", tar_package("mozjs_sys-0.67.1-extract_crate-1.0.0")),
         Report::Version {
             crate_name: "a".into(),
             crate_version: "1".to_string(),
             total_size_in_bytes: 161224996,
             total_files: 13185,
             wasted_files: [("etc/COMMIT", 41), ("etc/patches/README", 86), ("etc/patches/bindgen-bug.patch", 544), ("etc/patches/bionic-glue-android-api-not-version.patch", 867), ("etc/patches/configure-override-android-ndk-paths.patch", 2818), ("etc/patches/configure-with-android-sysroot.patch", 637), ("etc/patches/disable-nspr-shared.patch", 597), ("etc/patches/generate-android-system-wrappers.patch", 445), ("etc/patches/libcpp-visibility-envvar.patch", 771), ("etc/patches/mozglue-static-library.patch", 386), ("etc/patches/no-subst-config.patch", 354), ("etc/patches/no_asm_defines.patch", 629), ("etc/patches/no_bindgen_in_configure.patch", 698), ("etc/patches/nspr-emulation-windiws.patch", 702), ("etc/patches/uwp-pc-triple.patch", 4799), ("etc/patches/uwp-support.patch", 23071), ("etc/update.py", 3777), ("mozjs/build/.gdbinit_python.in", 112), ("mozjs/build/.lldbinit.in", 153), ("mozjs/build/__init__.py", 0), ("mozjs/build/appini_header.py", 2628), ("mozjs/build/application.ini.in", 1409), ("mozjs/build/autoconf/alloc.m4", 1906), ("mozjs/build/autoconf/altoptions.m4", 2763), ("mozjs/build/autoconf/android.m4", 3555), ("mozjs/build/autoconf/arch.m4", 7439), ("mozjs/build/autoconf/clang-plugin.m4", 8599), ("mozjs/build/autoconf/codeset.m4", 958), ("mozjs/build/autoconf/compiler-opts.m4", 7242), ("mozjs/build/autoconf/config.guess", 44169), ("mozjs/build/autoconf/config.status.m4", 6580), ("mozjs/build/autoconf/config.sub", 31461), ("mozjs/build/autoconf/expandlibs.m4", 2418), ("mozjs/build/autoconf/frameptr.m4", 1727), ("mozjs/build/autoconf/hooks.m4", 1528), ("mozjs/build/autoconf/hotfixes.m4", 1073), ("mozjs/build/autoconf/icu.m4", 3371), ("mozjs/build/autoconf/install-sh", 2386), ("mozjs/build/autoconf/ios.m4", 3126), ("mozjs/build/autoconf/mozheader.m4", 1217), ("mozjs/build/autoconf/mozprog.m4", 1045), ("mozjs/build/autoconf/nspr-build.m4", 6999), ("mozjs/build/autoconf/nspr.m4", 3880), ("mozjs/build/autoconf/nss.m4", 3023), ("mozjs/build/autoconf/pkg.m4", 2377), ("mozjs/build/autoconf/sanitize.m4", 6409), ("mozjs/build/autoconf/toolchain.m4", 3241), ("mozjs/build/autoconf/zlib.m4", 1917), ("mozjs/build/automation.py.in", 21567), ("mozjs/build/binary-location.mk", 606), ("mozjs/build/build-clang/README", 2294), ("mozjs/build/build-clang/android-mangling-error.patch", 1339), ("mozjs/build/build-clang/build-clang.py", 33042), ("mozjs/build/build-clang/clang-4.0-linux64.json", 866), ("mozjs/build/build-clang/clang-7-android.json", 2652), ("mozjs/build/build-clang/clang-7-linux64.json", 1120), ("mozjs/build/build-clang/clang-7-macosx64.json", 1455), ("mozjs/build/build-clang/clang-tidy-linux64.json", 909), ("mozjs/build/build-clang/clang-tidy-macosx64.json", 1268), ("mozjs/build/build-clang/clang-tidy-win64.json", 747), ("mozjs/build/build-clang/clang-trunk-mingw.json", 871), ("mozjs/build/build-clang/clang-win64.json", 882), ("mozjs/build/build-clang/compiler-rt-cross-compile.patch", 846), ("mozjs/build/build-clang/compiler-rt-no-codesign.patch", 881), ("mozjs/build/build-clang/downgrade-mangling-error.patch", 1427), ("mozjs/build/build-clang/find_symbolizer_linux.patch", 1976), ("mozjs/build/build-clang/llvm-debug-frame.patch", 524), ("mozjs/build/build-clang/loosen-msvc-detection.patch", 1163), ("mozjs/build/build-clang/r277806.patch", 13706), ("mozjs/build/build-clang/r285657.patch", 1955), ("mozjs/build/build-clang/r289565-for-3.9.patch", 105681), ("mozjs/build/build-clang/r313872.patch", 725), ("mozjs/build/build-clang/r322325.patch", 70562), ("mozjs/build/build-clang/r322401.patch", 85570), ("mozjs/build/build-clang/r325356.patch", 11531), ("mozjs/build/build-clang/r339636.patch", 4446), ("mozjs/build/build-clang/r350774.patch", 714), ("mozjs/build/build-clang/rename_gcov_flush_.patch", 524), ("mozjs/build/build-clang/revert-r355311.patch", 4312), ("mozjs/build/build-clang/static-llvm-symbolizer.patch", 376), ("mozjs/build/build-clang/unpoison-thread-stacks.patch", 1376), ("mozjs/build/build-clang/workaround-issue38586.patch", 1338), ("mozjs/build/build-infer/README", 777), ("mozjs/build/build-infer/build-infer.py", 4065), ("mozjs/build/build-infer/infer-linux64.json", 143), ("mozjs/build/buildconfig.py", 691), ("mozjs/build/cargo-linker", 962), ("mozjs/build/checksums.py", 4944), ("mozjs/build/clang-plugin/.clang-format", 19), ("mozjs/build/clang-plugin/ArithmeticArgChecker.cpp", 2891), ("mozjs/build/clang-plugin/ArithmeticArgChecker.h", 600), ("mozjs/build/clang-plugin/AssertAssignmentChecker.cpp", 771), ("mozjs/build/clang-plugin/AssertAssignmentChecker.h", 612), ("mozjs/build/clang-plugin/BaseCheck.h", 1096), ("mozjs/build/clang-plugin/CanRunScriptChecker.cpp", 13541), ("mozjs/build/clang-plugin/CanRunScriptChecker.h", 1040), ("mozjs/build/clang-plugin/Checks.inc", 1851), ("mozjs/build/clang-plugin/ChecksIncludes.inc", 1399), ("mozjs/build/clang-plugin/CustomAttributes.cpp", 4106), ("mozjs/build/clang-plugin/CustomAttributes.h", 997), ("mozjs/build/clang-plugin/CustomAttributes.inc", 815), ("mozjs/build/clang-plugin/CustomMatchers.h", 12443), ("mozjs/build/clang-plugin/CustomTypeAnnotation.cpp", 6053), ("mozjs/build/clang-plugin/CustomTypeAnnotation.h", 2278), ("mozjs/build/clang-plugin/DanglingOnTemporaryChecker.cpp", 10075), ("mozjs/build/clang-plugin/DanglingOnTemporaryChecker.h", 653), ("mozjs/build/clang-plugin/DiagnosticsMatcher.cpp", 535), ("mozjs/build/clang-plugin/DiagnosticsMatcher.h", 583), ("mozjs/build/clang-plugin/ExplicitImplicitChecker.cpp", 1319), ("mozjs/build/clang-plugin/ExplicitImplicitChecker.h", 612), ("mozjs/build/clang-plugin/ExplicitOperatorBoolChecker.cpp", 1394), ("mozjs/build/clang-plugin/ExplicitOperatorBoolChecker.h", 658), ("mozjs/build/clang-plugin/KungFuDeathGripChecker.cpp", 3797), ("mozjs/build/clang-plugin/KungFuDeathGripChecker.h", 608), ("mozjs/build/clang-plugin/LoadLibraryUsageChecker.cpp", 1352), ("mozjs/build/clang-plugin/LoadLibraryUsageChecker.h", 653), ("mozjs/build/clang-plugin/Makefile.in", 648), ("mozjs/build/clang-plugin/MemMoveAnnotation.h", 1925), ("mozjs/build/clang-plugin/MozCheckAction.cpp", 984), ("mozjs/build/clang-plugin/MozillaTidyModule.cpp", 1078), ("mozjs/build/clang-plugin/MustOverrideChecker.cpp", 2273), ("mozjs/build/clang-plugin/MustOverrideChecker.h", 708), ("mozjs/build/clang-plugin/MustReturnFromCallerChecker.cpp", 3569), ("mozjs/build/clang-plugin/MustReturnFromCallerChecker.h", 872), ("mozjs/build/clang-plugin/MustUseChecker.cpp", 2407), ("mozjs/build/clang-plugin/MustUseChecker.h", 640), ("mozjs/build/clang-plugin/NaNExprChecker.cpp", 2425), ("mozjs/build/clang-plugin/NaNExprChecker.h", 576), ("mozjs/build/clang-plugin/NeedsNoVTableTypeChecker.cpp", 1381), ("mozjs/build/clang-plugin/NeedsNoVTableTypeChecker.h", 616), ("mozjs/build/clang-plugin/NoAddRefReleaseOnReturnChecker.cpp", 1296), ("mozjs/build/clang-plugin/NoAddRefReleaseOnReturnChecker.h", 673), ("mozjs/build/clang-plugin/NoAutoTypeChecker.cpp", 809), ("mozjs/build/clang-plugin/NoAutoTypeChecker.h", 588), ("mozjs/build/clang-plugin/NoDuplicateRefCntMemberChecker.cpp", 2627), ("mozjs/build/clang-plugin/NoDuplicateRefCntMemberChecker.h", 673), ("mozjs/build/clang-plugin/NoExplicitMoveConstructorChecker.cpp", 859), ("mozjs/build/clang-plugin/NoExplicitMoveConstructorChecker.h", 683), ("mozjs/build/clang-plugin/NonMemMovableMemberChecker.cpp", 1299), ("mozjs/build/clang-plugin/NonMemMovableMemberChecker.h", 653), ("mozjs/build/clang-plugin/NonMemMovableTemplateArgChecker.cpp", 2129), ("mozjs/build/clang-plugin/NonMemMovableTemplateArgChecker.h", 678), ("mozjs/build/clang-plugin/NonParamInsideFunctionDeclChecker.cpp", 3695), ("mozjs/build/clang-plugin/NonParamInsideFunctionDeclChecker.h", 688), ("mozjs/build/clang-plugin/OverrideBaseCallChecker.cpp", 3677), ("mozjs/build/clang-plugin/OverrideBaseCallChecker.h", 1065), ("mozjs/build/clang-plugin/OverrideBaseCallUsageChecker.cpp", 798), ("mozjs/build/clang-plugin/OverrideBaseCallUsageChecker.h", 843), ("mozjs/build/clang-plugin/ParamTraitsEnumChecker.cpp", 1324), ("mozjs/build/clang-plugin/ParamTraitsEnumChecker.h", 631), ("mozjs/build/clang-plugin/RecurseGuard.h", 1658), ("mozjs/build/clang-plugin/RefCountedCopyConstructorChecker.cpp", 1427), ("mozjs/build/clang-plugin/RefCountedCopyConstructorChecker.h", 683), ("mozjs/build/clang-plugin/RefCountedInsideLambdaChecker.cpp", 5832), ("mozjs/build/clang-plugin/RefCountedInsideLambdaChecker.h", 1031), ("mozjs/build/clang-plugin/ScopeChecker.cpp", 6505), ("mozjs/build/clang-plugin/ScopeChecker.h", 568), ("mozjs/build/clang-plugin/SprintfLiteralChecker.cpp", 3192), ("mozjs/build/clang-plugin/SprintfLiteralChecker.h", 604), ("mozjs/build/clang-plugin/StmtToBlockMap.h", 2993), ("mozjs/build/clang-plugin/ThirdPartyPaths.h", 291), ("mozjs/build/clang-plugin/ThirdPartyPaths.py", 821), ("mozjs/build/clang-plugin/TrivialCtorDtorChecker.cpp", 1229), ("mozjs/build/clang-plugin/TrivialCtorDtorChecker.h", 608), ("mozjs/build/clang-plugin/Utils.h", 14440), ("mozjs/build/clang-plugin/VariableUsageHelpers.cpp", 9615), ("mozjs/build/clang-plugin/VariableUsageHelpers.h", 2638), ("mozjs/build/clang-plugin/import_mozilla_checks.py", 4674), ("mozjs/build/clang-plugin/moz.build", 2857), ("mozjs/build/clang-plugin/mozsearch-plugin/FileOperations.cpp", 3252), ("mozjs/build/clang-plugin/mozsearch-plugin/FileOperations.h", 1212), ("mozjs/build/clang-plugin/mozsearch-plugin/JSONFormatter.cpp", 3578), ("mozjs/build/clang-plugin/mozsearch-plugin/JSONFormatter.h", 1491), ("mozjs/build/clang-plugin/mozsearch-plugin/MozsearchIndexer.cpp", 50321), ("mozjs/build/clang-plugin/mozsearch-plugin/README", 561), ("mozjs/build/clang-plugin/mozsearch-plugin/StringOperations.cpp", 1084), ("mozjs/build/clang-plugin/mozsearch-plugin/StringOperations.h", 812), ("mozjs/build/clang-plugin/plugin.h", 2317), ("mozjs/build/clang-plugin/tests/Makefile.in", 415), ("mozjs/build/clang-plugin/tests/NonParameterTestCases.h", 5596), ("mozjs/build/clang-plugin/tests/TestAssertWithAssignment.cpp", 1941), ("mozjs/build/clang-plugin/tests/TestBadImplicitConversionCtor.cpp", 1691), ("mozjs/build/clang-plugin/tests/TestCanRunScript.cpp", 10129), ("mozjs/build/clang-plugin/tests/TestCustomHeap.cpp", 936), ("mozjs/build/clang-plugin/tests/TestDanglingOnTemporary.cpp", 2476), ("mozjs/build/clang-plugin/tests/TestExplicitOperatorBool.cpp", 332), ("mozjs/build/clang-plugin/tests/TestGlobalClass.cpp", 2519), ("mozjs/build/clang-plugin/tests/TestHeapClass.cpp", 3468), ("mozjs/build/clang-plugin/tests/TestInheritTypeAnnotationsFromTemplateArgs.cpp", 4574), ("mozjs/build/clang-plugin/tests/TestKungFuDeathGrip.cpp", 4591), ("mozjs/build/clang-plugin/tests/TestLoadLibraryUsage.cpp", 1081), ("mozjs/build/clang-plugin/tests/TestMultipleAnnotations.cpp", 490), ("mozjs/build/clang-plugin/tests/TestMustOverride.cpp", 2441), ("mozjs/build/clang-plugin/tests/TestMustReturnFromCaller.cpp", 4270), ("mozjs/build/clang-plugin/tests/TestMustUse.cpp", 7067), ("mozjs/build/clang-plugin/tests/TestNANTestingExpr.cpp", 923), ("mozjs/build/clang-plugin/tests/TestNANTestingExprC.c", 288), ("mozjs/build/clang-plugin/tests/TestNeedsNoVTableType.cpp", 3593), ("mozjs/build/clang-plugin/tests/TestNoAddRefReleaseOnReturn.cpp", 5162), ("mozjs/build/clang-plugin/tests/TestNoArithmeticExprInArgument.cpp", 1246), ("mozjs/build/clang-plugin/tests/TestNoAutoType.cpp", 2330), ("mozjs/build/clang-plugin/tests/TestNoDuplicateRefCntMember.cpp", 1350), ("mozjs/build/clang-plugin/tests/TestNoExplicitMoveConstructor.cpp", 477), ("mozjs/build/clang-plugin/tests/TestNoRefcountedInsideLambdas.cpp", 14499), ("mozjs/build/clang-plugin/tests/TestNonHeapClass.cpp", 2729), ("mozjs/build/clang-plugin/tests/TestNonMemMovable.cpp", 41652), ("mozjs/build/clang-plugin/tests/TestNonMemMovableStd.cpp", 1929), ("mozjs/build/clang-plugin/tests/TestNonMemMovableStdAtomic.cpp", 1095), ("mozjs/build/clang-plugin/tests/TestNonParameterChecker.cpp", 9646), ("mozjs/build/clang-plugin/tests/TestNonTemporaryClass.cpp", 2950), ("mozjs/build/clang-plugin/tests/TestOverrideBaseCall.cpp", 2921), ("mozjs/build/clang-plugin/tests/TestOverrideBaseCallAnnotation.cpp", 964), ("mozjs/build/clang-plugin/tests/TestParamTraitsEnum.cpp", 2334), ("mozjs/build/clang-plugin/tests/TestRefCountedCopyConstructor.cpp", 696), ("mozjs/build/clang-plugin/tests/TestSprintfLiteral.cpp", 2839), ("mozjs/build/clang-plugin/tests/TestStackClass.cpp", 2856), ("mozjs/build/clang-plugin/tests/TestTemporaryClass.cpp", 3544), ("mozjs/build/clang-plugin/tests/TestTrivialCtorDtor.cpp", 2718), ("mozjs/build/clang-plugin/tests/moz.build", 2492), ("mozjs/build/compare-mozconfig/compare-mozconfigs.py", 5799), ("mozjs/build/compare-mozconfig/python.ini", 57), ("mozjs/build/debian-packages/cmake-wheezy.diff", 6806), ("mozjs/build/debian-packages/dpkg-wheezy.diff", 963), ("mozjs/build/debian-packages/gdb-wheezy.diff", 2795), ("mozjs/build/debian-packages/glib-wheezy.diff", 1029), ("mozjs/build/debian-packages/gtk-wheezy.diff", 1674), ("mozjs/build/debian-packages/make-wheezy.diff", 471), ("mozjs/build/debian-packages/nasm-wheezy.diff", 1664), ("mozjs/build/debian-packages/python-defaults-wheezy.diff", 2580), ("mozjs/build/debian-packages/python-wheezy.diff", 5796), ("mozjs/build/debian-packages/python-zstandard-wheezy.diff", 730), ("mozjs/build/debian-packages/python3-defaults-wheezy.diff", 988), ("mozjs/build/debian-packages/python3.5-wheezy.diff", 7576), ("mozjs/build/debian-packages/sqlite3-wheezy.diff", 2503), ("mozjs/build/debian-packages/valgrind-wheezy.diff", 9924), ("mozjs/build/debian-packages/xkeyboard-config-wheezy.diff", 972), ("mozjs/build/defines.sh", 141), ("mozjs/build/docs/build-overview.rst", 5813), ("mozjs/build/docs/build-targets.rst", 1971), ("mozjs/build/docs/cppeclipse.rst", 1763), ("mozjs/build/docs/defining-binaries.rst", 10928), ("mozjs/build/docs/defining-xpcom-components.rst", 9665), ("mozjs/build/docs/environment-variables.rst", 1074), ("mozjs/build/docs/files-metadata.rst", 6436), ("mozjs/build/docs/glossary.rst", 1750), ("mozjs/build/docs/gn.rst", 2260), ("mozjs/build/docs/index.rst", 932), ("mozjs/build/docs/jar-manifests.rst", 4072), ("mozjs/build/docs/locales.rst", 2359), ("mozjs/build/docs/mozbuild-files.rst", 8046), ("mozjs/build/docs/mozbuild-symbols.rst", 146), ("mozjs/build/docs/mozbuild/dumbmake.rst", 1161), ("mozjs/build/docs/mozbuild/index.rst", 1626), ("mozjs/build/docs/mozconfigs.rst", 1491), ("mozjs/build/docs/mozinfo.rst", 3688), ("mozjs/build/docs/pgo.rst", 1102), ("mozjs/build/docs/preprocessor.rst", 5332), ("mozjs/build/docs/python.rst", 7594), ("mozjs/build/docs/rust.rst", 4029), ("mozjs/build/docs/slow.rst", 8106), ("mozjs/build/docs/sparse.rst", 6317), ("mozjs/build/docs/supported-configurations.rst", 1748), ("mozjs/build/docs/telemetry.rst", 6848), ("mozjs/build/docs/test_certificates.rst", 1806), ("mozjs/build/docs/test_manifests.rst", 6999), ("mozjs/build/docs/toolchains.rst", 4663), ("mozjs/build/docs/tup.rst", 7543), ("mozjs/build/docs/visualstudio.rst", 4024), ("mozjs/build/dumbmake-dependencies", 1050), ("mozjs/build/gecko_templates.mozbuild", 3872), ("mozjs/build/gen_automation.py", 1446), ("mozjs/build/gen_symverscript.py", 571), ("mozjs/build/gen_test_backend.py", 1385), ("mozjs/build/gen_test_packages_manifest.py", 3144), ("mozjs/build/genrc.sh", 401), ("mozjs/build/gn.mozbuild", 996), ("mozjs/build/gyp.mozbuild", 4315), ("mozjs/build/gyp_base.mozbuild", 1077), ("mozjs/build/gyp_includes/common.gypi", 141963), ("mozjs/build/gyp_includes/filename_rules.gypi", 3554), ("mozjs/build/gyp_includes/internal/release_defaults.gypi", 497), ("mozjs/build/gyp_includes/internal/release_impl.gypi", 650), ("mozjs/build/gyp_includes/internal/release_impl_official.gypi", 1671), ("mozjs/build/gyp_includes/release.gypi", 387), ("mozjs/build/mach_bootstrap.py", 16470), ("mozjs/build/macosx/cross-mozconfig.common", 2235), ("mozjs/build/macosx/llvm-dsymutil", 2295), ("mozjs/build/macosx/local-mozconfig.common", 1453), ("mozjs/build/macosx/mozconfig.common", 179), ("mozjs/build/macosx/permissions/chown_revert.c", 541), ("mozjs/build/macosx/permissions/chown_root.c", 406), ("mozjs/build/merge_profdata.py", 406), ("mozjs/build/mobile/remoteautomation.py", 17914), ("mozjs/build/moz-automation.mk", 3211), ("mozjs/build/moz.build", 4694), ("mozjs/build/moz.configure/android-ndk.configure", 13283), ("mozjs/build/moz.configure/android-sdk.configure", 4019), ("mozjs/build/moz.configure/bindgen.configure", 12538), ("mozjs/build/moz.configure/checks.configure", 6404), ("mozjs/build/moz.configure/compile-checks.configure", 10478), ("mozjs/build/moz.configure/compilers-util.configure", 3996), ("mozjs/build/moz.configure/flags.configure", 1063), ("mozjs/build/moz.configure/headers.configure", 2868), ("mozjs/build/moz.configure/init.configure", 44169), ("mozjs/build/moz.configure/java.configure", 1823), ("mozjs/build/moz.configure/keyfiles.configure", 2337), ("mozjs/build/moz.configure/memory.configure", 1774), ("mozjs/build/moz.configure/node.configure", 2055), ("mozjs/build/moz.configure/old.configure", 15033), ("mozjs/build/moz.configure/pkg.configure", 4382), ("mozjs/build/moz.configure/rust.configure", 13550), ("mozjs/build/moz.configure/toolchain.configure", 81993), ("mozjs/build/moz.configure/tup.configure", 2929), ("mozjs/build/moz.configure/util.configure", 17208), ("mozjs/build/moz.configure/warnings.configure", 8378), ("mozjs/build/moz.configure/windows.configure", 18464), ("mozjs/build/mozconfig.artifact", 366), ("mozjs/build/mozconfig.artifact.automation", 248), ("mozjs/build/mozconfig.automation", 1091), ("mozjs/build/mozconfig.cache", 2095), ("mozjs/build/mozconfig.clang-cl", 703), ("mozjs/build/mozconfig.comm-support", 1973), ("mozjs/build/mozconfig.common", 1052), ("mozjs/build/mozconfig.common.override", 577), ("mozjs/build/mozconfig.lld-link", 154), ("mozjs/build/mozconfig.nasm", 330), ("mozjs/build/mozconfig.no-compile", 702), ("mozjs/build/mozconfig.node", 338), ("mozjs/build/mozconfig.pgo", 97), ("mozjs/build/mozconfig.rust", 372), ("mozjs/build/mozconfig.stylo", 147), ("mozjs/build/mozconfig.win-common", 282), ("mozjs/build/package/mac_osx/make-diskimage", 1308), ("mozjs/build/package/mac_osx/mozilla-background.jpg", 16591), ("mozjs/build/package/mac_osx/mozilla.dsstore", 6148), ("mozjs/build/package/mac_osx/unpack-diskimage", 2049), ("mozjs/build/pgo/blueprint/LICENSE", 16748), ("mozjs/build/pgo/blueprint/elements.html", 8795), ("mozjs/build/pgo/blueprint/fancytype-screen.css", 2416), ("mozjs/build/pgo/blueprint/forms.html", 3508), ("mozjs/build/pgo/blueprint/grid.html", 9405), ("mozjs/build/pgo/blueprint/grid.png", 206), ("mozjs/build/pgo/blueprint/print.css", 1325), ("mozjs/build/pgo/blueprint/sample.html", 8014), ("mozjs/build/pgo/blueprint/screen.css", 9411), ("mozjs/build/pgo/blueprint/test-small.jpg", 1886), ("mozjs/build/pgo/blueprint/test.jpg", 35467), ("mozjs/build/pgo/blueprint/valid.png", 1669), ("mozjs/build/pgo/certs/README", 587), ("mozjs/build/pgo/certs/alternateroot.ca", 1094), ("mozjs/build/pgo/certs/alternateroot.ca.keyspec", 10), ("mozjs/build/pgo/certs/alternateroot.certspec", 216), ("mozjs/build/pgo/certs/bug413909cert.certspec", 232), ("mozjs/build/pgo/certs/cert9.db", 229376), ("mozjs/build/pgo/certs/dynamicPinningBad.certspec", 219), ("mozjs/build/pgo/certs/dynamicPinningBad.server.keyspec", 10), ("mozjs/build/pgo/certs/dynamicPinningGood.certspec", 255), ("mozjs/build/pgo/certs/escapeattack1.certspec", 212), ("mozjs/build/pgo/certs/evintermediate.ca", 1614), ("mozjs/build/pgo/certs/evintermediate.ca.keyspec", 3), ("mozjs/build/pgo/certs/evintermediate.certspec", 543), ("mozjs/build/pgo/certs/expired.certspec", 215), ("mozjs/build/pgo/certs/imminently_distrusted.certspec", 261), ("mozjs/build/pgo/certs/key4.db", 294912), ("mozjs/build/pgo/certs/mochitest.certspec", 147), ("mozjs/build/pgo/certs/mochitest.client", 2448), ("mozjs/build/pgo/certs/mochitest.client.keyspec", 8), ("mozjs/build/pgo/certs/noSubjectAltName.certspec", 159), ("mozjs/build/pgo/certs/pgoca.ca", 1277), ("mozjs/build/pgo/certs/pgoca.ca.keyspec", 8), ("mozjs/build/pgo/certs/pgoca.certspec", 312), ("mozjs/build/pgo/certs/selfsigned.certspec", 120), ("mozjs/build/pgo/certs/sha1_end_entity.certspec", 218), ("mozjs/build/pgo/certs/sha256_end_entity.certspec", 224), ("mozjs/build/pgo/certs/staticPinningBad.certspec", 195), ("mozjs/build/pgo/certs/staticPinningBad.server.keyspec", 10), ("mozjs/build/pgo/certs/unknown_ca.certspec", 134), ("mozjs/build/pgo/certs/untrusted.certspec", 103), ("mozjs/build/pgo/certs/untrustedandexpired.certspec", 146), ("mozjs/build/pgo/favicon.ico", 1406), ("mozjs/build/pgo/genpgocert.py", 8488), ("mozjs/build/pgo/index.html", 2241), ("mozjs/build/pgo/js-input/3d-thingy.html", 13077), ("mozjs/build/pgo/js-input/crypto-otp.html", 43991), ("mozjs/build/pgo/js-input/key.gif", 1119), ("mozjs/build/pgo/js-input/sunspider/3d-cube.html", 9720), ("mozjs/build/pgo/js-input/sunspider/3d-morph.html", 3524), ("mozjs/build/pgo/js-input/sunspider/3d-raytrace.html", 15970), ("mozjs/build/pgo/js-input/sunspider/access-binary-trees.html", 2907), ("mozjs/build/pgo/js-input/sunspider/access-fannkuch.html", 3210), ("mozjs/build/pgo/js-input/sunspider/access-nbody.html", 5796), ("mozjs/build/pgo/js-input/sunspider/access-nsieve.html", 2376), ("mozjs/build/pgo/js-input/sunspider/bitops-3bit-bits-in-byte.html", 2426), ("mozjs/build/pgo/js-input/sunspider/bitops-bits-in-byte.html", 2048), ("mozjs/build/pgo/js-input/sunspider/bitops-bitwise-and.html", 3117), ("mozjs/build/pgo/js-input/sunspider/bitops-nsieve-bits.html", 2310), ("mozjs/build/pgo/js-input/sunspider/controlflow-recursive.html", 2167), ("mozjs/build/pgo/js-input/sunspider/crypto-aes.html", 18687), ("mozjs/build/pgo/js-input/sunspider/crypto-md5.html", 11384), ("mozjs/build/pgo/js-input/sunspider/crypto-sha1.html", 7982), ("mozjs/build/pgo/js-input/sunspider/date-format-tofte.html", 12153), ("mozjs/build/pgo/js-input/sunspider/date-format-xparb.html", 13716), ("mozjs/build/pgo/js-input/sunspider/math-cordic.html", 4299), ("mozjs/build/pgo/js-input/sunspider/math-partial-sums.html", 2402), ("mozjs/build/pgo/js-input/sunspider/math-spectral-norm.html", 2525), ("mozjs/build/pgo/js-input/sunspider/regexp-dna.html", 109620), ("mozjs/build/pgo/js-input/sunspider/string-base64.html", 4981), ("mozjs/build/pgo/js-input/sunspider/string-fasta.html", 3507), ("mozjs/build/pgo/js-input/sunspider/string-tagcloud.html", 176594), ("mozjs/build/pgo/js-input/sunspider/string-unpack-code.html", 168576), ("mozjs/build/pgo/js-input/sunspider/string-validate-input.html", 3630), ("mozjs/build/pgo/js-input/valid-xhtml10.png", 2414), ("mozjs/build/pgo/profileserver.py", 5421), ("mozjs/build/pgo/server-locations.txt", 11892), ("mozjs/build/pymake/.hgignore", 41), ("mozjs/build/pymake/LICENSE", 1109), ("mozjs/build/pymake/README", 2784), ("mozjs/build/pymake/make.py", 967), ("mozjs/build/pymake/mkformat.py", 237), ("mozjs/build/pymake/mkparse.py", 222), ("mozjs/build/pymake/pymake/__init__.py", 0), ("mozjs/build/pymake/pymake/builtins.py", 3508), ("mozjs/build/pymake/pymake/command.py", 9665), ("mozjs/build/pymake/pymake/data.py", 62126), ("mozjs/build/pymake/pymake/functions.py", 26980), ("mozjs/build/pymake/pymake/globrelative.py", 1618), ("mozjs/build/pymake/pymake/implicit.py", 385), ("mozjs/build/pymake/pymake/parser.py", 30800), ("mozjs/build/pymake/pymake/parserdata.py", 33292), ("mozjs/build/pymake/pymake/process.py", 19738), ("mozjs/build/pymake/pymake/util.py", 3924), ("mozjs/build/pymake/pymake/win32process.py", 1013), ("mozjs/build/pymake/tests/automatic-variables.mk", 1886), ("mozjs/build/pymake/tests/bad-command-continuation.mk", 30), ("mozjs/build/pymake/tests/call.mk", 310), ("mozjs/build/pymake/tests/cmd-stripdotslash.mk", 81), ("mozjs/build/pymake/tests/cmdgoals.mk", 143), ("mozjs/build/pymake/tests/commandmodifiers.mk", 518), ("mozjs/build/pymake/tests/comment-parsing.mk", 650), ("mozjs/build/pymake/tests/continuations-in-functions.mk", 121), ("mozjs/build/pymake/tests/datatests.py", 6981), ("mozjs/build/pymake/tests/default-goal-set-first.mk", 108), ("mozjs/build/pymake/tests/default-goal.mk", 254), ("mozjs/build/pymake/tests/default-target.mk", 220), ("mozjs/build/pymake/tests/default-target2.mk", 141), ("mozjs/build/pymake/tests/define-directive.mk", 929), ("mozjs/build/pymake/tests/depfailed.mk", 55), ("mozjs/build/pymake/tests/depfailedj.mk", 133), ("mozjs/build/pymake/tests/diamond-deps.mk", 227), ("mozjs/build/pymake/tests/dotslash-dir.mk", 118), ("mozjs/build/pymake/tests/dotslash-parse.mk", 94), ("mozjs/build/pymake/tests/dotslash-phony.mk", 32), ("mozjs/build/pymake/tests/dotslash.mk", 141), ("mozjs/build/pymake/tests/doublecolon-exists.mk", 370), ("mozjs/build/pymake/tests/doublecolon-priordeps.mk", 289), ("mozjs/build/pymake/tests/doublecolon-remake.mk", 57), ("mozjs/build/pymake/tests/dynamic-var.mk", 345), ("mozjs/build/pymake/tests/empty-arg.mk", 91), ("mozjs/build/pymake/tests/empty-command-semicolon.mk", 31), ("mozjs/build/pymake/tests/empty-with-deps.mk", 53), ("mozjs/build/pymake/tests/env-var-append.mk", 99), ("mozjs/build/pymake/tests/env-var-append2.mk", 113), ("mozjs/build/pymake/tests/eof-continuation.mk", 73), ("mozjs/build/pymake/tests/escape-chars.mk", 493), ("mozjs/build/pymake/tests/escaped-continuation.mk", 80), ("mozjs/build/pymake/tests/eval-duringexecute.mk", 230), ("mozjs/build/pymake/tests/eval.mk", 90), ("mozjs/build/pymake/tests/exit-code.mk", 48), ("mozjs/build/pymake/tests/file-functions-symlinks.mk", 833), ("mozjs/build/pymake/tests/file-functions.mk", 620), ("mozjs/build/pymake/tests/foreach-local-variable.mk", 230), ("mozjs/build/pymake/tests/formattingtests.py", 9203), ("mozjs/build/pymake/tests/func-refs.mk", 283), ("mozjs/build/pymake/tests/functions.mk", 1652), ("mozjs/build/pymake/tests/functiontests.py", 1434), ("mozjs/build/pymake/tests/if-syntaxerr.mk", 64), ("mozjs/build/pymake/tests/ifdefs-nesting.mk", 152), ("mozjs/build/pymake/tests/ifdefs.mk", 2205), ("mozjs/build/pymake/tests/ignore-error.mk", 151), ("mozjs/build/pymake/tests/implicit-chain.mk", 249), ("mozjs/build/pymake/tests/implicit-dir.mk", 391), ("mozjs/build/pymake/tests/implicit-terminal.mk", 371), ("mozjs/build/pymake/tests/implicitsubdir.mk", 106), ("mozjs/build/pymake/tests/include-dynamic.mk", 482), ("mozjs/build/pymake/tests/include-file.inc", 15), ("mozjs/build/pymake/tests/include-missing.mk", 155), ("mozjs/build/pymake/tests/include-notfound.mk", 480), ("mozjs/build/pymake/tests/include-optional-warning.mk", 45), ("mozjs/build/pymake/tests/include-regen.mk", 236), ("mozjs/build/pymake/tests/include-regen2.mk", 174), ("mozjs/build/pymake/tests/include-regen3.mk", 175), ("mozjs/build/pymake/tests/include-test.mk", 187), ("mozjs/build/pymake/tests/includedeps-norebuild.mk", 170), ("mozjs/build/pymake/tests/includedeps-sideeffects.mk", 131), ("mozjs/build/pymake/tests/includedeps-stripdotslash.deps", 18), ("mozjs/build/pymake/tests/includedeps-stripdotslash.mk", 99), ("mozjs/build/pymake/tests/includedeps-variables.deps", 22), ("mozjs/build/pymake/tests/includedeps-variables.mk", 142), ("mozjs/build/pymake/tests/includedeps.deps", 19), ("mozjs/build/pymake/tests/includedeps.mk", 103), ("mozjs/build/pymake/tests/info.mk", 139), ("mozjs/build/pymake/tests/justprint-native.mk", 948), ("mozjs/build/pymake/tests/justprint.mk", 102), ("mozjs/build/pymake/tests/keep-going-doublecolon.mk", 155), ("mozjs/build/pymake/tests/keep-going-parallel.mk", 141), ("mozjs/build/pymake/tests/keep-going.mk", 137), ("mozjs/build/pymake/tests/line-continuations.mk", 396), ("mozjs/build/pymake/tests/link-search.mk", 84), ("mozjs/build/pymake/tests/makeflags.mk", 116), ("mozjs/build/pymake/tests/matchany.mk", 209), ("mozjs/build/pymake/tests/matchany2.mk", 217), ("mozjs/build/pymake/tests/matchany3.mk", 120), ("mozjs/build/pymake/tests/mkdir-fail.mk", 117), ("mozjs/build/pymake/tests/mkdir.mk", 689), ("mozjs/build/pymake/tests/multiple-rules-prerequisite-merge.mk", 403), ("mozjs/build/pymake/tests/native-command-delay-load.mk", 247), ("mozjs/build/pymake/tests/native-command-raise.mk", 177), ("mozjs/build/pymake/tests/native-command-return-fail1.mk", 123), ("mozjs/build/pymake/tests/native-command-return-fail2.mk", 136), ("mozjs/build/pymake/tests/native-command-return.mk", 171), ("mozjs/build/pymake/tests/native-command-shell-glob.mk", 260), ("mozjs/build/pymake/tests/native-command-sys-exit-fail1.mk", 116), ("mozjs/build/pymake/tests/native-command-sys-exit-fail2.mk", 129), ("mozjs/build/pymake/tests/native-command-sys-exit.mk", 164), ("mozjs/build/pymake/tests/native-environment.mk", 265), ("mozjs/build/pymake/tests/native-pycommandpath-sep.mk", 475), ("mozjs/build/pymake/tests/native-pycommandpath.mk", 407), ("mozjs/build/pymake/tests/native-simple.mk", 213), ("mozjs/build/pymake/tests/native-touch.mk", 225), ("mozjs/build/pymake/tests/newlines.mk", 514), ("mozjs/build/pymake/tests/no-remake.mk", 117), ("mozjs/build/pymake/tests/nosuchfile.mk", 51), ("mozjs/build/pymake/tests/notargets.mk", 55), ("mozjs/build/pymake/tests/notparallel.mk", 135), ("mozjs/build/pymake/tests/oneline-command-continuations.mk", 78), ("mozjs/build/pymake/tests/override-propagate.mk", 1067), ("mozjs/build/pymake/tests/parallel-dep-resolution.mk", 80), ("mozjs/build/pymake/tests/parallel-dep-resolution2.mk", 106), ("mozjs/build/pymake/tests/parallel-native.mk", 341), ("mozjs/build/pymake/tests/parallel-simple.mk", 441), ("mozjs/build/pymake/tests/parallel-submake.mk", 436), ("mozjs/build/pymake/tests/parallel-toserial.mk", 553), ("mozjs/build/pymake/tests/parallel-waiting.mk", 388), ("mozjs/build/pymake/tests/parentheses.mk", 24), ("mozjs/build/pymake/tests/parsertests.py", 10914), ("mozjs/build/pymake/tests/path-length.mk", 9247), ("mozjs/build/pymake/tests/pathdir/pathtest", 73), ("mozjs/build/pymake/tests/pathdir/pathtest.exe", 45056), ("mozjs/build/pymake/tests/pathdir/src/Makefile", 44), ("mozjs/build/pymake/tests/pathdir/src/pathtest.cpp", 137), ("mozjs/build/pymake/tests/patsubst.mk", 374), ("mozjs/build/pymake/tests/phony.mk", 90), ("mozjs/build/pymake/tests/pycmd.py", 907), ("mozjs/build/pymake/tests/recursive-set.mk", 67), ("mozjs/build/pymake/tests/recursive-set2.mk", 80), ("mozjs/build/pymake/tests/remake-mtime.mk", 291), ("mozjs/build/pymake/tests/rm-fail.mk", 105), ("mozjs/build/pymake/tests/rm.mk", 444), ("mozjs/build/pymake/tests/runtests.py", 6205), ("mozjs/build/pymake/tests/serial-dep-resolution.mk", 46), ("mozjs/build/pymake/tests/serial-doublecolon-execution.mk", 262), ("mozjs/build/pymake/tests/serial-rule-execution.mk", 55), ("mozjs/build/pymake/tests/serial-rule-execution2.mk", 227), ("mozjs/build/pymake/tests/serial-toparallel.mk", 85), ("mozjs/build/pymake/tests/shellfunc.mk", 146), ("mozjs/build/pymake/tests/simple-makeflags.mk", 222), ("mozjs/build/pymake/tests/sort.mk", 113), ("mozjs/build/pymake/tests/specified-target.mk", 140), ("mozjs/build/pymake/tests/static-pattern.mk", 84), ("mozjs/build/pymake/tests/static-pattern2.mk", 112), ("mozjs/build/pymake/tests/subdir/delayload.py", 69), ("mozjs/build/pymake/tests/subdir/pymod.py", 106), ("mozjs/build/pymake/tests/subdir/testmodule.py", 180), ("mozjs/build/pymake/tests/submake-path.makefile2", 318), ("mozjs/build/pymake/tests/submake-path.mk", 378), ("mozjs/build/pymake/tests/submake.makefile2", 624), ("mozjs/build/pymake/tests/submake.mk", 361), ("mozjs/build/pymake/tests/subprocess-path.mk", 1027), ("mozjs/build/pymake/tests/tab-intro.mk", 252), ("mozjs/build/pymake/tests/target-specific.mk", 502), ("mozjs/build/pymake/tests/unexport.mk", 246), ("mozjs/build/pymake/tests/unexport.submk", 264), ("mozjs/build/pymake/tests/unterminated-dollar.mk", 74), ("mozjs/build/pymake/tests/var-change-flavor.mk", 203), ("mozjs/build/pymake/tests/var-commandline.mk", 164), ("mozjs/build/pymake/tests/var-overrides.mk", 578), ("mozjs/build/pymake/tests/var-ref.mk", 275), ("mozjs/build/pymake/tests/var-set.mk", 868), ("mozjs/build/pymake/tests/var-substitutions.mk", 1558), ("mozjs/build/pymake/tests/vpath-directive-dynamic.mk", 144), ("mozjs/build/pymake/tests/vpath-directive.mk", 622), ("mozjs/build/pymake/tests/vpath.mk", 395), ("mozjs/build/pymake/tests/vpath2.mk", 403), ("mozjs/build/pymake/tests/wildcards.mk", 295), ("mozjs/build/pymake/tests/windows-paths.mk", 151), ("mozjs/build/qemu-wrap", 1029), ("mozjs/build/rebuild-backend.mk", 1280), ("mozjs/build/sanitizers/asan_blacklist_win.txt", 1064), ("mozjs/build/sanitizers/lsan_suppressions.txt", 2609), ("mozjs/build/sanitizers/tsan_suppressions.txt", 377), ("mozjs/build/sanitizers/ubsan_signed_overflow_blacklist.txt", 9191), ("mozjs/build/sanitizers/ubsan_suppressions.txt", 662), ("mozjs/build/sanitizers/ubsan_unsigned_overflow_blacklist.txt", 9426), ("mozjs/build/sparse-profiles/docker-image", 759), ("mozjs/build/sparse-profiles/mach", 537), ("mozjs/build/sparse-profiles/mozharness", 71), ("mozjs/build/sparse-profiles/sphinx-docs", 558), ("mozjs/build/sparse-profiles/taskgraph", 1658), ("mozjs/build/sparse-profiles/toolchain-build", 271), ("mozjs/build/sparse-profiles/tps", 89), ("mozjs/build/sparse-profiles/update-verify", 73), ("mozjs/build/sparse-profiles/upload-generated-sources", 86), ("mozjs/build/sparse-profiles/upload-symbols", 98), ("mozjs/build/submit_telemetry_data.py", 4884), ("mozjs/build/templates.mozbuild", 4391), ("mozjs/build/test_templates.mozbuild", 1450), ("mozjs/build/tests/cram/cram.ini", 24), ("mozjs/build/tests/cram/test_configure_help.t", 426), ("mozjs/build/unix/aix.exp", 212), ("mozjs/build/unix/build-binutils/3A24BC1E8FB409FA9F14371813FCEF89DD9E3C4F.key", 3106), ("mozjs/build/unix/build-binutils/build-binutils.sh", 2431), ("mozjs/build/unix/build-gcc/07F3DBBECC1A39605078094D980C197698C3739D.key", 3220), ("mozjs/build/unix/build-gcc/13975A70E63C361C73AE69EF6EEB81F8981C74C7.key", 5101), ("mozjs/build/unix/build-gcc/33C235A34C46AA3FFB293709A328C3A2C3C45C06.key", 1936), ("mozjs/build/unix/build-gcc/343C2FF0FBEE5EC2EDBEF399F3599FF828C67298.key", 2050), ("mozjs/build/unix/build-gcc/5ED46A6721D365587791E2AA783FCD8E58BCAFBA.key", 2233), ("mozjs/build/unix/build-gcc/7F74F97C103468EE5D750B583AB00996FC26A641.key", 3297), ("mozjs/build/unix/build-gcc/AD17A21EF8AED8F1CC02DBD9F7D5C9BF765C61E3.key", 3496), ("mozjs/build/unix/build-gcc/DA23579A74D4AD9AF9D3F945CEFAC8EAAF17519D.key", 3175), ("mozjs/build/unix/build-gcc/EAF1C276A747E9ED86210CBAC3126D3B4AE55E93.key", 1652), ("mozjs/build/unix/build-gcc/PR64905.patch", 421), ("mozjs/build/unix/build-gcc/build-gcc.sh", 3504), ("mozjs/build/unix/build-hfsplus/build-hfsplus.sh", 1925), ("mozjs/build/unix/elfhack/Makefile.in", 1730), ("mozjs/build/unix/elfhack/README", 1328), ("mozjs/build/unix/elfhack/dummy.c", 317), ("mozjs/build/unix/elfhack/elf.cpp", 31393), ("mozjs/build/unix/elfhack/elfhack.cpp", 50493), ("mozjs/build/unix/elfhack/elfxx.h", 18927), ("mozjs/build/unix/elfhack/inject.c", 4964), ("mozjs/build/unix/elfhack/inject/copy_source.py", 388), ("mozjs/build/unix/elfhack/inject/moz.build", 1193), ("mozjs/build/unix/elfhack/moz.build", 818), ("mozjs/build/unix/elfhack/test-array.c", 326), ("mozjs/build/unix/elfhack/test-ctors.c", 602), ("mozjs/build/unix/elfhack/test.c", 3150), ("mozjs/build/unix/moz.build", 502), ("mozjs/build/unix/mozconfig.asan", 509), ("mozjs/build/unix/mozconfig.linux", 69), ("mozjs/build/unix/mozconfig.linux32", 248), ("mozjs/build/unix/mozconfig.stdcxx", 1010), ("mozjs/build/unix/mozconfig.tsan", 675), ("mozjs/build/unix/mozconfig.unix", 1238), ("mozjs/build/unix/mozilla.in", 2451), ("mozjs/build/unix/print-failed-commands.sh", 832), ("mozjs/build/unix/print-non-newline.sh", 766), ("mozjs/build/unix/rewrite_asan_dylib.py", 4405), ("mozjs/build/unix/run-gprof.sh", 414), ("mozjs/build/unix/run-hiprof.sh", 548), ("mozjs/build/unix/run-mozilla.sh", 8782), ("mozjs/build/unix/run-third.sh", 567), ("mozjs/build/unix/stdc++compat/hide_std.ld", 97), ("mozjs/build/unix/stdc++compat/moz.build", 841), ("mozjs/build/unix/stdc++compat/stdc++compat.cpp", 5623), ("mozjs/build/update-settings.ini", 368), ("mozjs/build/upload.py", 3898), ("mozjs/build/upload_generated_sources.py", 5838), ("mozjs/build/valgrind/__init__.py", 0), ("mozjs/build/valgrind/cross-architecture.sup", 2868), ("mozjs/build/valgrind/i386-pc-linux-gnu.sup", 768), ("mozjs/build/valgrind/mach_commands.py", 7903), ("mozjs/build/valgrind/output_handler.py", 5347), ("mozjs/build/valgrind/x86_64-pc-linux-gnu.sup", 33033), ("mozjs/build/variables.py", 3551), ("mozjs/build/virtualenv_packages.txt", 3732), ("mozjs/build/win32/Makefile.in", 918), ("mozjs/build/win32/__init__.py", 0), ("mozjs/build/win32/autobinscope.py", 3822), ("mozjs/build/win32/crashinject.cpp", 3351), ("mozjs/build/win32/crashinjectdll/crashinjectdll.cpp", 1082), ("mozjs/build/win32/crashinjectdll/crashinjectdll.def", 250), ("mozjs/build/win32/crashinjectdll/moz.build", 433), ("mozjs/build/win32/moz.build", 1146), ("mozjs/build/win32/mozconfig.vs-latest", 122), ("mozjs/build/win32/mozconfig.vs2017", 512), ("mozjs/build/win32/pgomerge.py", 1956), ("mozjs/build/win64-aarch64/mozconfig.vs-latest", 130), ("mozjs/build/win64-aarch64/mozconfig.vs2017", 403), ("mozjs/build/win64/cargo-linker.bat.in", 44), ("mozjs/build/win64/mozconfig.asan", 909), ("mozjs/build/win64/mozconfig.vs-latest", 122), ("mozjs/build/win64/mozconfig.vs2017", 469), ("mozjs/build/windows_toolchain.py", 8133), ("mozjs/build/workspace-hack/src/lib.rs", 95), ("mozjs/config/tests/chrome.manifest.flat", 156), ("mozjs/config/tests/python.ini", 130), ("mozjs/config/tests/ref-simple/one/file.xml", 27), ("mozjs/config/tests/ref-simple/one/preproc", 21), ("mozjs/config/tests/ref-simple/one/some.css", 144), ("mozjs/config/tests/ref-simple/three/l10nfile.txt", 18), ("mozjs/config/tests/ref-simple/two/otherfile.xml", 32), ("mozjs/config/tests/src-simple/Makefile.in", 1313), ("mozjs/config/tests/src-simple/jar.mn", 741), ("mozjs/config/tests/src-simple/l10n/l10nfile.txt", 18), ("mozjs/config/tests/src-simple/moz.build", 316), ("mozjs/config/tests/src-simple/thesrcdir/file.xml", 27), ("mozjs/config/tests/src-simple/thesrcdir/preproc.in", 111), ("mozjs/config/tests/src-simple/thesrcdir/some.css", 151), ("mozjs/config/tests/src-simple/thetopsrcdir/otherfile.xml", 32), ("mozjs/config/tests/test.manifest.flat", 135), ("mozjs/config/tests/test.manifest.jar", 162), ("mozjs/config/tests/test.manifest.symlink", 135), ("mozjs/config/tests/test_mozbuild_reading.py", 4421), ("mozjs/config/tests/unit-mozunit.py", 3356), ("mozjs/config/tests/unit-nsinstall.py", 6971), ("mozjs/config/tests/unit-printprereleasesuffix.py", 2345), ("mozjs/config/tests/unitMozZipFile.py", 6327), ("mozjs/dom/bindings/mozwebidlcodegen/test/Child.webidl", 49), ("mozjs/dom/bindings/mozwebidlcodegen/test/ExampleBinding.webidl", 137), ("mozjs/dom/bindings/mozwebidlcodegen/test/Parent.webidl", 42), ("mozjs/dom/bindings/mozwebidlcodegen/test/TestEvent.webidl", 261), ("mozjs/dom/bindings/mozwebidlcodegen/test/python.ini", 60), ("mozjs/dom/bindings/mozwebidlcodegen/test/test_mozwebidlcodegen.py", 10154), ("mozjs/intl/icu/source/test/Makefile.in", 5853), ("mozjs/intl/icu/source/test/cintltst/Makefile.in", 4126), ("mozjs/intl/icu/source/test/compat/Makefile.in", 1678), ("mozjs/intl/icu/source/test/hdrtst/Makefile.in", 5981), ("mozjs/intl/icu/source/test/intltest/Makefile.in", 5161), ("mozjs/intl/icu/source/test/iotest/Makefile.in", 2917), ("mozjs/intl/icu/source/test/letest/Makefile.in", 3190), ("mozjs/intl/icu/source/test/perf/DateFmtPerf/Makefile.in", 2061), ("mozjs/intl/icu/source/test/perf/Makefile.in", 2564), ("mozjs/intl/icu/source/test/perf/charperf/Makefile.in", 1876), ("mozjs/intl/icu/source/test/perf/collationperf/Makefile.in", 2017), ("mozjs/intl/icu/source/test/perf/collperf/Makefile.in", 1897), ("mozjs/intl/icu/source/test/perf/collperf2/Makefile.in", 1896), ("mozjs/intl/icu/source/test/perf/convperf/Makefile.in", 1897), ("mozjs/intl/icu/source/test/perf/dicttrieperf/Makefile.in", 1892), ("mozjs/intl/icu/source/test/perf/howExpensiveIs/Makefile.in", 1991), ("mozjs/intl/icu/source/test/perf/leperf/Makefile.in", 2091), ("mozjs/intl/icu/source/test/perf/normperf/Makefile.in", 2136), ("mozjs/intl/icu/source/test/perf/strsrchperf/Makefile.in", 1909), ("mozjs/intl/icu/source/test/perf/ubrkperf/Makefile.in", 1876), ("mozjs/intl/icu/source/test/perf/unisetperf/Makefile.in", 1864), ("mozjs/intl/icu/source/test/perf/usetperf/Makefile.in", 1885), ("mozjs/intl/icu/source/test/perf/ustrperf/Makefile.in", 1880), ("mozjs/intl/icu/source/test/perf/utfperf/Makefile.in", 1872), ("mozjs/intl/icu/source/test/perf/utrie2perf/Makefile.in", 1884), ("mozjs/intl/icu/source/test/testdata/Makefile.in", 10655), ("mozjs/intl/icu/source/test/testdata/pkgdataMakefile.in", 1478), ("mozjs/intl/icu/source/test/testmap/Makefile.in", 1853), ("mozjs/intl/icu/source/test/thaitest/Makefile.in", 1786), ("mozjs/js/examples/jorendb.js", 26322), ("mozjs/js/rust/etc/wrapper.hpp", 973), ("mozjs/js/rust/tests/bigint.rs", 1477), ("mozjs/js/rust/tests/callback.rs", 2372), ("mozjs/js/rust/tests/enumerate.rs", 1926), ("mozjs/js/rust/tests/evaluate.rs", 1016), ("mozjs/js/rust/tests/panic.rs", 1528), ("mozjs/js/rust/tests/rooting.rs", 3027), ("mozjs/js/rust/tests/runtime.rs", 1745), ("mozjs/js/rust/tests/stack_limit.rs", 1135), ("mozjs/js/rust/tests/typedarray.rs", 3514), ("mozjs/js/rust/tests/value.rs", 1668), ("mozjs/js/rust/tests/vec_conversion.rs", 2962), ("mozjs/js/src/build/Makefile.in", 3284), ("mozjs/js/src/build/js-config.in", 2139), ("mozjs/js/src/build/js.pc.in", 364), ("mozjs/js/src/build/moz.build", 2706), ("mozjs/js/src/build/symverscript.in", 51), ("mozjs/js/src/ctypes/libffi/doc/libffi.info", 21528), ("mozjs/js/src/ctypes/libffi/doc/libffi.texi", 17883), ("mozjs/js/src/ctypes/libffi/doc/stamp-vti", 91), ("mozjs/js/src/ctypes/libffi/doc/version.texi", 91), ("mozjs/js/src/devtools/gc-ubench/benchmarks/bigTextNodes.js", 659), ("mozjs/js/src/devtools/gc-ubench/benchmarks/events.js", 664), ("mozjs/js/src/devtools/gc-ubench/benchmarks/expandoEvents.js", 703), ("mozjs/js/src/devtools/gc-ubench/benchmarks/globalArrayArrayLiteral.js", 489), ("mozjs/js/src/devtools/gc-ubench/benchmarks/globalArrayBuffer.js", 614), ("mozjs/js/src/devtools/gc-ubench/benchmarks/globalArrayFgFinalized.js", 613), ("mozjs/js/src/devtools/gc-ubench/benchmarks/globalArrayLargeArray.js", 507), ("mozjs/js/src/devtools/gc-ubench/benchmarks/globalArrayLargeObject.js", 591), ("mozjs/js/src/devtools/gc-ubench/benchmarks/globalArrayNewObject.js", 480), ("mozjs/js/src/devtools/gc-ubench/benchmarks/globalArrayObjectLiteral.js", 502), ("mozjs/js/src/devtools/gc-ubench/benchmarks/globalArrayReallocArray.js", 499), ("mozjs/js/src/devtools/gc-ubench/benchmarks/largeArrayPropertyAndElements.js", 634), ("mozjs/js/src/devtools/gc-ubench/benchmarks/noAllocation.js", 158), ("mozjs/js/src/devtools/gc-ubench/benchmarks/pairCyclicWeakMap.js", 886), ("mozjs/js/src/devtools/gc-ubench/benchmarks/propertyTreeSplitting.js", 685), ("mozjs/js/src/devtools/gc-ubench/benchmarks/selfCyclicWeakMap.js", 759), ("mozjs/js/src/devtools/gc-ubench/benchmarks/textNodes.js", 596), ("mozjs/js/src/devtools/gc/tests/clock.js", 669), ("mozjs/js/src/devtools/gc/tests/dslots.js", 704), ("mozjs/js/src/devtools/gc/tests/loops.js", 448), ("mozjs/js/src/devtools/gc/tests/objGraph.js", 518), ("mozjs/js/src/devtools/rootAnalysis/build/sixgill-b2g.manifest", 266), ("mozjs/js/src/devtools/rootAnalysis/build/sixgill.manifest", 266), ("mozjs/js/src/gdb/tests/test-ExecutableAllocator.cpp", 903), ("mozjs/js/src/gdb/tests/test-ExecutableAllocator.py", 725), ("mozjs/js/src/gdb/tests/test-GCCellPtr.cpp", 544), ("mozjs/js/src/gdb/tests/test-GCCellPtr.py", 373), ("mozjs/js/src/gdb/tests/test-Interpreter.cpp", 2747), ("mozjs/js/src/gdb/tests/test-Interpreter.py", 703), ("mozjs/js/src/gdb/tests/test-JSObject-null.py", 151), ("mozjs/js/src/gdb/tests/test-JSObject.cpp", 1292), ("mozjs/js/src/gdb/tests/test-JSObject.py", 967), ("mozjs/js/src/gdb/tests/test-JSString-null.py", 151), ("mozjs/js/src/gdb/tests/test-JSString-subclasses.py", 142), ("mozjs/js/src/gdb/tests/test-JSString.cpp", 1811), ("mozjs/js/src/gdb/tests/test-JSString.py", 558), ("mozjs/js/src/gdb/tests/test-JSSymbol.cpp", 528), ("mozjs/js/src/gdb/tests/test-JSSymbol.py", 333), ("mozjs/js/src/gdb/tests/test-Root-null.py", 796), ("mozjs/js/src/gdb/tests/test-Root.cpp", 1454), ("mozjs/js/src/gdb/tests/test-Root.py", 1422), ("mozjs/js/src/gdb/tests/test-asmjs.cpp", 939), ("mozjs/js/src/gdb/tests/test-asmjs.py", 566), ("mozjs/js/src/gdb/tests/test-jsid.cpp", 1475), ("mozjs/js/src/gdb/tests/test-jsid.py", 709), ("mozjs/js/src/gdb/tests/test-jsval.cpp", 1315), ("mozjs/js/src/gdb/tests/test-jsval.py", 949), ("mozjs/js/src/gdb/tests/test-prettyprinters.cpp", 418), ("mozjs/js/src/gdb/tests/test-prettyprinters.py", 1068), ("mozjs/js/src/gdb/tests/test-unwind.cpp", 1825), ("mozjs/js/src/gdb/tests/test-unwind.py", 1997), ("mozjs/js/src/gdb/tests/typedef-printers.cpp", 133), ("mozjs/js/src/gdb/tests/typedef-printers.py", 404), ("mozjs/memory/build/Makefile.in", 374), ("mozjs/memory/build/Mutex.h", 3510), ("mozjs/memory/build/Utils.h", 4252), ("mozjs/memory/build/fallback.cpp", 1399), ("mozjs/memory/build/malloc_decls.h", 5206), ("mozjs/memory/build/moz.build", 1349), ("mozjs/memory/build/mozjemalloc.cpp", 149090), ("mozjs/memory/build/mozjemalloc.h", 2433), ("mozjs/memory/build/mozjemalloc_types.h", 5739), ("mozjs/memory/build/mozmemory.h", 2061), ("mozjs/memory/build/mozmemory_wrap.cpp", 3211), ("mozjs/memory/build/mozmemory_wrap.h", 6004), ("mozjs/memory/build/rb.h", 23825), ("mozjs/memory/build/replace_malloc.h", 4235), ("mozjs/memory/build/replace_malloc_bridge.h", 7909), ("mozjs/memory/build/zone.c", 13755), ("mozjs/mfbt/tests/TestAlgorithm.cpp", 1591), ("mozjs/mfbt/tests/TestArray.cpp", 957), ("mozjs/mfbt/tests/TestArrayUtils.cpp", 11541), ("mozjs/mfbt/tests/TestAtomics.cpp", 9175), ("mozjs/mfbt/tests/TestBinarySearch.cpp", 3902), ("mozjs/mfbt/tests/TestBloomFilter.cpp", 3210), ("mozjs/mfbt/tests/TestBufferList.cpp", 10032), ("mozjs/mfbt/tests/TestCasting.cpp", 4576), ("mozjs/mfbt/tests/TestCeilingFloor.cpp", 2428), ("mozjs/mfbt/tests/TestCheckedInt.cpp", 18896), ("mozjs/mfbt/tests/TestCountPopulation.cpp", 1253), ("mozjs/mfbt/tests/TestCountZeroes.cpp", 4832), ("mozjs/mfbt/tests/TestDefineEnum.cpp", 3024), ("mozjs/mfbt/tests/TestDoublyLinkedList.cpp", 5257), ("mozjs/mfbt/tests/TestEndian.cpp", 20624), ("mozjs/mfbt/tests/TestEnumSet.cpp", 8917), ("mozjs/mfbt/tests/TestEnumTypeTraits.cpp", 4929), ("mozjs/mfbt/tests/TestEnumeratedArray.cpp", 1375), ("mozjs/mfbt/tests/TestFastBernoulliTrial.cpp", 5436), ("mozjs/mfbt/tests/TestFloatingPoint.cpp", 28308), ("mozjs/mfbt/tests/TestFunctionTypeTraits.cpp", 7982), ("mozjs/mfbt/tests/TestIntegerPrintfMacros.cpp", 31998), ("mozjs/mfbt/tests/TestIntegerRange.cpp", 5474), ("mozjs/mfbt/tests/TestJSONWriter.cpp", 12994), ("mozjs/mfbt/tests/TestLinkedList.cpp", 7198), ("mozjs/mfbt/tests/TestMacroArgs.cpp", 1450), ("mozjs/mfbt/tests/TestMacroForEach.cpp", 1883), ("mozjs/mfbt/tests/TestMathAlgorithms.cpp", 4355), ("mozjs/mfbt/tests/TestMaybe.cpp", 36176), ("mozjs/mfbt/tests/TestNonDereferenceable.cpp", 5579), ("mozjs/mfbt/tests/TestNotNull.cpp", 12385), ("mozjs/mfbt/tests/TestPair.cpp", 3793), ("mozjs/mfbt/tests/TestPoisonArea.cpp", 17479), ("mozjs/mfbt/tests/TestRandomNum.cpp", 1700), ("mozjs/mfbt/tests/TestRange.cpp", 786), ("mozjs/mfbt/tests/TestRefPtr.cpp", 2565), ("mozjs/mfbt/tests/TestResult.cpp", 7108), ("mozjs/mfbt/tests/TestRollingMean.cpp", 2523), ("mozjs/mfbt/tests/TestSHA1.cpp", 14084), ("mozjs/mfbt/tests/TestSPSCQueue.cpp", 6290), ("mozjs/mfbt/tests/TestSaturate.cpp", 4302), ("mozjs/mfbt/tests/TestScopeExit.cpp", 1148), ("mozjs/mfbt/tests/TestSegmentedVector.cpp", 10953), ("mozjs/mfbt/tests/TestSmallPointerArray.cpp", 5654), ("mozjs/mfbt/tests/TestSplayTree.cpp", 9569), ("mozjs/mfbt/tests/TestTemplateLib.cpp", 1317), ("mozjs/mfbt/tests/TestTextUtils.cpp", 50342), ("mozjs/mfbt/tests/TestThreadSafeWeakPtr.cpp", 3327), ("mozjs/mfbt/tests/TestTuple.cpp", 6685), ("mozjs/mfbt/tests/TestTypeTraits.cpp", 28708), ("mozjs/mfbt/tests/TestTypedEnum.cpp", 19018), ("mozjs/mfbt/tests/TestUniquePtr.cpp", 12748), ("mozjs/mfbt/tests/TestUtf8.cpp", 26392), ("mozjs/mfbt/tests/TestVariant.cpp", 16275), ("mozjs/mfbt/tests/TestVector.cpp", 14370), ("mozjs/mfbt/tests/TestWeakPtr.cpp", 3576), ("mozjs/mfbt/tests/TestWrappingOperations.cpp", 25913), ("mozjs/mfbt/tests/TestXorShift128PlusRNG.cpp", 3034), ("mozjs/mfbt/tests/gtest/TestBuffer.cpp", 2164), ("mozjs/mfbt/tests/gtest/TestLinkedList.cpp", 1974), ("mozjs/mfbt/tests/gtest/TestSpan.cpp", 48341), ("mozjs/mfbt/tests/gtest/moz.build", 453), ("mozjs/mfbt/tests/moz.build", 2282), ("mozjs/mozglue/build/AsanOptions.cpp", 3197), ("mozjs/mozglue/build/Authenticode.cpp", 13152), ("mozjs/mozglue/build/Authenticode.h", 642), ("mozjs/mozglue/build/BionicGlue.cpp", 4064), ("mozjs/mozglue/build/Makefile.in", 795), ("mozjs/mozglue/build/MozglueUtils.h", 4043), ("mozjs/mozglue/build/SSE.cpp", 5657), ("mozjs/mozglue/build/SSE.h", 10528), ("mozjs/mozglue/build/UbsanOptions.cpp", 450), ("mozjs/mozglue/build/UntrustedDllsHandler.cpp", 9817), ("mozjs/mozglue/build/UntrustedDllsHandler.h", 2607), ("mozjs/mozglue/build/WindowsDllBlocklist.cpp", 33185), ("mozjs/mozglue/build/WindowsDllBlocklist.h", 2227), ("mozjs/mozglue/build/WindowsDllBlocklistCommon.h", 3533), ("mozjs/mozglue/build/WindowsDllBlocklistDefs.h", 8791), ("mozjs/mozglue/build/WindowsDllServices.h", 5942), ("mozjs/mozglue/build/arm-eabi-filter", 28), ("mozjs/mozglue/build/arm.cpp", 4170), ("mozjs/mozglue/build/arm.h", 4546), ("mozjs/mozglue/build/cygprofile.cpp", 3271), ("mozjs/mozglue/build/dummy.cpp", 227), ("mozjs/mozglue/build/mips.cpp", 1109), ("mozjs/mozglue/build/mips.h", 684), ("mozjs/mozglue/build/moz.build", 3703), ("mozjs/mozglue/build/mozglue.def.in", 654), ("mozjs/mozglue/build/mozglue.dll.manifest", 248), ("mozjs/mozglue/build/mozglue.rc", 314), ("mozjs/mozglue/build/replace_malloc.mk", 291), ("mozjs/nsprpub/build/autoconf/README", 323), ("mozjs/nsprpub/build/autoconf/config.guess", 42846), ("mozjs/nsprpub/build/autoconf/config.sub", 35525), ("mozjs/nsprpub/build/autoconf/install-sh", 2386), ("mozjs/nsprpub/build/autoconf/patches/config.sub.patch", 1665), ("mozjs/nsprpub/build/cygwin-wrapper", 2329), ("mozjs/nsprpub/build/win32/pgomerge.py", 1832), ("mozjs/nsprpub/lib/prstreams/tests/testprstrm/.cvsignore", 9), ("mozjs/nsprpub/lib/prstreams/tests/testprstrm/Makefile.in", 4150), ("mozjs/nsprpub/lib/prstreams/tests/testprstrm/testprstrm.cpp", 4256), ("mozjs/nsprpub/lib/tests/.cvsignore", 9), ("mozjs/nsprpub/lib/tests/Makefile.in", 4231), ("mozjs/nsprpub/lib/tests/arena.c", 10057), ("mozjs/nsprpub/lib/tests/base64t.c", 126716), ("mozjs/nsprpub/lib/tests/getopt.c", 1237), ("mozjs/nsprpub/lib/tests/string.c", 106271), ("mozjs/nsprpub/pr/src/cplus/tests/.cvsignore", 9), ("mozjs/nsprpub/pr/src/cplus/tests/Makefile.in", 5465), ("mozjs/nsprpub/pr/src/cplus/tests/fileio.cpp", 823), ("mozjs/nsprpub/pr/src/cplus/tests/interval.cpp", 2678), ("mozjs/nsprpub/pr/src/cplus/tests/ranfile.cpp", 13410), ("mozjs/nsprpub/pr/src/cplus/tests/switch.cpp", 6219), ("mozjs/nsprpub/pr/src/cplus/tests/thread.cpp", 2498), ("mozjs/nsprpub/pr/src/cplus/tests/time.cpp", 646), ("mozjs/nsprpub/pr/src/cplus/tests/tpd.cpp", 8612), ("mozjs/nsprpub/pr/tests/.cvsignore", 9), ("mozjs/nsprpub/pr/tests/Makefile.in", 10897), ("mozjs/nsprpub/pr/tests/README.TXT", 7372), ("mozjs/nsprpub/pr/tests/abstract.c", 3507), ("mozjs/nsprpub/pr/tests/accept.c", 15158), ("mozjs/nsprpub/pr/tests/acceptread.c", 7501), ("mozjs/nsprpub/pr/tests/acceptreademu.c", 8603), ("mozjs/nsprpub/pr/tests/addrstr.c", 2219), ("mozjs/nsprpub/pr/tests/affinity.c", 1961), ("mozjs/nsprpub/pr/tests/alarm.c", 13325), ("mozjs/nsprpub/pr/tests/anonfm.c", 8057), ("mozjs/nsprpub/pr/tests/append.c", 4154), ("mozjs/nsprpub/pr/tests/atomic.c", 6283), ("mozjs/nsprpub/pr/tests/attach.c", 8579), ("mozjs/nsprpub/pr/tests/bigfile.c", 10172), ("mozjs/nsprpub/pr/tests/bigfile2.c", 2706), ("mozjs/nsprpub/pr/tests/bigfile3.c", 2664), ("mozjs/nsprpub/pr/tests/bug1test.c", 5758), ("mozjs/nsprpub/pr/tests/cleanup.c", 3449), ("mozjs/nsprpub/pr/tests/cltsrv.c", 38934), ("mozjs/nsprpub/pr/tests/concur.c", 3830), ("mozjs/nsprpub/pr/tests/cvar.c", 6919), ("mozjs/nsprpub/pr/tests/cvar2.c", 29460), ("mozjs/nsprpub/pr/tests/dbmalloc.c", 7442), ("mozjs/nsprpub/pr/tests/dbmalloc1.c", 2486), ("mozjs/nsprpub/pr/tests/dceemu.c", 2789), ("mozjs/nsprpub/pr/tests/depend.c", 4070), ("mozjs/nsprpub/pr/tests/dll/.cvsignore", 9), ("mozjs/nsprpub/pr/tests/dll/Makefile.in", 1613), ("mozjs/nsprpub/pr/tests/dll/my.def", 1115), ("mozjs/nsprpub/pr/tests/dll/mygetval.c", 618), ("mozjs/nsprpub/pr/tests/dll/mysetval.c", 399), ("mozjs/nsprpub/pr/tests/dlltest.c", 6106), ("mozjs/nsprpub/pr/tests/dtoa.c", 6103), ("mozjs/nsprpub/pr/tests/env.c", 11325), ("mozjs/nsprpub/pr/tests/errcodes.c", 5440), ("mozjs/nsprpub/pr/tests/errset.c", 6209), ("mozjs/nsprpub/pr/tests/exit.c", 2821), ("mozjs/nsprpub/pr/tests/fdcach.c", 5960), ("mozjs/nsprpub/pr/tests/fileio.c", 4917), ("mozjs/nsprpub/pr/tests/foreign.c", 9620), ("mozjs/nsprpub/pr/tests/forktest.c", 7515), ("mozjs/nsprpub/pr/tests/formattm.c", 1493), ("mozjs/nsprpub/pr/tests/freeif.c", 929), ("mozjs/nsprpub/pr/tests/fsync.c", 3726), ("mozjs/nsprpub/pr/tests/getai.c", 958), ("mozjs/nsprpub/pr/tests/gethost.c", 8008), ("mozjs/nsprpub/pr/tests/getproto.c", 2691), ("mozjs/nsprpub/pr/tests/i2l.c", 2407), ("mozjs/nsprpub/pr/tests/initclk.c", 2287), ("mozjs/nsprpub/pr/tests/inrval.c", 6637), ("mozjs/nsprpub/pr/tests/instrumt.c", 12412), ("mozjs/nsprpub/pr/tests/intrio.c", 3840), ("mozjs/nsprpub/pr/tests/intrupt.c", 8955), ("mozjs/nsprpub/pr/tests/io_timeout.c", 6789), ("mozjs/nsprpub/pr/tests/io_timeoutk.c", 5699), ("mozjs/nsprpub/pr/tests/io_timeoutu.c", 5699), ("mozjs/nsprpub/pr/tests/ioconthr.c", 3647), ("mozjs/nsprpub/pr/tests/ipv6.c", 7011), ("mozjs/nsprpub/pr/tests/join.c", 6350), ("mozjs/nsprpub/pr/tests/joinkk.c", 4026), ("mozjs/nsprpub/pr/tests/joinku.c", 4099), ("mozjs/nsprpub/pr/tests/joinuk.c", 4059), ("mozjs/nsprpub/pr/tests/joinuu.c", 4065), ("mozjs/nsprpub/pr/tests/layer.c", 15120), ("mozjs/nsprpub/pr/tests/lazyinit.c", 2815), ("mozjs/nsprpub/pr/tests/libfilename.c", 2734), ("mozjs/nsprpub/pr/tests/lltest.c", 25718), ("mozjs/nsprpub/pr/tests/lock.c", 15712), ("mozjs/nsprpub/pr/tests/lockfile.c", 6997), ("mozjs/nsprpub/pr/tests/logfile.c", 1089), ("mozjs/nsprpub/pr/tests/logger.c", 3004), ("mozjs/nsprpub/pr/tests/makedir.c", 1993), ("mozjs/nsprpub/pr/tests/many_cv.c", 3152), ("mozjs/nsprpub/pr/tests/mbcs.c", 5021), ("mozjs/nsprpub/pr/tests/monref.c", 1810), ("mozjs/nsprpub/pr/tests/multiacc.c", 6671), ("mozjs/nsprpub/pr/tests/multiwait.c", 21809), ("mozjs/nsprpub/pr/tests/nameshm1.c", 16903), ("mozjs/nsprpub/pr/tests/nbconn.c", 14022), ("mozjs/nsprpub/pr/tests/nblayer.c", 23919), ("mozjs/nsprpub/pr/tests/nonblock.c", 6465), ("mozjs/nsprpub/pr/tests/ntioto.c", 8228), ("mozjs/nsprpub/pr/tests/ntoh.c", 2669), ("mozjs/nsprpub/pr/tests/obsints.c", 1087), ("mozjs/nsprpub/pr/tests/op_2long.c", 1789), ("mozjs/nsprpub/pr/tests/op_excl.c", 3324), ("mozjs/nsprpub/pr/tests/op_filnf.c", 1504), ("mozjs/nsprpub/pr/tests/op_filok.c", 1363), ("mozjs/nsprpub/pr/tests/op_noacc.c", 1488), ("mozjs/nsprpub/pr/tests/op_nofil.c", 1615), ("mozjs/nsprpub/pr/tests/openfile.c", 3206), ("mozjs/nsprpub/pr/tests/parent.c", 3288), ("mozjs/nsprpub/pr/tests/parsetm.c", 2508), ("mozjs/nsprpub/pr/tests/peek.c", 11300), ("mozjs/nsprpub/pr/tests/perf.c", 11403), ("mozjs/nsprpub/pr/tests/pipeping.c", 4613), ("mozjs/nsprpub/pr/tests/pipeping2.c", 4682), ("mozjs/nsprpub/pr/tests/pipepong.c", 1814), ("mozjs/nsprpub/pr/tests/pipepong2.c", 2865), ("mozjs/nsprpub/pr/tests/pipeself.c", 6463), ("mozjs/nsprpub/pr/tests/poll_er.c", 6056), ("mozjs/nsprpub/pr/tests/poll_nm.c", 10017), ("mozjs/nsprpub/pr/tests/poll_to.c", 5422), ("mozjs/nsprpub/pr/tests/pollable.c", 7998), ("mozjs/nsprpub/pr/tests/prfdbl.c", 896), ("mozjs/nsprpub/pr/tests/prftest.c", 1561), ("mozjs/nsprpub/pr/tests/prftest1.c", 3310), ("mozjs/nsprpub/pr/tests/prftest2.c", 2750), ("mozjs/nsprpub/pr/tests/prfz.c", 2323), ("mozjs/nsprpub/pr/tests/primblok.c", 3071), ("mozjs/nsprpub/pr/tests/priotest.c", 5477), ("mozjs/nsprpub/pr/tests/provider.c", 43587), ("mozjs/nsprpub/pr/tests/prpoll.c", 9731), ("mozjs/nsprpub/pr/tests/prpollml.c", 4383), ("mozjs/nsprpub/pr/tests/prselect.c", 9673), ("mozjs/nsprpub/pr/tests/prttools.h", 384), ("mozjs/nsprpub/pr/tests/pushtop.c", 1981), ("mozjs/nsprpub/pr/tests/randseed.c", 3373), ("mozjs/nsprpub/pr/tests/ranfile.c", 13254), ("mozjs/nsprpub/pr/tests/reinit.c", 939), ("mozjs/nsprpub/pr/tests/rmdir.c", 2461), ("mozjs/nsprpub/pr/tests/runtests.pl", 7847), ("mozjs/nsprpub/pr/tests/runtests.sh", 4761), ("mozjs/nsprpub/pr/tests/runy2ktests.ksh", 3604), ("mozjs/nsprpub/pr/tests/rwlockrank.c", 3041), ("mozjs/nsprpub/pr/tests/rwlocktest.c", 4769), ("mozjs/nsprpub/pr/tests/sel_spd.c", 13863), ("mozjs/nsprpub/pr/tests/selct_er.c", 5738), ("mozjs/nsprpub/pr/tests/selct_nm.c", 7860), ("mozjs/nsprpub/pr/tests/selct_to.c", 5124), ("mozjs/nsprpub/pr/tests/select2.c", 8741), ("mozjs/nsprpub/pr/tests/selintr.c", 1266), ("mozjs/nsprpub/pr/tests/sem.c", 5263), ("mozjs/nsprpub/pr/tests/sema.c", 4517), ("mozjs/nsprpub/pr/tests/semaerr.c", 3524), ("mozjs/nsprpub/pr/tests/semaerr1.c", 3219), ("mozjs/nsprpub/pr/tests/semaping.c", 5714), ("mozjs/nsprpub/pr/tests/semapong.c", 3733), ("mozjs/nsprpub/pr/tests/sendzlf.c", 6609), ("mozjs/nsprpub/pr/tests/server_test.c", 17573), ("mozjs/nsprpub/pr/tests/servr_kk.c", 16825), ("mozjs/nsprpub/pr/tests/servr_ku.c", 16466), ("mozjs/nsprpub/pr/tests/servr_uk.c", 16451), ("mozjs/nsprpub/pr/tests/servr_uu.c", 16470), ("mozjs/nsprpub/pr/tests/short_thread.c", 1454), ("mozjs/nsprpub/pr/tests/sigpipe.c", 2300), ("mozjs/nsprpub/pr/tests/sleep.c", 2794), ("mozjs/nsprpub/pr/tests/socket.c", 70489), ("mozjs/nsprpub/pr/tests/sockopt.c", 5829), ("mozjs/nsprpub/pr/tests/sockping.c", 3822), ("mozjs/nsprpub/pr/tests/sockpong.c", 2354), ("mozjs/nsprpub/pr/tests/sprintf.c", 11275), ("mozjs/nsprpub/pr/tests/sproc_ch.c", 2256), ("mozjs/nsprpub/pr/tests/sproc_p.c", 1790), ("mozjs/nsprpub/pr/tests/stack.c", 6865), ("mozjs/nsprpub/pr/tests/stat.c", 1817), ("mozjs/nsprpub/pr/tests/stdio.c", 1437), ("mozjs/nsprpub/pr/tests/str2addr.c", 1442), ("mozjs/nsprpub/pr/tests/strod.c", 2133), ("mozjs/nsprpub/pr/tests/suspend.c", 4531), ("mozjs/nsprpub/pr/tests/switch.c", 6528), ("mozjs/nsprpub/pr/tests/system.c", 1568), ("mozjs/nsprpub/pr/tests/testbit.c", 2296), ("mozjs/nsprpub/pr/tests/testfile.c", 22141), ("mozjs/nsprpub/pr/tests/threads.c", 5021), ("mozjs/nsprpub/pr/tests/thrpool_client.c", 9586), ("mozjs/nsprpub/pr/tests/thrpool_server.c", 14913), ("mozjs/nsprpub/pr/tests/thruput.c", 11926), ("mozjs/nsprpub/pr/tests/time.c", 3742), ("mozjs/nsprpub/pr/tests/timemac.c", 3285), ("mozjs/nsprpub/pr/tests/timetest.c", 21097), ("mozjs/nsprpub/pr/tests/tmoacc.c", 9753), ("mozjs/nsprpub/pr/tests/tmocon.c", 12582), ("mozjs/nsprpub/pr/tests/tpd.c", 7672), ("mozjs/nsprpub/pr/tests/udpsrv.c", 15987), ("mozjs/nsprpub/pr/tests/ut_ttools.h", 384), ("mozjs/nsprpub/pr/tests/vercheck.c", 2881), ("mozjs/nsprpub/pr/tests/version.c", 2856), ("mozjs/nsprpub/pr/tests/writev.c", 6705), ("mozjs/nsprpub/pr/tests/xnotify.c", 10154), ("mozjs/nsprpub/pr/tests/y2k.c", 35372), ("mozjs/nsprpub/pr/tests/y2ktmo.c", 16862), ("mozjs/nsprpub/pr/tests/yield.c", 1429), ("mozjs/nsprpub/pr/tests/zerolen.c", 7740), ("mozjs/python/devtools/migrate-l10n/migrate/tests/__init__.py", 0), ("mozjs/python/docs/index.rst", 3260), ("mozjs/python/mach/docs/commands.rst", 5222), ("mozjs/python/mach/docs/driver.rst", 1489), ("mozjs/python/mach/docs/index.rst", 2472), ("mozjs/python/mach/docs/logging.rst", 3486), ("mozjs/python/mach/docs/settings.rst", 3978), ("mozjs/python/mach/docs/usage.rst", 2512), ("mozjs/python/mach/mach/test/__init__.py", 0), ("mozjs/python/mach/mach/test/common.py", 1339), ("mozjs/python/mach/mach/test/invoke_mach_command.py", 85), ("mozjs/python/mach/mach/test/providers/__init__.py", 0), ("mozjs/python/mach/mach/test/providers/basic.py", 644), ("mozjs/python/mach/mach/test/providers/conditions.py", 1328), ("mozjs/python/mach/mach/test/providers/conditions_invalid.py", 464), ("mozjs/python/mach/mach/test/providers/throw.py", 775), ("mozjs/python/mach/mach/test/providers/throw2.py", 482), ("mozjs/python/mach/mach/test/python.ini", 174), ("mozjs/python/mach/mach/test/registrar_dispatch.py", 188), ("mozjs/python/mach/mach/test/test_conditions.py", 2905), ("mozjs/python/mach/mach/test/test_config.py", 6850), ("mozjs/python/mach/mach/test/test_dispatcher.py", 1674), ("mozjs/python/mach/mach/test/test_entry_point.py", 1843), ("mozjs/python/mach/mach/test/test_error_output.py", 1043), ("mozjs/python/mach/mach/test/test_logger.py", 1251), ("mozjs/python/mach/mach/test/test_telemetry.py", 5729), ("mozjs/python/mach/mach/test/zero_microseconds.py", 480), ("mozjs/python/mozboot/mozboot/test/python.ini", 56), ("mozjs/python/mozboot/mozboot/test/test_write_config.py", 2318), ("mozjs/python/mozbuild/dumbmake/test/__init__.py", 0), ("mozjs/python/mozbuild/dumbmake/test/python.ini", 52), ("mozjs/python/mozbuild/dumbmake/test/test_dumbmake.py", 5677), ("mozjs/python/mozbuild/mozbuild/test/__init__.py", 0), ("mozjs/python/mozbuild/mozbuild/test/action/data/invalid/region.properties", 808), ("mozjs/python/mozbuild/mozbuild/test/action/data/node/node-test-script.js", 162), ("mozjs/python/mozbuild/mozbuild/test/action/data/package_fennec_apk/assets/asset.txt", 17), ("mozjs/python/mozbuild/mozbuild/test/action/data/package_fennec_apk/input1.ap_", 503), ("mozjs/python/mozbuild/mozbuild/test/action/data/package_fennec_apk/input1/res/res.txt", 19), ("mozjs/python/mozbuild/mozbuild/test/action/data/package_fennec_apk/input1/resources.arsc", 21), ("mozjs/python/mozbuild/mozbuild/test/action/data/package_fennec_apk/input2.apk", 1649), ("mozjs/python/mozbuild/mozbuild/test/action/data/package_fennec_apk/input2/assets/asset.txt", 24), ("mozjs/python/mozbuild/mozbuild/test/action/data/package_fennec_apk/input2/assets/omni.ja", 21), ("mozjs/python/mozbuild/mozbuild/test/action/data/package_fennec_apk/input2/classes.dex", 18), ("mozjs/python/mozbuild/mozbuild/test/action/data/package_fennec_apk/input2/lib/lib.txt", 19), ("mozjs/python/mozbuild/mozbuild/test/action/data/package_fennec_apk/input2/res/res.txt", 19), ("mozjs/python/mozbuild/mozbuild/test/action/data/package_fennec_apk/input2/resources.arsc", 20), ("mozjs/python/mozbuild/mozbuild/test/action/data/package_fennec_apk/input2/root_file.txt", 21), ("mozjs/python/mozbuild/mozbuild/test/action/data/package_fennec_apk/lib/lib.txt", 12), ("mozjs/python/mozbuild/mozbuild/test/action/data/package_fennec_apk/omni.ja", 7), ("mozjs/python/mozbuild/mozbuild/test/action/data/package_fennec_apk/root_file.txt", 14), ("mozjs/python/mozbuild/mozbuild/test/action/data/valid-zh-CN/region.properties", 1770), ("mozjs/python/mozbuild/mozbuild/test/action/test_buildlist.py", 2919), ("mozjs/python/mozbuild/mozbuild/test/action/test_langpack_manifest.py", 1844), ("mozjs/python/mozbuild/mozbuild/test/action/test_node.py", 2076), ("mozjs/python/mozbuild/mozbuild/test/action/test_package_fennec_apk.py", 2255), ("mozjs/python/mozbuild/mozbuild/test/action/test_process_install_manifest.py", 2055), ("mozjs/python/mozbuild/mozbuild/test/analyze/test_graph.py", 5646), ("mozjs/python/mozbuild/mozbuild/test/backend/__init__.py", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/common.py", 7720), ("mozjs/python/mozbuild/mozbuild/test/backend/data/build/app/moz.build", 677), ("mozjs/python/mozbuild/mozbuild/test/backend/data/build/bar.ini", 8), ("mozjs/python/mozbuild/mozbuild/test/backend/data/build/bar.js", 42), ("mozjs/python/mozbuild/mozbuild/test/backend/data/build/bar.jsm", 8), ("mozjs/python/mozbuild/mozbuild/test/backend/data/build/baz.ini", 43), ("mozjs/python/mozbuild/mozbuild/test/backend/data/build/baz.jsm", 43), ("mozjs/python/mozbuild/mozbuild/test/backend/data/build/components.manifest", 46), ("mozjs/python/mozbuild/mozbuild/test/backend/data/build/foo.css", 43), ("mozjs/python/mozbuild/mozbuild/test/backend/data/build/foo.ini", 8), ("mozjs/python/mozbuild/mozbuild/test/backend/data/build/foo.js", 7), ("mozjs/python/mozbuild/mozbuild/test/backend/data/build/foo.jsm", 8), ("mozjs/python/mozbuild/mozbuild/test/backend/data/build/jar.mn", 247), ("mozjs/python/mozbuild/mozbuild/test/backend/data/build/moz.build", 786), ("mozjs/python/mozbuild/mozbuild/test/backend/data/build/prefs.js", 9), ("mozjs/python/mozbuild/mozbuild/test/backend/data/build/qux.ini", 76), ("mozjs/python/mozbuild/mozbuild/test/backend/data/build/qux.jsm", 76), ("mozjs/python/mozbuild/mozbuild/test/backend/data/build/resource", 9), ("mozjs/python/mozbuild/mozbuild/test/backend/data/build/resource2", 10), ("mozjs/python/mozbuild/mozbuild/test/backend/data/build/subdir/bar.js", 7), ("mozjs/python/mozbuild/mozbuild/test/backend/data/defines/moz.build", 250), ("mozjs/python/mozbuild/mozbuild/test/backend/data/dist-files/install.rdf", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/dist-files/main.js", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/dist-files/moz.build", 228), ("mozjs/python/mozbuild/mozbuild/test/backend/data/exports-generated/dom1.h", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/exports-generated/foo.h", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/exports-generated/gfx.h", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/exports-generated/moz.build", 389), ("mozjs/python/mozbuild/mozbuild/test/backend/data/exports-generated/mozilla1.h", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/exports/dom1.h", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/exports/dom2.h", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/exports/foo.h", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/exports/gfx.h", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/exports/moz.build", 286), ("mozjs/python/mozbuild/mozbuild/test/backend/data/exports/mozilla1.h", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/exports/mozilla2.h", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/exports/pprio.h", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/final-target-files-wildcard/bar.xyz", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/final-target-files-wildcard/foo.xyz", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/final-target-files-wildcard/moz.build", 236), ("mozjs/python/mozbuild/mozbuild/test/backend/data/final_target/both/moz.build", 213), ("mozjs/python/mozbuild/mozbuild/test/backend/data/final_target/dist-subdir/moz.build", 189), ("mozjs/python/mozbuild/mozbuild/test/backend/data/final_target/final-target/moz.build", 202), ("mozjs/python/mozbuild/mozbuild/test/backend/data/final_target/moz.build", 225), ("mozjs/python/mozbuild/mozbuild/test/backend/data/final_target/xpi-name/moz.build", 189), ("mozjs/python/mozbuild/mozbuild/test/backend/data/generated-files-force/foo-data", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/generated-files-force/generate-bar.py", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/generated-files-force/generate-foo.py", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/generated-files-force/moz.build", 406), ("mozjs/python/mozbuild/mozbuild/test/backend/data/generated-files/foo-data", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/generated-files/generate-bar.py", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/generated-files/generate-foo.py", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/generated-files/moz.build", 371), ("mozjs/python/mozbuild/mozbuild/test/backend/data/generated_includes/moz.build", 205), ("mozjs/python/mozbuild/mozbuild/test/backend/data/gn-processor/gn-configs/x64_False_x64_linux.json", 946), ("mozjs/python/mozbuild/mozbuild/test/backend/data/gn-processor/gn-configs/x64_False_x64_mac.json", 1217), ("mozjs/python/mozbuild/mozbuild/test/backend/data/gn-processor/gn-configs/x64_True_x64_linux.json", 934), ("mozjs/python/mozbuild/mozbuild/test/backend/data/gn-processor/ipc/chromium/src/header.h", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/gn-processor/ipc/glue/header.h", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/gn-processor/moz.build", 1200), ("mozjs/python/mozbuild/mozbuild/test/backend/data/gn-processor/trunk/moz.build", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/gn-processor/trunk/webrtc/build/function.cc", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/gn-processor/trunk/webrtc/build/no_op_function.cc", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/gn-processor/trunk/webrtc/modules/include/header.h", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/host-defines/moz.build", 265), ("mozjs/python/mozbuild/mozbuild/test/backend/data/host-rust-library-features/moz.build", 472), ("mozjs/python/mozbuild/mozbuild/test/backend/data/host-rust-library/moz.build", 439), ("mozjs/python/mozbuild/mozbuild/test/backend/data/install_substitute_config_files/moz.build", 255), ("mozjs/python/mozbuild/mozbuild/test/backend/data/install_substitute_config_files/sub/foo.h.in", 26), ("mozjs/python/mozbuild/mozbuild/test/backend/data/install_substitute_config_files/sub/moz.build", 226), ("mozjs/python/mozbuild/mozbuild/test/backend/data/ipdl_sources/bar/moz.build", 394), ("mozjs/python/mozbuild/mozbuild/test/backend/data/ipdl_sources/foo/moz.build", 394), ("mozjs/python/mozbuild/mozbuild/test/backend/data/ipdl_sources/moz.build", 323), ("mozjs/python/mozbuild/mozbuild/test/backend/data/jar-manifests/moz.build", 318), ("mozjs/python/mozbuild/mozbuild/test/backend/data/linkage/moz.build", 202), ("mozjs/python/mozbuild/mozbuild/test/backend/data/linkage/prog/moz.build", 181), ("mozjs/python/mozbuild/mozbuild/test/backend/data/linkage/prog/qux/moz.build", 147), ("mozjs/python/mozbuild/mozbuild/test/backend/data/linkage/prog/qux/qux1.c", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/linkage/real/foo/foo1.c", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/linkage/real/foo/foo2.c", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/linkage/real/foo/moz.build", 168), ("mozjs/python/mozbuild/mozbuild/test/backend/data/linkage/real/moz.build", 215), ("mozjs/python/mozbuild/mozbuild/test/backend/data/linkage/shared/baz/baz1.c", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/linkage/shared/baz/moz.build", 148), ("mozjs/python/mozbuild/mozbuild/test/backend/data/linkage/shared/moz.build", 245), ("mozjs/python/mozbuild/mozbuild/test/backend/data/linkage/static/bar/bar1.cc", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/linkage/static/bar/bar2.cc", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/linkage/static/bar/bar_helper/bar_helper1.cpp", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/linkage/static/bar/bar_helper/moz.build", 164), ("mozjs/python/mozbuild/mozbuild/test/backend/data/linkage/static/bar/moz.build", 202), ("mozjs/python/mozbuild/mozbuild/test/backend/data/linkage/static/moz.build", 186), ("mozjs/python/mozbuild/mozbuild/test/backend/data/linkage/templates.mozbuild", 394), ("mozjs/python/mozbuild/mozbuild/test/backend/data/local_includes/bar/baz/dummy_file_for_nonempty_directory", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/local_includes/foo/dummy_file_for_nonempty_directory", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/local_includes/moz.build", 203), ("mozjs/python/mozbuild/mozbuild/test/backend/data/localized-files/en-US/bar.ini", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/localized-files/en-US/foo.js", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/localized-files/moz.build", 252), ("mozjs/python/mozbuild/mozbuild/test/backend/data/localized-generated-files-AB_CD/en-US/localized-input", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/localized-generated-files-AB_CD/foo-data", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/localized-generated-files-AB_CD/generate-foo.py", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/localized-generated-files-AB_CD/inner/locales/en-US/localized-input", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/localized-generated-files-AB_CD/locales/en-US/localized-input", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/localized-generated-files-AB_CD/moz.build", 865), ("mozjs/python/mozbuild/mozbuild/test/backend/data/localized-generated-files-AB_CD/non-localized-input", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/localized-generated-files-force/en-US/localized-input", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/localized-generated-files-force/foo-data", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/localized-generated-files-force/generate-foo.py", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/localized-generated-files-force/moz.build", 565), ("mozjs/python/mozbuild/mozbuild/test/backend/data/localized-generated-files-force/non-localized-input", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/localized-generated-files/en-US/localized-input", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/localized-generated-files/foo-data", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/localized-generated-files/generate-foo.py", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/localized-generated-files/moz.build", 459), ("mozjs/python/mozbuild/mozbuild/test/backend/data/localized-generated-files/non-localized-input", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/localized-pp-files/en-US/bar.ini", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/localized-pp-files/en-US/foo.js", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/localized-pp-files/moz.build", 232), ("mozjs/python/mozbuild/mozbuild/test/backend/data/prog-lib-c-only/c-library/c-library.c", 105), ("mozjs/python/mozbuild/mozbuild/test/backend/data/prog-lib-c-only/c-library/moz.build", 219), ("mozjs/python/mozbuild/mozbuild/test/backend/data/prog-lib-c-only/c-program/c_test_program.c", 105), ("mozjs/python/mozbuild/mozbuild/test/backend/data/prog-lib-c-only/c-program/moz.build", 223), ("mozjs/python/mozbuild/mozbuild/test/backend/data/prog-lib-c-only/c-simple-programs/c_simple_program.c", 105), ("mozjs/python/mozbuild/mozbuild/test/backend/data/prog-lib-c-only/c-simple-programs/moz.build", 212), ("mozjs/python/mozbuild/mozbuild/test/backend/data/prog-lib-c-only/cxx-library/c-source.c", 105), ("mozjs/python/mozbuild/mozbuild/test/backend/data/prog-lib-c-only/cxx-library/cxx-library.cpp", 105), ("mozjs/python/mozbuild/mozbuild/test/backend/data/prog-lib-c-only/cxx-library/moz.build", 250), ("mozjs/python/mozbuild/mozbuild/test/backend/data/prog-lib-c-only/cxx-program/cxx_test_program.cpp", 105), ("mozjs/python/mozbuild/mozbuild/test/backend/data/prog-lib-c-only/cxx-program/moz.build", 229), ("mozjs/python/mozbuild/mozbuild/test/backend/data/prog-lib-c-only/cxx-simple-programs/cxx_simple_program.cpp", 105), ("mozjs/python/mozbuild/mozbuild/test/backend/data/prog-lib-c-only/cxx-simple-programs/moz.build", 204), ("mozjs/python/mozbuild/mozbuild/test/backend/data/prog-lib-c-only/moz.build", 622), ("mozjs/python/mozbuild/mozbuild/test/backend/data/prog-lib-c-only/simple-programs/moz.build", 165), ("mozjs/python/mozbuild/mozbuild/test/backend/data/program-paths/dist-bin/moz.build", 123), ("mozjs/python/mozbuild/mozbuild/test/backend/data/program-paths/dist-subdir/moz.build", 146), ("mozjs/python/mozbuild/mozbuild/test/backend/data/program-paths/final-target/moz.build", 158), ("mozjs/python/mozbuild/mozbuild/test/backend/data/program-paths/moz.build", 241), ("mozjs/python/mozbuild/mozbuild/test/backend/data/program-paths/not-installed/moz.build", 149), ("mozjs/python/mozbuild/mozbuild/test/backend/data/resources/bar.res.in", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/resources/cursor.cur", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/resources/desktop1.ttf", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/resources/desktop2.ttf", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/resources/extra.manifest", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/resources/font1.ttf", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/resources/font2.ttf", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/resources/foo.res", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/resources/mobile.ttf", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/resources/moz.build", 411), ("mozjs/python/mozbuild/mozbuild/test/backend/data/resources/test.manifest", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/rust-library-features/moz.build", 424), ("mozjs/python/mozbuild/mozbuild/test/backend/data/rust-library/moz.build", 341), ("mozjs/python/mozbuild/mozbuild/test/backend/data/rust-programs/code/moz.build", 224), ("mozjs/python/mozbuild/mozbuild/test/backend/data/rust-programs/moz.build", 182), ("mozjs/python/mozbuild/mozbuild/test/backend/data/sources/bar.c", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/sources/bar.cpp", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/sources/bar.mm", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/sources/bar.s", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/sources/baz.S", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/sources/foo.S", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/sources/foo.asm", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/sources/foo.c", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/sources/foo.cpp", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/sources/foo.mm", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/sources/moz.build", 473), ("mozjs/python/mozbuild/mozbuild/test/backend/data/stub0/Makefile.in", 115), ("mozjs/python/mozbuild/mozbuild/test/backend/data/stub0/dir1/Makefile.in", 179), ("mozjs/python/mozbuild/mozbuild/test/backend/data/stub0/dir1/moz.build", 166), ("mozjs/python/mozbuild/mozbuild/test/backend/data/stub0/dir2/moz.build", 165), ("mozjs/python/mozbuild/mozbuild/test/backend/data/stub0/dir3/Makefile.in", 179), ("mozjs/python/mozbuild/mozbuild/test/backend/data/stub0/dir3/moz.build", 165), ("mozjs/python/mozbuild/mozbuild/test/backend/data/stub0/moz.build", 221), ("mozjs/python/mozbuild/mozbuild/test/backend/data/substitute_config_files/Makefile.in", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/substitute_config_files/foo.in", 17), ("mozjs/python/mozbuild/mozbuild/test/backend/data/substitute_config_files/moz.build", 197), ("mozjs/python/mozbuild/mozbuild/test/backend/data/test-manifest-shared-support/child/another-file.sjs", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/test-manifest-shared-support/child/browser.ini", 69), ("mozjs/python/mozbuild/mozbuild/test/backend/data/test-manifest-shared-support/child/data/one.txt", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/test-manifest-shared-support/child/data/two.txt", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/test-manifest-shared-support/child/test_sub.js", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/test-manifest-shared-support/mochitest.ini", 127), ("mozjs/python/mozbuild/mozbuild/test/backend/data/test-manifest-shared-support/moz.build", 195), ("mozjs/python/mozbuild/mozbuild/test/backend/data/test-manifest-shared-support/support-file.txt", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/test-manifest-shared-support/test_foo.js", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/test-manifests-backend-sources/mochitest-common.ini", 14), ("mozjs/python/mozbuild/mozbuild/test/backend/data/test-manifests-backend-sources/mochitest.ini", 45), ("mozjs/python/mozbuild/mozbuild/test/backend/data/test-manifests-backend-sources/moz.build", 152), ("mozjs/python/mozbuild/mozbuild/test/backend/data/test-manifests-backend-sources/test_bar.js", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/test-manifests-backend-sources/test_foo.js", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/test-manifests-duplicate-support-files/mochitest1.ini", 58), ("mozjs/python/mozbuild/mozbuild/test/backend/data/test-manifests-duplicate-support-files/mochitest2.ini", 58), ("mozjs/python/mozbuild/mozbuild/test/backend/data/test-manifests-duplicate-support-files/moz.build", 175), ("mozjs/python/mozbuild/mozbuild/test/backend/data/test-manifests-duplicate-support-files/test_bar.js", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/test-manifests-duplicate-support-files/test_foo.js", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/test-manifests-package-tests/instrumentation.ini", 20), ("mozjs/python/mozbuild/mozbuild/test/backend/data/test-manifests-package-tests/mochitest.ini", 15), ("mozjs/python/mozbuild/mozbuild/test/backend/data/test-manifests-package-tests/mochitest.js", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/test-manifests-package-tests/moz.build", 217), ("mozjs/python/mozbuild/mozbuild/test/backend/data/test-manifests-package-tests/not_packaged.java", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/test-manifests-written/dir1/test_bar.js", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/test-manifests-written/dir1/xpcshell.ini", 25), ("mozjs/python/mozbuild/mozbuild/test/backend/data/test-manifests-written/mochitest.ini", 26), ("mozjs/python/mozbuild/mozbuild/test/backend/data/test-manifests-written/mochitest.js", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/test-manifests-written/moz.build", 219), ("mozjs/python/mozbuild/mozbuild/test/backend/data/test-manifests-written/xpcshell.ini", 52), ("mozjs/python/mozbuild/mozbuild/test/backend/data/test-manifests-written/xpcshell.js", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/test-support-binaries-tracked/moz.build", 189), ("mozjs/python/mozbuild/mozbuild/test/backend/data/test-support-binaries-tracked/src/moz.build", 266), ("mozjs/python/mozbuild/mozbuild/test/backend/data/test-support-binaries-tracked/test/moz.build", 779), ("mozjs/python/mozbuild/mozbuild/test/backend/data/test-support-binaries-tracked/test/test-one.cpp", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/test-support-binaries-tracked/test/test-two.cpp", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/test_config/file.in", 23), ("mozjs/python/mozbuild/mozbuild/test/backend/data/test_config/moz.build", 40), ("mozjs/python/mozbuild/mozbuild/test/backend/data/variable_passthru/Makefile.in", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/variable_passthru/baz.def", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/variable_passthru/moz.build", 330), ("mozjs/python/mozbuild/mozbuild/test/backend/data/variable_passthru/test1.c", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/variable_passthru/test1.cpp", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/variable_passthru/test1.mm", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/variable_passthru/test2.c", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/variable_passthru/test2.cpp", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/variable_passthru/test2.mm", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/visual-studio/dir1/bar.cpp", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/visual-studio/dir1/foo.cpp", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/visual-studio/dir1/moz.build", 315), ("mozjs/python/mozbuild/mozbuild/test/backend/data/visual-studio/moz.build", 199), ("mozjs/python/mozbuild/mozbuild/test/backend/data/xpidl/bar.idl", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/xpidl/config/makefiles/xpidl/Makefile.in", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/xpidl/foo.idl", 0), ("mozjs/python/mozbuild/mozbuild/test/backend/data/xpidl/moz.build", 231), ("mozjs/python/mozbuild/mozbuild/test/backend/test_build.py", 9784), ("mozjs/python/mozbuild/mozbuild/test/backend/test_configenvironment.py", 2431), ("mozjs/python/mozbuild/mozbuild/test/backend/test_fastermake.py", 1626), ("mozjs/python/mozbuild/mozbuild/test/backend/test_gn_processor.py", 8359), ("mozjs/python/mozbuild/mozbuild/test/backend/test_partialconfigenvironment.py", 5812), ("mozjs/python/mozbuild/mozbuild/test/backend/test_recursivemake.py", 49929), ("mozjs/python/mozbuild/mozbuild/test/backend/test_test_manifest.py", 3531), ("mozjs/python/mozbuild/mozbuild/test/backend/test_visualstudio.py", 2727), ("mozjs/python/mozbuild/mozbuild/test/codecoverage/sample_lcov.info", 25755), ("mozjs/python/mozbuild/mozbuild/test/codecoverage/test_lcov_rewrite.py", 12758), ("mozjs/python/mozbuild/mozbuild/test/common.py", 2464), ("mozjs/python/mozbuild/mozbuild/test/compilation/__init__.py", 0), ("mozjs/python/mozbuild/mozbuild/test/compilation/test_warnings.py", 6571), ("mozjs/python/mozbuild/mozbuild/test/configure/common.py", 9553), ("mozjs/python/mozbuild/mozbuild/test/configure/data/decorators.configure", 1156), ("mozjs/python/mozbuild/mozbuild/test/configure/data/empty_mozconfig", 0), ("mozjs/python/mozbuild/mozbuild/test/configure/data/extra.configure", 405), ("mozjs/python/mozbuild/mozbuild/test/configure/data/imply_option/imm.configure", 802), ("mozjs/python/mozbuild/mozbuild/test/configure/data/imply_option/infer.configure", 603), ("mozjs/python/mozbuild/mozbuild/test/configure/data/imply_option/infer_ko.configure", 706), ("mozjs/python/mozbuild/mozbuild/test/configure/data/imply_option/negative.configure", 773), ("mozjs/python/mozbuild/mozbuild/test/configure/data/imply_option/simple.configure", 587), ("mozjs/python/mozbuild/mozbuild/test/configure/data/imply_option/values.configure", 610), ("mozjs/python/mozbuild/mozbuild/test/configure/data/included.configure", 1259), ("mozjs/python/mozbuild/mozbuild/test/configure/data/moz.configure", 5129), ("mozjs/python/mozbuild/mozbuild/test/configure/data/set_config.configure", 874), ("mozjs/python/mozbuild/mozbuild/test/configure/data/set_define.configure", 874), ("mozjs/python/mozbuild/mozbuild/test/configure/data/subprocess.configure", 830), ("mozjs/python/mozbuild/mozbuild/test/configure/lint.py", 1693), ("mozjs/python/mozbuild/mozbuild/test/configure/test_checks_configure.py", 37294), ("mozjs/python/mozbuild/mozbuild/test/configure/test_compile_checks.py", 14052), ("mozjs/python/mozbuild/mozbuild/test/configure/test_configure.py", 54003), ("mozjs/python/mozbuild/mozbuild/test/configure/test_lint.py", 12972), ("mozjs/python/mozbuild/mozbuild/test/configure/test_moz_configure.py", 6175), ("mozjs/python/mozbuild/mozbuild/test/configure/test_options.py", 39559), ("mozjs/python/mozbuild/mozbuild/test/configure/test_toolchain_configure.py", 54554), ("mozjs/python/mozbuild/mozbuild/test/configure/test_toolchain_helpers.py", 13733), ("mozjs/python/mozbuild/mozbuild/test/configure/test_toolkit_moz_configure.py", 6640), ("mozjs/python/mozbuild/mozbuild/test/configure/test_util.py", 17351), ("mozjs/python/mozbuild/mozbuild/test/controller/__init__.py", 0), ("mozjs/python/mozbuild/mozbuild/test/controller/test_ccachestats.py", 12001), ("mozjs/python/mozbuild/mozbuild/test/controller/test_clobber.py", 6840), ("mozjs/python/mozbuild/mozbuild/test/data/Makefile", 0), ("mozjs/python/mozbuild/mozbuild/test/data/bad.properties", 808), ("mozjs/python/mozbuild/mozbuild/test/data/test-dir/Makefile", 0), ("mozjs/python/mozbuild/mozbuild/test/data/test-dir/with/Makefile", 0), ("mozjs/python/mozbuild/mozbuild/test/data/test-dir/with/without/with/Makefile", 0), ("mozjs/python/mozbuild/mozbuild/test/data/test-dir/without/with/Makefile", 0), ("mozjs/python/mozbuild/mozbuild/test/data/valid.properties", 206), ("mozjs/python/mozbuild/mozbuild/test/frontend/__init__.py", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/allow-compiler-warnings/moz.build", 350), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/allow-compiler-warnings/test1.c", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/asflags/moz.build", 287), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/asflags/test1.S", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/asflags/test1.c", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/branding-files/bar.ico", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/branding-files/baz.png", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/branding-files/foo.xpm", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/branding-files/moz.build", 218), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/branding-files/quux.icns", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/compile-defines/moz.build", 327), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/compile-defines/test1.c", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/compile-flags-field-validation/moz.build", 274), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/compile-flags-field-validation/test1.c", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/compile-flags-templates/moz.build", 423), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/compile-flags-templates/test1.c", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/compile-flags-type-validation/moz.build", 277), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/compile-flags-type-validation/test1.c", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/compile-flags/moz.build", 387), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/compile-flags/test1.c", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/compile-includes/moz.build", 270), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/compile-includes/subdir/header.h", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/compile-includes/test1.c", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/config-file-substitution/moz.build", 231), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/crate-dependency-path-resolution/moz.build", 341), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/defines/moz.build", 248), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/disable-compiler-warnings/moz.build", 352), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/disable-compiler-warnings/test1.c", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/disable-stl-wrapping/moz.build", 330), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/disable-stl-wrapping/test1.c", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/dist-files-missing/install.rdf", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/dist-files-missing/moz.build", 228), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/dist-files/install.rdf", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/dist-files/main.js", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/dist-files/moz.build", 228), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/exports-generated/foo.h", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/exports-generated/moz.build", 229), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/exports-generated/mozilla1.h", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/exports-missing-generated/foo.h", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/exports-missing-generated/moz.build", 147), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/exports-missing/foo.h", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/exports-missing/moz.build", 193), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/exports-missing/mozilla1.h", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/exports/bar.h", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/exports/baz.h", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/exports/dom1.h", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/exports/dom2.h", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/exports/dom3.h", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/exports/foo.h", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/exports/gfx.h", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/exports/mem.h", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/exports/mem2.h", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/exports/moz.build", 433), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/exports/mozilla1.h", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/exports/mozilla2.h", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/exports/pprio.h", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/exports/pprthred.h", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/files-info/bug_component/bad-assignment/moz.build", 49), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/files-info/bug_component/different-matchers/moz.build", 119), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/files-info/bug_component/final/moz.build", 103), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/files-info/bug_component/final/subcomponent/moz.build", 63), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/files-info/bug_component/moz.build", 79), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/files-info/bug_component/simple/moz.build", 73), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/files-info/bug_component/static/moz.build", 141), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/files-info/moz.build", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/files-test-metadata/default/module.js", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/files-test-metadata/default/moz.build", 154), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/files-test-metadata/default/tests/reftests/reftest.list", 29), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/files-test-metadata/default/tests/reftests/test1-ref.html", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/files-test-metadata/default/tests/reftests/test1.html", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/files-test-metadata/default/tests/xpcshell/test_default_mod.js", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/files-test-metadata/default/tests/xpcshell/xpcshell.ini", 22), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/files-test-metadata/moz.build", 36), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/files-test-metadata/simple/base.cpp", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/files-test-metadata/simple/browser/browser.ini", 13), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/files-test-metadata/simple/browser/test_mod.js", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/files-test-metadata/simple/moz.build", 469), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/files-test-metadata/simple/src/module.jsm", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/files-test-metadata/simple/src/moz.build", 39), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/files-test-metadata/simple/tests/mochitest.ini", 40), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/files-test-metadata/simple/tests/moz.build", 40), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/files-test-metadata/simple/tests/test_general.html", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/files-test-metadata/simple/tests/test_specific.html", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/files-test-metadata/tagged/moz.build", 335), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/files-test-metadata/tagged/src/bar.jsm", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/files-test-metadata/tagged/src/submodule/foo.js", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/files-test-metadata/tagged/tests/mochitest.ini", 56), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/files-test-metadata/tagged/tests/test_bar.js", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/files-test-metadata/tagged/tests/test_simple.html", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/files-test-metadata/tagged/tests/test_specific.html", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/files-test-metadata/tagged/tests/xpcshell.ini", 14), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/final-target-pp-files-non-srcdir/moz.build", 209), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/generated-files-absolute-script/moz.build", 277), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/generated-files-absolute-script/script.py", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/generated-files-force/moz.build", 316), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/generated-files-method-names/moz.build", 360), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/generated-files-method-names/script.py", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/generated-files-no-inputs/moz.build", 286), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/generated-files-no-inputs/script.py", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/generated-files-no-python-script/moz.build", 260), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/generated-files-no-python-script/script.rb", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/generated-files-no-script/moz.build", 274), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/generated-files/moz.build", 239), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/generated-sources/a.cpp", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/generated-sources/b.cc", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/generated-sources/c.cxx", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/generated-sources/d.c", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/generated-sources/e.m", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/generated-sources/f.mm", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/generated-sources/g.S", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/generated-sources/h.s", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/generated-sources/i.asm", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/generated-sources/moz.build", 482), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/generated_includes/moz.build", 205), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/host-compile-flags/moz.build", 458), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/host-compile-flags/test1.c", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/host-program-paths/final-target/moz.build", 162), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/host-program-paths/installed/moz.build", 133), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/host-program-paths/moz.build", 232), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/host-program-paths/not-installed/moz.build", 153), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/host-rust-libraries/moz.build", 430), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/host-rust-program-no-cargo-toml/moz.build", 31), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/host-rust-program-nonexistent-name/moz.build", 31), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/host-rust-programs/moz.build", 31), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/host-sources/a.cpp", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/host-sources/b.cc", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/host-sources/c.cxx", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/host-sources/d.c", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/host-sources/e.mm", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/host-sources/f.mm", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/host-sources/moz.build", 419), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/include-basic/included.build", 120), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/include-basic/moz.build", 207), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/include-file-stack/included-1.build", 132), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/include-file-stack/included-2.build", 119), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/include-file-stack/moz.build", 193), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/include-missing/moz.build", 190), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/include-outside-topsrcdir/relative.build", 128), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/include-relative-from-child/child/child.build", 131), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/include-relative-from-child/child/child2.build", 143), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/include-relative-from-child/child/grandchild/grandchild.build", 134), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/include-relative-from-child/parent.build", 119), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/include-topsrcdir-relative/moz.build", 191), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/include-topsrcdir-relative/sibling.build", 119), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/inheriting-variables/bar/moz.build", 288), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/inheriting-variables/foo/baz/moz.build", 310), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/inheriting-variables/foo/moz.build", 305), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/inheriting-variables/moz.build", 360), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/ipdl_sources/bar/moz.build", 394), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/ipdl_sources/foo/moz.build", 394), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/ipdl_sources/moz.build", 323), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/jar-manifests-multiple-files/moz.build", 331), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/jar-manifests/moz.build", 317), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/library-defines/liba/moz.build", 154), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/library-defines/libb/moz.build", 198), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/library-defines/libc/moz.build", 143), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/library-defines/libd/moz.build", 144), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/library-defines/moz.build", 232), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/link-flags/moz.build", 303), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/link-flags/test1.c", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/local_includes-filename/foo.h", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/local_includes-filename/moz.build", 193), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/local_includes-invalid/objdir/moz.build", 190), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/local_includes-invalid/srcdir/moz.build", 189), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/local_includes/bar/baz/dummy_file_for_nonempty_directory", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/local_includes/foo/dummy_file_for_nonempty_directory", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/local_includes/moz.build", 203), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/localized-files-from-generated/moz.build", 242), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/localized-files-no-en-us/en-US/bar.ini", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/localized-files-no-en-us/foo.js", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/localized-files-no-en-us/inner/locales/en-US/bar.ini", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/localized-files-no-en-us/moz.build", 262), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/localized-files-not-localized-generated/moz.build", 232), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/localized-files/en-US/bar.ini", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/localized-files/en-US/foo.js", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/localized-files/moz.build", 256), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/localized-generated-files-final-target-files/moz.build", 245), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/localized-generated-files-force/moz.build", 274), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/localized-generated-files/moz.build", 224), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/localized-pp-files/en-US/bar.ini", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/localized-pp-files/en-US/foo.js", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/localized-pp-files/moz.build", 236), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/missing-local-includes/moz.build", 203), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/missing-xpidl/moz.build", 228), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/multiple-rust-libraries/moz.build", 410), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/multiple-rust-libraries/rust1/moz.build", 125), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/multiple-rust-libraries/rust2/moz.build", 125), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/program-paths/dist-bin/moz.build", 123), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/program-paths/dist-subdir/moz.build", 146), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/program-paths/final-target/moz.build", 158), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/program-paths/moz.build", 241), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/program-paths/not-installed/moz.build", 149), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/program/moz.build", 367), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/program/test_program1.cpp", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/program/test_program2.cpp", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/reader-error-bad-dir/moz.build", 180), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/reader-error-basic/moz.build", 180), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/reader-error-empty-list/moz.build", 175), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/reader-error-error-func/moz.build", 187), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/reader-error-included-from/child.build", 119), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/reader-error-included-from/moz.build", 188), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/reader-error-missing-include/moz.build", 190), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/reader-error-outside-topsrcdir/moz.build", 203), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/reader-error-read-unknown-global/moz.build", 173), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/reader-error-repeated-dir/moz.build", 197), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/reader-error-script-error/moz.build", 183), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/reader-error-syntax/moz.build", 171), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/reader-error-write-bad-value/moz.build", 178), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/reader-error-write-unknown-global/moz.build", 202), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/reader-relevant-mozbuild/d1/every-level/a/file", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/reader-relevant-mozbuild/d1/every-level/a/moz.build", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/reader-relevant-mozbuild/d1/every-level/b/file", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/reader-relevant-mozbuild/d1/every-level/b/moz.build", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/reader-relevant-mozbuild/d1/every-level/moz.build", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/reader-relevant-mozbuild/d1/file1", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/reader-relevant-mozbuild/d1/file2", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/reader-relevant-mozbuild/d1/moz.build", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/reader-relevant-mozbuild/d1/no-intermediate-moz-build/child/file", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/reader-relevant-mozbuild/d1/no-intermediate-moz-build/child/moz.build", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/reader-relevant-mozbuild/d1/parent-is-far/dir1/dir2/dir3/file", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/reader-relevant-mozbuild/d1/parent-is-far/moz.build", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/reader-relevant-mozbuild/d2/dir1/file", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/reader-relevant-mozbuild/d2/dir1/moz.build", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/reader-relevant-mozbuild/d2/dir2/file", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/reader-relevant-mozbuild/d2/dir2/moz.build", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/reader-relevant-mozbuild/d2/moz.build", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/reader-relevant-mozbuild/file", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/reader-relevant-mozbuild/moz.build", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/resolved-flags-error/moz.build", 363), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/resolved-flags-error/test1.c", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/rust-library-dash-folding/moz.build", 341), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/rust-library-duplicate-features/moz.build", 433), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/rust-library-features/moz.build", 421), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/rust-library-invalid-crate-type/moz.build", 341), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/rust-library-name-mismatch/moz.build", 341), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/rust-library-no-cargo-toml/moz.build", 341), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/rust-library-no-lib-section/moz.build", 341), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/rust-program-no-cargo-toml/moz.build", 26), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/rust-program-nonexistent-name/moz.build", 26), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/rust-programs/moz.build", 26), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/schedules/moz.build", 513), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/schedules/subd/moz.build", 125), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/sources-just-c/d.c", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/sources-just-c/e.m", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/sources-just-c/g.S", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/sources-just-c/h.s", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/sources-just-c/i.asm", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/sources-just-c/moz.build", 391), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/sources/a.cpp", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/sources/b.cc", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/sources/c.cxx", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/sources/d.c", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/sources/e.m", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/sources/f.mm", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/sources/g.S", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/sources/h.s", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/sources/i.asm", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/sources/moz.build", 473), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/templates/templates.mozbuild", 334), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-harness-files-root/moz.build", 137), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-harness-files/mochitest.ini", 68), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-harness-files/mochitest.py", 68), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-harness-files/moz.build", 312), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-harness-files/runtests.py", 68), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-harness-files/utils.py", 68), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-install-shared-lib/moz.build", 325), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-linkables-cxx-link/moz.build", 276), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-linkables-cxx-link/one/foo.cpp", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-linkables-cxx-link/one/moz.build", 203), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-linkables-cxx-link/three/moz.build", 165), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-linkables-cxx-link/two/foo.c", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-linkables-cxx-link/two/moz.build", 204), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-absolute-support/absolute-support.ini", 63), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-absolute-support/foo.txt", 6), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-absolute-support/moz.build", 152), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-absolute-support/test_file.js", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-dupes/bar.js", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-dupes/foo.js", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-dupes/mochitest.ini", 166), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-dupes/moz.build", 145), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-dupes/test_baz.js", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-emitted-includes/included-reftest.list", 34), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-emitted-includes/moz.build", 37), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-emitted-includes/reftest.list", 65), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-empty/empty.ini", 20), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-empty/moz.build", 141), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-inactive-ignored/test_inactive.html", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-install-includes/common.ini", 16), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-install-includes/mochitest.ini", 59), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-install-includes/moz.build", 145), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-install-includes/test_foo.html", 14), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-install-subdir/moz.build", 142), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-install-subdir/subdir.ini", 82), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-install-subdir/test_foo.html", 14), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-just-support/foo.txt", 6), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-just-support/just-support.ini", 34), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-just-support/moz.build", 148), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-keys-extracted/a11y-support/dir1/bar", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-keys-extracted/a11y-support/foo", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-keys-extracted/a11y.ini", 58), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-keys-extracted/browser.ini", 63), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-keys-extracted/chrome.ini", 28), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-keys-extracted/crashtest.list", 39), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-keys-extracted/metro.ini", 27), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-keys-extracted/mochitest.ini", 105), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-keys-extracted/moz.build", 474), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-keys-extracted/python.ini", 14), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-keys-extracted/reftest.list", 35), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-keys-extracted/test_a11y.js", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-keys-extracted/test_browser.js", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-keys-extracted/test_chrome.js", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-keys-extracted/test_foo.py", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-keys-extracted/test_metro.js", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-keys-extracted/test_mochitest.js", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-keys-extracted/test_xpcshell.js", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-keys-extracted/xpcshell.ini", 65), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-missing-manifest/moz.build", 155), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-missing-test-file-unfiltered/moz.build", 149), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-missing-test-file-unfiltered/xpcshell.ini", 51), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-missing-test-file/mochitest.ini", 20), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-missing-test-file/moz.build", 145), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-parent-support-files-dir/child/mochitest.ini", 61), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-parent-support-files-dir/child/test_foo.js", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-parent-support-files-dir/moz.build", 151), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-parent-support-files-dir/support-file.txt", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-shared-missing/child/another-file.sjs", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-shared-missing/child/browser.ini", 69), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-shared-missing/child/data/one.txt", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-shared-missing/child/data/two.txt", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-shared-missing/child/test_sub.js", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-shared-missing/mochitest.ini", 150), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-shared-missing/moz.build", 195), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-shared-missing/support-file.txt", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-shared-missing/test_foo.js", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-shared-support/child/another-file.sjs", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-shared-support/child/browser.ini", 69), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-shared-support/child/data/one.txt", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-shared-support/child/data/two.txt", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-shared-support/child/test_sub.js", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-shared-support/mochitest.ini", 127), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-shared-support/moz.build", 195), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-shared-support/support-file.txt", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-shared-support/test_foo.js", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-unmatched-generated/moz.build", 140), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-unmatched-generated/test.ini", 55), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-manifest-unmatched-generated/test_foo", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-symbols-file-objdir-missing-generated/moz.build", 243), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-symbols-file-objdir/foo.py", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-symbols-file-objdir/moz.build", 328), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-symbols-file/foo.symbols", 4), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/test-symbols-file/moz.build", 242), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/traversal-all-vars/moz.build", 207), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/traversal-all-vars/parallel/moz.build", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/traversal-all-vars/regular/moz.build", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/traversal-all-vars/test/moz.build", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/traversal-outside-topsrcdir/moz.build", 186), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/traversal-relative-dirs/bar/moz.build", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/traversal-relative-dirs/foo/moz.build", 183), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/traversal-relative-dirs/moz.build", 180), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/traversal-repeated-dirs/bar/moz.build", 183), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/traversal-repeated-dirs/foo/moz.build", 183), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/traversal-repeated-dirs/moz.build", 187), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/traversal-simple/bar/moz.build", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/traversal-simple/foo/biz/moz.build", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/traversal-simple/foo/moz.build", 76), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/traversal-simple/moz.build", 187), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/unified-sources-non-unified/bar.cxx", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/unified-sources-non-unified/c1.c", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/unified-sources-non-unified/c2.c", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/unified-sources-non-unified/foo.cpp", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/unified-sources-non-unified/moz.build", 471), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/unified-sources-non-unified/objc1.mm", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/unified-sources-non-unified/objc2.mm", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/unified-sources-non-unified/quux.cc", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/unified-sources/bar.cxx", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/unified-sources/c1.c", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/unified-sources/c2.c", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/unified-sources/foo.cpp", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/unified-sources/moz.build", 472), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/unified-sources/objc1.mm", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/unified-sources/objc2.mm", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/unified-sources/quux.cc", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/use-yasm/moz.build", 277), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/use-yasm/test1.S", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/variable-passthru/bans.S", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/variable-passthru/baz.def", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/variable-passthru/moz.build", 352), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/variable-passthru/test1.c", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/variable-passthru/test1.cpp", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/variable-passthru/test1.mm", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/variable-passthru/test2.c", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/variable-passthru/test2.cpp", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/variable-passthru/test2.mm", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/visibility-flags/moz.build", 335), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/visibility-flags/test1.c", 0), ("mozjs/python/mozbuild/mozbuild/test/frontend/data/xpidl-module-no-sources/moz.build", 195), ("mozjs/python/mozbuild/mozbuild/test/frontend/test_context.py", 25122), ("mozjs/python/mozbuild/mozbuild/test/frontend/test_emitter.py", 67656), ("mozjs/python/mozbuild/mozbuild/test/frontend/test_namespaces.py", 6319), ("mozjs/python/mozbuild/mozbuild/test/frontend/test_reader.py", 19640), ("mozjs/python/mozbuild/mozbuild/test/frontend/test_sandbox.py", 17411), ("mozjs/python/mozbuild/mozbuild/test/python.ini", 1399), ("mozjs/python/mozbuild/mozbuild/test/test_android_version_code.py", 3495), ("mozjs/python/mozbuild/mozbuild/test/test_artifacts.py", 5031), ("mozjs/python/mozbuild/mozbuild/test/test_base.py", 14178), ("mozjs/python/mozbuild/mozbuild/test/test_containers.py", 5635), ("mozjs/python/mozbuild/mozbuild/test/test_dotproperties.py", 4987), ("mozjs/python/mozbuild/mozbuild/test/test_expression.py", 2428), ("mozjs/python/mozbuild/mozbuild/test/test_jarmaker.py", 17893), ("mozjs/python/mozbuild/mozbuild/test/test_licenses.py", 1058), ("mozjs/python/mozbuild/mozbuild/test/test_line_endings.py", 1084), ("mozjs/python/mozbuild/mozbuild/test/test_makeutil.py", 5296), ("mozjs/python/mozbuild/mozbuild/test/test_manifest.py", 2859), ("mozjs/python/mozbuild/mozbuild/test/test_mozconfig.py", 16932), ("mozjs/python/mozbuild/mozbuild/test/test_mozinfo.py", 7676), ("mozjs/python/mozbuild/mozbuild/test/test_preprocessor.py", 18642), ("mozjs/python/mozbuild/mozbuild/test/test_pythonutil.py", 652), ("mozjs/python/mozbuild/mozbuild/test/test_util.py", 30101), ("mozjs/python/mozbuild/mozpack/test/__init__.py", 0), ("mozjs/python/mozbuild/mozpack/test/data/test_data", 9), ("mozjs/python/mozbuild/mozpack/test/python.ini", 280), ("mozjs/python/mozbuild/mozpack/test/support/minify_js_verify.py", 454), ("mozjs/python/mozbuild/mozpack/test/test_archive.py", 5866), ("mozjs/python/mozbuild/mozpack/test/test_chrome_flags.py", 5668), ("mozjs/python/mozbuild/mozpack/test/test_chrome_manifest.py", 6620), ("mozjs/python/mozbuild/mozpack/test/test_copier.py", 19882), ("mozjs/python/mozbuild/mozpack/test/test_errors.py", 3172), ("mozjs/python/mozbuild/mozpack/test/test_files.py", 43446), ("mozjs/python/mozbuild/mozpack/test/test_manifests.py", 15342), ("mozjs/python/mozbuild/mozpack/test/test_mozjar.py", 11431), ("mozjs/python/mozbuild/mozpack/test/test_packager.py", 21838), ("mozjs/python/mozbuild/mozpack/test/test_packager_formats.py", 17820), ("mozjs/python/mozbuild/mozpack/test/test_packager_l10n.py", 5052), ("mozjs/python/mozbuild/mozpack/test/test_packager_unpack.py", 2166), ("mozjs/python/mozbuild/mozpack/test/test_path.py", 5930), ("mozjs/python/mozlint/test/__init__.py", 0), ("mozjs/python/mozlint/test/conftest.py", 1684), ("mozjs/python/mozlint/test/files/foobar.js", 73), ("mozjs/python/mozlint/test/files/foobar.py", 108), ("mozjs/python/mozlint/test/files/irrelevant/file.txt", 7), ("mozjs/python/mozlint/test/files/no_foobar.js", 49), ("mozjs/python/mozlint/test/filter/a.js", 0), ("mozjs/python/mozlint/test/filter/a.py", 0), ("mozjs/python/mozlint/test/filter/subdir1/b.js", 0), ("mozjs/python/mozlint/test/filter/subdir1/b.py", 0), ("mozjs/python/mozlint/test/filter/subdir1/subdir3/d.js", 0), ("mozjs/python/mozlint/test/filter/subdir1/subdir3/d.py", 0), ("mozjs/python/mozlint/test/filter/subdir2/c.js", 0), ("mozjs/python/mozlint/test/filter/subdir2/c.py", 0), ("mozjs/python/mozlint/test/linters/badreturncode.yml", 195), ("mozjs/python/mozlint/test/linters/excludes.yml", 254), ("mozjs/python/mozlint/test/linters/explicit_path.yml", 171), ("mozjs/python/mozlint/test/linters/external.py", 1834), ("mozjs/python/mozlint/test/linters/external.yml", 196), ("mozjs/python/mozlint/test/linters/invalid_exclude.yml", 161), ("mozjs/python/mozlint/test/linters/invalid_extension.ym", 109), ("mozjs/python/mozlint/test/linters/invalid_include.yml", 140), ("mozjs/python/mozlint/test/linters/invalid_include_with_glob.yml", 143), ("mozjs/python/mozlint/test/linters/invalid_support_files.yml", 157), ("mozjs/python/mozlint/test/linters/invalid_type.yml", 95), ("mozjs/python/mozlint/test/linters/missing_attrs.yml", 66), ("mozjs/python/mozlint/test/linters/missing_definition.yml", 16), ("mozjs/python/mozlint/test/linters/non_existing_exclude.yml", 154), ("mozjs/python/mozlint/test/linters/non_existing_include.yml", 154), ("mozjs/python/mozlint/test/linters/non_existing_support_files.yml", 171), ("mozjs/python/mozlint/test/linters/raises.yml", 122), ("mozjs/python/mozlint/test/linters/regex.yml", 242), ("mozjs/python/mozlint/test/linters/setup.yml", 219), ("mozjs/python/mozlint/test/linters/setupfailed.yml", 231), ("mozjs/python/mozlint/test/linters/setupraised.yml", 231), ("mozjs/python/mozlint/test/linters/slow.yml", 174), ("mozjs/python/mozlint/test/linters/string.yml", 222), ("mozjs/python/mozlint/test/linters/structured.yml", 208), ("mozjs/python/mozlint/test/linters/support_files.yml", 225), ("mozjs/python/mozlint/test/linters/warning.yml", 295), ("mozjs/python/mozlint/test/python.ini", 191), ("mozjs/python/mozlint/test/runcli.py", 623), ("mozjs/python/mozlint/test/test_cli.py", 2269), ("mozjs/python/mozlint/test/test_editor.py", 2175), ("mozjs/python/mozlint/test/test_formatters.py", 3205), ("mozjs/python/mozlint/test/test_parser.py", 1777), ("mozjs/python/mozlint/test/test_pathutils.py", 3195), ("mozjs/python/mozlint/test/test_result.py", 637), ("mozjs/python/mozlint/test/test_roller.py", 8181), ("mozjs/python/mozlint/test/test_types.py", 1428), ("mozjs/python/mozrelease/test/data/Firefox-62.0.3.update.json", 1730), ("mozjs/python/mozrelease/test/data/Firefox-62.0b11-update.json", 1730), ("mozjs/python/mozrelease/test/data/Firefox-64.0b13.update.json", 182), ("mozjs/python/mozrelease/test/data/buglist_changesets.json", 7347), ("mozjs/python/mozrelease/test/data/sample-update-verify.cfg", 1149), ("mozjs/python/mozrelease/test/data/whatsnew-62.0.3.yml", 1450), ("mozjs/python/mozrelease/test/data/whatsnew-release.yml", 1450), ("mozjs/python/mozrelease/test/python.ini", 183), ("mozjs/python/mozrelease/test/test_balrog.py", 1373), ("mozjs/python/mozrelease/test/test_buglist_creator.py", 4713), ("mozjs/python/mozrelease/test/test_update_verify.py", 15430), ("mozjs/python/mozrelease/test/test_versions.py", 2250), ("mozjs/python/mozterm/test/python.ini", 67), ("mozjs/python/mozterm/test/test_terminal.py", 1233), ("mozjs/python/mozterm/test/test_widgets.py", 1350), ("mozjs/python/mozversioncontrol/test/conftest.py", 1611), ("mozjs/python/mozversioncontrol/test/python.ini", 141), ("mozjs/python/mozversioncontrol/test/test_context_manager.py", 865), ("mozjs/python/mozversioncontrol/test/test_push_to_try.py", 1943), ("mozjs/python/mozversioncontrol/test/test_workdir_outgoing.py", 1788), ("mozjs/python/mozversioncontrol/test/test_working_directory.py", 1131), ("mozjs/taskcluster/taskgraph/test/__init__.py", 0), ("mozjs/taskcluster/taskgraph/test/automationrelevance.json", 17980), ("mozjs/taskcluster/taskgraph/test/python.ini", 647), ("mozjs/taskcluster/taskgraph/test/test_actions_util.py", 1431), ("mozjs/taskcluster/taskgraph/test/test_create.py", 3729), ("mozjs/taskcluster/taskgraph/test/test_cron_util.py", 3495), ("mozjs/taskcluster/taskgraph/test/test_decision.py", 5487), ("mozjs/taskcluster/taskgraph/test/test_files_changed.py", 2639), ("mozjs/taskcluster/taskgraph/test/test_generator.py", 7465), ("mozjs/taskcluster/taskgraph/test/test_graph.py", 7007), ("mozjs/taskcluster/taskgraph/test/test_morph.py", 7395), ("mozjs/taskcluster/taskgraph/test/test_optimize.py", 9009), ("mozjs/taskcluster/taskgraph/test/test_parameters.py", 3929), ("mozjs/taskcluster/taskgraph/test/test_target_tasks.py", 6716), ("mozjs/taskcluster/taskgraph/test/test_taskgraph.py", 3334), ("mozjs/taskcluster/taskgraph/test/test_transforms_base.py", 978), ("mozjs/taskcluster/taskgraph/test/test_transforms_job.py", 2928), ("mozjs/taskcluster/taskgraph/test/test_try_option_syntax.py", 16368), ("mozjs/taskcluster/taskgraph/test/test_util_attributes.py", 4512), ("mozjs/taskcluster/taskgraph/test/test_util_docker.py", 8359), ("mozjs/taskcluster/taskgraph/test/test_util_parameterization.py", 5542), ("mozjs/taskcluster/taskgraph/test/test_util_python_path.py", 1310), ("mozjs/taskcluster/taskgraph/test/test_util_runnable_jobs.py", 2345), ("mozjs/taskcluster/taskgraph/test/test_util_schema.py", 5525), ("mozjs/taskcluster/taskgraph/test/test_util_taskcluster.py", 626), ("mozjs/taskcluster/taskgraph/test/test_util_templates.py", 1827), ("mozjs/taskcluster/taskgraph/test/test_util_time.py", 2014), ("mozjs/taskcluster/taskgraph/test/test_util_treeherder.py", 1051), ("mozjs/taskcluster/taskgraph/test/test_util_yaml.py", 677), ("mozjs/testing/mozbase/README.md", 1005), ("mozjs/testing/mozbase/docs/Makefile", 5568), ("mozjs/testing/mozbase/docs/_static/structured_example.py", 2374), ("mozjs/testing/mozbase/docs/conf.py", 8525), ("mozjs/testing/mozbase/docs/devicemanagement.rst", 273), ("mozjs/testing/mozbase/docs/gettinginfo.rst", 302), ("mozjs/testing/mozbase/docs/index.rst", 1725), ("mozjs/testing/mozbase/docs/loggingreporting.rst", 291), ("mozjs/testing/mozbase/docs/make.bat", 5098), ("mozjs/testing/mozbase/docs/manifestparser.rst", 20047), ("mozjs/testing/mozbase/docs/mozcrash.rst", 352), ("mozjs/testing/mozbase/docs/mozdebug.rst", 250), ("mozjs/testing/mozbase/docs/mozdevice.rst", 3592), ("mozjs/testing/mozbase/docs/mozfile.rst", 381), ("mozjs/testing/mozbase/docs/mozhttpd.rst", 493), ("mozjs/testing/mozbase/docs/mozinfo.rst", 2517), ("mozjs/testing/mozbase/docs/mozinstall.rst", 881), ("mozjs/testing/mozbase/docs/mozlog.rst", 19502), ("mozjs/testing/mozbase/docs/moznetwork.rst", 200), ("mozjs/testing/mozbase/docs/mozprocess.rst", 9137), ("mozjs/testing/mozbase/docs/mozprofile.rst", 3413), ("mozjs/testing/mozbase/docs/mozproxy.rst", 1877), ("mozjs/testing/mozbase/docs/mozrunner.rst", 5485), ("mozjs/testing/mozbase/docs/mozversion.rst", 1848), ("mozjs/testing/mozbase/docs/requirements.txt", 18), ("mozjs/testing/mozbase/docs/setuprunning.rst", 418), ("mozjs/testing/mozbase/manifestparser/manifestparser/__init__.py", 330), ("mozjs/testing/mozbase/manifestparser/manifestparser/cli.py", 7114), ("mozjs/testing/mozbase/manifestparser/manifestparser/expression.py", 9164), ("mozjs/testing/mozbase/manifestparser/manifestparser/filters.py", 15235), ("mozjs/testing/mozbase/manifestparser/manifestparser/ini.py", 6353), ("mozjs/testing/mozbase/manifestparser/manifestparser/manifestparser.py", 31333), ("mozjs/testing/mozbase/manifestparser/setup.py", 1189), ("mozjs/testing/mozbase/manifestparser/tests/comment-example.ini", 490), ("mozjs/testing/mozbase/manifestparser/tests/default-skipif.ini", 259), ("mozjs/testing/mozbase/manifestparser/tests/default-suppfiles.ini", 122), ("mozjs/testing/mozbase/manifestparser/tests/filter-example.ini", 205), ("mozjs/testing/mozbase/manifestparser/tests/fleem", 30), ("mozjs/testing/mozbase/manifestparser/tests/include-example.ini", 128), ("mozjs/testing/mozbase/manifestparser/tests/include-invalid.ini", 22), ("mozjs/testing/mozbase/manifestparser/tests/include/bar.ini", 39), ("mozjs/testing/mozbase/manifestparser/tests/include/crash-handling", 39), ("mozjs/testing/mozbase/manifestparser/tests/include/flowers", 32), ("mozjs/testing/mozbase/manifestparser/tests/include/foo.ini", 52), ("mozjs/testing/mozbase/manifestparser/tests/just-defaults.ini", 20), ("mozjs/testing/mozbase/manifestparser/tests/manifest.ini", 301), ("mozjs/testing/mozbase/manifestparser/tests/missing-path.ini", 12), ("mozjs/testing/mozbase/manifestparser/tests/mozmill-example.ini", 3340), ("mozjs/testing/mozbase/manifestparser/tests/mozmill-restart-example.ini", 997), ("mozjs/testing/mozbase/manifestparser/tests/no-tests.ini", 20), ("mozjs/testing/mozbase/manifestparser/tests/parent/include/first/manifest.ini", 41), ("mozjs/testing/mozbase/manifestparser/tests/parent/include/manifest.ini", 111), ("mozjs/testing/mozbase/manifestparser/tests/parent/include/second/manifest.ini", 42), ("mozjs/testing/mozbase/manifestparser/tests/parent/level_1/level_1.ini", 41), ("mozjs/testing/mozbase/manifestparser/tests/parent/level_1/level_1_server-root.ini", 63), ("mozjs/testing/mozbase/manifestparser/tests/parent/level_1/level_2/level_2.ini", 34), ("mozjs/testing/mozbase/manifestparser/tests/parent/level_1/level_2/level_2_server-root.ini", 46), ("mozjs/testing/mozbase/manifestparser/tests/parent/level_1/level_2/level_3/level_3.ini", 34), ("mozjs/testing/mozbase/manifestparser/tests/parent/level_1/level_2/level_3/level_3_default.ini", 57), ("mozjs/testing/mozbase/manifestparser/tests/parent/level_1/level_2/level_3/level_3_server-root.ini", 46), ("mozjs/testing/mozbase/manifestparser/tests/parent/level_1/level_2/level_3/test_3", 31), ("mozjs/testing/mozbase/manifestparser/tests/parent/level_1/level_2/test_2", 31), ("mozjs/testing/mozbase/manifestparser/tests/parent/level_1/test_1", 31), ("mozjs/testing/mozbase/manifestparser/tests/parent/root/dummy", 0), ("mozjs/testing/mozbase/manifestparser/tests/path-example.ini", 18), ("mozjs/testing/mozbase/manifestparser/tests/relative-path.ini", 60), ("mozjs/testing/mozbase/manifestparser/tests/subsuite.ini", 170), ("mozjs/testing/mozbase/manifestparser/tests/test_chunking.py", 9596), ("mozjs/testing/mozbase/manifestparser/tests/test_convert_directory.py", 6227), ("mozjs/testing/mozbase/manifestparser/tests/test_convert_symlinks.py", 4950), ("mozjs/testing/mozbase/manifestparser/tests/test_default_overrides.py", 4697), ("mozjs/testing/mozbase/manifestparser/tests/test_expressionparser.py", 6193), ("mozjs/testing/mozbase/manifestparser/tests/test_filters.py", 5085), ("mozjs/testing/mozbase/manifestparser/tests/test_manifestparser.py", 20044), ("mozjs/testing/mozbase/manifestparser/tests/test_read_ini.py", 1545), ("mozjs/testing/mozbase/manifestparser/tests/test_testmanifest.py", 4963), ("mozjs/testing/mozbase/manifestparser/tests/verifyDirectory/subdir/manifest.ini", 14), ("mozjs/testing/mozbase/manifestparser/tests/verifyDirectory/subdir/test_sub.js", 15), ("mozjs/testing/mozbase/manifestparser/tests/verifyDirectory/test_1.js", 13), ("mozjs/testing/mozbase/manifestparser/tests/verifyDirectory/test_2.js", 13), ("mozjs/testing/mozbase/manifestparser/tests/verifyDirectory/test_3.js", 13), ("mozjs/testing/mozbase/manifestparser/tests/verifyDirectory/verifyDirectory.ini", 66), ("mozjs/testing/mozbase/manifestparser/tests/verifyDirectory/verifyDirectory_incomplete.ini", 54), ("mozjs/testing/mozbase/manifestparser/tests/verifyDirectory/verifyDirectory_toocomplete.ini", 99), ("mozjs/testing/mozbase/moz.build", 1638), ("mozjs/testing/mozbase/mozcrash/mozcrash/__init__.py", 436), ("mozjs/testing/mozbase/mozcrash/mozcrash/mozcrash.py", 24133), ("mozjs/testing/mozbase/mozcrash/setup.cfg", 26), ("mozjs/testing/mozbase/mozcrash/setup.py", 1178), ("mozjs/testing/mozbase/mozcrash/tests/conftest.py", 2458), ("mozjs/testing/mozbase/mozcrash/tests/manifest.ini", 133), ("mozjs/testing/mozbase/mozcrash/tests/test_basic.py", 1399), ("mozjs/testing/mozbase/mozcrash/tests/test_java_exception.py", 1838), ("mozjs/testing/mozbase/mozcrash/tests/test_save_path.py", 2328), ("mozjs/testing/mozbase/mozcrash/tests/test_stackwalk.py", 1397), ("mozjs/testing/mozbase/mozcrash/tests/test_symbols_path.py", 1813), ("mozjs/testing/mozbase/mozdebug/mozdebug/__init__.py", 946), ("mozjs/testing/mozbase/mozdebug/mozdebug/mozdebug.py", 10710), ("mozjs/testing/mozbase/mozdebug/setup.cfg", 26), ("mozjs/testing/mozbase/mozdebug/setup.py", 1179), ("mozjs/testing/mozbase/mozdebug/tests/fake_debuggers/cgdb/cgdb", 0), ("mozjs/testing/mozbase/mozdebug/tests/fake_debuggers/devenv/devenv.exe", 0), ("mozjs/testing/mozbase/mozdebug/tests/fake_debuggers/gdb/gdb", 0), ("mozjs/testing/mozbase/mozdebug/tests/fake_debuggers/lldb/lldb", 0), ("mozjs/testing/mozbase/mozdebug/tests/fake_debuggers/wdexpress/wdexpress.exe", 0), ("mozjs/testing/mozbase/mozdebug/tests/manifest.ini", 39), ("mozjs/testing/mozbase/mozdebug/tests/test.py", 1756), ("mozjs/testing/mozbase/mozdevice/mozdevice/__init__.py", 565), ("mozjs/testing/mozbase/mozdevice/mozdevice/adb.py", 137285), ("mozjs/testing/mozbase/mozdevice/mozdevice/adb_android.py", 415), ("mozjs/testing/mozbase/mozdevice/mozdevice/version_codes.py", 1557), ("mozjs/testing/mozbase/mozdevice/setup.cfg", 28), ("mozjs/testing/mozbase/mozdevice/setup.py", 1143), ("mozjs/testing/mozbase/mozdevice/tests/conftest.py", 7065), ("mozjs/testing/mozbase/mozdevice/tests/manifest.ini", 130), ("mozjs/testing/mozbase/mozdevice/tests/test_chown.py", 2250), ("mozjs/testing/mozbase/mozdevice/tests/test_escape_command_line.py", 708), ("mozjs/testing/mozbase/mozdevice/tests/test_is_app_installed.py", 1294), ("mozjs/testing/mozbase/mozdevice/tests/test_socket_connection.py", 3852), ("mozjs/testing/mozbase/mozfile/mozfile/__init__.py", 278), ("mozjs/testing/mozbase/mozfile/mozfile/mozfile.py", 12121), ("mozjs/testing/mozbase/mozfile/setup.cfg", 28), ("mozjs/testing/mozbase/mozfile/setup.py", 1182), ("mozjs/testing/mozbase/mozfile/tests/files/missing_file_attributes.zip", 442), ("mozjs/testing/mozbase/mozfile/tests/manifest.ini", 150), ("mozjs/testing/mozbase/mozfile/tests/stubs.py", 912), ("mozjs/testing/mozbase/mozfile/tests/test_extract.py", 5018), ("mozjs/testing/mozbase/mozfile/tests/test_load.py", 1457), ("mozjs/testing/mozbase/mozfile/tests/test_move_remove.py", 8127), ("mozjs/testing/mozbase/mozfile/tests/test_tempdir.py", 1373), ("mozjs/testing/mozbase/mozfile/tests/test_tempfile.py", 3233), ("mozjs/testing/mozbase/mozfile/tests/test_tree.py", 760), ("mozjs/testing/mozbase/mozfile/tests/test_url.py", 517), ("mozjs/testing/mozbase/mozhttpd/mozhttpd/__init__.py", 1762), ("mozjs/testing/mozbase/mozhttpd/mozhttpd/handlers.py", 586), ("mozjs/testing/mozbase/mozhttpd/mozhttpd/mozhttpd.py", 12061), ("mozjs/testing/mozbase/mozhttpd/setup.py", 1239), ("mozjs/testing/mozbase/mozhttpd/tests/api.py", 9833), ("mozjs/testing/mozbase/mozhttpd/tests/baseurl.py", 743), ("mozjs/testing/mozbase/mozhttpd/tests/basic.py", 1198), ("mozjs/testing/mozbase/mozhttpd/tests/filelisting.py", 1781), ("mozjs/testing/mozbase/mozhttpd/tests/manifest.ini", 150), ("mozjs/testing/mozbase/mozhttpd/tests/paths.py", 3227), ("mozjs/testing/mozbase/mozhttpd/tests/requestlog.py", 1590), ("mozjs/testing/mozbase/mozinfo/mozinfo/__init__.py", 1235), ("mozjs/testing/mozbase/mozinfo/mozinfo/mozinfo.py", 10736), ("mozjs/testing/mozbase/mozinfo/mozinfo/string_version.py", 1291), ("mozjs/testing/mozbase/mozinfo/setup.cfg", 26), ("mozjs/testing/mozbase/mozinfo/setup.py", 1288), ("mozjs/testing/mozbase/mozinfo/tests/manifest.ini", 39), ("mozjs/testing/mozbase/mozinfo/tests/test.py", 5776), ("mozjs/testing/mozbase/mozinstall/mozinstall/__init__.py", 280), ("mozjs/testing/mozbase/mozinstall/mozinstall/mozinstall.py", 11873), ("mozjs/testing/mozbase/mozinstall/setup.cfg", 28), ("mozjs/testing/mozbase/mozinstall/setup.py", 2159), ("mozjs/testing/mozbase/mozinstall/tests/conftest.py", 550), ("mozjs/testing/mozbase/mozinstall/tests/installer_stubs/firefox.dmg", 27309), ("mozjs/testing/mozbase/mozinstall/tests/installer_stubs/firefox.tar.bz2", 2882), ("mozjs/testing/mozbase/mozinstall/tests/installer_stubs/firefox.zip", 8707), ("mozjs/testing/mozbase/mozinstall/tests/manifest.ini", 167), ("mozjs/testing/mozbase/mozinstall/tests/test_binary.py", 1565), ("mozjs/testing/mozbase/mozinstall/tests/test_install.py", 2870), ("mozjs/testing/mozbase/mozinstall/tests/test_is_installer.py", 1095), ("mozjs/testing/mozbase/mozinstall/tests/test_uninstall.py", 1180), ("mozjs/testing/mozbase/mozleak/mozleak/__init__.py", 431), ("mozjs/testing/mozbase/mozleak/mozleak/leaklog.py", 8562), ("mozjs/testing/mozbase/mozleak/mozleak/lsan.py", 7214), ("mozjs/testing/mozbase/mozleak/setup.py", 1017), ("mozjs/testing/mozbase/mozleak/tests/manifest.ini", 81), ("mozjs/testing/mozbase/mozleak/tests/test_lsan.py", 1029), ("mozjs/testing/mozbase/mozlog/mozlog/__init__.py", 1111), ("mozjs/testing/mozbase/mozlog/mozlog/capture.py", 2792), ("mozjs/testing/mozbase/mozlog/mozlog/commandline.py", 12719), ("mozjs/testing/mozbase/mozlog/mozlog/formatters/__init__.py", 837), ("mozjs/testing/mozbase/mozlog/mozlog/formatters/base.py", 811), ("mozjs/testing/mozbase/mozlog/mozlog/formatters/errorsummary.py", 2463), ("mozjs/testing/mozbase/mozlog/mozlog/formatters/grouping.py", 11496), ("mozjs/testing/mozbase/mozlog/mozlog/formatters/html/__init__.py", 102), ("mozjs/testing/mozbase/mozlog/mozlog/formatters/html/html.py", 10763), ("mozjs/testing/mozbase/mozlog/mozlog/formatters/html/main.js", 5534), ("mozjs/testing/mozbase/mozlog/mozlog/formatters/html/style.css", 2720), ("mozjs/testing/mozbase/mozlog/mozlog/formatters/html/xmlgen.py", 9204), ("mozjs/testing/mozbase/mozlog/mozlog/formatters/machformatter.py", 16175), ("mozjs/testing/mozbase/mozlog/mozlog/formatters/process.py", 1720), ("mozjs/testing/mozbase/mozlog/mozlog/formatters/tbplformatter.py", 13399), ("mozjs/testing/mozbase/mozlog/mozlog/formatters/unittest.py", 2448), ("mozjs/testing/mozbase/mozlog/mozlog/formatters/xunit.py", 3856), ("mozjs/testing/mozbase/mozlog/mozlog/handlers/__init__.py", 615), ("mozjs/testing/mozbase/mozlog/mozlog/handlers/base.py", 4454), ("mozjs/testing/mozbase/mozlog/mozlog/handlers/bufferhandler.py", 2956), ("mozjs/testing/mozbase/mozlog/mozlog/handlers/statushandler.py", 2887), ("mozjs/testing/mozbase/mozlog/mozlog/handlers/summaryhandler.py", 5172), ("mozjs/testing/mozbase/mozlog/mozlog/handlers/valgrindhandler.py", 6079), ("mozjs/testing/mozbase/mozlog/mozlog/logtypes.py", 8151), ("mozjs/testing/mozbase/mozlog/mozlog/proxy.py", 2390), ("mozjs/testing/mozbase/mozlog/mozlog/pytest_mozlog/__init__.py", 0), ("mozjs/testing/mozbase/mozlog/mozlog/pytest_mozlog/plugin.py", 5323), ("mozjs/testing/mozbase/mozlog/mozlog/reader.py", 2878), ("mozjs/testing/mozbase/mozlog/mozlog/scripts/__init__.py", 1047), ("mozjs/testing/mozbase/mozlog/mozlog/scripts/format.py", 1396), ("mozjs/testing/mozbase/mozlog/mozlog/scripts/logmerge.py", 2340), ("mozjs/testing/mozbase/mozlog/mozlog/scripts/unstable.py", 3786), ("mozjs/testing/mozbase/mozlog/mozlog/stdadapter.py", 1321), ("mozjs/testing/mozbase/mozlog/mozlog/structuredlog.py", 23805), ("mozjs/testing/mozbase/mozlog/mozlog/unstructured/__init__.py", 358), ("mozjs/testing/mozbase/mozlog/mozlog/unstructured/logger.py", 6639), ("mozjs/testing/mozbase/mozlog/mozlog/unstructured/loggingmixin.py", 1559), ("mozjs/testing/mozbase/mozlog/mozlog/unstructured/loglistener.py", 1884), ("mozjs/testing/mozbase/mozlog/setup.cfg", 26), ("mozjs/testing/mozbase/mozlog/setup.py", 1816), ("mozjs/testing/mozbase/mozlog/tests/manifest.ini", 125), ("mozjs/testing/mozbase/mozlog/tests/test_capture.py", 1246), ("mozjs/testing/mozbase/mozlog/tests/test_formatters.py", 5706), ("mozjs/testing/mozbase/mozlog/tests/test_logger.py", 11452), ("mozjs/testing/mozbase/mozlog/tests/test_logtypes.py", 3126), ("mozjs/testing/mozbase/mozlog/tests/test_structured.py", 46544), ("mozjs/testing/mozbase/moznetwork/moznetwork/__init__.py", 654), ("mozjs/testing/mozbase/moznetwork/moznetwork/moznetwork.py", 5620), ("mozjs/testing/mozbase/moznetwork/setup.py", 1196), ("mozjs/testing/mozbase/moznetwork/tests/manifest.ini", 61), ("mozjs/testing/mozbase/moznetwork/tests/test.py", 2031), ("mozjs/testing/mozbase/mozprocess/mozprocess/__init__.py", 285), ("mozjs/testing/mozbase/mozprocess/mozprocess/processhandler.py", 50056), ("mozjs/testing/mozbase/mozprocess/mozprocess/qijo.py", 5319), ("mozjs/testing/mozbase/mozprocess/mozprocess/winprocess.py", 18970), ("mozjs/testing/mozbase/mozprocess/setup.cfg", 28), ("mozjs/testing/mozbase/mozprocess/setup.py", 1421), ("mozjs/testing/mozbase/mozprocess/tests/manifest.ini", 332), ("mozjs/testing/mozbase/mozprocess/tests/process_normal_broad.ini", 373), ("mozjs/testing/mozbase/mozprocess/tests/process_normal_deep.ini", 921), ("mozjs/testing/mozbase/mozprocess/tests/process_normal_finish.ini", 161), ("mozjs/testing/mozbase/mozprocess/tests/process_normal_finish_no_process_group.ini", 18), ("mozjs/testing/mozbase/mozprocess/tests/process_waittimeout.ini", 166), ("mozjs/testing/mozbase/mozprocess/tests/process_waittimeout_10s.ini", 158), ("mozjs/testing/mozbase/mozprocess/tests/proclaunch.py", 7016), ("mozjs/testing/mozbase/mozprocess/tests/proctest.py", 2115), ("mozjs/testing/mozbase/mozprocess/tests/scripts/infinite_loop.py", 365), ("mozjs/testing/mozbase/mozprocess/tests/scripts/proccountfive.py", 91), ("mozjs/testing/mozbase/mozprocess/tests/scripts/procnonewline.py", 134), ("mozjs/testing/mozbase/mozprocess/tests/test_detached.py", 1980), ("mozjs/testing/mozbase/mozprocess/tests/test_kill.py", 3582), ("mozjs/testing/mozbase/mozprocess/tests/test_misc.py", 1516), ("mozjs/testing/mozbase/mozprocess/tests/test_output.py", 2193), ("mozjs/testing/mozbase/mozprocess/tests/test_params.py", 2774), ("mozjs/testing/mozbase/mozprocess/tests/test_pid.py", 1328), ("mozjs/testing/mozbase/mozprocess/tests/test_poll.py", 4901), ("mozjs/testing/mozbase/mozprocess/tests/test_process_reader.py", 3724), ("mozjs/testing/mozbase/mozprocess/tests/test_wait.py", 4383), ("mozjs/testing/mozbase/mozprofile/mozprofile/__init__.py", 769), ("mozjs/testing/mozbase/mozprofile/mozprofile/addons.py", 12126), ("mozjs/testing/mozbase/mozprofile/mozprofile/cli.py", 5758), ("mozjs/testing/mozbase/mozprofile/mozprofile/diff.py", 2217), ("mozjs/testing/mozbase/mozprofile/mozprofile/permissions.py", 14295), ("mozjs/testing/mozbase/mozprofile/mozprofile/prefs.py", 7676), ("mozjs/testing/mozbase/mozprofile/mozprofile/profile.py", 18526), ("mozjs/testing/mozbase/mozprofile/mozprofile/view.py", 1052), ("mozjs/testing/mozbase/mozprofile/setup.cfg", 26), ("mozjs/testing/mozbase/mozprofile/setup.py", 1779), ("mozjs/testing/mozbase/mozprofile/tests/addon_stubs.py", 2325), ("mozjs/testing/mozbase/mozprofile/tests/addons/apply-css-sans-id.xpi", 3371), ("mozjs/testing/mozbase/mozprofile/tests/addons/apply-css.xpi", 3412), ("mozjs/testing/mozbase/mozprofile/tests/addons/empty.xpi", 530), ("mozjs/testing/mozbase/mozprofile/tests/addons/empty/install.rdf", 690), ("mozjs/testing/mozbase/mozprofile/tests/addons/invalid.xpi", 564), ("mozjs/testing/mozbase/mozprofile/tests/files/dummy-profile/.eslintrc.js", 75), ("mozjs/testing/mozbase/mozprofile/tests/files/dummy-profile/Preferences", 19), ("mozjs/testing/mozbase/mozprofile/tests/files/dummy-profile/extensions/empty.xpi", 530), ("mozjs/testing/mozbase/mozprofile/tests/files/dummy-profile/prefs.js", 26), ("mozjs/testing/mozbase/mozprofile/tests/files/dummy-profile/user.js", 25), ("mozjs/testing/mozbase/mozprofile/tests/files/not_an_addon.txt", 0), ("mozjs/testing/mozbase/mozprofile/tests/files/prefs_with_comments.js", 270), ("mozjs/testing/mozbase/mozprofile/tests/files/prefs_with_interpolation.js", 178), ("mozjs/testing/mozbase/mozprofile/tests/install_manifests/test_addon_1.rdf", 782), ("mozjs/testing/mozbase/mozprofile/tests/install_manifests/test_addon_2.rdf", 782), ("mozjs/testing/mozbase/mozprofile/tests/install_manifests/test_addon_3.rdf", 783), ("mozjs/testing/mozbase/mozprofile/tests/install_manifests/test_addon_4.rdf", 783), ("mozjs/testing/mozbase/mozprofile/tests/install_manifests/test_addon_invalid_no_id.rdf", 866), ("mozjs/testing/mozbase/mozprofile/tests/install_manifests/test_addon_invalid_not_wellformed.rdf", 932), ("mozjs/testing/mozbase/mozprofile/tests/install_manifests/test_addon_invalid_version.rdf", 924), ("mozjs/testing/mozbase/mozprofile/tests/install_manifests/test_addon_unpack.rdf", 830), ("mozjs/testing/mozbase/mozprofile/tests/manifest.ini", 261), ("mozjs/testing/mozbase/mozprofile/tests/test_addonid.py", 5137), ("mozjs/testing/mozbase/mozprofile/tests/test_addons.py", 11596), ("mozjs/testing/mozbase/mozprofile/tests/test_bug758250.py", 1230), ("mozjs/testing/mozbase/mozprofile/tests/test_chrome_profile.py", 1877), ("mozjs/testing/mozbase/mozprofile/tests/test_clone_cleanup.py", 2181), ("mozjs/testing/mozbase/mozprofile/tests/test_nonce.py", 1219), ("mozjs/testing/mozbase/mozprofile/tests/test_permissions.py", 5953), ("mozjs/testing/mozbase/mozprofile/tests/test_preferences.py", 12989), ("mozjs/testing/mozbase/mozprofile/tests/test_profile.py", 2818), ("mozjs/testing/mozbase/mozprofile/tests/test_profile_view.py", 2195), ("mozjs/testing/mozbase/mozprofile/tests/test_server_locations.py", 4402), ("mozjs/testing/mozbase/mozproxy/MANIFEST.in", 29), ("mozjs/testing/mozbase/mozproxy/mozproxy/__init__.py", 1547), ("mozjs/testing/mozbase/mozproxy/mozproxy/backends/__init__.py", 199), ("mozjs/testing/mozbase/mozproxy/mozproxy/backends/base.py", 723), ("mozjs/testing/mozbase/mozproxy/mozproxy/backends/mitm.py", 20723), ("mozjs/testing/mozbase/mozproxy/mozproxy/backends/mitmproxy-rel-bin-linux64.manifest", 298), ("mozjs/testing/mozbase/mozproxy/mozproxy/backends/mitmproxy-rel-bin-osx.manifest", 296), ("mozjs/testing/mozbase/mozproxy/mozproxy/backends/mitmproxy-rel-bin-win.manifest", 299), ("mozjs/testing/mozbase/mozproxy/mozproxy/backends/mitmproxy_requirements.txt", 569), ("mozjs/testing/mozbase/mozproxy/mozproxy/server.py", 591), ("mozjs/testing/mozbase/mozproxy/mozproxy/utils.py", 6256), ("mozjs/testing/mozbase/mozproxy/setup.py", 1038), ("mozjs/testing/mozbase/mozproxy/tests/archive.tar.gz", 184), ("mozjs/testing/mozbase/mozproxy/tests/firefox", 15), ("mozjs/testing/mozbase/mozproxy/tests/manifest.ini", 61), ("mozjs/testing/mozbase/mozproxy/tests/paypal.mp", 25), ("mozjs/testing/mozbase/mozproxy/tests/test_proxy.py", 2682), ("mozjs/testing/mozbase/mozproxy/tests/test_utils.py", 1111), ("mozjs/testing/mozbase/mozrunner/mozrunner/__init__.py", 390), ("mozjs/testing/mozbase/mozrunner/mozrunner/application.py", 4527), ("mozjs/testing/mozbase/mozrunner/mozrunner/base/__init__.py", 193), ("mozjs/testing/mozbase/mozrunner/mozrunner/base/browser.py", 4227), ("mozjs/testing/mozbase/mozrunner/mozrunner/base/device.py", 6277), ("mozjs/testing/mozbase/mozrunner/mozrunner/base/runner.py", 8563), ("mozjs/testing/mozbase/mozrunner/mozrunner/cli.py", 5614), ("mozjs/testing/mozbase/mozrunner/mozrunner/devices/__init__.py", 569), ("mozjs/testing/mozbase/mozrunner/mozrunner/devices/android_device.py", 38603), ("mozjs/testing/mozbase/mozrunner/mozrunner/devices/base.py", 8701), ("mozjs/testing/mozbase/mozrunner/mozrunner/devices/emulator.py", 7441), ("mozjs/testing/mozbase/mozrunner/mozrunner/devices/emulator_battery.py", 1604), ("mozjs/testing/mozbase/mozrunner/mozrunner/devices/emulator_geo.py", 596), ("mozjs/testing/mozbase/mozrunner/mozrunner/devices/emulator_screen.py", 3606), ("mozjs/testing/mozbase/mozrunner/mozrunner/errors.py", 575), ("mozjs/testing/mozbase/mozrunner/mozrunner/runners.py", 5191), ("mozjs/testing/mozbase/mozrunner/mozrunner/utils.py", 10966), ("mozjs/testing/mozbase/mozrunner/setup.cfg", 28), ("mozjs/testing/mozbase/mozrunner/setup.py", 1798), ("mozjs/testing/mozbase/mozrunner/tests/conftest.py", 2123), ("mozjs/testing/mozbase/mozrunner/tests/manifest.ini", 293), ("mozjs/testing/mozbase/mozrunner/tests/test_crash.py", 1077), ("mozjs/testing/mozbase/mozrunner/tests/test_interactive.py", 1003), ("mozjs/testing/mozbase/mozrunner/tests/test_start.py", 1103), ("mozjs/testing/mozbase/mozrunner/tests/test_states.py", 511), ("mozjs/testing/mozbase/mozrunner/tests/test_stop.py", 1188), ("mozjs/testing/mozbase/mozrunner/tests/test_threads.py", 1641), ("mozjs/testing/mozbase/mozrunner/tests/test_wait.py", 903), ("mozjs/testing/mozbase/mozscreenshot/mozscreenshot/__init__.py", 4163), ("mozjs/testing/mozbase/mozscreenshot/setup.py", 1039), ("mozjs/testing/mozbase/mozsystemmonitor/README.rst", 334), ("mozjs/testing/mozbase/mozsystemmonitor/mozsystemmonitor/__init__.py", 0), ("mozjs/testing/mozbase/mozsystemmonitor/mozsystemmonitor/resourcemonitor.py", 23641), ("mozjs/testing/mozbase/mozsystemmonitor/setup.py", 951), ("mozjs/testing/mozbase/mozsystemmonitor/tests/manifest.ini", 56), ("mozjs/testing/mozbase/mozsystemmonitor/tests/test_resource_monitor.py", 5178), ("mozjs/testing/mozbase/moztest/moztest/__init__.py", 292), ("mozjs/testing/mozbase/moztest/moztest/adapters/__init__.py", 294), ("mozjs/testing/mozbase/moztest/moztest/adapters/unit.py", 8348), ("mozjs/testing/mozbase/moztest/moztest/output/__init__.py", 0), ("mozjs/testing/mozbase/moztest/moztest/output/autolog.py", 2937), ("mozjs/testing/mozbase/moztest/moztest/output/base.py", 1650), ("mozjs/testing/mozbase/moztest/moztest/output/xunit.py", 3510), ("mozjs/testing/mozbase/moztest/moztest/resolve.py", 25326), ("mozjs/testing/mozbase/moztest/moztest/results.py", 11249), ("mozjs/testing/mozbase/moztest/moztest/selftest/__init__.py", 0), ("mozjs/testing/mozbase/moztest/moztest/selftest/fixtures.py", 3307), ("mozjs/testing/mozbase/moztest/moztest/selftest/output.py", 1759), ("mozjs/testing/mozbase/moztest/setup.py", 1104), ("mozjs/testing/mozbase/moztest/tests/manifest.ini", 79), ("mozjs/testing/mozbase/moztest/tests/test.py", 1675), ("mozjs/testing/mozbase/moztest/tests/test_resolve.py", 18521), ("mozjs/testing/mozbase/mozversion/mozversion/__init__.py", 345), ("mozjs/testing/mozbase/mozversion/mozversion/errors.py", 914), ("mozjs/testing/mozbase/mozversion/mozversion/mozversion.py", 5185), ("mozjs/testing/mozbase/mozversion/setup.cfg", 26), ("mozjs/testing/mozbase/mozversion/setup.py", 1139), ("mozjs/testing/mozbase/mozversion/tests/manifest.ini", 61), ("mozjs/testing/mozbase/mozversion/tests/test_apk.py", 1331), ("mozjs/testing/mozbase/mozversion/tests/test_binary.py", 4887), ("mozjs/testing/mozbase/packages.txt", 772), ("mozjs/testing/mozbase/rust/mozprofile/src/lib.rs", 7654), ("mozjs/testing/mozbase/rust/mozprofile/src/preferences.rs", 2954), ("mozjs/testing/mozbase/rust/mozprofile/src/prefreader.rs", 36897), ("mozjs/testing/mozbase/rust/mozprofile/src/profile.rs", 3178), ("mozjs/testing/mozbase/rust/mozrunner/moz.build", 266), ("mozjs/testing/mozbase/rust/mozrunner/src/bin/firefox-default-path.rs", 486), ("mozjs/testing/mozbase/rust/mozrunner/src/firefox_args.rs", 5432), ("mozjs/testing/mozbase/rust/mozrunner/src/lib.rs", 311), ("mozjs/testing/mozbase/rust/mozrunner/src/path.rs", 1369), ("mozjs/testing/mozbase/rust/mozrunner/src/runner.rs", 14667), ("mozjs/testing/mozbase/rust/mozversion/src/lib.rs", 10960), ("mozjs/testing/mozbase/setup_development.py", 9528), ("mozjs/testing/mozbase/versioninfo.py", 4561), ("mozjs/testing/web-platform/tests/streams/META.yml", 139), ("mozjs/testing/web-platform/tests/streams/README.md", 436), ("mozjs/testing/web-platform/tests/streams/byte-length-queuing-strategy.any.js", 4230), ("mozjs/testing/web-platform/tests/streams/count-queuing-strategy.any.js", 3812), ("mozjs/testing/web-platform/tests/streams/piping/abort.any.js", 12833), ("mozjs/testing/web-platform/tests/streams/piping/close-propagation-backward.any.js", 4485), ("mozjs/testing/web-platform/tests/streams/piping/close-propagation-forward.any.js", 16563), ("mozjs/testing/web-platform/tests/streams/piping/error-propagation-backward.any.js", 18695), ("mozjs/testing/web-platform/tests/streams/piping/error-propagation-forward.any.js", 17125), ("mozjs/testing/web-platform/tests/streams/piping/flow-control.any.js", 10943), ("mozjs/testing/web-platform/tests/streams/piping/general.any.js", 5295), ("mozjs/testing/web-platform/tests/streams/piping/multiple-propagation.any.js", 6702), ("mozjs/testing/web-platform/tests/streams/piping/pipe-through.any.js", 8258), ("mozjs/testing/web-platform/tests/streams/piping/then-interception.any.js", 1615), ("mozjs/testing/web-platform/tests/streams/piping/transform-streams.any.js", 507), ("mozjs/testing/web-platform/tests/streams/readable-byte-streams/brand-checks.any.js", 6583), ("mozjs/testing/web-platform/tests/streams/readable-byte-streams/construct-byob-request.any.js", 2698), ("mozjs/testing/web-platform/tests/streams/readable-byte-streams/constructor.any.js", 1708), ("mozjs/testing/web-platform/tests/streams/readable-byte-streams/detached-buffers.any.js", 5522), ("mozjs/testing/web-platform/tests/streams/readable-byte-streams/general.any.js", 65411), ("mozjs/testing/web-platform/tests/streams/readable-byte-streams/properties.any.js", 6569), ("mozjs/testing/web-platform/tests/streams/readable-streams/async-iterator.any.js", 9185), ("mozjs/testing/web-platform/tests/streams/readable-streams/bad-strategies.any.js", 3595), ("mozjs/testing/web-platform/tests/streams/readable-streams/bad-underlying-sources.any.js", 9534), ("mozjs/testing/web-platform/tests/streams/readable-streams/brand-checks.any.js", 6186), ("mozjs/testing/web-platform/tests/streams/readable-streams/cancel.any.js", 7223), ("mozjs/testing/web-platform/tests/streams/readable-streams/constructor.any.js", 1159), ("mozjs/testing/web-platform/tests/streams/readable-streams/count-queuing-strategy-integration.any.js", 8690), ("mozjs/testing/web-platform/tests/streams/readable-streams/default-reader.any.js", 14133), ("mozjs/testing/web-platform/tests/streams/readable-streams/floating-point-total-queue-size.any.js", 4868), ("mozjs/testing/web-platform/tests/streams/readable-streams/garbage-collection.any.js", 1723), ("mozjs/testing/web-platform/tests/streams/readable-streams/general.any.js", 29389), ("mozjs/testing/web-platform/tests/streams/readable-streams/patched-global.any.js", 3569), ("mozjs/testing/web-platform/tests/streams/readable-streams/reentrant-strategies.any.js", 7362), ("mozjs/testing/web-platform/tests/streams/readable-streams/tee.any.js", 8011), ("mozjs/testing/web-platform/tests/streams/readable-streams/templated.any.js", 3682), ("mozjs/testing/web-platform/tests/streams/resources/constructor-ordering.js", 4797), ("mozjs/testing/web-platform/tests/streams/resources/recording-streams.js", 2595), ("mozjs/testing/web-platform/tests/streams/resources/rs-test-templates.js", 20229), ("mozjs/testing/web-platform/tests/streams/resources/rs-utils.js", 3917), ("mozjs/testing/web-platform/tests/streams/resources/test-utils.js", 3054), ("mozjs/testing/web-platform/tests/streams/transform-streams/backpressure.any.js", 7501), ("mozjs/testing/web-platform/tests/streams/transform-streams/brand-checks.any.js", 2618), ("mozjs/testing/web-platform/tests/streams/transform-streams/constructor.any.js", 1662), ("mozjs/testing/web-platform/tests/streams/transform-streams/errors.any.js", 12321), ("mozjs/testing/web-platform/tests/streams/transform-streams/flush.any.js", 4003), ("mozjs/testing/web-platform/tests/streams/transform-streams/general.any.js", 15268), ("mozjs/testing/web-platform/tests/streams/transform-streams/lipfuzz.any.js", 3861), ("mozjs/testing/web-platform/tests/streams/transform-streams/patched-global.any.js", 1750), ("mozjs/testing/web-platform/tests/streams/transform-streams/properties.any.js", 6566), ("mozjs/testing/web-platform/tests/streams/transform-streams/reentrant-strategies.any.js", 10126), ("mozjs/testing/web-platform/tests/streams/transform-streams/strategies.any.js", 5957), ("mozjs/testing/web-platform/tests/streams/transform-streams/terminate.any.js", 3491), ("mozjs/testing/web-platform/tests/streams/writable-streams/aborting.any.js", 44422), ("mozjs/testing/web-platform/tests/streams/writable-streams/bad-strategies.any.js", 2639), ("mozjs/testing/web-platform/tests/streams/writable-streams/bad-underlying-sinks.any.js", 5877), ("mozjs/testing/web-platform/tests/streams/writable-streams/brand-checks.any.js", 4458), ("mozjs/testing/web-platform/tests/streams/writable-streams/byte-length-queuing-strategy.any.js", 705), ("mozjs/testing/web-platform/tests/streams/writable-streams/close.any.js", 12150), ("mozjs/testing/web-platform/tests/streams/writable-streams/constructor.any.js", 6216), ("mozjs/testing/web-platform/tests/streams/writable-streams/count-queuing-strategy.any.js", 3935), ("mozjs/testing/web-platform/tests/streams/writable-streams/error.any.js", 1502), ("mozjs/testing/web-platform/tests/streams/writable-streams/floating-point-total-queue-size.any.js", 3104), ("mozjs/testing/web-platform/tests/streams/writable-streams/general.any.js", 8309), ("mozjs/testing/web-platform/tests/streams/writable-streams/properties.any.js", 6817), ("mozjs/testing/web-platform/tests/streams/writable-streams/reentrant-strategy.any.js", 6155), ("mozjs/testing/web-platform/tests/streams/writable-streams/start.any.js", 5202), ("mozjs/testing/web-platform/tests/streams/writable-streams/write.any.js", 9884), ("mozjs/third_party/python/Click/docs/Makefile", 603), ("mozjs/third_party/python/Click/docs/_static/click-icon.png", 625), ("mozjs/third_party/python/Click/docs/_static/click-logo-sidebar.png", 4246), ("mozjs/third_party/python/Click/docs/_static/click-logo.png", 26081), ("mozjs/third_party/python/Click/docs/advanced.rst", 14218), ("mozjs/third_party/python/Click/docs/api.rst", 2343), ("mozjs/third_party/python/Click/docs/arguments.rst", 8312), ("mozjs/third_party/python/Click/docs/bashcomplete.rst", 4586), ("mozjs/third_party/python/Click/docs/changelog.rst", 54), ("mozjs/third_party/python/Click/docs/commands.rst", 18595), ("mozjs/third_party/python/Click/docs/complex.rst", 7828), ("mozjs/third_party/python/Click/docs/conf.py", 1796), ("mozjs/third_party/python/Click/docs/contrib.rst", 1193), ("mozjs/third_party/python/Click/docs/documentation.rst", 5059), ("mozjs/third_party/python/Click/docs/exceptions.rst", 2924), ("mozjs/third_party/python/Click/docs/index.rst", 2263), ("mozjs/third_party/python/Click/docs/license.rst", 407), ("mozjs/third_party/python/Click/docs/make.bat", 773), ("mozjs/third_party/python/Click/docs/options.rst", 22141), ("mozjs/third_party/python/Click/docs/parameters.rst", 4782), ("mozjs/third_party/python/Click/docs/prompts.rst", 1651), ("mozjs/third_party/python/Click/docs/python3.rst", 7624), ("mozjs/third_party/python/Click/docs/quickstart.rst", 9166), ("mozjs/third_party/python/Click/docs/requirements.txt", 43), ("mozjs/third_party/python/Click/docs/setuptools.rst", 4172), ("mozjs/third_party/python/Click/docs/testing.rst", 3748), ("mozjs/third_party/python/Click/docs/upgrading.rst", 3764), ("mozjs/third_party/python/Click/docs/utils.rst", 13279), ("mozjs/third_party/python/Click/docs/why.rst", 7444), ("mozjs/third_party/python/Click/docs/wincmd.rst", 4016), ("mozjs/third_party/python/Click/examples/README", 436), ("mozjs/third_party/python/Click/examples/aliases/README", 445), ("mozjs/third_party/python/Click/examples/aliases/aliases.ini", 20), ("mozjs/third_party/python/Click/examples/aliases/aliases.py", 3103), ("mozjs/third_party/python/Click/examples/aliases/setup.py", 282), ("mozjs/third_party/python/Click/examples/bashcompletion/README", 306), ("mozjs/third_party/python/Click/examples/bashcompletion/bashcompletion.py", 1241), ("mozjs/third_party/python/Click/examples/bashcompletion/setup.py", 310), ("mozjs/third_party/python/Click/examples/colors/README", 183), ("mozjs/third_party/python/Click/examples/colors/colors.py", 1033), ("mozjs/third_party/python/Click/examples/colors/setup.py", 347), ("mozjs/third_party/python/Click/examples/complex/README", 452), ("mozjs/third_party/python/Click/examples/complex/complex/__init__.py", 0), ("mozjs/third_party/python/Click/examples/complex/complex/cli.py", 1845), ("mozjs/third_party/python/Click/examples/complex/complex/commands/__init__.py", 0), ("mozjs/third_party/python/Click/examples/complex/complex/commands/cmd_init.py", 389), ("mozjs/third_party/python/Click/examples/complex/complex/commands/cmd_status.py", 277), ("mozjs/third_party/python/Click/examples/complex/setup.py", 304), ("mozjs/third_party/python/Click/examples/imagepipe/.gitignore", 12), ("mozjs/third_party/python/Click/examples/imagepipe/README", 310), ("mozjs/third_party/python/Click/examples/imagepipe/example01.jpg", 51677), ("mozjs/third_party/python/Click/examples/imagepipe/example02.jpg", 39106), ("mozjs/third_party/python/Click/examples/imagepipe/imagepipe.py", 8425), ("mozjs/third_party/python/Click/examples/imagepipe/setup.py", 308), ("mozjs/third_party/python/Click/examples/inout/README", 236), ("mozjs/third_party/python/Click/examples/inout/inout.py", 722), ("mozjs/third_party/python/Click/examples/inout/setup.py", 274), ("mozjs/third_party/python/Click/examples/naval/README", 354), ("mozjs/third_party/python/Click/examples/naval/naval.py", 1764), ("mozjs/third_party/python/Click/examples/naval/setup.py", 274), ("mozjs/third_party/python/Click/examples/repo/README", 153), ("mozjs/third_party/python/Click/examples/repo/repo.py", 4802), ("mozjs/third_party/python/Click/examples/repo/setup.py", 270), ("mozjs/third_party/python/Click/examples/termui/README", 143), ("mozjs/third_party/python/Click/examples/termui/setup.py", 347), ("mozjs/third_party/python/Click/examples/termui/termui.py", 4332), ("mozjs/third_party/python/Click/examples/validation/README", 241), ("mozjs/third_party/python/Click/examples/validation/setup.py", 294), ("mozjs/third_party/python/Click/examples/validation/validation.py", 1427), ("mozjs/third_party/python/Click/tests/conftest.py", 131), ("mozjs/third_party/python/Click/tests/test_arguments.py", 7865), ("mozjs/third_party/python/Click/tests/test_bashcomplete.py", 15237), ("mozjs/third_party/python/Click/tests/test_basic.py", 15867), ("mozjs/third_party/python/Click/tests/test_chain.py", 5706), ("mozjs/third_party/python/Click/tests/test_commands.py", 7782), ("mozjs/third_party/python/Click/tests/test_compat.py", 813), ("mozjs/third_party/python/Click/tests/test_context.py", 5602), ("mozjs/third_party/python/Click/tests/test_defaults.py", 1149), ("mozjs/third_party/python/Click/tests/test_formatting.py", 7437), ("mozjs/third_party/python/Click/tests/test_imports.py", 1429), ("mozjs/third_party/python/Click/tests/test_normalization.py", 955), ("mozjs/third_party/python/Click/tests/test_options.py", 14928), ("mozjs/third_party/python/Click/tests/test_termui.py", 3685), ("mozjs/third_party/python/Click/tests/test_testing.py", 7241), ("mozjs/third_party/python/Click/tests/test_utils.py", 9599), ("mozjs/third_party/python/atomicwrites/docs/Makefile", 6786), ("mozjs/third_party/python/atomicwrites/docs/conf.py", 3283), ("mozjs/third_party/python/atomicwrites/docs/index.rst", 607), ("mozjs/third_party/python/atomicwrites/docs/make.bat", 6713), ("mozjs/third_party/python/atomicwrites/tests/test_atomicwrites.py", 2686), ("mozjs/third_party/python/attrs/docs/Makefile", 6758), ("mozjs/third_party/python/attrs/docs/_static/attrs_logo.png", 7639), ("mozjs/third_party/python/attrs/docs/_static/attrs_logo.svg", 6789), ("mozjs/third_party/python/attrs/docs/api.rst", 10692), ("mozjs/third_party/python/attrs/docs/backward-compatibility.rst", 852), ("mozjs/third_party/python/attrs/docs/changelog.rst", 30), ("mozjs/third_party/python/attrs/docs/conf.py", 4831), ("mozjs/third_party/python/attrs/docs/contributing.rst", 105), ("mozjs/third_party/python/attrs/docs/docutils.conf", 53), ("mozjs/third_party/python/attrs/docs/examples.rst", 17271), ("mozjs/third_party/python/attrs/docs/extending.rst", 3489), ("mozjs/third_party/python/attrs/docs/glossary.rst", 3088), ("mozjs/third_party/python/attrs/docs/hashing.rst", 3407), ("mozjs/third_party/python/attrs/docs/how-does-it-work.rst", 4328), ("mozjs/third_party/python/attrs/docs/index.rst", 2890), ("mozjs/third_party/python/attrs/docs/init.rst", 11956), ("mozjs/third_party/python/attrs/docs/license.rst", 315), ("mozjs/third_party/python/attrs/docs/overview.rst", 3697), ("mozjs/third_party/python/attrs/docs/why.rst", 11501), ("mozjs/third_party/python/attrs/tests/__init__.py", 0), ("mozjs/third_party/python/attrs/tests/strategies.py", 6548), ("mozjs/third_party/python/attrs/tests/test_annotations.py", 6334), ("mozjs/third_party/python/attrs/tests/test_config.py", 1164), ("mozjs/third_party/python/attrs/tests/test_converters.py", 755), ("mozjs/third_party/python/attrs/tests/test_dark_magic.py", 11253), ("mozjs/third_party/python/attrs/tests/test_dunders.py", 14341), ("mozjs/third_party/python/attrs/tests/test_filters.py", 2205), ("mozjs/third_party/python/attrs/tests/test_funcs.py", 17193), ("mozjs/third_party/python/attrs/tests/test_init_subclass.py", 963), ("mozjs/third_party/python/attrs/tests/test_make.py", 32491), ("mozjs/third_party/python/attrs/tests/test_slots.py", 10688), ("mozjs/third_party/python/attrs/tests/test_validators.py", 6577), ("mozjs/third_party/python/attrs/tests/utils.py", 1270), ("mozjs/third_party/python/cbor2/docs/conf.py", 707), ("mozjs/third_party/python/cbor2/docs/customizing.rst", 5703), ("mozjs/third_party/python/cbor2/docs/index.rst", 220), ("mozjs/third_party/python/cbor2/docs/modules/decoder.rst", 87), ("mozjs/third_party/python/cbor2/docs/modules/encoder.rst", 87), ("mozjs/third_party/python/cbor2/docs/modules/types.rst", 81), ("mozjs/third_party/python/cbor2/docs/usage.rst", 3715), ("mozjs/third_party/python/cbor2/docs/versionhistory.rst", 3213), ("mozjs/third_party/python/cbor2/tests/test_decoder.py", 9787), ("mozjs/third_party/python/cbor2/tests/test_encoder.py", 8090), ("mozjs/third_party/python/cbor2/tests/test_types.py", 842), ("mozjs/third_party/python/compare-locales/compare_locales/integration_tests/__init__.py", 154), ("mozjs/third_party/python/compare-locales/compare_locales/integration_tests/test_plurals.py", 3049), ("mozjs/third_party/python/compare-locales/compare_locales/tests/__init__.py", 1898), ("mozjs/third_party/python/compare-locales/compare_locales/tests/android/__init__.py", 0), ("mozjs/third_party/python/compare-locales/compare_locales/tests/android/test_checks.py", 8050), ("mozjs/third_party/python/compare-locales/compare_locales/tests/android/test_merge.py", 1398), ("mozjs/third_party/python/compare-locales/compare_locales/tests/android/test_parser.py", 3277), ("mozjs/third_party/python/compare-locales/compare_locales/tests/data/bug121341.properties", 4103), ("mozjs/third_party/python/compare-locales/compare_locales/tests/data/test.properties", 332), ("mozjs/third_party/python/compare-locales/compare_locales/tests/data/triple-license.dtd", 1771), ("mozjs/third_party/python/compare-locales/compare_locales/tests/paths/__init__.py", 2562), ("mozjs/third_party/python/compare-locales/compare_locales/tests/paths/test_configparser.py", 2239), ("mozjs/third_party/python/compare-locales/compare_locales/tests/paths/test_files.py", 9821), ("mozjs/third_party/python/compare-locales/compare_locales/tests/paths/test_ini.py", 3389), ("mozjs/third_party/python/compare-locales/compare_locales/tests/paths/test_matcher.py", 14019), ("mozjs/third_party/python/compare-locales/compare_locales/tests/paths/test_paths.py", 1036), ("mozjs/third_party/python/compare-locales/compare_locales/tests/paths/test_project.py", 6947), ("mozjs/third_party/python/compare-locales/compare_locales/tests/po/__init__.py", 0), ("mozjs/third_party/python/compare-locales/compare_locales/tests/po/test_parser.py", 3773), ("mozjs/third_party/python/compare-locales/compare_locales/tests/serializer/__init__.py", 1136), ("mozjs/third_party/python/compare-locales/compare_locales/tests/serializer/test_android.py", 5537), ("mozjs/third_party/python/compare-locales/compare_locales/tests/serializer/test_fluent.py", 1645), ("mozjs/third_party/python/compare-locales/compare_locales/tests/serializer/test_properties.py", 2007), ("mozjs/third_party/python/compare-locales/compare_locales/tests/test_apps.py", 3831), ("mozjs/third_party/python/compare-locales/compare_locales/tests/test_checks.py", 17006), ("mozjs/third_party/python/compare-locales/compare_locales/tests/test_checks_ftl.py", 1458), ("mozjs/third_party/python/compare-locales/compare_locales/tests/test_compare.py", 5860), ("mozjs/third_party/python/compare-locales/compare_locales/tests/test_defines.py", 6881), ("mozjs/third_party/python/compare-locales/compare_locales/tests/test_dtd.py", 8919), ("mozjs/third_party/python/compare-locales/compare_locales/tests/test_ftl.py", 11416), ("mozjs/third_party/python/compare-locales/compare_locales/tests/test_ini.py", 5018), ("mozjs/third_party/python/compare-locales/compare_locales/tests/test_keyedtuple.py", 1879), ("mozjs/third_party/python/compare-locales/compare_locales/tests/test_merge.py", 37178), ("mozjs/third_party/python/compare-locales/compare_locales/tests/test_merge_comments.py", 3470), ("mozjs/third_party/python/compare-locales/compare_locales/tests/test_merge_dtd.py", 6262), ("mozjs/third_party/python/compare-locales/compare_locales/tests/test_merge_ftl.py", 5743), ("mozjs/third_party/python/compare-locales/compare_locales/tests/test_merge_messages.py", 1756), ("mozjs/third_party/python/compare-locales/compare_locales/tests/test_merge_properties.py", 1348), ("mozjs/third_party/python/compare-locales/compare_locales/tests/test_merge_unknown.py", 662), ("mozjs/third_party/python/compare-locales/compare_locales/tests/test_merge_whitespace.py", 1290), ("mozjs/third_party/python/compare-locales/compare_locales/tests/test_mozpath.py", 5917), ("mozjs/third_party/python/compare-locales/compare_locales/tests/test_parser.py", 2290), ("mozjs/third_party/python/compare-locales/compare_locales/tests/test_properties.py", 7360), ("mozjs/third_party/python/compare-locales/compare_locales/tests/test_util.py", 728), ("mozjs/third_party/python/cram/cram/_test.py", 7904), ("mozjs/third_party/python/dlmanager/doc/Makefile", 7668), ("mozjs/third_party/python/dlmanager/doc/api.rst", 289), ("mozjs/third_party/python/dlmanager/doc/conf.py", 9301), ("mozjs/third_party/python/dlmanager/doc/index.rst", 567), ("mozjs/third_party/python/dlmanager/doc/make.bat", 7255), ("mozjs/third_party/python/dlmanager/examples/dl_progressbar.py", 1074), ("mozjs/third_party/python/dlmanager/examples/dl_tqdm.py", 1028), ("mozjs/third_party/python/dlmanager/tests/__init__.py", 0), ("mozjs/third_party/python/dlmanager/tests/test_manager.py", 8349), ("mozjs/third_party/python/dlmanager/tests/test_persist_limit.py", 1476), ("mozjs/third_party/python/enum34/enum/doc/enum.pdf", 70178), ("mozjs/third_party/python/enum34/enum/doc/enum.rst", 22448), ("mozjs/third_party/python/funcsigs/docs/Makefile", 5570), ("mozjs/third_party/python/funcsigs/docs/_templates/page.html", 336), ("mozjs/third_party/python/funcsigs/docs/conf.py", 8084), ("mozjs/third_party/python/funcsigs/docs/index.rst", 13295), ("mozjs/third_party/python/funcsigs/tests/__init__.py", 0), ("mozjs/third_party/python/funcsigs/tests/test_formatannotation.py", 541), ("mozjs/third_party/python/funcsigs/tests/test_funcsigs.py", 3262), ("mozjs/third_party/python/funcsigs/tests/test_inspect.py", 37216), ("mozjs/third_party/python/futures/docs/Makefile", 2995), ("mozjs/third_party/python/futures/docs/conf.py", 6302), ("mozjs/third_party/python/futures/docs/index.rst", 13391), ("mozjs/third_party/python/futures/docs/make.bat", 2961), ("mozjs/third_party/python/gyp/pylib/gyp/MSVSSettings_test.py", 65937), ("mozjs/third_party/python/gyp/pylib/gyp/common_test.py", 1970), ("mozjs/third_party/python/gyp/pylib/gyp/easy_xml_test.py", 3270), ("mozjs/third_party/python/gyp/pylib/gyp/generator/msvs_test.py", 1018), ("mozjs/third_party/python/gyp/pylib/gyp/generator/ninja_test.py", 1786), ("mozjs/third_party/python/gyp/pylib/gyp/generator/xcode_test.py", 645), ("mozjs/third_party/python/gyp/pylib/gyp/input_test.py", 3207), ("mozjs/third_party/python/gyp/test/actions-bare/gyptest-bare.py", 558), ("mozjs/third_party/python/gyp/test/actions-bare/src/bare.gyp", 565), ("mozjs/third_party/python/gyp/test/actions-bare/src/bare.py", 262), ("mozjs/third_party/python/gyp/test/actions-depfile/depfile.gyp", 527), ("mozjs/third_party/python/gyp/test/actions-depfile/gyptest-all.py", 524), ("mozjs/third_party/python/gyp/test/actions-depfile/input.txt", 6), ("mozjs/third_party/python/gyp/test/actions-multiple-outputs-with-dependencies/gyptest-action.py", 1172), ("mozjs/third_party/python/gyp/test/actions-multiple-outputs-with-dependencies/src/action.gyp", 800), ("mozjs/third_party/python/gyp/test/actions-multiple-outputs-with-dependencies/src/rcopy.py", 514), ("mozjs/third_party/python/gyp/test/actions-multiple-outputs/gyptest-multiple-outputs.py", 1071), ("mozjs/third_party/python/gyp/test/actions-multiple-outputs/src/multiple-outputs.gyp", 540), ("mozjs/third_party/python/gyp/test/actions-multiple-outputs/src/touch.py", 350), ("mozjs/third_party/python/gyp/test/actions-multiple/gyptest-all.py", 2115), ("mozjs/third_party/python/gyp/test/actions-multiple/src/actions.gyp", 6382), ("mozjs/third_party/python/gyp/test/actions-multiple/src/copy.py", 247), ("mozjs/third_party/python/gyp/test/actions-multiple/src/filter.py", 317), ("mozjs/third_party/python/gyp/test/actions-multiple/src/foo.c", 225), ("mozjs/third_party/python/gyp/test/actions-multiple/src/input.txt", 12), ("mozjs/third_party/python/gyp/test/actions-multiple/src/main.c", 349), ("mozjs/third_party/python/gyp/test/actions-none/gyptest-none.py", 575), ("mozjs/third_party/python/gyp/test/actions-none/src/fake_cross.py", 295), ("mozjs/third_party/python/gyp/test/actions-none/src/foo.cc", 7), ("mozjs/third_party/python/gyp/test/actions-none/src/none_with_source_files.gyp", 842), ("mozjs/third_party/python/gyp/test/actions-subdir/gyptest-action.py", 717), ("mozjs/third_party/python/gyp/test/actions-subdir/src/make-file.py", 272), ("mozjs/third_party/python/gyp/test/actions-subdir/src/none.gyp", 703), ("mozjs/third_party/python/gyp/test/actions-subdir/src/subdir/make-subdir-file.py", 279), ("mozjs/third_party/python/gyp/test/actions-subdir/src/subdir/subdir.gyp", 657), ("mozjs/third_party/python/gyp/test/actions/generated-header/action.py", 292), ("mozjs/third_party/python/gyp/test/actions/generated-header/main.cc", 82), ("mozjs/third_party/python/gyp/test/actions/generated-header/test.gyp", 855), ("mozjs/third_party/python/gyp/test/actions/gyptest-all.py", 3608), ("mozjs/third_party/python/gyp/test/actions/gyptest-default.py", 2338), ("mozjs/third_party/python/gyp/test/actions/gyptest-errors.py", 639), ("mozjs/third_party/python/gyp/test/actions/gyptest-generated-header.py", 1124), ("mozjs/third_party/python/gyp/test/actions/src/action_missing_name.gyp", 482), ("mozjs/third_party/python/gyp/test/actions/src/actions.gyp", 3658), ("mozjs/third_party/python/gyp/test/actions/src/confirm-dep-files.py", 640), ("mozjs/third_party/python/gyp/test/actions/src/subdir1/counter.py", 1107), ("mozjs/third_party/python/gyp/test/actions/src/subdir1/executable.gyp", 2180), ("mozjs/third_party/python/gyp/test/actions/src/subdir1/make-prog1.py", 346), ("mozjs/third_party/python/gyp/test/actions/src/subdir1/make-prog2.py", 346), ("mozjs/third_party/python/gyp/test/actions/src/subdir1/program.c", 171), ("mozjs/third_party/python/gyp/test/actions/src/subdir2/make-file.py", 272), ("mozjs/third_party/python/gyp/test/actions/src/subdir2/none.gyp", 899), ("mozjs/third_party/python/gyp/test/actions/src/subdir3/generate_main.py", 359), ("mozjs/third_party/python/gyp/test/actions/src/subdir3/null_input.gyp", 793), ("mozjs/third_party/python/gyp/test/additional-targets/gyptest-additional.py", 1723), ("mozjs/third_party/python/gyp/test/additional-targets/src/all.gyp", 298), ("mozjs/third_party/python/gyp/test/additional-targets/src/dir1/actions.gyp", 1255), ("mozjs/third_party/python/gyp/test/additional-targets/src/dir1/emit.py", 262), ("mozjs/third_party/python/gyp/test/additional-targets/src/dir1/lib1.c", 76), ("mozjs/third_party/python/gyp/test/analyzer/common.gypi", 161), ("mozjs/third_party/python/gyp/test/analyzer/gyptest-analyzer.py", 13779), ("mozjs/third_party/python/gyp/test/analyzer/static_library_test.gyp", 591), ("mozjs/third_party/python/gyp/test/analyzer/subdir/subdir.gyp", 734), ("mozjs/third_party/python/gyp/test/analyzer/subdir/subdir2/subdir2.gyp", 316), ("mozjs/third_party/python/gyp/test/analyzer/subdir2/subdir.gyp", 370), ("mozjs/third_party/python/gyp/test/analyzer/subdir2/subdir.includes.gypi", 192), ("mozjs/third_party/python/gyp/test/analyzer/test.gyp", 2442), ("mozjs/third_party/python/gyp/test/analyzer/test2.gyp", 504), ("mozjs/third_party/python/gyp/test/analyzer/test2.includes.gypi", 261), ("mozjs/third_party/python/gyp/test/analyzer/test2.includes.includes.gypi", 192), ("mozjs/third_party/python/gyp/test/analyzer/test2.toplevel_includes.gypi", 295), ("mozjs/third_party/python/gyp/test/analyzer/test3.gyp", 1249), ("mozjs/third_party/python/gyp/test/analyzer/test4.gyp", 1367), ("mozjs/third_party/python/gyp/test/analyzer/test5.gyp", 447), ("mozjs/third_party/python/gyp/test/arflags/gyptest-arflags.py", 611), ("mozjs/third_party/python/gyp/test/arflags/lib.cc", 0), ("mozjs/third_party/python/gyp/test/arflags/test.gyp", 162), ("mozjs/third_party/python/gyp/test/assembly/gyptest-assembly.py", 677), ("mozjs/third_party/python/gyp/test/assembly/gyptest-override.py", 610), ("mozjs/third_party/python/gyp/test/assembly/src/as.bat", 59), ("mozjs/third_party/python/gyp/test/assembly/src/assembly.gyp", 1407), ("mozjs/third_party/python/gyp/test/assembly/src/lib1.S", 296), ("mozjs/third_party/python/gyp/test/assembly/src/lib1.c", 41), ("mozjs/third_party/python/gyp/test/assembly/src/override.gyp", 842), ("mozjs/third_party/python/gyp/test/assembly/src/override_asm.asm", 277), ("mozjs/third_party/python/gyp/test/assembly/src/program.c", 214), ("mozjs/third_party/python/gyp/test/build-option/gyptest-build.py", 686), ("mozjs/third_party/python/gyp/test/build-option/hello.c", 247), ("mozjs/third_party/python/gyp/test/build-option/hello.gyp", 299), ("mozjs/third_party/python/gyp/test/builddir/gyptest-all.py", 2684), ("mozjs/third_party/python/gyp/test/builddir/gyptest-default.py", 2674), ("mozjs/third_party/python/gyp/test/builddir/src/builddir.gypi", 424), ("mozjs/third_party/python/gyp/test/builddir/src/func1.c", 75), ("mozjs/third_party/python/gyp/test/builddir/src/func2.c", 75), ("mozjs/third_party/python/gyp/test/builddir/src/func3.c", 75), ("mozjs/third_party/python/gyp/test/builddir/src/func4.c", 75), ("mozjs/third_party/python/gyp/test/builddir/src/func5.c", 75), ("mozjs/third_party/python/gyp/test/builddir/src/prog1.c", 122), ("mozjs/third_party/python/gyp/test/builddir/src/prog1.gyp", 669), ("mozjs/third_party/python/gyp/test/builddir/src/subdir2/prog2.c", 130), ("mozjs/third_party/python/gyp/test/builddir/src/subdir2/prog2.gyp", 366), ("mozjs/third_party/python/gyp/test/builddir/src/subdir2/subdir3/prog3.c", 138), ("mozjs/third_party/python/gyp/test/builddir/src/subdir2/subdir3/prog3.gyp", 372), ("mozjs/third_party/python/gyp/test/builddir/src/subdir2/subdir3/subdir4/prog4.c", 146), ("mozjs/third_party/python/gyp/test/builddir/src/subdir2/subdir3/subdir4/prog4.gyp", 378), ("mozjs/third_party/python/gyp/test/builddir/src/subdir2/subdir3/subdir4/subdir5/prog5.c", 154), ("mozjs/third_party/python/gyp/test/builddir/src/subdir2/subdir3/subdir4/subdir5/prog5.gyp", 384), ("mozjs/third_party/python/gyp/test/cflags/cflags.c", 296), ("mozjs/third_party/python/gyp/test/cflags/cflags.gyp", 454), ("mozjs/third_party/python/gyp/test/cflags/gyptest-cflags.py", 2088), ("mozjs/third_party/python/gyp/test/compilable/gyptest-headers.py", 608), ("mozjs/third_party/python/gyp/test/compilable/src/headers.gyp", 495), ("mozjs/third_party/python/gyp/test/compilable/src/lib1.cpp", 129), ("mozjs/third_party/python/gyp/test/compilable/src/lib1.hpp", 78), ("mozjs/third_party/python/gyp/test/compilable/src/program.cpp", 153), ("mozjs/third_party/python/gyp/test/compiler-override/compiler-exe.gyp", 320), ("mozjs/third_party/python/gyp/test/compiler-override/compiler-global-settings.gyp.in", 963), ("mozjs/third_party/python/gyp/test/compiler-override/compiler-host.gyp", 345), ("mozjs/third_party/python/gyp/test/compiler-override/compiler-shared-lib.gyp", 328), ("mozjs/third_party/python/gyp/test/compiler-override/cxxtest.cc", 298), ("mozjs/third_party/python/gyp/test/compiler-override/gyptest-compiler-env-toolchain.py", 2877), ("mozjs/third_party/python/gyp/test/compiler-override/gyptest-compiler-env.py", 3337), ("mozjs/third_party/python/gyp/test/compiler-override/gyptest-compiler-global-settings.py", 2292), ("mozjs/third_party/python/gyp/test/compiler-override/my_cc.py", 204), ("mozjs/third_party/python/gyp/test/compiler-override/my_cxx.py", 204), ("mozjs/third_party/python/gyp/test/compiler-override/my_ld.py", 204), ("mozjs/third_party/python/gyp/test/compiler-override/my_nm.py", 261), ("mozjs/third_party/python/gyp/test/compiler-override/my_readelf.py", 271), ("mozjs/third_party/python/gyp/test/compiler-override/test.c", 298), ("mozjs/third_party/python/gyp/test/conditions/elseif/elseif.gyp", 1166), ("mozjs/third_party/python/gyp/test/conditions/elseif/elseif_bad1.gyp", 487), ("mozjs/third_party/python/gyp/test/conditions/elseif/elseif_bad2.gyp", 568), ("mozjs/third_party/python/gyp/test/conditions/elseif/elseif_bad3.gyp", 554), ("mozjs/third_party/python/gyp/test/conditions/elseif/elseif_conditions.gypi", 340), ("mozjs/third_party/python/gyp/test/conditions/elseif/gyptest_elseif.py", 906), ("mozjs/third_party/python/gyp/test/conditions/elseif/program.cc", 230), ("mozjs/third_party/python/gyp/test/configurations/basics/configurations.c", 216), ("mozjs/third_party/python/gyp/test/configurations/basics/configurations.gyp", 612), ("mozjs/third_party/python/gyp/test/configurations/basics/gyptest-configurations.py", 777), ("mozjs/third_party/python/gyp/test/configurations/inheritance/configurations.c", 334), ("mozjs/third_party/python/gyp/test/configurations/inheritance/configurations.gyp", 868), ("mozjs/third_party/python/gyp/test/configurations/inheritance/duplicates.gyp", 537), ("mozjs/third_party/python/gyp/test/configurations/inheritance/duplicates.gypd.golden", 575), ("mozjs/third_party/python/gyp/test/configurations/inheritance/gyptest-duplicates.py", 1090), ("mozjs/third_party/python/gyp/test/configurations/inheritance/gyptest-inheritance.py", 1047), ("mozjs/third_party/python/gyp/test/configurations/invalid/actions.gyp", 355), ("mozjs/third_party/python/gyp/test/configurations/invalid/all_dependent_settings.gyp", 370), ("mozjs/third_party/python/gyp/test/configurations/invalid/configurations.gyp", 362), ("mozjs/third_party/python/gyp/test/configurations/invalid/dependencies.gyp", 360), ("mozjs/third_party/python/gyp/test/configurations/invalid/direct_dependent_settings.gyp", 373), ("mozjs/third_party/python/gyp/test/configurations/invalid/gyptest-configurations.py", 923), ("mozjs/third_party/python/gyp/test/configurations/invalid/libraries.gyp", 357), ("mozjs/third_party/python/gyp/test/configurations/invalid/link_settings.gyp", 361), ("mozjs/third_party/python/gyp/test/configurations/invalid/sources.gyp", 355), ("mozjs/third_party/python/gyp/test/configurations/invalid/standalone_static_library.gyp", 361), ("mozjs/third_party/python/gyp/test/configurations/invalid/target_name.gyp", 359), ("mozjs/third_party/python/gyp/test/configurations/invalid/type.gyp", 352), ("mozjs/third_party/python/gyp/test/configurations/target_platform/configurations.gyp", 1174), ("mozjs/third_party/python/gyp/test/configurations/target_platform/front.c", 108), ("mozjs/third_party/python/gyp/test/configurations/target_platform/gyptest-target_platform.py", 1112), ("mozjs/third_party/python/gyp/test/configurations/target_platform/left.c", 47), ("mozjs/third_party/python/gyp/test/configurations/target_platform/right.c", 48), ("mozjs/third_party/python/gyp/test/configurations/x64/configurations.c", 226), ("mozjs/third_party/python/gyp/test/configurations/x64/configurations.gyp", 805), ("mozjs/third_party/python/gyp/test/configurations/x64/gyptest-x86.py", 787), ("mozjs/third_party/python/gyp/test/copies/gyptest-all.py", 1368), ("mozjs/third_party/python/gyp/test/copies/gyptest-attribs.py", 1007), ("mozjs/third_party/python/gyp/test/copies/gyptest-default.py", 1347), ("mozjs/third_party/python/gyp/test/copies/gyptest-samedir.py", 814), ("mozjs/third_party/python/gyp/test/copies/gyptest-slash.py", 1434), ("mozjs/third_party/python/gyp/test/copies/gyptest-sourceless-shared-lib.py", 625), ("mozjs/third_party/python/gyp/test/copies/gyptest-updir.py", 780), ("mozjs/third_party/python/gyp/test/copies/src/copies-attribs.gyp", 407), ("mozjs/third_party/python/gyp/test/copies/src/copies-samedir.gyp", 802), ("mozjs/third_party/python/gyp/test/copies/src/copies-slash.gyp", 944), ("mozjs/third_party/python/gyp/test/copies/src/copies-sourceless-shared-lib.gyp", 710), ("mozjs/third_party/python/gyp/test/copies/src/copies-updir.gyp", 417), ("mozjs/third_party/python/gyp/test/copies/src/copies.gyp", 1548), ("mozjs/third_party/python/gyp/test/copies/src/directory/file3", 15), ("mozjs/third_party/python/gyp/test/copies/src/directory/file4", 15), ("mozjs/third_party/python/gyp/test/copies/src/directory/subdir/file5", 15), ("mozjs/third_party/python/gyp/test/copies/src/executable-file.sh", 44), ("mozjs/third_party/python/gyp/test/copies/src/file1", 15), ("mozjs/third_party/python/gyp/test/copies/src/file2", 15), ("mozjs/third_party/python/gyp/test/copies/src/foo.c", 406), ("mozjs/third_party/python/gyp/test/copies/src/parentdir/subdir/file6", 15), ("mozjs/third_party/python/gyp/test/custom-generator/gyptest-custom-generator.py", 511), ("mozjs/third_party/python/gyp/test/custom-generator/mygenerator.py", 391), ("mozjs/third_party/python/gyp/test/custom-generator/test.gyp", 296), ("mozjs/third_party/python/gyp/test/cxxflags/cxxflags.cc", 290), ("mozjs/third_party/python/gyp/test/cxxflags/cxxflags.gyp", 306), ("mozjs/third_party/python/gyp/test/cxxflags/gyptest-cxxflags.py", 1034), ("mozjs/third_party/python/gyp/test/defines-escaping/defines-escaping.c", 248), ("mozjs/third_party/python/gyp/test/defines-escaping/defines-escaping.gyp", 423), ("mozjs/third_party/python/gyp/test/defines-escaping/gyptest-defines-escaping.py", 4737), ("mozjs/third_party/python/gyp/test/defines/defines-env.gyp", 399), ("mozjs/third_party/python/gyp/test/defines/defines.c", 444), ("mozjs/third_party/python/gyp/test/defines/defines.gyp", 740), ("mozjs/third_party/python/gyp/test/defines/gyptest-define-override.py", 1476), ("mozjs/third_party/python/gyp/test/defines/gyptest-defines-env-regyp.py", 1350), ("mozjs/third_party/python/gyp/test/defines/gyptest-defines-env.py", 1874), ("mozjs/third_party/python/gyp/test/defines/gyptest-defines.py", 973), ("mozjs/third_party/python/gyp/test/dependencies/a.c", 218), ("mozjs/third_party/python/gyp/test/dependencies/adso/all_dependent_settings_order.gyp", 1008), ("mozjs/third_party/python/gyp/test/dependencies/adso/write_args.py", 258), ("mozjs/third_party/python/gyp/test/dependencies/b/b.c", 28), ("mozjs/third_party/python/gyp/test/dependencies/b/b.gyp", 411), ("mozjs/third_party/python/gyp/test/dependencies/b/b3.c", 195), ("mozjs/third_party/python/gyp/test/dependencies/c/c.c", 129), ("mozjs/third_party/python/gyp/test/dependencies/c/c.gyp", 416), ("mozjs/third_party/python/gyp/test/dependencies/c/d.c", 28), ("mozjs/third_party/python/gyp/test/dependencies/double_dependency.gyp", 492), ("mozjs/third_party/python/gyp/test/dependencies/double_dependent.gyp", 257), ("mozjs/third_party/python/gyp/test/dependencies/extra_targets.gyp", 471), ("mozjs/third_party/python/gyp/test/dependencies/gyptest-all-dependent-settings-order.py", 540), ("mozjs/third_party/python/gyp/test/dependencies/gyptest-double-dependency.py", 470), ("mozjs/third_party/python/gyp/test/dependencies/gyptest-extra-targets.py", 551), ("mozjs/third_party/python/gyp/test/dependencies/gyptest-indirect-module-dependency.py", 597), ("mozjs/third_party/python/gyp/test/dependencies/gyptest-lib-only.py", 1207), ("mozjs/third_party/python/gyp/test/dependencies/gyptest-none-traversal.py", 555), ("mozjs/third_party/python/gyp/test/dependencies/gyptest-sharedlib-linksettings.py", 595), ("mozjs/third_party/python/gyp/test/dependencies/lib_only.gyp", 332), ("mozjs/third_party/python/gyp/test/dependencies/main.c", 262), ("mozjs/third_party/python/gyp/test/dependencies/module-dep/a.cc", 196), ("mozjs/third_party/python/gyp/test/dependencies/module-dep/dll.cc", 239), ("mozjs/third_party/python/gyp/test/dependencies/module-dep/exe.cc", 187), ("mozjs/third_party/python/gyp/test/dependencies/module-dep/indirect-module-dependency.gyp", 745), ("mozjs/third_party/python/gyp/test/dependencies/none_traversal.gyp", 963), ("mozjs/third_party/python/gyp/test/dependencies/sharedlib-linksettings/program.c", 494), ("mozjs/third_party/python/gyp/test/dependencies/sharedlib-linksettings/sharedlib.c", 367), ("mozjs/third_party/python/gyp/test/dependencies/sharedlib-linksettings/staticlib.c", 451), ("mozjs/third_party/python/gyp/test/dependencies/sharedlib-linksettings/test.gyp", 918), ("mozjs/third_party/python/gyp/test/dependency-copy/gyptest-copy.py", 640), ("mozjs/third_party/python/gyp/test/dependency-copy/src/copies.gyp", 466), ("mozjs/third_party/python/gyp/test/dependency-copy/src/file1.c", 85), ("mozjs/third_party/python/gyp/test/dependency-copy/src/file2.c", 85), ("mozjs/third_party/python/gyp/test/determinism/determinism.gyp", 1212), ("mozjs/third_party/python/gyp/test/determinism/empty-targets.gyp", 619), ("mozjs/third_party/python/gyp/test/determinism/gyptest-determinism.py", 786), ("mozjs/third_party/python/gyp/test/determinism/gyptest-empty-target-names.py", 783), ("mozjs/third_party/python/gyp/test/determinism/gyptest-needed-variables.py", 802), ("mozjs/third_party/python/gyp/test/determinism/gyptest-solibs.py", 993), ("mozjs/third_party/python/gyp/test/determinism/main.cc", 50), ("mozjs/third_party/python/gyp/test/determinism/needed-variables.gyp", 904), ("mozjs/third_party/python/gyp/test/determinism/rule.py", 43), ("mozjs/third_party/python/gyp/test/determinism/solib.cc", 117), ("mozjs/third_party/python/gyp/test/determinism/solibs.gyp", 691), ("mozjs/third_party/python/gyp/test/empty-target/empty-target.gyp", 253), ("mozjs/third_party/python/gyp/test/empty-target/gyptest-empty-target.py", 405), ("mozjs/third_party/python/gyp/test/errors/dependency_cycle.gyp", 489), ("mozjs/third_party/python/gyp/test/errors/duplicate_basenames.gyp", 291), ("mozjs/third_party/python/gyp/test/errors/duplicate_node.gyp", 306), ("mozjs/third_party/python/gyp/test/errors/duplicate_rule.gyp", 432), ("mozjs/third_party/python/gyp/test/errors/duplicate_targets.gyp", 260), ("mozjs/third_party/python/gyp/test/errors/error_command.gyp", 286), ("mozjs/third_party/python/gyp/test/errors/file_cycle0.gyp", 358), ("mozjs/third_party/python/gyp/test/errors/file_cycle1.gyp", 295), ("mozjs/third_party/python/gyp/test/errors/gyptest-errors.py", 3158), ("mozjs/third_party/python/gyp/test/errors/missing_command.gyp", 290), ("mozjs/third_party/python/gyp/test/errors/missing_dep.gyp", 307), ("mozjs/third_party/python/gyp/test/errors/missing_targets.gyp", 189), ("mozjs/third_party/python/gyp/test/escaping/colon/test.gyp", 504), ("mozjs/third_party/python/gyp/test/escaping/gyptest-colon.py", 1530), ("mozjs/third_party/python/gyp/test/exclusion/exclusion.gyp", 476), ("mozjs/third_party/python/gyp/test/exclusion/gyptest-exclusion.py", 536), ("mozjs/third_party/python/gyp/test/exclusion/hello.c", 304), ("mozjs/third_party/python/gyp/test/external-cross-compile/gyptest-cross.py", 655), ("mozjs/third_party/python/gyp/test/external-cross-compile/src/bogus1.cc", 15), ("mozjs/third_party/python/gyp/test/external-cross-compile/src/bogus2.c", 14), ("mozjs/third_party/python/gyp/test/external-cross-compile/src/cross.gyp", 1865), ("mozjs/third_party/python/gyp/test/external-cross-compile/src/cross_compile.gypi", 596), ("mozjs/third_party/python/gyp/test/external-cross-compile/src/fake_cross.py", 367), ("mozjs/third_party/python/gyp/test/external-cross-compile/src/program.cc", 313), ("mozjs/third_party/python/gyp/test/external-cross-compile/src/test1.cc", 14), ("mozjs/third_party/python/gyp/test/external-cross-compile/src/test2.c", 13), ("mozjs/third_party/python/gyp/test/external-cross-compile/src/test3.cc", 14), ("mozjs/third_party/python/gyp/test/external-cross-compile/src/test4.c", 13), ("mozjs/third_party/python/gyp/test/external-cross-compile/src/tochar.py", 317), ("mozjs/third_party/python/gyp/test/generator-output/actions/actions.gyp", 359), ("mozjs/third_party/python/gyp/test/generator-output/actions/build/README.txt", 245), ("mozjs/third_party/python/gyp/test/generator-output/actions/subdir1/actions-out/README.txt", 245), ("mozjs/third_party/python/gyp/test/generator-output/actions/subdir1/build/README.txt", 245), ("mozjs/third_party/python/gyp/test/generator-output/actions/subdir1/executable.gyp", 1019), ("mozjs/third_party/python/gyp/test/generator-output/actions/subdir1/make-prog1.py", 346), ("mozjs/third_party/python/gyp/test/generator-output/actions/subdir1/make-prog2.py", 346), ("mozjs/third_party/python/gyp/test/generator-output/actions/subdir1/program.c", 171), ("mozjs/third_party/python/gyp/test/generator-output/actions/subdir2/actions-out/README.txt", 245), ("mozjs/third_party/python/gyp/test/generator-output/actions/subdir2/build/README.txt", 245), ("mozjs/third_party/python/gyp/test/generator-output/actions/subdir2/make-file.py", 272), ("mozjs/third_party/python/gyp/test/generator-output/actions/subdir2/none.gyp", 806), ("mozjs/third_party/python/gyp/test/generator-output/copies/build/README.txt", 241), ("mozjs/third_party/python/gyp/test/generator-output/copies/copies-out/README.txt", 241), ("mozjs/third_party/python/gyp/test/generator-output/copies/copies.gyp", 1015), ("mozjs/third_party/python/gyp/test/generator-output/copies/file1", 15), ("mozjs/third_party/python/gyp/test/generator-output/copies/file2", 15), ("mozjs/third_party/python/gyp/test/generator-output/copies/subdir/build/README.txt", 241), ("mozjs/third_party/python/gyp/test/generator-output/copies/subdir/copies-out/README.txt", 241), ("mozjs/third_party/python/gyp/test/generator-output/copies/subdir/file3", 15), ("mozjs/third_party/python/gyp/test/generator-output/copies/subdir/file4", 15), ("mozjs/third_party/python/gyp/test/generator-output/copies/subdir/subdir.gyp", 614), ("mozjs/third_party/python/gyp/test/generator-output/gyptest-actions.py", 1953), ("mozjs/third_party/python/gyp/test/generator-output/gyptest-copies.py", 1854), ("mozjs/third_party/python/gyp/test/generator-output/gyptest-depth.py", 1561), ("mozjs/third_party/python/gyp/test/generator-output/gyptest-mac-bundle.py", 794), ("mozjs/third_party/python/gyp/test/generator-output/gyptest-relocate.py", 1604), ("mozjs/third_party/python/gyp/test/generator-output/gyptest-rules.py", 1702), ("mozjs/third_party/python/gyp/test/generator-output/gyptest-subdir2-deep.py", 968), ("mozjs/third_party/python/gyp/test/generator-output/gyptest-symlink.py", 1226), ("mozjs/third_party/python/gyp/test/generator-output/gyptest-top-all.py", 1371), ("mozjs/third_party/python/gyp/test/generator-output/mac-bundle/Info.plist", 979), ("mozjs/third_party/python/gyp/test/generator-output/mac-bundle/app.order", 6), ("mozjs/third_party/python/gyp/test/generator-output/mac-bundle/header.h", 9), ("mozjs/third_party/python/gyp/test/generator-output/mac-bundle/main.c", 14), ("mozjs/third_party/python/gyp/test/generator-output/mac-bundle/resource.sb", 13), ("mozjs/third_party/python/gyp/test/generator-output/mac-bundle/test.gyp", 623), ("mozjs/third_party/python/gyp/test/generator-output/rules/build/README.txt", 245), ("mozjs/third_party/python/gyp/test/generator-output/rules/copy-file.py", 286), ("mozjs/third_party/python/gyp/test/generator-output/rules/rules.gyp", 359), ("mozjs/third_party/python/gyp/test/generator-output/rules/subdir1/build/README.txt", 245), ("mozjs/third_party/python/gyp/test/generator-output/rules/subdir1/define3.in0", 43), ("mozjs/third_party/python/gyp/test/generator-output/rules/subdir1/define4.in0", 43), ("mozjs/third_party/python/gyp/test/generator-output/rules/subdir1/executable.gyp", 1641), ("mozjs/third_party/python/gyp/test/generator-output/rules/subdir1/function1.in1", 85), ("mozjs/third_party/python/gyp/test/generator-output/rules/subdir1/function2.in1", 85), ("mozjs/third_party/python/gyp/test/generator-output/rules/subdir1/program.c", 326), ("mozjs/third_party/python/gyp/test/generator-output/rules/subdir2/build/README.txt", 245), ("mozjs/third_party/python/gyp/test/generator-output/rules/subdir2/file1.in0", 21), ("mozjs/third_party/python/gyp/test/generator-output/rules/subdir2/file2.in0", 21), ("mozjs/third_party/python/gyp/test/generator-output/rules/subdir2/file3.in1", 21), ("mozjs/third_party/python/gyp/test/generator-output/rules/subdir2/file4.in1", 21), ("mozjs/third_party/python/gyp/test/generator-output/rules/subdir2/none.gyp", 1196), ("mozjs/third_party/python/gyp/test/generator-output/rules/subdir2/rules-out/README.txt", 245), ("mozjs/third_party/python/gyp/test/generator-output/src/build/README.txt", 241), ("mozjs/third_party/python/gyp/test/generator-output/src/inc.h", 32), ("mozjs/third_party/python/gyp/test/generator-output/src/inc1/include1.h", 42), ("mozjs/third_party/python/gyp/test/generator-output/src/prog1.c", 412), ("mozjs/third_party/python/gyp/test/generator-output/src/prog1.gyp", 544), ("mozjs/third_party/python/gyp/test/generator-output/src/subdir2/build/README.txt", 241), ("mozjs/third_party/python/gyp/test/generator-output/src/subdir2/deeper/build/README.txt", 241), ("mozjs/third_party/python/gyp/test/generator-output/src/subdir2/deeper/deeper.c", 86), ("mozjs/third_party/python/gyp/test/generator-output/src/subdir2/deeper/deeper.gyp", 348), ("mozjs/third_party/python/gyp/test/generator-output/src/subdir2/deeper/deeper.h", 50), ("mozjs/third_party/python/gyp/test/generator-output/src/subdir2/inc2/include2.h", 42), ("mozjs/third_party/python/gyp/test/generator-output/src/subdir2/prog2.c", 412), ("mozjs/third_party/python/gyp/test/generator-output/src/subdir2/prog2.gyp", 541), ("mozjs/third_party/python/gyp/test/generator-output/src/subdir3/build/README.txt", 241), ("mozjs/third_party/python/gyp/test/generator-output/src/subdir3/inc3/include3.h", 42), ("mozjs/third_party/python/gyp/test/generator-output/src/subdir3/prog3.c", 412), ("mozjs/third_party/python/gyp/test/generator-output/src/subdir3/prog3.gyp", 481), ("mozjs/third_party/python/gyp/test/generator-output/src/symroot.gypi", 336), ("mozjs/third_party/python/gyp/test/gyp-defines/defines.gyp", 566), ("mozjs/third_party/python/gyp/test/gyp-defines/echo.py", 251), ("mozjs/third_party/python/gyp/test/gyp-defines/gyptest-multiple-values.py", 1013), ("mozjs/third_party/python/gyp/test/gyp-defines/gyptest-regyp.py", 1260), ("mozjs/third_party/python/gyp/test/hard_dependency/gyptest-exported-hard-dependency.py", 1332), ("mozjs/third_party/python/gyp/test/hard_dependency/gyptest-no-exported-hard-dependency.py", 1226), ("mozjs/third_party/python/gyp/test/hard_dependency/src/a.c", 208), ("mozjs/third_party/python/gyp/test/hard_dependency/src/a.h", 244), ("mozjs/third_party/python/gyp/test/hard_dependency/src/b.c", 213), ("mozjs/third_party/python/gyp/test/hard_dependency/src/b.h", 236), ("mozjs/third_party/python/gyp/test/hard_dependency/src/c.c", 228), ("mozjs/third_party/python/gyp/test/hard_dependency/src/c.h", 220), ("mozjs/third_party/python/gyp/test/hard_dependency/src/d.c", 213), ("mozjs/third_party/python/gyp/test/hard_dependency/src/emit.py", 261), ("mozjs/third_party/python/gyp/test/hard_dependency/src/hard_dependency.gyp", 1579), ("mozjs/third_party/python/gyp/test/hello/gyptest-all.py", 537), ("mozjs/third_party/python/gyp/test/hello/gyptest-default.py", 526), ("mozjs/third_party/python/gyp/test/hello/gyptest-disable-regyp.py", 860), ("mozjs/third_party/python/gyp/test/hello/gyptest-regyp-output.py", 1052), ("mozjs/third_party/python/gyp/test/hello/gyptest-regyp.py", 828), ("mozjs/third_party/python/gyp/test/hello/gyptest-target.py", 540), ("mozjs/third_party/python/gyp/test/hello/hello.c", 243), ("mozjs/third_party/python/gyp/test/hello/hello.gyp", 299), ("mozjs/third_party/python/gyp/test/hello/hello2.c", 241), ("mozjs/third_party/python/gyp/test/hello/hello2.gyp", 300), ("mozjs/third_party/python/gyp/test/home_dot_gyp/gyptest-home-includes-config-arg.py", 817), ("mozjs/third_party/python/gyp/test/home_dot_gyp/gyptest-home-includes-config-env.py", 919), ("mozjs/third_party/python/gyp/test/home_dot_gyp/gyptest-home-includes-regyp.py", 1282), ("mozjs/third_party/python/gyp/test/home_dot_gyp/gyptest-home-includes.py", 757), ("mozjs/third_party/python/gyp/test/home_dot_gyp/home/.gyp/include.gypi", 51), ("mozjs/third_party/python/gyp/test/home_dot_gyp/home2/.gyp/include.gypi", 52), ("mozjs/third_party/python/gyp/test/home_dot_gyp/home2/.gyp_new/include.gypi", 52), ("mozjs/third_party/python/gyp/test/home_dot_gyp/src/all.gyp", 407), ("mozjs/third_party/python/gyp/test/home_dot_gyp/src/printfoo.c", 81), ("mozjs/third_party/python/gyp/test/include_dirs/gyptest-all.py", 976), ("mozjs/third_party/python/gyp/test/include_dirs/gyptest-default.py", 967), ("mozjs/third_party/python/gyp/test/include_dirs/src/inc.h", 27), ("mozjs/third_party/python/gyp/test/include_dirs/src/inc1/include1.h", 37), ("mozjs/third_party/python/gyp/test/include_dirs/src/includes.c", 534), ("mozjs/third_party/python/gyp/test/include_dirs/src/includes.gyp", 544), ("mozjs/third_party/python/gyp/test/include_dirs/src/shadow1/shadow.h", 41), ("mozjs/third_party/python/gyp/test/include_dirs/src/shadow2/shadow.h", 41), ("mozjs/third_party/python/gyp/test/include_dirs/src/subdir/inc.h", 34), ("mozjs/third_party/python/gyp/test/include_dirs/src/subdir/inc2/include2.h", 49), ("mozjs/third_party/python/gyp/test/include_dirs/src/subdir/subdir_includes.c", 297), ("mozjs/third_party/python/gyp/test/include_dirs/src/subdir/subdir_includes.gyp", 400), ("mozjs/third_party/python/gyp/test/intermediate_dir/gyptest-intermediate-dir.py", 1400), ("mozjs/third_party/python/gyp/test/intermediate_dir/src/script.py", 698), ("mozjs/third_party/python/gyp/test/intermediate_dir/src/shared_infile.txt", 12), ("mozjs/third_party/python/gyp/test/intermediate_dir/src/test.gyp", 1160), ("mozjs/third_party/python/gyp/test/intermediate_dir/src/test2.gyp", 1160), ("mozjs/third_party/python/gyp/test/ios/app-bundle/TestApp/English.lproj/InfoPlist-error.strings", 103), ("mozjs/third_party/python/gyp/test/ios/app-bundle/TestApp/English.lproj/InfoPlist.strings", 104), ("mozjs/third_party/python/gyp/test/ios/app-bundle/TestApp/English.lproj/LanguageMap.plist", 226), ("mozjs/third_party/python/gyp/test/ios/app-bundle/TestApp/English.lproj/MainMenu.xib", 1042), ("mozjs/third_party/python/gyp/test/ios/app-bundle/TestApp/English.lproj/Main_iPhone.storyboard", 1565), ("mozjs/third_party/python/gyp/test/ios/app-bundle/TestApp/Images.xcassets/AppIcon.appiconset/Contents.json", 903), ("mozjs/third_party/python/gyp/test/ios/app-bundle/TestApp/Images.xcassets/image.imageset/Contents.json", 396), ("mozjs/third_party/python/gyp/test/ios/app-bundle/TestApp/Images.xcassets/image.imageset/super_sylvain.png", 3263), ("mozjs/third_party/python/gyp/test/ios/app-bundle/TestApp/Images.xcassets/image.imageset/super_sylvain@2x.png", 3847), ("mozjs/third_party/python/gyp/test/ios/app-bundle/TestApp/Images.xcassets/image.imageset/super_sylvain@3x.png", 4394), ("mozjs/third_party/python/gyp/test/ios/app-bundle/TestApp/TestApp-Info.plist", 850), ("mozjs/third_party/python/gyp/test/ios/app-bundle/TestApp/check_no_signature.py", 414), ("mozjs/third_party/python/gyp/test/ios/app-bundle/TestApp/main.m", 376), ("mozjs/third_party/python/gyp/test/ios/app-bundle/TestApp/only-compile-in-32-bits.m", 210), ("mozjs/third_party/python/gyp/test/ios/app-bundle/TestApp/only-compile-in-64-bits.m", 211), ("mozjs/third_party/python/gyp/test/ios/app-bundle/test-archs.gyp", 2598), ("mozjs/third_party/python/gyp/test/ios/app-bundle/test-assets-catalog.gyp", 1304), ("mozjs/third_party/python/gyp/test/ios/app-bundle/test-crosscompile.gyp", 1180), ("mozjs/third_party/python/gyp/test/ios/app-bundle/test-device.gyp", 3078), ("mozjs/third_party/python/gyp/test/ios/app-bundle/test.gyp", 2251), ("mozjs/third_party/python/gyp/test/ios/app-bundle/tool_main.cc", 187), ("mozjs/third_party/python/gyp/test/ios/copies-with-xcode-envvars/Info.plist", 738), ("mozjs/third_party/python/gyp/test/ios/copies-with-xcode-envvars/copies-with-xcode-envvars.gyp", 2937), ("mozjs/third_party/python/gyp/test/ios/copies-with-xcode-envvars/empty.c", 14), ("mozjs/third_party/python/gyp/test/ios/copies-with-xcode-envvars/file0", 15), ("mozjs/third_party/python/gyp/test/ios/copies-with-xcode-envvars/file1", 15), ("mozjs/third_party/python/gyp/test/ios/copies-with-xcode-envvars/file10", 16), ("mozjs/third_party/python/gyp/test/ios/copies-with-xcode-envvars/file11", 16), ("mozjs/third_party/python/gyp/test/ios/copies-with-xcode-envvars/file2", 15), ("mozjs/third_party/python/gyp/test/ios/copies-with-xcode-envvars/file3", 15), ("mozjs/third_party/python/gyp/test/ios/copies-with-xcode-envvars/file4", 15), ("mozjs/third_party/python/gyp/test/ios/copies-with-xcode-envvars/file5", 15), ("mozjs/third_party/python/gyp/test/ios/copies-with-xcode-envvars/file6", 15), ("mozjs/third_party/python/gyp/test/ios/copies-with-xcode-envvars/file7", 15), ("mozjs/third_party/python/gyp/test/ios/copies-with-xcode-envvars/file8", 15), ("mozjs/third_party/python/gyp/test/ios/copies-with-xcode-envvars/file9", 15), ("mozjs/third_party/python/gyp/test/ios/deployment-target/check-version-min.c", 1114), ("mozjs/third_party/python/gyp/test/ios/deployment-target/deployment-target.gyp", 990), ("mozjs/third_party/python/gyp/test/ios/extension/ActionExtension/ActionViewController.h", 242), ("mozjs/third_party/python/gyp/test/ios/extension/ActionExtension/ActionViewController.m", 773), ("mozjs/third_party/python/gyp/test/ios/extension/ActionExtension/Info.plist", 1276), ("mozjs/third_party/python/gyp/test/ios/extension/ActionExtension/MainInterface.storyboard", 4753), ("mozjs/third_party/python/gyp/test/ios/extension/ExtensionContainer/AppDelegate.h", 302), ("mozjs/third_party/python/gyp/test/ios/extension/ExtensionContainer/AppDelegate.m", 446), ("mozjs/third_party/python/gyp/test/ios/extension/ExtensionContainer/Base.lproj/Main.storyboard", 1575), ("mozjs/third_party/python/gyp/test/ios/extension/ExtensionContainer/Images.xcassets/AppIcon.appiconset/Contents.json", 826), ("mozjs/third_party/python/gyp/test/ios/extension/ExtensionContainer/Images.xcassets/LaunchImage.launchimage/Contents.json", 1101), ("mozjs/third_party/python/gyp/test/ios/extension/ExtensionContainer/Info.plist", 995), ("mozjs/third_party/python/gyp/test/ios/extension/ExtensionContainer/ViewController.h", 238), ("mozjs/third_party/python/gyp/test/ios/extension/ExtensionContainer/ViewController.m", 510), ("mozjs/third_party/python/gyp/test/ios/extension/ExtensionContainer/main.m", 368), ("mozjs/third_party/python/gyp/test/ios/extension/extension.gyp", 2825), ("mozjs/third_party/python/gyp/test/ios/framework/framework.gyp", 1311), ("mozjs/third_party/python/gyp/test/ios/framework/iOSFramework/Info.plist", 806), ("mozjs/third_party/python/gyp/test/ios/framework/iOSFramework/Thing.h", 136), ("mozjs/third_party/python/gyp/test/ios/framework/iOSFramework/Thing.m", 309), ("mozjs/third_party/python/gyp/test/ios/framework/iOSFramework/iOSFramework.h", 267), ("mozjs/third_party/python/gyp/test/ios/gyptest-app-ios-assets-catalog.py", 1726), ("mozjs/third_party/python/gyp/test/ios/gyptest-app-ios.py", 2419), ("mozjs/third_party/python/gyp/test/ios/gyptest-archs.py", 1886), ("mozjs/third_party/python/gyp/test/ios/gyptest-copies-with-xcode-envvars.py", 2952), ("mozjs/third_party/python/gyp/test/ios/gyptest-crosscompile.py", 851), ("mozjs/third_party/python/gyp/test/ios/gyptest-deployment-target.py", 520), ("mozjs/third_party/python/gyp/test/ios/gyptest-extension.py", 1337), ("mozjs/third_party/python/gyp/test/ios/gyptest-framework.py", 918), ("mozjs/third_party/python/gyp/test/ios/gyptest-per-config-settings.py", 7134), ("mozjs/third_party/python/gyp/test/ios/gyptest-watch.py", 982), ("mozjs/third_party/python/gyp/test/ios/gyptest-xcode-ninja.py", 599), ("mozjs/third_party/python/gyp/test/ios/watch/WatchApp/Images.xcassets/AppIcon.appiconset/Contents.json", 1172), ("mozjs/third_party/python/gyp/test/ios/watch/WatchApp/Images.xcassets/LaunchImage.launchimage/Contents.json", 464), ("mozjs/third_party/python/gyp/test/ios/watch/WatchApp/Info.plist", 1123), ("mozjs/third_party/python/gyp/test/ios/watch/WatchApp/Interface.storyboard", 810), ("mozjs/third_party/python/gyp/test/ios/watch/WatchContainer/AppDelegate.h", 302), ("mozjs/third_party/python/gyp/test/ios/watch/WatchContainer/AppDelegate.m", 446), ("mozjs/third_party/python/gyp/test/ios/watch/WatchContainer/Base.lproj/Main.storyboard", 1575), ("mozjs/third_party/python/gyp/test/ios/watch/WatchContainer/Images.xcassets/AppIcon.appiconset/Contents.json", 826), ("mozjs/third_party/python/gyp/test/ios/watch/WatchContainer/Images.xcassets/LaunchImage.launchimage/Contents.json", 1101), ("mozjs/third_party/python/gyp/test/ios/watch/WatchContainer/Info.plist", 987), ("mozjs/third_party/python/gyp/test/ios/watch/WatchContainer/ViewController.h", 238), ("mozjs/third_party/python/gyp/test/ios/watch/WatchContainer/ViewController.m", 510), ("mozjs/third_party/python/gyp/test/ios/watch/WatchContainer/main.m", 368), ("mozjs/third_party/python/gyp/test/ios/watch/WatchKitExtension/Images.xcassets/MyImage.imageset/Contents.json", 271), ("mozjs/third_party/python/gyp/test/ios/watch/WatchKitExtension/Info.plist", 1217), ("mozjs/third_party/python/gyp/test/ios/watch/WatchKitExtension/InterfaceController.h", 286), ("mozjs/third_party/python/gyp/test/ios/watch/WatchKitExtension/InterfaceController.m", 471), ("mozjs/third_party/python/gyp/test/ios/watch/WatchKitExtension/MainInterface.storyboard", 4753), ("mozjs/third_party/python/gyp/test/ios/watch/watch.gyp", 3000), ("mozjs/third_party/python/gyp/test/ios/xctests/App/AppDelegate.h", 305), ("mozjs/third_party/python/gyp/test/ios/xctests/App/AppDelegate.m", 388), ("mozjs/third_party/python/gyp/test/ios/xctests/App/Base.lproj/LaunchScreen.xib", 3694), ("mozjs/third_party/python/gyp/test/ios/xctests/App/Base.lproj/Main.storyboard", 1575), ("mozjs/third_party/python/gyp/test/ios/xctests/App/Images.xcassets/AppIcon.appiconset/Contents.json", 1077), ("mozjs/third_party/python/gyp/test/ios/xctests/App/Info.plist", 1489), ("mozjs/third_party/python/gyp/test/ios/xctests/App/ViewController.h", 242), ("mozjs/third_party/python/gyp/test/ios/xctests/App/ViewController.m", 387), ("mozjs/third_party/python/gyp/test/ios/xctests/App/main.m", 373), ("mozjs/third_party/python/gyp/test/ios/xctests/AppTests/AppTests.m", 494), ("mozjs/third_party/python/gyp/test/ios/xctests/AppTests/Info.plist", 732), ("mozjs/third_party/python/gyp/test/ios/xctests/gyptest-xctests.py", 1862), ("mozjs/third_party/python/gyp/test/ios/xctests/xctests.gyp", 2067), ("mozjs/third_party/python/gyp/test/lib/README.txt", 542), ("mozjs/third_party/python/gyp/test/lib/TestCmd.py", 52544), ("mozjs/third_party/python/gyp/test/lib/TestCommon.py", 21747), ("mozjs/third_party/python/gyp/test/lib/TestGyp.py", 44094), ("mozjs/third_party/python/gyp/test/lib/TestMac.py", 2472), ("mozjs/third_party/python/gyp/test/lib/TestWin.py", 3168), ("mozjs/third_party/python/gyp/test/library/gyptest-shared-obj-install-path.py", 1098), ("mozjs/third_party/python/gyp/test/library/gyptest-shared.py", 2230), ("mozjs/third_party/python/gyp/test/library/gyptest-static.py", 2241), ("mozjs/third_party/python/gyp/test/library/src/lib1.c", 152), ("mozjs/third_party/python/gyp/test/library/src/lib1_moveable.c", 165), ("mozjs/third_party/python/gyp/test/library/src/lib2.c", 152), ("mozjs/third_party/python/gyp/test/library/src/lib2_moveable.c", 165), ("mozjs/third_party/python/gyp/test/library/src/library.gyp", 1136), ("mozjs/third_party/python/gyp/test/library/src/program.c", 279), ("mozjs/third_party/python/gyp/test/library/src/shared_dependency.gyp", 655), ("mozjs/third_party/python/gyp/test/library_dirs/gyptest-library-dirs.py", 1421), ("mozjs/third_party/python/gyp/test/library_dirs/subdir/README.txt", 77), ("mozjs/third_party/python/gyp/test/library_dirs/subdir/hello.cc", 279), ("mozjs/third_party/python/gyp/test/library_dirs/subdir/mylib.cc", 241), ("mozjs/third_party/python/gyp/test/library_dirs/subdir/mylib.h", 332), ("mozjs/third_party/python/gyp/test/library_dirs/subdir/test-win.gyp", 2092), ("mozjs/third_party/python/gyp/test/library_dirs/subdir/test.gyp", 2307), ("mozjs/third_party/python/gyp/test/link-dependency/gyptest-link-dependency.py", 624), ("mozjs/third_party/python/gyp/test/link-dependency/main.c", 113), ("mozjs/third_party/python/gyp/test/link-dependency/mymalloc.c", 276), ("mozjs/third_party/python/gyp/test/link-dependency/test.gyp", 741), ("mozjs/third_party/python/gyp/test/link-objects/base.c", 57), ("mozjs/third_party/python/gyp/test/link-objects/extra.c", 57), ("mozjs/third_party/python/gyp/test/link-objects/gyptest-all.py", 623), ("mozjs/third_party/python/gyp/test/link-objects/link-objects.gyp", 574), ("mozjs/third_party/python/gyp/test/linux/gyptest-implicit-rpath.py", 1172), ("mozjs/third_party/python/gyp/test/linux/gyptest-ldflags-duplicates.py", 455), ("mozjs/third_party/python/gyp/test/linux/gyptest-ldflags-from-environment.py", 1257), ("mozjs/third_party/python/gyp/test/linux/gyptest-target-rpath.py", 1067), ("mozjs/third_party/python/gyp/test/linux/implicit-rpath/file.c", 12), ("mozjs/third_party/python/gyp/test/linux/implicit-rpath/main.c", 14), ("mozjs/third_party/python/gyp/test/linux/implicit-rpath/test.gyp", 1069), ("mozjs/third_party/python/gyp/test/linux/ldflags-duplicates/check-ldflags.py", 684), ("mozjs/third_party/python/gyp/test/linux/ldflags-duplicates/lib1.c", 175), ("mozjs/third_party/python/gyp/test/linux/ldflags-duplicates/lib2.c", 175), ("mozjs/third_party/python/gyp/test/linux/ldflags-duplicates/main.c", 187), ("mozjs/third_party/python/gyp/test/linux/ldflags-duplicates/test.gyp", 958), ("mozjs/third_party/python/gyp/test/linux/ldflags-from-environment/main.c", 187), ("mozjs/third_party/python/gyp/test/linux/ldflags-from-environment/test.gyp", 452), ("mozjs/third_party/python/gyp/test/linux/target-rpath/file.c", 12), ("mozjs/third_party/python/gyp/test/linux/target-rpath/main.c", 14), ("mozjs/third_party/python/gyp/test/linux/target-rpath/test.gyp", 1069), ("mozjs/third_party/python/gyp/test/mac/action-envvars/action/action.gyp", 920), ("mozjs/third_party/python/gyp/test/mac/action-envvars/action/action.sh", 244), ("mozjs/third_party/python/gyp/test/mac/app-bundle/TestApp/English.lproj/InfoPlist-error.strings", 103), ("mozjs/third_party/python/gyp/test/mac/app-bundle/TestApp/English.lproj/InfoPlist.strings", 104), ("mozjs/third_party/python/gyp/test/mac/app-bundle/TestApp/English.lproj/MainMenu.xib", 179051), ("mozjs/third_party/python/gyp/test/mac/app-bundle/TestApp/English.lproj/utf-16be.strings", 208), ("mozjs/third_party/python/gyp/test/mac/app-bundle/TestApp/English.lproj/utf-16le.strings", 208), ("mozjs/third_party/python/gyp/test/mac/app-bundle/TestApp/Images.xcassets/AppIcon.appiconset/Contents.json", 903), ("mozjs/third_party/python/gyp/test/mac/app-bundle/TestApp/Images.xcassets/image.imageset/Contents.json", 396), ("mozjs/third_party/python/gyp/test/mac/app-bundle/TestApp/Images.xcassets/image.imageset/super_sylvain.png", 3263), ("mozjs/third_party/python/gyp/test/mac/app-bundle/TestApp/Images.xcassets/image.imageset/super_sylvain@2x.png", 3847), ("mozjs/third_party/python/gyp/test/mac/app-bundle/TestApp/Images.xcassets/image.imageset/super_sylvain@3x.png", 4394), ("mozjs/third_party/python/gyp/test/mac/app-bundle/TestApp/TestApp-Info.plist", 1083), ("mozjs/third_party/python/gyp/test/mac/app-bundle/TestApp/TestAppAppDelegate.h", 329), ("mozjs/third_party/python/gyp/test/mac/app-bundle/TestApp/TestAppAppDelegate.m", 382), ("mozjs/third_party/python/gyp/test/mac/app-bundle/TestApp/main.m", 281), ("mozjs/third_party/python/gyp/test/mac/app-bundle/empty.c", 0), ("mozjs/third_party/python/gyp/test/mac/app-bundle/test-assets-catalog.gyp", 1282), ("mozjs/third_party/python/gyp/test/mac/app-bundle/test-error.gyp", 856), ("mozjs/third_party/python/gyp/test/mac/app-bundle/test.gyp", 1188), ("mozjs/third_party/python/gyp/test/mac/archs/empty_main.cc", 14), ("mozjs/third_party/python/gyp/test/mac/archs/file.mm", 24), ("mozjs/third_party/python/gyp/test/mac/archs/file_a.cc", 211), ("mozjs/third_party/python/gyp/test/mac/archs/file_a.h", 341), ("mozjs/third_party/python/gyp/test/mac/archs/file_b.cc", 211), ("mozjs/third_party/python/gyp/test/mac/archs/file_b.h", 341), ("mozjs/third_party/python/gyp/test/mac/archs/file_c.cc", 276), ("mozjs/third_party/python/gyp/test/mac/archs/file_d.cc", 276), ("mozjs/third_party/python/gyp/test/mac/archs/header.h", 19), ("mozjs/third_party/python/gyp/test/mac/archs/my_file.cc", 173), ("mozjs/third_party/python/gyp/test/mac/archs/my_main_file.cc", 245), ("mozjs/third_party/python/gyp/test/mac/archs/test-archs-multiarch.gyp", 2306), ("mozjs/third_party/python/gyp/test/mac/archs/test-archs-x86_64.gyp", 571), ("mozjs/third_party/python/gyp/test/mac/archs/test-dependencies.gyp", 1891), ("mozjs/third_party/python/gyp/test/mac/archs/test-no-archs.gyp", 453), ("mozjs/third_party/python/gyp/test/mac/archs/test-valid-archs.gyp", 690), ("mozjs/third_party/python/gyp/test/mac/bundle-resources/change.sh", 42), ("mozjs/third_party/python/gyp/test/mac/bundle-resources/executable-file.sh", 44), ("mozjs/third_party/python/gyp/test/mac/bundle-resources/secret.txt", 4), ("mozjs/third_party/python/gyp/test/mac/bundle-resources/test.gyp", 1600), ("mozjs/third_party/python/gyp/test/mac/cflags/ccfile.cc", 102), ("mozjs/third_party/python/gyp/test/mac/cflags/ccfile_withcflags.cc", 99), ("mozjs/third_party/python/gyp/test/mac/cflags/cfile.c", 102), ("mozjs/third_party/python/gyp/test/mac/cflags/cppfile.cpp", 102), ("mozjs/third_party/python/gyp/test/mac/cflags/cppfile_withcflags.cpp", 99), ("mozjs/third_party/python/gyp/test/mac/cflags/cxxfile.cxx", 102), ("mozjs/third_party/python/gyp/test/mac/cflags/cxxfile_withcflags.cxx", 99), ("mozjs/third_party/python/gyp/test/mac/cflags/mfile.m", 102), ("mozjs/third_party/python/gyp/test/mac/cflags/mmfile.mm", 102), ("mozjs/third_party/python/gyp/test/mac/cflags/mmfile_withcflags.mm", 99), ("mozjs/third_party/python/gyp/test/mac/cflags/test.gyp", 3140), ("mozjs/third_party/python/gyp/test/mac/clang-cxx-language-standard/c++11.cc", 256), ("mozjs/third_party/python/gyp/test/mac/clang-cxx-language-standard/c++98.cc", 420), ("mozjs/third_party/python/gyp/test/mac/clang-cxx-language-standard/clang-cxx-language-standard.gyp", 775), ("mozjs/third_party/python/gyp/test/mac/clang-cxx-library/clang-cxx-library.gyp", 776), ("mozjs/third_party/python/gyp/test/mac/clang-cxx-library/libc++.cc", 294), ("mozjs/third_party/python/gyp/test/mac/clang-cxx-library/libstdc++.cc", 293), ("mozjs/third_party/python/gyp/test/mac/copies-with-xcode-envvars/copies-with-xcode-envvars.gyp", 2537), ("mozjs/third_party/python/gyp/test/mac/copies-with-xcode-envvars/empty.c", 14), ("mozjs/third_party/python/gyp/test/mac/copies-with-xcode-envvars/file0", 15), ("mozjs/third_party/python/gyp/test/mac/copies-with-xcode-envvars/file1", 15), ("mozjs/third_party/python/gyp/test/mac/copies-with-xcode-envvars/file10", 16), ("mozjs/third_party/python/gyp/test/mac/copies-with-xcode-envvars/file11", 16), ("mozjs/third_party/python/gyp/test/mac/copies-with-xcode-envvars/file2", 15), ("mozjs/third_party/python/gyp/test/mac/copies-with-xcode-envvars/file3", 15), ("mozjs/third_party/python/gyp/test/mac/copies-with-xcode-envvars/file4", 15), ("mozjs/third_party/python/gyp/test/mac/copies-with-xcode-envvars/file5", 15), ("mozjs/third_party/python/gyp/test/mac/copies-with-xcode-envvars/file6", 15), ("mozjs/third_party/python/gyp/test/mac/copies-with-xcode-envvars/file7", 15), ("mozjs/third_party/python/gyp/test/mac/copies-with-xcode-envvars/file8", 15), ("mozjs/third_party/python/gyp/test/mac/copies-with-xcode-envvars/file9", 15), ("mozjs/third_party/python/gyp/test/mac/copy-dylib/empty.c", 14), ("mozjs/third_party/python/gyp/test/mac/copy-dylib/test.gyp", 714), ("mozjs/third_party/python/gyp/test/mac/debuginfo/file.c", 186), ("mozjs/third_party/python/gyp/test/mac/debuginfo/test.gyp", 2364), ("mozjs/third_party/python/gyp/test/mac/depend-on-bundle/English.lproj/InfoPlist.strings", 44), ("mozjs/third_party/python/gyp/test/mac/depend-on-bundle/Info.plist", 837), ("mozjs/third_party/python/gyp/test/mac/depend-on-bundle/bundle.c", 23), ("mozjs/third_party/python/gyp/test/mac/depend-on-bundle/executable.c", 38), ("mozjs/third_party/python/gyp/test/mac/depend-on-bundle/test.gyp", 650), ("mozjs/third_party/python/gyp/test/mac/deployment-target/check-version-min.c", 1114), ("mozjs/third_party/python/gyp/test/mac/deployment-target/deployment-target.gyp", 773), ("mozjs/third_party/python/gyp/test/mac/framework-dirs/calculate.c", 444), ("mozjs/third_party/python/gyp/test/mac/framework-dirs/framework-dirs.gyp", 486), ("mozjs/third_party/python/gyp/test/mac/framework-headers/myframework.h", 233), ("mozjs/third_party/python/gyp/test/mac/framework-headers/myframework.m", 217), ("mozjs/third_party/python/gyp/test/mac/framework-headers/test.gyp", 1149), ("mozjs/third_party/python/gyp/test/mac/framework/TestFramework/English.lproj/InfoPlist.strings", 48), ("mozjs/third_party/python/gyp/test/mac/framework/TestFramework/Info.plist", 848), ("mozjs/third_party/python/gyp/test/mac/framework/TestFramework/ObjCVector.h", 574), ("mozjs/third_party/python/gyp/test/mac/framework/TestFramework/ObjCVector.mm", 1274), ("mozjs/third_party/python/gyp/test/mac/framework/TestFramework/ObjCVectorInternal.h", 226), ("mozjs/third_party/python/gyp/test/mac/framework/TestFramework/TestFramework_Prefix.pch", 158), ("mozjs/third_party/python/gyp/test/mac/framework/empty.c", 0), ("mozjs/third_party/python/gyp/test/mac/framework/framework.gyp", 3115), ("mozjs/third_party/python/gyp/test/mac/global-settings/src/dir1/dir1.gyp", 251), ("mozjs/third_party/python/gyp/test/mac/global-settings/src/dir2/dir2.gyp", 565), ("mozjs/third_party/python/gyp/test/mac/global-settings/src/dir2/file.txt", 6), ("mozjs/third_party/python/gyp/test/mac/gyptest-action-envvars.py", 1073), ("mozjs/third_party/python/gyp/test/mac/gyptest-app-assets-catalog.py", 4120), ("mozjs/third_party/python/gyp/test/mac/gyptest-app-error.py", 1281), ("mozjs/third_party/python/gyp/test/mac/gyptest-app.py", 4074), ("mozjs/third_party/python/gyp/test/mac/gyptest-archs.py", 3580), ("mozjs/third_party/python/gyp/test/mac/gyptest-bundle-resources.py", 1804), ("mozjs/third_party/python/gyp/test/mac/gyptest-cflags.py", 473), ("mozjs/third_party/python/gyp/test/mac/gyptest-clang-cxx-language-standard.py", 589), ("mozjs/third_party/python/gyp/test/mac/gyptest-clang-cxx-library.py", 807), ("mozjs/third_party/python/gyp/test/mac/gyptest-copies-with-xcode-envvars.py", 2977), ("mozjs/third_party/python/gyp/test/mac/gyptest-copies.py", 1875), ("mozjs/third_party/python/gyp/test/mac/gyptest-copy-dylib.py", 596), ("mozjs/third_party/python/gyp/test/mac/gyptest-debuginfo.py", 1152), ("mozjs/third_party/python/gyp/test/mac/gyptest-depend-on-bundle.py", 1270), ("mozjs/third_party/python/gyp/test/mac/gyptest-deployment-target.py", 649), ("mozjs/third_party/python/gyp/test/mac/gyptest-framework-dirs.py", 557), ("mozjs/third_party/python/gyp/test/mac/gyptest-framework-headers.py", 1103), ("mozjs/third_party/python/gyp/test/mac/gyptest-framework.py", 2394), ("mozjs/third_party/python/gyp/test/mac/gyptest-global-settings.py", 991), ("mozjs/third_party/python/gyp/test/mac/gyptest-identical-name.py", 1547), ("mozjs/third_party/python/gyp/test/mac/gyptest-infoplist-process.py", 1761), ("mozjs/third_party/python/gyp/test/mac/gyptest-installname.py", 2597), ("mozjs/third_party/python/gyp/test/mac/gyptest-kext.py", 725), ("mozjs/third_party/python/gyp/test/mac/gyptest-ldflags-passed-to-libtool.py", 864), ("mozjs/third_party/python/gyp/test/mac/gyptest-ldflags.py", 2078), ("mozjs/third_party/python/gyp/test/mac/gyptest-libraries.py", 746), ("mozjs/third_party/python/gyp/test/mac/gyptest-libtool-zero.py", 533), ("mozjs/third_party/python/gyp/test/mac/gyptest-loadable-module-bundle-product-extension.py", 801), ("mozjs/third_party/python/gyp/test/mac/gyptest-loadable-module.py", 1364), ("mozjs/third_party/python/gyp/test/mac/gyptest-lto.py", 2050), ("mozjs/third_party/python/gyp/test/mac/gyptest-missing-cfbundlesignature.py", 1026), ("mozjs/third_party/python/gyp/test/mac/gyptest-non-strs-flattened-to-env.py", 1013), ("mozjs/third_party/python/gyp/test/mac/gyptest-objc-arc.py", 651), ("mozjs/third_party/python/gyp/test/mac/gyptest-objc-gc.py", 1586), ("mozjs/third_party/python/gyp/test/mac/gyptest-postbuild-copy-bundle.py", 2697), ("mozjs/third_party/python/gyp/test/mac/gyptest-postbuild-defaults.py", 740), ("mozjs/third_party/python/gyp/test/mac/gyptest-postbuild-fail.py", 2218), ("mozjs/third_party/python/gyp/test/mac/gyptest-postbuild-multiple-configurations.py", 687), ("mozjs/third_party/python/gyp/test/mac/gyptest-postbuild-static-library.py", 819), ("mozjs/third_party/python/gyp/test/mac/gyptest-postbuild.py", 1708), ("mozjs/third_party/python/gyp/test/mac/gyptest-prefixheader.py", 472), ("mozjs/third_party/python/gyp/test/mac/gyptest-rebuild.py", 1344), ("mozjs/third_party/python/gyp/test/mac/gyptest-rpath.py", 1303), ("mozjs/third_party/python/gyp/test/mac/gyptest-sdkroot.py", 1521), ("mozjs/third_party/python/gyp/test/mac/gyptest-sourceless-module.py", 2569), ("mozjs/third_party/python/gyp/test/mac/gyptest-strip-default.py", 2448), ("mozjs/third_party/python/gyp/test/mac/gyptest-strip.py", 2071), ("mozjs/third_party/python/gyp/test/mac/gyptest-swift-library.py", 1893), ("mozjs/third_party/python/gyp/test/mac/gyptest-type-envvars.py", 642), ("mozjs/third_party/python/gyp/test/mac/gyptest-unicode-settings.py", 539), ("mozjs/third_party/python/gyp/test/mac/gyptest-xcode-env-order.py", 3368), ("mozjs/third_party/python/gyp/test/mac/gyptest-xcode-gcc-clang.py", 1403), ("mozjs/third_party/python/gyp/test/mac/gyptest-xcode-gcc.py", 1838), ("mozjs/third_party/python/gyp/test/mac/gyptest-xcode-support-actions.py", 575), ("mozjs/third_party/python/gyp/test/mac/gyptest-xctest.py", 1262), ("mozjs/third_party/python/gyp/test/mac/gyptest-xcuitest.py", 1102), ("mozjs/third_party/python/gyp/test/mac/identical-name/proxy/proxy.cc", 15), ("mozjs/third_party/python/gyp/test/mac/identical-name/proxy/proxy.gyp", 190), ("mozjs/third_party/python/gyp/test/mac/identical-name/proxy/testlib/testlib.cc", 15), ("mozjs/third_party/python/gyp/test/mac/identical-name/proxy/testlib/testlib.gyp", 152), ("mozjs/third_party/python/gyp/test/mac/identical-name/test-should-fail.gyp", 176), ("mozjs/third_party/python/gyp/test/mac/identical-name/test.gyp", 204), ("mozjs/third_party/python/gyp/test/mac/identical-name/test.gypi", 102), ("mozjs/third_party/python/gyp/test/mac/identical-name/testlib/main.cc", 48), ("mozjs/third_party/python/gyp/test/mac/identical-name/testlib/testlib.gyp", 318), ("mozjs/third_party/python/gyp/test/mac/identical-name/testlib/void.cc", 15), ("mozjs/third_party/python/gyp/test/mac/infoplist-process/Info.plist", 1111), ("mozjs/third_party/python/gyp/test/mac/infoplist-process/main.c", 187), ("mozjs/third_party/python/gyp/test/mac/infoplist-process/test1.gyp", 617), ("mozjs/third_party/python/gyp/test/mac/infoplist-process/test2.gyp", 606), ("mozjs/third_party/python/gyp/test/mac/infoplist-process/test3.gyp", 603), ("mozjs/third_party/python/gyp/test/mac/installname/Info.plist", 837), ("mozjs/third_party/python/gyp/test/mac/installname/file.c", 22), ("mozjs/third_party/python/gyp/test/mac/installname/main.c", 14), ("mozjs/third_party/python/gyp/test/mac/installname/test.gyp", 2760), ("mozjs/third_party/python/gyp/test/mac/kext/GypKext/GypKext-Info.plist", 1075), ("mozjs/third_party/python/gyp/test/mac/kext/GypKext/GypKext.c", 447), ("mozjs/third_party/python/gyp/test/mac/kext/kext.gyp", 444), ("mozjs/third_party/python/gyp/test/mac/ldflags-libtool/file.c", 12), ("mozjs/third_party/python/gyp/test/mac/ldflags-libtool/test.gyp", 423), ("mozjs/third_party/python/gyp/test/mac/ldflags/subdirectory/Info.plist", 241), ("mozjs/third_party/python/gyp/test/mac/ldflags/subdirectory/file.c", 24), ("mozjs/third_party/python/gyp/test/mac/ldflags/subdirectory/symbol_list.def", 3), ("mozjs/third_party/python/gyp/test/mac/ldflags/subdirectory/test.gyp", 1988), ("mozjs/third_party/python/gyp/test/mac/libraries/subdir/README.txt", 77), ("mozjs/third_party/python/gyp/test/mac/libraries/subdir/hello.cc", 253), ("mozjs/third_party/python/gyp/test/mac/libraries/subdir/mylib.c", 198), ("mozjs/third_party/python/gyp/test/mac/libraries/subdir/test.gyp", 1525), ("mozjs/third_party/python/gyp/test/mac/libtool-zero/mylib.c", 198), ("mozjs/third_party/python/gyp/test/mac/libtool-zero/test.gyp", 377), ("mozjs/third_party/python/gyp/test/mac/loadable-module-bundle-product-extension/src.cc", 190), ("mozjs/third_party/python/gyp/test/mac/loadable-module-bundle-product-extension/test.gyp", 639), ("mozjs/third_party/python/gyp/test/mac/loadable-module/Info.plist", 803), ("mozjs/third_party/python/gyp/test/mac/loadable-module/module.c", 244), ("mozjs/third_party/python/gyp/test/mac/loadable-module/test.gyp", 440), ("mozjs/third_party/python/gyp/test/mac/lto/asmfile.S", 18), ("mozjs/third_party/python/gyp/test/mac/lto/ccfile.cc", 16), ("mozjs/third_party/python/gyp/test/mac/lto/cfile.c", 15), ("mozjs/third_party/python/gyp/test/mac/lto/mfile.m", 15), ("mozjs/third_party/python/gyp/test/mac/lto/mmfile.mm", 16), ("mozjs/third_party/python/gyp/test/mac/lto/test.gyp", 715), ("mozjs/third_party/python/gyp/test/mac/missing-cfbundlesignature/Info.plist", 311), ("mozjs/third_party/python/gyp/test/mac/missing-cfbundlesignature/Other-Info.plist", 375), ("mozjs/third_party/python/gyp/test/mac/missing-cfbundlesignature/Third-Info.plist", 397), ("mozjs/third_party/python/gyp/test/mac/missing-cfbundlesignature/file.c", 14), ("mozjs/third_party/python/gyp/test/mac/missing-cfbundlesignature/test.gyp", 812), ("mozjs/third_party/python/gyp/test/mac/non-strs-flattened-to-env/Info.plist", 482), ("mozjs/third_party/python/gyp/test/mac/non-strs-flattened-to-env/main.c", 187), ("mozjs/third_party/python/gyp/test/mac/non-strs-flattened-to-env/test.gyp", 744), ("mozjs/third_party/python/gyp/test/mac/objc-arc/c-file.c", 90), ("mozjs/third_party/python/gyp/test/mac/objc-arc/cc-file.cc", 92), ("mozjs/third_party/python/gyp/test/mac/objc-arc/m-file-no-arc.m", 123), ("mozjs/third_party/python/gyp/test/mac/objc-arc/m-file.m", 117), ("mozjs/third_party/python/gyp/test/mac/objc-arc/mm-file-no-arc.mm", 126), ("mozjs/third_party/python/gyp/test/mac/objc-arc/mm-file.mm", 120), ("mozjs/third_party/python/gyp/test/mac/objc-arc/test.gyp", 1128), ("mozjs/third_party/python/gyp/test/mac/objc-gc/c-file.c", 16), ("mozjs/third_party/python/gyp/test/mac/objc-gc/cc-file.cc", 17), ("mozjs/third_party/python/gyp/test/mac/objc-gc/main.m", 134), ("mozjs/third_party/python/gyp/test/mac/objc-gc/needs-gc-mm.mm", 22), ("mozjs/third_party/python/gyp/test/mac/objc-gc/needs-gc.m", 20), ("mozjs/third_party/python/gyp/test/mac/objc-gc/test.gyp", 2609), ("mozjs/third_party/python/gyp/test/mac/postbuild-copy-bundle/Framework-Info.plist", 889), ("mozjs/third_party/python/gyp/test/mac/postbuild-copy-bundle/TestApp-Info.plist", 979), ("mozjs/third_party/python/gyp/test/mac/postbuild-copy-bundle/copied.txt", 16), ("mozjs/third_party/python/gyp/test/mac/postbuild-copy-bundle/empty.c", 0), ("mozjs/third_party/python/gyp/test/mac/postbuild-copy-bundle/main.c", 173), ("mozjs/third_party/python/gyp/test/mac/postbuild-copy-bundle/postbuild-copy-framework.sh", 208), ("mozjs/third_party/python/gyp/test/mac/postbuild-copy-bundle/resource_file.sb", 42), ("mozjs/third_party/python/gyp/test/mac/postbuild-copy-bundle/test.gyp", 1292), ("mozjs/third_party/python/gyp/test/mac/postbuild-defaults/Info.plist", 443), ("mozjs/third_party/python/gyp/test/mac/postbuild-defaults/main.c", 187), ("mozjs/third_party/python/gyp/test/mac/postbuild-defaults/postbuild-defaults.sh", 582), ("mozjs/third_party/python/gyp/test/mac/postbuild-defaults/test.gyp", 657), ("mozjs/third_party/python/gyp/test/mac/postbuild-fail/file.c", 210), ("mozjs/third_party/python/gyp/test/mac/postbuild-fail/postbuild-fail.sh", 180), ("mozjs/third_party/python/gyp/test/mac/postbuild-fail/test.gyp", 969), ("mozjs/third_party/python/gyp/test/mac/postbuild-fail/touch-dynamic.sh", 220), ("mozjs/third_party/python/gyp/test/mac/postbuild-fail/touch-static.sh", 219), ("mozjs/third_party/python/gyp/test/mac/postbuild-multiple-configurations/main.c", 173), ("mozjs/third_party/python/gyp/test/mac/postbuild-multiple-configurations/postbuild-touch-file.sh", 215), ("mozjs/third_party/python/gyp/test/mac/postbuild-multiple-configurations/test.gyp", 565), ("mozjs/third_party/python/gyp/test/mac/postbuild-static-library/empty.c", 171), ("mozjs/third_party/python/gyp/test/mac/postbuild-static-library/postbuild-touch-file.sh", 203), ("mozjs/third_party/python/gyp/test/mac/postbuild-static-library/test.gyp", 836), ("mozjs/third_party/python/gyp/test/mac/postbuilds/copy.sh", 21), ("mozjs/third_party/python/gyp/test/mac/postbuilds/file.c", 171), ("mozjs/third_party/python/gyp/test/mac/postbuilds/file_g.c", 171), ("mozjs/third_party/python/gyp/test/mac/postbuilds/file_h.c", 171), ("mozjs/third_party/python/gyp/test/mac/postbuilds/script/shared_library_postbuild.sh", 493), ("mozjs/third_party/python/gyp/test/mac/postbuilds/script/static_library_postbuild.sh", 504), ("mozjs/third_party/python/gyp/test/mac/postbuilds/subdirectory/copied_file.txt", 48), ("mozjs/third_party/python/gyp/test/mac/postbuilds/subdirectory/nested_target.gyp", 1322), ("mozjs/third_party/python/gyp/test/mac/postbuilds/test.gyp", 2474), ("mozjs/third_party/python/gyp/test/mac/prefixheader/file.c", 24), ("mozjs/third_party/python/gyp/test/mac/prefixheader/file.cc", 24), ("mozjs/third_party/python/gyp/test/mac/prefixheader/file.m", 24), ("mozjs/third_party/python/gyp/test/mac/prefixheader/file.mm", 24), ("mozjs/third_party/python/gyp/test/mac/prefixheader/header.h", 19), ("mozjs/third_party/python/gyp/test/mac/prefixheader/test.gyp", 2063), ("mozjs/third_party/python/gyp/test/mac/rebuild/TestApp-Info.plist", 979), ("mozjs/third_party/python/gyp/test/mac/rebuild/delay-touch.sh", 78), ("mozjs/third_party/python/gyp/test/mac/rebuild/empty.c", 0), ("mozjs/third_party/python/gyp/test/mac/rebuild/main.c", 14), ("mozjs/third_party/python/gyp/test/mac/rebuild/test.gyp", 1388), ("mozjs/third_party/python/gyp/test/mac/rpath/file.c", 12), ("mozjs/third_party/python/gyp/test/mac/rpath/main.c", 14), ("mozjs/third_party/python/gyp/test/mac/rpath/test.gyp", 1313), ("mozjs/third_party/python/gyp/test/mac/sdkroot/file.cc", 47), ("mozjs/third_party/python/gyp/test/mac/sdkroot/test.gyp", 807), ("mozjs/third_party/python/gyp/test/mac/sdkroot/test_shorthand.sh", 434), ("mozjs/third_party/python/gyp/test/mac/sourceless-module/empty.c", 14), ("mozjs/third_party/python/gyp/test/mac/sourceless-module/empty.txt", 2), ("mozjs/third_party/python/gyp/test/mac/sourceless-module/fun.c", 23), ("mozjs/third_party/python/gyp/test/mac/sourceless-module/test.gyp", 2453), ("mozjs/third_party/python/gyp/test/mac/strip/file.c", 658), ("mozjs/third_party/python/gyp/test/mac/strip/main.c", 683), ("mozjs/third_party/python/gyp/test/mac/strip/strip.saves", 227), ("mozjs/third_party/python/gyp/test/mac/strip/subdirectory/nested_file.c", 19), ("mozjs/third_party/python/gyp/test/mac/strip/subdirectory/nested_strip.saves", 227), ("mozjs/third_party/python/gyp/test/mac/strip/subdirectory/subdirectory.gyp", 1132), ("mozjs/third_party/python/gyp/test/mac/strip/subdirectory/test_reading_save_file_from_postbuild.sh", 57), ("mozjs/third_party/python/gyp/test/mac/strip/test-defaults.gyp", 1187), ("mozjs/third_party/python/gyp/test/mac/strip/test.gyp", 3565), ("mozjs/third_party/python/gyp/test/mac/swift-library/Info.plist", 870), ("mozjs/third_party/python/gyp/test/mac/swift-library/file.swift", 116), ("mozjs/third_party/python/gyp/test/mac/swift-library/test.gyp", 542), ("mozjs/third_party/python/gyp/test/mac/type_envvars/file.c", 186), ("mozjs/third_party/python/gyp/test/mac/type_envvars/test.gyp", 2477), ("mozjs/third_party/python/gyp/test/mac/type_envvars/test_bundle_executable.sh", 1267), ("mozjs/third_party/python/gyp/test/mac/type_envvars/test_bundle_loadable_module.sh", 1485), ("mozjs/third_party/python/gyp/test/mac/type_envvars/test_bundle_shared_library.sh", 1575), ("mozjs/third_party/python/gyp/test/mac/type_envvars/test_check_sdkroot.sh", 1362), ("mozjs/third_party/python/gyp/test/mac/type_envvars/test_nonbundle_executable.sh", 1377), ("mozjs/third_party/python/gyp/test/mac/type_envvars/test_nonbundle_loadable_module.sh", 1287), ("mozjs/third_party/python/gyp/test/mac/type_envvars/test_nonbundle_none.sh", 1302), ("mozjs/third_party/python/gyp/test/mac/type_envvars/test_nonbundle_shared_library.sh", 1305), ("mozjs/third_party/python/gyp/test/mac/type_envvars/test_nonbundle_static_library.sh", 1289), ("mozjs/third_party/python/gyp/test/mac/unicode-settings/file.cc", 15), ("mozjs/third_party/python/gyp/test/mac/unicode-settings/test.gyp", 529), ("mozjs/third_party/python/gyp/test/mac/unicode-settings/test_bundle_display_name.sh", 204), ("mozjs/third_party/python/gyp/test/mac/xcode-env-order/Info.plist", 1705), ("mozjs/third_party/python/gyp/test/mac/xcode-env-order/file.ext1", 0), ("mozjs/third_party/python/gyp/test/mac/xcode-env-order/file.ext2", 0), ("mozjs/third_party/python/gyp/test/mac/xcode-env-order/file.ext3", 0), ("mozjs/third_party/python/gyp/test/mac/xcode-env-order/main.c", 187), ("mozjs/third_party/python/gyp/test/mac/xcode-env-order/test.gyp", 5079), ("mozjs/third_party/python/gyp/test/mac/xcode-gcc/aliasing.cc", 159), ("mozjs/third_party/python/gyp/test/mac/xcode-gcc/test-clang.gyp", 1100), ("mozjs/third_party/python/gyp/test/mac/xcode-gcc/test.gyp", 1640), ("mozjs/third_party/python/gyp/test/mac/xcode-gcc/valid_c.c", 258), ("mozjs/third_party/python/gyp/test/mac/xcode-gcc/valid_cc.cc", 257), ("mozjs/third_party/python/gyp/test/mac/xcode-gcc/valid_m.m", 268), ("mozjs/third_party/python/gyp/test/mac/xcode-gcc/valid_mm.mm", 267), ("mozjs/third_party/python/gyp/test/mac/xcode-gcc/warn_about_invalid_offsetof_macro.cc", 379), ("mozjs/third_party/python/gyp/test/mac/xcode-gcc/warn_about_missing_newline.c", 242), ("mozjs/third_party/python/gyp/test/mac/xcode-support-actions/source.c", 0), ("mozjs/third_party/python/gyp/test/mac/xcode-support-actions/test.gyp", 681), ("mozjs/third_party/python/gyp/test/mac/xctest/MyClass.h", 230), ("mozjs/third_party/python/gyp/test/mac/xctest/MyClass.m", 210), ("mozjs/third_party/python/gyp/test/mac/xctest/TestCase.m", 389), ("mozjs/third_party/python/gyp/test/mac/xctest/resource.txt", 4), ("mozjs/third_party/python/gyp/test/mac/xctest/test.gyp", 1012), ("mozjs/third_party/python/gyp/test/mac/xctest/test.xcodeproj/xcshareddata/xcschemes/classes.xcscheme", 2441), ("mozjs/third_party/python/gyp/test/mac/xcuitest/Info.plist", 837), ("mozjs/third_party/python/gyp/test/mac/xcuitest/MyAppDelegate.h", 249), ("mozjs/third_party/python/gyp/test/mac/xcuitest/MyAppDelegate.m", 520), ("mozjs/third_party/python/gyp/test/mac/xcuitest/TestCase.m", 385), ("mozjs/third_party/python/gyp/test/mac/xcuitest/main.m", 391), ("mozjs/third_party/python/gyp/test/mac/xcuitest/resource.txt", 4), ("mozjs/third_party/python/gyp/test/mac/xcuitest/test.gyp", 1615), ("mozjs/third_party/python/gyp/test/make/dependencies.gyp", 298), ("mozjs/third_party/python/gyp/test/make/gyptest-dependencies.py", 662), ("mozjs/third_party/python/gyp/test/make/gyptest-noload.py", 2023), ("mozjs/third_party/python/gyp/test/make/main.cc", 260), ("mozjs/third_party/python/gyp/test/make/main.h", 0), ("mozjs/third_party/python/gyp/test/make/noload/all.gyp", 362), ("mozjs/third_party/python/gyp/test/make/noload/lib/shared.c", 59), ("mozjs/third_party/python/gyp/test/make/noload/lib/shared.gyp", 325), ("mozjs/third_party/python/gyp/test/make/noload/lib/shared.h", 32), ("mozjs/third_party/python/gyp/test/make/noload/main.c", 118), ("mozjs/third_party/python/gyp/test/make_global_settings/ar/gyptest-make_global_settings_ar.py", 3814), ("mozjs/third_party/python/gyp/test/make_global_settings/ar/make_global_settings_ar.gyp", 659), ("mozjs/third_party/python/gyp/test/make_global_settings/basics/gyptest-make_global_settings.py", 1219), ("mozjs/third_party/python/gyp/test/make_global_settings/basics/make_global_settings.gyp", 362), ("mozjs/third_party/python/gyp/test/make_global_settings/env-wrapper/gyptest-wrapper.py", 1433), ("mozjs/third_party/python/gyp/test/make_global_settings/env-wrapper/wrapper.gyp", 365), ("mozjs/third_party/python/gyp/test/make_global_settings/full-toolchain/bar.cc", 30), ("mozjs/third_party/python/gyp/test/make_global_settings/full-toolchain/foo.c", 30), ("mozjs/third_party/python/gyp/test/make_global_settings/full-toolchain/gyptest-make_global_settings.py", 1763), ("mozjs/third_party/python/gyp/test/make_global_settings/full-toolchain/make_global_settings.gyp", 515), ("mozjs/third_party/python/gyp/test/make_global_settings/full-toolchain/my_nm.py", 261), ("mozjs/third_party/python/gyp/test/make_global_settings/full-toolchain/my_readelf.py", 271), ("mozjs/third_party/python/gyp/test/make_global_settings/ld/gyptest-make_global_settings_ld.py", 4140), ("mozjs/third_party/python/gyp/test/make_global_settings/ld/make_global_settings_ld.gyp", 659), ("mozjs/third_party/python/gyp/test/make_global_settings/wrapper/gyptest-wrapper.py", 1524), ("mozjs/third_party/python/gyp/test/make_global_settings/wrapper/wrapper.gyp", 489), ("mozjs/third_party/python/gyp/test/many-actions/file0", 0), ("mozjs/third_party/python/gyp/test/many-actions/file1", 0), ("mozjs/third_party/python/gyp/test/many-actions/file2", 0), ("mozjs/third_party/python/gyp/test/many-actions/file3", 0), ("mozjs/third_party/python/gyp/test/many-actions/file4", 0), ("mozjs/third_party/python/gyp/test/many-actions/gyptest-many-actions-unsorted.py", 966), ("mozjs/third_party/python/gyp/test/many-actions/gyptest-many-actions.py", 607), ("mozjs/third_party/python/gyp/test/many-actions/many-actions-unsorted.gyp", 4117), ("mozjs/third_party/python/gyp/test/many-actions/many-actions.gyp", 47837), ("mozjs/third_party/python/gyp/test/module/gyptest-default.py", 660), ("mozjs/third_party/python/gyp/test/module/src/lib1.c", 150), ("mozjs/third_party/python/gyp/test/module/src/lib2.c", 150), ("mozjs/third_party/python/gyp/test/module/src/module.gyp", 1132), ("mozjs/third_party/python/gyp/test/module/src/program.c", 2803), ("mozjs/third_party/python/gyp/test/msvs/buildevents/buildevents.gyp", 366), ("mozjs/third_party/python/gyp/test/msvs/buildevents/gyptest-msbuild-supports-prepostbuild.py", 732), ("mozjs/third_party/python/gyp/test/msvs/buildevents/gyptest-ninja-warnings.py", 710), ("mozjs/third_party/python/gyp/test/msvs/buildevents/main.cc", 174), ("mozjs/third_party/python/gyp/test/msvs/config_attrs/gyptest-config_attrs.py", 851), ("mozjs/third_party/python/gyp/test/msvs/config_attrs/hello.c", 243), ("mozjs/third_party/python/gyp/test/msvs/config_attrs/hello.gyp", 500), ("mozjs/third_party/python/gyp/test/msvs/express/base/base.gyp", 409), ("mozjs/third_party/python/gyp/test/msvs/express/express.gyp", 387), ("mozjs/third_party/python/gyp/test/msvs/express/gyptest-express.py", 753), ("mozjs/third_party/python/gyp/test/msvs/external_builder/external.gyp", 1607), ("mozjs/third_party/python/gyp/test/msvs/external_builder/external_builder.py", 245), ("mozjs/third_party/python/gyp/test/msvs/external_builder/gyptest-all.py", 1878), ("mozjs/third_party/python/gyp/test/msvs/external_builder/hello.cpp", 240), ("mozjs/third_party/python/gyp/test/msvs/external_builder/hello.z", 201), ("mozjs/third_party/python/gyp/test/msvs/external_builder/msbuild_action.py", 243), ("mozjs/third_party/python/gyp/test/msvs/external_builder/msbuild_rule.py", 295), ("mozjs/third_party/python/gyp/test/msvs/filters/filters.gyp", 1002), ("mozjs/third_party/python/gyp/test/msvs/filters/gyptest-filters-2008.py", 1599), ("mozjs/third_party/python/gyp/test/msvs/filters/gyptest-filters-2010.py", 1517), ("mozjs/third_party/python/gyp/test/msvs/list_excluded/gyptest-all.py", 1292), ("mozjs/third_party/python/gyp/test/msvs/list_excluded/hello.cpp", 240), ("mozjs/third_party/python/gyp/test/msvs/list_excluded/hello_exclude.gyp", 412), ("mozjs/third_party/python/gyp/test/msvs/list_excluded/hello_mac.cpp", 236), ("mozjs/third_party/python/gyp/test/msvs/missing_sources/gyptest-missing.py", 1413), ("mozjs/third_party/python/gyp/test/msvs/missing_sources/hello_missing.gyp", 309), ("mozjs/third_party/python/gyp/test/msvs/multiple_actions_error_handling/action_fail.py", 181), ("mozjs/third_party/python/gyp/test/msvs/multiple_actions_error_handling/action_succeed.py", 181), ("mozjs/third_party/python/gyp/test/msvs/multiple_actions_error_handling/actions.gyp", 908), ("mozjs/third_party/python/gyp/test/msvs/multiple_actions_error_handling/gyptest.py", 647), ("mozjs/third_party/python/gyp/test/msvs/props/AppName.props", 458), ("mozjs/third_party/python/gyp/test/msvs/props/AppName.vsprops", 241), ("mozjs/third_party/python/gyp/test/msvs/props/gyptest-props.py", 475), ("mozjs/third_party/python/gyp/test/msvs/props/hello.c", 243), ("mozjs/third_party/python/gyp/test/msvs/props/hello.gyp", 478), ("mozjs/third_party/python/gyp/test/msvs/rules_stdout_stderr/dummy.bar", 218), ("mozjs/third_party/python/gyp/test/msvs/rules_stdout_stderr/dummy.foo", 218), ("mozjs/third_party/python/gyp/test/msvs/rules_stdout_stderr/gyptest-rules-stdout-stderr.py", 750), ("mozjs/third_party/python/gyp/test/msvs/rules_stdout_stderr/rule_stderr.py", 235), ("mozjs/third_party/python/gyp/test/msvs/rules_stdout_stderr/rule_stdout.py", 210), ("mozjs/third_party/python/gyp/test/msvs/rules_stdout_stderr/rules-stdout-stderr.gyp", 1173), ("mozjs/third_party/python/gyp/test/msvs/shared_output/common.gypi", 431), ("mozjs/third_party/python/gyp/test/msvs/shared_output/gyptest-shared_output.py", 1394), ("mozjs/third_party/python/gyp/test/msvs/shared_output/hello.c", 247), ("mozjs/third_party/python/gyp/test/msvs/shared_output/hello.gyp", 448), ("mozjs/third_party/python/gyp/test/msvs/shared_output/there/there.c", 247), ("mozjs/third_party/python/gyp/test/msvs/shared_output/there/there.gyp", 333), ("mozjs/third_party/python/gyp/test/msvs/uldi2010/gyptest-all.py", 491), ("mozjs/third_party/python/gyp/test/msvs/uldi2010/hello.c", 277), ("mozjs/third_party/python/gyp/test/msvs/uldi2010/hello.gyp", 528), ("mozjs/third_party/python/gyp/test/msvs/uldi2010/hello2.c", 239), ("mozjs/third_party/python/gyp/test/multiple-targets/gyptest-all.py", 658), ("mozjs/third_party/python/gyp/test/multiple-targets/gyptest-default.py", 635), ("mozjs/third_party/python/gyp/test/multiple-targets/src/common.c", 87), ("mozjs/third_party/python/gyp/test/multiple-targets/src/multiple.gyp", 457), ("mozjs/third_party/python/gyp/test/multiple-targets/src/prog1.c", 124), ("mozjs/third_party/python/gyp/test/multiple-targets/src/prog2.c", 124), ("mozjs/third_party/python/gyp/test/ninja/action-rule-hash/gyptest-action-rule-hash.py", 938), ("mozjs/third_party/python/gyp/test/ninja/action-rule-hash/subdir/action-rule-hash.gyp", 726), ("mozjs/third_party/python/gyp/test/ninja/action-rule-hash/subdir/emit.py", 296), ("mozjs/third_party/python/gyp/test/ninja/action_dependencies/gyptest-action-dependencies.py", 2086), ("mozjs/third_party/python/gyp/test/ninja/action_dependencies/src/a.c", 209), ("mozjs/third_party/python/gyp/test/ninja/action_dependencies/src/a.h", 247), ("mozjs/third_party/python/gyp/test/ninja/action_dependencies/src/action_dependencies.gyp", 1975), ("mozjs/third_party/python/gyp/test/ninja/action_dependencies/src/b.c", 375), ("mozjs/third_party/python/gyp/test/ninja/action_dependencies/src/b.h", 237), ("mozjs/third_party/python/gyp/test/ninja/action_dependencies/src/c.c", 212), ("mozjs/third_party/python/gyp/test/ninja/action_dependencies/src/c.h", 247), ("mozjs/third_party/python/gyp/test/ninja/action_dependencies/src/emit.py", 261), ("mozjs/third_party/python/gyp/test/ninja/chained-dependency/chained-dependency.gyp", 1314), ("mozjs/third_party/python/gyp/test/ninja/chained-dependency/chained.c", 62), ("mozjs/third_party/python/gyp/test/ninja/chained-dependency/gyptest-chained-dependency.py", 909), ("mozjs/third_party/python/gyp/test/ninja/empty-and-non-empty-duplicate-name/gyptest-empty-and-non-empty-duplicate-name.py", 708), ("mozjs/third_party/python/gyp/test/ninja/empty-and-non-empty-duplicate-name/subdir/included.gyp", 387), ("mozjs/third_party/python/gyp/test/ninja/empty-and-non-empty-duplicate-name/test.gyp", 400), ("mozjs/third_party/python/gyp/test/ninja/normalize-paths-win/gyptest-normalize-paths.py", 1406), ("mozjs/third_party/python/gyp/test/ninja/normalize-paths-win/hello.cc", 187), ("mozjs/third_party/python/gyp/test/ninja/normalize-paths-win/normalize-paths.gyp", 1644), ("mozjs/third_party/python/gyp/test/ninja/s-needs-no-depfiles/empty.s", 38), ("mozjs/third_party/python/gyp/test/ninja/s-needs-no-depfiles/gyptest-s-needs-no-depfiles.py", 1243), ("mozjs/third_party/python/gyp/test/ninja/s-needs-no-depfiles/s-needs-no-depfiles.gyp", 288), ("mozjs/third_party/python/gyp/test/ninja/solibs_avoid_relinking/gyptest-solibs-avoid-relinking.py", 1565), ("mozjs/third_party/python/gyp/test/ninja/solibs_avoid_relinking/main.cc", 50), ("mozjs/third_party/python/gyp/test/ninja/solibs_avoid_relinking/solib.cc", 117), ("mozjs/third_party/python/gyp/test/ninja/solibs_avoid_relinking/solibs_avoid_relinking.gyp", 850), ("mozjs/third_party/python/gyp/test/ninja/use-console/foo.bar", 201), ("mozjs/third_party/python/gyp/test/ninja/use-console/gyptest-use-console.py", 732), ("mozjs/third_party/python/gyp/test/ninja/use-console/use-console.gyp", 1304), ("mozjs/third_party/python/gyp/test/ninja/use-custom-environment-files/gyptest-use-custom-environment-files.py", 690), ("mozjs/third_party/python/gyp/test/ninja/use-custom-environment-files/use-custom-environment-files.cc", 187), ("mozjs/third_party/python/gyp/test/ninja/use-custom-environment-files/use-custom-environment-files.gyp", 351), ("mozjs/third_party/python/gyp/test/no-cpp/gyptest-no-cpp.py", 1481), ("mozjs/third_party/python/gyp/test/no-cpp/src/call-f-main.c", 31), ("mozjs/third_party/python/gyp/test/no-cpp/src/empty-main.c", 14), ("mozjs/third_party/python/gyp/test/no-cpp/src/f.cc", 57), ("mozjs/third_party/python/gyp/test/no-cpp/src/test.gyp", 674), ("mozjs/third_party/python/gyp/test/no-output/gyptest-no-output.py", 580), ("mozjs/third_party/python/gyp/test/no-output/src/nooutput.gyp", 346), ("mozjs/third_party/python/gyp/test/product/gyptest-product.py", 1512), ("mozjs/third_party/python/gyp/test/product/hello.c", 304), ("mozjs/third_party/python/gyp/test/product/product.gyp", 2670), ("mozjs/third_party/python/gyp/test/prune_targets/gyptest-prune-targets.py", 2285), ("mozjs/third_party/python/gyp/test/prune_targets/lib1.cc", 180), ("mozjs/third_party/python/gyp/test/prune_targets/lib2.cc", 180), ("mozjs/third_party/python/gyp/test/prune_targets/lib3.cc", 180), ("mozjs/third_party/python/gyp/test/prune_targets/lib_indirect.cc", 188), ("mozjs/third_party/python/gyp/test/prune_targets/program.cc", 187), ("mozjs/third_party/python/gyp/test/prune_targets/test1.gyp", 640), ("mozjs/third_party/python/gyp/test/prune_targets/test2.gyp", 705), ("mozjs/third_party/python/gyp/test/relative/foo/a/a.cc", 194), ("mozjs/third_party/python/gyp/test/relative/foo/a/a.gyp", 203), ("mozjs/third_party/python/gyp/test/relative/foo/a/c/c.cc", 194), ("mozjs/third_party/python/gyp/test/relative/foo/a/c/c.gyp", 182), ("mozjs/third_party/python/gyp/test/relative/foo/b/b.cc", 195), ("mozjs/third_party/python/gyp/test/relative/foo/b/b.gyp", 122), ("mozjs/third_party/python/gyp/test/relative/gyptest-default.py", 656), ("mozjs/third_party/python/gyp/test/rename/filecase/file.c", 25), ("mozjs/third_party/python/gyp/test/rename/filecase/test-casesensitive.gyp", 334), ("mozjs/third_party/python/gyp/test/rename/filecase/test.gyp", 306), ("mozjs/third_party/python/gyp/test/rename/gyptest-filecase.py", 1110), ("mozjs/third_party/python/gyp/test/restat/gyptest-restat.py", 914), ("mozjs/third_party/python/gyp/test/restat/src/create_intermediate.py", 347), ("mozjs/third_party/python/gyp/test/restat/src/restat.gyp", 1220), ("mozjs/third_party/python/gyp/test/restat/src/touch.py", 350), ("mozjs/third_party/python/gyp/test/rules-dirname/gyptest-dirname.py", 1577), ("mozjs/third_party/python/gyp/test/rules-dirname/src/actions.gyp", 336), ("mozjs/third_party/python/gyp/test/rules-dirname/src/copy-file.py", 285), ("mozjs/third_party/python/gyp/test/rules-dirname/src/subdir/a/b/c.gencc", 98), ("mozjs/third_party/python/gyp/test/rules-dirname/src/subdir/a/b/c.printvars", 37), ("mozjs/third_party/python/gyp/test/rules-dirname/src/subdir/foo/bar/baz.gencc", 105), ("mozjs/third_party/python/gyp/test/rules-dirname/src/subdir/foo/bar/baz.printvars", 37), ("mozjs/third_party/python/gyp/test/rules-dirname/src/subdir/input-rule-dirname.gyp", 3735), ("mozjs/third_party/python/gyp/test/rules-dirname/src/subdir/main.cc", 298), ("mozjs/third_party/python/gyp/test/rules-dirname/src/subdir/nodir.gencc", 109), ("mozjs/third_party/python/gyp/test/rules-dirname/src/subdir/printvars.py", 279), ("mozjs/third_party/python/gyp/test/rules-rebuild/gyptest-all.py", 1662), ("mozjs/third_party/python/gyp/test/rules-rebuild/gyptest-default.py", 2242), ("mozjs/third_party/python/gyp/test/rules-rebuild/src/main.c", 157), ("mozjs/third_party/python/gyp/test/rules-rebuild/src/make-sources.py", 459), ("mozjs/third_party/python/gyp/test/rules-rebuild/src/prog1.in", 96), ("mozjs/third_party/python/gyp/test/rules-rebuild/src/prog2.in", 96), ("mozjs/third_party/python/gyp/test/rules-rebuild/src/same_target.gyp", 694), ("mozjs/third_party/python/gyp/test/rules-use-built-dependencies/gyptest-use-built-dependencies.py", 620), ("mozjs/third_party/python/gyp/test/rules-use-built-dependencies/src/main.cc", 427), ("mozjs/third_party/python/gyp/test/rules-use-built-dependencies/src/use-built-dependencies-rule.gyp", 1152), ("mozjs/third_party/python/gyp/test/rules-variables/gyptest-rules-variables.py", 806), ("mozjs/third_party/python/gyp/test/rules-variables/src/input_ext.c", 226), ("mozjs/third_party/python/gyp/test/rules-variables/src/input_name/test.c", 228), ("mozjs/third_party/python/gyp/test/rules-variables/src/input_path/subdir/test.c", 228), ("mozjs/third_party/python/gyp/test/rules-variables/src/subdir/input_dirname.c", 234), ("mozjs/third_party/python/gyp/test/rules-variables/src/subdir/test.c", 402), ("mozjs/third_party/python/gyp/test/rules-variables/src/test.input_root.c", 228), ("mozjs/third_party/python/gyp/test/rules-variables/src/variables.gyp", 1353), ("mozjs/third_party/python/gyp/test/rules/gyptest-all.py", 2191), ("mozjs/third_party/python/gyp/test/rules/gyptest-default.py", 1869), ("mozjs/third_party/python/gyp/test/rules/gyptest-input-root.py", 574), ("mozjs/third_party/python/gyp/test/rules/gyptest-special-variables.py", 487), ("mozjs/third_party/python/gyp/test/rules/src/actions.gyp", 624), ("mozjs/third_party/python/gyp/test/rules/src/an_asm.S", 192), ("mozjs/third_party/python/gyp/test/rules/src/as.bat", 226), ("mozjs/third_party/python/gyp/test/rules/src/copy-file.py", 285), ("mozjs/third_party/python/gyp/test/rules/src/external/external.gyp", 1697), ("mozjs/third_party/python/gyp/test/rules/src/external/file1.in", 20), ("mozjs/third_party/python/gyp/test/rules/src/external/file2.in", 20), ("mozjs/third_party/python/gyp/test/rules/src/input-root.gyp", 717), ("mozjs/third_party/python/gyp/test/rules/src/noaction/file1.in", 20), ("mozjs/third_party/python/gyp/test/rules/src/noaction/no_action_with_rules_fails.gyp", 994), ("mozjs/third_party/python/gyp/test/rules/src/rule.py", 335), ("mozjs/third_party/python/gyp/test/rules/src/somefile.ext", 0), ("mozjs/third_party/python/gyp/test/rules/src/special-variables.gyp", 855), ("mozjs/third_party/python/gyp/test/rules/src/subdir1/executable.gyp", 965), ("mozjs/third_party/python/gyp/test/rules/src/subdir1/function1.in", 84), ("mozjs/third_party/python/gyp/test/rules/src/subdir1/function2.in", 84), ("mozjs/third_party/python/gyp/test/rules/src/subdir1/program.c", 176), ("mozjs/third_party/python/gyp/test/rules/src/subdir2/both_rule_and_action_input.gyp", 1183), ("mozjs/third_party/python/gyp/test/rules/src/subdir2/file1.in", 20), ("mozjs/third_party/python/gyp/test/rules/src/subdir2/file2.in", 20), ("mozjs/third_party/python/gyp/test/rules/src/subdir2/never_used.gyp", 775), ("mozjs/third_party/python/gyp/test/rules/src/subdir2/no_action.gyp", 1006), ("mozjs/third_party/python/gyp/test/rules/src/subdir2/no_inputs.gyp", 790), ("mozjs/third_party/python/gyp/test/rules/src/subdir2/none.gyp", 748), ("mozjs/third_party/python/gyp/test/rules/src/subdir2/program.c", 251), ("mozjs/third_party/python/gyp/test/rules/src/subdir3/executable2.gyp", 950), ("mozjs/third_party/python/gyp/test/rules/src/subdir3/function3.in", 95), ("mozjs/third_party/python/gyp/test/rules/src/subdir3/program.c", 132), ("mozjs/third_party/python/gyp/test/rules/src/subdir4/asm-function.assem", 199), ("mozjs/third_party/python/gyp/test/rules/src/subdir4/build-asm.gyp", 1193), ("mozjs/third_party/python/gyp/test/rules/src/subdir4/program.c", 361), ("mozjs/third_party/python/gyp/test/same-gyp-name/gyptest-all.py", 794), ("mozjs/third_party/python/gyp/test/same-gyp-name/gyptest-default.py", 784), ("mozjs/third_party/python/gyp/test/same-gyp-name/gyptest-library.py", 435), ("mozjs/third_party/python/gyp/test/same-gyp-name/library/one/sub.gyp", 253), ("mozjs/third_party/python/gyp/test/same-gyp-name/library/test.gyp", 352), ("mozjs/third_party/python/gyp/test/same-gyp-name/library/two/sub.gyp", 253), ("mozjs/third_party/python/gyp/test/same-gyp-name/src/all.gyp", 354), ("mozjs/third_party/python/gyp/test/same-gyp-name/src/subdir1/executable.gyp", 303), ("mozjs/third_party/python/gyp/test/same-gyp-name/src/subdir1/main1.cc", 82), ("mozjs/third_party/python/gyp/test/same-gyp-name/src/subdir2/executable.gyp", 303), ("mozjs/third_party/python/gyp/test/same-gyp-name/src/subdir2/main2.cc", 82), ("mozjs/third_party/python/gyp/test/same-rule-output-file-name/gyptest-all.py", 540), ("mozjs/third_party/python/gyp/test/same-rule-output-file-name/src/subdir1/subdir1.gyp", 682), ("mozjs/third_party/python/gyp/test/same-rule-output-file-name/src/subdir2/subdir2.gyp", 682), ("mozjs/third_party/python/gyp/test/same-rule-output-file-name/src/subdirs.gyp", 347), ("mozjs/third_party/python/gyp/test/same-rule-output-file-name/src/touch.py", 263), ("mozjs/third_party/python/gyp/test/same-source-file-name/gyptest-all.py", 718), ("mozjs/third_party/python/gyp/test/same-source-file-name/gyptest-default.py", 708), ("mozjs/third_party/python/gyp/test/same-source-file-name/gyptest-pass-executable.py", 849), ("mozjs/third_party/python/gyp/test/same-source-file-name/gyptest-pass-shared.py", 401), ("mozjs/third_party/python/gyp/test/same-source-file-name/gyptest-static.py", 1094), ("mozjs/third_party/python/gyp/test/same-source-file-name/src/all.gyp", 557), ("mozjs/third_party/python/gyp/test/same-source-file-name/src/double-executable.gyp", 421), ("mozjs/third_party/python/gyp/test/same-source-file-name/src/double-shared.gyp", 564), ("mozjs/third_party/python/gyp/test/same-source-file-name/src/double-static.gyp", 464), ("mozjs/third_party/python/gyp/test/same-source-file-name/src/func.c", 82), ("mozjs/third_party/python/gyp/test/same-source-file-name/src/prog1.c", 275), ("mozjs/third_party/python/gyp/test/same-source-file-name/src/prog2.c", 275), ("mozjs/third_party/python/gyp/test/same-source-file-name/src/prog3.c", 380), ("mozjs/third_party/python/gyp/test/same-source-file-name/src/subdir1/func.c", 98), ("mozjs/third_party/python/gyp/test/same-source-file-name/src/subdir2/func.c", 98), ("mozjs/third_party/python/gyp/test/same-target-name-different-directory/gyptest-all.py", 1341), ("mozjs/third_party/python/gyp/test/same-target-name-different-directory/src/subdir1/subdir1.gyp", 1570), ("mozjs/third_party/python/gyp/test/same-target-name-different-directory/src/subdir2/subdir2.gyp", 1570), ("mozjs/third_party/python/gyp/test/same-target-name-different-directory/src/subdirs.gyp", 347), ("mozjs/third_party/python/gyp/test/same-target-name-different-directory/src/touch.py", 263), ("mozjs/third_party/python/gyp/test/same-target-name/gyptest-same-target-name.py", 436), ("mozjs/third_party/python/gyp/test/same-target-name/src/all.gyp", 340), ("mozjs/third_party/python/gyp/test/same-target-name/src/executable1.gyp", 302), ("mozjs/third_party/python/gyp/test/same-target-name/src/executable2.gyp", 302), ("mozjs/third_party/python/gyp/test/sanitize-rule-names/blah.S", 0), ("mozjs/third_party/python/gyp/test/sanitize-rule-names/gyptest-sanitize-rule-names.py", 479), ("mozjs/third_party/python/gyp/test/sanitize-rule-names/hello.cc", 187), ("mozjs/third_party/python/gyp/test/sanitize-rule-names/sanitize-rule-names.gyp", 705), ("mozjs/third_party/python/gyp/test/sanitize-rule-names/script.py", 237), ("mozjs/third_party/python/gyp/test/self-dependency/common.gypi", 345), ("mozjs/third_party/python/gyp/test/self-dependency/dep.gyp", 614), ("mozjs/third_party/python/gyp/test/self-dependency/gyptest-self-dependency.py", 468), ("mozjs/third_party/python/gyp/test/self-dependency/self_dependency.gyp", 282), ("mozjs/third_party/python/gyp/test/sibling/gyptest-all.py", 1282), ("mozjs/third_party/python/gyp/test/sibling/gyptest-relocate.py", 1365), ("mozjs/third_party/python/gyp/test/sibling/src/build/all.gyp", 341), ("mozjs/third_party/python/gyp/test/sibling/src/prog1/prog1.c", 85), ("mozjs/third_party/python/gyp/test/sibling/src/prog1/prog1.gyp", 302), ("mozjs/third_party/python/gyp/test/sibling/src/prog2/prog2.c", 85), ("mozjs/third_party/python/gyp/test/sibling/src/prog2/prog2.gyp", 302), ("mozjs/third_party/python/gyp/test/small/gyptest-small.py", 1480), ("mozjs/third_party/python/gyp/test/standalone-static-library/gyptest-standalone-static-library.py", 1747), ("mozjs/third_party/python/gyp/test/standalone-static-library/invalid.gyp", 333), ("mozjs/third_party/python/gyp/test/standalone-static-library/mylib.c", 85), ("mozjs/third_party/python/gyp/test/standalone-static-library/mylib.gyp", 507), ("mozjs/third_party/python/gyp/test/standalone-static-library/prog.c", 68), ("mozjs/third_party/python/gyp/test/standalone/gyptest-standalone.py", 869), ("mozjs/third_party/python/gyp/test/standalone/standalone.gyp", 250), ("mozjs/third_party/python/gyp/test/subdirectory/gyptest-SYMROOT-all.py", 1236), ("mozjs/third_party/python/gyp/test/subdirectory/gyptest-SYMROOT-default.py", 1227), ("mozjs/third_party/python/gyp/test/subdirectory/gyptest-subdir-all.py", 972), ("mozjs/third_party/python/gyp/test/subdirectory/gyptest-subdir-default.py", 959), ("mozjs/third_party/python/gyp/test/subdirectory/gyptest-subdir2-deep.py", 640), ("mozjs/third_party/python/gyp/test/subdirectory/gyptest-top-all.py", 1373), ("mozjs/third_party/python/gyp/test/subdirectory/gyptest-top-default.py", 1363), ("mozjs/third_party/python/gyp/test/subdirectory/src/prog1.c", 85), ("mozjs/third_party/python/gyp/test/subdirectory/src/prog1.gyp", 407), ("mozjs/third_party/python/gyp/test/subdirectory/src/subdir/prog2.c", 85), ("mozjs/third_party/python/gyp/test/subdirectory/src/subdir/prog2.gyp", 343), ("mozjs/third_party/python/gyp/test/subdirectory/src/subdir/subdir2/prog3.c", 85), ("mozjs/third_party/python/gyp/test/subdirectory/src/subdir/subdir2/prog3.gyp", 346), ("mozjs/third_party/python/gyp/test/subdirectory/src/symroot.gypi", 336), ("mozjs/third_party/python/gyp/test/symlinks/gyptest-symlinks.py", 2539), ("mozjs/third_party/python/gyp/test/symlinks/hello.c", 243), ("mozjs/third_party/python/gyp/test/symlinks/hello.gyp", 315), ("mozjs/third_party/python/gyp/test/target/gyptest-target.py", 1109), ("mozjs/third_party/python/gyp/test/target/hello.c", 212), ("mozjs/third_party/python/gyp/test/target/target.gyp", 488), ("mozjs/third_party/python/gyp/test/toolsets/gyptest-toolsets.py", 746), ("mozjs/third_party/python/gyp/test/toolsets/main.cc", 339), ("mozjs/third_party/python/gyp/test/toolsets/toolsets.cc", 255), ("mozjs/third_party/python/gyp/test/toolsets/toolsets.gyp", 1526), ("mozjs/third_party/python/gyp/test/toolsets/toolsets_shared.cc", 261), ("mozjs/third_party/python/gyp/test/toplevel-dir/gyptest-toplevel-dir.py", 918), ("mozjs/third_party/python/gyp/test/toplevel-dir/src/sub1/main.gyp", 376), ("mozjs/third_party/python/gyp/test/toplevel-dir/src/sub1/prog1.c", 85), ("mozjs/third_party/python/gyp/test/toplevel-dir/src/sub2/prog2.c", 85), ("mozjs/third_party/python/gyp/test/toplevel-dir/src/sub2/prog2.gyp", 299), ("mozjs/third_party/python/gyp/test/variables/commands/commands-repeated.gyp", 3535), ("mozjs/third_party/python/gyp/test/variables/commands/commands-repeated.gyp.stdout", 16564), ("mozjs/third_party/python/gyp/test/variables/commands/commands-repeated.gypd.golden", 3686), ("mozjs/third_party/python/gyp/test/variables/commands/commands.gyp", 2489), ("mozjs/third_party/python/gyp/test/variables/commands/commands.gyp.ignore-env.stdout", 11512), ("mozjs/third_party/python/gyp/test/variables/commands/commands.gyp.stdout", 11512), ("mozjs/third_party/python/gyp/test/variables/commands/commands.gypd.golden", 2941), ("mozjs/third_party/python/gyp/test/variables/commands/commands.gypi", 526), ("mozjs/third_party/python/gyp/test/variables/commands/gyptest-commands-ignore-env.py", 1466), ("mozjs/third_party/python/gyp/test/variables/commands/gyptest-commands-repeated-multidir.py", 745), ("mozjs/third_party/python/gyp/test/variables/commands/gyptest-commands-repeated.py", 1313), ("mozjs/third_party/python/gyp/test/variables/commands/gyptest-commands.py", 1208), ("mozjs/third_party/python/gyp/test/variables/commands/repeated_multidir/dir_1/test_1.gyp", 301), ("mozjs/third_party/python/gyp/test/variables/commands/repeated_multidir/dir_2/test_2.gyp", 301), ("mozjs/third_party/python/gyp/test/variables/commands/repeated_multidir/main.gyp", 348), ("mozjs/third_party/python/gyp/test/variables/commands/repeated_multidir/print_cwd_basename.py", 238), ("mozjs/third_party/python/gyp/test/variables/commands/repeated_multidir/repeated_command_common.gypi", 790), ("mozjs/third_party/python/gyp/test/variables/commands/test.py", 30), ("mozjs/third_party/python/gyp/test/variables/commands/update_golden", 611), ("mozjs/third_party/python/gyp/test/variables/empty/empty.gyp", 276), ("mozjs/third_party/python/gyp/test/variables/empty/empty.gypi", 195), ("mozjs/third_party/python/gyp/test/variables/empty/gyptest-empty.py", 345), ("mozjs/third_party/python/gyp/test/variables/filelist/filelist.gyp.stdout", 3254), ("mozjs/third_party/python/gyp/test/variables/filelist/filelist.gypd.golden", 2020), ("mozjs/third_party/python/gyp/test/variables/filelist/gyptest-filelist-golden.py", 1584), ("mozjs/third_party/python/gyp/test/variables/filelist/gyptest-filelist.py", 696), ("mozjs/third_party/python/gyp/test/variables/filelist/src/dummy.py", 90), ("mozjs/third_party/python/gyp/test/variables/filelist/src/filelist.gyp", 2051), ("mozjs/third_party/python/gyp/test/variables/filelist/src/filelist2.gyp", 990), ("mozjs/third_party/python/gyp/test/variables/filelist/update_golden", 332), ("mozjs/third_party/python/gyp/test/variables/latelate/gyptest-latelate.py", 526), ("mozjs/third_party/python/gyp/test/variables/latelate/src/latelate.gyp", 672), ("mozjs/third_party/python/gyp/test/variables/latelate/src/program.cc", 239), ("mozjs/third_party/python/gyp/test/variables/variable-in-path/C1/hello.cc", 187), ("mozjs/third_party/python/gyp/test/variables/variable-in-path/gyptest-variable-in-path.py", 482), ("mozjs/third_party/python/gyp/test/variables/variable-in-path/variable-in-path.gyp", 607), ("mozjs/third_party/python/gyp/test/win/asm-files/asm-files.gyp", 339), ("mozjs/third_party/python/gyp/test/win/asm-files/b.s", 0), ("mozjs/third_party/python/gyp/test/win/asm-files/c.S", 0), ("mozjs/third_party/python/gyp/test/win/asm-files/hello.cc", 187), ("mozjs/third_party/python/gyp/test/win/batch-file-action/batch-file-action.gyp", 498), ("mozjs/third_party/python/gyp/test/win/batch-file-action/infile", 6), ("mozjs/third_party/python/gyp/test/win/batch-file-action/somecmd.bat", 265), ("mozjs/third_party/python/gyp/test/win/command-quote/a.S", 0), ("mozjs/third_party/python/gyp/test/win/command-quote/bat with spaces.bat", 182), ("mozjs/third_party/python/gyp/test/win/command-quote/command-quote.gyp", 2145), ("mozjs/third_party/python/gyp/test/win/command-quote/go.bat", 182), ("mozjs/third_party/python/gyp/test/win/command-quote/subdir/and/another/in-subdir.gyp", 834), ("mozjs/third_party/python/gyp/test/win/compiler-flags/additional-include-dirs.cc", 277), ("mozjs/third_party/python/gyp/test/win/compiler-flags/additional-include-dirs.gyp", 456), ("mozjs/third_party/python/gyp/test/win/compiler-flags/additional-options.cc", 311), ("mozjs/third_party/python/gyp/test/win/compiler-flags/additional-options.gyp", 756), ("mozjs/third_party/python/gyp/test/win/compiler-flags/analysis.gyp", 921), ("mozjs/third_party/python/gyp/test/win/compiler-flags/buffer-security-check.gyp", 1339), ("mozjs/third_party/python/gyp/test/win/compiler-flags/buffer-security.cc", 308), ("mozjs/third_party/python/gyp/test/win/compiler-flags/calling-convention-cdecl.def", 171), ("mozjs/third_party/python/gyp/test/win/compiler-flags/calling-convention-fastcall.def", 174), ("mozjs/third_party/python/gyp/test/win/compiler-flags/calling-convention-stdcall.def", 174), ("mozjs/third_party/python/gyp/test/win/compiler-flags/calling-convention-vectorcall.def", 174), ("mozjs/third_party/python/gyp/test/win/compiler-flags/calling-convention.cc", 186), ("mozjs/third_party/python/gyp/test/win/compiler-flags/calling-convention.gyp", 1511), ("mozjs/third_party/python/gyp/test/win/compiler-flags/character-set-mbcs.cc", 216), ("mozjs/third_party/python/gyp/test/win/compiler-flags/character-set-unicode.cc", 250), ("mozjs/third_party/python/gyp/test/win/compiler-flags/character-set.gyp", 893), ("mozjs/third_party/python/gyp/test/win/compiler-flags/compile-as-managed.cc", 207), ("mozjs/third_party/python/gyp/test/win/compiler-flags/compile-as-managed.gyp", 740), ("mozjs/third_party/python/gyp/test/win/compiler-flags/compile-as-winrt.cc", 326), ("mozjs/third_party/python/gyp/test/win/compiler-flags/compile-as-winrt.gyp", 590), ("mozjs/third_party/python/gyp/test/win/compiler-flags/debug-format.gyp", 1121), ("mozjs/third_party/python/gyp/test/win/compiler-flags/default-char-is-unsigned.cc", 388), ("mozjs/third_party/python/gyp/test/win/compiler-flags/default-char-is-unsigned.gyp", 461), ("mozjs/third_party/python/gyp/test/win/compiler-flags/disable-specific-warnings.cc", 232), ("mozjs/third_party/python/gyp/test/win/compiler-flags/disable-specific-warnings.gyp", 740), ("mozjs/third_party/python/gyp/test/win/compiler-flags/enable-enhanced-instruction-set.cc", 545), ("mozjs/third_party/python/gyp/test/win/compiler-flags/enable-enhanced-instruction-set.gyp", 1886), ("mozjs/third_party/python/gyp/test/win/compiler-flags/exception-handling-on.cc", 417), ("mozjs/third_party/python/gyp/test/win/compiler-flags/exception-handling.gyp", 1208), ("mozjs/third_party/python/gyp/test/win/compiler-flags/floating-point-model-fast.cc", 292), ("mozjs/third_party/python/gyp/test/win/compiler-flags/floating-point-model-precise.cc", 292), ("mozjs/third_party/python/gyp/test/win/compiler-flags/floating-point-model-strict.cc", 292), ("mozjs/third_party/python/gyp/test/win/compiler-flags/floating-point-model.gyp", 1113), ("mozjs/third_party/python/gyp/test/win/compiler-flags/force-include-files-with-precompiled.cc", 224), ("mozjs/third_party/python/gyp/test/win/compiler-flags/force-include-files.cc", 229), ("mozjs/third_party/python/gyp/test/win/compiler-flags/force-include-files.gyp", 925), ("mozjs/third_party/python/gyp/test/win/compiler-flags/function-level-linking.cc", 226), ("mozjs/third_party/python/gyp/test/win/compiler-flags/function-level-linking.gyp", 674), ("mozjs/third_party/python/gyp/test/win/compiler-flags/hello.cc", 187), ("mozjs/third_party/python/gyp/test/win/compiler-flags/optimizations.gyp", 4640), ("mozjs/third_party/python/gyp/test/win/compiler-flags/pdbname-override.gyp", 676), ("mozjs/third_party/python/gyp/test/win/compiler-flags/pdbname.cc", 196), ("mozjs/third_party/python/gyp/test/win/compiler-flags/pdbname.gyp", 526), ("mozjs/third_party/python/gyp/test/win/compiler-flags/precomp.cc", 196), ("mozjs/third_party/python/gyp/test/win/compiler-flags/rtti-on.cc", 219), ("mozjs/third_party/python/gyp/test/win/compiler-flags/rtti.gyp", 837), ("mozjs/third_party/python/gyp/test/win/compiler-flags/runtime-checks.cc", 232), ("mozjs/third_party/python/gyp/test/win/compiler-flags/runtime-checks.gyp", 660), ("mozjs/third_party/python/gyp/test/win/compiler-flags/runtime-library-md.cc", 271), ("mozjs/third_party/python/gyp/test/win/compiler-flags/runtime-library-mdd.cc", 272), ("mozjs/third_party/python/gyp/test/win/compiler-flags/runtime-library-mt.cc", 270), ("mozjs/third_party/python/gyp/test/win/compiler-flags/runtime-library-mtd.cc", 271), ("mozjs/third_party/python/gyp/test/win/compiler-flags/runtime-library.gyp", 1087), ("mozjs/third_party/python/gyp/test/win/compiler-flags/subdir/header.h", 0), ("mozjs/third_party/python/gyp/test/win/compiler-flags/treat-wchar-t-as-built-in-type.gyp", 795), ("mozjs/third_party/python/gyp/test/win/compiler-flags/treat-wchar-t-as-built-in-type1.cc", 233), ("mozjs/third_party/python/gyp/test/win/compiler-flags/treat-wchar-t-as-built-in-type2.cc", 234), ("mozjs/third_party/python/gyp/test/win/compiler-flags/uninit.cc", 306), ("mozjs/third_party/python/gyp/test/win/compiler-flags/warning-as-error.cc", 235), ("mozjs/third_party/python/gyp/test/win/compiler-flags/warning-as-error.gyp", 858), ("mozjs/third_party/python/gyp/test/win/compiler-flags/warning-level.gyp", 2479), ("mozjs/third_party/python/gyp/test/win/compiler-flags/warning-level1.cc", 237), ("mozjs/third_party/python/gyp/test/win/compiler-flags/warning-level2.cc", 289), ("mozjs/third_party/python/gyp/test/win/compiler-flags/warning-level3.cc", 314), ("mozjs/third_party/python/gyp/test/win/compiler-flags/warning-level4.cc", 267), ("mozjs/third_party/python/gyp/test/win/enable-winrt/dllmain.cc", 885), ("mozjs/third_party/python/gyp/test/win/enable-winrt/enable-winrt.gyp", 860), ("mozjs/third_party/python/gyp/test/win/generator-output-different-drive/gyptest-generator-output-different-drive.py", 1463), ("mozjs/third_party/python/gyp/test/win/generator-output-different-drive/prog.c", 250), ("mozjs/third_party/python/gyp/test/win/generator-output-different-drive/prog.gyp", 306), ("mozjs/third_party/python/gyp/test/win/gyptest-asm-files.py", 722), ("mozjs/third_party/python/gyp/test/win/gyptest-cl-additional-include-dirs.py", 529), ("mozjs/third_party/python/gyp/test/win/gyptest-cl-additional-options.py", 726), ("mozjs/third_party/python/gyp/test/win/gyptest-cl-analysis.py", 818), ("mozjs/third_party/python/gyp/test/win/gyptest-cl-buffer-security-check.py", 1612), ("mozjs/third_party/python/gyp/test/win/gyptest-cl-calling-convention.py", 521), ("mozjs/third_party/python/gyp/test/win/gyptest-cl-character-set.py", 506), ("mozjs/third_party/python/gyp/test/win/gyptest-cl-compile-as-managed.py", 630), ("mozjs/third_party/python/gyp/test/win/gyptest-cl-compile-as-winrt.py", 503), ("mozjs/third_party/python/gyp/test/win/gyptest-cl-debug-format.py", 1270), ("mozjs/third_party/python/gyp/test/win/gyptest-cl-default-char-is-unsigned.py", 527), ("mozjs/third_party/python/gyp/test/win/gyptest-cl-disable-specific-warnings.py", 918), ("mozjs/third_party/python/gyp/test/win/gyptest-cl-enable-enhanced-instruction-set.py", 1516), ("mozjs/third_party/python/gyp/test/win/gyptest-cl-exception-handling.py", 886), ("mozjs/third_party/python/gyp/test/win/gyptest-cl-floating-point-model.py", 504), ("mozjs/third_party/python/gyp/test/win/gyptest-cl-force-include-files.py", 514), ("mozjs/third_party/python/gyp/test/win/gyptest-cl-function-level-linking.py", 1595), ("mozjs/third_party/python/gyp/test/win/gyptest-cl-optimizations.py", 3416), ("mozjs/third_party/python/gyp/test/win/gyptest-cl-pdbname-override.py", 742), ("mozjs/third_party/python/gyp/test/win/gyptest-cl-pdbname.py", 861), ("mozjs/third_party/python/gyp/test/win/gyptest-cl-rtti.py", 661), ("mozjs/third_party/python/gyp/test/win/gyptest-cl-runtime-checks.py", 798), ("mozjs/third_party/python/gyp/test/win/gyptest-cl-runtime-library.py", 514), ("mozjs/third_party/python/gyp/test/win/gyptest-cl-treat-wchar-t-as-built-in-type.py", 543), ("mozjs/third_party/python/gyp/test/win/gyptest-cl-warning-as-error.py", 861), ("mozjs/third_party/python/gyp/test/win/gyptest-cl-warning-level.py", 1394), ("mozjs/third_party/python/gyp/test/win/gyptest-command-quote.py", 1366), ("mozjs/third_party/python/gyp/test/win/gyptest-crosscompile-ar.py", 669), ("mozjs/third_party/python/gyp/test/win/gyptest-lib-ltcg.py", 553), ("mozjs/third_party/python/gyp/test/win/gyptest-link-additional-deps.py", 508), ("mozjs/third_party/python/gyp/test/win/gyptest-link-additional-options.py", 501), ("mozjs/third_party/python/gyp/test/win/gyptest-link-aslr.py", 894), ("mozjs/third_party/python/gyp/test/win/gyptest-link-base-address.py", 1817), ("mozjs/third_party/python/gyp/test/win/gyptest-link-debug-info.py", 701), ("mozjs/third_party/python/gyp/test/win/gyptest-link-default-libs.py", 502), ("mozjs/third_party/python/gyp/test/win/gyptest-link-deffile.py", 1252), ("mozjs/third_party/python/gyp/test/win/gyptest-link-defrelink.py", 1767), ("mozjs/third_party/python/gyp/test/win/gyptest-link-delay-load-dlls.py", 923), ("mozjs/third_party/python/gyp/test/win/gyptest-link-embed-manifest.py", 3546), ("mozjs/third_party/python/gyp/test/win/gyptest-link-enable-uac.py", 3382), ("mozjs/third_party/python/gyp/test/win/gyptest-link-enable-winrt-app-revision.py", 1180), ("mozjs/third_party/python/gyp/test/win/gyptest-link-enable-winrt-target-platform-version.py", 1427), ("mozjs/third_party/python/gyp/test/win/gyptest-link-enable-winrt.py", 872), ("mozjs/third_party/python/gyp/test/win/gyptest-link-entrypointsymbol.py", 588), ("mozjs/third_party/python/gyp/test/win/gyptest-link-fixed-base.py", 1099), ("mozjs/third_party/python/gyp/test/win/gyptest-link-force-symbol-reference.py", 715), ("mozjs/third_party/python/gyp/test/win/gyptest-link-generate-manifest.py", 4708), ("mozjs/third_party/python/gyp/test/win/gyptest-link-incremental.py", 992), ("mozjs/third_party/python/gyp/test/win/gyptest-link-large-address-aware.py", 896), ("mozjs/third_party/python/gyp/test/win/gyptest-link-large-pdb.py", 2444), ("mozjs/third_party/python/gyp/test/win/gyptest-link-library-adjust.py", 518), ("mozjs/third_party/python/gyp/test/win/gyptest-link-library-directories.py", 953), ("mozjs/third_party/python/gyp/test/win/gyptest-link-ltcg.py", 1499), ("mozjs/third_party/python/gyp/test/win/gyptest-link-mapfile.py", 1391), ("mozjs/third_party/python/gyp/test/win/gyptest-link-nodefaultlib.py", 572), ("mozjs/third_party/python/gyp/test/win/gyptest-link-noimportlib.py", 939), ("mozjs/third_party/python/gyp/test/win/gyptest-link-nxcompat.py", 960), ("mozjs/third_party/python/gyp/test/win/gyptest-link-opt-icf.py", 1319), ("mozjs/third_party/python/gyp/test/win/gyptest-link-opt-ref.py", 1145), ("mozjs/third_party/python/gyp/test/win/gyptest-link-ordering.py", 3058), ("mozjs/third_party/python/gyp/test/win/gyptest-link-outputfile.py", 792), ("mozjs/third_party/python/gyp/test/win/gyptest-link-pdb-no-output.py", 690), ("mozjs/third_party/python/gyp/test/win/gyptest-link-pdb-output.py", 872), ("mozjs/third_party/python/gyp/test/win/gyptest-link-pdb.py", 866), ("mozjs/third_party/python/gyp/test/win/gyptest-link-pgo.py", 2993), ("mozjs/third_party/python/gyp/test/win/gyptest-link-profile.py", 941), ("mozjs/third_party/python/gyp/test/win/gyptest-link-restat-importlib.py", 1375), ("mozjs/third_party/python/gyp/test/win/gyptest-link-safeseh.py", 1684), ("mozjs/third_party/python/gyp/test/win/gyptest-link-shard.py", 792), ("mozjs/third_party/python/gyp/test/win/gyptest-link-stacksize.py", 1964), ("mozjs/third_party/python/gyp/test/win/gyptest-link-subsystem.py", 1241), ("mozjs/third_party/python/gyp/test/win/gyptest-link-target-machine.py", 860), ("mozjs/third_party/python/gyp/test/win/gyptest-link-tsaware.py", 849), ("mozjs/third_party/python/gyp/test/win/gyptest-link-uldi-depending-on-module.py", 637), ("mozjs/third_party/python/gyp/test/win/gyptest-link-uldi.py", 777), ("mozjs/third_party/python/gyp/test/win/gyptest-link-unsupported-manifest.py", 721), ("mozjs/third_party/python/gyp/test/win/gyptest-link-update-manifest.py", 3008), ("mozjs/third_party/python/gyp/test/win/gyptest-link-warnings-as-errors.py", 649), ("mozjs/third_party/python/gyp/test/win/gyptest-long-command-line.py", 515), ("mozjs/third_party/python/gyp/test/win/gyptest-macro-projectname.py", 671), ("mozjs/third_party/python/gyp/test/win/gyptest-macro-targetext.py", 815), ("mozjs/third_party/python/gyp/test/win/gyptest-macro-targetfilename.py", 1256), ("mozjs/third_party/python/gyp/test/win/gyptest-macro-targetname.py", 899), ("mozjs/third_party/python/gyp/test/win/gyptest-macro-targetpath.py", 1010), ("mozjs/third_party/python/gyp/test/win/gyptest-macro-vcinstalldir.py", 719), ("mozjs/third_party/python/gyp/test/win/gyptest-macros-containing-gyp.py", 499), ("mozjs/third_party/python/gyp/test/win/gyptest-macros-in-inputs-and-outputs.py", 642), ("mozjs/third_party/python/gyp/test/win/gyptest-midl-excluded.py", 514), ("mozjs/third_party/python/gyp/test/win/gyptest-midl-includedirs.py", 499), ("mozjs/third_party/python/gyp/test/win/gyptest-midl-rules.py", 709), ("mozjs/third_party/python/gyp/test/win/gyptest-ml-safeseh.py", 484), ("mozjs/third_party/python/gyp/test/win/gyptest-quoting-commands.py", 739), ("mozjs/third_party/python/gyp/test/win/gyptest-rc-build.py", 689), ("mozjs/third_party/python/gyp/test/win/gyptest-sys.py", 725), ("mozjs/third_party/python/gyp/test/win/gyptest-system-include.py", 476), ("mozjs/third_party/python/gyp/test/win/idl-excluded/bad.idl", 261), ("mozjs/third_party/python/gyp/test/win/idl-excluded/copy-file.py", 285), ("mozjs/third_party/python/gyp/test/win/idl-excluded/idl-excluded.gyp", 1310), ("mozjs/third_party/python/gyp/test/win/idl-excluded/program.cc", 187), ("mozjs/third_party/python/gyp/test/win/idl-includedirs/hello.cc", 187), ("mozjs/third_party/python/gyp/test/win/idl-includedirs/idl-includedirs.gyp", 612), ("mozjs/third_party/python/gyp/test/win/idl-includedirs/subdir/bar.idl", 306), ("mozjs/third_party/python/gyp/test/win/idl-includedirs/subdir/foo.idl", 332), ("mozjs/third_party/python/gyp/test/win/idl-rules/Window.idl", 232), ("mozjs/third_party/python/gyp/test/win/idl-rules/basic-idl.gyp", 1558), ("mozjs/third_party/python/gyp/test/win/idl-rules/history_indexer.idl", 468), ("mozjs/third_party/python/gyp/test/win/idl-rules/history_indexer_user.cc", 458), ("mozjs/third_party/python/gyp/test/win/idl-rules/idl_compiler.py", 475), ("mozjs/third_party/python/gyp/test/win/importlib/dll_no_exports.cc", 270), ("mozjs/third_party/python/gyp/test/win/importlib/has-exports.cc", 235), ("mozjs/third_party/python/gyp/test/win/importlib/hello.cc", 239), ("mozjs/third_party/python/gyp/test/win/importlib/importlib.gyp", 664), ("mozjs/third_party/python/gyp/test/win/importlib/noimplib.gyp", 376), ("mozjs/third_party/python/gyp/test/win/large-pdb/dllmain.cc", 273), ("mozjs/third_party/python/gyp/test/win/large-pdb/large-pdb.gyp", 2551), ("mozjs/third_party/python/gyp/test/win/large-pdb/main.cc", 191), ("mozjs/third_party/python/gyp/test/win/lib-crosscompile/answer.cc", 211), ("mozjs/third_party/python/gyp/test/win/lib-crosscompile/answer.h", 173), ("mozjs/third_party/python/gyp/test/win/lib-crosscompile/use_host_ar.gyp", 395), ("mozjs/third_party/python/gyp/test/win/lib-flags/answer.cc", 211), ("mozjs/third_party/python/gyp/test/win/lib-flags/answer.h", 173), ("mozjs/third_party/python/gyp/test/win/lib-flags/ltcg.gyp", 514), ("mozjs/third_party/python/gyp/test/win/linker-flags/a/x.cc", 184), ("mozjs/third_party/python/gyp/test/win/linker-flags/a/z.cc", 184), ("mozjs/third_party/python/gyp/test/win/linker-flags/additional-deps.cc", 230), ("mozjs/third_party/python/gyp/test/win/linker-flags/additional-deps.gyp", 653), ("mozjs/third_party/python/gyp/test/win/linker-flags/additional-options.gyp", 657), ("mozjs/third_party/python/gyp/test/win/linker-flags/aslr.gyp", 767), ("mozjs/third_party/python/gyp/test/win/linker-flags/b/y.cc", 184), ("mozjs/third_party/python/gyp/test/win/linker-flags/base-address.gyp", 879), ("mozjs/third_party/python/gyp/test/win/linker-flags/debug-info.gyp", 633), ("mozjs/third_party/python/gyp/test/win/linker-flags/deffile-multiple.gyp", 378), ("mozjs/third_party/python/gyp/test/win/linker-flags/deffile.cc", 254), ("mozjs/third_party/python/gyp/test/win/linker-flags/deffile.def", 217), ("mozjs/third_party/python/gyp/test/win/linker-flags/deffile.gyp", 803), ("mozjs/third_party/python/gyp/test/win/linker-flags/delay-load-dlls.gyp", 749), ("mozjs/third_party/python/gyp/test/win/linker-flags/delay-load.cc", 235), ("mozjs/third_party/python/gyp/test/win/linker-flags/embed-manifest.gyp", 2586), ("mozjs/third_party/python/gyp/test/win/linker-flags/enable-uac.gyp", 1000), ("mozjs/third_party/python/gyp/test/win/linker-flags/entrypointsymbol.cc", 416), ("mozjs/third_party/python/gyp/test/win/linker-flags/entrypointsymbol.gyp", 636), ("mozjs/third_party/python/gyp/test/win/linker-flags/extra.manifest", 433), ("mozjs/third_party/python/gyp/test/win/linker-flags/extra2.manifest", 438), ("mozjs/third_party/python/gyp/test/win/linker-flags/fixed-base.gyp", 1297), ("mozjs/third_party/python/gyp/test/win/linker-flags/force-symbol-reference.gyp", 1085), ("mozjs/third_party/python/gyp/test/win/linker-flags/generate-manifest.gyp", 4241), ("mozjs/third_party/python/gyp/test/win/linker-flags/hello.cc", 187), ("mozjs/third_party/python/gyp/test/win/linker-flags/incremental.gyp", 1670), ("mozjs/third_party/python/gyp/test/win/linker-flags/inline_test.cc", 349), ("mozjs/third_party/python/gyp/test/win/linker-flags/inline_test.h", 214), ("mozjs/third_party/python/gyp/test/win/linker-flags/inline_test_main.cc", 352), ("mozjs/third_party/python/gyp/test/win/linker-flags/large-address-aware.gyp", 641), ("mozjs/third_party/python/gyp/test/win/linker-flags/library-adjust.cc", 230), ("mozjs/third_party/python/gyp/test/win/linker-flags/library-adjust.gyp", 349), ("mozjs/third_party/python/gyp/test/win/linker-flags/library-directories-define.cc", 199), ("mozjs/third_party/python/gyp/test/win/linker-flags/library-directories-reference.cc", 241), ("mozjs/third_party/python/gyp/test/win/linker-flags/library-directories.gyp", 1261), ("mozjs/third_party/python/gyp/test/win/linker-flags/link-ordering.gyp", 2547), ("mozjs/third_party/python/gyp/test/win/linker-flags/link-warning.cc", 262), ("mozjs/third_party/python/gyp/test/win/linker-flags/ltcg.gyp", 977), ("mozjs/third_party/python/gyp/test/win/linker-flags/main-crt.c", 261), ("mozjs/third_party/python/gyp/test/win/linker-flags/manifest-in-comment.cc", 552), ("mozjs/third_party/python/gyp/test/win/linker-flags/mapfile.cc", 315), ("mozjs/third_party/python/gyp/test/win/linker-flags/mapfile.gyp", 1093), ("mozjs/third_party/python/gyp/test/win/linker-flags/no-default-libs.cc", 617), ("mozjs/third_party/python/gyp/test/win/linker-flags/no-default-libs.gyp", 298), ("mozjs/third_party/python/gyp/test/win/linker-flags/nodefaultlib.cc", 441), ("mozjs/third_party/python/gyp/test/win/linker-flags/nodefaultlib.gyp", 762), ("mozjs/third_party/python/gyp/test/win/linker-flags/nxcompat.gyp", 783), ("mozjs/third_party/python/gyp/test/win/linker-flags/opt-icf.cc", 475), ("mozjs/third_party/python/gyp/test/win/linker-flags/opt-icf.gyp", 1909), ("mozjs/third_party/python/gyp/test/win/linker-flags/opt-ref.cc", 226), ("mozjs/third_party/python/gyp/test/win/linker-flags/opt-ref.gyp", 1637), ("mozjs/third_party/python/gyp/test/win/linker-flags/outputfile.gyp", 1365), ("mozjs/third_party/python/gyp/test/win/linker-flags/pdb-output.gyp", 1233), ("mozjs/third_party/python/gyp/test/win/linker-flags/pgo.gyp", 4259), ("mozjs/third_party/python/gyp/test/win/linker-flags/profile.gyp", 1255), ("mozjs/third_party/python/gyp/test/win/linker-flags/program-database.gyp", 1153), ("mozjs/third_party/python/gyp/test/win/linker-flags/safeseh.gyp", 1936), ("mozjs/third_party/python/gyp/test/win/linker-flags/safeseh_hello.cc", 224), ("mozjs/third_party/python/gyp/test/win/linker-flags/safeseh_zero.asm", 109), ("mozjs/third_party/python/gyp/test/win/linker-flags/safeseh_zero64.asm", 94), ("mozjs/third_party/python/gyp/test/win/linker-flags/stacksize.gyp", 1021), ("mozjs/third_party/python/gyp/test/win/linker-flags/subdir/library.gyp", 325), ("mozjs/third_party/python/gyp/test/win/linker-flags/subsystem-windows.cc", 253), ("mozjs/third_party/python/gyp/test/win/linker-flags/subsystem.gyp", 1577), ("mozjs/third_party/python/gyp/test/win/linker-flags/target-machine.gyp", 1071), ("mozjs/third_party/python/gyp/test/win/linker-flags/tsaware.gyp", 621), ("mozjs/third_party/python/gyp/test/win/linker-flags/unsupported-manifest.gyp", 307), ("mozjs/third_party/python/gyp/test/win/linker-flags/update_pgd.py", 1210), ("mozjs/third_party/python/gyp/test/win/linker-flags/warn-as-error.gyp", 788), ("mozjs/third_party/python/gyp/test/win/linker-flags/x.cc", 184), ("mozjs/third_party/python/gyp/test/win/linker-flags/y.cc", 184), ("mozjs/third_party/python/gyp/test/win/linker-flags/z.cc", 184), ("mozjs/third_party/python/gyp/test/win/long-command-line/function.cc", 187), ("mozjs/third_party/python/gyp/test/win/long-command-line/hello.cc", 187), ("mozjs/third_party/python/gyp/test/win/long-command-line/long-command-line.gyp", 1250), ("mozjs/third_party/python/gyp/test/win/ml-safeseh/a.asm", 109), ("mozjs/third_party/python/gyp/test/win/ml-safeseh/hello.cc", 224), ("mozjs/third_party/python/gyp/test/win/ml-safeseh/ml-safeseh.gyp", 517), ("mozjs/third_party/python/gyp/test/win/precompiled/gyptest-all.py", 584), ("mozjs/third_party/python/gyp/test/win/precompiled/hello.c", 362), ("mozjs/third_party/python/gyp/test/win/precompiled/hello.gyp", 673), ("mozjs/third_party/python/gyp/test/win/precompiled/hello2.c", 313), ("mozjs/third_party/python/gyp/test/win/precompiled/precomp.c", 277), ("mozjs/third_party/python/gyp/test/win/rc-build/Resource.h", 593), ("mozjs/third_party/python/gyp/test/win/rc-build/hello.cpp", 932), ("mozjs/third_party/python/gyp/test/win/rc-build/hello.gyp", 2023), ("mozjs/third_party/python/gyp/test/win/rc-build/hello.h", 39), ("mozjs/third_party/python/gyp/test/win/rc-build/hello.ico", 23558), ("mozjs/third_party/python/gyp/test/win/rc-build/hello.rc", 1974), ("mozjs/third_party/python/gyp/test/win/rc-build/hello3.rc", 1996), ("mozjs/third_party/python/gyp/test/win/rc-build/small.ico", 23558), ("mozjs/third_party/python/gyp/test/win/rc-build/subdir/hello2.rc", 2003), ("mozjs/third_party/python/gyp/test/win/rc-build/subdir/include.h", 49), ("mozjs/third_party/python/gyp/test/win/rc-build/targetver.h", 1428), ("mozjs/third_party/python/gyp/test/win/shard/hello.cc", 183), ("mozjs/third_party/python/gyp/test/win/shard/hello1.cc", 185), ("mozjs/third_party/python/gyp/test/win/shard/hello2.cc", 185), ("mozjs/third_party/python/gyp/test/win/shard/hello3.cc", 185), ("mozjs/third_party/python/gyp/test/win/shard/hello4.cc", 185), ("mozjs/third_party/python/gyp/test/win/shard/shard.gyp", 659), ("mozjs/third_party/python/gyp/test/win/shard/shard_ref.gyp", 990), ("mozjs/third_party/python/gyp/test/win/system-include/bar/header.h", 0), ("mozjs/third_party/python/gyp/test/win/system-include/common/commonheader.h", 0), ("mozjs/third_party/python/gyp/test/win/system-include/foo/header.h", 0), ("mozjs/third_party/python/gyp/test/win/system-include/main.cc", 61), ("mozjs/third_party/python/gyp/test/win/system-include/test.gyp", 517), ("mozjs/third_party/python/gyp/test/win/uldi/a.cc", 196), ("mozjs/third_party/python/gyp/test/win/uldi/b.cc", 196), ("mozjs/third_party/python/gyp/test/win/uldi/dll.cc", 206), ("mozjs/third_party/python/gyp/test/win/uldi/exe.cc", 187), ("mozjs/third_party/python/gyp/test/win/uldi/main.cc", 235), ("mozjs/third_party/python/gyp/test/win/uldi/uldi-depending-on-module.gyp", 863), ("mozjs/third_party/python/gyp/test/win/uldi/uldi.gyp", 1087), ("mozjs/third_party/python/gyp/test/win/vs-macros/as.py", 489), ("mozjs/third_party/python/gyp/test/win/vs-macros/containing-gyp.gyp", 988), ("mozjs/third_party/python/gyp/test/win/vs-macros/do_stuff.py", 257), ("mozjs/third_party/python/gyp/test/win/vs-macros/hello.cc", 187), ("mozjs/third_party/python/gyp/test/win/vs-macros/input-output-macros.gyp", 781), ("mozjs/third_party/python/gyp/test/win/vs-macros/input.S", 0), ("mozjs/third_party/python/gyp/test/win/vs-macros/projectname.gyp", 731), ("mozjs/third_party/python/gyp/test/win/vs-macros/stuff.blah", 18), ("mozjs/third_party/python/gyp/test/win/vs-macros/targetext.gyp", 1558), ("mozjs/third_party/python/gyp/test/win/vs-macros/targetfilename.gyp", 1535), ("mozjs/third_party/python/gyp/test/win/vs-macros/targetname.gyp", 1411), ("mozjs/third_party/python/gyp/test/win/vs-macros/targetpath.gyp", 1425), ("mozjs/third_party/python/gyp/test/win/vs-macros/test_exists.py", 254), ("mozjs/third_party/python/gyp/test/win/vs-macros/vcinstalldir.gyp", 1041), ("mozjs/third_party/python/gyp/test/win/win-driver-target-type/win-driver-target-type.c", 345), ("mozjs/third_party/python/gyp/test/win/win-driver-target-type/win-driver-target-type.gyp", 792), ("mozjs/third_party/python/gyp/test/win/win-driver-target-type/win-driver-target-type.h", 306), ("mozjs/third_party/python/gyp/test/win/win-driver-target-type/win-driver-target-type.rc", 496), ("mozjs/third_party/python/gyp/test/win/win-tool/copies_readonly_files.gyp", 494), ("mozjs/third_party/python/gyp/test/win/win-tool/gyptest-win-tool-handles-readonly-files.py", 1699), ("mozjs/third_party/python/gyp/test/win/winrt-app-type-revision/dllmain.cc", 885), ("mozjs/third_party/python/gyp/test/win/winrt-app-type-revision/winrt-app-type-revison.gyp", 1026), ("mozjs/third_party/python/gyp/test/win/winrt-target-platform-version/dllmain.cc", 885), ("mozjs/third_party/python/gyp/test/win/winrt-target-platform-version/winrt-target-platform-version.gyp", 1367), ("mozjs/third_party/python/gyp/test/xcode-ninja/list_excluded/gyptest-all.py", 1284), ("mozjs/third_party/python/gyp/test/xcode-ninja/list_excluded/hello.cpp", 197), ("mozjs/third_party/python/gyp/test/xcode-ninja/list_excluded/hello_exclude.gyp", 400), ("mozjs/third_party/python/gyp/test/xcode-ninja/list_excluded/hello_excluded.cpp", 198), ("mozjs/third_party/python/gyp/tools/emacs/testdata/media.gyp", 37163), ("mozjs/third_party/python/gyp/tools/emacs/testdata/media.gyp.fontified", 162854), ("mozjs/third_party/python/mock-1.0.0/docs/changelog.txt", 32435), ("mozjs/third_party/python/mock-1.0.0/docs/compare.txt", 17014), ("mozjs/third_party/python/mock-1.0.0/docs/conf.py", 6310), ("mozjs/third_party/python/mock-1.0.0/docs/examples.txt", 36147), ("mozjs/third_party/python/mock-1.0.0/docs/getting-started.txt", 14894), ("mozjs/third_party/python/mock-1.0.0/docs/helpers.txt", 19714), ("mozjs/third_party/python/mock-1.0.0/docs/index.txt", 13653), ("mozjs/third_party/python/mock-1.0.0/docs/magicmock.txt", 7881), ("mozjs/third_party/python/mock-1.0.0/docs/mock.txt", 27276), ("mozjs/third_party/python/mock-1.0.0/docs/patch.txt", 22455), ("mozjs/third_party/python/mock-1.0.0/docs/sentinel.txt", 1537), ("mozjs/third_party/python/mock-1.0.0/html/_static/breadcrumb_background.png", 136), ("mozjs/third_party/python/mock-1.0.0/html/_static/documentation.png", 412), ("mozjs/third_party/python/mock-1.0.0/html/_static/file.png", 392), ("mozjs/third_party/python/mock-1.0.0/html/_static/header_sm_mid.png", 159), ("mozjs/third_party/python/mock-1.0.0/html/_static/minus.png", 199), ("mozjs/third_party/python/mock-1.0.0/html/_static/plus.png", 199), ("mozjs/third_party/python/mock-1.0.0/html/_static/scrn1.png", 108046), ("mozjs/third_party/python/mock-1.0.0/html/_static/scrn2.png", 121395), ("mozjs/third_party/python/mock-1.0.0/html/_static/searchfield_leftcap.png", 855), ("mozjs/third_party/python/mock-1.0.0/html/_static/searchfield_repeat.png", 158), ("mozjs/third_party/python/mock-1.0.0/html/_static/searchfield_rightcap.png", 530), ("mozjs/third_party/python/mock-1.0.0/html/_static/title_background.png", 132), ("mozjs/third_party/python/mock-1.0.0/html/_static/triangle_closed.png", 181), ("mozjs/third_party/python/mock-1.0.0/html/_static/triangle_left.png", 195), ("mozjs/third_party/python/mock-1.0.0/html/_static/triangle_open.png", 191), ("mozjs/third_party/python/mock-1.0.0/tests/__init__.py", 146), ("mozjs/third_party/python/mock-1.0.0/tests/_testwith.py", 6077), ("mozjs/third_party/python/mock-1.0.0/tests/support.py", 702), ("mozjs/third_party/python/mock-1.0.0/tests/support_with.py", 2972), ("mozjs/third_party/python/mock-1.0.0/tests/testcallable.py", 4477), ("mozjs/third_party/python/mock-1.0.0/tests/testhelpers.py", 27419), ("mozjs/third_party/python/mock-1.0.0/tests/testmagicmethods.py", 14863), ("mozjs/third_party/python/mock-1.0.0/tests/testmock.py", 42396), ("mozjs/third_party/python/mock-1.0.0/tests/testpatch.py", 53251), ("mozjs/third_party/python/mock-1.0.0/tests/testsentinel.py", 989), ("mozjs/third_party/python/mock-1.0.0/tests/testwith.py", 340), ("mozjs/third_party/python/more-itertools/docs/Makefile", 5596), ("mozjs/third_party/python/more-itertools/docs/_static/theme_overrides.css", 437), ("mozjs/third_party/python/more-itertools/docs/api.rst", 4077), ("mozjs/third_party/python/more-itertools/docs/conf.py", 8040), ("mozjs/third_party/python/more-itertools/docs/index.rst", 155), ("mozjs/third_party/python/more-itertools/docs/license.rst", 646), ("mozjs/third_party/python/more-itertools/docs/make.bat", 5112), ("mozjs/third_party/python/more-itertools/docs/testing.rst", 304), ("mozjs/third_party/python/more-itertools/docs/versions.rst", 8271), ("mozjs/third_party/python/more-itertools/more_itertools/tests/__init__.py", 0), ("mozjs/third_party/python/more-itertools/more_itertools/tests/test_more.py", 70672), ("mozjs/third_party/python/more-itertools/more_itertools/tests/test_recipes.py", 19837), ("mozjs/third_party/python/mozilla-version/mozilla_version/test/__init__.py", 0), ("mozjs/third_party/python/mozilla-version/mozilla_version/test/test_balrog.py", 7681), ("mozjs/third_party/python/mozilla-version/mozilla_version/test/test_gecko.py", 12971), ("mozjs/third_party/python/mozilla-version/mozilla_version/test/test_version.py", 1816), ("mozjs/third_party/python/pathlib2/tests/test_pathlib2.py", 94367), ("mozjs/third_party/python/pip-tools/examples/django.in", 132), ("mozjs/third_party/python/pip-tools/examples/flask.in", 49), ("mozjs/third_party/python/pip-tools/examples/hypothesis.in", 19), ("mozjs/third_party/python/pip-tools/examples/protection.in", 168), ("mozjs/third_party/python/pip-tools/examples/sentry.in", 49), ("mozjs/third_party/python/pip-tools/img/pip-tools-overview.png", 23961), ("mozjs/third_party/python/pip-tools/tests/__init__.py", 110), ("mozjs/third_party/python/pip-tools/tests/conftest.py", 3327), ("mozjs/third_party/python/pip-tools/tests/test_cache.py", 3158), ("mozjs/third_party/python/pip-tools/tests/test_cli.py", 13096), ("mozjs/third_party/python/pip-tools/tests/test_data/fake-editables.json", 56), ("mozjs/third_party/python/pip-tools/tests/test_data/fake-index.json", 2753), ("mozjs/third_party/python/pip-tools/tests/test_data/fake_package/setup.py", 603), ("mozjs/third_party/python/pip-tools/tests/test_data/minimal_wheels/small_fake_a-0.1-py2.py3-none-any.whl", 1631), ("mozjs/third_party/python/pip-tools/tests/test_data/minimal_wheels/small_fake_a-0.2-py2.py3-none-any.whl", 1632), ("mozjs/third_party/python/pip-tools/tests/test_data/minimal_wheels/small_fake_b-0.1-py2.py3-none-any.whl", 1635), ("mozjs/third_party/python/pip-tools/tests/test_data/minimal_wheels/small_fake_b-0.2-py2.py3-none-any.whl", 1631), ("mozjs/third_party/python/pip-tools/tests/test_data/small_fake_package/setup.py", 142), ("mozjs/third_party/python/pip-tools/tests/test_fake_index.py", 2528), ("mozjs/third_party/python/pip-tools/tests/test_minimal_upgrade.py", 1348), ("mozjs/third_party/python/pip-tools/tests/test_repositories.py", 2530), ("mozjs/third_party/python/pip-tools/tests/test_repository_local.py", 4615), ("mozjs/third_party/python/pip-tools/tests/test_repository_pypi.py", 4664), ("mozjs/third_party/python/pip-tools/tests/test_resolver.py", 4967), ("mozjs/third_party/python/pip-tools/tests/test_sync.py", 7164), ("mozjs/third_party/python/pip-tools/tests/test_top_level_editable.py", 1369), ("mozjs/third_party/python/pip-tools/tests/test_utils.py", 1659), ("mozjs/third_party/python/pip-tools/tests/test_writer.py", 3900), ("mozjs/third_party/python/pipenv/tests/integration/__init__.py", 0), ("mozjs/third_party/python/pipenv/tests/integration/conftest.py", 4274), ("mozjs/third_party/python/pipenv/tests/integration/test_cli.py", 4361), ("mozjs/third_party/python/pipenv/tests/integration/test_dot_venv.py", 3380), ("mozjs/third_party/python/pipenv/tests/integration/test_install_basic.py", 8296), ("mozjs/third_party/python/pipenv/tests/integration/test_install_markers.py", 4979), ("mozjs/third_party/python/pipenv/tests/integration/test_install_twists.py", 7480), ("mozjs/third_party/python/pipenv/tests/integration/test_install_uri.py", 5264), ("mozjs/third_party/python/pipenv/tests/integration/test_lock.py", 7906), ("mozjs/third_party/python/pipenv/tests/integration/test_pipenv.py", 1492), ("mozjs/third_party/python/pipenv/tests/integration/test_project.py", 4513), ("mozjs/third_party/python/pipenv/tests/integration/test_run.py", 1577), ("mozjs/third_party/python/pipenv/tests/integration/test_sync.py", 1122), ("mozjs/third_party/python/pipenv/tests/integration/test_uninstall.py", 3618), ("mozjs/third_party/python/pipenv/tests/integration/test_windows.py", 815), ("mozjs/third_party/python/pipenv/tests/unit/__init__.py", 0), ("mozjs/third_party/python/pipenv/tests/unit/test_cmdparse.py", 1295), ("mozjs/third_party/python/pipenv/tests/unit/test_help.py", 224), ("mozjs/third_party/python/pipenv/tests/unit/test_utils.py", 17020), ("mozjs/third_party/python/pipenv/tests/unit/test_vendor.py", 3378), ("mozjs/third_party/python/pluggy/testing/benchmark.py", 1328), ("mozjs/third_party/python/pluggy/testing/conftest.py", 547), ("mozjs/third_party/python/pluggy/testing/test_details.py", 2753), ("mozjs/third_party/python/pluggy/testing/test_helpers.py", 1299), ("mozjs/third_party/python/pluggy/testing/test_hookrelay.py", 4997), ("mozjs/third_party/python/pluggy/testing/test_method_ordering.py", 7642), ("mozjs/third_party/python/pluggy/testing/test_multicall.py", 4084), ("mozjs/third_party/python/pluggy/testing/test_pluginmanager.py", 8644), ("mozjs/third_party/python/pluggy/testing/test_tracer.py", 2110), ("mozjs/third_party/python/psutil-cp27-none-win_amd64/psutil/tests/__init__.py", 37656), ("mozjs/third_party/python/psutil-cp27-none-win_amd64/psutil/tests/__main__.py", 2784), ("mozjs/third_party/python/psutil-cp27-none-win_amd64/psutil/tests/test_aix.py", 4483), ("mozjs/third_party/python/psutil-cp27-none-win_amd64/psutil/tests/test_bsd.py", 17785), ("mozjs/third_party/python/psutil-cp27-none-win_amd64/psutil/tests/test_connections.py", 20676), ("mozjs/third_party/python/psutil-cp27-none-win_amd64/psutil/tests/test_contracts.py", 24136), ("mozjs/third_party/python/psutil-cp27-none-win_amd64/psutil/tests/test_linux.py", 78746), ("mozjs/third_party/python/psutil-cp27-none-win_amd64/psutil/tests/test_memory_leaks.py", 18259), ("mozjs/third_party/python/psutil-cp27-none-win_amd64/psutil/tests/test_misc.py", 37821), ("mozjs/third_party/python/psutil-cp27-none-win_amd64/psutil/tests/test_osx.py", 9652), ("mozjs/third_party/python/psutil-cp27-none-win_amd64/psutil/tests/test_posix.py", 16397), ("mozjs/third_party/python/psutil-cp27-none-win_amd64/psutil/tests/test_process.py", 60495), ("mozjs/third_party/python/psutil-cp27-none-win_amd64/psutil/tests/test_sunos.py", 1322), ("mozjs/third_party/python/psutil-cp27-none-win_amd64/psutil/tests/test_system.py", 34885), ("mozjs/third_party/python/psutil-cp27-none-win_amd64/psutil/tests/test_unicode.py", 12913), ("mozjs/third_party/python/psutil-cp27-none-win_amd64/psutil/tests/test_windows.py", 32609), ("mozjs/third_party/python/psutil/docs/Makefile", 7634), ("mozjs/third_party/python/psutil/docs/README", 385), ("mozjs/third_party/python/psutil/docs/_static/copybutton.js", 2495), ("mozjs/third_party/python/psutil/docs/_static/css/custom.css", 7913), ("mozjs/third_party/python/psutil/docs/_static/favicon.ico", 15086), ("mozjs/third_party/python/psutil/docs/_static/sidebar.js", 5164), ("mozjs/third_party/python/psutil/docs/conf.py", 10834), ("mozjs/third_party/python/psutil/docs/index.rst", 132415), ("mozjs/third_party/python/psutil/docs/make.bat", 6707), ("mozjs/third_party/python/psutil/psutil/tests/README.rst", 823), ("mozjs/third_party/python/psutil/psutil/tests/__init__.py", 37656), ("mozjs/third_party/python/psutil/psutil/tests/__main__.py", 2784), ("mozjs/third_party/python/psutil/psutil/tests/test_aix.py", 4483), ("mozjs/third_party/python/psutil/psutil/tests/test_bsd.py", 17785), ("mozjs/third_party/python/psutil/psutil/tests/test_connections.py", 20676), ("mozjs/third_party/python/psutil/psutil/tests/test_contracts.py", 24136), ("mozjs/third_party/python/psutil/psutil/tests/test_linux.py", 78746), ("mozjs/third_party/python/psutil/psutil/tests/test_memory_leaks.py", 18259), ("mozjs/third_party/python/psutil/psutil/tests/test_misc.py", 37821), ("mozjs/third_party/python/psutil/psutil/tests/test_osx.py", 9652), ("mozjs/third_party/python/psutil/psutil/tests/test_posix.py", 16397), ("mozjs/third_party/python/psutil/psutil/tests/test_process.py", 60495), ("mozjs/third_party/python/psutil/psutil/tests/test_sunos.py", 1322), ("mozjs/third_party/python/psutil/psutil/tests/test_system.py", 34885), ("mozjs/third_party/python/psutil/psutil/tests/test_unicode.py", 12913), ("mozjs/third_party/python/psutil/psutil/tests/test_windows.py", 32609), ("mozjs/third_party/python/py/doc/Makefile", 4636), ("mozjs/third_party/python/py/doc/_templates/layout.html", 553), ("mozjs/third_party/python/py/doc/announce/release-0.9.0.txt", 221), ("mozjs/third_party/python/py/doc/announce/release-0.9.2.txt", 876), ("mozjs/third_party/python/py/doc/announce/release-1.0.0.txt", 2851), ("mozjs/third_party/python/py/doc/announce/release-1.0.1.txt", 1760), ("mozjs/third_party/python/py/doc/announce/release-1.0.2.txt", 159), ("mozjs/third_party/python/py/doc/announce/release-1.1.0.txt", 4055), ("mozjs/third_party/python/py/doc/announce/release-1.1.1.txt", 1874), ("mozjs/third_party/python/py/doc/announce/release-1.2.0.txt", 4932), ("mozjs/third_party/python/py/doc/announce/release-1.2.1.txt", 2952), ("mozjs/third_party/python/py/doc/announce/release-1.3.0.txt", 22142), ("mozjs/third_party/python/py/doc/announce/release-1.3.1.txt", 4360), ("mozjs/third_party/python/py/doc/announce/release-1.3.2.txt", 28187), ("mozjs/third_party/python/py/doc/announce/release-1.3.3.txt", 995), ("mozjs/third_party/python/py/doc/announce/release-1.3.4.txt", 1010), ("mozjs/third_party/python/py/doc/announce/release-1.4.0.txt", 1907), ("mozjs/third_party/python/py/doc/announce/release-1.4.1.txt", 1861), ("mozjs/third_party/python/py/doc/announce/releases.txt", 205), ("mozjs/third_party/python/py/doc/changelog.txt", 44), ("mozjs/third_party/python/py/doc/code.txt", 4666), ("mozjs/third_party/python/py/doc/conf.py", 8482), ("mozjs/third_party/python/py/doc/download.html", 539), ("mozjs/third_party/python/py/doc/example/genhtml.py", 259), ("mozjs/third_party/python/py/doc/example/genhtmlcss.py", 426), ("mozjs/third_party/python/py/doc/example/genxml.py", 344), ("mozjs/third_party/python/py/doc/faq.txt", 6489), ("mozjs/third_party/python/py/doc/img/pylib.png", 8276), ("mozjs/third_party/python/py/doc/index.txt", 635), ("mozjs/third_party/python/py/doc/install.txt", 2341), ("mozjs/third_party/python/py/doc/io.txt", 1145), ("mozjs/third_party/python/py/doc/links.inc", 754), ("mozjs/third_party/python/py/doc/log.txt", 9286), ("mozjs/third_party/python/py/doc/misc.txt", 3299), ("mozjs/third_party/python/py/doc/path.txt", 8320), ("mozjs/third_party/python/py/doc/style.css", 17414), ("mozjs/third_party/python/py/doc/xml.txt", 5631), ("mozjs/third_party/python/py/testing/code/test_assertion.py", 7796), ("mozjs/third_party/python/py/testing/code/test_code.py", 4212), ("mozjs/third_party/python/py/testing/code/test_excinfo.py", 31283), ("mozjs/third_party/python/py/testing/code/test_source.py", 17086), ("mozjs/third_party/python/py/testing/conftest.py", 31), ("mozjs/third_party/python/py/testing/io_/__init__.py", 2), ("mozjs/third_party/python/py/testing/io_/test_capture.py", 14792), ("mozjs/third_party/python/py/testing/io_/test_saferepr.py", 2263), ("mozjs/third_party/python/py/testing/io_/test_terminalwriter.py", 9533), ("mozjs/third_party/python/py/testing/log/__init__.py", 0), ("mozjs/third_party/python/py/testing/log/test_log.py", 6678), ("mozjs/third_party/python/py/testing/log/test_warning.py", 2540), ("mozjs/third_party/python/py/testing/path/common.py", 16410), ("mozjs/third_party/python/py/testing/path/conftest.py", 2685), ("mozjs/third_party/python/py/testing/path/repotest.dump", 3287), ("mozjs/third_party/python/py/testing/path/svntestbase.py", 924), ("mozjs/third_party/python/py/testing/path/test_cacheutil.py", 2923), ("mozjs/third_party/python/py/testing/path/test_local.py", 33213), ("mozjs/third_party/python/py/testing/path/test_svnauth.py", 16210), ("mozjs/third_party/python/py/testing/path/test_svnurl.py", 3524), ("mozjs/third_party/python/py/testing/path/test_svnwc.py", 20074), ("mozjs/third_party/python/py/testing/process/__init__.py", 2), ("mozjs/third_party/python/py/testing/process/test_cmdexec.py", 1135), ("mozjs/third_party/python/py/testing/process/test_forkedfunc.py", 4612), ("mozjs/third_party/python/py/testing/process/test_killproc.py", 603), ("mozjs/third_party/python/py/testing/root/__init__.py", 2), ("mozjs/third_party/python/py/testing/root/test_builtin.py", 5177), ("mozjs/third_party/python/py/testing/root/test_error.py", 2017), ("mozjs/third_party/python/py/testing/root/test_py_imports.py", 1977), ("mozjs/third_party/python/py/testing/root/test_std.py", 270), ("mozjs/third_party/python/py/testing/root/test_xmlgen.py", 4005), ("mozjs/third_party/python/pyasn1-modules/tests/__init__.py", 59), ("mozjs/third_party/python/pyasn1-modules/tests/__main__.py", 703), ("mozjs/third_party/python/pyasn1-modules/tests/test_rfc2314.py", 2050), ("mozjs/third_party/python/pyasn1-modules/tests/test_rfc2315.py", 9203), ("mozjs/third_party/python/pyasn1-modules/tests/test_rfc2437.py", 1422), ("mozjs/third_party/python/pyasn1-modules/tests/test_rfc2459.py", 3954), ("mozjs/third_party/python/pyasn1-modules/tests/test_rfc2511.py", 1563), ("mozjs/third_party/python/pyasn1-modules/tests/test_rfc2560.py", 3210), ("mozjs/third_party/python/pyasn1-modules/tests/test_rfc4210.py", 7810), ("mozjs/third_party/python/pyasn1-modules/tests/test_rfc5208.py", 2451), ("mozjs/third_party/python/pyasn1-modules/tests/test_rfc5280.py", 2909), ("mozjs/third_party/python/pyasn1-modules/tests/test_rfc5652.py", 3361), ("mozjs/third_party/python/pyasn1/doc/Makefile", 7418), ("mozjs/third_party/python/pyasn1/doc/source/changelog.rst", 54), ("mozjs/third_party/python/pyasn1/doc/source/conf.py", 10456), ("mozjs/third_party/python/pyasn1/doc/source/contents.rst", 6894), ("mozjs/third_party/python/pyasn1/doc/source/docs/api-reference.rst", 492), ("mozjs/third_party/python/pyasn1/doc/source/docs/codec/ber/contents.rst", 208), ("mozjs/third_party/python/pyasn1/doc/source/docs/codec/cer/contents.rst", 186), ("mozjs/third_party/python/pyasn1/doc/source/docs/codec/der/contents.rst", 194), ("mozjs/third_party/python/pyasn1/doc/source/docs/codec/native/contents.rst", 180), ("mozjs/third_party/python/pyasn1/doc/source/docs/tutorial.rst", 58552), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/char/bmpstring.rst", 770), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/char/contents.rst", 482), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/char/generalstring.rst", 804), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/char/graphicstring.rst", 816), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/char/ia5string.rst", 776), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/char/iso646string.rst", 738), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/char/numericstring.rst", 750), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/char/printablestring.rst", 822), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/char/t61string.rst", 718), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/char/teletexstring.rst", 929), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/char/universalstring.rst", 787), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/char/utf8string.rst", 758), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/char/videotexstring.rst", 856), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/char/visiblestring.rst", 821), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/namedtype/contents.rst", 242), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/namedtype/defaultednamedtype.rst", 266), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/namedtype/namedtype.rst", 221), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/namedtype/namedtypes.rst", 85), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/namedtype/optionalnamedtype.rst", 244), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/namedval/contents.rst", 309), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/namedval/namedval.rst", 217), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/tag/contents.rst", 132), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/tag/tag.rst", 204), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/tag/tagmap.rst", 84), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/tag/tagset.rst", 93), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/univ/any.rst", 942), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/univ/bitstring.rst", 916), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/univ/boolean.rst", 629), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/univ/choice.rst", 926), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/univ/contents.rst", 490), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/univ/enumerated.rst", 793), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/univ/integer.rst", 808), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/univ/null.rst", 468), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/univ/objectidentifier.rst", 692), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/univ/octetstring.rst", 855), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/univ/real.rst", 645), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/univ/sequence.rst", 897), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/univ/sequenceof.rst", 858), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/univ/set.rst", 934), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/univ/setof.rst", 837), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/useful/contents.rst", 162), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/useful/generalizedtime.rst", 1477), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/useful/objectdescriptor.rst", 844), ("mozjs/third_party/python/pyasn1/doc/source/docs/type/useful/utctime.rst", 1307), ("mozjs/third_party/python/pyasn1/doc/source/example-use-case.rst", 6415), ("mozjs/third_party/python/pyasn1/doc/source/license.rst", 62), ("mozjs/third_party/python/pyasn1/tests/__init__.py", 59), ("mozjs/third_party/python/pyasn1/tests/__main__.py", 500), ("mozjs/third_party/python/pyasn1/tests/base.py", 453), ("mozjs/third_party/python/pyasn1/tests/codec/__init__.py", 59), ("mozjs/third_party/python/pyasn1/tests/codec/__main__.py", 523), ("mozjs/third_party/python/pyasn1/tests/codec/ber/__init__.py", 59), ("mozjs/third_party/python/pyasn1/tests/codec/ber/__main__.py", 450), ("mozjs/third_party/python/pyasn1/tests/codec/ber/test_decoder.py", 48422), ("mozjs/third_party/python/pyasn1/tests/codec/ber/test_encoder.py", 34386), ("mozjs/third_party/python/pyasn1/tests/codec/cer/__init__.py", 59), ("mozjs/third_party/python/pyasn1/tests/codec/cer/__main__.py", 450), ("mozjs/third_party/python/pyasn1/tests/codec/cer/test_decoder.py", 2011), ("mozjs/third_party/python/pyasn1/tests/codec/cer/test_encoder.py", 22658), ("mozjs/third_party/python/pyasn1/tests/codec/der/__init__.py", 59), ("mozjs/third_party/python/pyasn1/tests/codec/der/__main__.py", 450), ("mozjs/third_party/python/pyasn1/tests/codec/der/test_decoder.py", 2163), ("mozjs/third_party/python/pyasn1/tests/codec/der/test_encoder.py", 8942), ("mozjs/third_party/python/pyasn1/tests/codec/native/__init__.py", 59), ("mozjs/third_party/python/pyasn1/tests/codec/native/__main__.py", 455), ("mozjs/third_party/python/pyasn1/tests/codec/native/test_decoder.py", 3630), ("mozjs/third_party/python/pyasn1/tests/codec/native/test_encoder.py", 3975), ("mozjs/third_party/python/pyasn1/tests/compat/__init__.py", 59), ("mozjs/third_party/python/pyasn1/tests/compat/__main__.py", 482), ("mozjs/third_party/python/pyasn1/tests/compat/test_binary.py", 1195), ("mozjs/third_party/python/pyasn1/tests/compat/test_integer.py", 1449), ("mozjs/third_party/python/pyasn1/tests/compat/test_octets.py", 3544), ("mozjs/third_party/python/pyasn1/tests/test_debug.py", 978), ("mozjs/third_party/python/pyasn1/tests/type/__init__.py", 59), ("mozjs/third_party/python/pyasn1/tests/type/__main__.py", 625), ("mozjs/third_party/python/pyasn1/tests/type/test_char.py", 5120), ("mozjs/third_party/python/pyasn1/tests/type/test_constraint.py", 9105), ("mozjs/third_party/python/pyasn1/tests/type/test_namedtype.py", 4668), ("mozjs/third_party/python/pyasn1/tests/type/test_namedval.py", 1828), ("mozjs/third_party/python/pyasn1/tests/type/test_tag.py", 4600), ("mozjs/third_party/python/pyasn1/tests/type/test_univ.py", 49207), ("mozjs/third_party/python/pyasn1/tests/type/test_useful.py", 3591), ("mozjs/third_party/python/pystache/gh/images/logo_phillips.png", 173595), ("mozjs/third_party/python/pystache/pystache/template_spec.py", 1725), ("mozjs/third_party/python/pytest/doc/en/Makefile", 5270), ("mozjs/third_party/python/pytest/doc/en/_templates/globaltoc.html", 846), ("mozjs/third_party/python/pytest/doc/en/_templates/layout.html", 600), ("mozjs/third_party/python/pytest/doc/en/_templates/links.html", 442), ("mozjs/third_party/python/pytest/doc/en/_templates/sidebarintro.html", 126), ("mozjs/third_party/python/pytest/doc/en/_themes/.gitignore", 22), ("mozjs/third_party/python/pytest/doc/en/_themes/LICENSE", 1789), ("mozjs/third_party/python/pytest/doc/en/_themes/README", 1093), ("mozjs/third_party/python/pytest/doc/en/_themes/flask/layout.html", 693), ("mozjs/third_party/python/pytest/doc/en/_themes/flask/relations.html", 590), ("mozjs/third_party/python/pytest/doc/en/_themes/flask/static/flasky.css_t", 8846), ("mozjs/third_party/python/pytest/doc/en/_themes/flask/theme.conf", 163), ("mozjs/third_party/python/pytest/doc/en/_themes/flask_theme_support.py", 3905), ("mozjs/third_party/python/pytest/doc/en/adopt.rst", 4262), ("mozjs/third_party/python/pytest/doc/en/announce/index.rst", 1286), ("mozjs/third_party/python/pytest/doc/en/announce/release-2.0.0.rst", 5151), ("mozjs/third_party/python/pytest/doc/en/announce/release-2.0.1.rst", 3089), ("mozjs/third_party/python/pytest/doc/en/announce/release-2.0.2.rst", 2798), ("mozjs/third_party/python/pytest/doc/en/announce/release-2.0.3.rst", 1307), ("mozjs/third_party/python/pytest/doc/en/announce/release-2.1.0.rst", 2153), ("mozjs/third_party/python/pytest/doc/en/announce/release-2.1.1.rst", 1579), ("mozjs/third_party/python/pytest/doc/en/announce/release-2.1.2.rst", 1284), ("mozjs/third_party/python/pytest/doc/en/announce/release-2.1.3.rst", 1203), ("mozjs/third_party/python/pytest/doc/en/announce/release-2.2.0.rst", 4327), ("mozjs/third_party/python/pytest/doc/en/announce/release-2.2.1.rst", 1555), ("mozjs/third_party/python/pytest/doc/en/announce/release-2.2.2.rst", 1619), ("mozjs/third_party/python/pytest/doc/en/announce/release-2.2.4.rst", 1454), ("mozjs/third_party/python/pytest/doc/en/announce/release-2.3.0.rst", 5531), ("mozjs/third_party/python/pytest/doc/en/announce/release-2.3.1.rst", 1115), ("mozjs/third_party/python/pytest/doc/en/announce/release-2.3.2.rst", 1761), ("mozjs/third_party/python/pytest/doc/en/announce/release-2.3.3.rst", 2089), ("mozjs/third_party/python/pytest/doc/en/announce/release-2.3.4.rst", 1686), ("mozjs/third_party/python/pytest/doc/en/announce/release-2.3.5.rst", 3269), ("mozjs/third_party/python/pytest/doc/en/announce/release-2.4.0.rst", 9216), ("mozjs/third_party/python/pytest/doc/en/announce/release-2.4.1.rst", 881), ("mozjs/third_party/python/pytest/doc/en/announce/release-2.4.2.rst", 1428), ("mozjs/third_party/python/pytest/doc/en/announce/release-2.5.0.rst", 7275), ("mozjs/third_party/python/pytest/doc/en/announce/release-2.5.1.rst", 1715), ("mozjs/third_party/python/pytest/doc/en/announce/release-2.5.2.rst", 1911), ("mozjs/third_party/python/pytest/doc/en/announce/release-2.6.0.rst", 5817), ("mozjs/third_party/python/pytest/doc/en/announce/release-2.6.1.rst", 1985), ("mozjs/third_party/python/pytest/doc/en/announce/release-2.6.2.rst", 1534), ("mozjs/third_party/python/pytest/doc/en/announce/release-2.6.3.rst", 1680), ("mozjs/third_party/python/pytest/doc/en/announce/release-2.7.0.rst", 3837), ("mozjs/third_party/python/pytest/doc/en/announce/release-2.7.1.rst", 1943), ("mozjs/third_party/python/pytest/doc/en/announce/release-2.7.2.rst", 1730), ("mozjs/third_party/python/pytest/doc/en/announce/release-2.8.2.rst", 1302), ("mozjs/third_party/python/pytest/doc/en/announce/release-2.8.3.rst", 1794), ("mozjs/third_party/python/pytest/doc/en/announce/release-2.8.4.rst", 1383), ("mozjs/third_party/python/pytest/doc/en/announce/release-2.8.5.rst", 1116), ("mozjs/third_party/python/pytest/doc/en/announce/release-2.8.6.rst", 1912), ("mozjs/third_party/python/pytest/doc/en/announce/release-2.8.7.rst", 721), ("mozjs/third_party/python/pytest/doc/en/announce/release-2.9.0.rst", 5962), ("mozjs/third_party/python/pytest/doc/en/announce/release-2.9.1.rst", 1909), ("mozjs/third_party/python/pytest/doc/en/announce/release-2.9.2.rst", 2273), ("mozjs/third_party/python/pytest/doc/en/announce/release-3.0.0.rst", 1773), ("mozjs/third_party/python/pytest/doc/en/announce/release-3.0.1.rst", 577), ("mozjs/third_party/python/pytest/doc/en/announce/release-3.0.2.rst", 514), ("mozjs/third_party/python/pytest/doc/en/announce/release-3.0.3.rst", 563), ("mozjs/third_party/python/pytest/doc/en/announce/release-3.0.4.rst", 610), ("mozjs/third_party/python/pytest/doc/en/announce/release-3.0.5.rst", 529), ("mozjs/third_party/python/pytest/doc/en/announce/release-3.0.6.rst", 618), ("mozjs/third_party/python/pytest/doc/en/announce/release-3.0.7.rst", 641), ("mozjs/third_party/python/pytest/doc/en/announce/release-3.1.0.rst", 1203), ("mozjs/third_party/python/pytest/doc/en/announce/release-3.1.1.rst", 494), ("mozjs/third_party/python/pytest/doc/en/announce/release-3.1.2.rst", 488), ("mozjs/third_party/python/pytest/doc/en/announce/release-3.1.3.rst", 497), ("mozjs/third_party/python/pytest/doc/en/announce/release-3.2.0.rst", 1034), ("mozjs/third_party/python/pytest/doc/en/announce/release-3.2.1.rst", 489), ("mozjs/third_party/python/pytest/doc/en/announce/release-3.2.2.rst", 569), ("mozjs/third_party/python/pytest/doc/en/announce/release-3.2.3.rst", 482), ("mozjs/third_party/python/pytest/doc/en/announce/release-3.2.4.rst", 716), ("mozjs/third_party/python/pytest/doc/en/announce/release-3.2.5.rst", 408), ("mozjs/third_party/python/pytest/doc/en/announce/release-3.3.0.rst", 1031), ("mozjs/third_party/python/pytest/doc/en/announce/release-3.3.1.rst", 532), ("mozjs/third_party/python/pytest/doc/en/announce/release-3.3.2.rst", 592), ("mozjs/third_party/python/pytest/doc/en/announce/release-3.4.0.rst", 1068), ("mozjs/third_party/python/pytest/doc/en/announce/release-3.4.1.rst", 551), ("mozjs/third_party/python/pytest/doc/en/announce/release-3.4.2.rst", 567), ("mozjs/third_party/python/pytest/doc/en/announce/release-3.5.0.rst", 1062), ("mozjs/third_party/python/pytest/doc/en/announce/release-3.5.1.rst", 625), ("mozjs/third_party/python/pytest/doc/en/announce/release-3.6.0.rst", 885), ("mozjs/third_party/python/pytest/doc/en/announce/release-3.6.1.rst", 521), ("mozjs/third_party/python/pytest/doc/en/announce/release-3.6.2.rst", 608), ("mozjs/third_party/python/pytest/doc/en/announce/sprint2016.rst", 2162), ("mozjs/third_party/python/pytest/doc/en/assert.rst", 9933), ("mozjs/third_party/python/pytest/doc/en/backwards-compatibility.rst", 1771), ("mozjs/third_party/python/pytest/doc/en/bash-completion.rst", 699), ("mozjs/third_party/python/pytest/doc/en/builtin.rst", 5124), ("mozjs/third_party/python/pytest/doc/en/cache.rst", 8247), ("mozjs/third_party/python/pytest/doc/en/capture.rst", 5415), ("mozjs/third_party/python/pytest/doc/en/changelog.rst", 103), ("mozjs/third_party/python/pytest/doc/en/conf.py", 10295), ("mozjs/third_party/python/pytest/doc/en/conftest.py", 29), ("mozjs/third_party/python/pytest/doc/en/contact.rst", 1659), ("mozjs/third_party/python/pytest/doc/en/contents.rst", 928), ("mozjs/third_party/python/pytest/doc/en/contributing.rst", 55), ("mozjs/third_party/python/pytest/doc/en/customize.rst", 5997), ("mozjs/third_party/python/pytest/doc/en/development_guide.rst", 2034), ("mozjs/third_party/python/pytest/doc/en/doctest.rst", 4821), ("mozjs/third_party/python/pytest/doc/en/example/assertion/failure_demo.py", 5389), ("mozjs/third_party/python/pytest/doc/en/example/assertion/global_testmodule_config/conftest.py", 334), ("mozjs/third_party/python/pytest/doc/en/example/assertion/global_testmodule_config/test_hello_world.py", 45), ("mozjs/third_party/python/pytest/doc/en/example/assertion/test_failures.py", 443), ("mozjs/third_party/python/pytest/doc/en/example/assertion/test_setup_flow_example.py", 1261), ("mozjs/third_party/python/pytest/doc/en/example/attic.rst", 2707), ("mozjs/third_party/python/pytest/doc/en/example/conftest.py", 31), ("mozjs/third_party/python/pytest/doc/en/example/costlysetup/conftest.py", 346), ("mozjs/third_party/python/pytest/doc/en/example/costlysetup/sub_a/__init__.py", 2), ("mozjs/third_party/python/pytest/doc/en/example/costlysetup/sub_a/test_quick.py", 32), ("mozjs/third_party/python/pytest/doc/en/example/costlysetup/sub_b/__init__.py", 2), ("mozjs/third_party/python/pytest/doc/en/example/costlysetup/sub_b/test_two.py", 127), ("mozjs/third_party/python/pytest/doc/en/example/index.rst", 964), ("mozjs/third_party/python/pytest/doc/en/example/markers.rst", 24942), ("mozjs/third_party/python/pytest/doc/en/example/multipython.py", 1888), ("mozjs/third_party/python/pytest/doc/en/example/nonpython.rst", 3612), ("mozjs/third_party/python/pytest/doc/en/example/nonpython/__init__.py", 0), ("mozjs/third_party/python/pytest/doc/en/example/nonpython/conftest.py", 1408), ("mozjs/third_party/python/pytest/doc/en/example/nonpython/test_simple.yml", 78), ("mozjs/third_party/python/pytest/doc/en/example/parametrize.rst", 19749), ("mozjs/third_party/python/pytest/doc/en/example/py2py3/conftest.py", 324), ("mozjs/third_party/python/pytest/doc/en/example/py2py3/test_py2.py", 97), ("mozjs/third_party/python/pytest/doc/en/example/py2py3/test_py3.py", 100), ("mozjs/third_party/python/pytest/doc/en/example/pythoncollection.py", 210), ("mozjs/third_party/python/pytest/doc/en/example/pythoncollection.rst", 8026), ("mozjs/third_party/python/pytest/doc/en/example/reportingdemo.rst", 22668), ("mozjs/third_party/python/pytest/doc/en/example/simple.rst", 28090), ("mozjs/third_party/python/pytest/doc/en/example/special.rst", 1991), ("mozjs/third_party/python/pytest/doc/en/example/xfail_demo.py", 448), ("mozjs/third_party/python/pytest/doc/en/existingtestsuite.rst", 1277), ("mozjs/third_party/python/pytest/doc/en/faq.rst", 6872), ("mozjs/third_party/python/pytest/doc/en/fixture.rst", 42328), ("mozjs/third_party/python/pytest/doc/en/funcarg_compare.rst", 8734), ("mozjs/third_party/python/pytest/doc/en/funcargs.rst", 441), ("mozjs/third_party/python/pytest/doc/en/getting-started.rst", 7093), ("mozjs/third_party/python/pytest/doc/en/goodpractices.rst", 9739), ("mozjs/third_party/python/pytest/doc/en/historical-notes.rst", 5914), ("mozjs/third_party/python/pytest/doc/en/img/cramer2.png", 25291), ("mozjs/third_party/python/pytest/doc/en/img/freiburg2.jpg", 104057), ("mozjs/third_party/python/pytest/doc/en/img/gaynor3.png", 23032), ("mozjs/third_party/python/pytest/doc/en/img/keleshev.png", 23246), ("mozjs/third_party/python/pytest/doc/en/img/pullrequest.png", 17035), ("mozjs/third_party/python/pytest/doc/en/img/pylib.png", 8276), ("mozjs/third_party/python/pytest/doc/en/img/pytest1.png", 6010), ("mozjs/third_party/python/pytest/doc/en/img/pytest1favi.ico", 3742), ("mozjs/third_party/python/pytest/doc/en/img/theuni.png", 31476), ("mozjs/third_party/python/pytest/doc/en/index.rst", 2547), ("mozjs/third_party/python/pytest/doc/en/license.rst", 1361), ("mozjs/third_party/python/pytest/doc/en/links.inc", 891), ("mozjs/third_party/python/pytest/doc/en/logging.rst", 8303), ("mozjs/third_party/python/pytest/doc/en/mark.rst", 6581), ("mozjs/third_party/python/pytest/doc/en/monkeypatch.rst", 3153), ("mozjs/third_party/python/pytest/doc/en/naming20.rst", 718), ("mozjs/third_party/python/pytest/doc/en/nose.rst", 2957), ("mozjs/third_party/python/pytest/doc/en/parametrize.rst", 7572), ("mozjs/third_party/python/pytest/doc/en/plugins.rst", 4230), ("mozjs/third_party/python/pytest/doc/en/projects.rst", 4881), ("mozjs/third_party/python/pytest/doc/en/proposals/parametrize_with_fixtures.rst", 4280), ("mozjs/third_party/python/pytest/doc/en/pytest.ini", 74), ("mozjs/third_party/python/pytest/doc/en/pythonpath.rst", 2607), ("mozjs/third_party/python/pytest/doc/en/recwarn.rst", 70), ("mozjs/third_party/python/pytest/doc/en/reference.rst", 30784), ("mozjs/third_party/python/pytest/doc/en/requirements.txt", 150), ("mozjs/third_party/python/pytest/doc/en/skipping.rst", 11363), ("mozjs/third_party/python/pytest/doc/en/talks.rst", 4350), ("mozjs/third_party/python/pytest/doc/en/tmpdir.rst", 3468), ("mozjs/third_party/python/pytest/doc/en/unittest.rst", 9885), ("mozjs/third_party/python/pytest/doc/en/usage.rst", 16476), ("mozjs/third_party/python/pytest/doc/en/warnings.rst", 9579), ("mozjs/third_party/python/pytest/doc/en/writing_plugins.rst", 20781), ("mozjs/third_party/python/pytest/doc/en/xunit_setup.rst", 3270), ("mozjs/third_party/python/pytest/doc/en/yieldfixture.rst", 512), ("mozjs/third_party/python/pytest/testing/acceptance_test.py", 34854), ("mozjs/third_party/python/pytest/testing/code/test_code.py", 4926), ("mozjs/third_party/python/pytest/testing/code/test_excinfo.py", 42380), ("mozjs/third_party/python/pytest/testing/code/test_source.py", 17810), ("mozjs/third_party/python/pytest/testing/code/test_source_multiline_block.py", 871), ("mozjs/third_party/python/pytest/testing/deprecated_test.py", 7384), ("mozjs/third_party/python/pytest/testing/freeze/.gitignore", 20), ("mozjs/third_party/python/pytest/testing/freeze/create_executable.py", 378), ("mozjs/third_party/python/pytest/testing/freeze/runtests_script.py", 193), ("mozjs/third_party/python/pytest/testing/freeze/tests/test_doctest.txt", 41), ("mozjs/third_party/python/pytest/testing/freeze/tests/test_trivial.py", 106), ("mozjs/third_party/python/pytest/testing/freeze/tox_run.py", 360), ("mozjs/third_party/python/pytest/testing/logging/test_fixture.py", 3581), ("mozjs/third_party/python/pytest/testing/logging/test_formatter.py", 931), ("mozjs/third_party/python/pytest/testing/logging/test_reporting.py", 26653), ("mozjs/third_party/python/pytest/testing/python/approx.py", 14801), ("mozjs/third_party/python/pytest/testing/python/collect.py", 48719), ("mozjs/third_party/python/pytest/testing/python/fixture.py", 119636), ("mozjs/third_party/python/pytest/testing/python/integration.py", 13137), ("mozjs/third_party/python/pytest/testing/python/metafunc.py", 56377), ("mozjs/third_party/python/pytest/testing/python/raises.py", 5455), ("mozjs/third_party/python/pytest/testing/python/setup_only.py", 6685), ("mozjs/third_party/python/pytest/testing/python/setup_plan.py", 492), ("mozjs/third_party/python/pytest/testing/python/show_fixtures_per_test.py", 4341), ("mozjs/third_party/python/pytest/testing/python/test_deprecations.py", 461), ("mozjs/third_party/python/pytest/testing/test_argcomplete.py", 3753), ("mozjs/third_party/python/pytest/testing/test_assertion.py", 35826), ("mozjs/third_party/python/pytest/testing/test_assertrewrite.py", 32289), ("mozjs/third_party/python/pytest/testing/test_cacheprovider.py", 26146), ("mozjs/third_party/python/pytest/testing/test_capture.py", 41314), ("mozjs/third_party/python/pytest/testing/test_collection.py", 31894), ("mozjs/third_party/python/pytest/testing/test_compat.py", 2615), ("mozjs/third_party/python/pytest/testing/test_config.py", 33360), ("mozjs/third_party/python/pytest/testing/test_conftest.py", 16392), ("mozjs/third_party/python/pytest/testing/test_doctest.py", 35743), ("mozjs/third_party/python/pytest/testing/test_entry_points.py", 484), ("mozjs/third_party/python/pytest/testing/test_helpconfig.py", 2049), ("mozjs/third_party/python/pytest/testing/test_junitxml.py", 37985), ("mozjs/third_party/python/pytest/testing/test_mark.py", 32326), ("mozjs/third_party/python/pytest/testing/test_modimport.py", 721), ("mozjs/third_party/python/pytest/testing/test_monkeypatch.py", 8832), ("mozjs/third_party/python/pytest/testing/test_nodes.py", 567), ("mozjs/third_party/python/pytest/testing/test_nose.py", 10599), ("mozjs/third_party/python/pytest/testing/test_parseopt.py", 13227), ("mozjs/third_party/python/pytest/testing/test_pastebin.py", 4091), ("mozjs/third_party/python/pytest/testing/test_pdb.py", 21116), ("mozjs/third_party/python/pytest/testing/test_pluginmanager.py", 13081), ("mozjs/third_party/python/pytest/testing/test_pytester.py", 13295), ("mozjs/third_party/python/pytest/testing/test_recwarn.py", 12085), ("mozjs/third_party/python/pytest/testing/test_resultlog.py", 7558), ("mozjs/third_party/python/pytest/testing/test_runner.py", 28022), ("mozjs/third_party/python/pytest/testing/test_runner_xunit.py", 9084), ("mozjs/third_party/python/pytest/testing/test_session.py", 10684), ("mozjs/third_party/python/pytest/testing/test_skipping.py", 33118), ("mozjs/third_party/python/pytest/testing/test_terminal.py", 40391), ("mozjs/third_party/python/pytest/testing/test_tmpdir.py", 6164), ("mozjs/third_party/python/pytest/testing/test_unittest.py", 27912), ("mozjs/third_party/python/pytest/testing/test_warnings.py", 8162), ("mozjs/third_party/python/python-hglib/examples/stats.py", 721), ("mozjs/third_party/python/python-hglib/tests/__init__.py", 779), ("mozjs/third_party/python/python-hglib/tests/common.py", 1497), ("mozjs/third_party/python/python-hglib/tests/test-annotate.py", 1361), ("mozjs/third_party/python/python-hglib/tests/test-bookmarks.py", 969), ("mozjs/third_party/python/python-hglib/tests/test-branch.py", 1617), ("mozjs/third_party/python/python-hglib/tests/test-branches.py", 712), ("mozjs/third_party/python/python-hglib/tests/test-bundle.py", 622), ("mozjs/third_party/python/python-hglib/tests/test-commit.py", 2394), ("mozjs/third_party/python/python-hglib/tests/test-config.py", 1399), ("mozjs/third_party/python/python-hglib/tests/test-copy.py", 818), ("mozjs/third_party/python/python-hglib/tests/test-diff.py", 1314), ("mozjs/third_party/python/python-hglib/tests/test-encoding.py", 239), ("mozjs/third_party/python/python-hglib/tests/test-forget.py", 439), ("mozjs/third_party/python/python-hglib/tests/test-grep.py", 1936), ("mozjs/third_party/python/python-hglib/tests/test-heads.py", 552), ("mozjs/third_party/python/python-hglib/tests/test-hglib.py", 836), ("mozjs/third_party/python/python-hglib/tests/test-import.py", 965), ("mozjs/third_party/python/python-hglib/tests/test-init.py", 420), ("mozjs/third_party/python/python-hglib/tests/test-log.py", 1085), ("mozjs/third_party/python/python-hglib/tests/test-manifest.py", 990), ("mozjs/third_party/python/python-hglib/tests/test-merge.py", 2164), ("mozjs/third_party/python/python-hglib/tests/test-move.py", 464), ("mozjs/third_party/python/python-hglib/tests/test-outgoing-incoming.py", 1727), ("mozjs/third_party/python/python-hglib/tests/test-parents.py", 480), ("mozjs/third_party/python/python-hglib/tests/test-paths.py", 635), ("mozjs/third_party/python/python-hglib/tests/test-pull.py", 878), ("mozjs/third_party/python/python-hglib/tests/test-push.py", 590), ("mozjs/third_party/python/python-hglib/tests/test-remove.py", 430), ("mozjs/third_party/python/python-hglib/tests/test-resolve.py", 1116), ("mozjs/third_party/python/python-hglib/tests/test-status.py", 1683), ("mozjs/third_party/python/python-hglib/tests/test-summary.py", 4097), ("mozjs/third_party/python/python-hglib/tests/test-tags.py", 791), ("mozjs/third_party/python/python-hglib/tests/test-update.py", 3442), ("mozjs/third_party/python/python-hglib/tests/with_hg.py", 969), ("mozjs/third_party/python/pytoml/test/test.py", 3857), ("mozjs/third_party/python/pyyaml/examples/pygments-lexer/example.yaml", 5052), ("mozjs/third_party/python/pyyaml/examples/pygments-lexer/yaml.py", 15617), ("mozjs/third_party/python/pyyaml/examples/yaml-highlight/yaml_hl.cfg", 4485), ("mozjs/third_party/python/pyyaml/examples/yaml-highlight/yaml_hl.py", 4429), ("mozjs/third_party/python/requests-unixsocket/requests_unixsocket/tests/test_requests_unixsocket.py", 5148), ("mozjs/third_party/python/rsa/run_tests.py", 1099), ("mozjs/third_party/python/rsa/tests/__init__.py", 0), ("mozjs/third_party/python/rsa/tests/constants.py", 151), ("mozjs/third_party/python/rsa/tests/py2kconstants.py", 84), ("mozjs/third_party/python/rsa/tests/py3kconstants.py", 83), ("mozjs/third_party/python/rsa/tests/test_bigfile.py", 1673), ("mozjs/third_party/python/rsa/tests/test_common.py", 2280), ("mozjs/third_party/python/rsa/tests/test_compat.py", 452), ("mozjs/third_party/python/rsa/tests/test_integers.py", 891), ("mozjs/third_party/python/rsa/tests/test_load_save_keys.py", 3195), ("mozjs/third_party/python/rsa/tests/test_pem.py", 345), ("mozjs/third_party/python/rsa/tests/test_pkcs1.py", 2949), ("mozjs/third_party/python/rsa/tests/test_strings.py", 615), ("mozjs/third_party/python/rsa/tests/test_transform.py", 2593), ("mozjs/third_party/python/rsa/tests/test_varblock.py", 2061), ("mozjs/third_party/python/scandir/test/run_tests.py", 640), ("mozjs/third_party/python/scandir/test/test_scandir.py", 12253), ("mozjs/third_party/python/scandir/test/test_walk.py", 8319), ("mozjs/third_party/python/taskcluster/test/test_async.py", 2301), ("mozjs/third_party/python/taskcluster/test/test_client.py", 37409), ("mozjs/third_party/python/taskcluster/test/test_utils.py", 15842), ("mozjs/third_party/python/virtualenv/docs/Makefile", 4634), ("mozjs/third_party/python/virtualenv/docs/changes.rst", 26444), ("mozjs/third_party/python/virtualenv/docs/conf.py", 4691), ("mozjs/third_party/python/virtualenv/docs/development.rst", 2017), ("mozjs/third_party/python/virtualenv/docs/index.rst", 5830), ("mozjs/third_party/python/virtualenv/docs/installation.rst", 1608), ("mozjs/third_party/python/virtualenv/docs/make.bat", 4533), ("mozjs/third_party/python/virtualenv/docs/reference.rst", 7310), ("mozjs/third_party/python/virtualenv/docs/userguide.rst", 10617), ("mozjs/third_party/python/virtualenv/tests/__init__.py", 0), ("mozjs/third_party/python/virtualenv/tests/test_activate.sh", 2834), ("mozjs/third_party/python/virtualenv/tests/test_activate_output.expected", 113), ("mozjs/third_party/python/virtualenv/tests/test_cmdline.py", 1389), ("mozjs/third_party/python/virtualenv/tests/test_virtualenv.py", 5025), ("mozjs/third_party/python/voluptuous/voluptuous/tests/__init__.py", 30), ("mozjs/third_party/python/voluptuous/voluptuous/tests/tests.md", 8402), ("mozjs/third_party/python/voluptuous/voluptuous/tests/tests.py", 37508), ("mozjs/third_party/python/which/logo.jpg", 3635), ("mozjs/third_party/python/which/test/test_which.py", 6848), ("mozjs/third_party/python/which/test/testsupport.py", 2206), ("tests/evaluate.rs", 3345)].iter().map(|(p, s)| (p.to_string(), *s)).collect(),
+            cargo_toml_diff: None,
+            fix_verification: None,
+            compressed_size_in_bytes: None,
             suggested_fix: Some(Fix::EnrichedExclude {
                 exclude: ["mozjs/js/src/tests/**", "mozjs/js/src/octane/**", "mozjs/js/src/jit-test/**", "mozjs/js/src/jsapi-tests/**", "mozjs/js/src/doc/**", "**/*.jpg", "**/*.png", "**/*.gif", "**/doc/**/*", "**/docs/**/*", "**/benchmarks/**/*", "**/test/**/*", "**/*_test.*", "**/tests/**/*", "**/*_tests.*", "**/*_tests/**/*", "**/testing/**/*", "**/*_spec.*", "**/examples/**/*", "**/build/**/*", "**/etc/**/*", "**/testdata/**/*"].iter().map(|s| s.to_string()).collect(),
                 exclude_added: ["**/*.jpg", "**/*.png", "**/*.gif", "**/doc/**/*", "**/docs/**/*", "**/benchmarks/**/*", "**/test/**/*", "**/*_test.*", "**/tests/**/*", "**/*_tests.*", "**/*_tests/**/*", "**/testing/**/*", "**/*_spec.*", "**/examples/**/*", "**/build/**/*", "**/etc/**/*", "**/testdata/**/*"].iter().map(|s| s.to_string()).collect(),
@@ -4870,3 +4953,41 @@ fn mozjs() {
         "build.rs + excludes in Cargo.toml - this leaves a chance for accidental includes for which we provide an updated include list"
     );
 }
+
+#[test]
+fn sovrin_client_fix_keeps_nested_readme_with_cargo_rules() {
+    match Report::from_package(
+        "a",
+        "1",
+        tar_package("sovrin-client.0.1.0-179-extract_crate-1.0.0"),
+        &WastePolicy::default(),
+    ) {
+        Report::Version { fix_verification, .. } => assert_eq!(
+            fix_verification,
+            Some(Verification {
+                removed_needed_files: vec![],
+                retained_waste: vec!["wrappers/java/README.md".into()],
+                invalid_patterns: vec![],
+            }),
+            "'README.md' matches at any depth with gitignore semantics"
+        ),
+        _ => unreachable!("always a version report"),
+    }
+}
+
+#[test]
+fn fixes_of_all_other_packages_verify() {
+    for name in &[
+        "avr_libc-0.1.3extract_crate-1.0.0",
+        "curl_sys-0.4.27-extract_crate-1.0.0",
+        "mozjs_sys-0.67.1-extract_crate-1.0.0",
+        "threed-ice-sys-0.3.0-extract_crate-1.0.0",
+    ] {
+        match Report::from_package("a", "1", tar_package(name), &WastePolicy::default()) {
+            Report::Version { fix_verification, .. } => {
+                assert!(fix_verification.is_some_and(|v| v.is_ok()), "{}", name)
+            }
+            _ => unreachable!("always a version report"),
+        }
+    }
+}
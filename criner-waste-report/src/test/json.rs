@@ -0,0 +1,109 @@
+use super::super::{json, AggregateFileInfo, CompressedSize, Fix, Report, VersionInfo};
+use serde_json::json;
+
+fn to_json(report: &Report) -> serde_json::Value {
+    serde_json::to_value(json::Document::from(report)).unwrap()
+}
+
+#[test]
+fn version_lists_wasted_files_largest_first_along_with_the_fix() {
+    let report = Report::Version {
+        crate_name: "a".into(),
+        crate_version: "1.0.0".into(),
+        total_size_in_bytes: 100,
+        total_files: 4,
+        wasted_files: vec![("tests/small".into(), 1), ("benches/large".into(), 20)],
+        cargo_toml_diff: None,
+        fix_verification: None,
+        compressed_size_in_bytes: Some(50),
+        suggested_fix: Some(Fix::NewInclude {
+            include: vec!["src/**/*".into()],
+            has_build_script: false,
+        }),
+    };
+    assert_eq!(
+        to_json(&report),
+        json!({
+            "schema_version": 1,
+            "kind": "version",
+            "crate_name": "a",
+            "crate_version": "1.0.0",
+            "total_size_in_bytes": 100,
+            "total_files": 4,
+            "waste": { "total_bytes": 21, "total_files": 2 },
+            "wasted_files": [
+                { "path": "benches/large", "size_in_bytes": 20, "category": "benchmarks" },
+                { "path": "tests/small", "size_in_bytes": 1, "category": "tests" },
+            ],
+            "wasted_by_category": {
+                "benchmarks": { "total_bytes": 20, "total_files": 1 },
+                "tests": { "total_bytes": 1, "total_files": 1 },
+            },
+            "compressed": { "total_bytes": 50, "wasted_bytes": 10 },
+            "suggested_fix": {
+                "kind": "new_include",
+                "include": ["src/**/*"],
+                "has_build_script": false,
+            },
+            "suggested_fix_toml": "[package]\ninclude = [\n    \"src/**/*\",\n]\n# remove the 'exclude' directive, if present\n",
+            "cargo_toml_diff": null,
+            "fix_verification": null,
+        })
+    );
+}
+
+#[test]
+fn collection_summarizes_crates_and_round_trips() {
+    let info = |bytes| AggregateFileInfo {
+        total_bytes: bytes,
+        total_files: 1,
+    };
+    let report = Report::CrateCollection {
+        total_size_in_bytes: 10,
+        total_files: 2,
+        info_by_crate: std::iter::once((
+            "a".to_string(),
+            VersionInfo {
+                all: info(10),
+                waste: info(5),
+                waste_latest_version: Some(("1.0.0".into(), info(5))),
+                potential_gains: None,
+                waste_by_category: std::iter::once(("documentation".to_string(), info(5))).collect(),
+                compressed: Some(CompressedSize {
+                    total_bytes: 4,
+                    wasted_bytes: 2,
+                }),
+            },
+        ))
+        .collect(),
+        wasted_by_extension: std::iter::once(("md".to_string(), info(5))).collect(),
+        wasted_by_category: std::iter::once(("documentation".to_string(), info(5))).collect(),
+    };
+    let value = to_json(&report);
+    assert_eq!(
+        value,
+        json!({
+            "schema_version": 1,
+            "kind": "crate_collection",
+            "total_size_in_bytes": 10,
+            "total_files": 2,
+            "crates": {
+                "a": {
+                    "all": { "total_bytes": 10, "total_files": 1 },
+                    "waste": { "total_bytes": 5, "total_files": 1 },
+                    "waste_latest_version": { "version": "1.0.0", "waste": { "total_bytes": 5, "total_files": 1 } },
+                    "potential_gains": null,
+                    "waste_by_category": { "documentation": { "total_bytes": 5, "total_files": 1 } },
+                    "compressed": { "total_bytes": 4, "wasted_bytes": 2 },
+                }
+            },
+            "wasted_by_extension": { "md": { "total_bytes": 5, "total_files": 1 } },
+            "wasted_by_category": { "documentation": { "total_bytes": 5, "total_files": 1 } },
+            "compressed": { "total_bytes": 4, "wasted_bytes": 2 },
+        })
+    );
+    assert_eq!(
+        serde_json::from_value::<json::Document>(value).unwrap(),
+        json::Document::from(&report)
+    );
+}
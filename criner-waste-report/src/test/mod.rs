@@ -1 +1,11 @@
+mod category;
+mod compressed;
+mod fix;
 mod from_package;
+mod json;
+#[cfg(feature = "cli")]
+mod package;
+mod paths;
+mod policy;
+mod references;
+mod verify;
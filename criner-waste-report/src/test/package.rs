@@ -0,0 +1,149 @@
+use super::super::{
+    package::{crate_file_in_db, LocalPackage},
+    tar_path_to_utf8_str, TarPackage,
+};
+use std::path::{Path, PathBuf};
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("criner-waste-report-{}-{}", name, std::process::id()));
+    std::fs::remove_dir_all(&dir).ok();
+    dir
+}
+
+fn write_files(root: &Path, files: &[(&str, &str)]) {
+    for (path, content) in files {
+        let path = root.join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+}
+
+fn paths(package: &TarPackage) -> (Vec<String>, Vec<String>) {
+    (
+        package
+            .entries_meta_data
+            .iter()
+            .map(|e| String::from_utf8(e.path.clone()).unwrap())
+            .collect(),
+        package
+            .entries
+            .iter()
+            .map(|(e, _)| tar_path_to_utf8_str(&e.path).into_owned())
+            .collect(),
+    )
+}
+
+#[test]
+fn directories_are_packaged_like_cargo_does() {
+    let dir = temp_dir("directory");
+    write_files(
+        &dir,
+        &[
+            ("Cargo.toml", "[package]\nname = \"a\"\nversion = \"1.0.0\"\nexclude = [\"*.bak\"]"),
+            ("src/lib.rs", "mod b;"),
+            ("src/b.rs", ""),
+            ("src/b.rs.bak", ""),
+            (".travis.yml", ""),
+            ("target/debug/a", ""),
+            ("nested/Cargo.toml", "[package]\nname = \"nested\""),
+            ("nested/src/lib.rs", ""),
+        ],
+    );
+    let package = LocalPackage::from_directory(&dir).unwrap();
+    assert_eq!((package.crate_name.as_str(), package.crate_version.as_str()), ("a", "1.0.0"));
+    assert_eq!(
+        paths(&package.package),
+        (
+            vec![
+                "a-1.0.0/.travis.yml".into(),
+                "a-1.0.0/Cargo.toml".into(),
+                "a-1.0.0/src/b.rs".into(),
+                "a-1.0.0/src/lib.rs".into()
+            ],
+            vec!["Cargo.toml".into(), "src/b.rs".into(), "src/lib.rs".into()]
+        ),
+        "the build directory, nested packages and excluded files are skipped, and sources are read"
+    );
+
+    write_files(
+        &dir,
+        &[(
+            "Cargo.toml",
+            "[package]\nname = \"a\"\nversion.workspace = true\ninclude = [\"src/lib.rs\"]",
+        )],
+    );
+    let package = LocalPackage::from_directory(&dir).unwrap();
+    assert_eq!(package.crate_version, "0.0.0", "inherited versions are unknown");
+    assert_eq!(
+        paths(&package.package).0,
+        vec!["a-0.0.0/Cargo.toml", "a-0.0.0/src/lib.rs"],
+        "the manifest is always included"
+    );
+    std::fs::remove_dir_all(dir).ok();
+}
+
+fn crate_file(files: &[(&str, &str)]) -> Vec<u8> {
+    let mut builder = tar::Builder::new(libflate::gzip::Encoder::new(Vec::new()).unwrap());
+    for (path, content) in files {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(content.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        builder.append_data(&mut header, path, content.as_bytes()).unwrap();
+    }
+    builder.into_inner().unwrap().finish().into_result().unwrap()
+}
+
+#[test]
+fn crate_files_are_read_completely() {
+    let data = crate_file(&[
+        ("b-0.1.0/Cargo.toml", "[package]\nname = \"b\"\nversion = \"0.1.0\""),
+        ("b-0.1.0/Cargo.toml.orig", "[package]\nname = \"b\""),
+        ("b-0.1.0/src/main.rs", "fn main() {}"),
+        ("b-0.1.0/README.md", "readme"),
+    ]);
+    let package = LocalPackage::from_crate_file(data.as_slice()).unwrap();
+    assert_eq!((package.crate_name.as_str(), package.crate_version.as_str()), ("b", "0.1.0"));
+    assert_eq!(
+        paths(&package.package),
+        (
+            vec![
+                "b-0.1.0/Cargo.toml".into(),
+                "b-0.1.0/Cargo.toml.orig".into(),
+                "b-0.1.0/src/main.rs".into(),
+                "b-0.1.0/README.md".into()
+            ],
+            vec!["Cargo.toml".into(), "Cargo.toml.orig".into(), "src/main.rs".into()]
+        )
+    );
+    assert!(
+        LocalPackage::from_crate_file(crate_file(&[("b-0.1.0/README.md", "")]).as_slice()).is_err(),
+        "a manifest is required"
+    );
+}
+
+#[test]
+fn crate_files_are_found_among_the_downloads_of_a_criner_database() {
+    let db = temp_dir("db");
+    write_files(
+        &db,
+        &[
+            ("assets/cr/at/crate/1.0.0-download:1.0.0.crate", ""),
+            ("assets/cr/at/crate/1.0.0-beta-download:1.0.0.crate", ""),
+            ("assets/3/a/bc/0.1.0-download:1.0.0.crate", ""),
+        ],
+    );
+    assert_eq!(
+        crate_file_in_db(&db, "crate", "1.0.0").unwrap(),
+        db.join("assets/cr/at/crate/1.0.0-download:1.0.0.crate")
+    );
+    assert_eq!(
+        crate_file_in_db(&db, "abc", "0.1.0").unwrap(),
+        db.join("assets/3/a/bc/0.1.0-download:1.0.0.crate")
+    );
+    assert_eq!(
+        crate_file_in_db(&db, "crate", "2.0.0").unwrap_err().kind(),
+        std::io::ErrorKind::NotFound
+    );
+    std::fs::remove_dir_all(db).ok();
+}
@@ -0,0 +1,40 @@
+use super::super::{path_bytes_to_str, tar_path_to_utf8_str, Report, TarHeader, TarPackage, WastePolicy};
+use std::borrow::Cow;
+
+fn file(path: &[u8], size: u64) -> TarHeader {
+    TarHeader {
+        path: path.to_vec(),
+        size,
+        entry_type: b'0',
+        hash: None,
+    }
+}
+
+#[test]
+fn valid_utf8_is_borrowed_and_everything_else_is_escaped() {
+    assert!(matches!(path_bytes_to_str(b"a/b.rs"), Cow::Borrowed("a/b.rs")));
+    assert_eq!(path_bytes_to_str(b"a/\xff\xfeb\xc3.rs"), "a/\\xff\\xfeb\\xc3.rs");
+    assert_eq!(tar_path_to_utf8_str(b"krate-1.0.0/\x80/lib.rs"), "\\x80/lib.rs");
+}
+
+#[test]
+fn packages_with_paths_which_are_not_utf8_can_be_analyzed() {
+    let manifest = b"[package]\nname = \"krate\"\n".to_vec();
+    let report = Report::from_package(
+        "krate",
+        "1.0.0",
+        TarPackage {
+            entries_meta_data: vec![
+                file(b"krate-1.0.0/Cargo.toml", manifest.len() as u64),
+                file(b"krate-1.0.0/src/lib.rs", 10),
+                file(b"krate-1.0.0/\xff\xfe/data.bin", 100),
+            ],
+            entries: vec![(file(b"krate-1.0.0/Cargo.toml", manifest.len() as u64), manifest)],
+        },
+        &WastePolicy::default(),
+    );
+    match report {
+        Report::Version { total_files, .. } => assert_eq!(total_files, 3),
+        _ => unreachable!("a version report"),
+    }
+}
@@ -0,0 +1,136 @@
+use super::super::{
+    policy::{CratePolicy, PolicyError},
+    Report, TarHeader, TarPackage, WastePolicy,
+};
+
+fn file(path: &str, size: u64) -> TarHeader {
+    TarHeader {
+        path: format!("krate-1.0.0/{}", path).into_bytes(),
+        size,
+        entry_type: b'0',
+        hash: None,
+    }
+}
+
+/// The sorted paths of all files wasted by a crate with `FILES`, according to `policy`
+fn wasted_files(crate_name: &str, policy: &WastePolicy) -> Vec<String> {
+    let manifest = format!("[package]\nname = \"{}\"\nversion = \"1.0.0\"\n", crate_name);
+    let mut entries_meta_data: Vec<_> = FILES.iter().map(|p| file(p, 10)).collect();
+    entries_meta_data.push(file("Cargo.toml", manifest.len() as u64));
+    let package = TarPackage {
+        entries: vec![(file("Cargo.toml", manifest.len() as u64), manifest.into_bytes())],
+        entries_meta_data,
+    };
+    match Report::from_package(crate_name, "1.0.0", package, policy) {
+        Report::Version { wasted_files, .. } => {
+            let mut paths: Vec<_> = wasted_files.into_iter().map(|(path, _)| path).collect();
+            paths.sort();
+            paths
+        }
+        _ => unreachable!("always a version report"),
+    }
+}
+
+const FILES: &[&str] = &[
+    "src/lib.rs",
+    "src/fixtures/data.bin",
+    "tests/it.rs",
+    "assets/logo.png",
+    "data/words.txt",
+];
+
+const DEFAULT_WASTE: &[&str] = &[
+    "assets/logo.png",
+    "data/words.txt",
+    "src/fixtures/data.bin",
+    "tests/it.rs",
+];
+
+#[test]
+fn policies_parse_from_toml_and_serialize_back() {
+    let policy = WastePolicy::from_toml(
+        r#"
+exclude = ["**/tests/**/*"]
+allow = ["**/testdata/**/*.json"]
+
+[crate.my-game]
+allow = ["assets/**/*"]
+"#,
+    )
+    .unwrap();
+    assert_eq!(
+        policy,
+        WastePolicy {
+            include: None,
+            exclude: Some(vec!["**/tests/**/*".into()]),
+            allow: vec!["**/testdata/**/*.json".into()],
+            crates: vec![(
+                "my-game".to_owned(),
+                CratePolicy {
+                    allow: vec!["assets/**/*".into()],
+                    ..Default::default()
+                }
+            )]
+            .into_iter()
+            .collect(),
+            ..Default::default()
+        }
+    );
+    assert_eq!(WastePolicy::from_toml(&policy.to_toml()).unwrap(), policy);
+}
+
+#[test]
+fn invalid_policies_are_rejected() {
+    assert!(matches!(
+        WastePolicy::from_toml("[crate.a]\nallow = [\"a/**{\"]"),
+        Err(PolicyError::Pattern(_))
+    ));
+    assert!(matches!(
+        WastePolicy::from_toml("excludes = []"),
+        Err(PolicyError::Toml(_))
+    ));
+}
+
+#[test]
+fn only_non_default_policies_have_a_hash_which_changes_with_them() {
+    assert_eq!(WastePolicy::default().hash(), None);
+    assert_eq!(WastePolicy::from_toml("").unwrap().hash(), None);
+
+    let policy = WastePolicy::from_toml("allow = [\"a\"]").unwrap();
+    assert!(policy.hash().is_some());
+    assert_eq!(policy.hash(), WastePolicy::from_toml("allow = [\"a\"]").unwrap().hash());
+    assert_ne!(policy.hash(), WastePolicy::from_toml("allow = [\"b\"]").unwrap().hash());
+}
+
+#[test]
+fn allowed_files_are_never_waste() {
+    assert_eq!(wasted_files("krate", &WastePolicy::default()), DEFAULT_WASTE);
+    let policy = WastePolicy::from_toml("allow = [\"**/fixtures/**/*\", \"data/*\"]").unwrap();
+    assert_eq!(wasted_files("krate", &policy), vec!["assets/logo.png", "tests/it.rs"]);
+}
+
+#[test]
+fn policy_patterns_replace_the_standard_ones() {
+    let policy = WastePolicy::from_toml("include = [\"src/**/*\", \"Cargo.*\", \"data/**/*\"]").unwrap();
+    assert_eq!(
+        wasted_files("krate", &policy),
+        vec!["assets/logo.png", "src/fixtures/data.bin", "tests/it.rs"]
+    );
+
+    let policy = WastePolicy::from_toml("exclude = [\"**/tests/**/*\"]").unwrap();
+    assert_eq!(
+        wasted_files("krate", &policy),
+        vec!["assets/logo.png", "data/words.txt", "tests/it.rs"],
+        "files in 'src/' are only waste if they match an exclude pattern"
+    );
+}
+
+#[test]
+fn crate_policies_only_apply_to_their_crate() {
+    let policy = WastePolicy::from_toml("[crate.my-game]\nallow = [\"assets/**/*\"]").unwrap();
+    assert_eq!(
+        wasted_files("my-game", &policy),
+        vec!["data/words.txt", "src/fixtures/data.bin", "tests/it.rs"]
+    );
+    assert_eq!(wasted_files("other", &policy), DEFAULT_WASTE);
+}
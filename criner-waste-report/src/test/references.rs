@@ -0,0 +1,135 @@
+use super::super::{references::resolve, CargoConfig};
+use std::collections::{BTreeMap, BTreeSet};
+
+fn resolve_in(roots: &[&str], files: &[(&str, &str)]) -> Vec<String> {
+    let files: BTreeMap<_, _> = files.iter().cloned().collect();
+    resolve(
+        roots.iter().cloned(),
+        |path| files.contains_key(path),
+        |path| files.get(path).map(|content| content.as_bytes()),
+    )
+    .into_iter()
+    .collect()
+}
+
+#[test]
+fn modules_are_found_in_files_and_directories_relative_to_their_parent_module() {
+    assert_eq!(
+        resolve_in(
+            &["src/lib.rs"],
+            &[
+                (
+                    "src/lib.rs",
+                    "mod a;\npub(crate) mod b;\n#[cfg(test)]\nmod r#type;\nmod inline { fn f() {} }"
+                ),
+                ("src/a.rs", "mod nested;"),
+                ("src/a/nested.rs", ""),
+                ("src/b/mod.rs", "pub mod c;"),
+                ("src/b/c.rs", ""),
+                ("src/type.rs", ""),
+                ("src/inline.rs", "not a module of the crate"),
+                ("src/unused.rs", ""),
+            ]
+        ),
+        vec![
+            "src/a.rs",
+            "src/a/nested.rs",
+            "src/b/c.rs",
+            "src/b/mod.rs",
+            "src/lib.rs",
+            "src/type.rs"
+        ]
+    );
+}
+
+#[test]
+fn path_attributes_are_relative_to_the_declaring_file() {
+    assert_eq!(
+        resolve_in(
+            &["build.rs", "src/lib.rs"],
+            &[
+                ("build.rs", "#[path = \"src/app.rs\"]\nmod app;"),
+                ("src/app.rs", "mod flags;"),
+                ("src/flags.rs", ""),
+                ("src/lib.rs", "#[cfg_attr(unix, path = \"os/unix.rs\")]\nmod os;"),
+                ("src/os/unix.rs", ""),
+            ]
+        ),
+        vec!["build.rs", "src/app.rs", "src/flags.rs", "src/lib.rs", "src/os/unix.rs"],
+        "files loaded by path keep their modules next to them"
+    );
+}
+
+#[test]
+fn includes_are_relative_to_the_including_file_or_the_manifest_directory() {
+    assert_eq!(
+        resolve_in(
+            &["src/lib.rs"],
+            &[
+                (
+                    "src/lib.rs",
+                    r#"include!("generated/table.rs");
+                    static LOGO: &[u8] = include_bytes!("../assets/logo.png");
+                    static README: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/", "README.md"));
+                    static BINDINGS: &str = include_str!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+                    static OUTSIDE: &str = include_str!("../../outside.txt");"#
+                ),
+                ("src/generated/table.rs", "mod entries;"),
+                ("src/entries.rs", "include!(\"entries/all.rs\");"),
+                ("src/entries/all.rs", ""),
+                ("assets/logo.png", ""),
+                ("README.md", "include!(\"not-followed.rs\")"),
+                ("not-followed.rs", ""),
+            ]
+        ),
+        vec![
+            "README.md",
+            "assets/logo.png",
+            "src/entries.rs",
+            "src/entries/all.rs",
+            "src/generated/table.rs",
+            "src/lib.rs"
+        ],
+        "included sources declare modules like the file including them, but include relative to themselves"
+    );
+}
+
+#[test]
+fn declared_targets_are_found_by_path_or_by_name() {
+    let config = CargoConfig::from(
+        r#"
+        [[example]]
+        name = "demo"
+        [[test]]
+        name = "integration"
+        path = "tests/all.rs"
+        [[bench]]
+        name = "speed"
+        "#,
+    );
+    assert_eq!(
+        config.declared_target_paths(),
+        vec![
+            "examples/demo.rs",
+            "examples/demo/main.rs",
+            "tests/all.rs",
+            "benches/speed.rs",
+            "benches/speed/main.rs"
+        ]
+    );
+    let present: BTreeSet<_> = ["tests/all.rs", "tests/util/mod.rs", "benches/speed/main.rs"]
+        .iter()
+        .cloned()
+        .collect();
+    let roots = config.target_paths();
+    assert_eq!(
+        resolve(
+            roots.iter().map(String::as_str),
+            |path| present.contains(path),
+            |path| (path == "tests/all.rs").then_some("mod util;"),
+        )
+        .into_iter()
+        .collect::<Vec<_>>(),
+        vec!["benches/speed/main.rs", "tests/all.rs", "tests/util/mod.rs"]
+    );
+}
@@ -0,0 +1,86 @@
+use super::super::{
+    verify::{needed_files, verify, Verification},
+    CargoConfig, Fix, TarHeader,
+};
+
+fn file(path: &str) -> TarHeader {
+    TarHeader {
+        path: format!("a-1.0.0/{}", path).into_bytes(),
+        size: 1,
+        entry_type: b'0',
+        hash: None,
+    }
+}
+
+fn files(paths: &[&str]) -> Vec<TarHeader> {
+    paths.iter().map(|p| file(p)).collect()
+}
+
+fn include(patterns: &[&str]) -> Fix {
+    Fix::NewInclude {
+        include: patterns.iter().map(|p| p.to_string()).collect(),
+        has_build_script: false,
+    }
+}
+
+fn strings(v: &[&str]) -> Vec<String> {
+    v.iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn needed_files_include_targets_and_compile_time_includes() {
+    let lib = b"const DATA: &str = include_str!(\"../data/table.txt\");".to_vec();
+    let entries = files(&["Cargo.toml", "src/lib.rs", "build.rs", "data/table.txt", "README.md"]);
+    assert_eq!(
+        needed_files(&CargoConfig::default(), &[(file("src/lib.rs"), lib)], &entries),
+        strings(&["build.rs", "data/table.txt", "src/lib.rs"])
+            .into_iter()
+            .collect()
+    );
+}
+
+#[test]
+fn includes_are_matched_with_gitignore_semantics() {
+    let entries = files(&["Cargo.toml", "src/lib.rs", "docs/README.md", "README.md", "tests/t.rs"]);
+    let needed = strings(&["src/lib.rs"]).into_iter().collect();
+    let wasted = vec![("docs/README.md".to_string(), 1), ("tests/t.rs".to_string(), 1)];
+
+    assert_eq!(
+        verify(&include(&["/src", "/README.md"]), &entries, &needed, &wasted),
+        Verification::default(),
+        "directories include everything below them, and leading slashes anchor to the crate root"
+    );
+    assert_eq!(
+        verify(&include(&["src/*.rs", "README.md"]), &entries, &needed, &wasted),
+        Verification {
+            retained_waste: strings(&["docs/README.md"]),
+            ..Default::default()
+        },
+        "patterns without slash match at any depth"
+    );
+    assert_eq!(
+        verify(&include(&["README.md"]), &entries, &needed, &[]),
+        Verification {
+            removed_needed_files: strings(&["src/lib.rs"]),
+            ..Default::default()
+        }
+    );
+}
+
+#[test]
+fn excludes_remove_matching_files() {
+    let entries = files(&["Cargo.toml", "src/lib.rs", "tests/t.rs"]);
+    let needed = strings(&["src/lib.rs"]).into_iter().collect();
+    let fix = Fix::EnrichedExclude {
+        exclude: strings(&["tests", "src/**/*.rs"]),
+        exclude_added: strings(&["tests"]),
+        has_build_script: false,
+    };
+    assert_eq!(
+        verify(&fix, &entries, &needed, &[("tests/t.rs".to_string(), 1)]),
+        Verification {
+            removed_needed_files: strings(&["src/lib.rs"]),
+            ..Default::default()
+        }
+    );
+}
@@ -0,0 +1,119 @@
+//! Verify suggested fixes by applying them the way cargo does when packaging a crate.
+//!
+//! Fixes are computed with `globset`, but cargo interprets `include` and `exclude` with gitignore semantics.
+//! For instance, patterns without a slash match at any depth and patterns may match directories, which globs don't do.
+use super::{result::referenced_files, CargoConfig, Fix, Patterns, TarHeader, WastedFile};
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use serde_derive::{Deserialize, Serialize};
+use std::{collections::BTreeSet, path::Path};
+
+/// The outcome of applying a suggested fix to all files of a crate version with cargo's rules
+#[derive(Default, Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+pub struct Verification {
+    /// Files needed to build the crate which would not be packaged anymore
+    pub removed_needed_files: Vec<String>,
+    /// Files predicted to be waste which would still be packaged
+    pub retained_waste: Vec<String>,
+    /// Patterns cargo wouldn't be able to parse
+    pub invalid_patterns: Vec<String>,
+}
+
+impl Verification {
+    /// Returns true if the fix does exactly what it promises.
+    pub fn is_ok(&self) -> bool {
+        self.removed_needed_files.is_empty() && self.retained_waste.is_empty() && self.invalid_patterns.is_empty()
+    }
+}
+
+fn gitignore(patterns: &Patterns, invalid_patterns: &mut Vec<String>) -> Gitignore {
+    let mut builder = GitignoreBuilder::new("");
+    for pattern in patterns {
+        if builder.add_line(None, pattern).is_err() {
+            invalid_patterns.push(pattern.clone());
+        }
+    }
+    builder.build().unwrap_or_else(|_| Gitignore::empty())
+}
+
+/// Decides which files cargo packages according to `include` or `exclude` patterns
+pub(crate) struct Packager {
+    include: Option<Gitignore>,
+    exclude: Option<Gitignore>,
+}
+
+impl Packager {
+    fn new(fix: &Fix, invalid_patterns: &mut Vec<String>) -> Packager {
+        let (include, exclude) = fix.directives();
+        Packager::from_directives(include, exclude, invalid_patterns)
+    }
+
+    pub(crate) fn from_directives(
+        include: Option<&Patterns>,
+        exclude: Option<&Patterns>,
+        invalid_patterns: &mut Vec<String>,
+    ) -> Packager {
+        Packager {
+            include: include.map(|p| gitignore(p, invalid_patterns)),
+            exclude: exclude.map(|p| gitignore(p, invalid_patterns)),
+        }
+    }
+
+    #[cfg(feature = "cli")]
+    pub(crate) fn has_include(&self) -> bool {
+        self.include.is_some()
+    }
+
+    pub(crate) fn is_packaged(&self, path: &str) -> bool {
+        // The manifest is always packaged
+        if path == "Cargo.toml" {
+            return true;
+        }
+        let path = Path::new(path);
+        match (&self.include, &self.exclude) {
+            (Some(include), _) => include.matched_path_or_any_parents(path, false).is_ignore(),
+            (None, Some(exclude)) => !exclude.matched_path_or_any_parents(path, false).is_ignore(),
+            (None, None) => true,
+        }
+    }
+}
+
+/// All files needed to build a crate: the sources of all its targets, the modules they declare and the files they include
+/// at compile time, as far as they are present in `entries`.
+pub fn needed_files(
+    config: &CargoConfig,
+    entries_with_buffer: &[(TarHeader, Vec<u8>)],
+    entries: &[TarHeader],
+) -> BTreeSet<String> {
+    let present: BTreeSet<_> = entries
+        .iter()
+        .map(|e| crate::tar_path_to_utf8_str(&e.path).into_owned())
+        .collect();
+    referenced_files(config, entries_with_buffer, entries)
+        .into_iter()
+        .filter(|p| present.contains(p))
+        .collect()
+}
+
+/// Apply `fix` to all `entries` like cargo would, and check that all `needed_files` are still packaged
+/// while none of the `wasted_files` are.
+pub fn verify(
+    fix: &Fix,
+    entries: &[TarHeader],
+    needed_files: &BTreeSet<String>,
+    wasted_files: &[WastedFile],
+) -> Verification {
+    let mut verification = Verification::default();
+    let packager = Packager::new(fix, &mut verification.invalid_patterns);
+    verification.removed_needed_files = entries
+        .iter()
+        .map(|e| crate::tar_path_to_utf8_str(&e.path))
+        .filter(|p| needed_files.contains(p.as_ref()) && !packager.is_packaged(p))
+        .map(|p| p.into_owned())
+        .collect();
+    verification.retained_waste = wasted_files
+        .iter()
+        .filter(|(p, _)| packager.is_packaged(p))
+        .map(|(p, _)| p.clone())
+        .collect();
+    verification
+}
@@ -0,0 +1,101 @@
+use crate::{
+    backup::run_blocking,
+    model::{Context, CrateVersion},
+    persistence::{Db, TableAccess},
+};
+use std::path::PathBuf;
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("criner-backup-test-{}-{}", name, std::process::id()));
+    std::fs::remove_dir_all(&dir).ok();
+    dir
+}
+
+fn populated_db(dir: &std::path::Path) -> Db {
+    let db = Db::open(dir).unwrap();
+    let root = prodash::tree::Root::new();
+    let mut progress = root.add_child("test");
+    db.open_crate_versions()
+        .unwrap()
+        .insert(
+            &mut progress,
+            "a:1.0.0",
+            &CrateVersion {
+                name: "a".into(),
+                version: "1.0.0".into(),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    db.open_context()
+        .unwrap()
+        .update_today(|c: &mut Context| c.counts.crates += 1)
+        .unwrap();
+    let crate_dir = dir.join("assets").join("1").join("a");
+    std::fs::create_dir_all(&crate_dir).unwrap();
+    std::fs::write(crate_dir.join("a-1.0.0-download:1.0.0.crate"), b"crate").unwrap();
+    db
+}
+
+#[test]
+fn snapshot_with_hardlinked_assets_and_verification() {
+    let (source, snapshot) = (temp_dir("source"), temp_dir("snapshot"));
+    let _db = populated_db(&source);
+
+    run_blocking(&source, &snapshot, true, true).unwrap();
+
+    let snapshot_db = Db::open(&snapshot).unwrap();
+    assert!(snapshot_db
+        .open_crate_versions()
+        .unwrap()
+        .get("a:1.0.0")
+        .unwrap()
+        .is_some());
+    assert_eq!(
+        snapshot_db
+            .open_context()
+            .unwrap()
+            .most_recent()
+            .unwrap()
+            .map(|(_, c)| c.counts.crates),
+        Some(1)
+    );
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+        let asset = PathBuf::from("assets/1/a/a-1.0.0-download:1.0.0.crate");
+        let (source_meta, snapshot_meta) = (
+            std::fs::metadata(source.join(&asset)).unwrap(),
+            std::fs::metadata(snapshot.join(&asset)).unwrap(),
+        );
+        assert_eq!(source_meta.ino(), snapshot_meta.ino(), "assets are hard-linked");
+    }
+
+    assert!(
+        run_blocking(&source, &snapshot, false, false).is_err(),
+        "existing snapshots are never overwritten"
+    );
+    std::fs::remove_dir_all(&source).ok();
+    std::fs::remove_dir_all(&snapshot).ok();
+}
+
+#[test]
+fn verification_fails_on_undecodable_data() {
+    let (source, snapshot) = (temp_dir("corrupt-source"), temp_dir("corrupt-snapshot"));
+    let db = populated_db(&source);
+    db.open_connection()
+        .unwrap()
+        .insert(None, "task", "a:1.0.0:download:1.0.0", b"not msgpack")
+        .unwrap();
+
+    let err = run_blocking(&source, &snapshot, false, true).unwrap_err();
+    assert!(
+        err.to_string().contains("a:1.0.0:download:1.0.0"),
+        "the offending key is mentioned: {}",
+        err
+    );
+    assert!(!snapshot.join("assets").exists(), "assets are only linked on demand");
+    std::fs::remove_dir_all(&source).ok();
+    std::fs::remove_dir_all(&snapshot).ok();
+}
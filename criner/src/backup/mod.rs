@@ -0,0 +1,199 @@
+use crate::{
+    model,
+    persistence::{self, Db, TableAccess},
+    Error, Result,
+};
+use std::{
+    path::Path,
+    time::{Duration, SystemTime},
+};
+
+/// The amount of items to deserialize from each end of a table when verifying a snapshot
+const VERIFY_SAMPLE_SIZE: usize = 1000;
+/// The longest time to wait for the source database to be unlocked by the miner before giving up
+const MAX_BUSY_WAIT: Duration = Duration::from_secs(100);
+
+/// Write a consistent snapshot of the criner database at `source_db` into the directory `destination_db`, which must
+/// not exist yet.
+///
+/// It's safe to run this while criner is mining as SQLite's online backup API is used.
+/// If `hardlink_assets` is set, the `assets` tree will be recreated in the snapshot with all files hard-linked.
+/// If `verify` is set, the snapshot is opened and a sample of every table is deserialized.
+pub fn run_blocking(
+    source_db: impl AsRef<Path>,
+    destination_db: impl AsRef<Path>,
+    hardlink_assets: bool,
+    verify: bool,
+) -> Result<()> {
+    let (source_db, destination_db) = (source_db.as_ref(), destination_db.as_ref());
+    let source_db_file = Db::sqlite_path(source_db);
+    if !source_db_file.is_file() {
+        return Err(Error::Message(format!(
+            "Source database at '{}' does not exist",
+            source_db_file.display()
+        )));
+    }
+    if destination_db.exists() {
+        return Err(Error::Message(format!(
+            "Destination at '{}' does already exist - this is currently unsupported",
+            destination_db.display()
+        )));
+    }
+    std::fs::create_dir_all(destination_db)?;
+
+    let start = SystemTime::now();
+    // Open the live database read-only, as opening it like the miner does would write to it.
+    let source = rusqlite::Connection::open_with_flags(&source_db_file, rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+    source.busy_timeout(MAX_BUSY_WAIT)?;
+    let mut destination = rusqlite::Connection::open(Db::sqlite_path(destination_db))?;
+    backup(&source, &mut destination)?;
+    drop(destination);
+    log::info!(
+        "Wrote database snapshot to '{}' in {:?}",
+        destination_db.display(),
+        start.elapsed().unwrap_or_default()
+    );
+
+    if hardlink_assets {
+        let start = SystemTime::now();
+        let num_files = hardlink_tree(&source_db.join("assets"), &destination_db.join("assets"))?;
+        log::info!(
+            "Hard-linked {} asset files in {:?}",
+            num_files,
+            start.elapsed().unwrap_or_default()
+        );
+    }
+
+    if verify {
+        verify_snapshot(destination_db)?;
+    }
+    Ok(())
+}
+
+fn backup(source: &rusqlite::Connection, destination: &mut rusqlite::Connection) -> Result<()> {
+    let backup = rusqlite::backup::Backup::new(source, destination)?;
+    // Copy all pages in one step, which keeps a read transaction open on the source. In WAL mode that doesn't
+    // block writers, and the snapshot stays consistent. Copying in smaller steps would restart the backup
+    // whenever the miner writes, which is all the time.
+    let retry_after = Duration::from_millis(50);
+    let mut total_wait_time = Duration::default();
+    loop {
+        match backup.step(-1)? {
+            rusqlite::backup::StepResult::Done => return Ok(()),
+            rusqlite::backup::StepResult::More => {}
+            _busy_or_locked => {
+                if total_wait_time >= MAX_BUSY_WAIT {
+                    return Err(Error::Message(format!(
+                        "Gave up to wait for the source database to be unlocked after {:?}",
+                        total_wait_time
+                    )));
+                }
+                std::thread::sleep(retry_after);
+                total_wait_time += retry_after;
+            }
+        }
+    }
+}
+
+fn hardlink_tree(source: &Path, destination: &Path) -> Result<usize> {
+    if !source.is_dir() {
+        return Ok(0);
+    }
+    std::fs::create_dir_all(destination)?;
+    let mut num_files = 0;
+    for entry in std::fs::read_dir(source)? {
+        let entry = entry?;
+        let destination = destination.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            num_files += hardlink_tree(&entry.path(), &destination)?;
+        } else {
+            std::fs::hard_link(entry.path(), &destination).map_err(|err| {
+                Error::Message(format!(
+                    "Failed to hard-link '{}' to '{}' - both need to be on the same file system: {}",
+                    entry.path().display(),
+                    destination.display(),
+                    err
+                ))
+            })?;
+            num_files += 1;
+        }
+    }
+    Ok(num_files)
+}
+
+/// Deserialize the oldest and the most recent items of `table_name` whose keys match `glob`, if set.
+fn verify_table<T: serde::de::DeserializeOwned>(
+    connection: &persistence::ThreadSafeConnection,
+    table_name: &str,
+    glob: Option<&str>,
+) -> Result<usize> {
+    let num_items = connection.count(table_name, glob)? as usize;
+    let mut items = connection.key_values_old_to_new(table_name, glob, Some((0, VERIFY_SAMPLE_SIZE)))?;
+    items.extend(connection.key_values_old_to_new(
+        table_name,
+        glob,
+        Some((num_items.saturating_sub(VERIFY_SAMPLE_SIZE), VERIFY_SAMPLE_SIZE)),
+    )?);
+    for (key, data) in items.iter() {
+        rmp_serde::from_slice::<T>(data).map_err(|err| {
+            Error::Message(format!(
+                "Could not deserialize item '{}' in table '{}': {}",
+                key, table_name, err
+            ))
+        })?;
+    }
+    Ok(items.len())
+}
+
+fn verify_snapshot(snapshot_db: &Path) -> Result<()> {
+    let db = Db::open(snapshot_db)?;
+    let connection = db.open_connection()?;
+    for (table_name, num_items) in [
+        (
+            persistence::CrateVersionTable::table_name(),
+            verify_table::<model::CrateVersion>(&connection, persistence::CrateVersionTable::table_name(), None)?,
+        ),
+        (
+            persistence::CrateTable::table_name(),
+            verify_table::<model::Crate>(&connection, persistence::CrateTable::table_name(), None)?,
+        ),
+        (
+            persistence::TaskTable::table_name(),
+            verify_table::<model::Task>(&connection, persistence::TaskTable::table_name(), None)?,
+        ),
+        (
+            persistence::TaskResultTable::table_name(),
+            verify_table::<model::TaskResult>(&connection, persistence::TaskResultTable::table_name(), None)?,
+        ),
+        (
+            persistence::ReverseDependencyTable::table_name(),
+            verify_table::<model::ReverseDependencies>(
+                &connection,
+                persistence::ReverseDependencyTable::table_name(),
+                None,
+            )?,
+        ),
+        (
+            persistence::MetaTable::table_name(),
+            verify_table::<model::Context>(
+                &connection,
+                persistence::MetaTable::table_name(),
+                Some(persistence::MetaTable::CONTEXT_GLOB),
+            )?,
+        ),
+        (
+            "crates.io-crate",
+            verify_table::<model::db_dump::Crate>(&connection, "crates.io-crate", None)?,
+        ),
+    ] {
+        log::info!("Verified {} items of table '{}'", num_items, table_name);
+    }
+    log::info!(
+        "Snapshot contains {} finished reports",
+        connection.count(persistence::ReportsTree::table_name(), None)?
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod backup_test;
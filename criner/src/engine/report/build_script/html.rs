@@ -0,0 +1,213 @@
+use super::{merge::union_of_all, Analysis, Dict, Report};
+use horrorshow::{box_html, helper::doctype, html, Render, RenderBox, RenderOnce, TemplateBuffer};
+use std::{collections::BTreeSet, time::SystemTime};
+
+/// The maximum amount of crates to list for each item on the top-level page
+const MAX_CRATES_PER_ITEM: usize = 50;
+
+fn page_head(title: impl Into<String>) -> Box<dyn RenderBox> {
+    let title = title.into();
+    box_html! {
+        head {
+            title: title;
+        }
+    }
+}
+
+fn page_footer() -> impl Render {
+    html! {
+        footer {
+            span {
+                : "Generated by ";
+                a(href="https://github.com/the-lean-crate/criner"): "Criner";
+                : format!(" at {}", humantime::format_rfc3339_seconds(SystemTime::now()));
+            }
+        }
+    }
+}
+
+fn analysis_section(analysis: Analysis) -> Box<dyn RenderBox> {
+    box_html! {
+        @ if analysis.is_empty() {
+            p: "Nothing noteworthy found"
+        } else {
+            dl {
+                @ for (name, items) in analysis.fields().iter().filter(|(_, items)| !items.is_empty()) {
+                    dt: *name;
+                    dd: items.iter().cloned().collect::<Vec<_>>().join(", ");
+                }
+            }
+        }
+    }
+}
+
+fn versions_section(analysis_by_version: Dict<Analysis>) -> Box<dyn RenderBox> {
+    box_html! {
+        section(id="children") {
+            h1: "Versions with build script";
+            ol {
+                @ for (version, analysis) in analysis_by_version.into_iter().rev() {
+                    li {
+                        h3 {
+                            a(href=format!("{}.html", version)): version.clone();
+                        }
+                        : analysis_section(analysis);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Crates by the item they use, most used first
+type CratesByItem = Vec<(String, BTreeSet<String>)>;
+
+/// For each kind of information, the crates by the items they use, like `cc` or `OUT_DIR`, most used first.
+fn crates_by_item(analysis_by_crate: &Dict<Analysis>) -> Vec<(&'static str, CratesByItem)> {
+    let mut fields = Vec::<(&'static str, Dict<BTreeSet<String>>)>::new();
+    for (crate_name, analysis) in analysis_by_crate {
+        for (idx, (name, items)) in analysis.fields().iter().enumerate() {
+            if fields.len() <= idx {
+                fields.push((name, Dict::new()));
+            }
+            for item in items.iter() {
+                fields[idx]
+                    .1
+                    .entry(item.clone())
+                    .or_default()
+                    .insert(crate_name.clone());
+            }
+        }
+    }
+    fields
+        .into_iter()
+        .map(|(name, crates_by_item)| {
+            let mut sorted: Vec<_> = crates_by_item.into_iter().collect();
+            sorted.sort_by_key(|(_, crates)| std::cmp::Reverse(crates.len()));
+            (name, sorted)
+        })
+        .collect()
+}
+
+fn crates_by_item_section(name: &'static str, items: CratesByItem) -> Box<dyn RenderBox> {
+    box_html! {
+        section {
+            h1: name;
+            @ if items.is_empty() {
+                p: "None"
+            } else {
+                ol {
+                    @ for (item, crates) in items {
+                        li {
+                            h3: format!("{} - used by {} crates", item, crates.len());
+                            p {
+                                @ for crate_name in crates.iter().take(MAX_CRATES_PER_ITEM) {
+                                    a(href=format!("{}/index.html", crate_name)): crate_name;
+                                    : " ";
+                                }
+                                @ if crates.len() > MAX_CRATES_PER_ITEM {
+                                    : format!("and {} more", crates.len() - MAX_CRATES_PER_ITEM);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl RenderOnce for Report {
+    fn render_once(self, tmpl: &mut TemplateBuffer<'_>)
+    where
+        Self: Sized,
+    {
+        use super::Report::*;
+        match self {
+            Version {
+                crate_name,
+                crate_version,
+                build_script,
+                truncated,
+                analysis,
+            } => {
+                let title = format!("{}:{}", crate_name, crate_version);
+                tmpl << html! {
+                    : doctype::HTML;
+                    html {
+                        : page_head(title.clone());
+                        body {
+                            article {
+                                h1: title;
+                                @ if let Some(build_script) = build_script {
+                                    p: format!("Build script at '{}'", build_script);
+                                    @ if truncated {
+                                        p: "Only the beginning of the build script was stored and analyzed";
+                                    }
+                                    : analysis_section(analysis);
+                                } else {
+                                    p: "No build script"
+                                }
+                            }
+                        }
+                        : page_footer();
+                    }
+                }
+            }
+            Crate {
+                crate_name,
+                total_versions,
+                analysis_by_version,
+            } => {
+                let all_versions = union_of_all(analysis_by_version.values().cloned());
+                tmpl << html! {
+                    : doctype::HTML;
+                    html {
+                        : page_head(crate_name.clone());
+                        body {
+                            article {
+                                h1: crate_name;
+                                p: format!("{} of {} versions have a build script", analysis_by_version.len(), total_versions);
+                                section {
+                                    h3: "All versions";
+                                    : analysis_section(all_versions);
+                                }
+                                : versions_section(analysis_by_version);
+                            }
+                        }
+                        : page_footer();
+                    }
+                }
+            }
+            CrateCollection {
+                total_versions,
+                versions_with_build_script,
+                analysis_by_crate,
+            } => {
+                let title = "Crates.io Build Script Report";
+                let fields = crates_by_item(&analysis_by_crate);
+                tmpl << html! {
+                    : doctype::HTML;
+                    html {
+                        : page_head(title);
+                        body {
+                            article {
+                                h1: title;
+                                p: format!(
+                                    "{} of {} versions have a build script, in {} crates",
+                                    versions_with_build_script,
+                                    total_versions,
+                                    analysis_by_crate.len()
+                                );
+                                @ for (name, items) in fields {
+                                    : crates_by_item_section(name, items);
+                                }
+                            }
+                        }
+                        : page_footer();
+                    }
+                }
+            }
+        }
+    }
+}
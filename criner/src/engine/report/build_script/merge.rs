@@ -0,0 +1,111 @@
+use super::{Analysis, Dict, Report};
+use crate::Result;
+use async_trait::async_trait;
+
+pub fn union_of_all(analyses: impl IntoIterator<Item = Analysis>) -> Analysis {
+    analyses.into_iter().fold(Analysis::default(), Analysis::union)
+}
+
+pub fn crate_from_version(version: Report) -> Report {
+    match version {
+        Report::Version {
+            crate_name,
+            crate_version,
+            build_script,
+            analysis,
+            truncated: _,
+        } => Report::Crate {
+            crate_name,
+            total_versions: 1,
+            analysis_by_version: build_script.map(|_| (crate_version, analysis)).into_iter().collect(),
+        },
+        _ => unreachable!("must only be called with version variant"),
+    }
+}
+
+pub fn collection_from_crate(krate: Report) -> Report {
+    match krate {
+        version @ Report::Version { .. } => collection_from_crate(crate_from_version(version)),
+        Report::Crate {
+            crate_name,
+            total_versions,
+            analysis_by_version,
+        } => Report::CrateCollection {
+            total_versions,
+            versions_with_build_script: analysis_by_version.len() as u64,
+            analysis_by_crate: if analysis_by_version.is_empty() {
+                Dict::new()
+            } else {
+                std::iter::once((crate_name, union_of_all(analysis_by_version.into_values()))).collect()
+            },
+        },
+        collection @ Report::CrateCollection { .. } => collection,
+    }
+}
+
+#[async_trait]
+impl crate::engine::report::generic::Aggregate for Report {
+    fn merge(self, other: Self) -> Self {
+        use Report::*;
+        match (self, other) {
+            (lhs @ Version { .. }, rhs) => crate_from_version(lhs).merge(rhs),
+            (lhs, rhs @ Version { .. }) => lhs.merge(crate_from_version(rhs)),
+            (
+                Crate {
+                    crate_name: lhs_crate_name,
+                    total_versions: lhs_tv,
+                    analysis_by_version: mut lhs_abv,
+                },
+                Crate {
+                    crate_name: rhs_crate_name,
+                    total_versions: rhs_tv,
+                    analysis_by_version: rhs_abv,
+                },
+            ) if lhs_crate_name == rhs_crate_name => {
+                // The build script of a version never changes, so there is nothing to combine
+                lhs_abv.extend(rhs_abv);
+                Crate {
+                    crate_name: lhs_crate_name,
+                    total_versions: lhs_tv + rhs_tv,
+                    analysis_by_version: lhs_abv,
+                }
+            }
+            (
+                CrateCollection {
+                    total_versions: lhs_tv,
+                    versions_with_build_script: lhs_vwbs,
+                    analysis_by_crate: lhs_abc,
+                },
+                CrateCollection {
+                    total_versions: rhs_tv,
+                    versions_with_build_script: rhs_vwbs,
+                    analysis_by_crate: rhs_abc,
+                },
+            ) => CrateCollection {
+                total_versions: lhs_tv + rhs_tv,
+                versions_with_build_script: lhs_vwbs + rhs_vwbs,
+                analysis_by_crate: rhs_abc.into_iter().fold(lhs_abc, |mut m, (k, v)| {
+                    let analysis = m.remove(&k).unwrap_or_default();
+                    m.insert(k, analysis.union(v));
+                    m
+                }),
+            },
+            (lhs, rhs) => collection_from_crate(lhs).merge(collection_from_crate(rhs)),
+        }
+    }
+
+    async fn complete(&mut self, _progress: &mut prodash::tree::Item, out: &mut Vec<u8>) -> Result<()> {
+        use horrorshow::Template;
+
+        let report = self.clone();
+        report.write_to_io(out)?;
+        Ok(())
+    }
+
+    fn crate_name(&self) -> Option<&str> {
+        match self {
+            Report::Version { crate_name, .. } | Report::Crate { crate_name, .. } => Some(crate_name),
+            Report::CrateCollection { .. } => None,
+        }
+    }
+}
@@ -0,0 +1,286 @@
+use crate::persistence::TableAccess;
+use crate::{
+    engine::report::waste::{tar_path_to_utf8_str, CargoConfig},
+    error::Result,
+    model::{TarHeader, TaskResult},
+    persistence,
+};
+use async_trait::async_trait;
+use serde_derive::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+mod html;
+mod merge;
+
+pub type Dict<T> = BTreeMap<String, T>;
+pub type Set = BTreeSet<String>;
+
+lazy_static! {
+    static ref LINE_COMMENT: regex::Regex = regex::Regex::new(r"(?m)^\s*//.*$").expect("valid statically known regex");
+    static ref NATIVE_TOOL: regex::Regex =
+        regex::Regex::new(r"\b(cc|cmake|bindgen|pkg_config|vcpkg|autotools|cxx_build|nasm_rs)::")
+            .expect("valid statically known regex");
+    static ref NETWORK_ACCESS: regex::Regex = regex::Regex::new(
+        r"\b(reqwest|ureq|curl|attohttpc|minreq|hyper|isahc|ssh2|std::net|TcpStream|UdpSocket)\b"
+    )
+    .expect("valid statically known regex");
+    static ref COMMAND: regex::Regex =
+        regex::Regex::new(r#"Command::new\(\s*"(?P<program>[^"]+)""#).expect("valid statically known regex");
+    static ref ENV_VAR: regex::Regex = regex::Regex::new(r#"\b(?:var|var_os|env!|option_env!)\(\s*"(?P<name>[^"]+)""#)
+        .expect("valid statically known regex");
+    static ref LINK_LIB: regex::Regex = regex::Regex::new(
+        r#"cargo::?rustc-link-lib=(?:(?:static|dylib|framework)(?::[^=\s"]*)?=)?(?P<lib>[^"\s\\{}=:]+)(?:["\s\\]|$)"#
+    )
+    .expect("valid statically known regex");
+    static ref WRITE: regex::Regex = regex::Regex::new(
+        r"\b(?P<function>File::create|fs::write|fs::copy|fs::create_dir_all|fs::create_dir|OpenOptions::new\(\)[^;]*?\.open)\((?P<args>[^;]*)"
+    )
+    .expect("valid statically known regex");
+}
+
+/// Programs that download something when run
+const NETWORK_PROGRAMS: &[&str] = &["curl", "wget", "git", "svn", "hg"];
+
+/// What a build script does, as found by looking for typical API calls in its source.
+///
+/// This is a heuristic, and code in other files included by the build script isn't analyzed.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Default)]
+pub struct Analysis {
+    /// Crates used to compile native code or to find native libraries, like `cc`, `cmake` or `bindgen`
+    pub native_tools: Set,
+    /// Programs run with `std::process::Command`
+    pub commands: Set,
+    /// Crates, APIs or programs which access the network
+    pub network_access: Set,
+    /// Environment variables read by the script
+    pub env_vars: Set,
+    /// The libraries to link to with `cargo:rustc-link-lib`
+    pub link_libs: Set,
+    /// The targets of file writes which aren't obviously within `OUT_DIR`
+    pub writes_outside_out_dir: Set,
+}
+
+impl Analysis {
+    pub fn is_empty(&self) -> bool {
+        *self == Analysis::default()
+    }
+
+    /// All fields with a human readable name
+    pub fn fields(&self) -> [(&'static str, &Set); 6] {
+        [
+            ("native tools", &self.native_tools),
+            ("commands", &self.commands),
+            ("network access", &self.network_access),
+            ("environment variables", &self.env_vars),
+            ("linked libraries", &self.link_libs),
+            ("writes outside of OUT_DIR", &self.writes_outside_out_dir),
+        ]
+    }
+
+    pub fn union(mut self, other: Analysis) -> Analysis {
+        self.native_tools.extend(other.native_tools);
+        self.commands.extend(other.commands);
+        self.network_access.extend(other.network_access);
+        self.env_vars.extend(other.env_vars);
+        self.link_libs.extend(other.link_libs);
+        self.writes_outside_out_dir.extend(other.writes_outside_out_dir);
+        self
+    }
+}
+
+/// Analyze the source of a build script.
+pub fn analyze(script: &str) -> Analysis {
+    let script = LINE_COMMENT.replace_all(script, "");
+    let captures = |re: &regex::Regex, name: &str| -> Set {
+        re.captures_iter(&script)
+            .filter_map(|c| c.name(name).map(|m| m.as_str().to_owned()))
+            .collect()
+    };
+    let matches = |re: &regex::Regex| -> Set { re.find_iter(&script).map(|m| m.as_str().to_owned()).collect() };
+
+    let native_tools = NATIVE_TOOL
+        .captures_iter(&script)
+        .map(|c| c[1].replace('_', "-"))
+        .collect();
+    let commands = captures(&COMMAND, "program");
+    let mut network_access = matches(&NETWORK_ACCESS);
+    network_access.extend(
+        commands
+            .iter()
+            .filter(|program| NETWORK_PROGRAMS.contains(&program.as_str()))
+            .cloned(),
+    );
+    let env_vars = captures(&ENV_VAR, "name");
+    let reads_out_dir = env_vars.contains("OUT_DIR");
+    let writes_outside_out_dir = WRITE
+        .captures_iter(&script)
+        .filter_map(|c| {
+            let args = split_arguments(c.name("args").expect("mandatory group").as_str());
+            // Only `copy` writes to its last argument
+            if c["function"].ends_with("copy") {
+                args.last().copied()
+            } else {
+                args.first().copied()
+            }
+        })
+        .filter(|target| !reads_out_dir || writes_outside_of_out_dir(target))
+        .map(ToOwned::to_owned)
+        .collect();
+
+    Analysis {
+        native_tools,
+        commands,
+        network_access,
+        env_vars,
+        link_libs: captures(&LINK_LIB, "lib"),
+        writes_outside_out_dir,
+    }
+}
+
+/// Split the arguments of a function call at commas which aren't nested in parentheses, brackets or strings,
+/// stopping at the parenthesis closing the call.
+fn split_arguments(args: &str) -> Vec<&str> {
+    let (mut depth, mut in_string, mut start) = (0usize, false, 0);
+    let mut out = Vec::new();
+    for (idx, c) in args.char_indices() {
+        match c {
+            '"' => in_string = !in_string,
+            '(' | '[' | '{' if !in_string => depth += 1,
+            ')' if !in_string && depth == 0 => {
+                out.push(args[start..idx].trim());
+                start = args.len();
+                break;
+            }
+            ')' | ']' | '}' if !in_string => depth = depth.saturating_sub(1),
+            ',' if !in_string && depth == 0 => {
+                out.push(args[start..idx].trim());
+                start = idx + 1;
+            }
+            _ => {}
+        }
+    }
+    out.push(args[start..].trim());
+    out.retain(|arg| !arg.is_empty());
+    out
+}
+
+/// Returns true if the `target` of a write in a script reading `OUT_DIR` obviously isn't below it.
+fn writes_outside_of_out_dir(target: &str) -> bool {
+    let lowercase = target.to_lowercase();
+    let is_literal = target.trim_start_matches('&').starts_with('"');
+    (is_literal || lowercase.contains("manifest_dir")) && !lowercase.contains("out_dir")
+}
+
+/// Returns the path and content of the build script in `selected_entries`, if there is one.
+pub fn find_build_script(selected_entries: &[(TarHeader, Vec<u8>)]) -> Option<(String, &TarHeader, &[u8])> {
+    let find = |path: &str| {
+        selected_entries
+            .iter()
+            .find(|(header, _)| tar_path_to_utf8_str(&header.path) == path)
+    };
+    let manifest = find("Cargo.toml").map(|(_, content)| String::from_utf8_lossy(content));
+    let manifest = manifest.as_deref().unwrap_or_default();
+    let has_disabled_build_script = toml::from_str::<toml::Table>(manifest)
+        .ok()
+        .and_then(|m| m.get("package")?.get("build")?.as_bool())
+        == Some(false);
+    if has_disabled_build_script {
+        return None;
+    }
+    let path = CargoConfig::from(manifest)
+        .actual_or_expected_build_script_path()
+        .trim_start_matches("./")
+        .to_owned();
+    find(&path).map(|(header, content)| (path, header, content.as_slice()))
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub enum Report {
+    Version {
+        crate_name: String,
+        crate_version: String,
+        /// The path to the build script, if there is one
+        build_script: Option<String>,
+        /// True if only the beginning of the build script was stored and analyzed
+        truncated: bool,
+        analysis: Analysis,
+    },
+    Crate {
+        crate_name: String,
+        total_versions: u64,
+        /// Only versions with a build script are listed
+        analysis_by_version: Dict<Analysis>,
+    },
+    CrateCollection {
+        total_versions: u64,
+        versions_with_build_script: u64,
+        /// What the build scripts of all versions of a crate do, only crates with a build script are listed
+        analysis_by_crate: Dict<Analysis>,
+    },
+}
+
+impl Report {
+    pub fn from_package(crate_name: &str, crate_version: &str, selected_entries: &[(TarHeader, Vec<u8>)]) -> Report {
+        let build_script = find_build_script(selected_entries);
+        Report::Version {
+            crate_name: crate_name.into(),
+            crate_version: crate_version.into(),
+            truncated: build_script
+                .as_ref()
+                .is_some_and(|(_, header, content)| (content.len() as u64) < header.size),
+            analysis: build_script
+                .as_ref()
+                .map(|(_, _, content)| analyze(&String::from_utf8_lossy(content)))
+                .unwrap_or_default(),
+            build_script: build_script.map(|(path, _, _)| path),
+        }
+    }
+}
+
+pub struct Generator;
+
+#[async_trait]
+impl super::generic::Generator for Generator {
+    type Report = Report;
+    type DBResult = TaskResult;
+
+    fn name() -> &'static str {
+        "build_script"
+    }
+
+    fn version() -> &'static str {
+        "1.1.0"
+    }
+
+    fn fq_result_key(crate_name: &str, crate_version: &str, key_buf: &mut String) {
+        <super::waste::Generator as super::generic::Generator>::fq_result_key(crate_name, crate_version, key_buf)
+    }
+
+    fn get_result(
+        connection: persistence::ThreadSafeConnection,
+        crate_name: &str,
+        crate_version: &str,
+        key_buf: &mut String,
+    ) -> Result<Option<TaskResult>> {
+        Self::fq_result_key(crate_name, crate_version, key_buf);
+        let table = persistence::TaskResultTable { inner: connection };
+        table.get(&key_buf)
+    }
+
+    async fn generate_report(
+        crate_name: &str,
+        crate_version: &str,
+        result: TaskResult,
+        _progress: &mut prodash::tree::Item,
+    ) -> Result<Self::Report> {
+        Ok(match result {
+            TaskResult::ExplodedCrate { selected_entries, .. } => {
+                Report::from_package(crate_name, crate_version, &selected_entries)
+            }
+            _ => unreachable!("caller must assure we are always an exploded entry"),
+        })
+    }
+}
+
+#[cfg(test)]
+mod report_test;
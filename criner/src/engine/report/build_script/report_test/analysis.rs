@@ -0,0 +1,125 @@
+use crate::{
+    engine::report::build_script::{analyze, find_build_script, Analysis, Report},
+    model::TarHeader,
+};
+
+fn set(items: &[&str]) -> std::collections::BTreeSet<String> {
+    items.iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn native_compilation_commands_network_and_links() {
+    let analysis = analyze(
+        r#"
+        use std::{env, fs, path::PathBuf, process::Command};
+        // cmake::Config::new("commented-out") is ignored
+        fn main() {
+            let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+            let target = env::var_os("TARGET");
+            if option_env!("DOCS_RS").is_none() {
+                cc::Build::new().file("src/native.c").compile("native");
+            }
+            bindgen::Builder::default().generate().unwrap();
+            pkg_config::probe_library("zlib").unwrap();
+            Command::new("curl").arg("https://example.com/blob").status().unwrap();
+            Command::new("make").status().unwrap();
+            let _ = reqwest::blocking::get("https://example.com");
+            println!("cargo:rustc-link-lib=static=native");
+            println!("cargo:rustc-link-lib=ssl");
+            println!("cargo:rustc-link-lib=dylib={}", name);
+            println!("cargo::rustc-link-lib=framework=Security");
+            fs::write(out_dir.join("bindings.rs"), code).unwrap();
+        }
+        "#,
+    );
+    assert_eq!(
+        analysis,
+        Analysis {
+            native_tools: set(&["bindgen", "cc", "pkg-config"]),
+            commands: set(&["curl", "make"]),
+            network_access: set(&["curl", "reqwest"]),
+            env_vars: set(&["DOCS_RS", "OUT_DIR", "TARGET"]),
+            link_libs: set(&["Security", "native", "ssl"]),
+            writes_outside_out_dir: Default::default(),
+        }
+    );
+}
+
+#[test]
+fn writes_outside_of_out_dir() {
+    let analysis = analyze(
+        r#"
+        fn main() {
+            let out_dir = std::env::var("OUT_DIR").unwrap();
+            let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+            std::fs::write("src/generated.rs", code).unwrap();
+            fs::create_dir_all(Path::new(&manifest_dir).join("target")).ok();
+            fs::copy(Path::new(&out_dir).join("lib.a"), format!("{}/lib.a", manifest_dir))?;
+            let mut f = File::create(Path::new(&out_dir).join("ok.rs"))?;
+            let f = OpenOptions::new().append(true).open(&some_path)?;
+        }
+        "#,
+    );
+    assert_eq!(
+        analysis.writes_outside_out_dir,
+        set(&[
+            "\"src/generated.rs\"",
+            "Path::new(&manifest_dir).join(\"target\")",
+            "format!(\"{}/lib.a\", manifest_dir)",
+        ]),
+        "writes to paths which could be within OUT_DIR are ignored"
+    );
+
+    assert_eq!(
+        analyze("fn main() { File::create(&path).unwrap(); }").writes_outside_out_dir,
+        set(&["&path"]),
+        "without reading OUT_DIR, every write is outside of it"
+    );
+}
+
+fn entry(path: &str, content: &str, size: usize) -> (TarHeader, Vec<u8>) {
+    (
+        TarHeader {
+            path: format!("krate-1.0.0/{}", path).into_bytes(),
+            size: size as u64,
+            entry_type: b'0',
+            hash: None,
+        },
+        content.as_bytes().to_vec(),
+    )
+}
+
+#[test]
+fn build_scripts_are_found_as_declared_in_the_manifest() {
+    let script = "fn main() { cc::Build::new(); }";
+    let entries = vec![
+        entry("Cargo.toml", "[package]\nbuild = \"./tools/build.rs\"\n", 0),
+        entry("tools/build.rs", script, 1000),
+        entry("build.rs", "", 0),
+    ];
+    let (path, _, content) = find_build_script(&entries).unwrap();
+    assert_eq!((path.as_str(), content), ("tools/build.rs", script.as_bytes()));
+    match Report::from_package("krate", "1.0.0", &entries) {
+        Report::Version {
+            build_script,
+            truncated,
+            analysis,
+            ..
+        } => {
+            assert_eq!(build_script.as_deref(), Some("tools/build.rs"));
+            assert!(truncated, "the stored script is smaller than the actual one");
+            assert_eq!(analysis.native_tools, set(&["cc"]));
+        }
+        _ => unreachable!(),
+    }
+
+    let implied = vec![entry("Cargo.toml", "[package]\n", 0), entry("build.rs", script, 0)];
+    assert_eq!(find_build_script(&implied).unwrap().0, "build.rs");
+
+    let disabled = vec![
+        entry("Cargo.toml", "[package]\nbuild = false\n", 0),
+        entry("build.rs", script, 0),
+    ];
+    assert!(find_build_script(&disabled).is_none());
+    assert!(find_build_script(&[entry("Cargo.toml", "[package]\n", 0)]).is_none());
+}
@@ -0,0 +1,65 @@
+use crate::engine::{
+    report::build_script::{Analysis, Report},
+    report::generic::Aggregate,
+};
+use common_macros::b_tree_map;
+
+fn version(crate_name: &str, crate_version: &str, native_tools: Option<&[&str]>) -> Report {
+    Report::Version {
+        crate_name: crate_name.into(),
+        crate_version: crate_version.into(),
+        build_script: native_tools.map(|_| "build.rs".into()),
+        truncated: false,
+        analysis: analysis(native_tools.unwrap_or_default()),
+    }
+}
+
+fn analysis(native_tools: &[&str]) -> Analysis {
+    Analysis {
+        native_tools: native_tools.iter().map(|s| s.to_string()).collect(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn two_versions_of_same_crate() {
+    assert_eq!(
+        version("a", "1", Some(&["cc"])).merge(version("a", "2", None)),
+        Report::Crate {
+            crate_name: "a".into(),
+            total_versions: 2,
+            analysis_by_version: b_tree_map! {
+                "1".into() => analysis(&["cc"]),
+            }
+        }
+    );
+}
+
+#[test]
+fn versions_of_different_crates_are_combined_per_crate() {
+    assert_eq!(
+        version("a", "1", Some(&["cc"]))
+            .merge(version("b", "1", None))
+            .merge(version("a", "2", Some(&["cmake"])))
+            .merge(version("c", "1", Some(&[]))),
+        Report::CrateCollection {
+            total_versions: 4,
+            versions_with_build_script: 3,
+            analysis_by_crate: b_tree_map! {
+                "a".into() => analysis(&["cc", "cmake"]),
+                "c".into() => analysis(&[]),
+            }
+        }
+    );
+}
+
+#[test]
+fn merging_is_independent_of_order() {
+    let krate = version("a", "1", Some(&["cc"])).merge(version("a", "2", Some(&["bindgen"])));
+    let other_version = version("b", "1", Some(&["cmake"]));
+    let collection = version("c", "1", None).merge(version("d", "1", Some(&["cc"])));
+    assert_eq!(
+        krate.clone().merge(other_version.clone()).merge(collection.clone()),
+        collection.merge(other_version).merge(krate)
+    );
+}
@@ -0,0 +1,176 @@
+use super::{merge::totals_of, Report, Totals, Weight};
+use bytesize::ByteSize;
+use horrorshow::{box_html, helper::doctype, html, Render, RenderBox, RenderOnce, TemplateBuffer};
+use std::time::SystemTime;
+
+fn page_head(title: impl Into<String>) -> Box<dyn RenderBox> {
+    let title = title.into();
+    box_html! {
+        head {
+            title: title;
+        }
+    }
+}
+
+fn page_footer() -> impl Render {
+    html! {
+        footer {
+            span {
+                : "Generated by ";
+                a(href="https://github.com/the-lean-crate/criner"): "Criner";
+                : format!(" at {}", humantime::format_rfc3339_seconds(SystemTime::now()));
+            }
+        }
+    }
+}
+
+fn size(bytes: Option<u64>) -> String {
+    bytes
+        .map(|b| ByteSize(b).to_string())
+        .unwrap_or_else(|| "unknown".into())
+}
+
+fn totals_section(totals: Totals) -> Box<dyn RenderBox> {
+    box_html! {
+        dl {
+            dt: "crate versions";
+            dd: totals.crate_versions;
+            dt: "download size";
+            dd: format!("{}", ByteSize(totals.compressed_bytes));
+            dt: "size after extraction";
+            dd: format!("{}", ByteSize(totals.uncompressed_bytes));
+            dt: "wasted";
+            dd: format!("{}", ByteSize(totals.wasted_bytes));
+        }
+        @ if totals.incomplete != 0 {
+            p: format!("Sizes are unknown for {} crate versions, the totals are a lower bound", totals.incomplete);
+        }
+    }
+}
+
+fn weight_row(name: String, version: String, weight: Weight) -> Box<dyn RenderBox> {
+    box_html! {
+        tr {
+            td: name;
+            td: version;
+            td: size(weight.compressed_bytes);
+            td: size(weight.uncompressed_bytes);
+            td: size(weight.wasted_bytes);
+        }
+    }
+}
+
+impl RenderOnce for Report {
+    fn render_once(self, tmpl: &mut TemplateBuffer<'_>)
+    where
+        Self: Sized,
+    {
+        use super::Report::*;
+        match self {
+            Version {
+                crate_name,
+                crate_version,
+                weight,
+                dependencies,
+                unresolved,
+            } => {
+                let title = format!("{}:{}", crate_name, crate_version);
+                let totals = totals_of(&weight, &dependencies);
+                tmpl << html! {
+                    : doctype::HTML;
+                    html {
+                        : page_head(title.clone());
+                        body {
+                            article {
+                                h1: title;
+                                : totals_section(totals);
+                                section(id="dependencies") {
+                                    h1: "Breakdown";
+                                    table {
+                                        tr { th: "crate"; th: "version"; th: "download size"; th: "size after extraction"; th: "wasted" }
+                                        : weight_row(crate_name, crate_version, weight);
+                                        @ for dependency in dependencies {
+                                            : weight_row(dependency.name, dependency.version, dependency.weight);
+                                        }
+                                    }
+                                }
+                                @ if !unresolved.is_empty() {
+                                    section(id="unresolved") {
+                                        h1: "Unresolved dependencies";
+                                        p: unresolved.join(", ");
+                                    }
+                                }
+                            }
+                        }
+                        : page_footer();
+                    }
+                }
+            }
+            Crate {
+                crate_name,
+                totals_by_version,
+            } => {
+                tmpl << html! {
+                    : doctype::HTML;
+                    html {
+                        : page_head(crate_name.clone());
+                        body {
+                            article {
+                                h1: crate_name;
+                                section(id="children") {
+                                    h1: "Versions";
+                                    ol {
+                                        @ for (version, totals) in totals_by_version.into_iter().rev() {
+                                            li {
+                                                h3 {
+                                                    a(href=format!("{}.html", version)): version.clone();
+                                                }
+                                                : totals_section(totals);
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        : page_footer();
+                    }
+                }
+            }
+            CrateCollection { latest_by_crate } => {
+                let title = "Crates.io Dependency Weight Report";
+                let mut heaviest: Vec<_> = latest_by_crate.into_iter().collect();
+                heaviest.sort_by_key(|(_, latest)| std::cmp::Reverse(latest.totals.compressed_bytes));
+                tmpl << html! {
+                    : doctype::HTML;
+                    html {
+                        : page_head(title);
+                        body {
+                            article {
+                                h1: title;
+                                p: format!("The size of a fresh build of the most recent version of {} crates, the heaviest first", heaviest.len());
+                                section(id="children") {
+                                    table {
+                                        tr { th: "crate"; th: "version"; th: "crate versions"; th: "download size"; th: "size after extraction"; th: "wasted" }
+                                        @ for (crate_name, latest) in heaviest {
+                                            tr {
+                                                td {
+                                                    a(href=format!("{}/index.html", crate_name)): crate_name.clone();
+                                                }
+                                                td: latest.version;
+                                                td: latest.totals.crate_versions;
+                                                td: format!("{}", ByteSize(latest.totals.compressed_bytes));
+                                                td: format!("{}", ByteSize(latest.totals.uncompressed_bytes));
+                                                td: format!("{}", ByteSize(latest.totals.wasted_bytes));
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        : page_footer();
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use super::{DependencyWeight, LatestTotals, Report, Totals, Weight};
+use crate::Result;
+use async_trait::async_trait;
+
+/// Returns true if `version` is newer than `other`, comparing them as semantic versions if possible.
+fn is_newer(version: &str, other: &str) -> bool {
+    match (semver::Version::parse(version), semver::Version::parse(other)) {
+        (Ok(version), Ok(other)) => version > other,
+        _ => version > other,
+    }
+}
+
+pub fn totals_of(weight: &Weight, dependencies: &[DependencyWeight]) -> Totals {
+    let mut totals = Totals::default();
+    totals += weight;
+    for dependency in dependencies {
+        totals += &dependency.weight;
+    }
+    totals
+}
+
+pub fn crate_from_version(version: Report) -> Report {
+    match version {
+        Report::Version {
+            crate_name,
+            crate_version,
+            weight,
+            dependencies,
+            ..
+        } => Report::Crate {
+            crate_name,
+            totals_by_version: std::iter::once((crate_version, totals_of(&weight, &dependencies))).collect(),
+        },
+        _ => unreachable!("must only be called with version variant"),
+    }
+}
+
+pub fn collection_from_crate(krate: Report) -> Report {
+    match krate {
+        version @ Report::Version { .. } => collection_from_crate(crate_from_version(version)),
+        Report::Crate {
+            crate_name,
+            totals_by_version,
+        } => {
+            let latest = totals_by_version
+                .into_iter()
+                .fold(None::<(String, Totals)>, |latest, (version, totals)| match latest {
+                    Some((ref latest_version, _)) if !is_newer(&version, latest_version) => latest,
+                    _ => Some((version, totals)),
+                });
+            Report::CrateCollection {
+                latest_by_crate: latest
+                    .map(|(version, totals)| (crate_name, LatestTotals { version, totals }))
+                    .into_iter()
+                    .collect(),
+            }
+        }
+        collection @ Report::CrateCollection { .. } => collection,
+    }
+}
+
+#[async_trait]
+impl crate::engine::report::generic::Aggregate for Report {
+    fn merge(self, other: Self) -> Self {
+        use Report::*;
+        match (self, other) {
+            (lhs @ Version { .. }, rhs) => crate_from_version(lhs).merge(rhs),
+            (lhs, rhs @ Version { .. }) => lhs.merge(crate_from_version(rhs)),
+            (
+                Crate {
+                    crate_name: lhs_crate_name,
+                    totals_by_version: mut lhs_tbv,
+                },
+                Crate {
+                    crate_name: rhs_crate_name,
+                    totals_by_version: rhs_tbv,
+                },
+            ) if lhs_crate_name == rhs_crate_name => {
+                lhs_tbv.extend(rhs_tbv);
+                Crate {
+                    crate_name: lhs_crate_name,
+                    totals_by_version: lhs_tbv,
+                }
+            }
+            (
+                CrateCollection {
+                    latest_by_crate: mut lhs_lbc,
+                },
+                CrateCollection {
+                    latest_by_crate: rhs_lbc,
+                },
+            ) => {
+                for (crate_name, rhs_latest) in rhs_lbc {
+                    match lhs_lbc.get(&crate_name) {
+                        Some(lhs_latest) if !is_newer(&rhs_latest.version, &lhs_latest.version) => {}
+                        _ => {
+                            lhs_lbc.insert(crate_name, rhs_latest);
+                        }
+                    }
+                }
+                CrateCollection {
+                    latest_by_crate: lhs_lbc,
+                }
+            }
+            (lhs, rhs) => collection_from_crate(lhs).merge(collection_from_crate(rhs)),
+        }
+    }
+
+    async fn complete(&mut self, _progress: &mut prodash::tree::Item, out: &mut Vec<u8>) -> Result<()> {
+        use horrorshow::Template;
+
+        let report = self.clone();
+        report.write_to_io(out)?;
+        Ok(())
+    }
+
+    fn crate_name(&self) -> Option<&str> {
+        match self {
+            Report::Version { crate_name, .. } | Report::Crate { crate_name, .. } => Some(crate_name),
+            Report::CrateCollection { .. } => None,
+        }
+    }
+}
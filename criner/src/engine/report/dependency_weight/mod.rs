@@ -0,0 +1,310 @@
+use crate::persistence::TableAccess;
+use crate::{
+    engine::report::waste,
+    error::Result,
+    model::{CrateVersion, TaskResult},
+    persistence,
+};
+use async_trait::async_trait;
+use once_cell::sync::OnceCell;
+use serde_derive::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+mod html;
+mod merge;
+mod resolve;
+
+pub use resolve::{latest, resolve, Index, Resolution};
+
+pub type Dict<T> = BTreeMap<String, T>;
+
+lazy_static! {
+    /// The weights of crate versions whose sizes are all known, which never change as crate versions are immutable.
+    /// It's only kept for the duration of a pass over all crates.
+    static ref WEIGHTS: parking_lot::Mutex<HashMap<(String, String), Weight>> = Default::default();
+}
+
+/// The sizes of a crate version, which are unknown if the respective data isn't available yet
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Weight {
+    /// The size of the crate archive as downloaded
+    pub compressed_bytes: Option<u64>,
+    /// The size of all files in the crate archive
+    pub uncompressed_bytes: Option<u64>,
+    /// The size of all files the waste report considers unnecessary to build the crate
+    pub wasted_bytes: Option<u64>,
+}
+
+impl Weight {
+    pub fn is_complete(&self) -> bool {
+        self.compressed_bytes.is_some() && self.uncompressed_bytes.is_some() && self.wasted_bytes.is_some()
+    }
+}
+
+/// The sum of the weights of multiple crate versions
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Totals {
+    pub crate_versions: u64,
+    pub compressed_bytes: u64,
+    pub uncompressed_bytes: u64,
+    pub wasted_bytes: u64,
+    /// The amount of crate versions with at least one unknown size, making the totals a lower bound
+    pub incomplete: u64,
+}
+
+impl std::ops::AddAssign<&Weight> for Totals {
+    fn add_assign(&mut self, rhs: &Weight) {
+        self.crate_versions += 1;
+        self.compressed_bytes += rhs.compressed_bytes.unwrap_or(0);
+        self.uncompressed_bytes += rhs.uncompressed_bytes.unwrap_or(0);
+        self.wasted_bytes += rhs.wasted_bytes.unwrap_or(0);
+        self.incomplete += !rhs.is_complete() as u64;
+    }
+}
+
+/// A crate version pulled in as dependency, along with its weight
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct DependencyWeight {
+    pub name: String,
+    pub version: String,
+    pub weight: Weight,
+}
+
+/// The totals of the most recent version of a crate
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct LatestTotals {
+    pub version: String,
+    pub totals: Totals,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub enum Report {
+    Version {
+        crate_name: String,
+        crate_version: String,
+        /// The weight of the crate version itself
+        weight: Weight,
+        /// All crate versions pulled in by building it
+        dependencies: Vec<DependencyWeight>,
+        /// Dependencies which couldn't be resolved, as `name requirement`
+        unresolved: Vec<String>,
+    },
+    Crate {
+        crate_name: String,
+        /// The weight of each version including all its dependencies
+        totals_by_version: Dict<Totals>,
+    },
+    CrateCollection {
+        /// The weight of the most recent version of each crate, including all its dependencies
+        latest_by_crate: Dict<LatestTotals>,
+    },
+}
+
+impl Report {
+    /// Create a version report from the weight of a crate version and its resolved dependencies.
+    pub fn from_weights(
+        crate_name: &str,
+        crate_version: &str,
+        weight: Weight,
+        mut dependencies: Vec<DependencyWeight>,
+        unresolved: impl IntoIterator<Item = String>,
+    ) -> Report {
+        dependencies.sort_by_key(|d| std::cmp::Reverse(d.weight.compressed_bytes));
+        Report::Version {
+            crate_name: crate_name.into(),
+            crate_version: crate_version.into(),
+            weight,
+            dependencies,
+            unresolved: unresolved.into_iter().collect(),
+        }
+    }
+}
+
+/// The index as stored in the database
+struct DbIndex {
+    crates: persistence::CrateTable,
+    crate_versions: persistence::CrateVersionTable,
+    key_buf: String,
+}
+
+impl DbIndex {
+    fn new(connection: persistence::ThreadSafeConnection) -> Self {
+        DbIndex {
+            crates: persistence::CrateTable {
+                inner: connection.clone(),
+            },
+            crate_versions: persistence::CrateVersionTable { inner: connection },
+            key_buf: String::new(),
+        }
+    }
+}
+
+impl Index for DbIndex {
+    fn versions(&mut self, name: &str) -> Result<Vec<String>> {
+        Ok(self.crates.get(name)?.map(|c| c.versions).unwrap_or_default())
+    }
+
+    fn crate_version(&mut self, name: &str, version: &str) -> Result<Option<CrateVersion>> {
+        self.key_buf.clear();
+        CrateVersion::key_from(name, version, &mut self.key_buf);
+        self.crate_versions.get(&self.key_buf)
+    }
+}
+
+/// Obtain the weight of a crate version from the crates.io database dump and the result of its extraction.
+fn weight_of(
+    connection: &persistence::ThreadSafeConnection,
+    crate_name: &str,
+    crate_version: &str,
+    key_buf: &mut String,
+) -> Result<Weight> {
+    let id = (crate_name.to_owned(), crate_version.to_owned());
+    if let Some(weight) = WEIGHTS.lock().get(&id) {
+        return Ok(*weight);
+    }
+    let mut weight = Weight {
+        compressed_bytes: waste::compressed_size_of(connection, crate_name, crate_version)?,
+        ..Default::default()
+    };
+    key_buf.clear();
+    if let Some(waste::WasteResult {
+        exploded_crate:
+            TaskResult::ExplodedCrate {
+                entries_meta_data,
+                selected_entries,
+                ..
+            },
+        ..
+    }) = <waste::Generator as super::generic::Generator>::get_result(
+        connection.clone(),
+        crate_name,
+        crate_version,
+        key_buf,
+    )? {
+        if let waste::Report::Version {
+            total_size_in_bytes,
+            wasted_files,
+            ..
+        } = waste::Report::from_package(
+            crate_name,
+            crate_version,
+            waste::TarPackage {
+                entries_meta_data,
+                entries: selected_entries,
+            },
+            waste::policy(),
+        ) {
+            weight.uncompressed_bytes = Some(total_size_in_bytes);
+            weight.wasted_bytes = Some(wasted_files.iter().map(|(_, size)| size).sum());
+        }
+    }
+    if weight.is_complete() {
+        WEIGHTS.lock().insert(id, weight);
+    }
+    Ok(weight)
+}
+
+/// The crate version to generate a report for, along with the resolution of its dependencies
+pub struct WeightResult {
+    pub weight: Weight,
+    pub dependencies: Vec<DependencyWeight>,
+    pub unresolved: Vec<String>,
+}
+
+pub struct Generator;
+
+#[async_trait]
+impl super::generic::Generator for Generator {
+    type Report = Report;
+    type DBResult = WeightResult;
+
+    fn name() -> &'static str {
+        "dependency_weight"
+    }
+
+    fn version() -> &'static str {
+        // weights include the waste, which depends on the waste policy
+        static VERSION: OnceCell<String> = OnceCell::new();
+        VERSION.get_or_init(|| waste::version_with_policy("1.1.0", waste::policy()))
+    }
+
+    fn fq_result_key(crate_name: &str, crate_version: &str, key_buf: &mut String) {
+        <waste::Generator as super::generic::Generator>::fq_result_key(crate_name, crate_version, key_buf)
+    }
+
+    /// Only the most recent version of each crate which isn't yanked or a pre-release is resolved, as that is what a
+    /// fresh build would use. All other versions are marked done, so they aren't looked at again.
+    fn selected_versions(
+        connection: persistence::ThreadSafeConnection,
+        crate_name: &str,
+    ) -> Result<Option<Vec<String>>> {
+        Ok(Some(
+            latest(&mut DbIndex::new(connection), crate_name)?
+                .map(|latest| latest.version)
+                .into_iter()
+                .collect(),
+        ))
+    }
+
+    /// Dependencies are resolved to what's known at the time the report is generated.
+    fn get_result(
+        connection: persistence::ThreadSafeConnection,
+        crate_name: &str,
+        crate_version: &str,
+        key_buf: &mut String,
+    ) -> Result<Option<WeightResult>> {
+        let mut index = DbIndex::new(connection.clone());
+        let root = match index.crate_version(crate_name, crate_version)? {
+            Some(root) => root,
+            None => return Ok(None),
+        };
+        let weight = weight_of(&connection, crate_name, crate_version, key_buf)?;
+        if weight.uncompressed_bytes.is_none() {
+            // not extracted yet, try again next time
+            return Ok(None);
+        }
+
+        let Resolution { resolved, unresolved } = resolve(&root, &mut index)?;
+        let mut dependencies = Vec::with_capacity(resolved.len());
+        for (name, version) in resolved {
+            let weight = weight_of(&connection, &name, &version, key_buf)?;
+            dependencies.push(DependencyWeight { name, version, weight });
+        }
+        Ok(Some(WeightResult {
+            weight,
+            dependencies,
+            unresolved: unresolved.into_iter().collect(),
+        }))
+    }
+
+    fn finish() {
+        *WEIGHTS.lock() = HashMap::new();
+    }
+
+    /// Reports with unknown weights are generated again until all weights are known.
+    fn is_final(result: &WeightResult) -> bool {
+        result.weight.is_complete() && result.dependencies.iter().all(|d| d.weight.is_complete())
+    }
+
+    async fn generate_report(
+        crate_name: &str,
+        crate_version: &str,
+        WeightResult {
+            weight,
+            dependencies,
+            unresolved,
+        }: WeightResult,
+        _progress: &mut prodash::tree::Item,
+    ) -> Result<Self::Report> {
+        Ok(Report::from_weights(
+            crate_name,
+            crate_version,
+            weight,
+            dependencies,
+            unresolved,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod report_test;
@@ -0,0 +1,128 @@
+use crate::engine::report::{
+    dependency_weight::{DependencyWeight, Generator, LatestTotals, Report, Totals, Weight, WeightResult},
+    generic::{self, Aggregate},
+};
+use common_macros::b_tree_map;
+
+fn weight(compressed: u64) -> Weight {
+    Weight {
+        compressed_bytes: Some(compressed),
+        uncompressed_bytes: Some(compressed * 4),
+        wasted_bytes: Some(compressed),
+    }
+}
+
+fn version(crate_name: &str, crate_version: &str, dependencies: &[u64]) -> Report {
+    Report::from_weights(
+        crate_name,
+        crate_version,
+        weight(1),
+        dependencies
+            .iter()
+            .enumerate()
+            .map(|(i, size)| DependencyWeight {
+                name: format!("dep{}", i),
+                version: "1.0.0".into(),
+                weight: weight(*size),
+            })
+            .collect(),
+        Vec::new(),
+    )
+}
+
+fn totals(crate_versions: u64, compressed: u64) -> Totals {
+    Totals {
+        crate_versions,
+        compressed_bytes: compressed,
+        uncompressed_bytes: compressed * 4,
+        wasted_bytes: compressed,
+        incomplete: 0,
+    }
+}
+
+#[test]
+fn dependencies_are_sorted_by_size_and_unknown_sizes_are_counted() {
+    let report = Report::from_weights(
+        "a",
+        "1.0.0",
+        weight(1),
+        vec![
+            DependencyWeight {
+                name: "small".into(),
+                version: "1.0.0".into(),
+                weight: weight(1),
+            },
+            DependencyWeight {
+                name: "unknown".into(),
+                version: "1.0.0".into(),
+                weight: Weight::default(),
+            },
+            DependencyWeight {
+                name: "large".into(),
+                version: "1.0.0".into(),
+                weight: weight(10),
+            },
+        ],
+        Vec::new(),
+    );
+    match &report {
+        Report::Version { dependencies, .. } => assert_eq!(
+            dependencies.iter().map(|d| d.name.as_str()).collect::<Vec<_>>(),
+            vec!["large", "small", "unknown"]
+        ),
+        _ => unreachable!("a version report"),
+    }
+    assert_eq!(
+        report.merge(version("a", "0.1.0", &[])),
+        Report::Crate {
+            crate_name: "a".into(),
+            totals_by_version: b_tree_map! {
+                "0.1.0".into() => totals(1, 1),
+                "1.0.0".into() => Totals {
+                    incomplete: 1,
+                    ..totals(4, 12)
+                },
+            }
+        }
+    );
+}
+
+#[test]
+fn collections_keep_the_most_recent_version_of_each_crate() {
+    assert_eq!(
+        version("a", "0.10.0", &[5])
+            .merge(version("a", "0.9.0", &[1, 2]))
+            .merge(version("b", "1.0.0", &[]))
+            .merge(version("a", "0.2.0", &[])),
+        Report::CrateCollection {
+            latest_by_crate: b_tree_map! {
+                "a".into() => LatestTotals {
+                    version: "0.10.0".into(),
+                    totals: totals(2, 6),
+                },
+                "b".into() => LatestTotals {
+                    version: "1.0.0".into(),
+                    totals: totals(1, 1),
+                },
+            }
+        }
+    );
+}
+
+#[test]
+fn reports_with_unknown_weights_are_not_final() {
+    let result = |dependency_weight: Weight| WeightResult {
+        weight: weight(1),
+        dependencies: vec![DependencyWeight {
+            name: "dep".into(),
+            version: "1.0.0".into(),
+            weight: dependency_weight,
+        }],
+        unresolved: Vec::new(),
+    };
+    assert!(<Generator as generic::Generator>::is_final(&result(weight(2))));
+    assert!(!<Generator as generic::Generator>::is_final(&result(Weight {
+        wasted_bytes: None,
+        ..weight(2)
+    })));
+}
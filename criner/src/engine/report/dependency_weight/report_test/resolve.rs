@@ -0,0 +1,184 @@
+use crate::{
+    engine::report::dependency_weight::{latest, resolve, Index, Resolution},
+    model::{ChangeKind, CrateVersion, Dependency},
+    Result,
+};
+use common_macros::{b_tree_set, hash_map};
+use std::collections::BTreeMap;
+
+#[derive(Default)]
+struct MemoryIndex(BTreeMap<String, Vec<CrateVersion>>);
+
+impl MemoryIndex {
+    fn with(mut self, krate: CrateVersion) -> Self {
+        self.0.entry(krate.name.clone()).or_default().push(krate);
+        self
+    }
+}
+
+impl Index for MemoryIndex {
+    fn versions(&mut self, name: &str) -> Result<Vec<String>> {
+        Ok(self
+            .0
+            .get(name)
+            .map(|versions| versions.iter().map(|v| v.version.clone()).collect())
+            .unwrap_or_default())
+    }
+
+    fn crate_version(&mut self, name: &str, version: &str) -> Result<Option<CrateVersion>> {
+        Ok(self
+            .0
+            .get(name)
+            .and_then(|versions| versions.iter().find(|v| v.version == version).cloned()))
+    }
+}
+
+fn krate(name: &str, version: &str, dependencies: Vec<Dependency>) -> CrateVersion {
+    CrateVersion {
+        name: name.into(),
+        kind: ChangeKind::Added,
+        version: version.into(),
+        checksum: String::new(),
+        features: Default::default(),
+        dependencies,
+    }
+}
+
+fn dep(name: &str, required_version: &str) -> Dependency {
+    Dependency {
+        name: name.into(),
+        required_version: required_version.into(),
+        features: Vec::new(),
+        optional: false,
+        default_features: true,
+        target: None,
+        kind: Some("normal".into()),
+        package: None,
+    }
+}
+
+fn resolved(items: &[(&str, &str)]) -> Resolution {
+    Resolution {
+        resolved: items.iter().map(|(n, v)| (n.to_string(), v.to_string())).collect(),
+        unresolved: Default::default(),
+    }
+}
+
+#[test]
+fn newest_matching_versions_which_are_not_yanked_are_used_transitively() {
+    let mut yanked = krate("b", "1.2.0", Vec::new());
+    yanked.kind = ChangeKind::Yanked;
+    let mut index = MemoryIndex::default()
+        .with(krate("b", "1.0.0", Vec::new()))
+        .with(krate("b", "1.1.0", vec![dep("c", "0.1")]))
+        .with(yanked)
+        .with(krate("b", "2.0.0", Vec::new()))
+        .with(krate("c", "0.1.5", Vec::new()))
+        .with(krate("c", "0.2.0", Vec::new()));
+    let root = krate("a", "1.0.0", vec![dep("b", "1")]);
+    assert_eq!(
+        resolve(&root, &mut index).unwrap(),
+        resolved(&[("b", "1.1.0"), ("c", "0.1.5")])
+    );
+}
+
+#[test]
+fn latest_versions_are_neither_yanked_nor_pre_releases_unless_there_is_nothing_else() {
+    let mut yanked = krate("a", "1.2.0", Vec::new());
+    yanked.kind = ChangeKind::Yanked;
+    let mut index = MemoryIndex::default()
+        .with(krate("a", "1.1.0", Vec::new()))
+        .with(krate("a", "1.10.0-alpha.1", Vec::new()))
+        .with(krate("a", "1.9.0", Vec::new()))
+        .with(yanked)
+        .with(krate("b", "0.1.0-beta.1", Vec::new()))
+        .with(krate("b", "0.1.0-beta.2", Vec::new()));
+    let version_of = |index: &mut MemoryIndex, name: &str| latest(index, name).unwrap().map(|k| k.version);
+    assert_eq!(version_of(&mut index, "a"), Some("1.9.0".into()));
+    assert_eq!(version_of(&mut index, "b"), Some("0.1.0-beta.2".into()));
+    assert_eq!(version_of(&mut index, "c"), None);
+}
+
+#[test]
+fn dev_dependencies_and_disabled_optional_dependencies_are_skipped() {
+    let mut dev = dep("dev", "1");
+    dev.kind = Some("dev".into());
+    let mut build = dep("build", "1");
+    build.kind = Some("build".into());
+    let mut optional = dep("optional", "1");
+    optional.optional = true;
+    let mut index = MemoryIndex::default()
+        .with(krate("dev", "1.0.0", Vec::new()))
+        .with(krate("build", "1.0.0", Vec::new()))
+        .with(krate("optional", "1.0.0", Vec::new()));
+    let root = krate("a", "1.0.0", vec![dev, build, optional]);
+    assert_eq!(resolve(&root, &mut index).unwrap(), resolved(&[("build", "1.0.0")]));
+}
+
+#[test]
+fn target_specific_dependencies_are_skipped() {
+    let mut windows = dep("windows", "1");
+    windows.target = Some("cfg(windows)".into());
+    let mut index = MemoryIndex::default()
+        .with(krate("windows", "1.0.0", Vec::new()))
+        .with(krate("b", "1.0.0", Vec::new()));
+    let root = krate("a", "1.0.0", vec![windows, dep("b", "1")]);
+    assert_eq!(resolve(&root, &mut index).unwrap(), resolved(&[("b", "1.0.0")]));
+}
+
+#[test]
+fn features_enable_optional_dependencies_and_features_of_dependencies() {
+    let mut implicit = dep("implicit", "1");
+    implicit.optional = true;
+    let mut explicit = dep("explicit", "1");
+    explicit.optional = true;
+    let mut renamed = dep("alias", "1");
+    renamed.package = Some("real".into());
+    let mut root = krate("a", "1.0.0", vec![implicit, explicit, renamed]);
+    root.features = hash_map! {
+        "default".into() => vec!["std".into(), "implicit".into()],
+        "std".into() => vec!["dep:explicit".into(), "alias/extra".into()],
+    };
+
+    let mut gated = dep("gated", "1");
+    gated.optional = true;
+    let mut real = krate("real", "1.0.0", vec![gated]);
+    real.features = hash_map! {
+        "extra".into() => vec!["gated".into()],
+    };
+    let mut index = MemoryIndex::default()
+        .with(krate("implicit", "1.0.0", Vec::new()))
+        .with(krate("explicit", "1.0.0", Vec::new()))
+        .with(krate("gated", "1.0.0", Vec::new()))
+        .with(real);
+    assert_eq!(
+        resolve(&root, &mut index).unwrap(),
+        resolved(&[
+            ("explicit", "1.0.0"),
+            ("gated", "1.0.0"),
+            ("implicit", "1.0.0"),
+            ("real", "1.0.0")
+        ])
+    );
+}
+
+#[test]
+fn unknown_crates_and_requirements_are_unresolved() {
+    let mut index = MemoryIndex::default().with(krate("b", "1.0.0", Vec::new()));
+    let root = krate(
+        "a",
+        "1.0.0",
+        vec![dep("b", "2"), dep("missing", "1"), dep("b", "not a requirement")],
+    );
+    assert_eq!(
+        resolve(&root, &mut index).unwrap(),
+        Resolution {
+            resolved: Default::default(),
+            unresolved: b_tree_set! {
+                "b 2".to_string(),
+                "b not a requirement".to_string(),
+                "missing 1".to_string(),
+            },
+        }
+    );
+}
@@ -0,0 +1,183 @@
+use crate::{error::Result, model::CrateVersion};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// The feature every dependency enables unless `default-features = false` is set
+const DEFAULT_FEATURE: &str = "default";
+
+/// Provides access to what the crates.io index knows about crates.
+pub trait Index {
+    /// All known versions of the crate `name`
+    fn versions(&mut self, name: &str) -> Result<Vec<String>>;
+    /// The crate version `version` of crate `name`, if it is known
+    fn crate_version(&mut self, name: &str, version: &str) -> Result<Option<CrateVersion>>;
+}
+
+/// All crate versions a fresh build of a crate version pulls in.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Resolution {
+    /// All resolved crate versions as `(name, version)`, without the crate version they were resolved for
+    pub resolved: BTreeSet<(String, String)>,
+    /// Dependencies which couldn't be resolved, as `name requirement`
+    pub unresolved: BTreeSet<String>,
+}
+
+/// The optional dependencies and the features of dependencies that are enabled by `enabled` features.
+struct EnabledFeatures {
+    optional_dependencies: BTreeSet<String>,
+    /// Features to enable in dependencies, by the name of the dependency in the manifest
+    dependency_features: BTreeMap<String, BTreeSet<String>>,
+}
+
+/// Expand the `enabled` features using the feature table of `krate`.
+fn enabled_features(krate: &CrateVersion, enabled: &BTreeSet<String>) -> EnabledFeatures {
+    let mut seen = BTreeSet::new();
+    let mut queue: Vec<&str> = enabled.iter().map(String::as_str).collect();
+    let mut out = EnabledFeatures {
+        optional_dependencies: BTreeSet::new(),
+        dependency_features: BTreeMap::new(),
+    };
+    while let Some(feature) = queue.pop() {
+        if !seen.insert(feature) {
+            continue;
+        }
+        match krate.features.get(feature) {
+            Some(enables) => {
+                for item in enables.iter() {
+                    if let Some(dependency) = item.strip_prefix("dep:") {
+                        out.optional_dependencies.insert(dependency.into());
+                    } else if let Some((dependency, dependency_feature)) = item.split_once('/') {
+                        let dependency = match dependency.strip_suffix('?') {
+                            Some(weak_dependency) => weak_dependency,
+                            None => {
+                                out.optional_dependencies.insert(dependency.into());
+                                dependency
+                            }
+                        };
+                        out.dependency_features
+                            .entry(dependency.into())
+                            .or_default()
+                            .insert(dependency_feature.into());
+                    } else {
+                        queue.push(item);
+                    }
+                }
+            }
+            // Optional dependencies are implicit features unless they are referred to with `dep:`
+            None if feature != DEFAULT_FEATURE => {
+                out.optional_dependencies.insert(feature.into());
+            }
+            None => {}
+        }
+    }
+    out
+}
+
+/// Returns the most recent version of `name` matching `requirement` which isn't yanked.
+fn resolve_requirement(index: &mut impl Index, name: &str, requirement: &str) -> Result<Option<CrateVersion>> {
+    let requirement = match semver::VersionReq::parse(requirement) {
+        Ok(requirement) => requirement,
+        Err(_) => return Ok(None),
+    };
+    let mut candidates: Vec<_> = index
+        .versions(name)?
+        .into_iter()
+        .filter_map(|v| semver::Version::parse(&v).ok().map(|parsed| (parsed, v)))
+        .filter(|(parsed, _)| requirement.matches(parsed))
+        .collect();
+    candidates.sort();
+    for (_, version) in candidates.into_iter().rev() {
+        if let Some(krate) = index.crate_version(name, &version)? {
+            if krate.kind != crate::model::ChangeKind::Yanked {
+                return Ok(Some(krate));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Returns the most recent version of `name` which isn't yanked, as `cargo install` would pick it.
+/// Pre-releases are only considered if there is nothing but pre-releases.
+pub fn latest(index: &mut impl Index, name: &str) -> Result<Option<CrateVersion>> {
+    let mut candidates: Vec<_> = index
+        .versions(name)?
+        .into_iter()
+        .filter_map(|v| semver::Version::parse(&v).ok().map(|parsed| (parsed, v)))
+        .collect();
+    candidates.sort_by(|(a, _), (b, _)| (a.pre.is_empty(), a).cmp(&(b.pre.is_empty(), b)));
+    for (_, version) in candidates.into_iter().rev() {
+        if let Some(krate) = index.crate_version(name, &version)? {
+            if krate.kind != crate::model::ChangeKind::Yanked {
+                return Ok(Some(krate));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Resolve all crate versions which are pulled in by building `root` with its default features, that is its
+/// normal and build dependencies, transitively, with optional dependencies as enabled by features.
+/// Each requirement resolves to the most recent version matching it, similar to what a fresh `cargo build` does.
+/// Dependencies which are only built for certain targets are left out, as if building for a target none of them
+/// applies to.
+pub fn resolve(root: &CrateVersion, index: &mut impl Index) -> Result<Resolution> {
+    let mut resolution = Resolution::default();
+    let root_id = (root.name.clone(), root.version.clone());
+    let mut features_by_crate = BTreeMap::<(String, String), BTreeSet<String>>::new();
+    let mut crates = BTreeMap::<(String, String), CrateVersion>::new();
+    features_by_crate.insert(root_id.clone(), std::iter::once(DEFAULT_FEATURE.to_owned()).collect());
+    crates.insert(root_id.clone(), root.clone());
+    let mut queue = vec![root_id.clone()];
+
+    while let Some(id) = queue.pop() {
+        let krate = &crates[&id];
+        let enabled = enabled_features(krate, &features_by_crate[&id]);
+        let mut edges = Vec::new();
+        for dependency in krate.dependencies.iter() {
+            if dependency.kind.as_deref() == Some("dev")
+                || dependency.target.is_some()
+                || (dependency.optional && !enabled.optional_dependencies.contains(&dependency.name))
+            {
+                continue;
+            }
+            let mut features: BTreeSet<String> = dependency.features.iter().cloned().collect();
+            if dependency.default_features {
+                features.insert(DEFAULT_FEATURE.into());
+            }
+            features.extend(
+                enabled
+                    .dependency_features
+                    .get(&dependency.name)
+                    .into_iter()
+                    .flatten()
+                    .cloned(),
+            );
+            edges.push((
+                dependency.package.clone().unwrap_or_else(|| dependency.name.clone()),
+                dependency.required_version.clone(),
+                features,
+            ));
+        }
+
+        for (name, requirement, features) in edges {
+            let krate = match resolve_requirement(index, &name, &requirement)? {
+                Some(krate) => krate,
+                None => {
+                    resolution.unresolved.insert(format!("{} {}", name, requirement));
+                    continue;
+                }
+            };
+            let dependency_id = (krate.name.clone(), krate.version.clone());
+            if dependency_id == root_id {
+                continue;
+            }
+            crates.entry(dependency_id.clone()).or_insert(krate);
+            let enabled = features_by_crate.entry(dependency_id.clone()).or_default();
+            let num_features = enabled.len();
+            enabled.extend(features);
+            if resolution.resolved.insert(dependency_id.clone()) || enabled.len() != num_features {
+                queue.push(dependency_id);
+            }
+        }
+    }
+    Ok(resolution)
+}
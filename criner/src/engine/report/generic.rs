@@ -1,12 +1,10 @@
 use crate::persistence::{CrateVersionTable, TableAccess};
-use crate::{
-    error::Result,
-    model, persistence,
-    persistence::{new_key_insertion, ReportsTree},
-};
+use crate::{error::Result, model, persistence, persistence::ReportsTree};
 use async_trait::async_trait;
-use rusqlite::{params, TransactionBehavior};
-use std::path::{Path, PathBuf};
+use std::{
+    convert::TryFrom,
+    path::{Path, PathBuf},
+};
 
 fn all_but_recently_yanked(
     crate_name: &str,
@@ -48,21 +46,29 @@ pub type WriteCallbackState = Option<async_channel::Sender<WriteRequest>>;
 pub type WriteCallback =
     fn(WriteRequest, &WriteCallbackState) -> futures_util::future::BoxFuture<Result<WriteInstruction>>;
 
+/// The prefix of the file storing the state of the report covering all crates
+const TOP_LEVEL_REPORT_NAME: &str = "__top-level-report__";
+
 #[async_trait]
 pub trait Aggregate
 where
-    Self: Sized,
+    Self: Sized + serde::Serialize + serde::de::DeserializeOwned,
 {
     fn merge(self, other: Self) -> Self;
     async fn complete(&mut self, progress: &mut prodash::tree::Item, out: &mut Vec<u8>) -> Result<()>;
-    async fn load_previous_state(&self, out_dir: &Path, progress: &mut prodash::tree::Item) -> Option<Self>;
-    async fn load_previous_top_level_state(out_dir: &Path, progress: &mut prodash::tree::Item) -> Option<Self>;
-    async fn store_current_state(&self, out_dir: &Path, progress: &mut prodash::tree::Item) -> Result<()>;
+    /// Write the report as JSON into `out` to be stored next to its HTML page, and return true,
+    /// or return false if there is no JSON representation.
+    fn complete_json(&self, out: &mut Vec<u8>) -> Result<bool> {
+        serde_json::to_writer_pretty(out, self)?;
+        Ok(true)
+    }
+    /// The name of the crate this report is about, or None if it covers multiple crates.
+    fn crate_name(&self) -> Option<&str>;
 }
 
 #[async_trait]
 pub trait Generator {
-    type Report: Aggregate + Send + Sync + Clone;
+    type Report: Aggregate + Send + Sync + Clone + 'static;
     type DBResult: Send;
 
     fn name() -> &'static str;
@@ -80,6 +86,58 @@ pub trait Generator {
         key_buf: &mut String,
     ) -> Result<Option<Self::DBResult>>;
 
+    /// Return the versions of `crate_name` to generate reports for, or None to generate reports for all of them.
+    /// The reports of all other versions are marked done without generating them.
+    fn selected_versions(
+        _connection: persistence::ThreadSafeConnection,
+        _crate_name: &str,
+    ) -> Result<Option<Vec<String>>> {
+        Ok(None)
+    }
+
+    /// Whether the report generated from `result` will never change, which allows to mark it done and skip it from now on.
+    fn is_final(_result: &Self::DBResult) -> bool {
+        true
+    }
+
+    /// Called once all reports of a pass were merged, to drop what was cached while generating them.
+    fn finish() {}
+
+    fn state_path(out_dir: &Path, prefix: &str) -> PathBuf {
+        out_dir.join(format!("{}-{}-{}.rmp", prefix, Self::name(), Self::version()))
+    }
+
+    async fn load_previous_top_level_state(out_dir: &Path, progress: &mut prodash::tree::Item) -> Option<Self::Report> {
+        progress.blocked("loading previous top-level report from disk", None);
+        load_state(Self::state_path(out_dir, TOP_LEVEL_REPORT_NAME)).await
+    }
+
+    async fn load_previous_state(
+        report: &Self::Report,
+        out_dir: &Path,
+        progress: &mut prodash::tree::Item,
+    ) -> Option<Self::Report> {
+        progress.blocked("loading previous report from disk", None);
+        load_state(Self::state_path(
+            out_dir,
+            report.crate_name().unwrap_or(TOP_LEVEL_REPORT_NAME),
+        ))
+        .await
+    }
+
+    async fn store_current_state(
+        report: &Self::Report,
+        out_dir: &Path,
+        progress: &mut prodash::tree::Item,
+    ) -> Result<()> {
+        let path = Self::state_path(out_dir, report.crate_name().unwrap_or(TOP_LEVEL_REPORT_NAME));
+        progress.blocked("storing current report to disk", None);
+        let data = rmp_serde::to_vec(report)?;
+        blocking::unblock(move || std::fs::write(path, data))
+            .await
+            .map_err(Into::into)
+    }
+
     async fn merge_reports(
         out_dir: PathBuf,
         cache_dir: Option<PathBuf>,
@@ -108,9 +166,9 @@ pub trait Generator {
         }
         if let Some(mut report) = report {
             let previous_report = match cache_dir.as_ref() {
-                Some(cd) => match Self::Report::load_previous_top_level_state(cd, &mut progress).await {
+                Some(cd) => match Self::load_previous_top_level_state(cd, &mut progress).await {
                     Some(r) => Some(r),
-                    None => report.load_previous_state(cd, &mut progress).await,
+                    None => Self::load_previous_state(&report, cd, &mut progress).await,
                 },
                 None => None,
             };
@@ -130,7 +188,7 @@ pub trait Generator {
                 .await?;
             }
             if let Some(cd) = cache_dir {
-                report.store_current_state(&cd, &mut progress).await?;
+                Self::store_current_state(&report, &cd, &mut progress).await?;
             }
         }
         Ok(())
@@ -162,11 +220,12 @@ pub trait Generator {
             let mut key_buf = String::with_capacity(32);
             // delaying writes works because we don't have overlap on work
             for (name, krate) in krates.into_iter() {
-                let c: model::Crate = krate.as_slice().into();
+                let c = model::Crate::try_from(krate.as_slice())?;
                 let crate_dir = crate_dir(&out_dir, &name);
                 progress.init(Some(c.versions.len()), Some("versions".into()));
                 progress.set_name(&name);
 
+                let selected_versions = Self::selected_versions(connection.clone(), &name)?;
                 let mut crate_report = None::<Self::Report>;
                 for version in c.versions.iter().take(all_but_recently_yanked(
                     &name,
@@ -187,7 +246,13 @@ pub trait Generator {
                         let reports_key = key_buf.clone();
                         key_buf.clear();
 
+                        if selected_versions.as_ref().is_some_and(|s| !s.contains(version)) {
+                            reports_to_mark_done.push(reports_key);
+                            continue;
+                        }
+
                         if let Some(result) = Self::get_result(connection.clone(), &name, version, &mut key_buf)? {
+                            let is_final = Self::is_final(&result);
                             let mut version_report =
                                 Self::generate_report(&name, version, result, &mut progress).await?;
 
@@ -206,13 +271,15 @@ pub trait Generator {
                                 None => version_report,
                             });
 
-                            reports_to_mark_done.push(reports_key);
+                            if is_final {
+                                reports_to_mark_done.push(reports_key);
+                            }
                         }
                     }
                 }
                 if let Some(mut crate_report) = crate_report {
                     let previous_state = match cache_dir.as_ref() {
-                        Some(cd) => crate_report.load_previous_state(cd, &mut progress).await,
+                        Some(cd) => Self::load_previous_state(&crate_report, cd, &mut progress).await,
                         None => None,
                     };
                     match previous_state {
@@ -228,7 +295,7 @@ pub trait Generator {
                             )
                             .await?;
                             if let Some(cd) = cache_dir.as_ref() {
-                                absolute_state.store_current_state(cd, &mut progress).await?;
+                                Self::store_current_state(&absolute_state, cd, &mut progress).await?;
                             };
                         }
                         None => {
@@ -242,7 +309,7 @@ pub trait Generator {
                             )
                             .await?;
                             if let Some(cd) = cache_dir.as_ref() {
-                                crate_report.store_current_state(cd, &mut progress).await?;
+                                Self::store_current_state(&crate_report, cd, &mut progress).await?;
                             }
                         }
                     }
@@ -255,26 +322,33 @@ pub trait Generator {
         }
 
         if !reports_to_mark_done.is_empty() {
-            let mut connection = db.open_connection_no_async_with_busy_wait()?;
+            let reports = ReportsTree {
+                inner: db.open_connection_with_busy_wait()?,
+            };
             progress.blocked("wait for write lock", None);
-            let transaction = connection.transaction_with_behavior(TransactionBehavior::Immediate)?;
             progress.init(
                 Some(reports_to_mark_done.len()),
                 Some("report done markers written".into()),
             );
-            {
-                let mut statement = new_key_insertion(ReportsTree::table_name(), &transaction)?;
-                for key in reports_to_mark_done.iter() {
-                    statement.execute(params![key])?;
-                    progress.inc();
-                }
-            }
-            transaction.commit()?;
+            reports.mark_done(reports_to_mark_done.iter().map(|key| {
+                progress.inc();
+                key.as_str()
+            }))?;
         }
         Ok(chunk_report)
     }
 }
 
+async fn load_state<T>(path: PathBuf) -> Option<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    blocking::unblock(move || std::fs::read(path))
+        .await
+        .ok()
+        .and_then(|v| rmp_serde::from_read(v.as_slice()).ok())
+}
+
 fn crate_dir(base: &Path, crate_name: &str) -> PathBuf {
     base.join(crate_name)
 }
@@ -296,11 +370,25 @@ async fn complete_and_write_report(
 ) -> Result<Vec<u8>> {
     out.clear();
     report.complete(progress, &mut out).await?;
+    let mut json = Vec::new();
+    if report.complete_json(&mut json)? {
+        write_report(json, progress, path.as_ref().with_extension("json"), write, write_state).await?;
+    }
+    write_report(out, progress, path, write, write_state).await
+}
+
+async fn write_report(
+    content: Vec<u8>,
+    progress: &mut prodash::tree::Item,
+    path: impl AsRef<Path>,
+    write: WriteCallback,
+    write_state: &WriteCallbackState,
+) -> Result<Vec<u8>> {
     progress.blocked("sending report to writer", None);
     match write(
         WriteRequest {
             path: path.as_ref().to_path_buf(),
-            content: out,
+            content,
         },
         write_state,
     )
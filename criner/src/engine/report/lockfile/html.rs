@@ -0,0 +1,196 @@
+use super::{Dict, Issues, Lockfile, Report};
+use horrorshow::{box_html, helper::doctype, html, Render, RenderBox, RenderOnce, TemplateBuffer};
+use std::time::SystemTime;
+
+fn page_head(title: impl Into<String>) -> Box<dyn RenderBox> {
+    let title = title.into();
+    box_html! {
+        head {
+            title: title;
+        }
+    }
+}
+
+fn page_footer() -> impl Render {
+    html! {
+        footer {
+            span {
+                : "Generated by ";
+                a(href="https://github.com/the-lean-crate/criner"): "Criner";
+                : format!(" at {}", humantime::format_rfc3339_seconds(SystemTime::now()));
+            }
+        }
+    }
+}
+
+fn issues_section(issues: Issues) -> Box<dyn RenderBox> {
+    box_html! {
+        @ if issues.is_empty() {
+            p: "All pinned crate versions are fine"
+        } else {
+            dl {
+                @ if !issues.yanked.is_empty() {
+                    dt: "yanked";
+                    dd: issues.yanked.join(", ");
+                }
+                @ if !issues.checksum_mismatches.is_empty() {
+                    dt: "checksum differs from the crates.io index";
+                    dd: issues.checksum_mismatches.join(", ");
+                }
+            }
+        }
+    }
+}
+
+/// Describe how many versions ship a lockfile, and how many of these have a binary target.
+fn lockfile_summary<'a>(versions_without_lockfile: u64, lockfiles: impl Iterator<Item = &'a Lockfile>) -> String {
+    let (versions_with_lockfile, binary_versions_with_lockfile) = lockfiles.fold((0, 0), |(total, binary), l| {
+        (total + 1, binary + l.has_binary_target as u64)
+    });
+    format!(
+        "{} of {} versions ship a Cargo.lock file, {} of which have a binary target",
+        versions_with_lockfile,
+        versions_with_lockfile + versions_without_lockfile,
+        binary_versions_with_lockfile
+    )
+}
+
+/// Only binary versions with issues are of interest, as only binaries use their lockfile.
+fn binary_issues(lockfile_by_version: Dict<Lockfile>) -> Dict<Issues> {
+    lockfile_by_version
+        .into_iter()
+        .filter(|(_, l)| l.has_binary_target && !l.issues.is_empty())
+        .map(|(version, l)| (version, l.issues))
+        .collect()
+}
+
+fn versions_section(issues_by_version: Dict<Issues>, link_prefix: String) -> Box<dyn RenderBox> {
+    box_html! {
+        ol {
+            @ for (version, issues) in issues_by_version.into_iter().rev() {
+                li {
+                    h3 {
+                        a(href=format!("{}{}.html", link_prefix, version)): version.clone();
+                    }
+                    : issues_section(issues);
+                }
+            }
+        }
+    }
+}
+
+impl RenderOnce for Report {
+    fn render_once(self, tmpl: &mut TemplateBuffer<'_>)
+    where
+        Self: Sized,
+    {
+        use super::Report::*;
+        match self {
+            Version {
+                crate_name,
+                crate_version,
+                has_lockfile,
+                has_binary_target,
+                issues,
+            } => {
+                let title = format!("{}:{}", crate_name, crate_version);
+                tmpl << html! {
+                    : doctype::HTML;
+                    html {
+                        : page_head(title.clone());
+                        body {
+                            article {
+                                h1: title;
+                                @ if has_lockfile {
+                                    p: if has_binary_target {
+                                        "Ships a Cargo.lock file along with a binary target"
+                                    } else {
+                                        "Ships a Cargo.lock file, but has no binary target to use it"
+                                    };
+                                    : issues_section(issues);
+                                } else {
+                                    p: "No Cargo.lock file"
+                                }
+                            }
+                        }
+                        : page_footer();
+                    }
+                }
+            }
+            Crate {
+                crate_name,
+                versions_without_lockfile,
+                lockfile_by_version,
+            } => {
+                let summary = lockfile_summary(versions_without_lockfile, lockfile_by_version.values());
+                let issues_by_version = binary_issues(lockfile_by_version);
+                tmpl << html! {
+                    : doctype::HTML;
+                    html {
+                        : page_head(crate_name.clone());
+                        body {
+                            article {
+                                h1: crate_name;
+                                p: summary;
+                                section(id="children") {
+                                    h1: "Binary versions with problematic pins";
+                                    @ if issues_by_version.is_empty() {
+                                        p: "None"
+                                    } else {
+                                        : versions_section(issues_by_version, String::new());
+                                    }
+                                }
+                            }
+                        }
+                        : page_footer();
+                    }
+                }
+            }
+            CrateCollection {
+                versions_without_lockfile,
+                lockfile_by_crate,
+            } => {
+                let title = "Crates.io Lockfile Report";
+                let summary = lockfile_summary(
+                    versions_without_lockfile,
+                    lockfile_by_crate.values().flat_map(|l| l.values()),
+                );
+                let issues_by_crate: Dict<_> = lockfile_by_crate
+                    .into_iter()
+                    .map(|(crate_name, lockfile_by_version)| (crate_name, binary_issues(lockfile_by_version)))
+                    .filter(|(_, issues_by_version)| !issues_by_version.is_empty())
+                    .collect();
+                tmpl << html! {
+                    : doctype::HTML;
+                    html {
+                        : page_head(title);
+                        body {
+                            article {
+                                h1: title;
+                                p: summary;
+                                section(id="children") {
+                                    h1: "Binary crates with problematic pins";
+                                    @ if issues_by_crate.is_empty() {
+                                        p: "None"
+                                    } else {
+                                        ol {
+                                            @ for (crate_name, issues_by_version) in issues_by_crate {
+                                                li {
+                                                    h2 {
+                                                        a(href=format!("{}/index.html", crate_name)): crate_name.clone();
+                                                    }
+                                                    : versions_section(issues_by_version, format!("{}/", crate_name));
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        : page_footer();
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use super::{Dict, Lockfile, Report};
+use crate::Result;
+use async_trait::async_trait;
+
+pub fn crate_from_version(version: Report) -> Report {
+    match version {
+        Report::Version {
+            crate_name,
+            crate_version,
+            has_lockfile,
+            has_binary_target,
+            issues,
+        } => Report::Crate {
+            crate_name,
+            versions_without_lockfile: !has_lockfile as u64,
+            lockfile_by_version: if has_lockfile {
+                std::iter::once((
+                    crate_version,
+                    Lockfile {
+                        has_binary_target,
+                        issues,
+                    },
+                ))
+                .collect()
+            } else {
+                Dict::new()
+            },
+        },
+        _ => unreachable!("must only be called with version variant"),
+    }
+}
+
+pub fn collection_from_crate(krate: Report) -> Report {
+    match krate {
+        version @ Report::Version { .. } => collection_from_crate(crate_from_version(version)),
+        Report::Crate {
+            crate_name,
+            versions_without_lockfile,
+            lockfile_by_version,
+        } => Report::CrateCollection {
+            versions_without_lockfile,
+            lockfile_by_crate: if lockfile_by_version.is_empty() {
+                Dict::new()
+            } else {
+                std::iter::once((crate_name, lockfile_by_version)).collect()
+            },
+        },
+        collection @ Report::CrateCollection { .. } => collection,
+    }
+}
+
+#[async_trait]
+impl crate::engine::report::generic::Aggregate for Report {
+    /// Lockfiles in `other` replace the ones of the same version in `self`, as they are the more recent ones.
+    fn merge(self, other: Self) -> Self {
+        use Report::*;
+        match (self, other) {
+            (lhs @ Version { .. }, rhs) => crate_from_version(lhs).merge(rhs),
+            (lhs, rhs @ Version { .. }) => lhs.merge(crate_from_version(rhs)),
+            (
+                Crate {
+                    crate_name: lhs_crate_name,
+                    versions_without_lockfile: lhs_vwl,
+                    lockfile_by_version: mut lhs_lbv,
+                },
+                Crate {
+                    crate_name: rhs_crate_name,
+                    versions_without_lockfile: rhs_vwl,
+                    lockfile_by_version: rhs_lbv,
+                },
+            ) if lhs_crate_name == rhs_crate_name => {
+                lhs_lbv.extend(rhs_lbv);
+                Crate {
+                    crate_name: lhs_crate_name,
+                    versions_without_lockfile: lhs_vwl + rhs_vwl,
+                    lockfile_by_version: lhs_lbv,
+                }
+            }
+            (
+                CrateCollection {
+                    versions_without_lockfile: lhs_vwl,
+                    lockfile_by_crate: lhs_lbc,
+                },
+                CrateCollection {
+                    versions_without_lockfile: rhs_vwl,
+                    lockfile_by_crate: rhs_lbc,
+                },
+            ) => CrateCollection {
+                versions_without_lockfile: lhs_vwl + rhs_vwl,
+                lockfile_by_crate: rhs_lbc.into_iter().fold(lhs_lbc, |mut m, (k, v)| {
+                    m.entry(k).or_default().extend(v);
+                    m
+                }),
+            },
+            (lhs, rhs) => collection_from_crate(lhs).merge(collection_from_crate(rhs)),
+        }
+    }
+
+    async fn complete(&mut self, _progress: &mut prodash::tree::Item, out: &mut Vec<u8>) -> Result<()> {
+        use horrorshow::Template;
+
+        let report = self.clone();
+        report.write_to_io(out)?;
+        Ok(())
+    }
+
+    fn crate_name(&self) -> Option<&str> {
+        match self {
+            Report::Version { crate_name, .. } | Report::Crate { crate_name, .. } => Some(crate_name),
+            Report::CrateCollection { .. } => None,
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use crate::persistence::TableAccess;
+use crate::{
+    engine::work::{lockfile::ParseLockfile, registry::TaskKind},
+    error::Result,
+    model::{CargoLock, ChangeKind, CrateVersion, TaskResult},
+    persistence,
+};
+use async_trait::async_trait;
+use serde_derive::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+mod html;
+mod merge;
+
+pub type Dict<T> = BTreeMap<String, T>;
+
+/// Problems with the crate versions pinned in a lockfile, each formatted as `name@version`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Default)]
+pub struct Issues {
+    /// Pinned crate versions which are yanked
+    pub yanked: Vec<String>,
+    /// Pinned crate versions whose checksum differs from the one in the crates.io index
+    pub checksum_mismatches: Vec<String>,
+}
+
+impl Issues {
+    pub fn is_empty(&self) -> bool {
+        self.yanked.is_empty() && self.checksum_mismatches.is_empty()
+    }
+}
+
+/// Returns true if `source` is the git or sparse crates.io index
+pub fn is_crates_io(source: &str) -> bool {
+    source == "registry+https://github.com/rust-lang/crates.io-index" || source == "sparse+https://index.crates.io/"
+}
+
+/// Find the crate versions pinned in `lockfile` which are yanked or have a checksum differing from ours,
+/// using `lookup` to obtain the crate version we know by name and version.
+pub fn issues_of(
+    lockfile: &CargoLock,
+    mut lookup: impl FnMut(&str, &str) -> Result<Option<CrateVersion>>,
+) -> Result<Issues> {
+    let mut issues = Issues::default();
+    for package in lockfile
+        .packages
+        .iter()
+        .filter(|p| p.source.as_deref().is_some_and(is_crates_io))
+    {
+        let known = match lookup(&package.name, &package.version)? {
+            Some(known) => known,
+            None => continue,
+        };
+        let id = format!("{}@{}", package.name, package.version);
+        if package
+            .checksum
+            .as_ref()
+            .is_some_and(|checksum| !checksum.eq_ignore_ascii_case(&known.checksum))
+        {
+            issues.checksum_mismatches.push(id.clone());
+        }
+        if known.kind == ChangeKind::Yanked {
+            issues.yanked.push(id);
+        }
+    }
+    Ok(issues)
+}
+
+/// The lockfile shipped by a crate version, along with the issues of the crate versions it pins
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Default)]
+pub struct Lockfile {
+    pub has_binary_target: bool,
+    pub issues: Issues,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub enum Report {
+    Version {
+        crate_name: String,
+        crate_version: String,
+        has_lockfile: bool,
+        has_binary_target: bool,
+        issues: Issues,
+    },
+    Crate {
+        crate_name: String,
+        /// Versions without a lockfile are only counted, as their report never changes
+        versions_without_lockfile: u64,
+        /// The issues of pinned crate versions change with the index, so newer reports of a version replace older ones
+        lockfile_by_version: Dict<Lockfile>,
+    },
+    CrateCollection {
+        versions_without_lockfile: u64,
+        /// The lockfiles of all versions shipping one, by crate and version
+        lockfile_by_crate: Dict<Dict<Lockfile>>,
+    },
+}
+
+/// The parsed lockfile of a crate version along with the issues of its pinned crate versions
+pub struct LockfileResult {
+    pub lockfile: Option<CargoLock>,
+    pub issues: Issues,
+}
+
+pub struct Generator;
+
+#[async_trait]
+impl super::generic::Generator for Generator {
+    type Report = Report;
+    type DBResult = LockfileResult;
+
+    fn name() -> &'static str {
+        "lockfile"
+    }
+
+    fn version() -> &'static str {
+        "1.2.0"
+    }
+
+    fn fq_result_key(crate_name: &str, crate_version: &str, key_buf: &mut String) {
+        TaskResult::CargoLock {
+            lockfile: None,
+            error: None,
+        }
+        .fq_key(crate_name, crate_version, &ParseLockfile.default_task(), key_buf);
+    }
+
+    fn get_result(
+        connection: persistence::ThreadSafeConnection,
+        crate_name: &str,
+        crate_version: &str,
+        key_buf: &mut String,
+    ) -> Result<Option<LockfileResult>> {
+        Self::fq_result_key(crate_name, crate_version, key_buf);
+        let table = persistence::TaskResultTable {
+            inner: connection.clone(),
+        };
+        let lockfile = match table.get(&key_buf)? {
+            Some(TaskResult::CargoLock { lockfile, .. }) => lockfile,
+            Some(_) => unreachable!("caller must assure we are always a lockfile result"),
+            None => return Ok(None),
+        };
+        let crate_versions = persistence::CrateVersionTable { inner: connection };
+        let issues = match lockfile.as_ref() {
+            Some(lockfile) => issues_of(lockfile, |name, version| {
+                key_buf.clear();
+                CrateVersion::key_from(name, version, key_buf);
+                crate_versions.get(&key_buf)
+            })?,
+            None => Issues::default(),
+        };
+        Ok(Some(LockfileResult { lockfile, issues }))
+    }
+
+    /// The issues of a lockfile change as pinned crate versions are yanked, so only reports without one are final.
+    fn is_final(result: &LockfileResult) -> bool {
+        result.lockfile.is_none()
+    }
+
+    async fn generate_report(
+        crate_name: &str,
+        crate_version: &str,
+        LockfileResult { lockfile, issues }: LockfileResult,
+        _progress: &mut prodash::tree::Item,
+    ) -> Result<Self::Report> {
+        Ok(Report::Version {
+            crate_name: crate_name.into(),
+            crate_version: crate_version.into(),
+            has_lockfile: lockfile.is_some(),
+            has_binary_target: lockfile.as_ref().is_some_and(|l| l.has_binary_target),
+            issues,
+        })
+    }
+}
+
+#[cfg(test)]
+mod report_test;
@@ -0,0 +1,66 @@
+use crate::{
+    engine::report::lockfile::{is_crates_io, issues_of, Issues},
+    model::{CargoLock, ChangeKind, CrateVersion, LockedPackage},
+};
+
+fn package(name: &str, version: &str, source: Option<&str>, checksum: Option<&str>) -> LockedPackage {
+    LockedPackage {
+        name: name.into(),
+        version: version.into(),
+        source: source.map(Into::into),
+        checksum: checksum.map(Into::into),
+    }
+}
+
+fn known(name: &str, version: &str, kind: ChangeKind, checksum: &str) -> CrateVersion {
+    CrateVersion {
+        name: name.into(),
+        version: version.into(),
+        kind,
+        checksum: checksum.into(),
+        ..Default::default()
+    }
+}
+
+const CRATES_IO: &str = "registry+https://github.com/rust-lang/crates.io-index";
+
+#[test]
+fn yanked_pins_and_checksum_mismatches_of_crates_io_packages_are_found() {
+    let lockfile = CargoLock {
+        version: Some(3),
+        packages: vec![
+            package("local", "1.0.0", None, None),
+            package("fine", "1.0.0", Some(CRATES_IO), Some("AA")),
+            package("yanked", "1.0.0", Some(CRATES_IO), Some("bb")),
+            package("tampered", "1.0.0", Some("sparse+https://index.crates.io/"), Some("cc")),
+            package("unknown", "1.0.0", Some(CRATES_IO), Some("dd")),
+            package("yanked", "2.0.0", Some("git+https://example.com/yanked"), Some("ee")),
+        ],
+        has_binary_target: true,
+    };
+    let index = [
+        known("fine", "1.0.0", ChangeKind::Added, "aa"),
+        known("yanked", "1.0.0", ChangeKind::Yanked, "bb"),
+        known("tampered", "1.0.0", ChangeKind::Added, "ff"),
+        known("yanked", "2.0.0", ChangeKind::Yanked, "00"),
+    ];
+    let issues = issues_of(&lockfile, |name, version| {
+        Ok(index.iter().find(|v| v.name == name && v.version == version).cloned())
+    })
+    .unwrap();
+    assert_eq!(
+        issues,
+        Issues {
+            yanked: vec!["yanked@1.0.0".into()],
+            checksum_mismatches: vec!["tampered@1.0.0".into()],
+        },
+        "checksums are compared case-insensitively, and packages from other sources are ignored"
+    );
+}
+
+#[test]
+fn crates_io_sources() {
+    assert!(is_crates_io(CRATES_IO));
+    assert!(is_crates_io("sparse+https://index.crates.io/"));
+    assert!(!is_crates_io("registry+https://example.com/index"));
+}
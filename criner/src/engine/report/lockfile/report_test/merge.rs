@@ -0,0 +1,120 @@
+use crate::{
+    engine::{
+        report::generic::{self, Aggregate},
+        report::lockfile::{Generator, Issues, Lockfile, LockfileResult, Report},
+    },
+    model::CargoLock,
+};
+use common_macros::b_tree_map;
+
+fn version(crate_name: &str, crate_version: &str, has_binary_target: bool, yanked: &[&str]) -> Report {
+    Report::Version {
+        crate_name: crate_name.into(),
+        crate_version: crate_version.into(),
+        has_lockfile: true,
+        has_binary_target,
+        issues: issues(yanked),
+    }
+}
+
+fn issues(yanked: &[&str]) -> Issues {
+    Issues {
+        yanked: yanked.iter().map(|s| s.to_string()).collect(),
+        checksum_mismatches: Vec::new(),
+    }
+}
+
+fn lockfile(has_binary_target: bool, yanked: &[&str]) -> Lockfile {
+    Lockfile {
+        has_binary_target,
+        issues: issues(yanked),
+    }
+}
+
+#[test]
+fn versions_of_the_same_crate_are_combined() {
+    assert_eq!(
+        version("a", "1", true, &["x@1"])
+            .merge(version("a", "2", false, &["x@1"]))
+            .merge(version("a", "3", true, &[])),
+        Report::Crate {
+            crate_name: "a".into(),
+            versions_without_lockfile: 0,
+            lockfile_by_version: b_tree_map! {
+                "1".into() => lockfile(true, &["x@1"]),
+                "2".into() => lockfile(false, &["x@1"]),
+                "3".into() => lockfile(true, &[]),
+            }
+        }
+    );
+}
+
+#[test]
+fn versions_of_different_crates_are_combined_per_crate() {
+    let no_lockfile = Report::Version {
+        crate_name: "c".into(),
+        crate_version: "1".into(),
+        has_lockfile: false,
+        has_binary_target: false,
+        issues: Issues::default(),
+    };
+    assert_eq!(
+        version("a", "1", true, &["x@1"])
+            .merge(version("b", "1", true, &[]))
+            .merge(version("a", "2", true, &["y@1"]))
+            .merge(no_lockfile),
+        Report::CrateCollection {
+            versions_without_lockfile: 1,
+            lockfile_by_crate: b_tree_map! {
+                "a".into() => b_tree_map! {
+                    "1".into() => lockfile(true, &["x@1"]),
+                    "2".into() => lockfile(true, &["y@1"]),
+                },
+                "b".into() => b_tree_map! {
+                    "1".into() => lockfile(true, &[]),
+                },
+            }
+        }
+    );
+}
+
+#[test]
+fn regenerated_versions_replace_their_previous_report() {
+    let previous = version("a", "1", true, &["x@1"]).merge(version("b", "1", true, &[]));
+    assert_eq!(
+        previous.merge(version("a", "1", true, &[])),
+        Report::CrateCollection {
+            versions_without_lockfile: 0,
+            lockfile_by_crate: b_tree_map! {
+                "a".into() => b_tree_map! {
+                    "1".into() => lockfile(true, &[]),
+                },
+                "b".into() => b_tree_map! {
+                    "1".into() => lockfile(true, &[]),
+                },
+            }
+        }
+    );
+    assert_eq!(
+        version("a", "1", true, &[]).merge(version("a", "1", true, &["x@1"])),
+        Report::Crate {
+            crate_name: "a".into(),
+            versions_without_lockfile: 0,
+            lockfile_by_version: b_tree_map! {
+                "1".into() => lockfile(true, &["x@1"]),
+            }
+        }
+    );
+}
+
+#[test]
+fn only_reports_without_lockfile_are_final() {
+    let result = |lockfile| LockfileResult {
+        lockfile,
+        issues: Issues::default(),
+    };
+    assert!(<Generator as generic::Generator>::is_final(&result(None)));
+    assert!(!<Generator as generic::Generator>::is_final(&result(Some(
+        CargoLock::default()
+    ))));
+}
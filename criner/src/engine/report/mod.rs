@@ -1,2 +1,6 @@
+pub mod build_script;
+pub mod dependency_weight;
 pub mod generic;
+pub mod lockfile;
+pub mod security;
 pub mod waste;
@@ -0,0 +1,162 @@
+use super::{merge::add_counts, Counts, Dict, Report, LARGE_BLOB_SIZE_IN_BYTES};
+use bytesize::ByteSize;
+use horrorshow::{box_html, helper::doctype, html, Render, RenderBox, RenderOnce, TemplateBuffer};
+use std::time::SystemTime;
+
+fn page_head(title: impl Into<String>) -> Box<dyn RenderBox> {
+    let title = title.into();
+    box_html! {
+        head {
+            title: title;
+        }
+    }
+}
+
+fn page_footer() -> impl Render {
+    html! {
+        footer {
+            span {
+                : "Generated by ";
+                a(href="https://github.com/the-lean-crate/criner"): "Criner";
+                : format!(" at {}", humantime::format_rfc3339_seconds(SystemTime::now()));
+            }
+        }
+    }
+}
+
+fn total(counts: &Counts) -> u64 {
+    counts.values().sum()
+}
+
+fn counts_section(title: impl Into<String>, counts: Counts) -> Box<dyn RenderBox> {
+    let title = title.into();
+    box_html! {
+        section(id="findings-by-kind") {
+            h3: title;
+            @ if counts.is_empty() {
+                p: "Nothing suspicious found"
+            } else {
+                ul {
+                    @ for (kind, count) in counts {
+                        li: format!("{}: {}", kind.as_str(), count);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn children_section(title: impl Into<String>, by_child: Dict<Counts>, suffix: &'static str) -> Box<dyn RenderBox> {
+    let title = title.into();
+    let mut sorted: Vec<_> = by_child.into_iter().collect();
+    sorted.sort_by_key(|(_, counts)| std::cmp::Reverse(total(counts)));
+    box_html! {
+        section(id="children") {
+            h1: title;
+            ol {
+                @ for (name, counts) in sorted {
+                    li {
+                        h3 {
+                            a(href=format!("{}{}", name, suffix)): name.clone();
+                        }
+                        p: counts.iter().map(|(kind, count)| format!("{}: {}", kind.as_str(), count)).collect::<Vec<_>>().join(", ");
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl RenderOnce for Report {
+    fn render_once(self, tmpl: &mut TemplateBuffer<'_>)
+    where
+        Self: Sized,
+    {
+        use super::Report::*;
+        match self {
+            Version {
+                crate_name,
+                crate_version,
+                total_files,
+                findings,
+            } => {
+                let title = format!("{}:{}", crate_name, crate_version);
+                let counts = super::counts_of(&findings);
+                tmpl << html! {
+                    : doctype::HTML;
+                    html {
+                        : page_head(title.clone());
+                        body {
+                            article {
+                                h1: title;
+                                p: format!("{} files checked", total_files);
+                                : counts_section("Findings", counts);
+                                @ if !findings.is_empty() {
+                                    section(id="findings") {
+                                        table {
+                                            tr { th: "kind"; th: "path"; th: "size"; th: "detail" }
+                                            @ for finding in findings {
+                                                tr {
+                                                    td: finding.kind.as_str();
+                                                    td: finding.path;
+                                                    td: format!("{}", ByteSize(finding.size));
+                                                    td: finding.detail.unwrap_or_default();
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        : page_footer();
+                    }
+                }
+            }
+            Crate {
+                crate_name,
+                total_versions,
+                counts_by_version,
+            } => {
+                let counts = counts_by_version.values().cloned().fold(Counts::new(), add_counts);
+                tmpl << html! {
+                    : doctype::HTML;
+                    html {
+                        : page_head(crate_name.clone());
+                        body {
+                            article {
+                                h1: crate_name;
+                                p: format!("{} of {} versions with findings", counts_by_version.len(), total_versions);
+                                : counts_section("Findings in all versions", counts);
+                                : children_section("Versions", counts_by_version, ".html");
+                            }
+                        }
+                        : page_footer();
+                    }
+                }
+            }
+            CrateCollection {
+                total_versions,
+                counts_by_crate,
+            } => {
+                let title = "Crates.io Security Report";
+                let counts = counts_by_crate.values().cloned().fold(Counts::new(), add_counts);
+                tmpl << html! {
+                    : doctype::HTML;
+                    html {
+                        : page_head(title);
+                        body {
+                            article {
+                                h1: title;
+                                p: format!("{} crates with findings in {} versions checked", counts_by_crate.len(), total_versions);
+                                p: format!("Files of at least {} are considered large blobs", ByteSize(LARGE_BLOB_SIZE_IN_BYTES));
+                                : counts_section("Findings in all crates", counts);
+                                : children_section("Crates", counts_by_crate, "/index.html");
+                            }
+                        }
+                        : page_footer();
+                    }
+                }
+            }
+        }
+    }
+}
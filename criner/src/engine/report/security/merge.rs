@@ -0,0 +1,117 @@
+use super::{counts_of, Counts, Dict, Report};
+use crate::Result;
+use async_trait::async_trait;
+
+pub fn add_counts(lhs: Counts, rhs: Counts) -> Counts {
+    rhs.into_iter().fold(lhs, |mut m, (k, v)| {
+        *m.entry(k).or_insert(0) += v;
+        m
+    })
+}
+
+fn sum_of_counts(counts: impl IntoIterator<Item = Counts>) -> Counts {
+    counts.into_iter().fold(Counts::new(), add_counts)
+}
+
+fn non_empty_entry(name: String, counts: Counts) -> Dict<Counts> {
+    let mut m = Dict::new();
+    if !counts.is_empty() {
+        m.insert(name, counts);
+    }
+    m
+}
+
+pub fn crate_from_version(version: Report) -> Report {
+    match version {
+        Report::Version {
+            crate_name,
+            crate_version,
+            findings,
+            total_files: _,
+        } => Report::Crate {
+            crate_name,
+            total_versions: 1,
+            counts_by_version: non_empty_entry(crate_version, counts_of(&findings)),
+        },
+        _ => unreachable!("must only be called with version variant"),
+    }
+}
+
+pub fn collection_from_crate(krate: Report) -> Report {
+    match krate {
+        version @ Report::Version { .. } => collection_from_crate(crate_from_version(version)),
+        Report::Crate {
+            crate_name,
+            total_versions,
+            counts_by_version,
+        } => Report::CrateCollection {
+            total_versions,
+            counts_by_crate: non_empty_entry(crate_name, sum_of_counts(counts_by_version.into_values())),
+        },
+        collection @ Report::CrateCollection { .. } => collection,
+    }
+}
+
+#[async_trait]
+impl crate::engine::report::generic::Aggregate for Report {
+    fn merge(self, other: Self) -> Self {
+        use Report::*;
+        match (self, other) {
+            (lhs @ Version { .. }, rhs) => crate_from_version(lhs).merge(rhs),
+            (lhs, rhs @ Version { .. }) => lhs.merge(crate_from_version(rhs)),
+            (
+                Crate {
+                    crate_name: lhs_crate_name,
+                    total_versions: lhs_tv,
+                    counts_by_version: mut lhs_cbv,
+                },
+                Crate {
+                    crate_name: rhs_crate_name,
+                    total_versions: rhs_tv,
+                    counts_by_version: rhs_cbv,
+                },
+            ) if lhs_crate_name == rhs_crate_name => {
+                // The findings of a version never change, so there is nothing to add up
+                lhs_cbv.extend(rhs_cbv);
+                Crate {
+                    crate_name: lhs_crate_name,
+                    total_versions: lhs_tv + rhs_tv,
+                    counts_by_version: lhs_cbv,
+                }
+            }
+            (
+                CrateCollection {
+                    total_versions: lhs_tv,
+                    counts_by_crate: lhs_cbc,
+                },
+                CrateCollection {
+                    total_versions: rhs_tv,
+                    counts_by_crate: rhs_cbc,
+                },
+            ) => CrateCollection {
+                total_versions: lhs_tv + rhs_tv,
+                counts_by_crate: rhs_cbc.into_iter().fold(lhs_cbc, |mut m, (k, v)| {
+                    let counts = m.remove(&k).unwrap_or_default();
+                    m.insert(k, add_counts(counts, v));
+                    m
+                }),
+            },
+            (lhs, rhs) => collection_from_crate(lhs).merge(collection_from_crate(rhs)),
+        }
+    }
+
+    async fn complete(&mut self, _progress: &mut prodash::tree::Item, out: &mut Vec<u8>) -> Result<()> {
+        use horrorshow::Template;
+
+        let report = self.clone();
+        report.write_to_io(out)?;
+        Ok(())
+    }
+
+    fn crate_name(&self) -> Option<&str> {
+        match self {
+            Report::Version { crate_name, .. } | Report::Crate { crate_name, .. } => Some(crate_name),
+            Report::CrateCollection { .. } => None,
+        }
+    }
+}
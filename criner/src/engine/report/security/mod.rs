@@ -0,0 +1,336 @@
+use crate::persistence::TableAccess;
+use crate::{
+    engine::report::waste::{path_bytes_to_str, tar_path_to_utf8_str},
+    error::Result,
+    model::{EntryFinding, TarHeader, TaskResult},
+    persistence,
+};
+use async_trait::async_trait;
+use serde_derive::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+mod html;
+mod merge;
+
+/// Regular files at least this large are reported as unusually large blobs
+pub const LARGE_BLOB_SIZE_IN_BYTES: u64 = 2 * 1024 * 1024;
+
+/// Extensions of files which contain native code, to detect binaries whose content wasn't stored
+const BINARY_EXTENSIONS: &[&str] = &["exe", "dll", "so", "dylib", "o", "obj", "a", "lib"];
+
+pub type Dict<T> = BTreeMap<String, T>;
+
+/// The amount of findings of each kind
+pub type Counts = BTreeMap<FindingKind, u64>;
+
+/// The kind of suspicious packaging found in a crate archive
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "kebab-case")]
+pub enum FindingKind {
+    Symlink,
+    Hardlink,
+    /// A character or block device, or a named pipe
+    DeviceFile,
+    /// A path, or the target of a link, which points outside of the crate directory
+    EscapingPath,
+    /// A native binary, detected by its content if it was stored during extraction, or by its extension
+    Executable,
+    /// A file of at least `LARGE_BLOB_SIZE_IN_BYTES`
+    LargeBlob,
+    /// A file whose name looks like `Cargo.toml`, but isn't
+    CargoManifestLookalike,
+}
+
+impl FindingKind {
+    pub fn as_str(&self) -> &'static str {
+        use FindingKind::*;
+        match self {
+            Symlink => "symlink",
+            Hardlink => "hardlink",
+            DeviceFile => "device-file",
+            EscapingPath => "escaping-path",
+            Executable => "executable",
+            LargeBlob => "large-blob",
+            CargoManifestLookalike => "cargo-manifest-lookalike",
+        }
+    }
+}
+
+/// A suspicious entry in a crate archive
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct Finding {
+    /// The crate relative path of the entry, with invalid UTF-8 escaped
+    pub path: String,
+    pub size: u64,
+    pub kind: FindingKind,
+    /// Additional information, like the target of a link or the kind of binary
+    pub detail: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub enum Report {
+    Version {
+        crate_name: String,
+        crate_version: String,
+        total_files: u64,
+        findings: Vec<Finding>,
+    },
+    Crate {
+        crate_name: String,
+        total_versions: u64,
+        /// Only versions with at least one finding are listed
+        counts_by_version: Dict<Counts>,
+    },
+    CrateCollection {
+        total_versions: u64,
+        /// The findings of all versions of a crate, only crates with at least one finding are listed
+        counts_by_crate: Dict<Counts>,
+    },
+}
+
+pub fn counts_of(findings: &[Finding]) -> Counts {
+    findings.iter().fold(Counts::new(), |mut m, f| {
+        *m.entry(f.kind).or_insert(0) += 1;
+        m
+    })
+}
+
+impl Report {
+    pub fn from_package(
+        crate_name: &str,
+        crate_version: &str,
+        entries_meta_data: &[TarHeader],
+        selected_entries: &[(TarHeader, Vec<u8>)],
+        entry_findings: &[EntryFinding],
+    ) -> Report {
+        Report::Version {
+            crate_name: crate_name.into(),
+            crate_version: crate_version.into(),
+            total_files: entries_meta_data.len() as u64,
+            findings: findings_of(entries_meta_data, selected_entries, entry_findings),
+        }
+    }
+}
+
+/// Find everything suspicious in the entries of a crate.
+///
+/// The file mode isn't stored, so executables are only found if they are native binaries.
+/// Link targets are only known for crates extracted with `EntryFinding`s.
+pub fn findings_of(
+    entries_meta_data: &[TarHeader],
+    selected_entries: &[(TarHeader, Vec<u8>)],
+    entry_findings: &[EntryFinding],
+) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    for entry in entries_meta_data {
+        let path = tar_path_to_utf8_str(&entry.path).into_owned();
+        let mut push = |kind, detail: Option<String>| {
+            findings.push(Finding {
+                path: path.clone(),
+                size: entry.size,
+                kind,
+                detail,
+            })
+        };
+        if path_escapes(&entry.path) {
+            push(FindingKind::EscapingPath, None);
+        }
+
+        let link_target = || {
+            entry_findings
+                .iter()
+                .find(|f| f.path == entry.path)
+                .and_then(|f| f.kind.link_target())
+        };
+        match tar::EntryType::new(entry.entry_type) {
+            t if t.is_symlink() || t.is_hard_link() => {
+                let target = link_target();
+                push(
+                    if t.is_symlink() {
+                        FindingKind::Symlink
+                    } else {
+                        FindingKind::Hardlink
+                    },
+                    target.map(|t| path_bytes_to_str(t).into_owned()),
+                );
+                if let Some(target) = target {
+                    if t.is_symlink() && symlink_target_escapes(&entry.path, target)
+                        || t.is_hard_link() && path_escapes(target)
+                    {
+                        push(
+                            FindingKind::EscapingPath,
+                            Some(format!("link to {}", path_bytes_to_str(target))),
+                        );
+                    }
+                }
+            }
+            t if t.is_character_special() => push(FindingKind::DeviceFile, Some("character device".into())),
+            t if t.is_block_special() => push(FindingKind::DeviceFile, Some("block device".into())),
+            t if t.is_fifo() => push(FindingKind::DeviceFile, Some("named pipe".into())),
+            t if t.is_file() => {
+                let content = selected_entries
+                    .iter()
+                    .find(|(h, _)| h.path == entry.path)
+                    .map(|(_, content)| content.as_slice());
+                if let Some(format) = binary_format(&path, content) {
+                    push(FindingKind::Executable, Some(format.into()));
+                }
+                if entry.size >= LARGE_BLOB_SIZE_IN_BYTES {
+                    push(FindingKind::LargeBlob, None);
+                }
+                if is_cargo_manifest_lookalike(&path) {
+                    push(FindingKind::CargoManifestLookalike, None);
+                }
+            }
+            _ => {}
+        }
+    }
+    findings
+}
+
+fn components(path: &[u8]) -> impl Iterator<Item = &[u8]> {
+    path.split(|b| *b == b'/' || *b == b'\\')
+        .filter(|c| !c.is_empty() && *c != b".")
+}
+
+fn is_absolute(path: &[u8]) -> bool {
+    let is_windows_drive = path.len() >= 2 && path[0].is_ascii_alphabetic() && path[1] == b':';
+    path.starts_with(b"/") || path.starts_with(b"\\") || is_windows_drive
+}
+
+/// Returns true if `components` leave the directory they start in, given they start `depth` directories deep.
+fn leaves_directory<'a>(mut depth: usize, components: impl Iterator<Item = &'a [u8]>) -> bool {
+    for component in components {
+        if component == b".." {
+            if depth == 0 {
+                return true;
+            }
+            depth -= 1;
+        } else {
+            depth += 1;
+        }
+    }
+    false
+}
+
+/// Returns true if the archive `path` points outside of the crate directory, which is its first component.
+pub fn path_escapes(path: &[u8]) -> bool {
+    let mut components = components(path);
+    match components.next() {
+        Some(b"..") => true,
+        Some(_) => is_absolute(path) || leaves_directory(0, components),
+        None => false,
+    }
+}
+
+/// Returns true if the `target` of the symlink at archive `path` points outside of the crate directory.
+pub fn symlink_target_escapes(path: &[u8], target: &[u8]) -> bool {
+    if is_absolute(target) {
+        return true;
+    }
+    // The directory containing the link, relative to the crate directory
+    let depth = components(path).count().saturating_sub(2);
+    leaves_directory(depth, components(target))
+}
+
+/// Returns the kind of native binary `content` is, or what its `path` suggests if the content is unknown.
+pub fn binary_format(path: &str, content: Option<&[u8]>) -> Option<&'static str> {
+    if let Some(content) = content.filter(|c| !c.is_empty()) {
+        return match content {
+            [0x7f, b'E', b'L', b'F', ..] => Some("ELF binary"),
+            [b'M', b'Z', ..] => Some("PE binary"),
+            [0xfe, 0xed, 0xfa, 0xce | 0xcf, ..] | [0xce | 0xcf, 0xfa, 0xed, 0xfe, ..] => Some("Mach-O binary"),
+            // Java class files share the magic with universal binaries
+            [0xca, 0xfe, 0xba, 0xbe, ..] if !path.ends_with(".class") => Some("Mach-O universal binary"),
+            _ => None,
+        };
+    }
+    let file_name = path.rsplit('/').next().unwrap_or(path).to_ascii_lowercase();
+    file_name
+        .split('.')
+        .skip(1)
+        .any(|ext| BINARY_EXTENSIONS.contains(&ext))
+        .then_some("native binary by extension")
+}
+
+/// Returns true if the file name of `path` is not `Cargo.toml`, but looks like it when ignoring case,
+/// whitespace, invisible characters and some common homoglyphs.
+pub fn is_cargo_manifest_lookalike(path: &str) -> bool {
+    let file_name = path.rsplit('/').next().unwrap_or(path);
+    if file_name == "Cargo.toml" {
+        return false;
+    }
+    let normalized: String = file_name
+        .chars()
+        .filter(|c| !c.is_whitespace() && !matches!(c, '\u{200b}'..='\u{200f}' | '\u{2060}' | '\u{feff}'))
+        .map(|c| match c {
+            'а' | 'А' => 'a',
+            'с' | 'С' => 'c',
+            'о' | 'О' | '0' => 'o',
+            'р' | 'Р' => 'p',
+            'т' | 'Т' => 't',
+            'г' => 'r',
+            'ӏ' | 'І' | 'і' | '1' => 'l',
+            '·' | '․' => '.',
+            c => c,
+        })
+        .flat_map(char::to_lowercase)
+        .collect();
+    normalized == "cargo.toml"
+}
+
+pub struct Generator;
+
+#[async_trait]
+impl super::generic::Generator for Generator {
+    type Report = Report;
+    type DBResult = TaskResult;
+
+    fn name() -> &'static str {
+        "security"
+    }
+
+    fn version() -> &'static str {
+        "1.1.0"
+    }
+
+    fn fq_result_key(crate_name: &str, crate_version: &str, key_buf: &mut String) {
+        <super::waste::Generator as super::generic::Generator>::fq_result_key(crate_name, crate_version, key_buf)
+    }
+
+    fn get_result(
+        connection: persistence::ThreadSafeConnection,
+        crate_name: &str,
+        crate_version: &str,
+        key_buf: &mut String,
+    ) -> Result<Option<TaskResult>> {
+        Self::fq_result_key(crate_name, crate_version, key_buf);
+        let table = persistence::TaskResultTable { inner: connection };
+        table.get(&key_buf)
+    }
+
+    async fn generate_report(
+        crate_name: &str,
+        crate_version: &str,
+        result: TaskResult,
+        _progress: &mut prodash::tree::Item,
+    ) -> Result<Self::Report> {
+        Ok(match result {
+            TaskResult::ExplodedCrate {
+                entries_meta_data,
+                selected_entries,
+                findings,
+            } => Report::from_package(
+                crate_name,
+                crate_version,
+                &entries_meta_data,
+                &selected_entries,
+                &findings,
+            ),
+            _ => unreachable!("caller must assure we are always an exploded entry"),
+        })
+    }
+}
+
+#[cfg(test)]
+mod report_test;
@@ -0,0 +1,140 @@
+use crate::{
+    engine::report::security::{
+        binary_format, findings_of, is_cargo_manifest_lookalike, path_escapes, symlink_target_escapes, Finding,
+        FindingKind, LARGE_BLOB_SIZE_IN_BYTES,
+    },
+    model::{EntryFinding, EntryFindingKind, TarHeader},
+};
+
+fn header(path: &str, size: u64, entry_type: u8) -> TarHeader {
+    TarHeader {
+        path: path.into(),
+        size,
+        entry_type,
+        hash: None,
+    }
+}
+
+fn finding(path: &str, size: u64, kind: FindingKind, detail: Option<&str>) -> Finding {
+    Finding {
+        path: path.into(),
+        size,
+        kind,
+        detail: detail.map(Into::into),
+    }
+}
+
+#[test]
+fn lean_crates_have_no_findings() {
+    let entries = vec![
+        header("a-1.0.0/Cargo.toml", 100, b'0'),
+        header("a-1.0.0/Cargo.toml.orig", 100, b'0'),
+        header("a-1.0.0/src/lib.rs", 1000, b'0'),
+        header("a-1.0.0/tests/fixture/Cargo.toml", 10, b'0'),
+    ];
+    let selected = vec![(entries[2].clone(), b"pub fn a() {}".to_vec())];
+    assert_eq!(findings_of(&entries, &selected, &[]), Vec::new());
+}
+
+#[test]
+fn all_kinds_of_suspicious_entries_are_found() {
+    let entries = vec![
+        header("a-1.0.0/src/link", 0, b'2'),
+        header("a-1.0.0/hard", 0, b'1'),
+        header("a-1.0.0/dev/tty", 0, b'3'),
+        header("a-1.0.0/dev/sda", 0, b'4'),
+        header("a-1.0.0/pipe", 0, b'6'),
+        header("a-1.0.0/../outside.rs", 5, b'0'),
+        header("a-1.0.0/build/helper", 20, b'0'),
+        header("a-1.0.0/lib/native.dll", 30, b'0'),
+        header("a-1.0.0/data.bin", LARGE_BLOB_SIZE_IN_BYTES, b'0'),
+        header("a-1.0.0/sub/cargo.toml", 40, b'0'),
+    ];
+    let selected = vec![(entries[6].clone(), b"\x7fELF\x02\x01".to_vec())];
+    let entry_findings = vec![
+        EntryFinding {
+            path: b"a-1.0.0/src/link".to_vec(),
+            kind: EntryFindingKind::Symlink(b"../../../etc/passwd".to_vec()),
+        },
+        EntryFinding {
+            path: b"a-1.0.0/hard".to_vec(),
+            kind: EntryFindingKind::Hardlink(b"a-1.0.0/Cargo.toml".to_vec()),
+        },
+    ];
+
+    use FindingKind::*;
+    assert_eq!(
+        findings_of(&entries, &selected, &entry_findings),
+        vec![
+            finding("src/link", 0, Symlink, Some("../../../etc/passwd")),
+            finding("src/link", 0, EscapingPath, Some("link to ../../../etc/passwd")),
+            finding("hard", 0, Hardlink, Some("a-1.0.0/Cargo.toml")),
+            finding("dev/tty", 0, DeviceFile, Some("character device")),
+            finding("dev/sda", 0, DeviceFile, Some("block device")),
+            finding("pipe", 0, DeviceFile, Some("named pipe")),
+            finding("../outside.rs", 5, EscapingPath, None),
+            finding("build/helper", 20, Executable, Some("ELF binary")),
+            finding("lib/native.dll", 30, Executable, Some("native binary by extension")),
+            finding("data.bin", LARGE_BLOB_SIZE_IN_BYTES, LargeBlob, None),
+            finding("sub/cargo.toml", 40, CargoManifestLookalike, None),
+        ]
+    );
+}
+
+#[test]
+fn links_without_recorded_targets_are_found_nonetheless() {
+    assert_eq!(
+        findings_of(&[header("a-1.0.0/link", 0, b'2')], &[], &[]),
+        vec![finding("link", 0, FindingKind::Symlink, None)]
+    );
+}
+
+#[test]
+fn paths_escaping_the_crate_directory() {
+    assert!(path_escapes(b"/etc/passwd"));
+    assert!(path_escapes(b"C:\\Windows"));
+    assert!(path_escapes(b"../a"));
+    assert!(path_escapes(b"a-1.0.0/src/../../b"));
+    assert!(!path_escapes(b"a-1.0.0/src/../b"));
+    assert!(!path_escapes(b"a-1.0.0/./src/lib.rs"));
+}
+
+#[test]
+fn symlink_targets_escaping_the_crate_directory() {
+    assert!(symlink_target_escapes(b"a-1.0.0/link", b"/etc/passwd"));
+    assert!(symlink_target_escapes(b"a-1.0.0/link", b"../b-1.0.0"));
+    assert!(symlink_target_escapes(b"a-1.0.0/src/link", b"../../a"));
+    assert!(!symlink_target_escapes(b"a-1.0.0/src/link", b"../README.md"));
+    assert!(!symlink_target_escapes(b"a-1.0.0/link", b"src/lib.rs"));
+}
+
+#[test]
+fn binaries_by_content_and_extension() {
+    assert_eq!(binary_format("a.exe", Some(b"MZ\x90\x00")), Some("PE binary"));
+    assert_eq!(binary_format("a", Some(b"\xcf\xfa\xed\xfe")), Some("Mach-O binary"));
+    assert_eq!(
+        binary_format("a", Some(b"\xca\xfe\xba\xbe")),
+        Some("Mach-O universal binary")
+    );
+    assert_eq!(binary_format("A.class", Some(b"\xca\xfe\xba\xbe")), None);
+    assert_eq!(binary_format("not-a.dll", Some(b"plain text")), None);
+    assert_eq!(
+        binary_format("lib/libfoo.so.1", None),
+        Some("native binary by extension")
+    );
+    assert_eq!(binary_format("src/lib.rs", None), None);
+    assert_eq!(binary_format("src/lib.rs", Some(b"")), None);
+}
+
+#[test]
+fn cargo_manifest_lookalikes() {
+    assert!(!is_cargo_manifest_lookalike("Cargo.toml"));
+    assert!(!is_cargo_manifest_lookalike("sub/Cargo.toml"));
+    assert!(!is_cargo_manifest_lookalike("Cargo.toml.orig"));
+    assert!(!is_cargo_manifest_lookalike("Cargo.lock"));
+    assert!(is_cargo_manifest_lookalike("cargo.toml"));
+    assert!(is_cargo_manifest_lookalike("Cargo.toml "));
+    assert!(is_cargo_manifest_lookalike("Cargo\u{200b}.toml"));
+    assert!(is_cargo_manifest_lookalike("C\u{0430}rgo.toml"));
+    assert!(is_cargo_manifest_lookalike("Carg0.toml"));
+}
@@ -0,0 +1,83 @@
+use crate::engine::{
+    report::generic::Aggregate,
+    report::security::{Finding, FindingKind, Report},
+};
+use common_macros::b_tree_map;
+
+fn version(crate_name: &str, crate_version: &str, kinds: &[FindingKind]) -> Report {
+    Report::Version {
+        crate_name: crate_name.into(),
+        crate_version: crate_version.into(),
+        total_files: 10,
+        findings: kinds
+            .iter()
+            .map(|kind| Finding {
+                path: "path".into(),
+                size: 1,
+                kind: *kind,
+                detail: None,
+            })
+            .collect(),
+    }
+}
+
+#[test]
+fn two_versions_of_same_crate() {
+    use FindingKind::*;
+    assert_eq!(
+        version("a", "1", &[Symlink, Symlink, LargeBlob]).merge(version("a", "2", &[])),
+        Report::Crate {
+            crate_name: "a".into(),
+            total_versions: 2,
+            counts_by_version: b_tree_map! {
+                "1".into() => b_tree_map! { Symlink => 2, LargeBlob => 1 },
+            }
+        }
+    );
+}
+
+#[test]
+fn versions_of_different_crates() {
+    use FindingKind::*;
+    assert_eq!(
+        version("a", "1", &[Executable])
+            .merge(version("b", "1", &[]))
+            .merge(version("a", "2", &[Executable, DeviceFile])),
+        Report::CrateCollection {
+            total_versions: 3,
+            counts_by_crate: b_tree_map! {
+                "a".into() => b_tree_map! { Executable => 2, DeviceFile => 1 },
+            }
+        }
+    );
+}
+
+#[test]
+fn merging_is_independent_of_order() {
+    use FindingKind::*;
+    let krate = version("a", "1", &[Hardlink]).merge(version("a", "2", &[Hardlink, EscapingPath]));
+    let other_version = version("b", "1", &[CargoManifestLookalike]);
+    let collection = version("c", "1", &[Symlink]).merge(version("d", "1", &[]));
+    assert_eq!(
+        krate.clone().merge(other_version.clone()).merge(collection.clone()),
+        collection.merge(other_version).merge(krate)
+    );
+}
+
+#[test]
+fn json_has_kebab_case_names() {
+    let mut out = Vec::new();
+    assert!(version("a", "1", &[FindingKind::LargeBlob])
+        .merge(version("a", "2", &[]))
+        .complete_json(&mut out)
+        .unwrap());
+    let json: serde_json::Value = serde_json::from_slice(&out).unwrap();
+    assert_eq!(json["Crate"]["counts_by_version"]["1"]["large-blob"], 1);
+}
+
+#[test]
+fn rmp_round_trip() {
+    let report = version("a", "1", &[FindingKind::Symlink]).merge(version("b", "1", &[FindingKind::Executable]));
+    let data = rmp_serde::to_vec(&report).unwrap();
+    assert_eq!(rmp_serde::from_read::<_, Report>(data.as_slice()).unwrap(), report);
+}
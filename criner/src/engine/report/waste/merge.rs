@@ -1,33 +1,8 @@
-use super::{AggregateFileInfo, AggregateVersionInfo, Dict, Fix, Report, VersionInfo, WastedFile};
+use super::{AggregateFileInfo, AggregateVersionInfo, CompressedSize, Dict, Fix, Report, VersionInfo, WastedFile};
 use crate::Result;
 use async_trait::async_trait;
-use criner_waste_report::{add_optional_aggregate, html::NO_EXT_MARKER};
-use std::{
-    collections::BTreeMap,
-    ops::AddAssign,
-    path::{Path, PathBuf},
-};
-
-const TOP_LEVEL_REPORT_NAME: &str = "__top-level-report__";
-
-fn path_from_prefix(out_dir: &Path, prefix: &str) -> PathBuf {
-    use crate::engine::report::generic::Generator;
-    out_dir.join(format!(
-        "{}-{}-{}.rmp",
-        prefix,
-        super::Generator::name(),
-        super::Generator::version()
-    ))
-}
-
-fn path_to_storage_location(report: &Report, out_dir: &Path) -> PathBuf {
-    use Report::*;
-    let prefix = match report {
-        Version { crate_name, .. } | Crate { crate_name, .. } => crate_name.as_str(),
-        CrateCollection { .. } => TOP_LEVEL_REPORT_NAME,
-    };
-    path_from_prefix(out_dir, prefix)
-}
+use criner_waste_report::{add_optional_aggregate, category::into_map_by_category, html::NO_EXT_MARKER, json};
+use std::{collections::BTreeMap, ops::AddAssign, path::PathBuf};
 
 pub fn vec_into_map_by_extension(initial: Dict<AggregateFileInfo>, from: Vec<WastedFile>) -> Dict<AggregateFileInfo> {
     from.into_iter().fold(initial, |mut m, e| {
@@ -87,6 +62,7 @@ pub fn version_to_new_version_map(
     total_files: u64,
     wasted_files: &[WastedFile],
     potential_gains: Option<AggregateFileInfo>,
+    compressed: Option<CompressedSize>,
 ) -> Dict<VersionInfo> {
     let mut m = BTreeMap::new();
     m.insert(
@@ -102,6 +78,8 @@ pub fn version_to_new_version_map(
             },
             potential_gains,
             waste_latest_version: None,
+            waste_by_category: into_map_by_category(BTreeMap::new(), wasted_files),
+            compressed,
         },
     );
     m
@@ -119,10 +97,14 @@ pub fn crate_collection_info_from_version_info(
                 all,
                 potential_gains,
                 waste_latest_version: _unused_and_always_none,
+                waste_by_category,
+                compressed,
             } = v;
             a.waste.add_assign(waste.clone());
+            a.waste_by_category = map_into_map(std::mem::take(&mut a.waste_by_category), waste_by_category);
             a.all.add_assign(all);
             a.potential_gains = add_optional_aggregate(a.potential_gains.clone(), potential_gains);
+            a.compressed = add_optional_aggregate(a.compressed.take(), compressed);
             a.waste_latest_version = if version_name > previous_name {
                 previous_name = version_name.clone();
                 Some((version_name, waste))
@@ -144,16 +126,19 @@ pub fn collection_from_crate(
     total_files: u64,
     info_by_version: Dict<VersionInfo>,
     wasted_by_extension: Dict<AggregateFileInfo>,
+    wasted_by_category: Dict<AggregateFileInfo>,
 ) -> Report {
     Report::CrateCollection {
         total_size_in_bytes,
         total_files,
         info_by_crate: crate_collection_info_from_version_info(crate_name, info_by_version),
         wasted_by_extension,
+        wasted_by_category,
     }
 }
 
 pub fn crate_from_version(version: Report) -> Report {
+    let compressed = version.compressed_size();
     match version {
         Report::Version {
             crate_name,
@@ -162,6 +147,7 @@ pub fn crate_from_version(version: Report) -> Report {
             total_files,
             wasted_files,
             suggested_fix,
+            ..
         } => Report::Crate {
             crate_name,
             info_by_version: version_to_new_version_map(
@@ -170,9 +156,11 @@ pub fn crate_from_version(version: Report) -> Report {
                 total_files,
                 &wasted_files,
                 fix_to_wasted_files_aggregate(suggested_fix),
+                compressed,
             ),
             total_size_in_bytes,
             total_files,
+            wasted_by_category: into_map_by_category(BTreeMap::new(), &wasted_files),
             wasted_by_extension: into_map_by_extension(wasted_files),
         },
         _ => unreachable!("must only be called with version variant"),
@@ -198,6 +186,7 @@ impl crate::engine::report::generic::Aggregate for Report {
                     total_files: lhs_tf,
                     info_by_version,
                     wasted_by_extension,
+                    wasted_by_category,
                 },
                 Version {
                     crate_name: rhs_crate_name,
@@ -206,9 +195,15 @@ impl crate::engine::report::generic::Aggregate for Report {
                     total_files: rhs_tf,
                     wasted_files,
                     suggested_fix,
+                    cargo_toml_diff,
+                    fix_verification,
+                    compressed_size_in_bytes,
                 },
             ) => {
                 if lhs_crate_name == rhs_crate_name {
+                    let compressed = compressed_size_in_bytes.map(|compressed_bytes| {
+                        CompressedSize::estimate(compressed_bytes, rhs_tsb, byte_count(&wasted_files))
+                    });
                     Crate {
                         crate_name: lhs_crate_name,
                         total_size_in_bytes: lhs_tsb + rhs_tsb,
@@ -221,21 +216,32 @@ impl crate::engine::report::generic::Aggregate for Report {
                                 rhs_tf,
                                 &wasted_files,
                                 fix_to_wasted_files_aggregate(suggested_fix),
+                                compressed,
                             ),
                         ),
+                        wasted_by_category: into_map_by_category(wasted_by_category, &wasted_files),
                         wasted_by_extension: vec_into_map_by_extension(wasted_by_extension, wasted_files),
                     }
                 } else {
-                    collection_from_crate(lhs_crate_name, lhs_tsb, lhs_tf, info_by_version, wasted_by_extension).merge(
-                        Version {
-                            crate_name: rhs_crate_name,
-                            crate_version,
-                            total_size_in_bytes: rhs_tsb,
-                            total_files: rhs_tf,
-                            wasted_files,
-                            suggested_fix,
-                        },
+                    collection_from_crate(
+                        lhs_crate_name,
+                        lhs_tsb,
+                        lhs_tf,
+                        info_by_version,
+                        wasted_by_extension,
+                        wasted_by_category,
                     )
+                    .merge(Version {
+                        crate_name: rhs_crate_name,
+                        crate_version,
+                        total_size_in_bytes: rhs_tsb,
+                        total_files: rhs_tf,
+                        wasted_files,
+                        suggested_fix,
+                        cargo_toml_diff,
+                        fix_verification,
+                        compressed_size_in_bytes,
+                    })
                 }
             }
             (
@@ -245,6 +251,7 @@ impl crate::engine::report::generic::Aggregate for Report {
                     total_files: lhs_tf,
                     info_by_version: lhs_ibv,
                     wasted_by_extension: lhs_wbe,
+                    wasted_by_category: lhs_wbc,
                 },
                 Crate {
                     crate_name: rhs_crate_name,
@@ -252,15 +259,17 @@ impl crate::engine::report::generic::Aggregate for Report {
                     total_files: rhs_tf,
                     info_by_version: rhs_ibv,
                     wasted_by_extension: rhs_wbe,
+                    wasted_by_category: rhs_wbc,
                 },
             ) => {
                 if lhs_crate_name != rhs_crate_name {
-                    collection_from_crate(lhs_crate_name, lhs_tsb, lhs_tf, lhs_ibv, lhs_wbe).merge(Crate {
+                    collection_from_crate(lhs_crate_name, lhs_tsb, lhs_tf, lhs_ibv, lhs_wbe, lhs_wbc).merge(Crate {
                         crate_name: rhs_crate_name,
                         total_size_in_bytes: rhs_tsb,
                         total_files: rhs_tf,
                         info_by_version: rhs_ibv,
                         wasted_by_extension: rhs_wbe,
+                        wasted_by_category: rhs_wbc,
                     })
                 } else {
                     Crate {
@@ -269,6 +278,7 @@ impl crate::engine::report::generic::Aggregate for Report {
                         total_files: lhs_tf + rhs_tf,
                         info_by_version: map_into_map(lhs_ibv, rhs_ibv),
                         wasted_by_extension: map_into_map(lhs_wbe, rhs_wbe),
+                        wasted_by_category: map_into_map(lhs_wbc, rhs_wbc),
                     }
                 }
             }
@@ -278,18 +288,21 @@ impl crate::engine::report::generic::Aggregate for Report {
                     total_files: lhs_tf,
                     info_by_crate: lhs_ibc,
                     wasted_by_extension: lhs_wbe,
+                    wasted_by_category: lhs_wbc,
                 },
                 CrateCollection {
                     total_size_in_bytes: rhs_tsb,
                     total_files: rhs_tf,
                     info_by_crate: rhs_ibc,
                     wasted_by_extension: rhs_wbe,
+                    wasted_by_category: rhs_wbc,
                 },
             ) => CrateCollection {
                 total_size_in_bytes: lhs_tsb + rhs_tsb,
                 total_files: lhs_tf + rhs_tf,
                 info_by_crate: map_into_map(lhs_ibc, rhs_ibc),
                 wasted_by_extension: map_into_map(lhs_wbe, rhs_wbe),
+                wasted_by_category: map_into_map(lhs_wbc, rhs_wbc),
             },
             (
                 CrateCollection {
@@ -297,6 +310,7 @@ impl crate::engine::report::generic::Aggregate for Report {
                     total_files: lhs_tf,
                     info_by_crate,
                     wasted_by_extension: lhs_wbe,
+                    wasted_by_category: lhs_wbc,
                 },
                 Crate {
                     crate_name,
@@ -304,11 +318,13 @@ impl crate::engine::report::generic::Aggregate for Report {
                     total_files: rhs_tf,
                     info_by_version,
                     wasted_by_extension: rhs_wbe,
+                    wasted_by_category: rhs_wbc,
                 },
             ) => CrateCollection {
                 total_size_in_bytes: lhs_tsb + rhs_tsb,
                 total_files: lhs_tf + rhs_tf,
                 wasted_by_extension: map_into_map(lhs_wbe, rhs_wbe),
+                wasted_by_category: map_into_map(lhs_wbc, rhs_wbc),
                 info_by_crate: map_into_map(
                     info_by_crate,
                     crate_collection_info_from_version_info(crate_name, info_by_version),
@@ -325,29 +341,15 @@ impl crate::engine::report::generic::Aggregate for Report {
         Ok(())
     }
 
-    async fn load_previous_top_level_state(out_dir: &Path, progress: &mut prodash::tree::Item) -> Option<Self> {
-        let path = path_from_prefix(out_dir, TOP_LEVEL_REPORT_NAME);
-        progress.blocked("loading previous top-level waste report from disk", None);
-        blocking::unblock(move || std::fs::read(path))
-            .await
-            .ok()
-            .and_then(|v| rmp_serde::from_read(v.as_slice()).ok())
+    fn complete_json(&self, out: &mut Vec<u8>) -> Result<bool> {
+        serde_json::to_writer_pretty(out, &json::Document::from(self))?;
+        Ok(true)
     }
 
-    async fn load_previous_state(&self, out_dir: &Path, progress: &mut prodash::tree::Item) -> Option<Self> {
-        let path = path_to_storage_location(self, out_dir);
-        progress.blocked("loading previous waste report from disk", None);
-        blocking::unblock(move || std::fs::read(path))
-            .await
-            .ok()
-            .and_then(|v| rmp_serde::from_read(v.as_slice()).ok())
-    }
-    async fn store_current_state(&self, out_dir: &Path, progress: &mut prodash::tree::Item) -> Result<()> {
-        let path = path_to_storage_location(self, out_dir);
-        progress.blocked("storing current waste report to disk", None);
-        let data = rmp_serde::to_vec(self)?;
-        blocking::unblock(move || std::fs::write(path, data))
-            .await
-            .map_err(Into::into)
+    fn crate_name(&self) -> Option<&str> {
+        match self {
+            Report::Version { crate_name, .. } | Report::Crate { crate_name, .. } => Some(crate_name),
+            Report::CrateCollection { .. } => None,
+        }
     }
 }
@@ -1,26 +1,101 @@
 use crate::persistence::TableAccess;
-use crate::{error::Result, model::TaskResult, persistence};
+use crate::{
+    error::{Error, Result},
+    model::{db_dump, TaskResult},
+    persistence,
+};
 use async_trait::async_trait;
+use once_cell::sync::OnceCell;
+use std::{convert::TryFrom, path::Path};
 
 pub use criner_waste_report::*;
 
 mod merge;
 
+const REPORT_VERSION: &str = "1.5.0";
+
+struct Configured {
+    policy: WastePolicy,
+    version: String,
+}
+
+static CONFIGURED: OnceCell<Configured> = OnceCell::new();
+
+fn configured() -> &'static Configured {
+    CONFIGURED.get_or_init(|| Configured {
+        policy: WastePolicy::default(),
+        version: REPORT_VERSION.into(),
+    })
+}
+
+/// Return `version` with a suffix identifying `policy`, unless it is the default policy.
+pub(crate) fn version_with_policy(version: &str, policy: &WastePolicy) -> String {
+    match policy.hash() {
+        Some(hash) => format!("{}+{:016x}", version, hash),
+        None => version.into(),
+    }
+}
+
+/// The waste policy all reports of this process are generated with.
+pub(crate) fn policy() -> &'static WastePolicy {
+    &configured().policy
+}
+
+/// Read a waste policy from the TOML file at `path`.
+pub fn policy_from_toml_file(path: impl AsRef<Path>) -> Result<WastePolicy> {
+    let path = path.as_ref();
+    WastePolicy::from_toml(&std::fs::read_to_string(path)?)
+        .map_err(|err| Error::Message(format!("'{}': {}", path.display(), err)))
+}
+
+/// Use `policy` for all waste reports of this process. It must be called before any report is generated, as their
+/// versions depend on it.
+pub fn set_waste_policy(policy: WastePolicy) -> Result<()> {
+    let version = version_with_policy(REPORT_VERSION, &policy);
+    CONFIGURED
+        .set(Configured { policy, version })
+        .map_err(|_| Error::Bug("The waste policy can only be set once, before it is used"))
+}
+
+/// The size of the `.crate` file of a crate version as published, according to the crates.io database dump.
+pub(crate) fn compressed_size_of(
+    connection: &persistence::ThreadSafeConnection,
+    crate_name: &str,
+    crate_version: &str,
+) -> Result<Option<u64>> {
+    Ok(connection
+        .get("crates.io-crate", crate_name)?
+        .map(|data| db_dump::Crate::try_from(data.as_slice()))
+        .transpose()?
+        .and_then(|krate| {
+            krate
+                .versions
+                .into_iter()
+                .find(|v| v.semver == crate_version)
+                .and_then(|v| v.crate_size)
+        })
+        .map(u64::from))
+}
+
+/// The extracted crate version to generate a report for, along with the size of its `.crate` file if known
+pub struct WasteResult {
+    pub exploded_crate: TaskResult,
+    pub compressed_size_in_bytes: Option<u64>,
+}
+
 pub struct Generator;
 
-// NOTE: When multiple reports should be combined, this must become a compound generator which combines
-// multiple implementations into one, statically.
 #[async_trait]
 impl super::generic::Generator for Generator {
     type Report = Report;
-    type DBResult = TaskResult;
+    type DBResult = WasteResult;
 
     fn name() -> &'static str {
         "waste"
     }
 
     fn version() -> &'static str {
-        "1.0.0"
+        &configured().version
     }
 
     fn fq_result_key(crate_name: &str, crate_version: &str, key_buf: &mut String) {
@@ -28,6 +103,7 @@ impl super::generic::Generator for Generator {
         let dummy_result = TaskResult::ExplodedCrate {
             entries_meta_data: Default::default(),
             selected_entries: Default::default(),
+            findings: Default::default(),
         };
         dummy_result.fq_key(crate_name, crate_version, &dummy_task, key_buf);
     }
@@ -37,22 +113,31 @@ impl super::generic::Generator for Generator {
         crate_name: &str,
         crate_version: &str,
         key_buf: &mut String,
-    ) -> Result<Option<TaskResult>> {
+    ) -> Result<Option<WasteResult>> {
         Self::fq_result_key(crate_name, crate_version, key_buf);
-        let table = persistence::TaskResultTable { inner: connection };
-        table.get(&key_buf)
+        let table = persistence::TaskResultTable {
+            inner: connection.clone(),
+        };
+        Ok(match table.get(&key_buf)? {
+            Some(exploded_crate) => Some(WasteResult {
+                exploded_crate,
+                compressed_size_in_bytes: compressed_size_of(&connection, crate_name, crate_version)?,
+            }),
+            None => None,
+        })
     }
 
     async fn generate_report(
         crate_name: &str,
         crate_version: &str,
-        result: TaskResult,
+        result: WasteResult,
         _progress: &mut prodash::tree::Item,
     ) -> Result<Self::Report> {
-        Ok(match result {
+        Ok(match result.exploded_crate {
             TaskResult::ExplodedCrate {
                 entries_meta_data,
                 selected_entries,
+                findings: _,
             } => Report::from_package(
                 crate_name,
                 crate_version,
@@ -60,7 +145,9 @@ impl super::generic::Generator for Generator {
                     entries_meta_data,
                     entries: selected_entries,
                 },
-            ),
+                policy(),
+            )
+            .with_compressed_size(result.compressed_size_in_bytes),
             _ => unreachable!("caller must assure we are always an exploded entry"),
         })
     }
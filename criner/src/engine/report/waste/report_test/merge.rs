@@ -1,6 +1,6 @@
 use crate::{
     engine::report::generic::Aggregate,
-    engine::report::waste::{AggregateFileInfo, Fix, PotentialWaste, Report, VersionInfo},
+    engine::report::waste::{AggregateFileInfo, CompressedSize, Fix, PotentialWaste, Report, VersionInfo},
     model::TarHeader,
 };
 use common_macros::b_tree_map;
@@ -14,6 +14,9 @@ fn crate_merging_version_equivalent_to_version_merging_crate() {
         total_size_in_bytes: 1,
         total_files: 4,
         wasted_files: vec![("a.a".into(), 20)],
+        cargo_toml_diff: None,
+        fix_verification: None,
+        compressed_size_in_bytes: None,
         suggested_fix: Some(Fix::RemoveExclude),
     };
 
@@ -27,6 +30,7 @@ fn crate_merging_version_equivalent_to_version_merging_crate() {
             "b".into()  => AggregateFileInfo {total_files: 3, total_bytes: 80},
             "c".into()  => AggregateFileInfo {total_files: 1, total_bytes: 90},
         },
+        wasted_by_category: BTreeMap::new(),
     };
     assert_eq!(version.clone().merge(krate.clone()), krate.merge(version));
 }
@@ -47,12 +51,16 @@ fn crate_and_crate_of_different_name() {
                         total_files: 8
                     }),
                     waste_latest_version: None,
+                    waste_by_category: BTreeMap::new(),
+                    compressed: None,
                 },
                 "2".into() => VersionInfo {
                     all: AggregateFileInfo { total_files: 4, total_bytes: 1 },
                     waste: AggregateFileInfo { total_files: 3, total_bytes: 50 },
                     potential_gains: None,
                     waste_latest_version: None,
+                    waste_by_category: BTreeMap::new(),
+                    compressed: None,
                 }
             },
             wasted_by_extension: b_tree_map! {
@@ -60,6 +68,7 @@ fn crate_and_crate_of_different_name() {
                 "b".into()  => AggregateFileInfo {total_files: 2, total_bytes: 20},
                 "c".into()  => AggregateFileInfo {total_files: 3, total_bytes: 30},
             },
+            wasted_by_category: BTreeMap::new(),
         }
         .merge(Report::Crate {
             crate_name: "b".into(),
@@ -71,6 +80,8 @@ fn crate_and_crate_of_different_name() {
                     waste: AggregateFileInfo { total_files: 6, total_bytes: 150 },
                     potential_gains: None,
                     waste_latest_version: None,
+                    waste_by_category: BTreeMap::new(),
+                    compressed: None,
                 }
             },
             wasted_by_extension: b_tree_map! {
@@ -78,25 +89,30 @@ fn crate_and_crate_of_different_name() {
                 "b".into()  => AggregateFileInfo {total_files: 2, total_bytes: 20},
                 "d".into()  => AggregateFileInfo {total_files: 1, total_bytes: 10},
             },
+            wasted_by_category: BTreeMap::new(),
         }),
         Report::CrateCollection {
             total_size_in_bytes: 12,
             total_files: 12,
             info_by_crate: b_tree_map! {
                 "a".into() => VersionInfo {
-                    all: AggregateFileInfo { total_files: 4*2, total_bytes: 1*2},
+                    all: AggregateFileInfo { total_files: 4*2, total_bytes: 2},
                     waste: AggregateFileInfo { total_files: 3*2, total_bytes: 50*2},
                     potential_gains: Some(AggregateFileInfo {
                         total_bytes: 2,
                         total_files: 8
                     }),
-                    waste_latest_version: Some(("2".into(), AggregateFileInfo { total_files: 3, total_bytes: 50 }))
+                    waste_latest_version: Some(("2".into(), AggregateFileInfo { total_files: 3, total_bytes: 50 })),
+                    waste_by_category: BTreeMap::new(),
+                    compressed: None,
                 },
                 "b".into() => VersionInfo {
                     all: AggregateFileInfo { total_files: 8, total_bytes: 10 },
                     waste: AggregateFileInfo { total_files: 6, total_bytes: 150 },
                     potential_gains: None,
-                    waste_latest_version: Some(("2".into(), AggregateFileInfo { total_files: 6, total_bytes: 150 }))
+                    waste_latest_version: Some(("2".into(), AggregateFileInfo { total_files: 6, total_bytes: 150 })),
+                    waste_by_category: BTreeMap::new(),
+                    compressed: None,
                 }
             },
             wasted_by_extension: b_tree_map! {
@@ -105,6 +121,7 @@ fn crate_and_crate_of_different_name() {
                 "c".into()  => AggregateFileInfo {total_files: 3, total_bytes: 30},
                 "d".into()  => AggregateFileInfo {total_files: 1, total_bytes: 10},
             },
+            wasted_by_category: BTreeMap::new(),
         }
     );
 }
@@ -122,7 +139,9 @@ fn two_crate_collections() {
                     total_files: 5,
                     total_bytes: 10,
                 }),
-                waste_latest_version: Some(("3".into(), AggregateFileInfo { total_files: 1, total_bytes: 20},))
+                waste_latest_version: Some(("3".into(), AggregateFileInfo { total_files: 1, total_bytes: 20},)),
+                waste_by_category: BTreeMap::new(),
+                compressed: None,
             },
         },
         wasted_by_extension: b_tree_map! {
@@ -131,6 +150,7 @@ fn two_crate_collections() {
             "c".into()  => AggregateFileInfo {total_files: 3, total_bytes: 30},
             "d".into()  => AggregateFileInfo {total_files: 1, total_bytes: 10},
         },
+        wasted_by_category: BTreeMap::new(),
     };
     let rhs_collection = Report::CrateCollection {
         total_size_in_bytes: 12,
@@ -143,13 +163,17 @@ fn two_crate_collections() {
                     total_files: 50,
                     total_bytes: 100,
                 }),
-                waste_latest_version: Some(("4".into(), AggregateFileInfo { total_files: 2, total_bytes: 40}))
+                waste_latest_version: Some(("4".into(), AggregateFileInfo { total_files: 2, total_bytes: 40})),
+                waste_by_category: BTreeMap::new(),
+                compressed: None,
             },
             "b".into() => VersionInfo {
                 all: AggregateFileInfo { total_files: 8, total_bytes: 10 },
                 waste: AggregateFileInfo { total_files: 6, total_bytes: 150 },
                 potential_gains: None,
-                waste_latest_version: Some(("1".into(), AggregateFileInfo { total_files: 3, total_bytes: 50}))
+                waste_latest_version: Some(("1".into(), AggregateFileInfo { total_files: 3, total_bytes: 50})),
+                waste_by_category: BTreeMap::new(),
+                compressed: None,
             },
         },
         wasted_by_extension: b_tree_map! {
@@ -159,6 +183,7 @@ fn two_crate_collections() {
             "d".into()  => AggregateFileInfo {total_files: 1, total_bytes: 10},
             "e".into()  => AggregateFileInfo {total_files: 4, total_bytes: 2},
         },
+        wasted_by_category: BTreeMap::new(),
     };
     assert_eq!(
         lhs_collection.merge(rhs_collection),
@@ -173,22 +198,27 @@ fn two_crate_collections() {
                         total_files: 55,
                         total_bytes: 110
                     }),
-                    waste_latest_version: Some(("4".into(), AggregateFileInfo { total_files: 2, total_bytes: 40}))
+                    waste_latest_version: Some(("4".into(), AggregateFileInfo { total_files: 2, total_bytes: 40})),
+                    waste_by_category: BTreeMap::new(),
+                    compressed: None,
                 },
                 "b".into() => VersionInfo {
                     all: AggregateFileInfo { total_files: 8, total_bytes: 10 },
                     waste: AggregateFileInfo { total_files: 6, total_bytes: 150 },
                     potential_gains: None,
-                    waste_latest_version: Some(("1".into(), AggregateFileInfo { total_files: 3, total_bytes: 50}))
+                    waste_latest_version: Some(("1".into(), AggregateFileInfo { total_files: 3, total_bytes: 50})),
+                    waste_by_category: BTreeMap::new(),
+                    compressed: None,
                 }
             },
             wasted_by_extension: b_tree_map! {
                 "a".into()  => AggregateFileInfo {total_files: 4*2, total_bytes: 40*2},
                 "b".into()  => AggregateFileInfo {total_files: 4*2, total_bytes: 40*2},
                 "c".into()  => AggregateFileInfo {total_files: 3*2, total_bytes: 30*2},
-                "d".into()  => AggregateFileInfo {total_files: 1*2, total_bytes: 10*2},
+                "d".into()  => AggregateFileInfo {total_files: 2, total_bytes: 10*2},
                 "e".into()  => AggregateFileInfo {total_files: 4, total_bytes: 2},
             },
+            wasted_by_category: BTreeMap::new(),
         }
     );
 }
@@ -209,6 +239,8 @@ fn crate_and_crate_of_same_name() {
                         total_bytes: 100
                     }),
                     waste_latest_version: None,
+                    waste_by_category: BTreeMap::new(),
+                    compressed: None,
                 }
             },
             wasted_by_extension: b_tree_map! {
@@ -216,6 +248,7 @@ fn crate_and_crate_of_same_name() {
                 "b".into()  => AggregateFileInfo {total_files: 2, total_bytes: 20},
                 "c".into()  => AggregateFileInfo {total_files: 3, total_bytes: 30},
             },
+            wasted_by_category: BTreeMap::new(),
         }
         .merge(Report::Crate {
             crate_name: "a".into(),
@@ -230,6 +263,8 @@ fn crate_and_crate_of_same_name() {
                         total_bytes: 10
                     }),
                     waste_latest_version: None,
+                    waste_by_category: BTreeMap::new(),
+                    compressed: None,
                 }
             },
             wasted_by_extension: b_tree_map! {
@@ -237,6 +272,7 @@ fn crate_and_crate_of_same_name() {
                 "b".into()  => AggregateFileInfo {total_files: 2, total_bytes: 20},
                 "d".into()  => AggregateFileInfo {total_files: 1, total_bytes: 10},
             },
+            wasted_by_category: BTreeMap::new(),
         }),
         Report::Crate {
             crate_name: "a".to_string(),
@@ -251,6 +287,8 @@ fn crate_and_crate_of_same_name() {
                         total_bytes: 100
                     }),
                     waste_latest_version: None,
+                    waste_by_category: BTreeMap::new(),
+                    compressed: None,
                 },
                 "2".into() => VersionInfo {
                     all: AggregateFileInfo { total_files: 8, total_bytes: 10 },
@@ -260,6 +298,8 @@ fn crate_and_crate_of_same_name() {
                         total_bytes: 10
                     }),
                     waste_latest_version: None,
+                    waste_by_category: BTreeMap::new(),
+                    compressed: None,
                 }
             },
             wasted_by_extension: b_tree_map! {
@@ -268,6 +308,7 @@ fn crate_and_crate_of_same_name() {
                 "c".into()  => AggregateFileInfo {total_files: 3, total_bytes: 30},
                 "d".into()  => AggregateFileInfo {total_files: 1, total_bytes: 10},
             },
+            wasted_by_category: BTreeMap::new(),
         }
     );
 }
@@ -280,7 +321,10 @@ fn two_versions_of_same_crate() {
             crate_version: "1".into(),
             total_size_in_bytes: 1,
             total_files: 4,
-            wasted_files: vec![("a.a".into(), 20), ("b/a.b".into(), 20), ("c/a.b".into(), 10)],
+            wasted_files: vec![("a.a".into(), 20), ("tests/a.b".into(), 20), ("docs/a.b".into(), 10)],
+            cargo_toml_diff: None,
+            fix_verification: None,
+            compressed_size_in_bytes: None,
             suggested_fix: Some(Fix::ImprovedInclude {
                 include: vec![],
                 include_removed: vec![],
@@ -289,7 +333,8 @@ fn two_versions_of_same_crate() {
                     potential_waste: vec![TarHeader {
                         path: (&b"a/d.c"[..]).into(),
                         size: 10,
-                        entry_type: 0
+                        entry_type: 0,
+                        hash: None
                     }]
                 }),
                 has_build_script: false
@@ -300,7 +345,10 @@ fn two_versions_of_same_crate() {
             crate_version: "2".into(),
             total_size_in_bytes: 2,
             total_files: 5,
-            wasted_files: vec![("a.a".into(), 40), ("c/a.b".into(), 50), ("d/a.c".into(), 90)],
+            wasted_files: vec![("a.a".into(), 40), ("docs/a.b".into(), 50), ("d/a.c".into(), 90)],
+            cargo_toml_diff: None,
+            fix_verification: None,
+            compressed_size_in_bytes: None,
             suggested_fix: Some(Fix::ImprovedInclude {
                 include: vec![],
                 include_removed: vec![],
@@ -309,7 +357,8 @@ fn two_versions_of_same_crate() {
                     potential_waste: vec![TarHeader {
                         path: (&b"a/d.c"[..]).into(),
                         size: 100,
-                        entry_type: 0
+                        entry_type: 0,
+                        hash: None
                     }]
                 }),
                 has_build_script: false
@@ -325,12 +374,24 @@ fn two_versions_of_same_crate() {
                                 waste: AggregateFileInfo { total_files: 3, total_bytes: 50 },
                                 potential_gains: Some(AggregateFileInfo {total_files: 1, total_bytes: 10}),
                                 waste_latest_version: None,
+                                waste_by_category: b_tree_map! {
+                                    "generated".into() => AggregateFileInfo { total_files: 1, total_bytes: 20 },
+                                    "tests".into() => AggregateFileInfo { total_files: 1, total_bytes: 20 },
+                                    "documentation".into() => AggregateFileInfo { total_files: 1, total_bytes: 10 },
+                                },
+                                compressed: None,
                               },
                  "2".into() => VersionInfo {
                                 all: AggregateFileInfo { total_files: 5, total_bytes: 2 },
                                 waste: AggregateFileInfo { total_files: 3, total_bytes: 180 },
                                 potential_gains: Some(AggregateFileInfo {total_files: 1, total_bytes: 100}),
                                 waste_latest_version: None,
+                                waste_by_category: b_tree_map! {
+                                    "generated".into() => AggregateFileInfo { total_files: 1, total_bytes: 40 },
+                                    "other".into() => AggregateFileInfo { total_files: 1, total_bytes: 90 },
+                                    "documentation".into() => AggregateFileInfo { total_files: 1, total_bytes: 50 },
+                                },
+                                compressed: None,
                               },
             },
             wasted_by_extension: b_tree_map! {
@@ -338,6 +399,12 @@ fn two_versions_of_same_crate() {
                 "b".into()  => AggregateFileInfo {total_files: 3, total_bytes: 80},
                 "c".into()  => AggregateFileInfo {total_files: 1, total_bytes: 90},
             },
+            wasted_by_category: b_tree_map! {
+                "generated".into() => AggregateFileInfo { total_files: 2, total_bytes: 60 },
+                "other".into() => AggregateFileInfo { total_files: 1, total_bytes: 90 },
+                "tests".into() => AggregateFileInfo { total_files: 1, total_bytes: 20 },
+                "documentation".into() => AggregateFileInfo { total_files: 2, total_bytes: 60 },
+            },
         }
     );
 }
@@ -351,6 +418,9 @@ fn two_versions_of_different_crate() {
             total_size_in_bytes: 1,
             total_files: 4,
             wasted_files: vec![("a.a".into(), 20), ("b/a.b".into(), 20), ("c/a.b".into(), 10)],
+            cargo_toml_diff: None,
+            fix_verification: None,
+            compressed_size_in_bytes: None,
             suggested_fix: Some(Fix::ImprovedInclude {
                 include: vec![],
                 include_removed: vec![],
@@ -359,7 +429,8 @@ fn two_versions_of_different_crate() {
                     potential_waste: vec![TarHeader {
                         path: (&b"a/b.c"[..]).into(),
                         size: 10,
-                        entry_type: 0
+                        entry_type: 0,
+                        hash: None
                     }]
                 }),
                 has_build_script: false
@@ -371,6 +442,9 @@ fn two_versions_of_different_crate() {
             total_size_in_bytes: 2,
             total_files: 5,
             wasted_files: vec![("a.a".into(), 40), ("c/a.b".into(), 50), ("d/a.c".into(), 90)],
+            cargo_toml_diff: None,
+            fix_verification: None,
+            compressed_size_in_bytes: None,
             suggested_fix: Some(Fix::ImprovedInclude {
                 include: vec![],
                 include_removed: vec![],
@@ -379,7 +453,8 @@ fn two_versions_of_different_crate() {
                     potential_waste: vec![TarHeader {
                         path: (&b"a/d.c"[..]).into(),
                         size: 100,
-                        entry_type: 0
+                        entry_type: 0,
+                        hash: None
                     }]
                 }),
                 has_build_script: false
@@ -393,13 +468,23 @@ fn two_versions_of_different_crate() {
                                 all: AggregateFileInfo { total_files: 4, total_bytes: 1 },
                                 waste: AggregateFileInfo { total_files: 3, total_bytes: 50 },
                                 potential_gains: Some(AggregateFileInfo{total_files: 1, total_bytes: 10}),
-                                waste_latest_version: Some(("1".into(), AggregateFileInfo { total_files: 3, total_bytes: 50 }))
+                                waste_latest_version: Some(("1".into(), AggregateFileInfo { total_files: 3, total_bytes: 50 })),
+                                waste_by_category: b_tree_map! {
+                                    "generated".into() => AggregateFileInfo { total_files: 1, total_bytes: 20 },
+                                    "other".into() => AggregateFileInfo { total_files: 2, total_bytes: 30 },
+                                },
+                                compressed: None,
                               },
                  "b".into() => VersionInfo {
                                 all: AggregateFileInfo { total_files: 5, total_bytes: 2 },
                                 waste: AggregateFileInfo { total_files: 3, total_bytes: 180 },
                                 potential_gains: Some(AggregateFileInfo{total_files: 1, total_bytes: 100}),
-                                waste_latest_version: Some(("1".into(), AggregateFileInfo { total_files: 3, total_bytes: 180 }))
+                                waste_latest_version: Some(("1".into(), AggregateFileInfo { total_files: 3, total_bytes: 180 })),
+                                waste_by_category: b_tree_map! {
+                                    "generated".into() => AggregateFileInfo { total_files: 1, total_bytes: 40 },
+                                    "other".into() => AggregateFileInfo { total_files: 2, total_bytes: 140 },
+                                },
+                                compressed: None,
                               },
             },
             wasted_by_extension: b_tree_map! {
@@ -407,6 +492,49 @@ fn two_versions_of_different_crate() {
                 "b".into()  => AggregateFileInfo {total_files: 3, total_bytes: 80},
                 "c".into()  => AggregateFileInfo {total_files: 1, total_bytes: 90},
             },
+            wasted_by_category: b_tree_map! {
+                "generated".into() => AggregateFileInfo { total_files: 2, total_bytes: 60 },
+                "other".into() => AggregateFileInfo { total_files: 4, total_bytes: 170 },
+            },
         }
     );
 }
+
+#[test]
+fn compressed_sizes_are_summed_where_known() {
+    let version = |crate_name: &str, crate_version: &str, compressed_size_in_bytes| Report::Version {
+        crate_name: crate_name.into(),
+        crate_version: crate_version.into(),
+        total_size_in_bytes: 100,
+        total_files: 2,
+        wasted_files: vec![("tests/a.rs".into(), 50)],
+        cargo_toml_diff: None,
+        fix_verification: None,
+        compressed_size_in_bytes,
+        suggested_fix: None,
+    };
+    let report = version("a", "1", Some(40))
+        .merge(version("a", "2", None))
+        .merge(version("a", "3", Some(20)))
+        .merge(version("b", "1", Some(10)));
+    match report {
+        Report::CrateCollection { info_by_crate, .. } => {
+            assert_eq!(
+                info_by_crate["a"].compressed,
+                Some(CompressedSize {
+                    total_bytes: 60,
+                    wasted_bytes: 30
+                }),
+                "versions without known size don't count"
+            );
+            assert_eq!(
+                info_by_crate["b"].compressed,
+                Some(CompressedSize {
+                    total_bytes: 10,
+                    wasted_bytes: 5
+                })
+            );
+        }
+        _ => unreachable!("crates of different names are merged into a collection"),
+    }
+}
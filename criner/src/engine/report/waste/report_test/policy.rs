@@ -0,0 +1,15 @@
+use crate::engine::report::waste::{version_with_policy, WastePolicy};
+
+#[test]
+fn versions_change_along_with_the_waste_policy() {
+    assert_eq!(version_with_policy("1.3.0", &WastePolicy::default()), "1.3.0");
+
+    let policy = WastePolicy::from_toml("allow = [\"assets/**/*\"]").unwrap();
+    let version = version_with_policy("1.3.0", &policy);
+    assert!(version.starts_with("1.3.0+"), "{}", version);
+    assert_eq!(version.len(), "1.3.0+".len() + 16);
+    assert_ne!(
+        version,
+        version_with_policy("1.3.0", &WastePolicy::from_toml("allow = [\"data/**/*\"]").unwrap())
+    );
+}
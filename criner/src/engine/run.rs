@@ -1,4 +1,15 @@
-use crate::{engine::stage, error::Result, model, persistence::Db, utils::*};
+use crate::{
+    engine::{
+        report::waste,
+        stage,
+        work::{self, registry::Registry},
+    },
+    error::Result,
+    model,
+    persistence::Db,
+    utils::*,
+};
+use bytesize::ByteSize;
 use futures_util::{
     future::{Either, FutureExt},
     stream::StreamExt,
@@ -44,6 +55,7 @@ pub async fn non_blocking(
     report_settings: GlobStageRunSettings,
     download_crates_io_database_every_24_hours_starting_at: Option<time::Time>,
     assets_dir: PathBuf,
+    registry: Arc<Registry>,
 ) -> Result<()> {
     check(deadline)?;
     let startup_time = SystemTime::now();
@@ -116,6 +128,7 @@ pub async fn non_blocking(
                     cpu_bound_processors,
                     progress.add_child("Downloads"),
                     assets_dir.clone(),
+                    registry.clone(),
                     startup_time,
                 )
             }
@@ -195,10 +208,20 @@ pub fn blocking(
     process_settings: StageRunSettings,
     report_settings: GlobStageRunSettings,
     download_crates_io_database_every_24_hours_starting_at: Option<time::Time>,
+    external_commands_config: Option<PathBuf>,
+    extraction_config: Option<PathBuf>,
+    waste_policy: Option<PathBuf>,
     root: Arc<prodash::tree::Root>,
     gui: Option<prodash::render::tui::Options>,
 ) -> Result<()> {
     let start_of_computation = SystemTime::now();
+    if let Some(path) = extraction_config {
+        work::cpubound::set_extraction_config(work::cpubound::ExtractionConfig::from_toml_file(path)?)?;
+    }
+    if let Some(path) = waste_policy {
+        waste::set_waste_policy(waste::policy_from_toml_file(path)?)?;
+    }
+    let registry = work::external::registry(external_commands_config.as_deref())?;
     let assets_dir = db.as_ref().join("assets");
     let db = Db::open(db)?;
     std::fs::create_dir_all(&assets_dir)?;
@@ -220,6 +243,7 @@ pub fn blocking(
         report_settings,
         download_crates_io_database_every_24_hours_starting_at,
         assets_dir,
+        Arc::new(registry),
     );
 
     match gui {
@@ -279,14 +303,29 @@ fn context_stream(db: &Db, start_of_computation: SystemTime) -> impl futures_uti
                 .and_then(|c| c.most_recent().ok())
                 .flatten()
                 .map(|(_, c): (_, model::Context)| {
-                    let lines = vec![
+                    let mut lines = vec![
                         Line::Text(wallclock(start_of_computation)),
                         Line::Title("Durations".into()),
                         Line::Text(format!("fetch-crate-versions: {:?}", c.durations.fetch_crate_versions)),
+                        Line::Text(format!("            download: {:?}", c.durations.download)),
+                        Line::Text(format!("          extraction: {:?}", c.durations.extraction)),
+                        Line::Text(format!("   report-generation: {:?}", c.durations.report_generation)),
+                        Line::Text(format!("   db-dump-ingestion: {:?}", c.durations.db_dump_ingestion)),
                         Line::Title("Counts".into()),
-                        Line::Text(format!("crate-versions: {}", c.counts.crate_versions)),
-                        Line::Text(format!("        crates: {}", c.counts.crates)),
+                        Line::Text(format!("  crate-versions: {}", c.counts.crate_versions)),
+                        Line::Text(format!("          crates: {}", c.counts.crates)),
+                        Line::Text(format!("crates-extracted: {}", c.counts.crates_extracted)),
+                        Line::Text(format!("bytes-downloaded: {}", ByteSize(c.counts.bytes_downloaded))),
                     ];
+                    if !c.counts.task_failures.is_empty() {
+                        lines.push(Line::Title("Failures".into()));
+                        lines.extend(
+                            c.counts
+                                .task_failures
+                                .iter()
+                                .map(|(process, failures)| Line::Text(format!("{}: {}", process, failures))),
+                        );
+                    }
                     Event::SetInformation(lines)
                 })
                 .unwrap_or(Event::Tick)
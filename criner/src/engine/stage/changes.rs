@@ -1,12 +1,11 @@
-use crate::persistence::{key_value_iter, new_key_value_query_old_to_new, CrateTable, Keyed};
+use crate::persistence::{CrateTable, Keyed, MetaTable, ReverseDependencyTable};
 use crate::{
     error::{Error, Result},
     model,
-    persistence::{self, new_key_value_insertion, CrateVersionTable, TableAccess},
+    persistence::{self, CrateVersionTable, TableAccess},
     utils::enforce_threaded,
 };
 use crates_index_diff::Index;
-use rusqlite::params;
 use std::convert::TryFrom;
 use std::sync::atomic::AtomicBool;
 use std::{
@@ -16,6 +15,11 @@ use std::{
     time::{Duration, SystemTime},
 };
 
+/// Marks the reverse dependency graph of all crate versions known before it was introduced as built
+const REVERSE_DEPENDENCIES_BUILT: &str = "reverse-dependencies-built";
+/// The amount of crate versions to load at once when building the reverse dependency graph
+const CHUNK_SIZE: usize = 10_000;
+
 pub async fn fetch(
     crates_io_path: impl AsRef<Path>,
     db: persistence::Db,
@@ -59,68 +63,85 @@ pub async fn fetch(
         let db = db.clone();
         let index_path = crates_io_path.as_ref().to_path_buf();
         move || {
-            let mut connection = db.open_connection_no_async_with_busy_wait()?;
-            let mut crates_lut: BTreeMap<_, _> = {
-                let transaction = connection.transaction()?;
-                store_progress.blocked("caching crates", None);
-                let mut statement = new_key_value_query_old_to_new(CrateTable::table_name(), &transaction)?;
-                let iter = key_value_iter::<model::Crate>(&mut statement)?.flat_map(Result::ok);
-                iter.collect()
+            let connection = db.open_connection_with_busy_wait()?;
+            let crate_versions_table = CrateVersionTable {
+                inner: connection.clone(),
+            };
+            let crates_table = CrateTable {
+                inner: connection.clone(),
+            };
+            let meta_table = MetaTable {
+                inner: connection.clone(),
             };
+            let reverse_dependencies_table = ReverseDependencyTable { inner: connection };
+            store_progress.blocked("caching crates", None);
+            let mut crates_lut: BTreeMap<_, _> = crates_table.chunk_old_to_new(None, None)?.into_iter().collect();
 
             let mut key_buf = String::new();
             let crate_versions_len = crate_versions.len();
             let mut new_crate_versions = 0;
             let mut new_crates = 0;
+            let mut new_reverse_dependencies = BTreeMap::<String, model::ReverseDependencies>::new();
+            if !meta_table.is_complete(REVERSE_DEPENDENCIES_BUILT)? {
+                store_progress.blocked("building reverse dependencies of all known crate versions", None);
+                let mut offset = 0;
+                loop {
+                    let versions = crate_versions_table.chunk_old_to_new(None, Some((offset, CHUNK_SIZE)))?;
+                    offset += versions.len();
+                    let mut edges = BTreeMap::new();
+                    for (_, version) in versions.iter() {
+                        add_reverse_dependencies(version, &mut edges);
+                    }
+                    store_reverse_dependencies(&reverse_dependencies_table, edges)?;
+                    if versions.len() != CHUNK_SIZE {
+                        break;
+                    }
+                }
+                meta_table.mark_complete(REVERSE_DEPENDENCIES_BUILT)?;
+            }
             store_progress.blocked("write lock for crate versions", None);
-            let transaction = connection.transaction_with_behavior(rusqlite::TransactionBehavior::Immediate)?;
-            {
-                let mut statement = new_key_value_insertion(CrateVersionTable::table_name(), &transaction)?;
-                for version in crate_versions
+            crate_versions_table.connection().insert_many(
+                CrateVersionTable::table_name(),
+                &mut crate_versions
                     .into_iter()
                     .filter_map(|v| model::CrateVersion::try_from(v).ok())
-                {
-                    key_buf.clear();
-                    version.key_buf(&mut key_buf);
-                    statement.execute(params![&key_buf, rmp_serde::to_vec(&version)?])?;
-                    new_crate_versions += 1;
-
-                    key_buf.clear();
-                    model::Crate::key_from_version_buf(&version, &mut key_buf);
-                    if crates_lut
-                        .entry(key_buf.to_owned())
-                        .or_default()
-                        .merge_mut(&version)
-                        .versions
-                        .len()
-                        == 1
-                    {
-                        new_crates += 1;
-                    }
+                    .map(|version| {
+                        key_buf.clear();
+                        version.key_buf(&mut key_buf);
+                        let item = (key_buf.clone(), rmp_serde::to_vec(&version)?);
+                        new_crate_versions += 1;
 
-                    store_progress.inc();
-                }
-            }
+                        key_buf.clear();
+                        model::Crate::key_from_version_buf(&version, &mut key_buf);
+                        add_reverse_dependencies(&version, &mut new_reverse_dependencies);
+                        if crates_lut
+                            .entry(key_buf.to_owned())
+                            .or_default()
+                            .merge_mut(&version)
+                            .versions
+                            .len()
+                            == 1
+                        {
+                            new_crates += 1;
+                        }
 
-            store_progress.blocked("commit crate versions", None);
-            transaction.commit()?;
+                        store_progress.inc();
+                        Ok(item)
+                    }),
+            )?;
 
-            let transaction = {
-                store_progress.blocked("write lock for crates", None);
-                let mut t = connection.transaction_with_behavior(rusqlite::TransactionBehavior::Immediate)?;
-                t.set_drop_behavior(rusqlite::DropBehavior::Commit);
-                t
-            };
-            {
-                let mut statement = new_key_value_insertion(CrateTable::table_name(), &transaction)?;
-                store_progress.init(Some(crates_lut.len()), Some("crates".into()));
-                for (key, value) in crates_lut.into_iter() {
-                    statement.execute(params![key, rmp_serde::to_vec(&value)?])?;
+            store_progress.blocked("write lock for crates", None);
+            store_progress.init(Some(crates_lut.len()), Some("crates".into()));
+            crates_table.connection().insert_many(
+                CrateTable::table_name(),
+                &mut crates_lut.into_iter().map(|(key, value)| {
                     store_progress.inc();
-                }
-            }
-            store_progress.blocked("commit crates", None);
-            transaction.commit()?;
+                    Ok((key, rmp_serde::to_vec(&value)?))
+                }),
+            )?;
+
+            store_progress.blocked("write lock for reverse dependencies", None);
+            store_reverse_dependencies(&reverse_dependencies_table, new_reverse_dependencies)?;
 
             Index::from_path_or_cloned(index_path)?.set_last_seen_reference(last_seen_git_object)?;
             db.open_context()?.update_today(|c| {
@@ -137,3 +158,29 @@ pub async fn fetch(
     .await??;
     Ok(())
 }
+
+/// Add the edges of `version` to all crates it depends on to `out`, by their key in the reverse dependency table.
+fn add_reverse_dependencies(version: &model::CrateVersion, out: &mut BTreeMap<String, model::ReverseDependencies>) {
+    let mut key_buf = String::new();
+    for (dependency, dependent) in version.reverse_dependencies() {
+        key_buf.clear();
+        model::ReverseDependencies::key_from(dependency, &version.name, &version.version, &mut key_buf);
+        let edges = &mut out.entry(key_buf.clone()).or_default().dependents;
+        if !edges.contains(&dependent) {
+            edges.push(dependent);
+        }
+    }
+}
+
+/// Write `edges` as they are, which is possible without merging as each key belongs to a single immutable crate version.
+fn store_reverse_dependencies(
+    table: &ReverseDependencyTable,
+    edges: BTreeMap<String, model::ReverseDependencies>,
+) -> Result<usize> {
+    table.connection().insert_many(
+        ReverseDependencyTable::table_name(),
+        &mut edges
+            .into_iter()
+            .map(|(key, value)| Ok((key, rmp_serde::to_vec(&value)?))),
+    )
+}
@@ -349,5 +349,5 @@ pub fn into_crates(
 
     progress.done(format!("assigned {} owners", crate_owners_len));
 
-    crate_by_id.into_iter().map(|(_, v)| v).collect()
+    crate_by_id.into_values().collect()
 }
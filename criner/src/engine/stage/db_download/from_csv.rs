@@ -88,4 +88,4 @@ pub fn mapping_downloads(
     })?;
     decode.info(format!("Decoded {} {} into memory", map.len(), name));
     Ok(map)
-}
\ No newline at end of file
+}
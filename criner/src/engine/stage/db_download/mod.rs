@@ -1,11 +1,7 @@
 use crate::model::db_dump;
-use crate::{
-    engine::work, persistence::new_key_value_insertion, persistence::Db, persistence::TableAccess, Error, Result,
-};
+use crate::{engine::work, persistence::Db, persistence::TableAccess, Error, Result};
 use bytesize::ByteSize;
 use futures_util::FutureExt;
-use rusqlite::params;
-use rusqlite::TransactionBehavior;
 use std::{collections::BTreeMap, fs::File, io::BufReader, path::PathBuf};
 
 mod convert;
@@ -16,18 +12,14 @@ fn store(db: Db, crates: Vec<db_dump::Crate>, mut progress: prodash::tree::Item)
     let now = std::time::SystemTime::now();
     let crates_len = crates.len();
     progress.init(Some(crates_len), Some("crates stored".into()));
-    let mut connection = db.open_connection_no_async_with_busy_wait()?;
-    let transaction = connection.transaction_with_behavior(TransactionBehavior::Immediate)?;
-    {
-        let mut insert = new_key_value_insertion("crates.io-crate", &transaction)?;
-        for mut krate in crates.into_iter() {
+    db.open_connection_with_busy_wait()?.insert_many(
+        "crates.io-crate",
+        &mut crates.into_iter().map(|mut krate| {
             progress.inc();
             krate.stored_at = now;
-            let data = rmp_serde::to_vec(&krate)?;
-            insert.execute(params![krate.name, data])?;
-        }
-    }
-    transaction.commit()?;
+            Ok((krate.name.clone(), rmp_serde::to_vec(&krate)?))
+        }),
+    )?;
     progress.done(format!("Stored {} crates in database", crates_len));
     Ok(())
 }
@@ -201,9 +193,7 @@ pub async fn schedule(
                 db.clone(),
                 progress.add_child("↓ IDLE"),
                 rx,
-                work::iobound::Agent::new(&db, tx_result, {
-                    move |_, _, output_file_path| Some(output_file_path.to_path_buf())
-                })?,
+                work::iobound::Agent::with_downloaded_files(&db, tx_result)?,
                 max_retries_on_timeout,
             )
             .map(|r| {
@@ -250,8 +240,10 @@ pub async fn schedule(
             .map_err(Error::send_msg("Download Request"))?;
         drop(tx_io);
         if let Ok(db_file_path) = rx_result.recv().await {
+            let start = std::time::SystemTime::now();
             blocking::unblock({
                 let progress = progress.add_child("ingest");
+                let db = db.clone();
                 move || extract_and_ingest(db, progress, db_file_path)
             })
             .await
@@ -259,6 +251,9 @@ pub async fn schedule(
                 progress.fail(format!("ingestion failed: {}", err));
                 err
             })?;
+            db.open_context()?.update_today(|c| {
+                c.durations.db_dump_ingestion += start.elapsed().unwrap_or_default();
+            })?;
         }
     }
 
@@ -240,7 +240,7 @@ pub fn select_callback(
     }
 }
 
-pub fn repo_with_working_dir(req: WriteRequest, send: &WriteCallbackState) -> BoxFuture<Result<WriteInstruction>> {
+pub fn repo_with_working_dir(req: WriteRequest, send: &WriteCallbackState) -> BoxFuture<'_, Result<WriteInstruction>> {
     async move {
         send.as_ref()
             .expect("send to be available if a repo is available")
@@ -252,7 +252,7 @@ pub fn repo_with_working_dir(req: WriteRequest, send: &WriteCallbackState) -> Bo
     .boxed()
 }
 
-pub fn repo_bare(req: WriteRequest, send: &WriteCallbackState) -> BoxFuture<Result<WriteInstruction>> {
+pub fn repo_bare(req: WriteRequest, send: &WriteCallbackState) -> BoxFuture<'_, Result<WriteInstruction>> {
     async move {
         send.as_ref()
             .expect("send to be available if a repo is available")
@@ -264,6 +264,6 @@ pub fn repo_bare(req: WriteRequest, send: &WriteCallbackState) -> BoxFuture<Resu
     .boxed()
 }
 
-pub fn not_available(req: WriteRequest, _state: &WriteCallbackState) -> BoxFuture<Result<WriteInstruction>> {
+pub fn not_available(req: WriteRequest, _state: &WriteCallbackState) -> BoxFuture<'_, Result<WriteInstruction>> {
     async move { Ok(WriteInstruction::DoWrite(req)) }.boxed()
 }
@@ -1,14 +1,213 @@
 use crate::{
-    engine::report,
-    persistence::{self, new_key_value_query_old_to_new_filtered, TableAccess},
+    engine::report::{
+        self,
+        generic::{WriteCallback, WriteCallbackState},
+    },
+    persistence::{self, TableAccess},
     utils::check,
     {Error, Result},
 };
-use futures_util::FutureExt;
-use std::{path::PathBuf, time::SystemTime};
+use futures_util::{future::BoxFuture, FutureExt};
+use std::{
+    marker::PhantomData,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::SystemTime,
+};
 
 mod git;
 
+/// A report generator with its type erased, so that any amount of them can be fed in a single pass over all crates.
+pub trait ReportKind: Send + Sync {
+    /// The name of the report, which is also the name of its output directory
+    fn name(&self) -> &'static str;
+    /// Put the key of the task result a report for the given crate version is generated from into `key_buf`.
+    fn fq_result_key(&self, crate_name: &str, crate_version: &str, key_buf: &mut String);
+    /// Prepare the output directory, the incremental cache and git for a new pass and start aggregating reports.
+    fn start<'a>(
+        &'a self,
+        db: persistence::Db,
+        output_dir: &'a Path,
+        globbed: bool,
+        num_chunks: usize,
+        cpu_o_bound_processors: u32,
+        progress: prodash::tree::Item,
+    ) -> BoxFuture<'a, Result<Box<dyn Pass>>>;
+}
+
+/// A report generator during a pass over all crates
+pub trait Pass: Send {
+    /// Return a future which writes the report files for a `chunk` of crates and passes their report on to the aggregator.
+    fn write_files(
+        &self,
+        chunk: Vec<(String, Vec<u8>)>,
+        progress: prodash::tree::Item,
+    ) -> BoxFuture<'static, Result<()>>;
+    /// Wait for all reports to be aggregated and written.
+    fn finish(self: Box<Self>) -> BoxFuture<'static, ()>;
+}
+
+/// Makes any `Generator` a `ReportKind`
+pub struct Kind<G>(PhantomData<fn() -> G>);
+
+impl<G> Default for Kind<G> {
+    fn default() -> Self {
+        Kind(PhantomData)
+    }
+}
+
+/// All reports generated in one pass, in order.
+pub fn default_kinds() -> Vec<Arc<dyn ReportKind>> {
+    vec![
+        Arc::new(Kind::<report::waste::Generator>::default()),
+        Arc::new(Kind::<report::security::Generator>::default()),
+        Arc::new(Kind::<report::build_script::Generator>::default()),
+        Arc::new(Kind::<report::lockfile::Generator>::default()),
+        Arc::new(Kind::<report::dependency_weight::Generator>::default()),
+    ]
+}
+
+struct GeneratorPass<G: report::generic::Generator> {
+    db: persistence::Db,
+    report_dir: PathBuf,
+    cache_dir: Option<PathBuf>,
+    git_handle: WriteCallback,
+    git_state: WriteCallbackState,
+    git_join_handle: Option<std::thread::JoinHandle<Result<()>>>,
+    reports: async_channel::Sender<Result<Option<G::Report>>>,
+    merge_reports: crate::spawn::Task<()>,
+    progress: prodash::tree::Item,
+}
+
+impl<G> ReportKind for Kind<G>
+where
+    G: report::generic::Generator + Send + 'static,
+{
+    fn name(&self) -> &'static str {
+        G::name()
+    }
+
+    fn fq_result_key(&self, crate_name: &str, crate_version: &str, key_buf: &mut String) {
+        G::fq_result_key(crate_name, crate_version, key_buf)
+    }
+
+    fn start<'a>(
+        &'a self,
+        db: persistence::Db,
+        output_dir: &'a Path,
+        globbed: bool,
+        num_chunks: usize,
+        cpu_o_bound_processors: u32,
+        mut progress: prodash::tree::Item,
+    ) -> BoxFuture<'a, Result<Box<dyn Pass>>> {
+        async move {
+            let report_dir = output_dir.join(G::name());
+            blocking::unblock({
+                let dir = report_dir.clone();
+                move || std::fs::create_dir_all(dir)
+            })
+            .await?;
+            let (cache_dir, (git_handle, git_state, git_join_handle)) = if globbed {
+                (None, (git::not_available as WriteCallback, None, None))
+            } else {
+                let cd = report_dir.join("__incremental_cache__");
+                blocking::unblock({
+                    let cd = cd.clone();
+                    move || std::fs::create_dir_all(cd)
+                })
+                .await?;
+                (
+                    Some(cd),
+                    git::select_callback(cpu_o_bound_processors, &report_dir, progress.add_child("git")),
+                )
+            };
+            let (reports, rx_reports) = async_channel::bounded(cpu_o_bound_processors as usize * 2);
+            let merge_reports = crate::spawn({
+                let mut merge_progress = progress.add_child("report aggregator");
+                merge_progress.init(Some(num_chunks), Some("Reports".into()));
+                G::merge_reports(
+                    report_dir.clone(),
+                    cache_dir.clone(),
+                    merge_progress,
+                    rx_reports,
+                    git_handle,
+                    git_state.clone(),
+                )
+                .map(|_| ())
+                .boxed()
+            });
+            Ok(Box::new(GeneratorPass::<G> {
+                db,
+                report_dir,
+                cache_dir,
+                git_handle,
+                git_state,
+                git_join_handle,
+                reports,
+                merge_reports,
+                progress,
+            }) as Box<dyn Pass>)
+        }
+        .boxed()
+    }
+}
+
+impl<G> Pass for GeneratorPass<G>
+where
+    G: report::generic::Generator + Send + 'static,
+{
+    fn write_files(
+        &self,
+        chunk: Vec<(String, Vec<u8>)>,
+        progress: prodash::tree::Item,
+    ) -> BoxFuture<'static, Result<()>> {
+        let write_files = G::write_files(
+            self.db.clone(),
+            self.report_dir.clone(),
+            self.cache_dir.clone(),
+            chunk,
+            progress,
+            self.git_handle,
+            self.git_state.clone(),
+        );
+        let reports = self.reports.clone();
+        async move {
+            reports
+                .send(write_files.await)
+                .await
+                .map_err(Error::send_msg("send CPU result"))
+        }
+        .boxed()
+    }
+
+    fn finish(self: Box<Self>) -> BoxFuture<'static, ()> {
+        let GeneratorPass {
+            git_state,
+            git_join_handle,
+            reports,
+            merge_reports,
+            mut progress,
+            ..
+        } = *self;
+        async move {
+            drop(git_state);
+            drop(reports);
+            merge_reports.await;
+            G::finish();
+            progress.done(format!("Generating and merging {} report done", G::name()));
+
+            if let Some(handle) = git_join_handle {
+                progress.blocked("waiting for git to finish", None);
+                if handle.join().is_err() {
+                    progress.fail("git failed with unknown error");
+                }
+            };
+        }
+        .boxed()
+    }
+}
+
+/// Generate all reports of `default_kinds()` in a single pass over all crates, or the ones matching `glob`.
 pub async fn generate(
     db: persistence::Db,
     mut progress: prodash::tree::Item,
@@ -17,135 +216,116 @@ pub async fn generate(
     deadline: Option<SystemTime>,
     cpu_o_bound_processors: u32,
 ) -> Result<()> {
-    use report::generic::Generator;
-    let krates = db.open_crates()?;
     let output_dir = assets_dir
         .parent()
         .expect("assets directory to be in criner.db")
         .join("reports");
+    let start = SystemTime::now();
+    generate_reports(
+        &default_kinds(),
+        db.clone(),
+        progress.add_child("reports"),
+        &output_dir,
+        glob,
+        deadline,
+        cpu_o_bound_processors,
+    )
+    .await?;
+    db.open_context()?.update_today(|c| {
+        c.durations.report_generation += start.elapsed().unwrap_or_default();
+    })?;
+    progress.done("Generating all reports done");
+    Ok(())
+}
+
+async fn generate_reports(
+    kinds: &[Arc<dyn ReportKind>],
+    db: persistence::Db,
+    mut progress: prodash::tree::Item,
+    output_dir: &Path,
+    glob: Option<String>,
+    deadline: Option<SystemTime>,
+    cpu_o_bound_processors: u32,
+) -> Result<()> {
+    let krates = db.open_crates()?;
     let glob_str = glob.as_deref();
     let num_crates = krates.count_filtered(glob_str) as usize;
     let chunk_size = 500.min(num_crates);
-    if chunk_size == 0 {
+    if chunk_size == 0 || kinds.is_empty() {
         return Ok(());
     }
     progress.init(Some(num_crates), Some("crates".into()));
 
-    let (processors, rx_result) = {
-        let (tx_task, rx_task) = async_channel::bounded(1);
-        let (tx_result, rx_result) = async_channel::bounded(cpu_o_bound_processors as usize * 2);
+    let processors = {
+        let (tx_task, rx_task) = async_channel::bounded::<BoxFuture<'static, Result<()>>>(1);
 
         for _ in 0..cpu_o_bound_processors {
             let task = rx_task.clone();
-            let result = tx_result.clone();
             crate::spawn(blocking::unblock(move || {
                 futures_lite::future::block_on(async move {
                     while let Ok(f) = task.recv().await {
-                        result.send(f.await).await.map_err(Error::send_msg("send CPU result"))?;
+                        f.await?;
                     }
                     Ok::<_, Error>(())
                 })
             }))
             .detach();
         }
-        (tx_task, rx_result)
+        tx_task
     };
 
-    let waste_report_dir = output_dir.join(report::waste::Generator::name());
-    blocking::unblock({
-        let dir = waste_report_dir.clone();
-        move || std::fs::create_dir_all(dir)
-    })
-    .await?;
-    use crate::engine::report::generic::WriteCallback;
-    let (cache_dir, (git_handle, git_state, maybe_join_handle)) = match glob.as_ref() {
-        Some(_) => (None, (git::not_available as WriteCallback, None, None)),
-        None => {
-            let cd = waste_report_dir.join("__incremental_cache__");
-            blocking::unblock({
-                let cd = cd.clone();
-                move || std::fs::create_dir_all(cd)
-            })
-            .await?;
-            (
-                Some(cd),
-                git::select_callback(cpu_o_bound_processors, &waste_report_dir, progress.add_child("git")),
+    let mut passes = Vec::with_capacity(kinds.len());
+    for kind in kinds {
+        passes.push(
+            kind.start(
+                db.clone(),
+                output_dir,
+                glob.is_some(),
+                num_crates / chunk_size,
+                cpu_o_bound_processors,
+                progress.add_child(kind.name()),
             )
-        }
-    };
-    let merge_reports = crate::spawn({
-        let mut merge_progress = progress.add_child("report aggregator");
-        merge_progress.init(Some(num_crates / chunk_size), Some("Reports".into()));
-        report::waste::Generator::merge_reports(
-            waste_report_dir.clone(),
-            cache_dir.clone(),
-            merge_progress,
-            rx_result,
-            git_handle,
-            git_state.clone(),
-        )
-        .map(|_| ())
-        .boxed()
-    });
+            .await?,
+        );
+    }
 
     let mut fetched_crates = 0;
-    let mut chunk = Vec::<(String, Vec<u8>)>::with_capacity(chunk_size as usize);
     let mut cid = 0;
     loop {
-        let abort_loop = {
-            progress.blocked("fetching chunk of crates to schedule", None);
-            let connection = db.open_connection_no_async_with_busy_wait()?;
-            let mut statement = new_key_value_query_old_to_new_filtered(
-                persistence::CrateTable::table_name(),
-                glob_str,
-                &connection,
-                Some((fetched_crates, chunk_size as usize)),
-            )?;
-
-            chunk.clear();
-            chunk.extend(
-                statement
-                    .query_map([], |r| Ok((r.get(0)?, r.get(1)?)))?
-                    .filter_map(|r| r.ok()),
-            );
-            fetched_crates += chunk.len();
-
-            chunk.len() != chunk_size as usize
-        };
+        progress.blocked("fetching chunk of crates to schedule", None);
+        let chunk: Vec<(String, Vec<u8>)> = db.open_connection_with_busy_wait()?.key_values_old_to_new(
+            persistence::CrateTable::table_name(),
+            glob_str,
+            Some((fetched_crates, chunk_size)),
+        )?;
+        fetched_crates += chunk.len();
+        let abort_loop = chunk.len() != chunk_size;
 
         cid += 1;
         check(deadline)?;
 
         progress.set(cid * chunk_size);
         progress.halted("write crate report", None);
-        processors
-            .send(report::waste::Generator::write_files(
-                db.clone(),
-                waste_report_dir.clone(),
-                cache_dir.clone(),
-                chunk,
-                progress.add_child(""),
-                git_handle,
-                git_state.clone(),
-            ))
-            .await
-            .map_err(Error::send_msg("Chunk of files to write"))?;
-        chunk = Vec::with_capacity(chunk_size as usize);
+        let tasks: Vec<_> = kinds
+            .iter()
+            .zip(passes.iter())
+            .map(|(kind, pass)| pass.write_files(chunk.clone(), progress.add_child(kind.name())))
+            .collect();
+        for task in tasks {
+            processors
+                .send(task)
+                .await
+                .map_err(Error::send_msg("Chunk of files to write"))?;
+        }
         if abort_loop {
             break;
         }
     }
-    drop(git_state);
     drop(processors);
     progress.set(num_crates);
-    merge_reports.await;
-    progress.done("Generating and merging waste report done");
-
-    if let Some(handle) = maybe_join_handle {
-        progress.blocked("waiting for git to finish", None);
-        if handle.join().is_err() {
-            progress.fail("git failed with unknown error");
-        }
-    };
+    for pass in passes {
+        pass.finish().await;
+    }
+    progress.done(format!("Generating and merging {} reports done", kinds.len()));
     Ok(())
 }
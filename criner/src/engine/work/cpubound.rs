@@ -1,8 +1,142 @@
-use crate::engine::report::waste::{tar_path_to_utf8_str, CargoConfig};
+use crate::engine::report::waste::{references, tar_path_to_utf8_str, CargoConfig};
+use crate::engine::work::registry::{BoxedProcessor, MapRequest, TaskKind, TaskRequest};
 use crate::{error::Result, model, persistence, Error};
 use async_trait::async_trait;
+use once_cell::sync::OnceCell;
+use serde_derive::{Deserialize, Serialize};
 use std::io::Seek;
-use std::{fs::File, io::BufReader, io::Read, path::PathBuf, time::SystemTime};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs::File,
+    io::BufReader,
+    io::Read,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+/// Files whose content to store when extracting a crate, in addition to `Cargo.toml` which is always stored in full.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct ExtractionConfig {
+    /// The size limit for the sources of all targets declared in `Cargo.toml` and the modules and sources they include,
+    /// unlimited if unset.
+    #[serde(default)]
+    pub targets_max_size: Option<u64>,
+    /// Files to store by their crate relative path
+    #[serde(default, rename = "file")]
+    pub files: Vec<SelectedFiles>,
+}
+
+/// All files matching `glob`, each stored up to `max_size` bytes, or completely if unset.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct SelectedFiles {
+    pub glob: String,
+    #[serde(default)]
+    pub max_size: Option<u64>,
+}
+
+const DEFAULT_MAX_SIZE: u64 = 128 * 1024;
+
+impl Default for ExtractionConfig {
+    fn default() -> Self {
+        ExtractionConfig {
+            targets_max_size: Some(DEFAULT_MAX_SIZE),
+            files: vec![
+                SelectedFiles {
+                    glob: "Cargo.lock".into(),
+                    max_size: None,
+                },
+                SelectedFiles {
+                    glob: "src/bin/*.rs".into(),
+                    max_size: Some(DEFAULT_MAX_SIZE),
+                },
+            ],
+        }
+    }
+}
+
+impl ExtractionConfig {
+    /// Read the configuration from a TOML file with `targets_max_size` and one `[[file]]` table per glob.
+    pub fn from_toml_file(path: impl AsRef<Path>) -> Result<ExtractionConfig> {
+        let path = path.as_ref();
+        toml::from_str(&std::fs::read_to_string(path)?)
+            .map_err(|err| Error::Message(format!("Could not parse '{}': {}", path.display(), err)))
+    }
+
+    /// The version of the extraction task, which changes along with the configuration.
+    pub fn version(&self) -> String {
+        if *self == ExtractionConfig::default() {
+            TASK_VERSION.into()
+        } else {
+            let config = toml::to_string(self).expect("configuration to always serialize");
+            format!(
+                "{}+{:016x}",
+                TASK_VERSION,
+                criner_waste_report::stable_hash(config.as_bytes())
+            )
+        }
+    }
+
+    fn compile(&self) -> Result<Selection> {
+        Ok(Selection {
+            targets_max_size: self.targets_max_size,
+            files: self
+                .files
+                .iter()
+                .map(|f| Ok((globset::Glob::new(&f.glob)?.compile_matcher(), f.max_size)))
+                .collect::<Result<_>>()?,
+        })
+    }
+}
+
+struct Configured {
+    config: ExtractionConfig,
+    version: String,
+}
+
+static CONFIGURED: OnceCell<Configured> = OnceCell::new();
+
+fn configured() -> &'static Configured {
+    CONFIGURED.get_or_init(|| Configured {
+        config: ExtractionConfig::default(),
+        version: ExtractionConfig::default().version(),
+    })
+}
+
+/// Use `config` for all extractions of this process. It must be called before any crate is extracted or a report
+/// is generated, as both depend on the version of the extraction task.
+pub fn set_extraction_config(config: ExtractionConfig) -> Result<()> {
+    let version = config.version();
+    CONFIGURED
+        .set(Configured { config, version })
+        .map_err(|_| Error::Bug("The extraction configuration can only be set once, before it is used"))
+}
+
+struct Selection {
+    targets_max_size: Option<u64>,
+    files: Vec<(globset::GlobMatcher, Option<u64>)>,
+}
+
+impl Selection {
+    /// Return `None` if the file at `path` is not selected, or `Some(max_size)` otherwise, which is
+    /// the largest of all limits applying to it.
+    fn max_size(&self, path: &str, targets: &BTreeSet<String>) -> Option<Option<u64>> {
+        if path == "Cargo.toml" {
+            return Some(None);
+        }
+        self.files
+            .iter()
+            .filter(|(glob, _)| glob.is_match(path))
+            .map(|(_, max_size)| *max_size)
+            .chain(targets.contains(path).then_some(self.targets_max_size))
+            .fold(None, |largest, max_size| match (largest, max_size) {
+                (None, max_size) => Some(max_size),
+                (Some(None), _) | (_, None) => Some(None),
+                (Some(Some(a)), Some(b)) => Some(Some(a.max(b))),
+            })
+    }
+}
 
 struct ProcessingState {
     downloaded_crate: PathBuf,
@@ -12,19 +146,22 @@ pub struct Agent {
     asset_dir: PathBuf,
     results: persistence::TaskResultTable,
     state: Option<ProcessingState>,
-    standard_bin_path: globset::GlobMatcher,
+    task: model::Task,
+    selection: Selection,
 }
 
 impl Agent {
-    pub fn new(asset_dir: PathBuf, db: &persistence::Db) -> Result<Agent> {
+    pub fn new(asset_dir: PathBuf, db: &persistence::Db, config: &ExtractionConfig) -> Result<Agent> {
         let results = db.open_results()?;
         Ok(Agent {
             asset_dir,
             results,
             state: None,
-            standard_bin_path: globset::Glob::new("src/bin/*.rs")
-                .expect("valid statically known glob")
-                .compile_matcher(),
+            task: model::Task {
+                version: config.version(),
+                ..default_persisted_extraction_task()
+            },
+            selection: config.compile()?,
         })
     }
 }
@@ -46,7 +183,10 @@ impl crate::engine::work::generic::Processor for Agent {
         } = request;
 
         let progress_info = format!("CPU UNZIP+UNTAR {}:{}", crate_name, crate_version);
-        let dummy_task = default_persisted_extraction_task();
+        let dummy_task = model::Task {
+            stored_at: SystemTime::now(),
+            ..self.task.clone()
+        };
         let mut task_key = String::new();
         dummy_task.fq_key(&crate_name, &crate_version, &mut task_key);
 
@@ -61,6 +201,7 @@ impl crate::engine::work::generic::Processor for Agent {
         let dummy_result = model::TaskResult::ExplodedCrate {
             entries_meta_data: vec![],
             selected_entries: vec![],
+            findings: vec![],
         };
 
         let mut key = String::with_capacity(task_key.len() * 2);
@@ -74,9 +215,20 @@ impl crate::engine::work::generic::Processor for Agent {
         "CPU IDLE".into()
     }
 
+    fn worker_kind(&self) -> model::WorkerKind {
+        model::WorkerKind::CpuBound
+    }
+
+    fn record(&self, attempt: &model::TaskAttempt, context: &mut model::Context) {
+        context.durations.extraction += attempt.duration();
+        if attempt.error.is_none() {
+            context.counts.crates_extracted += 1;
+        }
+    }
+
     async fn process(&mut self, progress: &mut prodash::tree::Item) -> std::result::Result<(), (Error, String)> {
         let ProcessingState { downloaded_crate, key } = self.state.take().expect("state to be set");
-        extract_crate(&self.results, &key, progress, downloaded_crate, &self.standard_bin_path)
+        extract_crate(&self.results, &key, progress, downloaded_crate, &self.selection)
             .map_err(|err| (err, "Failed to extract crate".into()))
     }
 }
@@ -88,15 +240,117 @@ pub struct ExtractRequest {
     pub crate_version: String,
 }
 
+pub const TASK_NAME: &str = "extract_crate";
+const TASK_VERSION: &str = "1.3.0";
+
 pub fn default_persisted_extraction_task() -> model::Task {
-    const TASK_NAME: &str = "extract_crate";
-    const TASK_VERSION: &str = "1.0.0";
     model::Task {
         stored_at: SystemTime::now(),
         process: TASK_NAME.into(),
-        version: TASK_VERSION.into(),
+        version: configured().version.clone(),
         state: Default::default(),
+        attempts: Vec::new(),
+    }
+}
+
+/// Extract downloaded crates, storing meta-data about all files and the content of selected ones
+pub struct ExtractCrate;
+
+impl TaskKind for ExtractCrate {
+    fn name(&self) -> &'static str {
+        TASK_NAME
+    }
+
+    fn version(&self) -> &'static str {
+        &configured().version
+    }
+
+    fn dependencies(&self) -> &'static [&'static str] {
+        &[super::iobound::TASK_NAME]
     }
+
+    fn worker_kind(&self) -> model::WorkerKind {
+        model::WorkerKind::CpuBound
+    }
+
+    fn default_task(&self) -> model::Task {
+        default_persisted_extraction_task()
+    }
+
+    fn new_processor(&self, db: &persistence::Db, assets_dir: &Path) -> Result<BoxedProcessor> {
+        Ok(Box::new(MapRequest {
+            processor: Agent::new(assets_dir.to_owned(), db, &configured().config)?,
+            map: |TaskRequest {
+                      crate_name,
+                      crate_version,
+                      mut dependencies,
+                  }| ExtractRequest {
+                download_task: dependencies.pop().expect("download task"),
+                crate_name,
+                crate_version,
+            },
+        }))
+    }
+}
+
+/// Find everything unusual about the path and type of `entry`, which might be harmful when unpacking the crate.
+fn findings_of(entry: &tar::Entry<impl Read>) -> Vec<model::EntryFindingKind> {
+    use model::EntryFindingKind::*;
+    let mut findings = Vec::new();
+    let path = entry.path_bytes();
+    if std::str::from_utf8(&path).is_err() {
+        findings.push(NonUtf8Path);
+    }
+    let is_windows_drive = path.len() >= 2 && path[0].is_ascii_alphabetic() && path[1] == b':';
+    if path.starts_with(b"/") || path.starts_with(b"\\") || is_windows_drive {
+        findings.push(AbsolutePath);
+    }
+    if path
+        .split(|b| *b == b'/' || *b == b'\\')
+        .any(|component| component == b"..")
+    {
+        findings.push(ParentDirectoryComponent);
+    }
+    let link_target = || entry.link_name_bytes().map(|t| t.into_owned()).unwrap_or_default();
+    let entry_type = entry.header().entry_type();
+    if entry_type.is_symlink() {
+        findings.push(Symlink(link_target()));
+    } else if entry_type.is_hard_link() {
+        findings.push(Hardlink(link_target()));
+    }
+    findings
+}
+
+/// Hash the entire content of `entry`, storing it in the given buffer up to an optional size limit if set.
+fn read_and_hash(entry: &mut impl Read, mut store: Option<(Option<u64>, &mut Vec<u8>)>) -> Result<[u8; 32]> {
+    let mut hasher = blake3::Hasher::new();
+    let mut chunk = [0; 64 * 1024];
+    loop {
+        let bytes_read = entry.read(&mut chunk)?;
+        if bytes_read == 0 {
+            break;
+        }
+        let chunk = &chunk[..bytes_read];
+        hasher.update(chunk);
+        if let Some((max_size, buf)) = store.as_mut() {
+            let remaining = max_size.map_or(chunk.len(), |max| (max as usize).saturating_sub(buf.len()));
+            buf.extend_from_slice(&chunk[..remaining.min(chunk.len())]);
+        }
+    }
+    Ok(*hasher.finalize().as_bytes())
+}
+
+type CrateArchive = tar::Archive<libflate::gzip::Decoder<BufReader<File>>>;
+
+fn open_archive(file: File) -> Result<CrateArchive> {
+    Ok(tar::Archive::new(libflate::gzip::Decoder::new(BufReader::new(file))?))
+}
+
+/// Return the file `archive` was read from, positioned at its start so it can be read again.
+fn rewind(archive: CrateArchive) -> Result<File> {
+    let mut file = archive.into_inner().into_inner().into_inner();
+    file.seek(std::io::SeekFrom::Start(0))?;
+    Ok(file)
 }
 
 fn extract_crate(
@@ -104,69 +358,96 @@ fn extract_crate(
     key: &str,
     progress: &mut prodash::tree::Item,
     downloaded_crate: PathBuf,
-    standard_bin_path: &globset::GlobMatcher,
+    selection: &Selection,
 ) -> Result<()> {
     use persistence::TableAccess;
-    let mut archive = tar::Archive::new(libflate::gzip::Decoder::new(BufReader::new(File::open(
-        downloaded_crate,
-    )?))?);
+    let mut archive = open_archive(File::open(downloaded_crate)?)?;
 
     let mut buf = Vec::new();
-    let mut interesting_paths = vec!["Cargo.toml".to_string(), "Cargo.lock".into()];
-    let mut files = Vec::new();
+    let mut config = CargoConfig::default();
+    let mut paths = BTreeSet::new();
+    let mut readable = BTreeSet::new();
     for e in archive.entries()? {
         progress.inc();
         let mut e: tar::Entry<_> = e?;
-        if tar_path_to_utf8_str(e.path_bytes().as_ref()) == "Cargo.toml" {
+        let path = tar_path_to_utf8_str(e.path_bytes().as_ref()).into_owned();
+        if path == "Cargo.toml" {
             e.read_to_end(&mut buf)?;
-            let config = std::str::from_utf8(&buf).map(CargoConfig::from).unwrap_or_default();
-            interesting_paths.push(config.actual_or_expected_build_script_path().to_owned());
-            interesting_paths.push(config.lib_path().to_owned());
-            interesting_paths.extend(config.bin_paths().into_iter().map(|s| s.to_owned()));
-            break;
+            config = std::str::from_utf8(&buf).map(CargoConfig::from).unwrap_or_default();
+        } else if path.ends_with(".rs") && e.header().entry_type().is_file() {
+            readable.insert(path.clone());
         }
+        paths.insert(path);
     }
+    // Find the sources of all targets along with the modules and sources they include, as needed to tell
+    // which files are required to build the crate. Only sources known to be reachable are held in memory,
+    // which takes another read of the archive for each level of modules.
+    let mut sources = BTreeMap::new();
+    let mut targets = loop {
+        let mut missing = BTreeSet::new();
+        let targets = references::resolve(
+            config.target_paths().iter().map(String::as_str),
+            |path| paths.contains(path),
+            |path| {
+                let source = sources.get(path);
+                if source.is_none() && readable.contains(path) {
+                    missing.insert(path.to_owned());
+                }
+                source
+            },
+        );
+        if missing.is_empty() {
+            break targets;
+        }
+        archive = open_archive(rewind(archive)?)?;
+        for e in archive.entries()? {
+            let mut e: tar::Entry<_> = e?;
+            let path = tar_path_to_utf8_str(e.path_bytes().as_ref()).into_owned();
+            if let Some(path) = missing.take(&path) {
+                let mut source = Vec::new();
+                (&mut e)
+                    .take(selection.targets_max_size.unwrap_or(u64::MAX))
+                    .read_to_end(&mut source)?;
+                sources.insert(path, source);
+            }
+        }
+    };
+    targets.retain(|path| readable.contains(path));
+    drop(sources);
 
-    let mut archive = tar::Archive::new(libflate::gzip::Decoder::new(BufReader::new({
-        let mut file = archive.into_inner().into_inner();
-        file.seek(std::io::SeekFrom::Start(0))?;
-        file
-    }))?);
+    let mut archive = open_archive(rewind(archive)?)?;
 
+    let mut files = Vec::new();
     let mut meta_data = Vec::new();
     let mut meta_count = 0;
     let mut file_count = 0;
-    let mut max_storage_size = [0; 128 * 1024];
+    let mut findings = Vec::new();
     for e in archive.entries()? {
         meta_count += 1;
         progress.set(meta_count);
         let mut e: tar::Entry<_> = e?;
+        let max_size = selection.max_size(&tar_path_to_utf8_str(e.path_bytes().as_ref()), &targets);
+        findings.extend(findings_of(&e).into_iter().map(|kind| model::EntryFinding {
+            path: e.path_bytes().to_vec(),
+            kind,
+        }));
+        let is_file = e.header().entry_type().is_file();
+        buf.clear();
+        let hash = is_file
+            .then(|| read_and_hash(&mut e, max_size.map(|s| (s, &mut buf))))
+            .transpose()?;
         meta_data.push(model::TarHeader {
             path: e.path_bytes().to_vec(),
             size: e.header().size()?,
             entry_type: e.header().entry_type().as_byte(),
+            hash,
         });
 
-        if interesting_paths
-            .iter()
-            .any(|p| p == tar_path_to_utf8_str(e.path_bytes().as_ref()))
-            || standard_bin_path.is_match(tar_path_to_utf8_str(e.path_bytes().as_ref()))
-        {
+        if max_size.is_some() {
             file_count += 1;
-
-            let slice = if tar_path_to_utf8_str(e.path_bytes().as_ref()) == "Cargo.toml"
-                || tar_path_to_utf8_str(e.path_bytes().as_ref()) == "Cargo.lock"
-            {
-                buf.clear();
-                e.read_to_end(&mut buf)?;
-                &buf
-            } else {
-                let bytes_read = e.read(&mut max_storage_size[..])?;
-                &max_storage_size[..bytes_read]
-            };
             files.push((
                 meta_data.last().expect("to have pushed one just now").to_owned(),
-                slice.to_owned(),
+                buf.clone(),
             ));
         }
     }
@@ -175,11 +456,16 @@ fn extract_crate(
         meta_count, file_count
     ));
 
+    if !findings.is_empty() {
+        progress.info(format!("Found {} entries with unusual paths or types", findings.len()));
+    }
+
     let task_result = model::TaskResult::ExplodedCrate {
         entries_meta_data: meta_data,
         selected_entries: files,
+        findings,
     };
-    results.insert(progress, &key, &task_result)?;
+    results.insert(progress, key, &task_result)?;
 
     Ok(())
 }
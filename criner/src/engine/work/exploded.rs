@@ -0,0 +1,132 @@
+use crate::{
+    engine::work::generic::Processor,
+    engine::work::registry::TaskRequest,
+    error::Result,
+    model::{self, TarHeader},
+    persistence::{self, TableAccess},
+    Error,
+};
+use async_trait::async_trait;
+
+/// Compute a task result from the meta data of all entries of an extracted crate and the content of the selected ones.
+pub type ComputeFn = fn(&[TarHeader], &[(TarHeader, Vec<u8>)], &mut prodash::tree::Item) -> model::TaskResult;
+
+struct ProcessingState {
+    extraction_key: String,
+    key: String,
+}
+
+/// A processor for tasks which depend on the extraction task only, and compute their result from the `ExplodedCrate`
+/// it stored.
+pub struct Agent {
+    task: model::Task,
+    results: persistence::TaskResultTable,
+    /// The result with all fields empty, to obtain its key
+    empty_result: model::TaskResult,
+    /// The name of the file to process, for use in messages
+    file_name: &'static str,
+    compute: ComputeFn,
+    state: Option<ProcessingState>,
+}
+
+impl Agent {
+    pub fn new(
+        task: model::Task,
+        db: &persistence::Db,
+        empty_result: model::TaskResult,
+        file_name: &'static str,
+        compute: ComputeFn,
+    ) -> Result<Agent> {
+        Ok(Agent {
+            task,
+            results: db.open_results()?,
+            empty_result,
+            file_name,
+            compute,
+            state: None,
+        })
+    }
+}
+
+#[async_trait]
+impl Processor for Agent {
+    type Item = TaskRequest;
+
+    fn set(
+        &mut self,
+        request: Self::Item,
+        progress: &mut prodash::tree::Item,
+    ) -> Result<(model::Task, String, String)> {
+        progress.init(None, None);
+        let TaskRequest {
+            crate_name,
+            crate_version,
+            dependencies,
+        } = request;
+        let extraction_task = dependencies.first().ok_or(Error::Bug("extraction task to be passed"))?;
+        let mut extraction_key = String::new();
+        model::TaskResult::ExplodedCrate {
+            entries_meta_data: Vec::new(),
+            selected_entries: Vec::new(),
+            findings: Vec::new(),
+        }
+        .fq_key(&crate_name, &crate_version, extraction_task, &mut extraction_key);
+
+        let mut task_key = String::new();
+        self.task.fq_key(&crate_name, &crate_version, &mut task_key);
+        let mut key = String::with_capacity(task_key.len() * 2);
+        self.empty_result
+            .fq_key(&crate_name, &crate_version, &self.task, &mut key);
+
+        let progress_info = format!("CPU {} {}:{}", self.file_name, crate_name, crate_version);
+        self.state = Some(ProcessingState { extraction_key, key });
+        Ok((self.task.clone(), task_key, progress_info))
+    }
+
+    fn idle_message(&self) -> String {
+        "CPU IDLE".into()
+    }
+
+    fn worker_kind(&self) -> model::WorkerKind {
+        model::WorkerKind::CpuBound
+    }
+
+    async fn process(&mut self, progress: &mut prodash::tree::Item) -> std::result::Result<(), (Error, String)> {
+        let ProcessingState { extraction_key, key } = self.state.take().expect("state to be set");
+        compute_and_store(&self.results, &extraction_key, &key, self.compute, progress)
+            .map_err(|err| (err, format!("Failed to parse {}", self.file_name)))
+    }
+}
+
+fn compute_and_store(
+    results: &persistence::TaskResultTable,
+    extraction_key: &str,
+    key: &str,
+    compute: ComputeFn,
+    progress: &mut prodash::tree::Item,
+) -> Result<()> {
+    let (entries_meta_data, selected_entries) = match results.get(extraction_key)? {
+        Some(model::TaskResult::ExplodedCrate {
+            entries_meta_data,
+            selected_entries,
+            ..
+        }) => (entries_meta_data, selected_entries),
+        _ => {
+            return Err(Error::Message(format!(
+                "There is no extracted crate at '{}'",
+                extraction_key
+            )))
+        }
+    };
+    let result = compute(&entries_meta_data, &selected_entries, progress);
+    results.insert(progress, key, &result)?;
+    Ok(())
+}
+
+/// Return the content of the selected entry at `path`, if present.
+pub fn find<'a>(selected_entries: &'a [(TarHeader, Vec<u8>)], path: &str) -> Option<&'a [u8]> {
+    selected_entries
+        .iter()
+        .find(|(header, _)| crate::engine::report::waste::tar_path_to_utf8_str(&header.path) == path)
+        .map(|(_, content)| content.as_slice())
+}
@@ -0,0 +1,357 @@
+use crate::{
+    engine::work::{
+        generic::Processor,
+        registry::{BoxedProcessor, Registry, TaskKind, TaskRequest},
+    },
+    error::Result,
+    model, persistence, Error,
+};
+use async_trait::async_trait;
+use serde_derive::Deserialize;
+use std::{
+    io::Read,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+/// What to pass to the external program as its last argument
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum Input {
+    /// The path to the downloaded `.crate` file
+    CrateFile,
+    /// The path to a temporary directory with the unpacked crate, removed once the program is done
+    CrateDirectory,
+}
+
+/// The configuration of a program to run on every crate, as found in the configuration file.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct ExternalCommandConfig {
+    /// The name of the task, which must be unique among all tasks
+    pub name: String,
+    /// The version of the task. Increment it to run the program again on all crates.
+    pub version: String,
+    /// The program to run, looked up in `PATH` if it is not a path
+    pub program: String,
+    /// Arguments to pass before the path to the crate
+    #[serde(default)]
+    pub args: Vec<String>,
+    pub input: Input,
+    /// The time the program may run before it is killed and the attempt fails, like 30s or 5min
+    #[serde(default = "default_timeout", deserialize_with = "parse_duration")]
+    pub timeout: Duration,
+}
+
+fn default_timeout() -> Duration {
+    Duration::from_secs(60)
+}
+
+fn parse_duration<'de, D>(deserializer: D) -> std::result::Result<Duration, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let s: String = serde::Deserialize::deserialize(deserializer)?;
+    humantime::parse_duration(&s).map_err(serde::de::Error::custom)
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Config {
+    #[serde(default)]
+    command: Vec<ExternalCommandConfig>,
+}
+
+/// Read all external commands from a TOML file with one `[[command]]` table per program.
+pub fn load_config(path: impl AsRef<Path>) -> Result<Vec<ExternalCommandConfig>> {
+    let path = path.as_ref();
+    load_config_from_str(&std::fs::read_to_string(path)?)
+        .map_err(|err| Error::Message(format!("Could not parse '{}': {}", path.display(), err)))
+}
+
+pub fn load_config_from_str(config: &str) -> Result<Vec<ExternalCommandConfig>> {
+    let config: Config = toml::from_str(config).map_err(|err| Error::Message(err.to_string()))?;
+    Ok(config.command)
+}
+
+/// The default registry with a task for each external command configured in the file at `config_path`, if set.
+pub fn registry(config_path: Option<&Path>) -> Result<Registry> {
+    let mut kinds = Registry::default_kinds();
+    if let Some(path) = config_path {
+        for config in load_config(path)? {
+            kinds.push(Arc::new(ExternalCommand::new(config)?));
+        }
+    }
+    Registry::new(kinds)
+}
+
+/// Runs a configured program on every downloaded crate and stores what it writes to stdout.
+pub struct ExternalCommand {
+    // Task names are `'static` as they are usually known at compile time. These are leaked once per configured
+    // command, which is fine as they live as long as the program anyway.
+    name: &'static str,
+    version: &'static str,
+    config: Arc<ExternalCommandConfig>,
+}
+
+impl ExternalCommand {
+    pub fn new(config: ExternalCommandConfig) -> Result<ExternalCommand> {
+        for (field, value) in &[("name", &config.name), ("version", &config.version)] {
+            if value.is_empty() || value.contains(persistence::KEY_SEP_CHAR) {
+                return Err(Error::Message(format!(
+                    "The {} of external command '{}' must not be empty or contain '{}'",
+                    field,
+                    config.name,
+                    persistence::KEY_SEP_CHAR
+                )));
+            }
+        }
+        Ok(ExternalCommand {
+            name: Box::leak(config.name.clone().into_boxed_str()),
+            version: Box::leak(config.version.clone().into_boxed_str()),
+            config: Arc::new(config),
+        })
+    }
+}
+
+impl TaskKind for ExternalCommand {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn version(&self) -> &'static str {
+        self.version
+    }
+
+    fn dependencies(&self) -> &'static [&'static str] {
+        &[super::iobound::TASK_NAME]
+    }
+
+    fn worker_kind(&self) -> model::WorkerKind {
+        model::WorkerKind::CpuBound
+    }
+
+    fn new_processor(&self, db: &persistence::Db, assets_dir: &Path) -> Result<BoxedProcessor> {
+        Ok(Box::new(Agent {
+            task: self.default_task(),
+            config: self.config.clone(),
+            assets_dir: assets_dir.to_owned(),
+            results: db.open_results()?,
+            state: None,
+        }))
+    }
+}
+
+struct ProcessingState {
+    crate_name: String,
+    crate_version: String,
+    downloaded_crate: PathBuf,
+    key: String,
+}
+
+pub struct Agent {
+    task: model::Task,
+    config: Arc<ExternalCommandConfig>,
+    assets_dir: PathBuf,
+    results: persistence::TaskResultTable,
+    state: Option<ProcessingState>,
+}
+
+#[async_trait]
+impl Processor for Agent {
+    type Item = TaskRequest;
+
+    fn set(
+        &mut self,
+        request: Self::Item,
+        progress: &mut prodash::tree::Item,
+    ) -> Result<(model::Task, String, String)> {
+        progress.init(None, None);
+        let TaskRequest {
+            crate_name,
+            crate_version,
+            dependencies,
+        } = request;
+        let download_task = dependencies.first().ok_or(Error::Bug("download task to be passed"))?;
+        let downloaded_crate = super::schedule::download_file_path(
+            &self.assets_dir,
+            &crate_name,
+            &crate_version,
+            &download_task.process,
+            &download_task.version,
+            "crate",
+        );
+
+        let mut task_key = String::new();
+        self.task.fq_key(&crate_name, &crate_version, &mut task_key);
+        let mut key = String::with_capacity(task_key.len() * 2);
+        model::TaskResult::ExternalCommand {
+            command: Vec::new(),
+            output: String::new(),
+        }
+        .fq_key(&crate_name, &crate_version, &self.task, &mut key);
+
+        let progress_info = format!("CPU {} {}:{}", self.config.name, crate_name, crate_version);
+        self.state = Some(ProcessingState {
+            crate_name,
+            crate_version,
+            downloaded_crate,
+            key,
+        });
+        Ok((self.task.clone(), task_key, progress_info))
+    }
+
+    fn idle_message(&self) -> String {
+        "CPU IDLE".into()
+    }
+
+    fn worker_kind(&self) -> model::WorkerKind {
+        model::WorkerKind::CpuBound
+    }
+
+    async fn process(&mut self, progress: &mut prodash::tree::Item) -> std::result::Result<(), (Error, String)> {
+        let ProcessingState {
+            crate_name,
+            crate_version,
+            downloaded_crate,
+            key,
+        } = self.state.take().expect("state to be set");
+        let context = format!(
+            "Failed to run '{}' on {}",
+            self.config.program,
+            downloaded_crate.display()
+        );
+        run_and_store(
+            &self.config,
+            &self.results,
+            &key,
+            progress,
+            &crate_name,
+            &crate_version,
+            &downloaded_crate,
+        )
+        .map_err(|err| (err, context))
+    }
+}
+
+fn run_and_store(
+    config: &ExternalCommandConfig,
+    results: &persistence::TaskResultTable,
+    key: &str,
+    progress: &mut prodash::tree::Item,
+    crate_name: &str,
+    crate_version: &str,
+    downloaded_crate: &Path,
+) -> Result<()> {
+    use persistence::TableAccess;
+    let output = match config.input {
+        Input::CrateFile => run(config, progress, crate_name, crate_version, downloaded_crate)?,
+        Input::CrateDirectory => {
+            let unpack_dir = std::env::temp_dir().join(format!(
+                "criner-{}-{}-{}-{}",
+                config.name,
+                crate_name,
+                crate_version,
+                std::process::id()
+            ));
+            let res = unpack(downloaded_crate, &unpack_dir).and_then(|crate_dir| {
+                progress.blocked("running external program", None);
+                run(config, progress, crate_name, crate_version, &crate_dir)
+            });
+            std::fs::remove_dir_all(&unpack_dir).ok();
+            res?
+        }
+    };
+    let task_result = model::TaskResult::ExternalCommand {
+        command: std::iter::once(config.program.clone())
+            .chain(config.args.iter().cloned())
+            .collect(),
+        output,
+    };
+    results.insert(progress, key, &task_result)?;
+    Ok(())
+}
+
+/// Unpack `crate_file` into `dir` and return the directory with the crate's content.
+fn unpack(crate_file: &Path, dir: &Path) -> Result<PathBuf> {
+    std::fs::create_dir_all(dir)?;
+    let mut archive = tar::Archive::new(libflate::gzip::Decoder::new(std::io::BufReader::new(
+        std::fs::File::open(crate_file)?,
+    ))?);
+    // `unpack` refuses to write outside of `dir`
+    archive.unpack(dir)?;
+
+    // Crates contain a single top-level directory named after the crate and version, which is what tools expect
+    let mut entries = std::fs::read_dir(dir)?.collect::<std::io::Result<Vec<_>>>()?;
+    Ok(match entries.len() {
+        1 if entries[0].file_type()?.is_dir() => entries.remove(0).path(),
+        _ => dir.to_owned(),
+    })
+}
+
+/// Run the program, passing `path` as last argument, and return its stdout if it is valid JSON.
+fn run(
+    config: &ExternalCommandConfig,
+    progress: &mut prodash::tree::Item,
+    crate_name: &str,
+    crate_version: &str,
+    path: &Path,
+) -> Result<String> {
+    let mut child = Command::new(&config.program)
+        .args(&config.args)
+        .arg(path)
+        .env("CRINER_CRATE_NAME", crate_name)
+        .env("CRINER_CRATE_VERSION", crate_version)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    // Read in the background, as the program would block once the pipes are full
+    let read_to_end = |mut pipe: Box<dyn Read + Send>| {
+        std::thread::spawn(move || {
+            let mut buf = Vec::new();
+            pipe.read_to_end(&mut buf).map(|_| buf)
+        })
+    };
+    let stdout = read_to_end(Box::new(child.stdout.take().expect("piped stdout")));
+    let stderr = read_to_end(Box::new(child.stderr.take().expect("piped stderr")));
+
+    let start = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if start.elapsed() > config.timeout {
+            child.kill().ok();
+            child.wait()?;
+            return Err(Error::Timeout(
+                config.timeout,
+                format!("External command '{}' did not finish in time", config.name),
+            ));
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    };
+    progress.running();
+
+    let stdout = stdout.join().expect("reader not to panic")?;
+    let stderr = stderr.join().expect("reader not to panic")?;
+    if !status.success() {
+        return Err(Error::Message(format!(
+            "External command '{}' failed with {}: {}",
+            config.name,
+            status,
+            String::from_utf8_lossy(&stderr).trim()
+        )));
+    }
+    let output = String::from_utf8(stdout)?;
+    if let Err(err) = serde_json::from_str::<serde::de::IgnoredAny>(&output) {
+        return Err(Error::Message(format!(
+            "External command '{}' did not write valid JSON to stdout: {}",
+            config.name, err
+        )));
+    }
+    Ok(output)
+}
@@ -1,5 +1,6 @@
 use crate::{model, persistence, persistence::TableAccess, Error, Result};
 use async_trait::async_trait;
+use std::time::SystemTime;
 
 #[async_trait]
 pub trait Processor {
@@ -8,7 +9,14 @@ pub trait Processor {
     fn set(&mut self, request: Self::Item, progress: &mut prodash::tree::Item)
         -> Result<(model::Task, String, String)>;
     fn idle_message(&self) -> String;
+    fn worker_kind(&self) -> model::WorkerKind;
     async fn process(&mut self, progress: &mut prodash::tree::Item) -> std::result::Result<(), (Error, String)>;
+    /// The amount of bytes transferred by the last call to `process()`, if known.
+    fn bytes_transferred(&mut self) -> Option<u64> {
+        None
+    }
+    /// Add metrics about a finished `attempt` to today's `context`.
+    fn record(&self, _attempt: &model::TaskAttempt, _context: &mut model::Context) {}
     async fn schedule_next(&mut self, _progress: &mut prodash::tree::Item) -> Result<()> {
         Ok(())
     }
@@ -22,17 +30,20 @@ pub async fn processor<T: Clone>(
     max_retries_on_timeout: usize,
 ) -> Result<()> {
     let tasks = db.open_tasks()?;
+    let context = db.open_context()?;
 
     while let Ok(request) = r.recv().await {
         let mut try_count = 0;
-        let (task, task_key) = loop {
+        let mut bytes_transferred = None;
+        let started_at = SystemTime::now();
+        let (mut task, task_key, dummy_task, res) = loop {
             let (dummy_task, task_key, progress_name) = agent.set(request.clone(), &mut progress)?;
             progress.set_name(progress_name);
 
-            let mut task = tasks.update(Some(&mut progress), &task_key, |mut t| {
+            let task = tasks.update(Some(&mut progress), &task_key, |mut t| {
                 t.process = dummy_task.process.clone();
                 t.version = dummy_task.version.clone();
-                t.state.merge_with(&model::TaskState::InProgress(None));
+                t.state = model::TaskState::InProgress;
                 t
             })?;
 
@@ -40,24 +51,52 @@ pub async fn processor<T: Clone>(
             progress.blocked("working", None);
             let res = agent.process(&mut progress).await;
             progress.running();
+            if let Some(bytes) = agent.bytes_transferred() {
+                *bytes_transferred.get_or_insert(0) += bytes;
+            }
 
-            task.state = match res {
+            match res {
                 Err((err @ Error::Timeout(_, _), _)) if try_count < max_retries_on_timeout => {
                     progress.fail(format!("{} → retrying ({}/{})", err, try_count, max_retries_on_timeout));
                     continue;
                 }
-                Err((err, msg)) => {
-                    progress.fail(format!("{}: {}", msg, err));
-                    model::TaskState::AttemptsWithFailure(vec![err.to_string()])
-                }
-                Ok(_) => {
-                    agent.schedule_next(&mut progress).await.ok();
-                    model::TaskState::Complete
-                }
-            };
-            break (task, task_key);
+                res => break (task, task_key, dummy_task, res),
+            }
+        };
+
+        // timeouts retried right away are part of a single attempt, to not give up on flaky connections too early
+        let attempt = model::TaskAttempt {
+            started_at,
+            finished_at: SystemTime::now(),
+            worker: agent.worker_kind(),
+            bytes_transferred,
+            error: res.as_ref().err().map(|(err, msg)| model::AttemptError {
+                kind: err.kind().into(),
+                message: err.to_string(),
+                context: msg.clone(),
+            }),
+            retried_timeouts: try_count - 1,
+        };
+        context.update_today(|c| {
+            if attempt.error.is_some() {
+                *c.counts.task_failures.entry(dummy_task.process.clone()).or_default() += 1;
+            }
+            agent.record(&attempt, c);
+        })?;
+
+        task.state = match res {
+            Err((err, msg)) => {
+                progress.fail(format!("{}: {}", msg, err));
+                model::TaskState::AttemptsWithFailure
+            }
+            Ok(_) => {
+                agent.schedule_next(&mut progress).await.ok();
+                model::TaskState::Complete
+            }
         };
 
+        // only the new attempts, the stored ones are kept when merging
+        task.attempts = vec![attempt];
         tasks.upsert(&mut progress, &task_key, &task)?;
         progress.set_name(agent.idle_message());
         progress.init(None, None);
@@ -6,6 +6,7 @@ use crate::{
 use bytesize::ByteSize;
 use futures_lite::{io::AsyncWriteExt, FutureExt};
 
+use crate::engine::work::registry::{BoxedProcessor, MapRequest, TaskKind, TaskRequest};
 use crate::utils::timeout_after;
 use async_trait::async_trait;
 use std::{
@@ -22,44 +23,45 @@ struct ProcessingState {
     output_file_path: PathBuf,
     result_key: Option<String>,
 }
-pub struct Agent<Fn, FnResult> {
+pub struct Agent {
     client: reqwest::Client,
     results: persistence::TaskResultTable,
-    channel: async_channel::Sender<FnResult>,
+    /// Receives the path of each file once it was downloaded, if set
+    downloaded_files: Option<async_channel::Sender<PathBuf>>,
     state: Option<ProcessingState>,
-    make_state: Fn,
-    next_action_state: Option<FnResult>,
+    downloaded_file: Option<PathBuf>,
+    bytes_transferred: Option<u64>,
 }
 
-impl<Fn, FnResult> Agent<Fn, FnResult>
-where
-    Fn: FnMut(Option<(String, String)>, &model::Task, &Path) -> Option<FnResult>,
-{
-    pub fn new(
-        db: &persistence::Db,
-        channel: async_channel::Sender<FnResult>,
-        make_state: Fn,
-    ) -> Result<Agent<Fn, FnResult>> {
+impl Agent {
+    pub fn new(db: &persistence::Db) -> Result<Agent> {
         let client = reqwest::ClientBuilder::new().gzip(true).build()?;
 
         let results = db.open_results()?;
         Ok(Agent {
             client,
             results,
-            channel,
+            downloaded_files: None,
             state: None,
-            next_action_state: None,
-            make_state,
+            downloaded_file: None,
+            bytes_transferred: None,
+        })
+    }
+
+    /// Like `new()`, but send the path of each file to `downloaded_files` once it was downloaded.
+    pub fn with_downloaded_files(
+        db: &persistence::Db,
+        downloaded_files: async_channel::Sender<PathBuf>,
+    ) -> Result<Agent> {
+        Ok(Agent {
+            downloaded_files: Some(downloaded_files),
+            ..Agent::new(db)?
         })
     }
 }
 
 #[async_trait]
-impl<Fn, FnResult> crate::engine::work::generic::Processor for Agent<Fn, FnResult>
-where
-    Fn: FnMut(Option<(String, String)>, &model::Task, &Path) -> Option<FnResult> + Send,
-    FnResult: Send,
-{
+impl crate::engine::work::generic::Processor for Agent {
     type Item = DownloadRequest;
 
     fn set(
@@ -86,7 +88,7 @@ where
             content_type: None,
         };
 
-        self.next_action_state = (self.make_state)(crate_name_and_version.clone(), &dummy_task, &output_file_path);
+        self.downloaded_file = self.downloaded_files.as_ref().map(|_| output_file_path.clone());
         self.state = Some(ProcessingState {
             url,
             kind,
@@ -104,6 +106,19 @@ where
         "↓ IDLE".into()
     }
 
+    fn worker_kind(&self) -> model::WorkerKind {
+        model::WorkerKind::IoBound
+    }
+
+    fn bytes_transferred(&mut self) -> Option<u64> {
+        self.bytes_transferred.take()
+    }
+
+    fn record(&self, attempt: &model::TaskAttempt, context: &mut model::Context) {
+        context.durations.download += attempt.duration();
+        context.counts.bytes_downloaded += attempt.bytes_transferred.unwrap_or(0);
+    }
+
     async fn process(&mut self, progress: &mut prodash::tree::Item) -> std::result::Result<(), (Error, String)> {
         let ProcessingState {
             url,
@@ -111,8 +126,10 @@ where
             output_file_path,
             result_key,
         } = self.state.take().expect("initialized state");
+        let bytes_transferred = self.bytes_transferred.insert(0);
         download_file_and_store_result(
             progress,
+            bytes_transferred,
             result_key,
             &self.results,
             &self.client,
@@ -125,16 +142,16 @@ where
     }
 
     async fn schedule_next(&mut self, progress: &mut prodash::tree::Item) -> Result<()> {
-        if let Some(request) = self.next_action_state.take() {
-            progress.blocked("schedule crate extraction", None);
-            // Here we risk doing this work twice, but most of the time, we don't. And since it's fast,
-            // we take the risk of duplicate work for keeping more processors busy.
-            // NOTE: We assume there is no risk of double-scheduling, also we assume the consumer is faster
-            // then the producer (us), so we are ok with blocking until the task is scheduled.
-            self.channel
-                .send(request)
+        if let (Some(downloaded_files), Some(downloaded_file)) =
+            (self.downloaded_files.as_ref(), self.downloaded_file.take())
+        {
+            progress.blocked("hand over downloaded file", None);
+            // NOTE: We assume the consumer is faster than the producer (us), so we are ok with blocking until
+            // the file is handed over.
+            downloaded_files
+                .send(downloaded_file)
                 .await
-                .map_err(Error::send_msg("IO Bound: Schedule next task"))?;
+                .map_err(Error::send_msg("IO Bound: Hand over downloaded file"))?;
         }
         Ok(())
     }
@@ -150,19 +167,85 @@ pub struct DownloadRequest {
     pub url: String,
 }
 
+pub const TASK_NAME: &str = "download";
+const TASK_VERSION: &str = "1.0.0";
+
 pub fn default_persisted_download_task() -> model::Task {
-    const TASK_NAME: &str = "download";
-    const TASK_VERSION: &str = "1.0.0";
     model::Task {
         stored_at: SystemTime::now(),
         process: TASK_NAME.into(),
         version: TASK_VERSION.into(),
         state: Default::default(),
+        attempts: Vec::new(),
+    }
+}
+
+/// Download `.crate` files from crates.io
+pub struct DownloadCrate;
+
+impl TaskKind for DownloadCrate {
+    fn name(&self) -> &'static str {
+        TASK_NAME
+    }
+
+    fn version(&self) -> &'static str {
+        TASK_VERSION
+    }
+
+    fn worker_kind(&self) -> model::WorkerKind {
+        model::WorkerKind::IoBound
+    }
+
+    fn max_retries_on_timeout(&self) -> usize {
+        40
+    }
+
+    fn default_task(&self) -> model::Task {
+        default_persisted_download_task()
+    }
+
+    fn new_processor(&self, db: &persistence::Db, assets_dir: &Path) -> Result<BoxedProcessor> {
+        let assets_dir = assets_dir.to_owned();
+        Ok(Box::new(MapRequest {
+            processor: Agent::new(db)?,
+            map: move |TaskRequest {
+                           crate_name,
+                           crate_version,
+                           ..
+                       }| {
+                let kind = "crate";
+                let dummy_task = default_persisted_download_task();
+                let mut task_key = String::new();
+                dummy_task.fq_key(&crate_name, &crate_version, &mut task_key);
+
+                DownloadRequest {
+                    output_file_path: super::schedule::download_file_path(
+                        &assets_dir,
+                        &crate_name,
+                        &crate_version,
+                        &dummy_task.process,
+                        &dummy_task.version,
+                        kind,
+                    ),
+                    progress_name: format!("{}:{}", crate_name, crate_version),
+                    task_key,
+                    url: format!(
+                        "https://static.crates.io/crates/{name}/{name}-{version}.crate",
+                        name = crate_name,
+                        version = crate_version
+                    ),
+                    crate_name_and_version: Some((crate_name, crate_version)),
+                    kind,
+                }
+            },
+        }))
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn download_file_and_store_result(
     progress: &mut prodash::tree::Item,
+    bytes_transferred: &mut u64,
     result_key: Option<String>,
     results: &persistence::TaskResultTable,
     client: &reqwest::Client,
@@ -172,7 +255,7 @@ async fn download_file_and_store_result(
 ) -> Result<()> {
     blocking::unblock({
         let out_file = out_file.clone();
-        move || std::fs::create_dir_all(&out_file.parent().expect("parent directory"))
+        move || std::fs::create_dir_all(out_file.parent().expect("parent directory"))
     })
     .await?;
 
@@ -210,7 +293,7 @@ async fn download_file_and_store_result(
                     ""
                 },
                 url,
-                ByteSize(start_byte as u64)
+                ByteSize(start_byte)
             ));
             return Ok(());
         }
@@ -261,6 +344,7 @@ async fn download_file_and_store_result(
         {
             out.write_all(&chunk).await?;
             bytes_received += chunk.len();
+            *bytes_transferred += chunk.len() as u64;
             progress.set(bytes_received / 1024);
         }
         progress.done(format!(
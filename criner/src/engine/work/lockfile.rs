@@ -0,0 +1,132 @@
+use crate::{
+    engine::{
+        report::waste::tar_path_to_utf8_str,
+        work::{
+            exploded, manifest,
+            registry::{BoxedProcessor, TaskKind},
+        },
+    },
+    error::Result,
+    model, persistence,
+};
+use std::path::Path;
+use toml::Value;
+
+const TASK_NAME: &str = "cargo_lock";
+const TASK_VERSION: &str = "1.0.0";
+
+/// The prefix of keys in the `[metadata]` section of lockfiles of the first format, which contain checksums
+const METADATA_CHECKSUM_PREFIX: &str = "checksum ";
+
+/// Parse the `Cargo.lock` stored during extraction, if the crate ships one, into a `CargoLock`
+pub struct ParseLockfile;
+
+impl TaskKind for ParseLockfile {
+    fn name(&self) -> &'static str {
+        TASK_NAME
+    }
+
+    fn version(&self) -> &'static str {
+        TASK_VERSION
+    }
+
+    fn dependencies(&self) -> &'static [&'static str] {
+        &[super::cpubound::TASK_NAME]
+    }
+
+    fn worker_kind(&self) -> model::WorkerKind {
+        model::WorkerKind::CpuBound
+    }
+
+    fn new_processor(&self, db: &persistence::Db, _assets_dir: &Path) -> Result<BoxedProcessor> {
+        Ok(Box::new(exploded::Agent::new(
+            self.default_task(),
+            db,
+            model::TaskResult::CargoLock {
+                lockfile: None,
+                error: None,
+            },
+            "Cargo.lock",
+            compute,
+        )?))
+    }
+}
+
+fn compute(
+    entries_meta_data: &[model::TarHeader],
+    selected_entries: &[(model::TarHeader, Vec<u8>)],
+    progress: &mut prodash::tree::Item,
+) -> model::TaskResult {
+    let (lockfile, error) = match exploded::find(selected_entries, "Cargo.lock") {
+        Some(content) => match std::str::from_utf8(content)
+            .map_err(|err| err.to_string())
+            .and_then(parse)
+        {
+            Ok(mut lockfile) => {
+                lockfile.has_binary_target = has_binary_target(
+                    entries_meta_data
+                        .iter()
+                        .map(|header| tar_path_to_utf8_str(&header.path)),
+                    exploded::find(selected_entries, "Cargo.toml")
+                        .and_then(|content| std::str::from_utf8(content).ok()),
+                );
+                (Some(lockfile), None)
+            }
+            Err(err) => (None, Some(err)),
+        },
+        None => (None, None),
+    };
+    if let Some(err) = error.as_ref() {
+        progress.info(format!("Could not parse Cargo.lock: {}", err));
+    }
+    model::TaskResult::CargoLock { lockfile, error }
+}
+
+/// Returns true if any of the `paths` in a crate is a binary by convention, or if `manifest` declares one.
+pub fn has_binary_target(paths: impl IntoIterator<Item = impl AsRef<str>>, manifest: Option<&str>) -> bool {
+    paths.into_iter().any(|path| {
+        let path = path.as_ref();
+        path == "src/main.rs" || (path.starts_with("src/bin/") && path.ends_with(".rs"))
+    }) || manifest
+        .and_then(|manifest| manifest::parse(manifest).ok())
+        .is_some_and(|manifest| manifest.build_targets.iter().any(|target| target.kind == "bin"))
+}
+
+/// Parse the `lockfile`, the content of a `Cargo.lock` file, or return a description of why it is invalid.
+///
+/// `has_binary_target` is always false as it isn't known by the lockfile itself.
+pub fn parse(lockfile: &str) -> std::result::Result<model::CargoLock, String> {
+    let lockfile: toml::Table = toml::from_str(lockfile).map_err(|err| err.to_string())?;
+    let metadata = lockfile.get("metadata").and_then(Value::as_table);
+    let mut packages = Vec::new();
+    for package in lockfile.get("package").and_then(Value::as_array).into_iter().flatten() {
+        let field = |name: &str| package.get(name).and_then(Value::as_str).map(ToOwned::to_owned);
+        let (name, version) = match (field("name"), field("version")) {
+            (Some(name), Some(version)) => (name, version),
+            _ => return Err(format!("A package lacks its name or version: {}", package)),
+        };
+        let source = field("source");
+        let checksum = field("checksum").or_else(|| {
+            let mut key = format!("{}{} {}", METADATA_CHECKSUM_PREFIX, name, version);
+            if let Some(source) = source.as_ref() {
+                key.push_str(&format!(" ({})", source));
+            }
+            metadata?
+                .get(&key)
+                .and_then(Value::as_str)
+                .filter(|checksum| *checksum != "<none>")
+                .map(ToOwned::to_owned)
+        });
+        packages.push(model::LockedPackage {
+            name,
+            version,
+            source,
+            checksum,
+        });
+    }
+    Ok(model::CargoLock {
+        version: lockfile.get("version").and_then(Value::as_integer),
+        packages,
+        has_binary_target: false,
+    })
+}
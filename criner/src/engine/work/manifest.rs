@@ -0,0 +1,177 @@
+use crate::{
+    engine::work::{
+        exploded,
+        registry::{BoxedProcessor, TaskKind},
+    },
+    error::Result,
+    model, persistence,
+};
+use std::path::Path;
+use toml::Value;
+
+const TASK_NAME: &str = "cargo_manifest";
+const TASK_VERSION: &str = "1.0.0";
+
+/// Sections of a manifest with dependencies
+const DEPENDENCY_SECTIONS: &[&str] = &["dependencies", "dev-dependencies", "build-dependencies"];
+
+/// Sections of a manifest with build targets
+const BUILD_TARGET_SECTIONS: &[&str] = &["lib", "bin", "example", "test", "bench"];
+
+/// Parse the `Cargo.toml` stored during extraction into a `CargoManifest`
+pub struct ParseManifest;
+
+impl TaskKind for ParseManifest {
+    fn name(&self) -> &'static str {
+        TASK_NAME
+    }
+
+    fn version(&self) -> &'static str {
+        TASK_VERSION
+    }
+
+    fn dependencies(&self) -> &'static [&'static str] {
+        &[super::cpubound::TASK_NAME]
+    }
+
+    fn worker_kind(&self) -> model::WorkerKind {
+        model::WorkerKind::CpuBound
+    }
+
+    fn new_processor(&self, db: &persistence::Db, _assets_dir: &Path) -> Result<BoxedProcessor> {
+        Ok(Box::new(exploded::Agent::new(
+            self.default_task(),
+            db,
+            model::TaskResult::CargoManifest {
+                manifest: None,
+                error: None,
+            },
+            "Cargo.toml",
+            compute,
+        )?))
+    }
+}
+
+fn compute(
+    _entries_meta_data: &[model::TarHeader],
+    selected_entries: &[(model::TarHeader, Vec<u8>)],
+    progress: &mut prodash::tree::Item,
+) -> model::TaskResult {
+    let (manifest, error) = match exploded::find(selected_entries, "Cargo.toml") {
+        Some(content) => match std::str::from_utf8(content)
+            .map_err(|err| err.to_string())
+            .and_then(parse)
+        {
+            Ok(manifest) => (Some(manifest), None),
+            Err(err) => (None, Some(err)),
+        },
+        None => (None, Some("Cargo.toml was not found in the crate".into())),
+    };
+    if let Some(err) = error.as_ref() {
+        progress.info(format!("Could not parse Cargo.toml: {}", err));
+    }
+    model::TaskResult::CargoManifest { manifest, error }
+}
+
+/// Parse the `manifest`, the content of a `Cargo.toml` file, or return a description of why it is invalid.
+pub fn parse(manifest: &str) -> std::result::Result<model::CargoManifest, String> {
+    let manifest: toml::Table = toml::from_str(manifest).map_err(|err| err.to_string())?;
+    let package = manifest.get("package").or_else(|| manifest.get("project"));
+    let package_str = |field: &str| {
+        package
+            .and_then(|p| p.get(field))
+            .and_then(Value::as_str)
+            .map(Into::into)
+    };
+
+    let mut workspace_inherited = Vec::new();
+    if let Some(package) = package.and_then(Value::as_table) {
+        workspace_inherited.extend(
+            package
+                .iter()
+                .filter(|(_, v)| is_inherited(v))
+                .map(|(field, _)| format!("package.{}", field)),
+        );
+    }
+    let mut platform_targets = Vec::new();
+    for section in DEPENDENCY_SECTIONS {
+        collect_inherited_dependencies(&manifest, section, section, &mut workspace_inherited);
+    }
+    for (platform, target) in table_entries(&manifest, "target") {
+        platform_targets.push(platform.clone());
+        if let Some(target) = target.as_table() {
+            for section in DEPENDENCY_SECTIONS {
+                collect_inherited_dependencies(
+                    target,
+                    section,
+                    &format!("target.{}.{}", platform, section),
+                    &mut workspace_inherited,
+                );
+            }
+        }
+    }
+    if manifest.get("lints").is_some_and(is_inherited) {
+        workspace_inherited.push("lints".into());
+    }
+
+    Ok(model::CargoManifest {
+        edition: package_str("edition"),
+        rust_version: package_str("rust-version"),
+        links: package_str("links"),
+        features: table_entries(&manifest, "features")
+            .map(|(name, enables)| {
+                (
+                    name.clone(),
+                    enables
+                        .as_array()
+                        .map(|a| a.iter().filter_map(Value::as_str).map(Into::into).collect())
+                        .unwrap_or_default(),
+                )
+            })
+            .collect(),
+        build_targets: BUILD_TARGET_SECTIONS
+            .iter()
+            .flat_map(|kind| {
+                let sections = match manifest.get(*kind) {
+                    Some(Value::Array(sections)) => sections.iter().collect(),
+                    Some(section @ Value::Table(_)) => vec![section],
+                    _ => Vec::new(),
+                };
+                sections.into_iter().map(move |section| model::BuildTarget {
+                    kind: (*kind).into(),
+                    name: section.get("name").and_then(Value::as_str).map(Into::into),
+                    path: section.get("path").and_then(Value::as_str).map(Into::into),
+                })
+            })
+            .collect(),
+        platform_targets,
+        profiles: table_entries(&manifest, "profile")
+            .map(|(name, _)| name.clone())
+            .collect(),
+        patched_sources: table_entries(&manifest, "patch")
+            .map(|(name, _)| name.clone())
+            .collect(),
+        replaced_packages: table_entries(&manifest, "replace")
+            .map(|(name, _)| name.clone())
+            .collect(),
+        workspace_inherited,
+        is_workspace_root: manifest.contains_key("workspace"),
+    })
+}
+
+fn table_entries<'a>(table: &'a toml::Table, key: &str) -> impl Iterator<Item = (&'a String, &'a Value)> {
+    table.get(key).and_then(Value::as_table).into_iter().flatten()
+}
+
+/// Returns true for values like `{ workspace = true }`
+fn is_inherited(value: &Value) -> bool {
+    value.get("workspace").and_then(Value::as_bool).unwrap_or(false)
+}
+
+fn collect_inherited_dependencies(table: &toml::Table, section: &str, prefix: &str, out: &mut Vec<String>) {
+    out.extend(
+        table_entries(table, section)
+            .filter(|(_, dependency)| is_inherited(dependency))
+            .map(|(name, _)| format!("{}.{}", prefix, name)),
+    );
+}
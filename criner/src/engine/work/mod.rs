@@ -1,5 +1,13 @@
+pub mod exploded;
+pub mod external;
 pub mod generic;
 pub mod iobound;
+pub mod lockfile;
+pub mod manifest;
+pub mod registry;
 pub mod schedule;
 
 pub mod cpubound;
+
+#[cfg(test)]
+mod work_test;
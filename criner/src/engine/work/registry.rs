@@ -0,0 +1,319 @@
+use crate::{
+    engine::work::generic::Processor,
+    model,
+    persistence::{self, Db},
+    Error, Result,
+};
+use async_trait::async_trait;
+use std::{collections::BTreeMap, path::Path, sync::Arc};
+
+/// Everything a processor needs to run a task on a crate version.
+#[derive(Clone)]
+pub struct TaskRequest {
+    pub crate_name: String,
+    pub crate_version: String,
+    /// The complete tasks this task depends on, in the order of `TaskKind::dependencies()`
+    pub dependencies: Vec<model::Task>,
+}
+
+pub type BoxedProcessor = Box<dyn Processor<Item = TaskRequest> + Send>;
+
+/// A kind of task to run once on every crate version, after all tasks it depends on are complete.
+///
+/// Its processor stores the outcome as `TaskResult` in the `result` table, keyed by the task.
+pub trait TaskKind: Send + Sync {
+    /// The name of the task, stored as `Task::process`
+    fn name(&self) -> &'static str;
+    /// The version of the task. Changing it causes the task to run again on all crate versions.
+    fn version(&self) -> &'static str;
+    /// The names of all tasks which must be complete before this one can run
+    fn dependencies(&self) -> &'static [&'static str] {
+        &[]
+    }
+    /// The kind of worker to run the processors on.
+    fn worker_kind(&self) -> model::WorkerKind;
+    /// The amount of times to try again if the processor timed out.
+    fn max_retries_on_timeout(&self) -> usize {
+        0
+    }
+    /// Create a new processor for this kind of task, one per worker.
+    fn new_processor(&self, db: &Db, assets_dir: &Path) -> Result<BoxedProcessor>;
+
+    /// The task as stored before it ran for the first time
+    fn default_task(&self) -> model::Task {
+        model::Task {
+            process: self.name().into(),
+            version: self.version().into(),
+            ..Default::default()
+        }
+    }
+}
+
+/// All kinds of tasks to run on crate versions, sorted so that dependencies come before their dependents.
+#[derive(Clone)]
+pub struct Registry {
+    kinds: Vec<Arc<dyn TaskKind>>,
+}
+
+impl Default for Registry {
+    /// Download and extract all crates, and parse their manifests and lockfiles.
+    fn default() -> Self {
+        Registry::new(Registry::default_kinds()).expect("valid built-in task kinds")
+    }
+}
+
+impl Registry {
+    /// The built-in task kinds.
+    pub fn default_kinds() -> Vec<Arc<dyn TaskKind>> {
+        vec![
+            Arc::new(super::iobound::DownloadCrate),
+            Arc::new(super::cpubound::ExtractCrate),
+            Arc::new(super::manifest::ParseManifest),
+            Arc::new(super::lockfile::ParseLockfile),
+        ]
+    }
+
+    /// Create a new instance with all `kinds`, which must have unique names and may not depend on each other
+    /// in cycles.
+    pub fn new(kinds: Vec<Arc<dyn TaskKind>>) -> Result<Registry> {
+        let mut by_name = BTreeMap::new();
+        for kind in kinds.into_iter() {
+            if by_name.insert(kind.name(), kind.clone()).is_some() {
+                return Err(Error::Message(format!(
+                    "Task '{}' was registered more than once",
+                    kind.name()
+                )));
+            }
+        }
+
+        for kind in by_name.values() {
+            if let Some(unknown) = kind.dependencies().iter().find(|d| !by_name.contains_key(**d)) {
+                return Err(Error::Message(format!(
+                    "Task '{}' depends on unknown task '{}'",
+                    kind.name(),
+                    unknown
+                )));
+            }
+        }
+
+        let mut sorted = Vec::<Arc<dyn TaskKind>>::with_capacity(by_name.len());
+        while !by_name.is_empty() {
+            let ready: Vec<_> = by_name
+                .values()
+                .filter(|kind| {
+                    kind.dependencies()
+                        .iter()
+                        .all(|dependency| sorted.iter().any(|k| k.name() == *dependency))
+                })
+                .map(|kind| kind.name())
+                .collect();
+            if ready.is_empty() {
+                return Err(Error::Message(format!(
+                    "Tasks {:?} depend on each other in a cycle",
+                    by_name.keys().collect::<Vec<_>>()
+                )));
+            }
+            for name in ready {
+                sorted.push(by_name.remove(name).expect("present"));
+            }
+        }
+        Ok(Registry { kinds: sorted })
+    }
+
+    /// All task kinds, dependencies first.
+    pub fn kinds(&self) -> &[Arc<dyn TaskKind>] {
+        &self.kinds
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Arc<dyn TaskKind>> {
+        self.kinds.iter().find(|k| k.name() == name)
+    }
+
+    /// All task kinds which directly depend on the task named `name`.
+    pub fn dependents<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Arc<dyn TaskKind>> + 'a {
+        self.kinds.iter().filter(move |k| k.dependencies().contains(&name))
+    }
+}
+
+/// The channels to send requests to, by task name
+pub type Senders = BTreeMap<&'static str, async_channel::Sender<TaskRequest>>;
+
+/// A processor which schedules all tasks depending on the task it just completed, if possible.
+pub struct ScheduleDependents {
+    processor: BoxedProcessor,
+    registry: Arc<Registry>,
+    tasks: persistence::TaskTable,
+    /// The channels of all dependents of our task
+    dependents: Senders,
+    startup_time: std::time::SystemTime,
+    state: Option<(TaskRequest, model::Task)>,
+}
+
+impl ScheduleDependents {
+    pub fn new(
+        processor: BoxedProcessor,
+        registry: Arc<Registry>,
+        db: &Db,
+        dependents: Senders,
+        startup_time: std::time::SystemTime,
+    ) -> Result<ScheduleDependents> {
+        Ok(ScheduleDependents {
+            processor,
+            registry,
+            tasks: db.open_tasks()?,
+            dependents,
+            startup_time,
+            state: None,
+        })
+    }
+}
+
+#[async_trait]
+impl Processor for ScheduleDependents {
+    type Item = TaskRequest;
+
+    fn set(
+        &mut self,
+        request: Self::Item,
+        progress: &mut prodash::tree::Item,
+    ) -> Result<(model::Task, String, String)> {
+        let res = self.processor.set(request.clone(), progress)?;
+        self.state = Some((request, res.0.clone()));
+        Ok(res)
+    }
+
+    fn idle_message(&self) -> String {
+        self.processor.idle_message()
+    }
+
+    fn worker_kind(&self) -> model::WorkerKind {
+        self.processor.worker_kind()
+    }
+
+    fn bytes_transferred(&mut self) -> Option<u64> {
+        self.processor.bytes_transferred()
+    }
+
+    fn record(&self, attempt: &model::TaskAttempt, context: &mut model::Context) {
+        self.processor.record(attempt, context)
+    }
+
+    async fn process(&mut self, progress: &mut prodash::tree::Item) -> std::result::Result<(), (Error, String)> {
+        self.processor.process(progress).await
+    }
+
+    async fn schedule_next(&mut self, progress: &mut prodash::tree::Item) -> Result<()> {
+        self.processor.schedule_next(progress).await?;
+        if let Some((request, task)) = self.state.take() {
+            if self.dependents.is_empty() {
+                return Ok(());
+            }
+            progress.blocked("schedule dependent tasks", None);
+            let mut completed = task;
+            completed.state = model::TaskState::Complete;
+            super::schedule::tasks(
+                &self.tasks,
+                &request.crate_name,
+                &request.crate_version,
+                progress,
+                super::schedule::Scheduling::AtLeastOne,
+                &self.registry,
+                &self.dependents,
+                Some(&completed),
+                self.startup_time,
+            )
+            .await?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<P> Processor for Box<P>
+where
+    P: Processor + Send + ?Sized,
+    P::Item: Send,
+{
+    type Item = P::Item;
+
+    fn set(
+        &mut self,
+        request: Self::Item,
+        progress: &mut prodash::tree::Item,
+    ) -> Result<(model::Task, String, String)> {
+        (**self).set(request, progress)
+    }
+
+    fn idle_message(&self) -> String {
+        (**self).idle_message()
+    }
+
+    fn worker_kind(&self) -> model::WorkerKind {
+        (**self).worker_kind()
+    }
+
+    fn bytes_transferred(&mut self) -> Option<u64> {
+        (**self).bytes_transferred()
+    }
+
+    fn record(&self, attempt: &model::TaskAttempt, context: &mut model::Context) {
+        (**self).record(attempt, context)
+    }
+
+    async fn process(&mut self, progress: &mut prodash::tree::Item) -> std::result::Result<(), (Error, String)> {
+        (**self).process(progress).await
+    }
+
+    async fn schedule_next(&mut self, progress: &mut prodash::tree::Item) -> Result<()> {
+        (**self).schedule_next(progress).await
+    }
+}
+
+/// Adapt a processor with its own kind of request to accept `TaskRequest`s.
+pub struct MapRequest<P, F> {
+    pub processor: P,
+    pub map: F,
+}
+
+#[async_trait]
+impl<P, F> Processor for MapRequest<P, F>
+where
+    P: Processor + Send,
+    P::Item: Send,
+    F: FnMut(TaskRequest) -> P::Item + Send,
+{
+    type Item = TaskRequest;
+
+    fn set(
+        &mut self,
+        request: Self::Item,
+        progress: &mut prodash::tree::Item,
+    ) -> Result<(model::Task, String, String)> {
+        let request = (self.map)(request);
+        self.processor.set(request, progress)
+    }
+
+    fn idle_message(&self) -> String {
+        self.processor.idle_message()
+    }
+
+    fn worker_kind(&self) -> model::WorkerKind {
+        self.processor.worker_kind()
+    }
+
+    fn bytes_transferred(&mut self) -> Option<u64> {
+        self.processor.bytes_transferred()
+    }
+
+    fn record(&self, attempt: &model::TaskAttempt, context: &mut model::Context) {
+        self.processor.record(attempt, context)
+    }
+
+    async fn process(&mut self, progress: &mut prodash::tree::Item) -> std::result::Result<(), (Error, String)> {
+        self.processor.process(progress).await
+    }
+
+    async fn schedule_next(&mut self, progress: &mut prodash::tree::Item) -> Result<()> {
+        self.processor.schedule_next(progress).await
+    }
+}
@@ -1,10 +1,11 @@
 use crate::{
-    engine::{work::cpubound, work::iobound},
+    engine::work::registry::{Registry, Senders, TaskRequest},
     error::Result,
     model, persistence,
     persistence::{TableAccess, TaskTable},
 };
 use std::{
+    collections::BTreeMap,
     path::{Path, PathBuf},
     time::SystemTime,
 };
@@ -28,75 +29,72 @@ pub enum AsyncResult {
     Done,
 }
 
+/// Submit all tasks of the `registry` for the given crate version which are not yet complete, but whose dependencies
+/// are. Only tasks with a channel in `senders` are submitted.
+/// `just_completed` is a task considered complete even though it might not yet have been stored as such.
 #[allow(clippy::too_many_arguments)]
 pub async fn tasks(
-    assets_dir: &Path,
     tasks: &persistence::TaskTable,
-    krate: &model::CrateVersion,
+    crate_name: &str,
+    crate_version: &str,
     progress: &mut prodash::tree::Item,
     _mode: Scheduling,
-    perform_io: &async_channel::Sender<iobound::DownloadRequest>,
-    perform_cpu: &async_channel::Sender<cpubound::ExtractRequest>,
+    registry: &Registry,
+    senders: &Senders,
+    just_completed: Option<&model::Task>,
     startup_time: SystemTime,
 ) -> Result<AsyncResult> {
     use SubmitResult::*;
     let mut key_buf = String::with_capacity(32);
-    let io_task = task_or_default(tasks, &mut key_buf, krate, iobound::default_persisted_download_task)?;
-
-    let kind = "crate";
-    let submit_result = submit_single(startup_time, io_task, progress, perform_io, 1, 1, || {
-        let dummy_task = iobound::default_persisted_download_task();
-        let mut task_key = String::new();
-        dummy_task.fq_key(&krate.name, &krate.version, &mut task_key);
-
-        iobound::DownloadRequest {
-            output_file_path: download_file_path(
-                assets_dir,
-                &krate.name,
-                &krate.version,
-                &dummy_task.process,
-                &dummy_task.version,
-                kind,
-            ),
-            progress_name: format!("{}:{}", krate.name, krate.version),
-            task_key,
-            crate_name_and_version: Some((krate.name.clone(), krate.version.clone())),
-            kind,
-            url: format!(
-                "https://static.crates.io/crates/{name}/{name}-{version}.crate",
-                name = krate.name,
-                version = krate.version
-            ),
+    let mut complete = BTreeMap::<&str, model::Task>::new();
+    let num_kinds = registry.kinds().len();
+    for (kind_index, kind) in registry.kinds().iter().enumerate() {
+        if let Some(task) = just_completed.filter(|t| t.process == kind.name() && t.version == kind.version()) {
+            complete.insert(kind.name(), task.clone());
+            continue;
         }
-    })
-    .await;
-
-    Ok(match submit_result {
-        PermanentFailure | Submitted => AsyncResult::Done,
-        Done(download_crate_task) => {
-            let cpu_task = task_or_default(tasks, &mut key_buf, krate, cpubound::default_persisted_extraction_task)?;
-            submit_single(startup_time, cpu_task, progress, perform_cpu, 2, 2, || {
-                cpubound::ExtractRequest {
-                    download_task: download_crate_task,
-                    crate_name: krate.name.clone(),
-                    crate_version: krate.version.clone(),
+        if !kind.dependencies().iter().all(|d| complete.contains_key(d)) {
+            continue;
+        }
+        let task = task_or_default(tasks, &mut key_buf, crate_name, crate_version, || kind.default_task())?;
+        let sender = match senders.get(kind.name()) {
+            Some(sender) => sender,
+            None => {
+                if task.state.is_complete() {
+                    complete.insert(kind.name(), task);
                 }
-            })
-            .await;
-            AsyncResult::Done
+                continue;
+            }
+        };
+        let submit_result = submit_single(startup_time, task, progress, sender, kind_index + 1, num_kinds, || {
+            TaskRequest {
+                crate_name: crate_name.to_owned(),
+                crate_version: crate_version.to_owned(),
+                dependencies: kind
+                    .dependencies()
+                    .iter()
+                    .map(|d| complete.get(d).expect("dependencies to be complete").clone())
+                    .collect(),
+            }
+        })
+        .await;
+        if let Done(task) = submit_result {
+            complete.insert(kind.name(), task);
         }
-    })
+    }
+    Ok(AsyncResult::Done)
 }
 
 fn task_or_default(
     tasks: &TaskTable,
     key_buf: &mut String,
-    crate_version: &model::CrateVersion,
+    crate_name: &str,
+    crate_version: &str,
     make_task: impl FnOnce() -> model::Task,
 ) -> Result<model::Task> {
     let task = make_task();
     key_buf.clear();
-    task.fq_key(&crate_version.name, &crate_version.version, key_buf);
+    task.fq_key(crate_name, crate_version, key_buf);
     Ok(tasks.get(key_buf)?.unwrap_or(task))
 }
 
@@ -123,7 +121,7 @@ async fn submit_single<R>(
         progress.blocked("wait for consumer", None);
     };
     match task.state {
-        InProgress(_) => {
+        InProgress => {
             if startup_time > task.stored_at {
                 configure();
                 channel.send(f()).await.unwrap();
@@ -135,13 +133,13 @@ async fn submit_single<R>(
             channel.send(f()).await.unwrap();
             Submitted
         }
-        AttemptsWithFailure(ref v) if v.len() < MAX_ATTEMPTS_BEFORE_WE_GIVE_UP => {
+        AttemptsWithFailure if task.failed_attempts() < MAX_ATTEMPTS_BEFORE_WE_GIVE_UP => {
             configure();
-            progress.info(format!("Retrying task, attempt {}", v.len() + 1));
+            progress.info(format!("Retrying task, attempt {}", task.failed_attempts() + 1));
             channel.send(f()).await.unwrap();
             Submitted
         }
-        AttemptsWithFailure(_) => PermanentFailure,
+        AttemptsWithFailure => PermanentFailure,
         Complete => Done(task),
     }
 }
@@ -0,0 +1,124 @@
+use crate::{
+    engine::work::{
+        external::{self, ExternalCommand, Input},
+        generic::Processor,
+        registry::{TaskKind, TaskRequest},
+    },
+    model,
+    persistence::{Db, TableAccess},
+    Error,
+};
+use std::{path::PathBuf, time::Duration};
+
+fn assets_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("criner-external-test-{}-{}", name, std::process::id()));
+    std::fs::remove_dir_all(&dir).ok();
+    dir
+}
+
+fn command(args: &[&str], input: Input) -> ExternalCommand {
+    let mut config = external::load_config_from_str(&format!(
+        "[[command]]\nname = \"sh\"\nversion = \"1.0.0\"\nprogram = \"sh\"\ninput = \"{}\"\ntimeout = \"2s\"\n",
+        match input {
+            Input::CrateFile => "crate-file",
+            Input::CrateDirectory => "crate-directory",
+        }
+    ))
+    .unwrap();
+    let mut config = config.remove(0);
+    config.args = std::iter::once("-c")
+        .chain(args.iter().copied())
+        .map(Into::into)
+        .collect();
+    ExternalCommand::new(config).unwrap()
+}
+
+fn run(kind: &ExternalCommand, db: &Db, assets_dir: &std::path::Path) -> Result<(), Error> {
+    let root = prodash::tree::Root::new();
+    let mut progress = root.add_child("external");
+    let download_task = super::write_crate(assets_dir, &[("krate-1.0.0/Cargo.toml", b"[package]")]);
+    let mut processor = kind.new_processor(db, assets_dir).unwrap();
+    processor
+        .set(
+            TaskRequest {
+                crate_name: "krate".into(),
+                crate_version: "1.0.0".into(),
+                dependencies: vec![download_task],
+            },
+            &mut progress,
+        )
+        .unwrap();
+    futures_lite::future::block_on(processor.process(&mut progress)).map_err(|(err, _)| err)
+}
+
+fn stored_output(db: &Db) -> Option<String> {
+    match db.open_results().unwrap().get("krate:1.0.0:sh:1.0.0").unwrap() {
+        Some(model::TaskResult::ExternalCommand { command, output }) => {
+            assert_eq!(command[..2], ["sh".to_string(), "-c".into()]);
+            Some(output)
+        }
+        Some(_) => panic!("unexpected result"),
+        None => None,
+    }
+}
+
+#[test]
+fn config_is_validated() {
+    let config = external::load_config_from_str(
+        "[[command]]\nname = \"licenses\"\nversion = \"1\"\nprogram = \"scan\"\nargs = [\"--json\"]\ninput = \"crate-directory\"\ntimeout = \"5min\"\n",
+    )
+    .unwrap();
+    assert_eq!(config.len(), 1);
+    assert_eq!(config[0].input, Input::CrateDirectory);
+    assert_eq!(config[0].timeout, Duration::from_secs(300));
+
+    let mut invalid = config[0].clone();
+    invalid.name = "a:b".into();
+    assert!(
+        ExternalCommand::new(invalid).is_err(),
+        "the key separator is not allowed"
+    );
+    assert!(
+        external::load_config_from_str("[[command]]\nname = \"x\"\n").is_err(),
+        "fields are required"
+    );
+}
+
+#[test]
+fn stdout_is_stored_as_json_with_the_crate_file_as_last_argument() {
+    let (db, assets_dir) = (Db::in_memory(), assets_dir("file"));
+    let kind = command(&[r#"printf '{"file": "%s"}' "$(basename "$0")""#], Input::CrateFile);
+    run(&kind, &db, &assets_dir).unwrap();
+    assert_eq!(stored_output(&db).unwrap(), r#"{"file": "1.0.0-download:1.0.0.crate"}"#);
+    std::fs::remove_dir_all(assets_dir).ok();
+}
+
+#[test]
+fn crates_are_unpacked_for_programs_that_want_a_directory() {
+    let (db, assets_dir) = (Db::in_memory(), assets_dir("directory"));
+    let kind = command(
+        &[r#"printf '{"dir": "%s", "name": "%s"}' "$(basename "$0")" "$(ls "$0")""#],
+        Input::CrateDirectory,
+    );
+    run(&kind, &db, &assets_dir).unwrap();
+    assert_eq!(
+        stored_output(&db).unwrap(),
+        r#"{"dir": "krate-1.0.0", "name": "Cargo.toml"}"#
+    );
+    std::fs::remove_dir_all(assets_dir).ok();
+}
+
+#[test]
+fn failures_timeouts_and_invalid_output_are_errors() {
+    let (db, assets_dir) = (Db::in_memory(), assets_dir("failures"));
+    for (script, expected_kind) in &[
+        ("echo oops >&2; exit 1", "Message"),
+        ("echo 'not json'", "Message"),
+        ("sleep 5", "Timeout"),
+    ] {
+        let err = run(&command(&[script], Input::CrateFile), &db, &assets_dir).unwrap_err();
+        assert_eq!(err.kind(), *expected_kind, "{}", script);
+    }
+    assert!(stored_output(&db).is_none());
+    std::fs::remove_dir_all(assets_dir).ok();
+}
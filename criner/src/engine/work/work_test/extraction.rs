@@ -0,0 +1,253 @@
+use crate::{
+    engine::work::{
+        cpubound::{Agent, ExtractRequest, ExtractionConfig, SelectedFiles},
+        generic::Processor,
+    },
+    model,
+    persistence::{Db, TableAccess},
+};
+
+fn extract(config: &ExtractionConfig, files: &[(&str, &[u8])]) -> Vec<(String, usize)> {
+    extract_result(config, files)
+        .1
+        .into_iter()
+        .map(|(header, data)| (String::from_utf8(header.path).unwrap(), data.len()))
+        .collect()
+}
+
+#[allow(clippy::type_complexity)]
+fn extract_result(
+    config: &ExtractionConfig,
+    files: &[(&str, &[u8])],
+) -> (Vec<model::TarHeader>, Vec<(model::TarHeader, Vec<u8>)>) {
+    let (entries, selected, _findings) = extract_with(config, |assets_dir| super::write_crate(assets_dir, files));
+    (entries, selected)
+}
+
+#[allow(clippy::type_complexity)]
+fn extract_with(
+    config: &ExtractionConfig,
+    write_crate: impl FnOnce(&std::path::Path) -> model::Task,
+) -> (
+    Vec<model::TarHeader>,
+    Vec<(model::TarHeader, Vec<u8>)>,
+    Vec<model::EntryFinding>,
+) {
+    let db = Db::in_memory();
+    let assets_dir = std::env::temp_dir().join(format!(
+        "criner-extraction-test-{}-{:?}",
+        std::process::id(),
+        std::thread::current().id()
+    ));
+    let download_task = write_crate(&assets_dir);
+
+    let root = prodash::tree::Root::new();
+    let mut progress = root.add_child("extract");
+    let mut agent = Agent::new(assets_dir.clone(), &db, config).unwrap();
+    let (task, _, _) = agent
+        .set(
+            ExtractRequest {
+                download_task,
+                crate_name: "krate".into(),
+                crate_version: "1.0.0".into(),
+            },
+            &mut progress,
+        )
+        .unwrap();
+    futures_lite::future::block_on(agent.process(&mut progress)).unwrap();
+    std::fs::remove_dir_all(assets_dir).ok();
+    assert_eq!(task.version, config.version());
+
+    let key = format!("krate:1.0.0:extract_crate:{}", config.version());
+    match db.open_results().unwrap().get(&key).unwrap() {
+        Some(model::TaskResult::ExplodedCrate {
+            entries_meta_data,
+            selected_entries,
+            findings,
+        }) => (entries_meta_data, selected_entries, findings),
+        _ => panic!("expected an exploded crate at {}", key),
+    }
+}
+
+const MANIFEST: &[u8] = b"[package]\nname = \"krate\"\n[lib]\npath = \"lib.rs\"\n";
+
+#[test]
+fn the_version_changes_with_the_configuration() {
+    let default = ExtractionConfig::default();
+    assert_eq!(default.version(), "1.3.0");
+
+    let mut config = default.clone();
+    config.files.push(SelectedFiles {
+        glob: "README*".into(),
+        max_size: Some(10),
+    });
+    let version = config.version();
+    assert!(version.starts_with("1.3.0+"), "{}", version);
+    assert_eq!(version, config.clone().version(), "it's stable");
+
+    config.files.last_mut().unwrap().max_size = Some(11);
+    assert_ne!(config.version(), version);
+}
+
+#[test]
+fn the_default_selects_manifests_targets_and_binaries() {
+    let big = vec![b'x'; 200 * 1024];
+    let files = extract(
+        &ExtractionConfig::default(),
+        &[
+            ("krate-1.0.0/Cargo.toml", MANIFEST),
+            ("krate-1.0.0/Cargo.lock", &big),
+            ("krate-1.0.0/lib.rs", &big),
+            ("krate-1.0.0/src/bin/tool.rs", b"fn main() {}"),
+            ("krate-1.0.0/README.md", b"readme"),
+        ],
+    );
+    assert_eq!(
+        files,
+        vec![
+            ("krate-1.0.0/Cargo.toml".into(), MANIFEST.len()),
+            ("krate-1.0.0/Cargo.lock".into(), big.len()),
+            ("krate-1.0.0/lib.rs".into(), 128 * 1024),
+            ("krate-1.0.0/src/bin/tool.rs".into(), 12),
+        ]
+    );
+}
+
+#[test]
+fn modules_and_sources_included_by_targets_are_selected() {
+    let manifest = b"[package]\nname = \"krate\"\n[[test]]\nname = \"all\"\n";
+    let files = extract(
+        &ExtractionConfig::default(),
+        &[
+            ("krate-1.0.0/Cargo.toml", manifest),
+            ("krate-1.0.0/src/lib.rs", b"mod a;\n#[path = \"gen/b.rs\"]\nmod b;"),
+            ("krate-1.0.0/src/a/mod.rs", b"include!(\"table.rs\");"),
+            ("krate-1.0.0/src/a/table.rs", b""),
+            ("krate-1.0.0/src/gen/b.rs", b""),
+            ("krate-1.0.0/src/unused.rs", b""),
+            ("krate-1.0.0/tests/all.rs", b"mod util;"),
+            ("krate-1.0.0/tests/util.rs", b""),
+            ("krate-1.0.0/tests/other.rs", b""),
+        ],
+    );
+    assert_eq!(
+        files.into_iter().map(|(path, _)| path).collect::<Vec<_>>(),
+        vec![
+            "krate-1.0.0/Cargo.toml",
+            "krate-1.0.0/src/lib.rs",
+            "krate-1.0.0/src/a/mod.rs",
+            "krate-1.0.0/src/a/table.rs",
+            "krate-1.0.0/src/gen/b.rs",
+            "krate-1.0.0/tests/all.rs",
+            "krate-1.0.0/tests/util.rs",
+        ]
+    );
+}
+
+#[test]
+fn globs_select_files_with_the_largest_applicable_limit() {
+    let config = ExtractionConfig {
+        targets_max_size: Some(2),
+        files: vec![
+            SelectedFiles {
+                glob: "LICENSE*".into(),
+                max_size: Some(3),
+            },
+            SelectedFiles {
+                glob: "*.rs".into(),
+                max_size: Some(4),
+            },
+            SelectedFiles {
+                glob: ".cargo_vcs_info.json".into(),
+                max_size: None,
+            },
+        ],
+    };
+    let files = extract(
+        &config,
+        &[
+            ("krate-1.0.0/Cargo.toml", MANIFEST),
+            ("krate-1.0.0/Cargo.lock", b"not selected"),
+            ("krate-1.0.0/lib.rs", b"0123456789"),
+            ("krate-1.0.0/LICENSE-MIT", b"0123456789"),
+            ("krate-1.0.0/.cargo_vcs_info.json", b"0123456789"),
+        ],
+    );
+    assert_eq!(
+        files,
+        vec![
+            ("krate-1.0.0/Cargo.toml".into(), MANIFEST.len()),
+            ("krate-1.0.0/lib.rs".into(), 4),
+            ("krate-1.0.0/LICENSE-MIT".into(), 3),
+            ("krate-1.0.0/.cargo_vcs_info.json".into(), 10),
+        ]
+    );
+}
+
+#[test]
+fn all_files_are_hashed_in_full() {
+    let big = vec![b'x'; 200 * 1024];
+    let (entries, selected) = extract_result(
+        &ExtractionConfig::default(),
+        &[
+            ("krate-1.0.0/Cargo.toml", MANIFEST),
+            ("krate-1.0.0/lib.rs", &big),
+            ("krate-1.0.0/vendored/copy.rs", &big),
+            ("krate-1.0.0/empty", b""),
+        ],
+    );
+    let hash_of = |path: &str| {
+        entries
+            .iter()
+            .find(|e| e.path == path.as_bytes())
+            .expect("entry present")
+            .hash
+    };
+    assert_eq!(hash_of("krate-1.0.0/lib.rs"), Some(*blake3::hash(&big).as_bytes()));
+    assert_eq!(hash_of("krate-1.0.0/lib.rs"), hash_of("krate-1.0.0/vendored/copy.rs"));
+    assert_eq!(hash_of("krate-1.0.0/empty"), Some(*blake3::hash(b"").as_bytes()));
+    assert_ne!(hash_of("krate-1.0.0/Cargo.toml"), hash_of("krate-1.0.0/lib.rs"));
+
+    let (lib, data) = &selected[1];
+    assert_eq!(data.len(), 128 * 1024, "the stored content is truncated…");
+    assert_eq!(lib.hash, hash_of("krate-1.0.0/lib.rs"), "…but not the hash");
+}
+
+#[test]
+fn unusual_paths_and_links_are_recorded_as_findings() {
+    use model::EntryFindingKind::*;
+    let entry = |path: &'static [u8], entry_type, link_name: &'static [u8]| super::Entry {
+        path,
+        entry_type,
+        link_name,
+        content: b"",
+    };
+    let regular = tar::EntryType::Regular;
+    let (entries, _, findings) = extract_with(&ExtractionConfig::default(), |assets_dir| {
+        super::write_crate_entries(
+            assets_dir,
+            &[
+                entry(b"krate-1.0.0/Cargo.toml", regular, b""),
+                entry(b"krate-1.0.0/\xff.rs", regular, b""),
+                entry(b"/etc/passwd", regular, b""),
+                entry(b"krate-1.0.0/../../escape", regular, b""),
+                entry(b"krate-1.0.0/link", tar::EntryType::Symlink, b"/etc/shadow"),
+                entry(b"krate-1.0.0/hard", tar::EntryType::Link, b"krate-1.0.0/Cargo.toml"),
+            ],
+        )
+    });
+    assert_eq!(entries.len(), 6, "all entries are recorded as usual");
+    assert_eq!(
+        findings.into_iter().map(|f| (f.path, f.kind)).collect::<Vec<_>>(),
+        vec![
+            (b"krate-1.0.0/\xff.rs".to_vec(), NonUtf8Path),
+            (b"/etc/passwd".to_vec(), AbsolutePath),
+            (b"krate-1.0.0/../../escape".to_vec(), ParentDirectoryComponent),
+            (b"krate-1.0.0/link".to_vec(), Symlink(b"/etc/shadow".to_vec())),
+            (
+                b"krate-1.0.0/hard".to_vec(),
+                Hardlink(b"krate-1.0.0/Cargo.toml".to_vec())
+            ),
+        ]
+    );
+}
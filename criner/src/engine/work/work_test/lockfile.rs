@@ -0,0 +1,123 @@
+use super::{exploded_entry, process_exploded};
+use crate::{
+    engine::work::lockfile::{has_binary_target, parse, ParseLockfile},
+    model,
+};
+
+fn package(name: &str, version: &str, source: Option<&str>, checksum: Option<&str>) -> model::LockedPackage {
+    model::LockedPackage {
+        name: name.into(),
+        version: version.into(),
+        source: source.map(Into::into),
+        checksum: checksum.map(Into::into),
+    }
+}
+
+const CRATES_IO: &str = "registry+https://github.com/rust-lang/crates.io-index";
+
+#[test]
+fn lockfiles_of_all_formats_are_parsed() {
+    assert_eq!(
+        parse(
+            r#"
+            version = 3
+
+            [[package]]
+            name = "krate"
+            version = "1.0.0"
+            dependencies = ["log"]
+
+            [[package]]
+            name = "log"
+            version = "0.4.0"
+            source = "registry+https://github.com/rust-lang/crates.io-index"
+            checksum = "abc"
+            "#
+        )
+        .unwrap(),
+        model::CargoLock {
+            version: Some(3),
+            packages: vec![
+                package("krate", "1.0.0", None, None),
+                package("log", "0.4.0", Some(CRATES_IO), Some("abc")),
+            ],
+            has_binary_target: false,
+        }
+    );
+    assert_eq!(
+        parse(
+            r#"
+            [[package]]
+            name = "log"
+            version = "0.4.0"
+            source = "registry+https://github.com/rust-lang/crates.io-index"
+
+            [[package]]
+            name = "local"
+            version = "0.1.0"
+            source = "git+https://example.com/local"
+
+            [metadata]
+            "checksum log 0.4.0 (registry+https://github.com/rust-lang/crates.io-index)" = "def"
+            "checksum local 0.1.0 (git+https://example.com/local)" = "<none>"
+            "#
+        )
+        .unwrap()
+        .packages,
+        vec![
+            package("log", "0.4.0", Some(CRATES_IO), Some("def")),
+            package("local", "0.1.0", Some("git+https://example.com/local"), None),
+        ],
+        "checksums of the first format are taken from the metadata section"
+    );
+    assert!(parse("[[package]]\nname = \"incomplete\"\n").is_err());
+}
+
+#[test]
+fn binary_targets_are_detected_by_path_or_manifest() {
+    assert!(has_binary_target(["src/lib.rs", "src/main.rs"], None));
+    assert!(has_binary_target(["src/bin/tool.rs"], None));
+    assert!(has_binary_target(
+        ["tool.rs"],
+        Some("[[bin]]\nname = \"tool\"\npath = \"tool.rs\"\n")
+    ));
+    assert!(!has_binary_target(["src/lib.rs", "src/bin/README.md"], Some("[lib]\n")));
+}
+
+#[test]
+fn missing_lockfiles_and_parse_errors_are_stored_as_result() {
+    match process_exploded(
+        &ParseLockfile,
+        vec![
+            exploded_entry("Cargo.lock", b"version = 3\n"),
+            exploded_entry("src/main.rs", b""),
+        ],
+    ) {
+        model::TaskResult::CargoLock {
+            lockfile: Some(lockfile),
+            error: None,
+        } => assert_eq!(
+            lockfile,
+            model::CargoLock {
+                version: Some(3),
+                packages: Vec::new(),
+                has_binary_target: true,
+            }
+        ),
+        res => panic!("unexpected result: {:?}", res),
+    }
+    match process_exploded(&ParseLockfile, vec![exploded_entry("Cargo.lock", b"[[package")]) {
+        model::TaskResult::CargoLock {
+            lockfile: None,
+            error: Some(_),
+        } => {}
+        res => panic!("unexpected result: {:?}", res),
+    }
+    match process_exploded(&ParseLockfile, vec![exploded_entry("src/main.rs", b"")]) {
+        model::TaskResult::CargoLock {
+            lockfile: None,
+            error: None,
+        } => {}
+        res => panic!("unexpected result: {:?}", res),
+    }
+}
@@ -0,0 +1,106 @@
+use super::{exploded_entry, process_exploded};
+use crate::{
+    engine::work::manifest::{parse, ParseManifest},
+    model,
+};
+use common_macros::b_tree_map;
+
+#[test]
+fn all_interesting_fields_are_parsed() {
+    let manifest = parse(
+        r#"
+        [package]
+        name = "krate"
+        edition = "2021"
+        rust-version = "1.60"
+        links = "z"
+        version.workspace = true
+
+        [features]
+        default = ["std"]
+        std = ["dep:serde"]
+
+        [lib]
+        path = "lib.rs"
+        [[bin]]
+        name = "tool"
+
+        [dependencies]
+        serde = { workspace = true }
+        log = "0.4"
+        [target.'cfg(unix)'.dev-dependencies]
+        libc = { workspace = true }
+
+        [profile.release]
+        lto = true
+        [patch.crates-io]
+        log = { path = "../log" }
+        [replace]
+        "foo:0.1.0" = { path = "foo" }
+        [workspace]
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        manifest,
+        model::CargoManifest {
+            edition: Some("2021".into()),
+            rust_version: Some("1.60".into()),
+            links: Some("z".into()),
+            features: b_tree_map! {
+                "default".into() => vec!["std".into()],
+                "std".into() => vec!["dep:serde".into()],
+            },
+            build_targets: vec![
+                model::BuildTarget {
+                    kind: "lib".into(),
+                    name: None,
+                    path: Some("lib.rs".into())
+                },
+                model::BuildTarget {
+                    kind: "bin".into(),
+                    name: Some("tool".into()),
+                    path: None
+                },
+            ],
+            platform_targets: vec!["cfg(unix)".into()],
+            profiles: vec!["release".into()],
+            patched_sources: vec!["crates-io".into()],
+            replaced_packages: vec!["foo:0.1.0".into()],
+            workspace_inherited: vec![
+                "package.version".into(),
+                "dependencies.serde".into(),
+                "target.cfg(unix).dev-dependencies.libc".into()
+            ],
+            is_workspace_root: true,
+        }
+    );
+    assert_eq!(
+        parse("[project]\nname = \"old\"\n").unwrap(),
+        model::CargoManifest::default(),
+        "nothing is set, and the edition is implied"
+    );
+}
+
+#[test]
+fn parse_errors_and_missing_manifests_are_stored_as_result() {
+    match process_exploded(
+        &ParseManifest,
+        vec![exploded_entry("Cargo.toml", b"[package]\nedition = \"2018\"\n")],
+    ) {
+        model::TaskResult::CargoManifest {
+            manifest: Some(manifest),
+            error: None,
+        } => assert_eq!(manifest.edition.as_deref(), Some("2018")),
+        res => panic!("unexpected result: {:?}", res),
+    }
+    for selected_entries in [vec![exploded_entry("Cargo.toml", b"[package")], vec![]] {
+        match process_exploded(&ParseManifest, selected_entries) {
+            model::TaskResult::CargoManifest {
+                manifest: None,
+                error: Some(_),
+            } => {}
+            res => panic!("unexpected result: {:?}", res),
+        }
+    }
+}
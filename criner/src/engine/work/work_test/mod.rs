@@ -0,0 +1,271 @@
+use crate::{
+    engine::work::{
+        cpubound::default_persisted_extraction_task,
+        generic::{processor, Processor},
+        registry::{TaskKind, TaskRequest},
+    },
+    model,
+    persistence::{Db, TableAccess},
+    Error, Result,
+};
+use async_trait::async_trait;
+
+/// Write a `.crate` file for version 1.0.0 of 'krate' with the given files to where it would have been downloaded to.
+fn write_crate(assets_dir: &std::path::Path, files: &[(&str, &[u8])]) -> model::Task {
+    let entries: Vec<_> = files
+        .iter()
+        .map(|(path, content)| Entry {
+            path: path.as_bytes(),
+            entry_type: tar::EntryType::Regular,
+            link_name: b"",
+            content,
+        })
+        .collect();
+    write_crate_entries(assets_dir, &entries)
+}
+
+struct Entry<'a> {
+    path: &'a [u8],
+    entry_type: tar::EntryType,
+    link_name: &'a [u8],
+    content: &'a [u8],
+}
+
+/// Like `write_crate()`, but writes paths and link names as is, even if they are unsafe to unpack.
+fn write_crate_entries(assets_dir: &std::path::Path, entries: &[Entry]) -> model::Task {
+    let download_task = crate::engine::work::iobound::default_persisted_download_task();
+    let path = crate::engine::work::schedule::download_file_path(
+        assets_dir,
+        "krate",
+        "1.0.0",
+        &download_task.process,
+        &download_task.version,
+        "crate",
+    );
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    let mut builder = tar::Builder::new(libflate::gzip::Encoder::new(std::fs::File::create(path).unwrap()).unwrap());
+    for entry in entries {
+        let mut header = tar::Header::new_gnu();
+        {
+            let gnu = header.as_gnu_mut().unwrap();
+            gnu.name[..entry.path.len()].copy_from_slice(entry.path);
+            gnu.linkname[..entry.link_name.len()].copy_from_slice(entry.link_name);
+        }
+        header.set_entry_type(entry.entry_type);
+        header.set_size(entry.content.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        builder.append(&header, entry.content).unwrap();
+    }
+    builder.into_inner().unwrap().finish().into_result().unwrap();
+    download_task
+}
+
+/// Fails the given amount of times before succeeding, with a timeout if `timeout` is set
+struct Agent {
+    failures_left: usize,
+    timeout: bool,
+}
+
+#[async_trait]
+impl Processor for Agent {
+    type Item = &'static str;
+
+    fn set(
+        &mut self,
+        request: Self::Item,
+        _progress: &mut prodash::tree::Item,
+    ) -> Result<(model::Task, String, String)> {
+        let task = model::Task {
+            process: "test".into(),
+            version: "1.0.0".into(),
+            ..Default::default()
+        };
+        let mut key = String::new();
+        task.fq_key(request, "1.0.0", &mut key);
+        Ok((task, key, request.into()))
+    }
+
+    fn idle_message(&self) -> String {
+        "IDLE".into()
+    }
+
+    fn worker_kind(&self) -> model::WorkerKind {
+        model::WorkerKind::CpuBound
+    }
+
+    async fn process(&mut self, _progress: &mut prodash::tree::Item) -> std::result::Result<(), (Error, String)> {
+        if self.failures_left == 0 {
+            Ok(())
+        } else {
+            self.failures_left -= 1;
+            let err = if self.timeout {
+                Error::Timeout(std::time::Duration::from_secs(1), "download".into())
+            } else {
+                Error::Message("failure".into())
+            };
+            Err((err, "processing failed".into()))
+        }
+    }
+}
+
+fn process(db: &Db, agent: Agent, requests: &[&'static str], max_retries_on_timeout: usize) {
+    let root = prodash::tree::Root::new();
+    let (tx, rx) = async_channel::unbounded();
+    for request in requests {
+        tx.try_send(*request).unwrap();
+    }
+    drop(tx);
+    futures_lite::future::block_on(processor(
+        db.clone(),
+        root.add_child("processor"),
+        rx,
+        agent,
+        max_retries_on_timeout,
+    ))
+    .unwrap();
+}
+
+#[test]
+fn processor_records_task_state_in_database() {
+    let db = Db::in_memory();
+    process(
+        &db,
+        Agent {
+            failures_left: 1,
+            timeout: false,
+        },
+        &["a", "b"],
+        0,
+    );
+
+    let tasks = db.open_tasks().unwrap();
+    assert_eq!(tasks.count(), 2);
+    let failed = tasks.get("a:1.0.0:test:1.0.0").unwrap().unwrap();
+    assert_eq!(failed.state, model::TaskState::AttemptsWithFailure);
+    assert_eq!(failed.failed_attempts(), 1);
+    let error = failed.attempts[0].error.as_ref().expect("failed attempt");
+    assert_eq!(error.kind, "Message");
+    assert_eq!(error.message, "failure");
+    assert_eq!(error.context, "processing failed");
+    assert_eq!(failed.attempts[0].worker, model::WorkerKind::CpuBound);
+    assert!(failed.attempts[0].finished_at >= failed.attempts[0].started_at);
+
+    let succeeded = tasks.get("b:1.0.0:test:1.0.0").unwrap().unwrap();
+    assert!(succeeded.state.is_complete());
+    assert_eq!(succeeded.attempts.len(), 1);
+    assert!(succeeded.attempts[0].error.is_none());
+
+    let context = db
+        .open_context()
+        .unwrap()
+        .most_recent()
+        .unwrap()
+        .expect("today's context")
+        .1;
+    assert_eq!(context.counts.task_failures.get("test"), Some(&1));
+
+    process(
+        &db,
+        Agent {
+            failures_left: 0,
+            timeout: false,
+        },
+        &["a"],
+        0,
+    );
+    let retried = tasks.get("a:1.0.0:test:1.0.0").unwrap().unwrap();
+    assert!(retried.state.is_complete());
+    assert_eq!(retried.attempts.len(), 2, "the history of attempts is kept");
+    assert!(retried.attempts[0].error.is_some());
+    assert!(retried.attempts[1].error.is_none());
+}
+
+#[test]
+fn task_failures_are_counted_once_per_run() {
+    let db = Db::in_memory();
+    let agent = |failures_left| Agent {
+        failures_left,
+        timeout: true,
+    };
+    process(&db, agent(3), &["a"], 10);
+    process(&db, agent(20), &["b"], 10);
+
+    let context = db
+        .open_context()
+        .unwrap()
+        .most_recent()
+        .unwrap()
+        .expect("today's context")
+        .1;
+    assert_eq!(
+        context.counts.task_failures.get("test"),
+        Some(&1),
+        "only the run giving up after all retries failed"
+    );
+}
+
+/// A selected entry of version 1.0.0 of 'krate' at `path`, as stored by the extraction task
+fn exploded_entry(path: &str, content: &[u8]) -> (model::TarHeader, Vec<u8>) {
+    (
+        model::TarHeader {
+            path: format!("krate-1.0.0/{}", path).into_bytes(),
+            size: content.len() as u64,
+            entry_type: b'0',
+            hash: None,
+        },
+        content.to_vec(),
+    )
+}
+
+/// Store the extraction result of version 1.0.0 of 'krate' with `selected_entries`, and return the result of running
+/// a task of `kind` on it.
+fn process_exploded(kind: &dyn TaskKind, selected_entries: Vec<(model::TarHeader, Vec<u8>)>) -> model::TaskResult {
+    let db = Db::in_memory();
+    let extraction_task = default_persisted_extraction_task();
+    let mut key = String::new();
+    extraction_task.fq_key("krate", "1.0.0", &mut key);
+    let root = prodash::tree::Root::new();
+    let mut progress = root.add_child(kind.name());
+    db.open_results()
+        .unwrap()
+        .insert(
+            &mut progress,
+            &key,
+            &model::TaskResult::ExplodedCrate {
+                entries_meta_data: selected_entries.iter().map(|(header, _)| header.clone()).collect(),
+                selected_entries,
+                findings: Vec::new(),
+            },
+        )
+        .unwrap();
+
+    let mut processor = kind.new_processor(&db, std::path::Path::new("")).unwrap();
+    processor
+        .set(
+            TaskRequest {
+                crate_name: "krate".into(),
+                crate_version: "1.0.0".into(),
+                dependencies: vec![extraction_task],
+            },
+            &mut progress,
+        )
+        .unwrap();
+    futures_lite::future::block_on(processor.process(&mut progress)).unwrap();
+    db.open_results()
+        .unwrap()
+        .get(format!("krate:1.0.0:{}:{}", kind.name(), kind.version()))
+        .unwrap()
+        .expect("a result")
+}
+
+mod registry;
+
+#[cfg(unix)]
+mod external;
+
+mod extraction;
+
+mod manifest;
+
+mod lockfile;
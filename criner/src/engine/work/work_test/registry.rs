@@ -0,0 +1,160 @@
+use crate::{
+    engine::work::{
+        registry::{BoxedProcessor, Registry, Senders, TaskKind},
+        schedule,
+    },
+    model,
+    persistence::{Db, TableAccess},
+    Result,
+};
+use std::{path::Path, sync::Arc, time::SystemTime};
+
+struct Kind {
+    name: &'static str,
+    dependencies: &'static [&'static str],
+}
+
+impl TaskKind for Kind {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn version(&self) -> &'static str {
+        "1.0.0"
+    }
+
+    fn dependencies(&self) -> &'static [&'static str] {
+        self.dependencies
+    }
+
+    fn worker_kind(&self) -> model::WorkerKind {
+        model::WorkerKind::CpuBound
+    }
+
+    fn new_processor(&self, _db: &Db, _assets_dir: &Path) -> Result<BoxedProcessor> {
+        unimplemented!("not needed for scheduling")
+    }
+}
+
+fn kind(name: &'static str, dependencies: &'static [&'static str]) -> Arc<dyn TaskKind> {
+    Arc::new(Kind { name, dependencies })
+}
+
+fn names(registry: &Registry) -> Vec<&'static str> {
+    registry.kinds().iter().map(|k| k.name()).collect()
+}
+
+#[test]
+fn kinds_are_sorted_dependencies_first() {
+    let registry = Registry::new(vec![
+        kind("report", &["extract", "metadata"]),
+        kind("metadata", &["extract"]),
+        kind("extract", &["download"]),
+        kind("download", &[]),
+    ])
+    .unwrap();
+    assert_eq!(names(&registry), vec!["download", "extract", "metadata", "report"]);
+    assert_eq!(
+        registry.dependents("extract").map(|k| k.name()).collect::<Vec<_>>(),
+        vec!["metadata", "report"]
+    );
+    assert_eq!(
+        names(&Registry::default()),
+        vec!["download", "extract_crate", "cargo_lock", "cargo_manifest"]
+    );
+}
+
+#[test]
+fn invalid_registrations_are_rejected() {
+    assert!(
+        Registry::new(vec![kind("a", &[]), kind("a", &[])]).is_err(),
+        "duplicate names"
+    );
+    assert!(Registry::new(vec![kind("a", &["b"])]).is_err(), "unknown dependency");
+    assert!(
+        Registry::new(vec![kind("a", &["c"]), kind("b", &["a"]), kind("c", &["b"])]).is_err(),
+        "cycle"
+    );
+}
+
+#[test]
+fn tasks_are_submitted_once_their_dependencies_are_complete() {
+    let db = Db::in_memory();
+    let tasks = db.open_tasks().unwrap();
+    let registry = Registry::new(vec![kind("first", &[]), kind("second", &["first"])]).unwrap();
+    let (tx_first, rx_first) = async_channel::unbounded();
+    let (tx_second, rx_second) = async_channel::unbounded();
+    let senders: Senders = vec![("first", tx_first), ("second", tx_second)].into_iter().collect();
+    let root = prodash::tree::Root::new();
+    let mut progress = root.add_child("schedule");
+
+    let schedule = |progress: &mut prodash::tree::Item, just_completed: Option<&model::Task>| {
+        futures_lite::future::block_on(schedule::tasks(
+            &tasks,
+            "krate",
+            "1.0.0",
+            progress,
+            schedule::Scheduling::AtLeastOne,
+            &registry,
+            &senders,
+            just_completed,
+            SystemTime::now(),
+        ))
+        .unwrap();
+    };
+
+    schedule(&mut progress, None);
+    let request = rx_first.try_recv().expect("first task submitted");
+    assert_eq!(request.crate_name, "krate");
+    assert!(request.dependencies.is_empty());
+    assert!(rx_second.try_recv().is_err(), "its dependency isn't complete");
+
+    let mut first = registry.get("first").unwrap().default_task();
+    first.state = model::TaskState::Complete;
+    schedule(&mut progress, Some(&first));
+    assert!(rx_first.try_recv().is_err(), "the completed task isn't submitted again");
+    let request = rx_second.try_recv().expect("second task submitted");
+    assert_eq!(request.dependencies.len(), 1);
+    assert_eq!(request.dependencies[0].process, "first");
+}
+
+#[test]
+fn tasks_with_many_retried_timeouts_in_one_run_are_rescheduled() {
+    let db = Db::in_memory();
+    super::process(
+        &db,
+        super::Agent {
+            failures_left: 20,
+            timeout: true,
+        },
+        &["krate"],
+        10,
+    );
+    let tasks = db.open_tasks().unwrap();
+    let task = tasks.get("krate:1.0.0:test:1.0.0").unwrap().unwrap();
+    assert_eq!(task.state, model::TaskState::AttemptsWithFailure);
+    assert_eq!(
+        task.failed_attempts(),
+        1,
+        "retried timeouts are part of a single attempt"
+    );
+    assert_eq!(task.attempts[0].retried_timeouts, 9);
+
+    let registry = Registry::new(vec![kind("test", &[])]).unwrap();
+    let (tx, rx) = async_channel::unbounded();
+    let senders: Senders = std::iter::once(("test", tx)).collect();
+    let root = prodash::tree::Root::new();
+    futures_lite::future::block_on(schedule::tasks(
+        &tasks,
+        "krate",
+        "1.0.0",
+        &mut root.add_child("schedule"),
+        schedule::Scheduling::AtLeastOne,
+        &registry,
+        &senders,
+        None,
+        SystemTime::now(),
+    ))
+    .unwrap();
+    assert!(rx.try_recv().is_ok(), "the task is retried");
+}
@@ -81,6 +81,10 @@ quick_error! {
             from()
             source(err)
         }
+        SerdeJson(err: serde_json::Error) {
+            from()
+            source(err)
+        }
         Horrorshow(err: horrorshow::Error) {
             from()
             source(err)
@@ -112,6 +116,38 @@ quick_error! {
 }
 
 impl Error {
+    /// The name of the variant, useful to group errors without looking at their messages.
+    pub fn kind(&self) -> &'static str {
+        use Error::*;
+        match self {
+            Bug(_) => "Bug",
+            Message(_) => "Message",
+            InvalidHeader(_) => "InvalidHeader",
+            HttpStatus(_) => "HttpStatus",
+            DeadlineExceeded(_) => "DeadlineExceeded",
+            Interrupted => "Interrupted",
+            Timeout(_, _) => "Timeout",
+            RmpSerdeEncode(_) => "RmpSerdeEncode",
+            Git2(_) => "Git2",
+            IndexDiffInit(_) => "IndexDiffInit",
+            IndexDiffChanges(_) => "IndexDiffChanges",
+            Io(_) => "Io",
+            FromUtf8(_) => "FromUtf8",
+            Reqwest(_) => "Reqwest",
+            ParseInt(_) => "ParseInt",
+            Rusqlite(_) => "Rusqlite",
+            GlobSet(_) => "GlobSet",
+            SerdeJson(_) => "SerdeJson",
+            Horrorshow(_) => "Horrorshow",
+            SystemTime(_) => "SystemTime",
+            StripPrefixError(_) => "StripPrefixError",
+            Csv(_) => "Csv",
+            GlobPattern(_) => "GlobPattern",
+            Glob(_) => "Glob",
+            ChannelSendMessage(_) => "ChannelSendMessage",
+        }
+    }
+
     pub fn send_msg<T>(msg: &'static str) -> impl FnOnce(async_channel::SendError<T>) -> Error {
         move |_err| Error::ChannelSendMessage(msg)
     }
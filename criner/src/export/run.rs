@@ -1,7 +1,7 @@
 use super::to_sql::SqlConvert;
-use crate::model;
+use crate::{model, persistence};
 use rusqlite::Connection;
-use std::path::Path;
+use std::{convert::TryFrom, path::Path};
 
 pub fn run_blocking(source_db: impl AsRef<Path>, destination_db: impl AsRef<Path>) -> crate::Result<()> {
     if destination_db.as_ref().is_file() {
@@ -27,6 +27,7 @@ pub fn run_blocking(source_db: impl AsRef<Path>, destination_db: impl AsRef<Path
     transfer::<model::Task>(&mut input, &mut output)?;
     transfer::<model::Context>(&mut input, &mut output)?;
     transfer::<model::CrateVersion>(&mut input, &mut output)?;
+    transfer::<model::ReverseDependencies>(&mut input, &mut output)?;
     transfer::<model::TaskResult>(&mut input, &mut output)?;
 
     Ok(())
@@ -34,10 +35,16 @@ pub fn run_blocking(source_db: impl AsRef<Path>, destination_db: impl AsRef<Path
 
 fn transfer<T>(input: &mut Connection, output: &mut Connection) -> crate::Result<()>
 where
-    for<'a> T: SqlConvert + From<&'a [u8]>,
+    for<'a> T: SqlConvert + TryFrom<&'a [u8], Error = crate::Error>,
 {
     output.execute_batch(T::init_table_statement())?;
-    let mut istm = input.prepare(&format!("SELECT key, data FROM '{}'", T::source_table_name()))?;
+    let mut istm = input.prepare(&format!(
+        "SELECT key, data FROM '{}' {}",
+        T::source_table_name(),
+        T::source_table_glob()
+            .map(|glob| format!("WHERE key GLOB '{}'", glob))
+            .unwrap_or_default()
+    ))?;
     let transaction = output.transaction()?;
     let mut count = 0;
     let start = std::time::SystemTime::now();
@@ -58,9 +65,12 @@ where
                 })?
                 .enumerate()
             {
-                count += 1;
                 let (key, value) = res?;
-                let value = T::from(value.as_slice());
+                let value = match persistence::decode_or_log::<T>(T::source_table_name(), Some(&key), &value) {
+                    Some(value) => value,
+                    None => continue,
+                };
+                count += 1;
                 value.insert(&key, uid as i32, &mut ostm, secondary_ostm.as_mut())?;
             }
         }
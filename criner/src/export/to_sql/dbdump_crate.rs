@@ -1,6 +1,6 @@
 use crate::{
     export::to_sql::{to_seconds_since_epoch, SqlConvert},
-    model,
+    model, persistence,
 };
 use rusqlite::{params, Statement};
 
@@ -111,7 +111,12 @@ fn do_it(input_statement: &mut rusqlite::Statement, transaction: &rusqlite::Tran
         let value: Vec<u8> = r.get(1)?;
         Ok((key, value))
     })? {
-        let (_crate_name, bytes) = res?;
+        let (crate_name, bytes) = res?;
+        let krate =
+            match persistence::decode_or_log::<model::db_dump::Crate>("crates.io-crate", Some(&crate_name), &bytes) {
+                Some(krate) => krate,
+                None => continue,
+            };
         let model::db_dump::Crate {
             name,
             stored_at,
@@ -128,7 +133,7 @@ fn do_it(input_statement: &mut rusqlite::Statement, transaction: &rusqlite::Tran
             categories,
             created_by,
             owners,
-        } = bytes.as_slice().into();
+        } = krate;
 
         if let Some(actor) = created_by.as_ref() {
             insert_actor_to_db(&mut insert_actor, actor)?;
@@ -5,23 +5,54 @@ use rusqlite::{params, Statement};
 impl SqlConvert for model::Context {
     fn replace_statement() -> &'static str {
         "INSERT INTO runtime_statistic
-                (sample_day, num_new_crate_versions, num_new_crates, dur_s_fetch_new_crate_versions)
-         VALUES (?1        , ?2                    , ?3            , ?4);
+                (sample_day, num_new_crate_versions, num_new_crates, dur_s_fetch_new_crate_versions,
+                 num_bytes_downloaded, num_crates_extracted, dur_s_download, dur_s_extraction,
+                 dur_s_report_generation, dur_s_db_dump_ingestion)
+         VALUES (?1        , ?2                    , ?3            , ?4                            ,
+                 ?5                  , ?6                  , ?7            , ?8              ,
+                 ?9                     , ?10);
         "
     }
 
+    fn secondary_replace_statement() -> Option<&'static str> {
+        Some(
+            "INSERT INTO runtime_statistic_task_failure
+                    (sample_day, process, num_failures)
+             VALUES (?1        , ?2     , ?3);",
+        )
+    }
+
     fn source_table_name() -> &'static str {
         "meta"
     }
 
+    fn source_table_glob() -> Option<&'static str> {
+        Some(crate::persistence::MetaTable::CONTEXT_GLOB)
+    }
+
     fn init_table_statement() -> &'static str {
-        "CREATE TABLE runtime_statistic (
+        "BEGIN;
+        CREATE TABLE runtime_statistic (
             sample_day                      TIMESTAMP NOT NULL,
             num_new_crate_versions          INTEGER NOT NULL,
             num_new_crates                  INTEGER NOT NULL,
             dur_s_fetch_new_crate_versions  INTEGER NOT NULL,
+            num_bytes_downloaded            INTEGER NOT NULL,
+            num_crates_extracted            INTEGER NOT NULL,
+            dur_s_download                  INTEGER NOT NULL,
+            dur_s_extraction                INTEGER NOT NULL,
+            dur_s_report_generation         INTEGER NOT NULL,
+            dur_s_db_dump_ingestion         INTEGER NOT NULL,
             PRIMARY KEY (sample_day)
         );
+        CREATE TABLE runtime_statistic_task_failure (
+            sample_day                      TIMESTAMP NOT NULL,
+            process                         TEXT NOT NULL,
+            num_failures                    INTEGER NOT NULL,
+            PRIMARY KEY (sample_day, process),
+            FOREIGN KEY (sample_day) REFERENCES runtime_statistic(sample_day)
+        );
+        COMMIT;
         "
     }
 
@@ -30,7 +61,7 @@ impl SqlConvert for model::Context {
         key: &str,
         _uid: i32,
         stm: &mut Statement<'_>,
-        _sstm: Option<&mut Statement<'_>>,
+        sstm: Option<&mut Statement<'_>>,
     ) -> crate::Result<usize> {
         let mut tokens = key.split('/').skip(1);
         let day_date = tokens.next().unwrap();
@@ -40,16 +71,42 @@ impl SqlConvert for model::Context {
         let date_stamp = day_date.duration_since(std::time::UNIX_EPOCH).unwrap();
 
         let model::Context {
-            counts: model::Counts { crate_versions, crates },
-            durations: model::Durations { fetch_crate_versions },
+            counts:
+                model::Counts {
+                    crate_versions,
+                    crates,
+                    bytes_downloaded,
+                    crates_extracted,
+                    task_failures,
+                },
+            durations:
+                model::Durations {
+                    fetch_crate_versions,
+                    download,
+                    extraction,
+                    report_generation,
+                    db_dump_ingestion,
+                },
         } = self;
 
         stm.execute(params![
             date_stamp.as_secs() as i64,
             *crate_versions as i64,
             *crates as i64,
-            fetch_crate_versions.as_secs() as i64
-        ])
-        .map_err(Into::into)
+            fetch_crate_versions.as_secs() as i64,
+            *bytes_downloaded as i64,
+            *crates_extracted as i64,
+            download.as_secs() as i64,
+            extraction.as_secs() as i64,
+            report_generation.as_secs() as i64,
+            db_dump_ingestion.as_secs() as i64,
+        ])?;
+        if !task_failures.is_empty() {
+            let sstm = sstm.ok_or(crate::Error::Bug("need secondary statement"))?;
+            for (process, failures) in task_failures.iter() {
+                sstm.execute(params![date_stamp.as_secs() as i64, process, *failures as i64])?;
+            }
+        }
+        Ok(1)
     }
 }
@@ -3,6 +3,7 @@ mod krate;
 mod krate_version;
 mod meta;
 mod result;
+mod reverse_dependency;
 mod task;
 
 pub fn to_seconds_since_epoch(time: std::time::SystemTime) -> i64 {
@@ -21,6 +22,10 @@ pub trait SqlConvert {
         None
     }
     fn source_table_name() -> &'static str;
+    /// A glob matching the keys of all rows to export, or None to export all of them.
+    fn source_table_glob() -> Option<&'static str> {
+        None
+    }
     fn init_table_statement() -> &'static str;
     fn insert(
         &self,
@@ -1,5 +1,6 @@
+use crate::engine::report::waste::path_bytes_to_str;
 use crate::export::to_sql::SqlConvert;
-use crate::model;
+use crate::{model, persistence};
 use rusqlite::{params, Statement};
 
 impl SqlConvert for model::TaskResult {
@@ -11,6 +12,9 @@ impl SqlConvert for model::TaskResult {
             let mut num_downloads = 0;
             let mut num_extract_crates = 0;
             let mut num_crate_entries = 0;
+            let mut num_external_commands = 0;
+            let mut num_cargo_manifests = 0;
+            let mut num_cargo_locks = 0;
             let mut insert_download = transaction
                 .prepare(
                     "
@@ -34,8 +38,88 @@ impl SqlConvert for model::TaskResult {
                 .prepare(
                     "
             REPLACE INTO crate_entry
-                     (parent_id, path, size, entry_type, data)
-              VALUES (?1        , ?2 , ?3  , ?4        , ?5);
+                     (parent_id, path, raw_path, size, entry_type, hash, data)
+              VALUES (?1        , ?2 , ?3      , ?4  , ?5        , ?6  , ?7);
+        ",
+                )
+                .unwrap();
+
+            let mut insert_crate_entry_finding = transaction
+                .prepare(
+                    "
+            INSERT INTO crate_entry_finding
+                     (parent_id, path, kind, link_target)
+              VALUES (?1       , ?2  , ?3  , ?4);
+        ",
+                )
+                .unwrap();
+
+            let mut insert_external_command = transaction
+                .prepare(
+                    "
+            REPLACE INTO result_external_command
+                     (crate_name, crate_version, process, version, command, output)
+              VALUES (?1        , ?2           , ?3     , ?4     , ?5     , ?6);
+        ",
+                )
+                .unwrap();
+
+            let mut insert_cargo_manifest = transaction
+                .prepare(
+                    "
+            REPLACE INTO result_cargo_manifest
+                     (id, crate_name, crate_version, version, edition, rust_version, links, is_workspace_root, error)
+              VALUES (?1, ?2        , ?3           , ?4     , ?5     , ?6          , ?7   , ?8               , ?9);
+        ",
+                )
+                .unwrap();
+
+            let mut insert_cargo_manifest_feature = transaction
+                .prepare(
+                    "
+            INSERT INTO cargo_manifest_feature
+                     (parent_id, name, enables)
+              VALUES (?1       , ?2  , ?3);
+        ",
+                )
+                .unwrap();
+
+            let mut insert_cargo_manifest_build_target = transaction
+                .prepare(
+                    "
+            INSERT INTO cargo_manifest_build_target
+                     (parent_id, kind, name, path)
+              VALUES (?1       , ?2  , ?3  , ?4);
+        ",
+                )
+                .unwrap();
+
+            let mut insert_cargo_manifest_item = transaction
+                .prepare(
+                    "
+            INSERT INTO cargo_manifest_item
+                     (parent_id, kind, name)
+              VALUES (?1       , ?2  , ?3);
+        ",
+                )
+                .unwrap();
+
+            let mut insert_cargo_lock = transaction
+                .prepare(
+                    "
+            REPLACE INTO result_cargo_lock
+                     (id, crate_name, crate_version, version, has_lockfile, lockfile_version, has_binary_target, error)
+              VALUES (?1, ?2        , ?3           , ?4     , ?5          , ?6              , ?7               , ?8);
+        ",
+                )
+                .unwrap();
+
+            let mut insert_cargo_lock_package = transaction
+                .prepare(
+                    "
+            INSERT INTO cargo_lock_package
+                     (parent_id, name, version, source, checksum)
+              VALUES (?1       , ?2  , ?3     , ?4    , ?5);
         ",
                 )
                 .unwrap();
@@ -54,7 +138,10 @@ impl SqlConvert for model::TaskResult {
                 let optional_last_key = tokens.next();
                 assert!(tokens.next().is_none());
 
-                let value = Self::from(value.as_slice());
+                let value = match persistence::decode_or_log(Self::source_table_name(), Some(&key), &value) {
+                    Some(value) => value,
+                    None => continue,
+                };
 
                 use model::TaskResult;
                 match value {
@@ -80,6 +167,7 @@ impl SqlConvert for model::TaskResult {
                     TaskResult::ExplodedCrate {
                         entries_meta_data,
                         selected_entries,
+                        findings,
                     } => {
                         assert_eq!(process, "extract_crate");
                         let id = num_extract_crates as i32;
@@ -91,33 +179,133 @@ impl SqlConvert for model::TaskResult {
                             entries_meta_data.len() as i64
                         ])?;
                         for entry in entries_meta_data.iter() {
-                            let model::TarHeader { path, size, entry_type } = entry;
+                            let model::TarHeader {
+                                path,
+                                size,
+                                entry_type,
+                                hash,
+                            } = entry;
                             insert_crate_entry.execute(params![
                                 id,
-                                std::str::from_utf8(path).expect("utf8 path in crate - lets see how long this is true"),
+                                path_bytes_to_str(path),
+                                path,
                                 *size as i64,
                                 entry_type,
+                                hash.map(hex::encode),
                                 rusqlite::types::Null
                             ])?;
                             num_crate_entries += 1;
                         }
                         for (entry, data) in selected_entries.iter() {
-                            let model::TarHeader { path, size, entry_type } = entry;
+                            let model::TarHeader {
+                                path,
+                                size,
+                                entry_type,
+                                hash,
+                            } = entry;
                             insert_crate_entry.execute(params![
                                 id,
-                                std::str::from_utf8(path).expect("utf8 path in crate - lets see how long this is true"),
+                                path_bytes_to_str(path),
+                                path,
                                 *size as i64,
                                 entry_type,
+                                hash.map(hex::encode),
                                 data
                             ])?;
                             num_crate_entries += 1;
                         }
+                        for model::EntryFinding { path, kind } in findings.iter() {
+                            insert_crate_entry_finding.execute(params![
+                                id,
+                                path_bytes_to_str(path),
+                                kind.as_str(),
+                                kind.link_target().map(path_bytes_to_str),
+                            ])?;
+                        }
                         num_extract_crates += 1;
                     }
+                    TaskResult::ExternalCommand { command, output } => {
+                        insert_external_command.execute(params![
+                            crate_name,
+                            crate_version,
+                            process,
+                            process_version,
+                            serde_json::to_string(&command).unwrap(),
+                            output
+                        ])?;
+                        num_external_commands += 1;
+                    }
+                    TaskResult::CargoManifest { manifest, error } => {
+                        let id = num_cargo_manifests as i32;
+                        let manifest = manifest.unwrap_or_default();
+                        insert_cargo_manifest.execute(params![
+                            id,
+                            crate_name,
+                            crate_version,
+                            process_version,
+                            manifest.edition,
+                            manifest.rust_version,
+                            manifest.links,
+                            manifest.is_workspace_root,
+                            error
+                        ])?;
+                        for (name, enables) in manifest.features.iter() {
+                            insert_cargo_manifest_feature.execute(params![
+                                id,
+                                name,
+                                serde_json::to_string(enables).unwrap()
+                            ])?;
+                        }
+                        for model::BuildTarget { kind, name, path } in manifest.build_targets.iter() {
+                            insert_cargo_manifest_build_target.execute(params![id, kind, name, path])?;
+                        }
+                        for (kind, names) in [
+                            ("platform-target", &manifest.platform_targets),
+                            ("profile", &manifest.profiles),
+                            ("patch", &manifest.patched_sources),
+                            ("replace", &manifest.replaced_packages),
+                            ("workspace-inherited", &manifest.workspace_inherited),
+                        ] {
+                            for name in names.iter() {
+                                insert_cargo_manifest_item.execute(params![id, kind, name])?;
+                            }
+                        }
+                        num_cargo_manifests += 1;
+                    }
+                    TaskResult::CargoLock { lockfile, error } => {
+                        let id = num_cargo_locks as i32;
+                        let has_lockfile = lockfile.is_some();
+                        let lockfile = lockfile.unwrap_or_default();
+                        insert_cargo_lock.execute(params![
+                            id,
+                            crate_name,
+                            crate_version,
+                            process_version,
+                            has_lockfile,
+                            lockfile.version,
+                            lockfile.has_binary_target,
+                            error
+                        ])?;
+                        for model::LockedPackage {
+                            name,
+                            version,
+                            source,
+                            checksum,
+                        } in lockfile.packages.iter()
+                        {
+                            insert_cargo_lock_package.execute(params![id, name, version, source, checksum])?;
+                        }
+                        num_cargo_locks += 1;
+                    }
                     TaskResult::None => {}
                 };
             }
-            Ok(num_downloads + num_extract_crates + num_crate_entries)
+            Ok(num_downloads
+                + num_extract_crates
+                + num_crate_entries
+                + num_external_commands
+                + num_cargo_manifests
+                + num_cargo_locks)
         })();
         Some(res)
     }
@@ -155,15 +343,129 @@ impl SqlConvert for model::TaskResult {
         );
         CREATE TABLE crate_entry (
             parent_id                       INTEGER NOT NULL,
-            path                            TEXT NOT NULL,
+            path                            TEXT NOT NULL, -- bytes which are not UTF-8 are escaped as \\xNN
+            raw_path                        BLOB NOT NULL, -- the path as stored in the archive, which identifies the entry
 
             size                            INTEGER NOT NULL, -- size in bytes
             entry_type                      INTEGER NOT NULL, -- tar::EntryType
+            hash                            TEXT, -- hex encoded blake3 hash of the entire content of files
             data                            BLOB, -- optionally with entire content
 
-            PRIMARY KEY (parent_id, path),
+            PRIMARY KEY (parent_id, raw_path),
+            FOREIGN KEY (parent_id) REFERENCES result_extract_crate(id)
+        );
+        CREATE INDEX crate_entry_hash ON crate_entry(hash);
+        CREATE TABLE crate_entry_finding (
+            parent_id                       INTEGER NOT NULL,
+            path                            TEXT NOT NULL, -- bytes which are not UTF-8 are escaped as \\xNN
+            kind                            TEXT NOT NULL, -- like 'symlink' or 'absolute-path'
+            link_target                     TEXT, -- the target of symlinks and hardlinks
             FOREIGN KEY (parent_id) REFERENCES result_extract_crate(id)
         );
+        -- Files with the same content, the ones wasting the most bytes on crates.io first
+        CREATE VIEW duplicated_crate_entry AS
+            SELECT e.hash,
+                   e.size,
+                   COUNT(*) AS copies,
+                   COUNT(DISTINCT c.crate_name) AS crates,
+                   e.size * (COUNT(*) - 1) AS duplicated_bytes,
+                   MIN(c.crate_name || '@' || c.crate_version || ':' || e.path) AS example
+              FROM crate_entry AS e
+              JOIN result_extract_crate AS c ON c.id = e.parent_id
+             WHERE e.hash IS NOT NULL AND e.size > 0
+             GROUP BY e.hash
+            HAVING copies > 1
+             ORDER BY duplicated_bytes DESC;
+        CREATE TABLE result_external_command (
+            crate_name                      TEXT NOT NULL,
+            crate_version                   TEXT NOT NULL,
+            process                         TEXT NOT NULL, -- the name of the configured command
+            version                         TEXT NOT NULL, -- version of the process that created the result
+
+            command                         TEXT NOT NULL, -- the program and its arguments as JSON array
+            output                          TEXT NOT NULL, -- JSON as written to stdout by the program
+            PRIMARY KEY (crate_name, crate_version, process, version)
+        );
+        CREATE TABLE result_cargo_manifest (
+            id                              INTEGER UNIQUE NOT NULL,
+            crate_name                      TEXT NOT NULL,
+            crate_version                   TEXT NOT NULL,
+            version                         TEXT NOT NULL, -- version of the process that created the result
+
+            edition                         TEXT, -- NULL if unset, which means 2015
+            rust_version                    TEXT, -- the minimal supported rust version, if set
+            links                           TEXT,
+            is_workspace_root               INTEGER NOT NULL,
+            error                           TEXT, -- set if Cargo.toml was missing or could not be parsed
+            PRIMARY KEY (crate_name, crate_version, version)
+        );
+        CREATE TABLE cargo_manifest_feature (
+            parent_id                       INTEGER NOT NULL,
+            name                            TEXT NOT NULL,
+            enables                         TEXT NOT NULL, -- the features and dependencies it enables as JSON array
+            FOREIGN KEY (parent_id) REFERENCES result_cargo_manifest(id)
+        );
+        CREATE TABLE cargo_manifest_build_target (
+            parent_id                       INTEGER NOT NULL,
+            kind                            TEXT NOT NULL, -- one of 'lib', 'bin', 'example', 'test' or 'bench'
+            name                            TEXT,
+            path                            TEXT,
+            FOREIGN KEY (parent_id) REFERENCES result_cargo_manifest(id)
+        );
+        CREATE TABLE cargo_manifest_item (
+            parent_id                       INTEGER NOT NULL,
+            kind                            TEXT NOT NULL, -- one of 'platform-target', 'profile', 'patch', 'replace' or 'workspace-inherited'
+            name                            TEXT NOT NULL, -- like 'cfg(unix)', 'release', 'crates-io', a package id or 'package.version'
+            FOREIGN KEY (parent_id) REFERENCES result_cargo_manifest(id)
+        );
+        -- The amount of crate versions using each edition
+        CREATE VIEW edition_adoption AS
+            SELECT COALESCE(edition, '2015') AS edition, COUNT(*) AS crate_versions
+              FROM result_cargo_manifest
+             WHERE error IS NULL
+             GROUP BY 1
+             ORDER BY 1;
+        -- The amount of crate versions declaring each minimal supported rust version
+        CREATE VIEW rust_version_adoption AS
+            SELECT rust_version, COUNT(*) AS crate_versions
+              FROM result_cargo_manifest
+             WHERE error IS NULL AND rust_version IS NOT NULL
+             GROUP BY 1
+             ORDER BY 2 DESC;
+        CREATE TABLE result_cargo_lock (
+            id                              INTEGER UNIQUE NOT NULL,
+            crate_name                      TEXT NOT NULL,
+            crate_version                   TEXT NOT NULL,
+            version                         TEXT NOT NULL, -- version of the process that created the result
+
+            has_lockfile                    INTEGER NOT NULL, -- 1 if the crate ships a Cargo.lock file
+            lockfile_version                INTEGER, -- the format version of the lockfile, NULL for the oldest format
+            has_binary_target               INTEGER NOT NULL,
+            error                           TEXT, -- set if Cargo.lock could not be parsed
+            PRIMARY KEY (crate_name, crate_version, version)
+        );
+        CREATE TABLE cargo_lock_package (
+            parent_id                       INTEGER NOT NULL,
+            name                            TEXT NOT NULL,
+            version                         TEXT NOT NULL,
+            source                          TEXT, -- like 'registry+https://github.com/rust-lang/crates.io-index', NULL for workspace members
+            checksum                        TEXT, -- hex encoded sha256 of the package archive, if known
+            FOREIGN KEY (parent_id) REFERENCES result_cargo_lock(id)
+        );
+        CREATE INDEX cargo_lock_package_name_version ON cargo_lock_package(name, version);
+        -- Crate versions shipping a lockfile which pins yanked crate versions or has checksums not matching the ones in the index
+        CREATE VIEW cargo_lock_issue AS
+            SELECT l.crate_name,
+                   l.crate_version,
+                   l.has_binary_target,
+                   p.name AS pinned_name,
+                   p.version AS pinned_version,
+                   CASE WHEN v.kind = 'yanked' THEN 'yanked' ELSE 'checksum-mismatch' END AS issue
+              FROM cargo_lock_package AS p
+              JOIN result_cargo_lock AS l ON l.id = p.parent_id
+              JOIN crate_version AS v ON v.name = p.name AND v.version = p.version
+             WHERE p.source IN ('registry+https://github.com/rust-lang/crates.io-index', 'sparse+https://index.crates.io/')
+               AND (v.kind = 'yanked' OR (p.checksum IS NOT NULL AND p.checksum != v.checksum));
         COMMIT;
         "
     }
@@ -0,0 +1,114 @@
+use crate::{export::to_sql::SqlConvert, model, persistence};
+use rusqlite::{params, Statement};
+
+impl SqlConvert for model::ReverseDependencies {
+    fn convert_to_sql(
+        istm: &mut rusqlite::Statement,
+        transaction: &rusqlite::Transaction,
+    ) -> Option<crate::Result<usize>> {
+        let res = (|| {
+            let mut count = 0;
+            // Rows of the same depended-on crate are mostly adjacent, so remember the versions of the last one
+            let mut known_versions = (String::new(), Vec::<String>::new());
+            // Requirements are resolved against the versions exported previously
+            let mut select_versions = transaction.prepare("SELECT version FROM crate_version WHERE name = ?1;")?;
+            let mut insert_edge = transaction.prepare(
+                "
+            INSERT INTO reverse_dependency
+                     (crate_name, dependent_name, dependent_version, required_version, kind, optional, resolved_version)
+              VALUES (?1        , ?2            , ?3               , ?4              , ?5  , ?6      , ?7);
+        ",
+            )?;
+            for res in istm.query_map([], |r| {
+                let key: String = r.get(0)?;
+                let value: Vec<u8> = r.get(1)?;
+                Ok((key, value))
+            })? {
+                let (key, value) = res?;
+                let crate_name = key
+                    .split(crate::persistence::KEY_SEP_CHAR)
+                    .next()
+                    .expect("split to always yield one item");
+                if known_versions.0 != crate_name {
+                    known_versions = (
+                        crate_name.to_owned(),
+                        select_versions
+                            .query_map(params![crate_name], |r| r.get::<_, String>(0))?
+                            .collect::<rusqlite::Result<Vec<_>>>()?,
+                    );
+                }
+                let dependents: Self = match persistence::decode_or_log(Self::source_table_name(), Some(&key), &value) {
+                    Some(dependents) => dependents,
+                    None => continue,
+                };
+                for dependent in dependents.dependents {
+                    let resolved_version = dependent.resolve(&known_versions.1);
+                    let model::ReverseDependency {
+                        crate_name: dependent_name,
+                        crate_version: dependent_version,
+                        required_version,
+                        kind,
+                        optional,
+                    } = dependent;
+                    insert_edge.execute(params![
+                        crate_name,
+                        dependent_name,
+                        dependent_version,
+                        required_version,
+                        kind,
+                        optional,
+                        resolved_version
+                    ])?;
+                    count += 1;
+                }
+            }
+            Ok(count)
+        })();
+        Some(res)
+    }
+
+    fn replace_statement() -> &'static str {
+        "will not be called"
+    }
+
+    fn source_table_name() -> &'static str {
+        "reverse_dependency"
+    }
+
+    fn init_table_statement() -> &'static str {
+        "
+        BEGIN;
+        CREATE TABLE reverse_dependency (
+            crate_name                      TEXT NOT NULL, -- the crate depended on
+            dependent_name                  TEXT NOT NULL,
+            dependent_version               TEXT NOT NULL,
+            required_version                TEXT NOT NULL, -- the version requirement of the dependent, like '^1.0'
+            kind                            TEXT NOT NULL, -- one of 'normal', 'dev' or 'build'
+            optional                        INTEGER NOT NULL, -- BOOL
+            resolved_version                TEXT -- the most recent version of the crate matching the requirement, if any
+        );
+        CREATE INDEX reverse_dependency_crate_name ON reverse_dependency(crate_name);
+        CREATE INDEX reverse_dependency_dependent ON reverse_dependency(dependent_name, dependent_version);
+        -- The amount of crates depending on each crate with any of their versions, the most depended-on first
+        CREATE VIEW dependent_crate_count AS
+            SELECT crate_name,
+                   kind,
+                   optional,
+                   COUNT(DISTINCT dependent_name) AS dependent_crates
+              FROM reverse_dependency
+             GROUP BY crate_name, kind, optional
+             ORDER BY dependent_crates DESC;
+        COMMIT;
+        "
+    }
+
+    fn insert(
+        &self,
+        _key: &str,
+        _uid: i32,
+        _stm: &mut Statement<'_>,
+        _sstm: Option<&mut Statement<'_>>,
+    ) -> crate::Result<usize> {
+        unimplemented!("we implement convert_to_sql instead")
+    }
+}
@@ -12,9 +12,9 @@ impl SqlConvert for model::Task {
     }
     fn secondary_replace_statement() -> Option<&'static str> {
         Some(
-            "REPLACE INTO task_error
-                        (parent_id, error)
-                VALUES  (?1       , ?2);",
+            "REPLACE INTO task_attempt
+                        (parent_id, started_at, finished_at, duration_ms, worker, bytes_transferred, error_kind, error, error_context, retried_timeouts)
+                VALUES  (?1       , ?2        , ?3         , ?4         , ?5    , ?6               , ?7        , ?8   , ?9           , ?10);",
         )
     }
     fn source_table_name() -> &'static str {
@@ -31,9 +31,17 @@ impl SqlConvert for model::Task {
                  state            TEXT NOT NULL,
                  PRIMARY KEY      (key)
             );
-            CREATE TABLE task_error (
+            CREATE TABLE task_attempt (
                  parent_id        INTEGER NOT NULL,
-                 error            TEXT NOT NULL,
+                 started_at       TIMESTAMP NOT NULL,
+                 finished_at      TIMESTAMP NOT NULL,
+                 duration_ms      INTEGER NOT NULL,
+                 worker           TEXT NOT NULL,
+                 bytes_transferred INTEGER,
+                 error_kind       TEXT,
+                 error            TEXT,
+                 error_context    TEXT,
+                 retried_timeouts INTEGER NOT NULL,
                  FOREIGN KEY (parent_id) REFERENCES task(id)
             );
          COMMIT;"
@@ -53,6 +61,7 @@ impl SqlConvert for model::Task {
             process,
             version,
             state,
+            attempts,
         } = self;
         stm.execute(params![
             uid,
@@ -63,18 +72,34 @@ impl SqlConvert for model::Task {
             match state {
                 NotStarted => "NotStarted",
                 Complete => "Complete",
-                InProgress(_) => "InProgress",
-                AttemptsWithFailure(_) => "AttemptsWithFailure",
+                InProgress => "InProgress",
+                AttemptsWithFailure => "AttemptsWithFailure",
             },
         ])?;
-        match state {
-            InProgress(Some(errors)) | AttemptsWithFailure(errors) => {
-                let sstm = sstm.ok_or(crate::Error::Bug("need secondary statement"))?;
-                for error in errors.iter() {
-                    sstm.execute(params![uid, error])?;
-                }
+        if !attempts.is_empty() {
+            let sstm = sstm.ok_or(crate::Error::Bug("need secondary statement"))?;
+            for attempt in attempts.iter() {
+                let model::TaskAttempt {
+                    started_at,
+                    finished_at,
+                    worker,
+                    bytes_transferred,
+                    error,
+                    retried_timeouts,
+                } = attempt;
+                sstm.execute(params![
+                    uid,
+                    to_seconds_since_epoch(*started_at),
+                    to_seconds_since_epoch(*finished_at),
+                    attempt.duration().as_millis() as i64,
+                    worker.as_str(),
+                    bytes_transferred.map(|b| b as i64),
+                    error.as_ref().map(|e| e.kind.as_str()),
+                    error.as_ref().map(|e| e.message.as_str()),
+                    error.as_ref().map(|e| e.context.as_str()),
+                    *retried_timeouts as i64,
+                ])?;
             }
-            _ => {}
         }
         Ok(1)
     }
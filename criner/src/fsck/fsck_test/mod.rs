@@ -0,0 +1,152 @@
+use crate::{
+    engine::{report::generic::Generator, report::lockfile, report::security, report::waste, work},
+    fsck::{check, quarantine_rows, Problem, ProblemKind, QUARANTINE_TABLE},
+    model,
+    persistence::{Db, TableAccess},
+};
+
+fn insert_raw(db: &Db, table: &str, key: &str, data: &[u8]) {
+    db.open_connection().unwrap().insert(None, table, key, data).unwrap();
+}
+
+fn insert<T: serde::Serialize>(db: &Db, table: &str, key: &str, item: &T) {
+    insert_raw(db, table, key, &rmp_serde::to_vec(item).unwrap());
+}
+
+fn complete_download_task() -> model::Task {
+    model::Task {
+        state: model::TaskState::Complete,
+        ..work::iobound::default_persisted_download_task()
+    }
+}
+
+#[test]
+fn consistent_databases_have_no_problems() {
+    let db = Db::in_memory();
+    let assets_dir = std::env::temp_dir().join(format!("criner-fsck-test-{}", std::process::id()));
+    std::fs::remove_dir_all(&assets_dir).ok();
+
+    let task = complete_download_task();
+    let download_path =
+        work::schedule::download_file_path(&assets_dir, "a", "1.0.0", &task.process, &task.version, "crate");
+    std::fs::create_dir_all(download_path.parent().unwrap()).unwrap();
+    std::fs::write(&download_path, b"crate").unwrap();
+
+    insert(
+        &db,
+        "crate",
+        "a",
+        &model::Crate {
+            versions: vec!["1.0.0".into()],
+        },
+    );
+    insert(&db, "crate_version", "a:1.0.0", &model::CrateVersion::default());
+    insert(&db, "task", "a:1.0.0:download:1.0.0", &task);
+    insert(&db, "task", "crates-io-db-dump:2020-01-01", &task);
+    insert(&db, "result", "a:1.0.0:download:1.0.0:crate", &model::TaskResult::None);
+    insert(&db, "result", "a:1.0.0:extract_crate:1.0.0", &model::TaskResult::None);
+    let mut report_key = String::new();
+    waste::Generator::fq_report_key("a", "1.0.0", &mut report_key);
+    let mut security_report_key = String::new();
+    security::Generator::fq_report_key("a", "1.0.0", &mut security_report_key);
+    insert(&db, "result", "a:1.0.0:cargo_lock:1.0.0", &model::TaskResult::None);
+    let mut lockfile_report_key = String::new();
+    lockfile::Generator::fq_report_key("a", "1.0.0", &mut lockfile_report_key);
+    db.open_reports()
+        .unwrap()
+        .mark_done(vec![
+            report_key.as_str(),
+            security_report_key.as_str(),
+            lockfile_report_key.as_str(),
+        ])
+        .unwrap();
+
+    assert_eq!(check(&db, &assets_dir).unwrap(), Vec::new());
+    std::fs::remove_dir_all(&assets_dir).ok();
+}
+
+#[test]
+fn problems_are_found_and_can_be_quarantined() {
+    let db = Db::in_memory();
+    let assets_dir = std::env::temp_dir().join("criner-fsck-test-does-not-exist");
+
+    insert_raw(&db, "crate_version", "broken:1.0.0", b"not msgpack");
+    insert(
+        &db,
+        "crate",
+        "a",
+        &model::Crate {
+            versions: vec!["1.0.0".into()],
+        },
+    );
+    insert(&db, "task", "a:1.0.0:download:1.0.0", &complete_download_task());
+    db.open_reports()
+        .unwrap()
+        .mark_done(vec!["a:1.0.0:waste:1.0.0"])
+        .unwrap();
+
+    let problems = check(&db, &assets_dir).unwrap();
+    let kinds: Vec<_> = problems
+        .iter()
+        .map(|p| (p.table, p.key.as_str(), std::mem::discriminant(&p.kind)))
+        .collect();
+    let discriminant = std::mem::discriminant;
+    assert_eq!(
+        kinds,
+        vec![
+            (
+                "crate_version",
+                "broken:1.0.0",
+                discriminant(&ProblemKind::Undecodable(String::new()))
+            ),
+            (
+                "crate",
+                "a",
+                discriminant(&ProblemKind::MissingCrateVersion(String::new()))
+            ),
+            (
+                "task",
+                "a:1.0.0:download:1.0.0",
+                discriminant(&ProblemKind::MissingDownloadFile(Default::default()))
+            ),
+            (
+                "task",
+                "a:1.0.0:download:1.0.0",
+                discriminant(&ProblemKind::MissingDownloadResult)
+            ),
+            (
+                "report_done",
+                "a:1.0.0:waste:1.0.0",
+                discriminant(&ProblemKind::MissingReportResult)
+            ),
+        ]
+    );
+
+    let fixable: Vec<&Problem> = problems.iter().filter(|p| p.can_be_quarantined()).collect();
+    assert_eq!(quarantine_rows(&db, fixable.iter().copied()).unwrap(), 4);
+
+    let connection = db.open_connection().unwrap();
+    assert!(connection
+        .get(QUARANTINE_TABLE, "crate_version:broken:1.0.0")
+        .unwrap()
+        .is_some());
+    assert!(connection
+        .get(QUARANTINE_TABLE, "task:a:1.0.0:download:1.0.0")
+        .unwrap()
+        .is_some());
+    assert!(connection
+        .get(QUARANTINE_TABLE, "report_done:a:1.0.0:waste:1.0.0")
+        .unwrap()
+        .is_some());
+    assert!(db
+        .open_tasks()
+        .unwrap()
+        .get("a:1.0.0:download:1.0.0")
+        .unwrap()
+        .is_none());
+    assert!(!db.open_reports().unwrap().is_done("a:1.0.0:waste:1.0.0"));
+
+    let remaining = check(&db, &assets_dir).unwrap();
+    assert_eq!(remaining.len(), 1, "only the crate remains as it can't be recreated");
+    assert!(!remaining[0].can_be_quarantined());
+}
@@ -0,0 +1,304 @@
+use crate::{
+    engine::{stage, work},
+    model,
+    persistence::{self, Db, TableAccess, KEY_SEP_CHAR},
+    Error, Result,
+};
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+};
+
+/// The amount of rows to load at once
+const CHUNK_SIZE: usize = 10_000;
+/// The table receiving all quarantined rows, keyed by `<table>:<key>`
+pub const QUARANTINE_TABLE: &str = "quarantine";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProblemKind {
+    /// The row could not be deserialized
+    Undecodable(String),
+    /// The crate lists a version for which there is no `crate_version` row
+    MissingCrateVersion(String),
+    /// A complete download task whose file is not present in the assets directory
+    MissingDownloadFile(PathBuf),
+    /// A complete download task without a download result
+    MissingDownloadResult,
+    /// A report marked done without the result it is computed from, or for an unknown report
+    MissingReportResult,
+}
+
+/// An issue with a single row of a table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Problem {
+    pub table: &'static str,
+    pub key: String,
+    pub kind: ProblemKind,
+}
+
+impl Problem {
+    /// Return true if the row should be quarantined to fix the problem.
+    ///
+    /// Tasks and markers without the results they promise are moved aside so they will be redone, but crates
+    /// are kept as they can't be recreated.
+    pub fn can_be_quarantined(&self) -> bool {
+        !matches!(self.kind, ProblemKind::MissingCrateVersion(_))
+    }
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: ", self.table, self.key)?;
+        match &self.kind {
+            ProblemKind::Undecodable(err) => write!(f, "could not be deserialized: {}", err),
+            ProblemKind::MissingCrateVersion(version) => write!(f, "crate version '{}' does not exist", version),
+            ProblemKind::MissingDownloadFile(path) => write!(f, "downloaded file at '{}' is missing", path.display()),
+            ProblemKind::MissingDownloadResult => write!(f, "download is complete, but its result is missing"),
+            ProblemKind::MissingReportResult => write!(f, "report is done, but the result it's based on is missing"),
+        }
+    }
+}
+
+/// Check the integrity of the database at `db_path`, logging all problems, and moving rows with problems into the
+/// `quarantine` table if `quarantine` is set.
+///
+/// Returns an error if problems remain.
+pub fn run_blocking(db_path: impl AsRef<Path>, quarantine: bool) -> Result<()> {
+    let db_path = db_path.as_ref();
+    if !Db::sqlite_path(db_path).is_file() {
+        return Err(Error::Message(format!(
+            "There is no criner database at '{}'",
+            db_path.display()
+        )));
+    }
+    let db = Db::open(db_path)?;
+    let problems = check(&db, &db_path.join("assets"))?;
+    for problem in problems.iter() {
+        log::warn!("{}", problem);
+    }
+
+    let mut num_remaining = problems.len();
+    if quarantine {
+        let num_quarantined = quarantine_rows(&db, problems.iter().filter(|p| p.can_be_quarantined()))?;
+        log::info!(
+            "Fixed {} problems by moving rows into the '{}' table",
+            num_quarantined,
+            QUARANTINE_TABLE
+        );
+        num_remaining -= num_quarantined;
+    }
+    match num_remaining {
+        0 => Ok(()),
+        n => Err(Error::Message(format!("Found {} problems in the database", n))),
+    }
+}
+
+/// Return all problems found in `db`, with `assets_dir` being the place where downloads are stored.
+pub fn check(db: &Db, assets_dir: &Path) -> Result<Vec<Problem>> {
+    let connection = db.open_connection_with_busy_wait()?;
+    let mut problems = Vec::new();
+
+    check_table::<model::CrateVersion>(
+        &connection,
+        persistence::CrateVersionTable::table_name(),
+        None,
+        &mut problems,
+        |_, _, _| Ok(()),
+    )?;
+    check_table::<model::TaskResult>(
+        &connection,
+        persistence::TaskResultTable::table_name(),
+        None,
+        &mut problems,
+        |_, _, _| Ok(()),
+    )?;
+    check_table::<model::Context>(
+        &connection,
+        persistence::MetaTable::table_name(),
+        Some(persistence::MetaTable::CONTEXT_GLOB),
+        &mut problems,
+        |_, _, _| Ok(()),
+    )?;
+    check_table::<model::db_dump::Crate>(&connection, "crates.io-crate", None, &mut problems, |_, _, _| Ok(()))?;
+    check_table::<model::ReverseDependencies>(
+        &connection,
+        persistence::ReverseDependencyTable::table_name(),
+        None,
+        &mut problems,
+        |_, _, _| Ok(()),
+    )?;
+
+    let mut key_buf = String::new();
+    check_table::<model::Crate>(
+        &connection,
+        persistence::CrateTable::table_name(),
+        None,
+        &mut problems,
+        |name, krate, problems| {
+            for version in krate.versions.iter() {
+                key_buf.clear();
+                model::CrateVersion::key_from(name, version, &mut key_buf);
+                if !connection.contains_key(persistence::CrateVersionTable::table_name(), &key_buf)? {
+                    problems.push(Problem {
+                        table: persistence::CrateTable::table_name(),
+                        key: name.to_owned(),
+                        kind: ProblemKind::MissingCrateVersion(version.to_owned()),
+                    });
+                }
+            }
+            Ok(())
+        },
+    )?;
+
+    let download_task = work::iobound::default_persisted_download_task();
+    let crate_download_kind = "crate";
+    check_table::<model::Task>(
+        &connection,
+        persistence::TaskTable::table_name(),
+        None,
+        &mut problems,
+        |key, task, problems| {
+            if task.process != download_task.process || !task.state.is_complete() {
+                return Ok(());
+            }
+            let (crate_name, crate_version) = match split_crate_version(key, 4) {
+                Some(v) => v,
+                // not a crate download, like the crates.io database dump
+                None => return Ok(()),
+            };
+            let problem = |kind| Problem {
+                table: persistence::TaskTable::table_name(),
+                key: key.to_owned(),
+                kind,
+            };
+            let download_path = work::schedule::download_file_path(
+                assets_dir,
+                crate_name,
+                crate_version,
+                &task.process,
+                &task.version,
+                crate_download_kind,
+            );
+            if !download_path.is_file() {
+                problems.push(problem(ProblemKind::MissingDownloadFile(download_path)));
+            }
+            key_buf.clear();
+            model::TaskResult::Download {
+                kind: crate_download_kind.into(),
+                url: String::new(),
+                content_length: 0,
+                content_type: None,
+            }
+            .fq_key(crate_name, crate_version, task, &mut key_buf);
+            if !connection.contains_key(persistence::TaskResultTable::table_name(), &key_buf)? {
+                problems.push(problem(ProblemKind::MissingDownloadResult));
+            }
+            Ok(())
+        },
+    )?;
+
+    let reports_table = persistence::ReportsTree::table_name();
+    let report_kinds = stage::report::default_kinds();
+    let mut offset = 0;
+    loop {
+        let keys = connection.keys_old_to_new(reports_table, Some((offset, CHUNK_SIZE)))?;
+        offset += keys.len();
+        for key in keys.iter() {
+            let has_result = match (split_crate_version(key, 4), key.split(KEY_SEP_CHAR).nth(2)) {
+                (Some((crate_name, crate_version)), Some(report_name)) => {
+                    key_buf.clear();
+                    if let Some(kind) = report_kinds.iter().find(|kind| kind.name() == report_name) {
+                        kind.fq_result_key(crate_name, crate_version, &mut key_buf);
+                    }
+                    // The report may have been generated from the result of any version of the task
+                    match key_buf.rfind(KEY_SEP_CHAR) {
+                        Some(pos) => {
+                            key_buf.truncate(pos + 1);
+                            key_buf.push('*');
+                            connection.count(persistence::TaskResultTable::table_name(), Some(&key_buf))? > 0
+                        }
+                        None => false,
+                    }
+                }
+                _ => false,
+            };
+            if !has_result {
+                problems.push(Problem {
+                    table: reports_table,
+                    key: key.to_owned(),
+                    kind: ProblemKind::MissingReportResult,
+                });
+            }
+        }
+        if keys.len() != CHUNK_SIZE {
+            break;
+        }
+    }
+    Ok(problems)
+}
+
+/// Move the rows of all `problems` into the quarantine table, returning the amount of problems fixed that way.
+pub fn quarantine_rows<'a>(db: &Db, problems: impl IntoIterator<Item = &'a Problem>) -> Result<usize> {
+    let connection = db.open_connection_with_busy_wait()?;
+    let mut num_quarantined = 0;
+    let mut key_buf = String::new();
+    for Problem { table, key, .. } in problems {
+        let data = if *table == persistence::ReportsTree::table_name() {
+            Some(Vec::new())
+        } else {
+            connection.get(table, key)?
+        };
+        if let Some(data) = data {
+            key_buf.clear();
+            key_buf.push_str(table);
+            key_buf.push(KEY_SEP_CHAR);
+            key_buf.push_str(key);
+            connection.insert(None, QUARANTINE_TABLE, &key_buf, &data)?;
+            connection.remove(table, key)?;
+        }
+        num_quarantined += 1;
+    }
+    Ok(num_quarantined)
+}
+
+/// Deserialize all rows of `table` whose keys match `glob`, if set, calling `f(key, item, problems)` for each
+/// decodable one.
+fn check_table<T: serde::de::DeserializeOwned>(
+    connection: &persistence::ThreadSafeConnection,
+    table: &'static str,
+    glob: Option<&str>,
+    problems: &mut Vec<Problem>,
+    mut f: impl FnMut(&str, &T, &mut Vec<Problem>) -> Result<()>,
+) -> Result<()> {
+    let mut offset = 0;
+    loop {
+        let items = connection.key_values_old_to_new(table, glob, Some((offset, CHUNK_SIZE)))?;
+        offset += items.len();
+        for (key, data) in items.iter() {
+            match rmp_serde::from_slice::<T>(data) {
+                Ok(item) => f(key, &item, problems)?,
+                Err(err) => problems.push(Problem {
+                    table,
+                    key: key.to_owned(),
+                    kind: ProblemKind::Undecodable(err.to_string()),
+                }),
+            }
+        }
+        if items.len() != CHUNK_SIZE {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// Return crate name and version from a key like `name:version:…` with exactly `num_parts`.
+fn split_crate_version(key: &str, num_parts: usize) -> Option<(&str, &str)> {
+    if key.split(KEY_SEP_CHAR).count() != num_parts {
+        return None;
+    }
+    let mut tokens = key.split(KEY_SEP_CHAR);
+    Some((tokens.next()?, tokens.next()?))
+}
+
+#[cfg(test)]
+mod fsck_test;
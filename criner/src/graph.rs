@@ -0,0 +1,22 @@
+use crate::{error::Error, model::ReverseDependency, persistence::Db, Result};
+use std::path::Path;
+
+/// Return all crate versions depending on `crate_name` according to the reverse dependency graph of the criner
+/// database at `db_path`, along with the most recent known version of `crate_name` matching their requirement.
+///
+/// The graph is maintained while fetching changes from the crates.io index, see `run::blocking()`.
+pub fn reverse_dependencies(
+    db_path: impl AsRef<Path>,
+    crate_name: &str,
+) -> Result<Vec<(ReverseDependency, Option<String>)>> {
+    let db_path = db_path.as_ref();
+    if !Db::sqlite_path(db_path).is_file() {
+        return Err(Error::Message(format!(
+            "There is no criner database at '{}'",
+            db_path.display()
+        )));
+    }
+    let db = Db::open(db_path)?;
+    db.open_reverse_dependencies()?
+        .dependents_of(crate_name, &db.open_crates()?)
+}
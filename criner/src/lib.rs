@@ -1,4 +1,4 @@
-#![allow(clippy::unneeded_field_pattern)]
+#![allow(clippy::unneeded_field_pattern, clippy::result_large_err)]
 #![deny(unsafe_code)]
 
 #[macro_use]
@@ -13,7 +13,9 @@ pub mod migration;
 pub mod error;
 pub use error::{Error, Result};
 
+pub mod backup;
 pub mod export;
+pub mod fsck;
 pub(crate) mod model;
 pub(crate) mod persistence;
 pub(crate) mod utils;
@@ -25,4 +27,8 @@ mod engine;
 
 pub use engine::run;
 
+mod graph;
+pub use graph::reverse_dependencies;
+pub use model::ReverseDependency;
+
 pub use prodash;
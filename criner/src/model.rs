@@ -1,7 +1,12 @@
 pub use crate::engine::report::waste::TarHeader;
 use serde_derive::{Deserialize, Serialize};
 use std::convert::TryFrom;
-use std::{collections::HashMap, ops::Add, time::Duration, time::SystemTime};
+use std::{
+    collections::{BTreeMap, HashMap},
+    ops::Add,
+    time::Duration,
+    time::SystemTime,
+};
 
 /// Represents a top-level crate and associated information
 #[derive(Serialize, Deserialize, Default, Clone)]
@@ -27,12 +32,74 @@ pub struct Counts {
 
     /// The amount of crates in the database
     pub crates: u32,
+
+    /// The amount of bytes received by downloads
+    #[serde(default)]
+    pub bytes_downloaded: u64,
+
+    /// The amount of crates that were successfully extracted
+    #[serde(default)]
+    pub crates_extracted: u64,
+
+    /// The amount of failed attempts by the name of the process that ran the task, i.e. `download`
+    #[serde(default)]
+    pub task_failures: BTreeMap<String, u64>,
+}
+
+impl Add<&Counts> for Counts {
+    type Output = Counts;
+
+    fn add(self, rhs: &Counts) -> Self::Output {
+        let Counts {
+            crate_versions,
+            crates,
+            bytes_downloaded,
+            crates_extracted,
+            mut task_failures,
+        } = self;
+        for (process, failures) in rhs.task_failures.iter() {
+            *task_failures.entry(process.to_owned()).or_default() += failures;
+        }
+        Counts {
+            crate_versions: crate_versions + rhs.crate_versions,
+            crates: crates + rhs.crates,
+            bytes_downloaded: bytes_downloaded + rhs.bytes_downloaded,
+            crates_extracted: crates_extracted + rhs.crates_extracted,
+            task_failures,
+        }
+    }
 }
 
 /// Stores wall clock time that elapsed for various kinds of computation
 #[derive(Default, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Clone)]
 pub struct Durations {
     pub fetch_crate_versions: Duration,
+    /// Time spent downloading, summed up over all downloads, including failed ones
+    #[serde(default)]
+    pub download: Duration,
+    /// Time spent extracting crates, summed up over all extractions, including failed ones
+    #[serde(default)]
+    pub extraction: Duration,
+    /// Time spent generating and merging reports
+    #[serde(default)]
+    pub report_generation: Duration,
+    /// Time spent extracting the crates.io database dump and storing its crates
+    #[serde(default)]
+    pub db_dump_ingestion: Duration,
+}
+
+impl Add<&Durations> for Durations {
+    type Output = Durations;
+
+    fn add(self, rhs: &Durations) -> Self::Output {
+        Durations {
+            fetch_crate_versions: self.fetch_crate_versions + rhs.fetch_crate_versions,
+            download: self.download + rhs.download,
+            extraction: self.extraction + rhs.extraction,
+            report_generation: self.report_generation + rhs.report_generation,
+            db_dump_ingestion: self.db_dump_ingestion + rhs.db_dump_ingestion,
+        }
+    }
 }
 
 /// Stores information about the work we have performed thus far
@@ -49,13 +116,8 @@ impl Add<&Context> for Context {
 
     fn add(self, rhs: &Context) -> Self::Output {
         Context {
-            counts: Counts {
-                crate_versions: self.counts.crate_versions + rhs.counts.crate_versions,
-                crates: self.counts.crates + rhs.counts.crates,
-            },
-            durations: Durations {
-                fetch_crate_versions: self.durations.fetch_crate_versions + rhs.durations.fetch_crate_versions,
-            },
+            counts: self.counts + &rhs.counts,
+            durations: self.durations + &rhs.durations,
         }
     }
 }
@@ -115,20 +177,15 @@ impl From<crates_index_diff::Dependency> for Dependency {
 }
 
 /// Identify a kind of change that occurred to a crate
-#[derive(Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Debug)]
+#[derive(Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Debug, Default)]
 pub enum ChangeKind {
     /// A crate version was added
+    #[default]
     Added,
     /// A crate version was added or it was unyanked.
     Yanked,
 }
 
-impl Default for ChangeKind {
-    fn default() -> Self {
-        ChangeKind::Added
-    }
-}
-
 impl<'de> serde::Deserialize<'de> for ChangeKind {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -185,61 +242,153 @@ pub struct CrateVersion {
     pub dependencies: Vec<Dependency>,
 }
 
+/// A crate version depending on another crate, an edge of the reverse dependency graph
+#[derive(Clone, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Debug)]
+pub struct ReverseDependency {
+    /// The name of the dependent crate
+    pub crate_name: String,
+    /// The version of the dependent crate
+    pub crate_version: String,
+    /// The version requirement of the dependent crate, like `^1.0`
+    pub required_version: String,
+    /// The kind of dependency, one of 'normal', 'dev' or 'build'
+    pub kind: String,
+    /// True if this is an optional dependency
+    pub optional: bool,
+}
+
+impl ReverseDependency {
+    /// Returns the most recent of the `known_versions` of the depended-on crate matching our requirement,
+    /// or `None` if none of them does or the requirement can't be parsed.
+    pub fn resolve<'a>(&self, known_versions: impl IntoIterator<Item = &'a String>) -> Option<&'a String> {
+        let requirement = semver::VersionReq::parse(&self.required_version).ok()?;
+        known_versions
+            .into_iter()
+            .filter_map(|v| semver::Version::parse(v).ok().map(|parsed| (parsed, v)))
+            .filter(|(parsed, _)| requirement.matches(parsed))
+            .max_by(|(lhs, _), (rhs, _)| lhs.cmp(rhs))
+            .map(|(_, v)| v)
+    }
+}
+
+/// All crate versions depending on a crate, sorted and without duplicates
+#[derive(Clone, Serialize, Deserialize, Default, Eq, PartialEq, Debug)]
+pub struct ReverseDependencies {
+    pub dependents: Vec<ReverseDependency>,
+}
+
+impl ReverseDependencies {
+    pub fn merge_mut(&mut self, other: &ReverseDependencies) -> &mut ReverseDependencies {
+        self.dependents.extend(other.dependents.iter().cloned());
+        self.dependents.sort();
+        self.dependents.dedup();
+        self
+    }
+}
+
+impl CrateVersion {
+    /// The reverse dependency graph edges of all our dependencies, by the name of the depended-on crate.
+    pub fn reverse_dependencies(&self) -> impl Iterator<Item = (&str, ReverseDependency)> + '_ {
+        self.dependencies.iter().map(move |dependency| {
+            (
+                dependency.package.as_deref().unwrap_or(&dependency.name),
+                ReverseDependency {
+                    crate_name: self.name.clone(),
+                    crate_version: self.version.clone(),
+                    required_version: dependency.required_version.clone(),
+                    kind: dependency.kind.clone().unwrap_or_else(|| "normal".into()),
+                    optional: dependency.optional,
+                },
+            )
+        })
+    }
+}
+
+#[allow(dead_code)]
 #[derive(Debug, Serialize, Deserialize, Clone, Copy)]
 pub enum ReportResult {
     Done,
     NotStarted,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
 pub enum TaskState {
     /// The task was never started
+    #[default]
     NotStarted,
-    /// The task tried to run, but failed N time with errors
-    AttemptsWithFailure(Vec<String>),
+    /// The task tried to run, but failed N time with errors, see `Task::attempts` for details
+    AttemptsWithFailure,
     /// The task completed successfully
     Complete,
     /// Indicates a task is currently running
     /// Please note that this would be unsafe as we don't update tasks in case the user requests
     /// a shutdown or the program is killed.
     /// Thus we cleanup in-progress tasks by checking if their stored_at time is before the process startup time.
-    InProgress(Option<Vec<String>>),
+    InProgress,
 }
 
 impl TaskState {
     pub fn is_complete(&self) -> bool {
         matches!(self, TaskState::Complete)
     }
-    pub fn merge_with(&mut self, other: &TaskState) {
-        fn merge_vec(mut existing: Vec<String>, new: &[String]) -> Vec<String> {
-            existing.extend(new.iter().cloned());
-            existing
+}
+
+/// The kind of worker which ran an attempt
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum WorkerKind {
+    /// The attempt was recorded before we kept track of workers
+    Unknown,
+    /// A worker waiting for the network most of the time, like downloads
+    IoBound,
+    /// A worker keeping a CPU busy, like crate extraction
+    CpuBound,
+}
+
+impl WorkerKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WorkerKind::Unknown => "Unknown",
+            WorkerKind::IoBound => "IoBound",
+            WorkerKind::CpuBound => "CpuBound",
         }
-        use TaskState::*;
-        *self = match (&self, other) {
-            (AttemptsWithFailure(existing), AttemptsWithFailure(new)) => {
-                AttemptsWithFailure(merge_vec(existing.clone(), new))
-            }
-            (AttemptsWithFailure(existing), InProgress(None)) => InProgress(Some(existing.clone())),
-            (AttemptsWithFailure(_), InProgress(Some(_))) => {
-                panic!("One must not create inProgress preloaded with failed attempts, I think :D")
-            }
-            (InProgress(Some(existing)), AttemptsWithFailure(other)) => {
-                AttemptsWithFailure(merge_vec(existing.clone(), other))
-            }
-            (_, other) => other.clone(),
-        };
     }
 }
 
-impl Default for TaskState {
-    fn default() -> Self {
-        TaskState::NotStarted
+/// The reason an attempt failed
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct AttemptError {
+    /// The kind of error, like `Timeout` or `HttpStatus`, for grouping similar failures
+    pub kind: String,
+    /// The error itself
+    pub message: String,
+    /// What we were trying to do when the error occurred
+    pub context: String,
+}
+
+/// A single run of a task, successful or not
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct TaskAttempt {
+    pub started_at: SystemTime,
+    pub finished_at: SystemTime,
+    pub worker: WorkerKind,
+    /// The amount of bytes received or read, if known
+    pub bytes_transferred: Option<u64>,
+    /// Set if the attempt failed
+    pub error: Option<AttemptError>,
+    /// The amount of timeouts which were retried right away as part of this attempt
+    #[serde(default)]
+    pub retried_timeouts: usize,
+}
+
+impl TaskAttempt {
+    pub fn duration(&self) -> Duration {
+        self.finished_at.duration_since(self.started_at).unwrap_or_default()
     }
 }
 
 /// Information about a task
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(from = "legacy::Task")]
 pub struct Task {
     /// This is set automatically, and can be roughly equivalent to the time a task was finished running (no matter if successfully or failed,
     /// but is generally equivalent to the last time the task was saved
@@ -249,6 +398,8 @@ pub struct Task {
     /// Information about the process version
     pub version: String,
     pub state: TaskState,
+    /// All attempts to run this task, the most recent one last
+    pub attempts: Vec<TaskAttempt>,
 }
 
 impl Default for Task {
@@ -258,6 +409,7 @@ impl Default for Task {
             process: Default::default(),
             version: Default::default(),
             state: Default::default(),
+            attempts: Default::default(),
         }
     }
 }
@@ -266,27 +418,209 @@ impl Task {
     // NOTE: Racy if task should be spawned based on the outcome, only for tasks with no contention!
     pub fn can_be_started(&self, startup_time: std::time::SystemTime) -> bool {
         match self.state {
-            TaskState::NotStarted | TaskState::AttemptsWithFailure(_) => true,
-            TaskState::InProgress(_) => startup_time > self.stored_at,
+            TaskState::NotStarted | TaskState::AttemptsWithFailure => true,
+            TaskState::InProgress => startup_time > self.stored_at,
             _ => false,
         }
     }
+
+    pub fn failed_attempts(&self) -> usize {
+        self.attempts.iter().filter(|a| a.error.is_some()).count()
+    }
+}
+
+/// Tasks as they were stored before attempts were recorded, which are migrated on the fly.
+mod legacy {
+    use super::{AttemptError, TaskAttempt, WorkerKind};
+    use serde_derive::Deserialize;
+    use std::time::SystemTime;
+
+    #[derive(Deserialize)]
+    pub enum TaskStateWithErrors {
+        NotStarted,
+        AttemptsWithFailure(Vec<String>),
+        Complete,
+        InProgress(Option<Vec<String>>),
+    }
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    pub enum TaskState {
+        Current(super::TaskState),
+        WithErrors(TaskStateWithErrors),
+    }
+
+    #[derive(Deserialize)]
+    pub struct Task {
+        stored_at: SystemTime,
+        process: String,
+        version: String,
+        state: TaskState,
+        #[serde(default)]
+        attempts: Vec<TaskAttempt>,
+    }
+
+    impl From<Task> for super::Task {
+        fn from(
+            Task {
+                stored_at,
+                process,
+                version,
+                state,
+                mut attempts,
+            }: Task,
+        ) -> Self {
+            use super::TaskState as Current;
+            let (state, errors) = match state {
+                TaskState::Current(state) => (state, Vec::new()),
+                TaskState::WithErrors(state) => match state {
+                    TaskStateWithErrors::NotStarted => (Current::NotStarted, Vec::new()),
+                    TaskStateWithErrors::AttemptsWithFailure(errors) => (Current::AttemptsWithFailure, errors),
+                    TaskStateWithErrors::Complete => (Current::Complete, Vec::new()),
+                    TaskStateWithErrors::InProgress(errors) => (Current::InProgress, errors.unwrap_or_default()),
+                },
+            };
+            attempts.extend(errors.into_iter().map(|message| TaskAttempt {
+                started_at: SystemTime::UNIX_EPOCH,
+                finished_at: SystemTime::UNIX_EPOCH,
+                worker: WorkerKind::Unknown,
+                bytes_transferred: None,
+                error: Some(AttemptError {
+                    kind: "Unknown".into(),
+                    message,
+                    context: String::new(),
+                }),
+                retried_timeouts: 0,
+            }));
+            super::Task {
+                stored_at,
+                process,
+                version,
+                state,
+                attempts,
+            }
+        }
+    }
+}
+
+/// Something unusual about an entry in a crate archive, found during extraction.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub enum EntryFindingKind {
+    /// The path is not valid UTF-8
+    NonUtf8Path,
+    /// The path is absolute, and would be written outside of the directory the crate is unpacked into
+    AbsolutePath,
+    /// The path contains `..` components, possibly escaping the directory the crate is unpacked into
+    ParentDirectoryComponent,
+    /// The entry is a symbolic link to the given target
+    Symlink(Vec<u8>),
+    /// The entry is a hard link to the given target
+    Hardlink(Vec<u8>),
+}
+
+impl EntryFindingKind {
+    pub fn as_str(&self) -> &'static str {
+        use EntryFindingKind::*;
+        match self {
+            NonUtf8Path => "non-utf8-path",
+            AbsolutePath => "absolute-path",
+            ParentDirectoryComponent => "parent-directory-component",
+            Symlink(_) => "symlink",
+            Hardlink(_) => "hardlink",
+        }
+    }
+
+    /// The target of links
+    pub fn link_target(&self) -> Option<&[u8]> {
+        match self {
+            EntryFindingKind::Symlink(target) | EntryFindingKind::Hardlink(target) => Some(target),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct EntryFinding {
+    /// The path of the entry as stored in the archive
+    pub path: Vec<u8>,
+    pub kind: EntryFindingKind,
+}
+
+/// A `[lib]`, `[[bin]]`, `[[example]]`, `[[test]]` or `[[bench]]` section of a manifest
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct BuildTarget {
+    /// The kind of section, like `lib` or `bin`
+    pub kind: String,
+    pub name: Option<String>,
+    pub path: Option<String>,
+}
+
+/// The most interesting information of a crate's `Cargo.toml`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Default)]
+pub struct CargoManifest {
+    /// The `package.edition`, which defaults to 2015 if unset
+    pub edition: Option<String>,
+    /// The `package.rust-version`, the minimal supported rust version
+    pub rust_version: Option<String>,
+    /// The `package.links`, the name of the native library the crate links to
+    pub links: Option<String>,
+    /// All features along with the features and dependencies they enable
+    pub features: BTreeMap<String, Vec<String>>,
+    pub build_targets: Vec<BuildTarget>,
+    /// The platforms of `[target.<platform>]` sections, like `cfg(unix)` or a target triple
+    pub platform_targets: Vec<String>,
+    /// The names of all `[profile.<name>]` sections
+    pub profiles: Vec<String>,
+    /// The sources of all `[patch.<source>]` sections, like `crates-io`
+    pub patched_sources: Vec<String>,
+    /// The package ids of all entries in the `[replace]` section
+    pub replaced_packages: Vec<String>,
+    /// The dotted keys of all fields inherited from the workspace, like `package.version` or `dependencies.serde`
+    pub workspace_inherited: Vec<String>,
+    /// True if there is a `[workspace]` section
+    pub is_workspace_root: bool,
+}
+
+/// A package pinned in a `Cargo.lock` file
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct LockedPackage {
+    pub name: String,
+    pub version: String,
+    /// Where the package comes from, like `registry+https://github.com/rust-lang/crates.io-index`, or `None`
+    /// for packages in the same workspace
+    pub source: Option<String>,
+    /// The checksum of the package archive as hex string, if known
+    pub checksum: Option<String>,
+}
+
+/// A parsed `Cargo.lock` file shipped with a crate
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Default)]
+pub struct CargoLock {
+    /// The format version of the lockfile, which is unset for the oldest format
+    pub version: Option<i64>,
+    pub packages: Vec<LockedPackage>,
+    /// True if the crate has at least one binary target, which is what the lockfile is used for
+    pub has_binary_target: bool,
 }
 
 /// Append-variant-only data structure, otherwise migrations are needed
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub enum TaskResult {
     /// A dummy value just so that we can have a default value
+    #[default]
     None,
     /// Most interesting information about an unpacked crate
     ExplodedCrate {
         /// Meta data of all entries in the crate
         entries_meta_data: Vec<TarHeader>,
-        /// The actual content of selected files, Cargo.*, build.rs and lib/main
+        /// The actual content of selected files, Cargo.*, build.rs and lib/main by default, see `ExtractionConfig`.
         /// IMPORTANT: This file may be partial and limited in size unless it is Cargo.toml, which
         /// is always complete.
         /// Note that these are also present in entries_meta_data.
         selected_entries: Vec<(TarHeader, Vec<u8>)>,
+        /// Entries with unusual paths or types, which are recorded but otherwise ignored
+        #[serde(default)]
+        findings: Vec<EntryFinding>,
     },
     /// A download with meta data and the downloaded blob itself
     Download {
@@ -296,12 +630,27 @@ pub enum TaskResult {
         /// The content type, it's optional because it might not be set (even though it should)
         content_type: Option<String>,
     },
-}
-
-impl Default for TaskResult {
-    fn default() -> Self {
-        TaskResult::None
-    }
+    /// The output of a configured external program run on a crate
+    ExternalCommand {
+        /// The program and its arguments, without the path to the crate which is passed last
+        command: Vec<String>,
+        /// Everything the program wrote to stdout, which is valid JSON
+        output: String,
+    },
+    /// The parsed `Cargo.toml` of a crate
+    CargoManifest {
+        /// The manifest, if it was found and could be parsed
+        manifest: Option<CargoManifest>,
+        /// Why the manifest could not be parsed or wasn't found
+        error: Option<String>,
+    },
+    /// The parsed `Cargo.lock` of a crate
+    CargoLock {
+        /// The lockfile, if the crate ships one and it could be parsed
+        lockfile: Option<CargoLock>,
+        /// Why the lockfile could not be parsed
+        error: Option<String>,
+    },
 }
 
 impl TryFrom<crates_index_diff::Change> for CrateVersion {
@@ -328,7 +677,7 @@ impl TryFrom<crates_index_diff::Change> for CrateVersion {
         } = v;
         Ok(CrateVersion {
             name: name.to_string(),
-            kind: yanked.then(|| ChangeKind::Yanked).unwrap_or(ChangeKind::Added),
+            kind: if yanked { ChangeKind::Yanked } else { ChangeKind::Added },
             version: version.to_string(),
             checksum: hex::encode(checksum),
             features,
@@ -375,6 +724,7 @@ pub mod db_dump {
         pub crates: Vec<String>,
     }
 
+    #[allow(dead_code)]
     #[derive(Clone, Default, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Debug)]
     pub struct Person {
         pub name: String,
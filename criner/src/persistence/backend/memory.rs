@@ -0,0 +1,177 @@
+use crate::{persistence::ThreadSafeConnection, Result};
+use std::{collections::BTreeMap, sync::Arc};
+
+#[derive(Default)]
+struct Table {
+    /// The insertion counter, equivalent to SQLite's `_rowid_`
+    next_row: u64,
+    /// key -> (row, data)
+    rows: BTreeMap<String, (u64, Vec<u8>)>,
+}
+
+impl Table {
+    fn insert(&mut self, key: &str, data: Vec<u8>) {
+        self.next_row += 1;
+        self.rows.insert(key.to_owned(), (self.next_row, data));
+    }
+
+    fn by_row<'a>(&'a self, glob: Option<&glob::Pattern>) -> Vec<(&'a String, &'a Vec<u8>)> {
+        let mut items: Vec<_> = self
+            .rows
+            .iter()
+            .filter(|(k, _)| glob.is_none_or(|g| g.matches(k)))
+            .map(|(k, (row, data))| (*row, k, data))
+            .collect();
+        items.sort_by_key(|(row, _, _)| *row);
+        items.into_iter().map(|(_, k, data)| (k, data)).collect()
+    }
+}
+
+/// A backend keeping all tables in memory, useful for tests as it's fast and leaves nothing behind.
+#[derive(Clone, Default)]
+pub struct MemoryBackend {
+    tables: Arc<parking_lot::Mutex<BTreeMap<String, Table>>>,
+}
+
+impl super::Backend for MemoryBackend {
+    fn connect(&self) -> Result<ThreadSafeConnection> {
+        Ok(Arc::new(self.clone()))
+    }
+}
+
+fn compile_glob(glob: Option<&str>) -> Result<Option<glob::Pattern>> {
+    Ok(match glob {
+        Some(glob) => Some(glob::Pattern::new(glob)?),
+        None => None,
+    })
+}
+
+impl super::Connection for MemoryBackend {
+    fn get(&self, table: &str, key: &str) -> Result<Option<Vec<u8>>> {
+        Ok(self
+            .tables
+            .lock()
+            .get(table)
+            .and_then(|t| t.rows.get(key))
+            .map(|(_, data)| data.clone()))
+    }
+
+    fn contains_key(&self, table: &str, key: &str) -> Result<bool> {
+        Ok(self.tables.lock().get(table).is_some_and(|t| t.rows.contains_key(key)))
+    }
+
+    fn count(&self, table: &str, glob: Option<&str>) -> Result<u64> {
+        let glob = compile_glob(glob)?;
+        Ok(self.tables.lock().get(table).map_or(0, |t| {
+            t.rows
+                .keys()
+                .filter(|k| glob.as_ref().is_none_or(|g| g.matches(k)))
+                .count()
+        }) as u64)
+    }
+
+    fn update(
+        &self,
+        _progress: Option<&mut prodash::tree::Item>,
+        table: &str,
+        key: &str,
+        f: &mut super::UpdateFn<'_>,
+    ) -> Result<()> {
+        let mut tables = self.tables.lock();
+        let table = tables.entry(table.to_owned()).or_default();
+        let new_value = f(table.rows.get(key).map(|(_, data)| data.as_slice()))?;
+        table.insert(key, new_value);
+        Ok(())
+    }
+
+    fn insert(&self, _progress: Option<&mut prodash::tree::Item>, table: &str, key: &str, data: &[u8]) -> Result<()> {
+        self.tables
+            .lock()
+            .entry(table.to_owned())
+            .or_default()
+            .insert(key, data.to_owned());
+        Ok(())
+    }
+
+    fn insert_many(&self, table: &str, items: &mut dyn Iterator<Item = Result<(String, Vec<u8>)>>) -> Result<usize> {
+        // Like a transaction, nothing is written if one item fails
+        let items = items.collect::<Result<Vec<_>>>()?;
+        let mut tables = self.tables.lock();
+        let table = tables.entry(table.to_owned()).or_default();
+        let count = items.len();
+        for (key, data) in items {
+            table.insert(&key, data);
+        }
+        Ok(count)
+    }
+
+    fn insert_keys(&self, table: &str, keys: &mut dyn Iterator<Item = &str>) -> Result<usize> {
+        let mut tables = self.tables.lock();
+        let table = tables.entry(table.to_owned()).or_default();
+        let mut count = 0;
+        for key in keys {
+            table.insert(key, Vec::new());
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    fn remove(&self, table: &str, key: &str) -> Result<bool> {
+        Ok(self
+            .tables
+            .lock()
+            .get_mut(table)
+            .is_some_and(|t| t.rows.remove(key).is_some()))
+    }
+
+    fn key_values_old_to_new(
+        &self,
+        table: &str,
+        glob: Option<&str>,
+        chunk: Option<(usize, usize)>,
+    ) -> Result<Vec<(String, Vec<u8>)>> {
+        let glob = compile_glob(glob)?;
+        let (offset, limit) = chunk.unwrap_or((0, usize::MAX));
+        Ok(self.tables.lock().get(table).map_or_else(Vec::new, |t| {
+            t.by_row(glob.as_ref())
+                .into_iter()
+                .skip(offset)
+                .take(limit)
+                .map(|(k, data)| (k.to_owned(), data.to_owned()))
+                .collect()
+        }))
+    }
+
+    fn keys_old_to_new(&self, table: &str, chunk: Option<(usize, usize)>) -> Result<Vec<String>> {
+        let (offset, limit) = chunk.unwrap_or((0, usize::MAX));
+        Ok(self.tables.lock().get(table).map_or_else(Vec::new, |t| {
+            t.by_row(None)
+                .into_iter()
+                .skip(offset)
+                .take(limit)
+                .map(|(k, _)| k.to_owned())
+                .collect()
+        }))
+    }
+
+    fn values_recent_first(&self, table: &str, offset: usize, limit: usize) -> Result<Vec<Vec<u8>>> {
+        Ok(self.tables.lock().get(table).map_or_else(Vec::new, |t| {
+            t.by_row(None)
+                .into_iter()
+                .rev()
+                .skip(offset)
+                .take(limit)
+                .map(|(_, data)| data.to_owned())
+                .collect()
+        }))
+    }
+
+    fn last_by_key(&self, table: &str) -> Result<Option<(String, Vec<u8>)>> {
+        Ok(self
+            .tables
+            .lock()
+            .get(table)
+            .and_then(|t| t.rows.iter().next_back())
+            .map(|(k, (_, data))| (k.to_owned(), data.to_owned())))
+    }
+}
@@ -0,0 +1,70 @@
+use crate::Result;
+
+mod memory;
+pub use memory::MemoryBackend;
+
+pub mod sqlite;
+pub use sqlite::SqliteBackend;
+
+/// Receives the existing value, if there is one, and returns the new value to store.
+pub type UpdateFn<'a> = dyn FnMut(Option<&[u8]>) -> Result<Vec<u8>> + 'a;
+
+/// Something able to hand out connections to a store of key-value tables.
+///
+/// All connections opened by the same backend see the same data.
+pub trait Backend: Send + Sync {
+    fn connect(&self) -> Result<super::ThreadSafeConnection>;
+    /// Like `connect`, but the connection will wait for locks to be released instead of failing early.
+    /// Use this for long-running bulk operations.
+    fn connect_with_busy_wait(&self) -> Result<super::ThreadSafeConnection> {
+        self.connect()
+    }
+}
+
+/// Operations on named tables each mapping a unique textual key to a binary blob.
+///
+/// Insertion order matters as it's used to iterate tables from old to new and vice versa. Replacing an existing
+/// key makes it the most recent one.
+/// Marker tables like `report_done` only store keys, for which the `*_key*` methods are used.
+pub trait Connection: Send + Sync {
+    fn get(&self, table: &str, key: &str) -> Result<Option<Vec<u8>>>;
+    fn contains_key(&self, table: &str, key: &str) -> Result<bool>;
+    fn count(&self, table: &str, glob: Option<&str>) -> Result<u64>;
+
+    /// Atomically read the value at `key` and replace it with the value returned by `f`.
+    /// `f` may be called multiple times in case of contention.
+    fn update(
+        &self,
+        progress: Option<&mut prodash::tree::Item>,
+        table: &str,
+        key: &str,
+        f: &mut UpdateFn<'_>,
+    ) -> Result<()>;
+    fn insert(&self, progress: Option<&mut prodash::tree::Item>, table: &str, key: &str, data: &[u8]) -> Result<()>;
+    /// Insert or replace all `items` in a single transaction, returning the amount of items written.
+    fn insert_many(&self, table: &str, items: &mut dyn Iterator<Item = Result<(String, Vec<u8>)>>) -> Result<usize>;
+    /// Insert all `keys` into a marker table in a single transaction
+    fn insert_keys(&self, table: &str, keys: &mut dyn Iterator<Item = &str>) -> Result<usize>;
+    /// Remove the item at `key`, returning true if it existed.
+    fn remove(&self, table: &str, key: &str) -> Result<bool>;
+
+    /// Return keys and values in insertion order, optionally filtered by a unix glob on the key and limited to
+    /// `(offset, limit)`.
+    fn key_values_old_to_new(
+        &self,
+        table: &str,
+        glob: Option<&str>,
+        chunk: Option<(usize, usize)>,
+    ) -> Result<Vec<(String, Vec<u8>)>>;
+    /// Like `key_values_old_to_new()`, but only returns keys, which also works for marker tables.
+    fn keys_old_to_new(&self, table: &str, chunk: Option<(usize, usize)>) -> Result<Vec<String>>;
+    /// Return values with the most recently inserted first, skipping `offset` and returning at most `limit` values.
+    fn values_recent_first(&self, table: &str, offset: usize, limit: usize) -> Result<Vec<Vec<u8>>>;
+    /// Return the key-value pair with the greatest key
+    fn last_by_key(&self, table: &str) -> Result<Option<(String, Vec<u8>)>>;
+
+    /// Give the backend a chance to compact what was written so far. Does nothing by default.
+    fn checkpoint(&self) -> Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,361 @@
+use crate::{persistence::ThreadSafeConnection, Result};
+use rusqlite::{params, OptionalExtension};
+use std::{
+    path::PathBuf,
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
+
+pub const TABLE_NAMES: &[&str] = &[
+    "meta",
+    "crate_version",
+    "crate",
+    "reverse_dependency",
+    "task",
+    "result",
+    "crates.io-crate",
+    "quarantine",
+];
+pub const KEY_ONLY_TABLE_NAMES: &[&str] = &["report_done"];
+
+/// The default backend, storing all tables in a single SQLite database in WAL mode.
+#[derive(Clone)]
+pub struct SqliteBackend {
+    path: PathBuf,
+}
+
+impl SqliteBackend {
+    /// Open the database at `path`, a file which is created if it doesn't exist yet, along with all tables.
+    pub fn open(path: impl Into<PathBuf>) -> Result<SqliteBackend> {
+        let path = path.into();
+        let mut connection = rusqlite::Connection::open(&path)?;
+        connection.execute_batch("
+            PRAGMA journal_mode = WAL;          -- better write-concurrency
+            PRAGMA synchronous = NORMAL;        -- fsync only in critical moments
+            PRAGMA wal_autocheckpoint = 1000;   -- write WAL changes back every 1000 pages, for an in average 1MB WAL file. May affect readers if number is increased
+            PRAGMA wal_checkpoint(TRUNCATE);    -- free some space by truncating possibly massive WAL files from the last run.
+        ")?;
+
+        let transaction = connection.transaction()?;
+        for name in TABLE_NAMES {
+            transaction.execute_batch(&format!(
+                "CREATE TABLE IF NOT EXISTS '{}' (
+                      key             TEXT PRIMARY KEY NOT NULL,
+                      data            BLOB NOT NULL
+                )",
+                name
+            ))?;
+        }
+        for name in KEY_ONLY_TABLE_NAMES {
+            transaction.execute_batch(&format!(
+                "CREATE TABLE IF NOT EXISTS '{}' (
+                        key             TEXT PRIMARY KEY NOT NULL
+                )",
+                name
+            ))?;
+        }
+        transaction.commit()?;
+        Ok(SqliteBackend { path })
+    }
+
+    /// A raw connection for operations only SQLite can do.
+    pub fn open_connection_no_async_with_busy_wait(&self) -> Result<rusqlite::Connection> {
+        let connection = rusqlite::Connection::open(&self.path)?;
+        connection.busy_handler(Some(sleeper))?;
+        Ok(connection)
+    }
+}
+
+impl super::Backend for SqliteBackend {
+    fn connect(&self) -> Result<ThreadSafeConnection> {
+        Ok(Arc::new(SqliteConnection {
+            inner: parking_lot::Mutex::new(rusqlite::Connection::open(&self.path)?),
+        }))
+    }
+
+    fn connect_with_busy_wait(&self) -> Result<ThreadSafeConnection> {
+        Ok(Arc::new(SqliteConnection {
+            inner: parking_lot::Mutex::new(self.open_connection_no_async_with_busy_wait()?),
+        }))
+    }
+}
+
+/// A single connection, which is locked for the duration of each operation.
+pub struct SqliteConnection {
+    inner: parking_lot::Mutex<rusqlite::Connection>,
+}
+
+impl super::Connection for SqliteConnection {
+    fn get(&self, table: &str, key: &str) -> Result<Option<Vec<u8>>> {
+        Ok(self
+            .inner
+            .lock()
+            .query_row(
+                &format!("SELECT data FROM '{}' WHERE key = ?1", table),
+                params![key],
+                |r| r.get::<_, Vec<u8>>(0),
+            )
+            .optional()?)
+    }
+
+    fn contains_key(&self, table: &str, key: &str) -> Result<bool> {
+        Ok(self
+            .inner
+            .lock()
+            .query_row(
+                &format!("SELECT key FROM '{}' WHERE key = ?1", table),
+                params![key],
+                |_r| Ok(()),
+            )
+            .optional()?
+            .is_some())
+    }
+
+    fn count(&self, table: &str, glob: Option<&str>) -> Result<u64> {
+        Ok(self.inner.lock().query_row(
+            &format!("SELECT COUNT(*) FROM '{}' {}", table, where_glob(glob)),
+            [],
+            |r| r.get::<_, i64>(0),
+        )? as u64)
+    }
+
+    fn update(
+        &self,
+        progress: Option<&mut prodash::tree::Item>,
+        table: &str,
+        key: &str,
+        f: &mut super::UpdateFn<'_>,
+    ) -> Result<()> {
+        retry_on_db_busy(progress, || {
+            let mut guard = self.inner.lock();
+            let transaction = guard.transaction_with_behavior(rusqlite::TransactionBehavior::Immediate)?;
+            let new_value = {
+                let existing = transaction
+                    .query_row(
+                        &format!("SELECT data FROM '{}' WHERE key = ?1", table),
+                        params![key],
+                        |r| r.get::<_, Vec<u8>>(0),
+                    )
+                    .optional()?;
+                f(existing.as_deref())?
+            };
+            transaction.execute(
+                &format!("REPLACE INTO '{}' (key, data) VALUES (?1, ?2)", table),
+                params![key, new_value],
+            )?;
+            transaction.commit()?;
+            Ok(())
+        })
+    }
+
+    fn insert(&self, progress: Option<&mut prodash::tree::Item>, table: &str, key: &str, data: &[u8]) -> Result<()> {
+        retry_on_db_busy(progress, || {
+            self.inner.lock().execute(
+                &format!("REPLACE INTO '{}' (key, data) VALUES (?1, ?2)", table),
+                params![key, data],
+            )?;
+            Ok(())
+        })
+    }
+
+    fn insert_many(&self, table: &str, items: &mut dyn Iterator<Item = Result<(String, Vec<u8>)>>) -> Result<usize> {
+        let mut guard = self.inner.lock();
+        let transaction = guard.transaction_with_behavior(rusqlite::TransactionBehavior::Immediate)?;
+        let mut count = 0;
+        {
+            let mut statement = new_key_value_insertion(table, &transaction)?;
+            for item in items {
+                let (key, data) = item?;
+                statement.execute(params![key, data])?;
+                count += 1;
+            }
+        }
+        transaction.commit()?;
+        Ok(count)
+    }
+
+    fn insert_keys(&self, table: &str, keys: &mut dyn Iterator<Item = &str>) -> Result<usize> {
+        let mut guard = self.inner.lock();
+        let transaction = guard.transaction_with_behavior(rusqlite::TransactionBehavior::Immediate)?;
+        let mut count = 0;
+        {
+            let mut statement = new_key_insertion(table, &transaction)?;
+            for key in keys {
+                statement.execute(params![key])?;
+                count += 1;
+            }
+        }
+        transaction.commit()?;
+        Ok(count)
+    }
+
+    fn remove(&self, table: &str, key: &str) -> Result<bool> {
+        retry_on_db_busy(None, || {
+            Ok(self
+                .inner
+                .lock()
+                .execute(&format!("DELETE FROM '{}' WHERE key = ?1", table), params![key])?
+                > 0)
+        })
+    }
+
+    fn key_values_old_to_new(
+        &self,
+        table: &str,
+        glob: Option<&str>,
+        chunk: Option<(usize, usize)>,
+    ) -> Result<Vec<(String, Vec<u8>)>> {
+        let guard = self.inner.lock();
+        let mut statement = new_key_value_query_old_to_new_filtered(table, glob, &guard, chunk)?;
+        let items = statement
+            .query_map([], |r| Ok((r.get::<_, String>(0)?, r.get::<_, Vec<u8>>(1)?)))?
+            .collect::<rusqlite::Result<_>>()?;
+        Ok(items)
+    }
+
+    fn keys_old_to_new(&self, table: &str, chunk: Option<(usize, usize)>) -> Result<Vec<String>> {
+        let guard = self.inner.lock();
+        let mut statement = guard.prepare(&format!(
+            "SELECT key FROM '{}' ORDER BY _rowid_ ASC {}",
+            table,
+            limit_chunk(chunk)
+        ))?;
+        let keys = statement
+            .query_map([], |r| r.get::<_, String>(0))?
+            .collect::<rusqlite::Result<_>>()?;
+        Ok(keys)
+    }
+
+    fn values_recent_first(&self, table: &str, offset: usize, limit: usize) -> Result<Vec<Vec<u8>>> {
+        let guard = self.inner.lock();
+        let mut statement = new_value_query_recent_first(table, &guard, offset, limit)?;
+        let items = statement
+            .query_map([], |r| r.get::<_, Vec<u8>>(0))?
+            .collect::<rusqlite::Result<_>>()?;
+        Ok(items)
+    }
+
+    fn last_by_key(&self, table: &str) -> Result<Option<(String, Vec<u8>)>> {
+        Ok(self
+            .inner
+            .lock()
+            .query_row(
+                &format!("SELECT key, data FROM '{}' ORDER BY key DESC limit 1", table),
+                [],
+                |r| Ok((r.get::<_, String>(0)?, r.get::<_, Vec<u8>>(1)?)),
+            )
+            .optional()?)
+    }
+
+    // We have too many writers which cause the WAL to get so large that all reads are slowing to a crawl
+    // Standard SQLITE autocheckpoints are passive, which are not effective in our case as they never
+    // kick in with too many writers. There is no way to change the autocheckpoint mode to something more suitable… :/
+    fn checkpoint(&self) -> Result<()> {
+        self.inner.lock().execute_batch("PRAGMA wal_checkpoint(TRUNCATE)")?;
+        Ok(())
+    }
+}
+
+fn where_glob(glob: Option<&str>) -> String {
+    match glob {
+        Some(glob) => format!("where key glob \"{}\"", glob),
+        None => "".into(),
+    }
+}
+
+fn new_value_query_recent_first<'conn>(
+    table_name: &str,
+    connection: &'conn rusqlite::Connection,
+    offset: usize,
+    limit: usize,
+) -> Result<rusqlite::Statement<'conn>> {
+    Ok(connection.prepare(&format!(
+        "SELECT data FROM '{}' ORDER BY _rowid_ DESC LIMIT {}, {}",
+        table_name, offset, limit
+    ))?)
+}
+
+fn new_key_value_query_old_to_new_filtered<'conn>(
+    table_name: &str,
+    glob: Option<&str>,
+    connection: &'conn rusqlite::Connection,
+    chunk: Option<(usize, usize)>,
+) -> Result<rusqlite::Statement<'conn>> {
+    Ok(connection.prepare(&format!(
+        "SELECT key,data FROM '{}' {} ORDER BY _rowid_ ASC {}",
+        table_name,
+        where_glob(glob),
+        limit_chunk(chunk)
+    ))?)
+}
+
+fn limit_chunk(chunk: Option<(usize, usize)>) -> String {
+    match chunk {
+        Some((offset, limit)) => format!("LIMIT {}, {}", offset, limit),
+        None => "".into(),
+    }
+}
+
+fn new_key_value_insertion<'conn>(
+    table_name: &str,
+    connection: &'conn rusqlite::Connection,
+) -> Result<rusqlite::Statement<'conn>> {
+    Ok(connection.prepare(&format!("REPLACE INTO '{}' (key, data) VALUES (?1, ?2)", table_name))?)
+}
+
+fn new_key_insertion<'conn>(
+    table_name: &str,
+    connection: &'conn rusqlite::Connection,
+) -> Result<rusqlite::Statement<'conn>> {
+    Ok(connection.prepare(&format!("REPLACE INTO '{}' (key) VALUES (?1)", table_name))?)
+}
+
+fn sleeper(attempts: i32) -> bool {
+    log::warn!("SQLITE_BUSY, retrying after 50ms (attempt {})", attempts);
+    std::thread::sleep(std::time::Duration::from_millis(50));
+    true
+}
+
+fn retry_on_db_busy<T>(mut progress: Option<&mut prodash::tree::Item>, mut f: impl FnMut() -> Result<T>) -> Result<T> {
+    use crate::Error;
+    use rusqlite::ffi::Error as SqliteFFIError;
+    use rusqlite::ffi::ErrorCode as SqliteFFIErrorCode;
+    use rusqlite::Error as SqliteError;
+    use std::ops::Add;
+
+    let max_wait_ms = Duration::from_secs(100);
+    let mut total_wait_time = Duration::default();
+    let mut wait_for = Duration::from_millis(1);
+    loop {
+        match f() {
+            Ok(v) => return Ok(v),
+            Err(
+                err @ Error::Rusqlite(SqliteError::SqliteFailure(
+                    SqliteFFIError {
+                        code: SqliteFFIErrorCode::DatabaseBusy,
+                        ..
+                    },
+                    _,
+                )),
+            ) => {
+                if total_wait_time >= max_wait_ms {
+                    log::warn!("Giving up to wait for {:?} after {:?})", err, total_wait_time);
+                    return Err(err);
+                }
+                log::warn!(
+                    "Waiting {:?} for {:?} (total wait time {:?})",
+                    wait_for,
+                    err,
+                    total_wait_time
+                );
+                if let Some(p) = progress.as_mut() {
+                    p.blocked("wait for write lock", Some(SystemTime::now().add(wait_for)));
+                };
+                std::thread::sleep(wait_for);
+                total_wait_time += wait_for;
+                wait_for *= 2;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::model::{Context, Crate, CrateVersion, Task, TaskResult};
+use crate::model::{Context, Crate, CrateVersion, ReverseDependencies, Task, TaskResult};
 use std::time::SystemTime;
 
 pub const KEY_SEP_CHAR: char = ':';
@@ -47,7 +47,11 @@ impl Keyed for TaskResult {
                 buf.push(KEY_SEP_CHAR);
                 buf.push_str(kind)
             }
-            TaskResult::None | TaskResult::ExplodedCrate { .. } => {}
+            TaskResult::None
+            | TaskResult::ExplodedCrate { .. }
+            | TaskResult::ExternalCommand { .. }
+            | TaskResult::CargoManifest { .. }
+            | TaskResult::CargoLock { .. } => {}
         }
     }
 }
@@ -81,3 +85,13 @@ impl CrateVersion {
         buf.push_str(version);
     }
 }
+
+impl ReverseDependencies {
+    /// The key of the edges from the dependent crate version `crate_name` at `crate_version` to `dependency`,
+    /// so that all dependents of a crate share the `<dependency>:` prefix.
+    pub fn key_from(dependency: &str, crate_name: &str, crate_version: &str, buf: &mut String) {
+        buf.push_str(dependency);
+        buf.push(KEY_SEP_CHAR);
+        CrateVersion::key_from(crate_name, crate_version, buf);
+    }
+}
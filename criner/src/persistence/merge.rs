@@ -6,35 +6,14 @@ pub trait Merge<T> {
 }
 
 impl Merge<model::Task> for model::Task {
+    /// Take everything from `other`, but keep our attempts, appending the ones of `other`.
     fn merge(mut self, other: &Task) -> Self {
-        let my_state = self.state;
-        self = other.clone();
-        self.state = my_state.merge(&other.state);
-        self
-    }
-}
-
-impl Merge<model::TaskState> for model::TaskState {
-    fn merge(mut self, other: &model::TaskState) -> Self {
-        fn merge_vec(mut existing: Vec<String>, new: &[String]) -> Vec<String> {
-            existing.extend(new.iter().cloned());
-            existing
+        let mut attempts = std::mem::take(&mut self.attempts);
+        attempts.extend(other.attempts.iter().cloned());
+        Task {
+            attempts,
+            ..other.clone()
         }
-        use model::TaskState::*;
-        self = match (&self, other) {
-            (AttemptsWithFailure(existing), AttemptsWithFailure(new)) => {
-                AttemptsWithFailure(merge_vec(existing.clone(), new))
-            }
-            (AttemptsWithFailure(existing), InProgress(None)) => InProgress(Some(existing.clone())),
-            (AttemptsWithFailure(_), InProgress(Some(_))) => {
-                panic!("One must not create inProgress preloaded with failed attempts, I think :D")
-            }
-            (InProgress(Some(existing)), AttemptsWithFailure(other)) => {
-                AttemptsWithFailure(merge_vec(existing.clone(), other))
-            }
-            (_, other) => other.clone(),
-        };
-        self
     }
 }
 
@@ -1,69 +1,56 @@
 use crate::Result;
-use std::path::{Path, PathBuf};
+use std::{path::Path, sync::Arc};
 
 mod keyed;
 mod merge;
 pub use keyed::*;
 
+pub mod backend;
+pub use backend::{Backend, Connection};
+
 mod serde;
 mod table;
 pub use table::*;
 
+/// Required as we send futures to threads. The type system can't statically prove that in fact
+/// these connections will only ever be created while already in the thread they should execute on.
+/// Also no one can prevent futures from being resumed in after having been send to a different thread.
+pub type ThreadSafeConnection = Arc<dyn Connection>;
+
 #[derive(Clone)]
 pub struct Db {
-    sqlite_path: PathBuf,
+    backend: Arc<dyn Backend>,
 }
 
 impl Db {
+    /// Open or create the SQLite database in the directory at `path`.
     pub fn open(path: impl AsRef<Path>) -> Result<Db> {
         std::fs::create_dir_all(&path)?;
-        let sqlite_path = path.as_ref().join("db.msgpack.sqlite");
-        {
-            let mut connection = rusqlite::Connection::open(&sqlite_path)?;
-            connection.execute_batch("
-                PRAGMA journal_mode = WAL;          -- better write-concurrency
-                PRAGMA synchronous = NORMAL;        -- fsync only in critical moments
-                PRAGMA wal_autocheckpoint = 1000;   -- write WAL changes back every 1000 pages, for an in average 1MB WAL file. May affect readers if number is increased
-                PRAGMA wal_checkpoint(TRUNCATE);    -- free some space by truncating possibly massive WAL files from the last run.
-            ")?;
+        Ok(Db::with_backend(backend::SqliteBackend::open(Self::sqlite_path(path))?))
+    }
 
-            let transaction = connection.transaction()?;
-            for name in &["meta", "crate_version", "crate", "task", "result", "crates.io-crate"] {
-                transaction.execute_batch(&format!(
-                    "CREATE TABLE IF NOT EXISTS '{}' (
-                          key             TEXT PRIMARY KEY NOT NULL,
-                          data            BLOB NOT NULL
-                    )",
-                    name
-                ))?;
-            }
-            transaction.execute_batch(
-                "CREATE TABLE IF NOT EXISTS report_done (
-                        key             TEXT PRIMARY KEY NOT NULL
-                )",
-            )?;
-            transaction.commit()?;
+    /// A database that lives only as long as the returned instance, or any of its clones.
+    pub fn in_memory() -> Db {
+        Db::with_backend(backend::MemoryBackend::default())
+    }
+
+    pub fn with_backend(backend: impl Backend + 'static) -> Db {
+        Db {
+            backend: Arc::new(backend),
         }
+    }
 
-        Ok(Db { sqlite_path })
+    /// The path to the SQLite database file within the database directory at `path`.
+    pub fn sqlite_path(path: impl AsRef<Path>) -> std::path::PathBuf {
+        path.as_ref().join("db.msgpack.sqlite")
     }
 
     pub fn open_connection(&self) -> Result<ThreadSafeConnection> {
-        Ok(std::sync::Arc::new(parking_lot::Mutex::new(
-            rusqlite::Connection::open(&self.sqlite_path)?,
-        )))
+        self.backend.connect()
     }
 
     pub fn open_connection_with_busy_wait(&self) -> Result<ThreadSafeConnection> {
-        let connection = rusqlite::Connection::open(&self.sqlite_path)?;
-        connection.busy_handler(Some(sleeper))?;
-        Ok(std::sync::Arc::new(parking_lot::Mutex::new(connection)))
-    }
-
-    pub fn open_connection_no_async_with_busy_wait(&self) -> Result<rusqlite::Connection> {
-        let connection = rusqlite::Connection::open(&self.sqlite_path)?;
-        connection.busy_handler(Some(sleeper))?;
-        Ok(connection)
+        self.backend.connect_with_busy_wait()
     }
 
     pub fn open_crate_versions(&self) -> Result<CrateVersionTable> {
@@ -76,6 +63,11 @@ impl Db {
             inner: self.open_connection()?,
         })
     }
+    pub fn open_reverse_dependencies(&self) -> Result<ReverseDependencyTable> {
+        Ok(ReverseDependencyTable {
+            inner: self.open_connection()?,
+        })
+    }
     pub fn open_tasks(&self) -> Result<TaskTable> {
         Ok(TaskTable {
             inner: self.open_connection()?,
@@ -98,8 +90,5 @@ impl Db {
     }
 }
 
-fn sleeper(attempts: i32) -> bool {
-    log::warn!("SQLITE_BUSY, retrying after 50ms (attempt {})", attempts);
-    std::thread::sleep(std::time::Duration::from_millis(50));
-    true
-}
+#[cfg(test)]
+mod persistence_test;
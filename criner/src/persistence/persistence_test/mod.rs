@@ -0,0 +1,470 @@
+use crate::{
+    model::{self, AttemptError, Context, Counts, Crate, CrateVersion, Task, TaskAttempt, TaskState, WorkerKind},
+    persistence::{merge::Merge, Db, MetaTable, TableAccess},
+};
+use std::{
+    convert::TryFrom,
+    time::{Duration, SystemTime},
+};
+
+fn version(name: &str, version: &str) -> CrateVersion {
+    CrateVersion {
+        name: name.into(),
+        version: version.into(),
+        ..Default::default()
+    }
+}
+
+fn assert_table_semantics(db: Db) {
+    let root = prodash::tree::Root::new();
+    let mut progress = root.add_child("test");
+
+    let versions = db.open_crate_versions().unwrap();
+    for (name, v) in &[("a", "1.0.0"), ("b", "1.0.0"), ("a", "2.0.0")] {
+        versions
+            .insert(&mut progress, format!("{}:{}", name, v), &version(name, v))
+            .unwrap();
+    }
+    assert_eq!(versions.count(), 3);
+    assert_eq!(versions.count_filtered(Some("a:*")), 2);
+    assert_eq!(versions.get("b:1.0.0").unwrap().map(|v| v.name), Some("b".into()));
+    assert!(versions.get("c:1.0.0").unwrap().is_none());
+
+    let keys = |chunk| -> Vec<String> {
+        versions
+            .chunk_old_to_new(None, chunk)
+            .unwrap()
+            .into_iter()
+            .map(|(k, _)| k)
+            .collect()
+    };
+    assert_eq!(keys(None), vec!["a:1.0.0", "b:1.0.0", "a:2.0.0"]);
+    assert_eq!(keys(Some((1, 1))), vec!["b:1.0.0"]);
+
+    versions
+        .insert(&mut progress, "a:1.0.0", &version("a", "1.0.0"))
+        .unwrap();
+    assert_eq!(
+        keys(None),
+        vec!["b:1.0.0", "a:2.0.0", "a:1.0.0"],
+        "replacing an item makes it the most recent one"
+    );
+    assert_eq!(
+        versions
+            .chunk_recent_first(0, 2)
+            .unwrap()
+            .into_iter()
+            .map(|v| v.version)
+            .collect::<Vec<_>>(),
+        vec!["1.0.0", "2.0.0"]
+    );
+
+    let crates = db.open_crates().unwrap();
+    crates.upsert(&mut progress, "a", &version("a", "2.0.0")).unwrap();
+    let krate: Crate = crates.upsert(&mut progress, "a", &version("a", "1.0.0")).unwrap();
+    assert_eq!(krate.versions, vec!["1.0.0", "2.0.0"], "merging keeps versions sorted");
+
+    let tasks = db.open_tasks().unwrap();
+    let task = tasks
+        .update(Some(&mut progress), "a:1.0.0:download:1.0.0", |mut t: Task| {
+            t.state = TaskState::Complete;
+            t
+        })
+        .unwrap();
+    assert!(task.state.is_complete());
+    assert!(tasks
+        .get("a:1.0.0:download:1.0.0")
+        .unwrap()
+        .expect("task to exist")
+        .state
+        .is_complete());
+
+    let context = db.open_context().unwrap();
+    assert!(context.most_recent().unwrap().is_none());
+    context.update_today(|c| c.counts.crates += 2).unwrap();
+    let c: Context = context.update_today(|c| c.counts.crates += 1).unwrap();
+    assert_eq!(c.counts.crates, 3);
+    assert_eq!(context.most_recent().unwrap().map(|(_, c)| c.counts.crates), Some(3));
+    assert!(!context.is_complete("bootstrap").unwrap());
+    context.mark_complete("bootstrap").unwrap();
+    assert!(context.is_complete("bootstrap").unwrap());
+    assert_eq!(
+        context.most_recent().unwrap().map(|(_, c)| c.counts.crates),
+        Some(3),
+        "markers are not contexts"
+    );
+    assert_eq!(
+        context.count_filtered(Some(MetaTable::CONTEXT_GLOB)),
+        1,
+        "markers can be told apart from contexts"
+    );
+
+    let reports = db.open_reports().unwrap();
+    assert!(!reports.is_done("a:1.0.0:waste:1.0.0"));
+    assert_eq!(reports.mark_done(vec!["a:1.0.0:waste:1.0.0"]).unwrap(), 1);
+    assert!(reports.is_done("a:1.0.0:waste:1.0.0"));
+}
+
+#[test]
+fn in_memory_backend() {
+    assert_table_semantics(Db::in_memory());
+}
+
+#[test]
+fn in_memory_backend_shares_data_between_connections() {
+    let db = Db::in_memory();
+    let root = prodash::tree::Root::new();
+    db.open_crate_versions()
+        .unwrap()
+        .insert(&mut root.add_child("test"), "a:1.0.0", &version("a", "1.0.0"))
+        .unwrap();
+    assert_eq!(db.clone().open_crate_versions().unwrap().count(), 1);
+    assert_eq!(Db::in_memory().open_crate_versions().unwrap().count(), 0);
+}
+
+#[test]
+fn undecodable_rows_are_errors_when_read_and_skipped_when_iterated() {
+    let db = Db::in_memory();
+    let root = prodash::tree::Root::new();
+    let mut progress = root.add_child("test");
+    let versions = db.open_crate_versions().unwrap();
+    versions
+        .insert(&mut progress, "a:1.0.0", &version("a", "1.0.0"))
+        .unwrap();
+    db.open_connection()
+        .unwrap()
+        .insert(None, "crate_version", "b:1.0.0", b"\xc1 not msgpack")
+        .unwrap();
+
+    assert!(versions.get("b:1.0.0").is_err());
+    assert!(versions
+        .upsert(&mut progress, "b:1.0.0", &version("b", "1.0.0"))
+        .is_err());
+    assert_eq!(
+        versions
+            .chunk_old_to_new(None, None)
+            .unwrap()
+            .into_iter()
+            .map(|(k, _)| k)
+            .collect::<Vec<_>>(),
+        vec!["a:1.0.0".to_string()]
+    );
+    assert_eq!(versions.chunk_recent_first(0, 10).unwrap().len(), 1);
+}
+
+#[test]
+fn sqlite_backend() {
+    let dir = std::env::temp_dir().join(format!("criner-sqlite-backend-test-{}", std::process::id()));
+    std::fs::remove_dir_all(&dir).ok();
+    assert_table_semantics(Db::open(&dir).unwrap());
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+fn attempt(error: Option<&str>) -> TaskAttempt {
+    TaskAttempt {
+        started_at: SystemTime::UNIX_EPOCH,
+        finished_at: SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1),
+        worker: WorkerKind::IoBound,
+        bytes_transferred: Some(42),
+        error: error.map(|message| AttemptError {
+            kind: "Message".into(),
+            message: message.into(),
+            context: "download".into(),
+        }),
+        retried_timeouts: 0,
+    }
+}
+
+#[test]
+fn task_merge_keeps_all_attempts_for_every_state_combination() {
+    let states = [
+        TaskState::NotStarted,
+        TaskState::AttemptsWithFailure,
+        TaskState::Complete,
+        TaskState::InProgress,
+    ];
+    for existing_state in states.iter() {
+        for new_state in states.iter() {
+            let existing = Task {
+                state: *existing_state,
+                attempts: vec![attempt(Some("first"))],
+                ..Default::default()
+            };
+            let new = Task {
+                state: *new_state,
+                attempts: vec![attempt(None)],
+                ..Default::default()
+            };
+            let merged = existing.merge(&new);
+            assert_eq!(merged.state, *new_state);
+            assert_eq!(merged.attempts, vec![attempt(Some("first")), attempt(None)]);
+        }
+    }
+}
+
+#[test]
+fn task_roundtrips_through_storage_format() {
+    let task = Task {
+        state: TaskState::AttemptsWithFailure,
+        attempts: vec![attempt(Some("failed")), attempt(None)],
+        ..Default::default()
+    };
+    let decoded = Task::try_from(rmp_serde::to_vec(&task).unwrap().as_slice()).unwrap();
+    assert_eq!(decoded.state, task.state);
+    assert_eq!(decoded.attempts, task.attempts);
+}
+
+#[test]
+fn tasks_with_error_strings_are_migrated_on_the_fly() {
+    #[derive(serde_derive::Serialize)]
+    enum LegacyTaskState {
+        AttemptsWithFailure(Vec<String>),
+        Complete,
+        InProgress(Option<Vec<String>>),
+    }
+    #[derive(serde_derive::Serialize)]
+    struct LegacyTask {
+        stored_at: SystemTime,
+        process: String,
+        version: String,
+        state: LegacyTaskState,
+    }
+    let legacy = |state| {
+        Task::try_from(
+            rmp_serde::to_vec(&LegacyTask {
+                stored_at: SystemTime::now(),
+                process: "download".into(),
+                version: "1.0.0".into(),
+                state,
+            })
+            .unwrap()
+            .as_slice(),
+        )
+        .unwrap()
+    };
+
+    let task = legacy(LegacyTaskState::AttemptsWithFailure(vec!["one".into(), "two".into()]));
+    assert_eq!(task.state, TaskState::AttemptsWithFailure);
+    assert_eq!(task.process, "download");
+    assert_eq!(task.failed_attempts(), 2);
+    let errors: Vec<_> = task
+        .attempts
+        .iter()
+        .map(|a| (a.worker, a.error.as_ref().unwrap().message.as_str()))
+        .collect();
+    assert_eq!(errors, vec![(WorkerKind::Unknown, "one"), (WorkerKind::Unknown, "two")]);
+
+    let task = legacy(LegacyTaskState::InProgress(Some(vec!["one".into()])));
+    assert_eq!(task.state, TaskState::InProgress);
+    assert_eq!(task.failed_attempts(), 1);
+
+    let task = legacy(LegacyTaskState::InProgress(None));
+    assert_eq!(task.state, TaskState::InProgress);
+    assert!(task.attempts.is_empty());
+
+    let task = legacy(LegacyTaskState::Complete);
+    assert_eq!(task.state, TaskState::Complete);
+    assert!(task.attempts.is_empty());
+}
+
+#[test]
+fn context_merge_adds_all_metrics() {
+    let sample = |n: u64| Context {
+        counts: Counts {
+            crate_versions: n,
+            crates: n as u32,
+            bytes_downloaded: n * 1000,
+            crates_extracted: n,
+            task_failures: vec![("download".to_string(), n), (format!("process-{}", n), 1)]
+                .into_iter()
+                .collect(),
+        },
+        durations: model::Durations {
+            fetch_crate_versions: Duration::from_secs(n),
+            download: Duration::from_secs(n * 2),
+            extraction: Duration::from_secs(n * 3),
+            report_generation: Duration::from_secs(n * 4),
+            db_dump_ingestion: Duration::from_secs(n * 5),
+        },
+    };
+    let merged = sample(1).merge(&sample(2));
+    assert_eq!(merged.counts.crate_versions, 3);
+    assert_eq!(merged.counts.crates, 3);
+    assert_eq!(merged.counts.bytes_downloaded, 3000);
+    assert_eq!(merged.counts.crates_extracted, 3);
+    assert_eq!(
+        merged.counts.task_failures.into_iter().collect::<Vec<_>>(),
+        vec![
+            ("download".to_string(), 3),
+            ("process-1".to_string(), 1),
+            ("process-2".to_string(), 1)
+        ]
+    );
+    assert_eq!(
+        merged.durations,
+        model::Durations {
+            fetch_crate_versions: Duration::from_secs(3),
+            download: Duration::from_secs(6),
+            extraction: Duration::from_secs(9),
+            report_generation: Duration::from_secs(12),
+            db_dump_ingestion: Duration::from_secs(15),
+        }
+    );
+}
+
+#[test]
+fn contexts_without_new_metrics_are_migrated_on_the_fly() {
+    #[derive(serde_derive::Serialize)]
+    struct LegacyCounts {
+        crate_versions: u64,
+        crates: u32,
+    }
+    #[derive(serde_derive::Serialize)]
+    struct LegacyDurations {
+        fetch_crate_versions: Duration,
+    }
+    #[derive(serde_derive::Serialize)]
+    struct LegacyContext {
+        counts: LegacyCounts,
+        durations: LegacyDurations,
+    }
+    let context = Context::try_from(
+        rmp_serde::to_vec(&LegacyContext {
+            counts: LegacyCounts {
+                crate_versions: 5,
+                crates: 2,
+            },
+            durations: LegacyDurations {
+                fetch_crate_versions: Duration::from_secs(1),
+            },
+        })
+        .unwrap()
+        .as_slice(),
+    )
+    .unwrap();
+    assert_eq!(context.counts.crate_versions, 5);
+    assert_eq!(context.counts.crates, 2);
+    assert_eq!(context.counts.bytes_downloaded, 0);
+    assert!(context.counts.task_failures.is_empty());
+    assert_eq!(context.durations.fetch_crate_versions, Duration::from_secs(1));
+    assert_eq!(context.durations.download, Duration::default());
+}
+
+fn dependency(name: &str, required_version: &str, kind: Option<&str>, package: Option<&str>) -> model::Dependency {
+    model::Dependency {
+        name: name.into(),
+        required_version: required_version.into(),
+        features: Vec::new(),
+        optional: package.is_some(),
+        default_features: true,
+        target: None,
+        kind: kind.map(Into::into),
+        package: package.map(Into::into),
+    }
+}
+
+#[test]
+fn reverse_dependencies_are_merged_and_resolved_against_known_versions() {
+    let db = Db::in_memory();
+    let root = prodash::tree::Root::new();
+    let mut progress = root.add_child("test");
+    let dependent = CrateVersion {
+        dependencies: vec![
+            dependency("dep", "^1.1", None, None),
+            dependency("dep", "=0.9.0", Some("dev"), None),
+            dependency("alias", "2", Some("build"), Some("dep")),
+        ],
+        ..version("a", "1.0.0")
+    };
+    let mut by_key = std::collections::BTreeMap::<String, model::ReverseDependencies>::new();
+    for (name, edge) in dependent.reverse_dependencies() {
+        let mut key = String::new();
+        model::ReverseDependencies::key_from(name, &dependent.name, &dependent.version, &mut key);
+        by_key.entry(key).or_default().dependents.push(edge);
+    }
+    assert_eq!(
+        by_key.keys().map(String::as_str).collect::<Vec<_>>(),
+        vec!["dep:a:1.0.0"],
+        "renames are followed"
+    );
+
+    let table = db.open_reverse_dependencies().unwrap();
+    let edges = &by_key["dep:a:1.0.0"];
+    table.upsert(&mut progress, "dep:a:1.0.0", edges).unwrap();
+    let stored = table.upsert(&mut progress, "dep:a:1.0.0", edges).unwrap();
+    assert_eq!(stored.dependents.len(), 3, "duplicates are removed on merge");
+    table.upsert(&mut progress, "dep-other:a:1.0.0", edges).unwrap();
+
+    let crates = db.open_crates().unwrap();
+    for v in ["0.9.0", "1.0.0", "1.2.0", "1.3.0-beta"] {
+        crates.upsert(&mut progress, "dep", &version("dep", v)).unwrap();
+    }
+    let resolved: Vec<_> = table
+        .dependents_of("dep", &crates)
+        .unwrap()
+        .into_iter()
+        .map(|(edge, resolved)| (edge.kind, edge.required_version, edge.optional, resolved))
+        .collect();
+    assert_eq!(
+        resolved,
+        vec![
+            ("build".into(), "2".into(), true, None),
+            ("dev".into(), "=0.9.0".into(), false, Some("0.9.0".into())),
+            ("normal".into(), "^1.1".into(), false, Some("1.2.0".into())),
+        ],
+        "pre-releases don't match unless asked for, and unknown kinds are normal dependencies"
+    );
+    assert!(table.dependents_of("unknown", &crates).unwrap().is_empty());
+}
+
+#[test]
+fn reverse_dependencies_are_queryable_through_the_library() {
+    let dir = std::env::temp_dir().join(format!("criner-reverse-dependencies-test-{}", std::process::id()));
+    std::fs::remove_dir_all(&dir).ok();
+    assert!(
+        crate::reverse_dependencies(&dir, "dep").is_err(),
+        "there is no database"
+    );
+    {
+        let db = Db::open(&dir).unwrap();
+        let root = prodash::tree::Root::new();
+        let mut progress = root.add_child("test");
+        let dependent = CrateVersion {
+            dependencies: vec![dependency("dep", "^1", None, None)],
+            ..version("a", "1.0.0")
+        };
+        for (name, edge) in dependent.reverse_dependencies() {
+            let mut key = String::new();
+            model::ReverseDependencies::key_from(name, &dependent.name, &dependent.version, &mut key);
+            db.open_reverse_dependencies()
+                .unwrap()
+                .upsert(
+                    &mut progress,
+                    key,
+                    &model::ReverseDependencies { dependents: vec![edge] },
+                )
+                .unwrap();
+        }
+        db.open_crates()
+            .unwrap()
+            .upsert(&mut progress, "dep", &version("dep", "1.1.0"))
+            .unwrap();
+    }
+
+    let dependents = crate::reverse_dependencies(&dir, "dep").unwrap();
+    assert_eq!(
+        dependents,
+        vec![(
+            crate::ReverseDependency {
+                crate_name: "a".into(),
+                crate_version: "1.0.0".into(),
+                required_version: "^1".into(),
+                kind: "normal".into(),
+                optional: false,
+            },
+            Some("1.1.0".into())
+        )]
+    );
+    assert!(crate::reverse_dependencies(&dir, "a").unwrap().is_empty());
+    std::fs::remove_dir_all(&dir).ok();
+}
@@ -1,22 +1,20 @@
-use crate::model::{db_dump, Context, Crate, CrateVersion, ReportResult, Task, TaskResult};
-
-fn expect<T, E: std::fmt::Display>(r: std::result::Result<T, E>, panic_message: impl FnOnce(E) -> String) -> T {
-    match r {
-        Ok(v) => v,
-        Err(e) => std::panic::panic_any(panic_message(e)),
-    }
-}
+use crate::model::{db_dump, Context, Crate, CrateVersion, ReportResult, ReverseDependencies, Task, TaskResult};
 
 macro_rules! impl_deserialize {
     ($ty:ty) => {
-        impl From<&[u8]> for $ty {
-            fn from(b: &[u8]) -> Self {
-                expect(rmp_serde::from_slice(b), |e| {
-                    format!(
-                        concat!("&[u8]: migration should succeed: ", stringify!($ty), "{:#?}: {}"),
-                        rmpv::decode::value::read_value(&mut std::io::Cursor::new(b)).unwrap(),
+        impl std::convert::TryFrom<&[u8]> for $ty {
+            type Error = crate::Error;
+
+            fn try_from(b: &[u8]) -> crate::Result<Self> {
+                rmp_serde::from_slice(b).map_err(|e| {
+                    crate::Error::Message(format!(
+                        concat!(
+                            "Could not decode ",
+                            stringify!($ty),
+                            ": {}\nRun 'criner fsck --quarantine' to move undecodable rows aside."
+                        ),
                         e
-                    )
+                    ))
                 })
             }
         }
@@ -27,6 +25,7 @@ impl_deserialize!(Crate);
 impl_deserialize!(Task);
 impl_deserialize!(TaskResult);
 impl_deserialize!(CrateVersion);
+impl_deserialize!(ReverseDependencies);
 impl_deserialize!(Context);
 impl_deserialize!(ReportResult);
 impl_deserialize!(db_dump::Crate);
@@ -1,99 +1,17 @@
 use crate::persistence::KEY_SEP_CHAR;
 use crate::{
     model::{Context, Crate, TaskResult},
-    model::{CrateVersion, Task},
-    persistence::{merge::Merge, Keyed},
+    model::{CrateVersion, ReverseDependencies, ReverseDependency, Task},
+    persistence::{merge::Merge, Keyed, ThreadSafeConnection},
     Result,
 };
-use rusqlite::{params, OptionalExtension};
-use std::time::{Duration, SystemTime};
-
-/// Required as we send futures to threads. The type system can't statically prove that in fact
-/// these connections will only ever be created while already in the thread they should execute on.
-/// Also no one can prevent futures from being resumed in after having been send to a different thread.
-pub type ThreadSafeConnection = std::sync::Arc<parking_lot::Mutex<rusqlite::Connection>>;
-
-pub fn new_value_query_recent_first<'conn>(
-    table_name: &str,
-    connection: &'conn rusqlite::Connection,
-    offset: usize,
-    limit: usize,
-) -> Result<rusqlite::Statement<'conn>> {
-    Ok(connection.prepare(&format!(
-        "SELECT data FROM {} ORDER BY _rowid_ DESC LIMIT {}, {}",
-        table_name, offset, limit
-    ))?)
-}
-
-pub fn new_key_value_query_old_to_new_filtered<'conn>(
-    table_name: &str,
-    glob: Option<&str>,
-    connection: &'conn rusqlite::Connection,
-    chunk: Option<(usize, usize)>,
-) -> Result<rusqlite::Statement<'conn>> {
-    Ok(connection.prepare(&format!(
-        "SELECT key,data FROM {} {} ORDER BY _rowid_ ASC {}",
-        table_name,
-        match glob {
-            Some(glob) => format!("where key glob \"{}\"", glob),
-            None => "".into(),
-        },
-        match chunk {
-            Some((offset, limit)) => format!("LIMIT {}, {}", offset, limit),
-            None => "".into(),
-        }
-    ))?)
-}
-
-pub fn new_key_value_query_old_to_new<'conn>(
-    table_name: &str,
-    connection: &'conn rusqlite::Connection,
-) -> Result<rusqlite::Statement<'conn>> {
-    new_key_value_query_old_to_new_filtered(table_name, None, connection, None)
-}
-
-pub fn new_key_value_insertion<'conn>(
-    table_name: &str,
-    connection: &'conn rusqlite::Connection,
-) -> Result<rusqlite::Statement<'conn>> {
-    Ok(connection.prepare(&format!("REPLACE INTO '{}' (key, data) VALUES (?1, ?2)", table_name))?)
-}
-
-pub fn new_key_insertion<'conn>(
-    table_name: &str,
-    connection: &'conn rusqlite::Connection,
-) -> Result<rusqlite::Statement<'conn>> {
-    Ok(connection.prepare(&format!("REPLACE INTO {} (key) VALUES (?1)", table_name))?)
-}
-
-pub fn value_iter<'stm, 'conn, StorageItem>(
-    statement: &'stm mut rusqlite::Statement<'conn>,
-) -> Result<impl Iterator<Item = Result<StorageItem>> + 'stm>
-where
-    StorageItem: for<'a> From<&'a [u8]>,
-{
-    Ok(statement
-        .query_map([], |r| r.get::<_, Vec<u8>>(0).map(|v| StorageItem::from(v.as_slice())))?
-        .map(|r| r.map_err(Into::into)))
-}
-
-pub fn key_value_iter<'stm, 'conn, StorageItem>(
-    statement: &'stm mut rusqlite::Statement<'conn>,
-) -> Result<impl Iterator<Item = Result<(String, StorageItem)>> + 'stm>
-where
-    StorageItem: for<'a> From<&'a [u8]>,
-{
-    Ok(statement
-        .query_map([], |r| {
-            let key = r.get::<_, String>(0)?;
-            let data = r.get::<_, Vec<u8>>(1)?;
-            Ok((key, StorageItem::from(data.as_slice())))
-        })?
-        .map(|r| r.map_err(Into::into)))
-}
+use std::{convert::TryFrom, time::SystemTime};
 
 pub trait TableAccess {
-    type StorageItem: serde::Serialize + for<'a> From<&'a [u8]> + Default + From<Self::InsertItem>;
+    type StorageItem: serde::Serialize
+        + for<'a> TryFrom<&'a [u8], Error = crate::Error>
+        + Default
+        + From<Self::InsertItem>;
     type InsertItem: Clone;
 
     fn connection(&self) -> &ThreadSafeConnection;
@@ -103,41 +21,19 @@ pub trait TableAccess {
         Self::StorageItem::from(new_item.clone())
     }
 
-    fn into_connection(self) -> ThreadSafeConnection;
-
     fn count(&self) -> u64 {
         self.count_filtered(None)
     }
 
     fn count_filtered(&self, glob: Option<&str>) -> u64 {
-        self.connection()
-            .lock()
-            .query_row(
-                &format!(
-                    "SELECT COUNT(*) FROM {} {}",
-                    Self::table_name(),
-                    match glob {
-                        Some(glob) => format!("where key glob \"{}\"", glob),
-                        None => "".into(),
-                    }
-                ),
-                [],
-                |r| r.get::<_, i64>(0),
-            )
-            .unwrap_or(0) as u64
+        self.connection().count(Self::table_name(), glob).unwrap_or(0)
     }
 
     fn get(&self, key: impl AsRef<str>) -> Result<Option<Self::StorageItem>> {
-        Ok(self
-            .connection()
-            .lock()
-            .query_row(
-                &format!("SELECT data FROM {} WHERE key = '{}'", Self::table_name(), key.as_ref()),
-                [],
-                |r| r.get::<_, Vec<u8>>(0),
-            )
-            .optional()?
-            .map(|d| Self::StorageItem::from(d.as_slice())))
+        self.connection()
+            .get(Self::table_name(), key.as_ref())?
+            .map(|d| Self::StorageItem::try_from(d.as_slice()))
+            .transpose()
     }
 
     /// Update an existing item, or create it as default, returning the stored item
@@ -148,25 +44,18 @@ pub trait TableAccess {
         key: impl AsRef<str>,
         f: impl Fn(Self::StorageItem) -> Self::StorageItem,
     ) -> Result<Self::StorageItem> {
-        retry_on_db_busy(progress, || {
-            let mut guard = self.connection().lock();
-            let transaction = guard.transaction_with_behavior(rusqlite::TransactionBehavior::Immediate)?;
-            let new_value = transaction
-                .query_row(
-                    &format!("SELECT data FROM {} WHERE key = '{}'", Self::table_name(), key.as_ref()),
-                    [],
-                    |r| r.get::<_, Vec<u8>>(0),
-                )
-                .optional()?
-                .map_or_else(|| f(Self::StorageItem::default()), |d| f(d.as_slice().into()));
-            transaction.execute(
-                &format!("REPLACE INTO {} (key, data) VALUES (?1, ?2)", Self::table_name()),
-                params![key.as_ref(), rmp_serde::to_vec(&new_value)?],
-            )?;
-            transaction.commit()?;
-
-            Ok(new_value)
-        })
+        let mut new_value = None;
+        self.connection()
+            .update(progress, Self::table_name(), key.as_ref(), &mut |existing| {
+                let value = match existing {
+                    Some(d) => f(Self::StorageItem::try_from(d)?),
+                    None => f(Self::StorageItem::default()),
+                };
+                let data = rmp_serde::to_vec(&value)?;
+                new_value = Some(value);
+                Ok(data)
+            })?;
+        Ok(new_value.expect("update function to have been called"))
     }
 
     /// Similar to 'update', but provides full control over the default and allows deletion
@@ -176,84 +65,71 @@ pub trait TableAccess {
         key: impl AsRef<str>,
         item: &Self::InsertItem,
     ) -> Result<Self::StorageItem> {
-        retry_on_db_busy(Some(progress), || {
-            let mut guard = self.connection().lock();
-            let transaction = guard.transaction_with_behavior(rusqlite::TransactionBehavior::Immediate)?;
-
-            let new_value = {
-                let maybe_vec = transaction
-                    .query_row(
-                        &format!("SELECT data FROM {} WHERE key = '{}'", Self::table_name(), key.as_ref()),
-                        [],
-                        |r| r.get::<_, Vec<u8>>(0),
-                    )
-                    .optional()?;
-                Self::merge(item, maybe_vec.map(|v| v.as_slice().into()))
-            };
-            transaction.execute(
-                &format!("REPLACE INTO {} (key, data) VALUES (?1, ?2)", Self::table_name()),
-                params![key.as_ref(), rmp_serde::to_vec(&new_value)?],
-            )?;
-            transaction.commit()?;
-            Ok(new_value)
-        })
+        let mut new_value = None;
+        self.connection()
+            .update(Some(progress), Self::table_name(), key.as_ref(), &mut |existing| {
+                let value = Self::merge(item, existing.map(Self::StorageItem::try_from).transpose()?);
+                let data = rmp_serde::to_vec(&value)?;
+                new_value = Some(value);
+                Ok(data)
+            })?;
+        Ok(new_value.expect("update function to have been called"))
     }
 
     fn insert(&self, progress: &mut prodash::tree::Item, key: impl AsRef<str>, v: &Self::InsertItem) -> Result<()> {
-        retry_on_db_busy(Some(progress), || {
-            self.connection().lock().execute(
-                &format!("REPLACE INTO {} (key, data) VALUES (?1, ?2)", Self::table_name()),
-                params![key.as_ref(), rmp_serde::to_vec(&Self::merge(v, None))?],
-            )?;
-            Ok(())
-        })
+        self.connection().insert(
+            Some(progress),
+            Self::table_name(),
+            key.as_ref(),
+            &rmp_serde::to_vec(&Self::merge(v, None))?,
+        )
     }
-}
 
-fn retry_on_db_busy<T>(mut progress: Option<&mut prodash::tree::Item>, mut f: impl FnMut() -> Result<T>) -> Result<T> {
-    use crate::Error;
-    use rusqlite::ffi::Error as SqliteFFIError;
-    use rusqlite::ffi::ErrorCode as SqliteFFIErrorCode;
-    use rusqlite::Error as SqliteError;
-    use std::ops::Add;
-
-    let max_wait_ms = Duration::from_secs(100);
-    let mut total_wait_time = Duration::default();
-    let mut wait_for = Duration::from_millis(1);
-    loop {
-        match f() {
-            Ok(v) => return Ok(v),
-            Err(
-                err @ Error::Rusqlite(SqliteError::SqliteFailure(
-                    SqliteFFIError {
-                        code: SqliteFFIErrorCode::DatabaseBusy,
-                        ..
-                    },
-                    _,
-                )),
-            ) => {
-                if total_wait_time >= max_wait_ms {
-                    log::warn!("Giving up to wait for {:?} after {:?})", err, total_wait_time);
-                    return Err(err);
-                }
-                log::warn!(
-                    "Waiting {:?} for {:?} (total wait time {:?})",
-                    wait_for,
-                    err,
-                    total_wait_time
-                );
-                if let Some(p) = progress.as_mut() {
-                    p.blocked("wait for write lock", Some(SystemTime::now().add(wait_for)));
-                };
-                std::thread::sleep(wait_for);
-                total_wait_time += wait_for;
-                wait_for *= 2;
-            }
-            Err(err) => return Err(err),
-        }
+    /// Return up to `limit` items in the order they were inserted, after skipping `offset` items, optionally
+    /// filtering them by a unix glob on their key. Items which can't be decoded are logged and skipped.
+    fn chunk_old_to_new(
+        &self,
+        glob: Option<&str>,
+        chunk: Option<(usize, usize)>,
+    ) -> Result<Vec<(String, Self::StorageItem)>> {
+        Ok(self
+            .connection()
+            .key_values_old_to_new(Self::table_name(), glob, chunk)?
+            .into_iter()
+            .filter_map(|(k, v)| decode_or_log(Self::table_name(), Some(&k), &v).map(|v| (k, v)))
+            .collect())
+    }
+
+    /// Return up to `limit` items with the most recently inserted one first, after skipping `offset` items.
+    /// Items which can't be decoded are logged and skipped.
+    fn chunk_recent_first(&self, offset: usize, limit: usize) -> Result<Vec<Self::StorageItem>> {
+        Ok(self
+            .connection()
+            .values_recent_first(Self::table_name(), offset, limit)?
+            .into_iter()
+            .filter_map(|v| decode_or_log(Self::table_name(), None, &v))
+            .collect())
     }
 }
 
+/// Decode `data` stored at `key` in `table`, or log why it can't be decoded so that iterating tables survives corrupt rows.
+pub(crate) fn decode_or_log<T: for<'a> TryFrom<&'a [u8], Error = crate::Error>>(
+    table: &str,
+    key: Option<&str>,
+    data: &[u8],
+) -> Option<T> {
+    T::try_from(data)
+        .map_err(|err| {
+            log::warn!(
+                "Skipping item{} in table '{}': {}",
+                key.map(|k| format!(" '{}'", k)).unwrap_or_default(),
+                table,
+                err
+            )
+        })
+        .ok()
+}
+
 pub struct TaskTable {
     pub(crate) inner: ThreadSafeConnection,
 }
@@ -274,10 +150,6 @@ impl TableAccess for TaskTable {
             ..existing_task.map_or_else(|| new_task.clone(), |existing_task| existing_task.merge(new_task))
         }
     }
-
-    fn into_connection(self) -> ThreadSafeConnection {
-        self.inner
-    }
 }
 
 pub struct ReportsTree {
@@ -302,16 +174,12 @@ impl ReportsTree {
 
     pub fn is_done(&self, key: impl AsRef<str>) -> bool {
         self.inner
-            .lock()
-            .query_row(
-                &format!("SELECT key FROM {} where key = '{}'", Self::table_name(), key.as_ref()),
-                [],
-                |_r| Ok(()),
-            )
-            .optional()
-            .ok()
+            .contains_key(Self::table_name(), key.as_ref())
             .unwrap_or_default()
-            .map_or(false, |_: ()| true)
+    }
+
+    pub fn mark_done<'a>(&self, keys: impl IntoIterator<Item = &'a str>) -> Result<usize> {
+        self.inner.insert_keys(Self::table_name(), &mut keys.into_iter())
     }
 }
 
@@ -329,9 +197,6 @@ impl TableAccess for TaskResultTable {
     fn table_name() -> &'static str {
         "result"
     }
-    fn into_connection(self) -> ThreadSafeConnection {
-        self.inner
-    }
 }
 
 pub struct MetaTable {
@@ -352,12 +217,15 @@ impl TableAccess for MetaTable {
     fn merge(new: &Context, existing_item: Option<Context>) -> Self::StorageItem {
         existing_item.map_or_else(|| new.to_owned(), |existing| existing.merge(new))
     }
-    fn into_connection(self) -> ThreadSafeConnection {
-        self.inner
-    }
 }
 
+/// The prefix of keys marking one-time operations as complete, which sorts before the keys of contexts.
+const COMPLETION_MARKER_PREFIX: &str = "completed/";
+
 impl MetaTable {
+    /// Matches the keys of all contexts, leaving out completion markers which have no data.
+    pub const CONTEXT_GLOB: &'static str = "context/*";
+
     pub fn update_today(&self, f: impl Fn(&mut Context)) -> Result<Context> {
         self.update(None, Context::default().key(), |mut c| {
             f(&mut c);
@@ -367,14 +235,25 @@ impl MetaTable {
 
     // NOTE: impl iterator is not allowed in traits unfortunately, but one could implement one manually
     pub fn most_recent(&self) -> Result<Option<(String, Context)>> {
-        Ok(self
-            .connection()
-            .lock()
-            .query_row("SELECT key, data FROM meta ORDER BY key DESC limit 1", [], |r| {
-                Ok((r.get::<_, String>(0)?, r.get::<_, Vec<u8>>(1)?))
-            })
-            .optional()?
-            .map(|(k, v)| (k, Context::from(v.as_slice()))))
+        self.connection()
+            .last_by_key(Self::table_name())?
+            .map(|(k, v)| Context::try_from(v.as_slice()).map(|c| (k, c)))
+            .transpose()
+    }
+
+    /// Return true if the one-time operation named `marker` was marked complete with `mark_complete()`.
+    pub fn is_complete(&self, marker: &str) -> Result<bool> {
+        self.connection()
+            .contains_key(Self::table_name(), &format!("{}{}", COMPLETION_MARKER_PREFIX, marker))
+    }
+
+    pub fn mark_complete(&self, marker: &str) -> Result<()> {
+        self.connection().insert(
+            None,
+            Self::table_name(),
+            &format!("{}{}", COMPLETION_MARKER_PREFIX, marker),
+            &[],
+        )
     }
 }
 
@@ -397,9 +276,6 @@ impl TableAccess for CrateTable {
     fn merge(new_item: &CrateVersion, existing_item: Option<Crate>) -> Crate {
         existing_item.map_or_else(|| Crate::from(new_item.to_owned()), |c| c.merge(new_item))
     }
-    fn into_connection(self) -> ThreadSafeConnection {
-        self.inner
-    }
 }
 
 #[derive(Clone)]
@@ -417,7 +293,54 @@ impl TableAccess for CrateVersionTable {
     fn table_name() -> &'static str {
         "crate_version"
     }
-    fn into_connection(self) -> ThreadSafeConnection {
-        self.inner
+}
+
+/// The reverse dependency graph, with the edges of each dependent crate version keyed by
+/// `<depended-on crate>:<dependent crate>:<dependent version>`
+#[derive(Clone)]
+pub struct ReverseDependencyTable {
+    pub(crate) inner: ThreadSafeConnection,
+}
+
+impl TableAccess for ReverseDependencyTable {
+    type StorageItem = ReverseDependencies;
+    type InsertItem = ReverseDependencies;
+
+    fn connection(&self) -> &ThreadSafeConnection {
+        &self.inner
+    }
+    fn table_name() -> &'static str {
+        "reverse_dependency"
+    }
+
+    fn merge(new_item: &ReverseDependencies, existing_item: Option<ReverseDependencies>) -> ReverseDependencies {
+        let mut item = existing_item.unwrap_or_default();
+        item.merge_mut(new_item);
+        item
+    }
+}
+
+impl ReverseDependencyTable {
+    /// All crate versions depending on `crate_name`, along with the most recent of its versions in `crates`
+    /// matching their requirement.
+    pub fn dependents_of(
+        &self,
+        crate_name: &str,
+        crates: &CrateTable,
+    ) -> Result<Vec<(ReverseDependency, Option<String>)>> {
+        let known_versions = crates.get(crate_name)?.unwrap_or_default().versions;
+        let mut dependents: Vec<_> = self
+            .chunk_old_to_new(Some(&format!("{}{}*", crate_name, KEY_SEP_CHAR)), None)?
+            .into_iter()
+            .flat_map(|(_, edges)| edges.dependents)
+            .collect();
+        dependents.sort();
+        Ok(dependents
+            .into_iter()
+            .map(|dependent| {
+                let resolved = dependent.resolve(&known_versions).cloned();
+                (dependent, resolved)
+            })
+            .collect())
     }
 }
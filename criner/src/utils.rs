@@ -118,7 +118,7 @@ where
     MakeFut: FnMut() -> Fut,
     MakeProgress: FnMut() -> prodash::tree::Item,
 {
-    let max_iterations = at_most.unwrap_or(std::usize::MAX);
+    let max_iterations = at_most.unwrap_or(usize::MAX);
     let mut iteration = 0;
     loop {
         if iteration == max_iterations {
@@ -15,6 +15,7 @@ pub struct Args {
 }
 
 #[derive(Debug, clap::Parser)]
+#[allow(clippy::large_enum_variant)] // parsed once, size does not matter
 pub enum SubCommands {
     /// Mine crates.io in an incorruptible and resumable fashion
     #[clap(display_order = 0)]
@@ -59,10 +60,12 @@ pub enum SubCommands {
         #[clap(long, alias = "cpu-o", value_name = "cpu-o", default_value = "20")]
         cpu_o_bound_processors: u32,
 
-        /// The amount of CPU-bound processors to run concurrently.
+        /// The amount of CPU-bound processors to run concurrently for each kind of CPU-bound task.
         ///
         /// One can assume that one of these can occupy one core of a CPU.
         /// However, they will not use a lot of IO, nor will they use much memory.
+        /// As each kind of task, like extracting crates or parsing manifests and lockfiles, gets its own processors,
+        /// the total amount of them is this number times the amount of CPU-bound task kinds.
         #[clap(long, alias = "cpu", value_name = "cpu", default_value = "4")]
         cpu_bound_processors: u32,
 
@@ -112,6 +115,31 @@ pub enum SubCommands {
         #[clap(long, short = 'g')]
         glob: Option<String>,
 
+        /// If set, the path to a TOML file with programs to run on every downloaded crate.
+        ///
+        /// Each program is declared as `[[command]]` table with a unique 'name' and a 'version', the 'program' and
+        /// its 'args', the 'input' to pass as last argument, either 'crate-file' or 'crate-directory', and
+        /// an optional 'timeout' like '5min'. Whatever the program writes to stdout must be JSON, and is stored
+        /// as result of the task.
+        #[clap(long, short = 'x')]
+        external_commands: Option<PathBuf>,
+
+        /// If set, the path to a TOML file declaring which files to store when extracting crates.
+        ///
+        /// Use 'targets_max_size' to limit the size of the build script, library and binaries, and a `[[file]]` table
+        /// with a 'glob' and optional 'max_size' in bytes for any other file to store. 'Cargo.toml' is always
+        /// stored in full. Changing the configuration extracts all crates again.
+        #[clap(long)]
+        extraction_config: Option<PathBuf>,
+
+        /// If set, the path to a TOML file with the patterns deciding which files of a crate are waste.
+        ///
+        /// Use 'include' and 'exclude' to replace the built-in patterns of files needed to build a crate and of files
+        /// which are not, 'allow' for files which are never waste, and a '[crate.<name>]' table to add patterns
+        /// for a single crate. Changing the policy generates all waste reports again.
+        #[clap(long)]
+        waste_policy: Option<PathBuf>,
+
         /// Path to the possibly existing database. It's used to persist all mining results.
         #[clap(default_value = "criner.db")]
         db_path: PathBuf,
@@ -132,6 +160,47 @@ pub enum SubCommands {
         /// Path to which to write the exported data. If it exists the operation will fail.
         export_db_path: PathBuf,
     },
+    /// Write a consistent snapshot of the database, which is safe to do while mining.
+    ///
+    /// SQLite's online backup API is used to copy the database, which may be in use by a running 'mine' command.
+    #[clap(display_order = 2)]
+    #[clap(disable_version_flag(true))]
+    Backup {
+        /// If set, the 'assets' directory will be recreated in the snapshot with all files hard-linked.
+        ///
+        /// This is fast and doesn't take additional space, but requires the snapshot to be on the same file system.
+        #[clap(long, short = 'a')]
+        hardlink_assets: bool,
+
+        /// If set, the snapshot will be opened and a sample of every table will be deserialized.
+        #[clap(long)]
+        verify: bool,
+
+        /// Path to the database to back up.
+        db_path: PathBuf,
+
+        /// Path to the directory to write the snapshot to. If it exists the operation will fail.
+        snapshot_db_path: PathBuf,
+    },
+    /// Check the integrity of the database and optionally quarantine rows with problems.
+    ///
+    /// All rows of all tables are deserialized, crate versions listed by crates must exist, completed downloads
+    /// must have their file and result, and reports marked as done must have the result they were computed from.
+    /// Exits with an error if problems remain.
+    #[clap(display_order = 3)]
+    #[clap(disable_version_flag(true))]
+    Fsck {
+        /// If set, rows with problems will be moved into the 'quarantine' table.
+        ///
+        /// Tasks and reports moved aside that way will be redone by the next 'mine' run.
+        /// Crates referring to missing versions are only reported.
+        #[clap(long, short = 'q')]
+        quarantine: bool,
+
+        /// Path to the database to check.
+        #[clap(default_value = "criner.db")]
+        db_path: PathBuf,
+    },
     #[cfg(feature = "migration")]
     /// A special purpose command only to be executed in special circumstances
     #[clap(display_order = 9)]
@@ -161,6 +230,9 @@ impl Default for SubCommands {
             report_at_most: None,
             db_path: PathBuf::from("criner.db"),
             glob: None,
+            external_commands: None,
+            extraction_config: None,
+            waste_policy: None,
         }
     }
 }
@@ -1,3 +1,5 @@
+#![allow(clippy::result_large_err)]
+
 use std::ops::Add;
 
 mod args;
@@ -14,6 +16,13 @@ pub fn run_blocking(args: Args) -> criner::error::Result<()> {
             input_db_path,
             export_db_path,
         } => criner::export::run_blocking(input_db_path, export_db_path),
+        Backup {
+            hardlink_assets,
+            verify,
+            db_path,
+            snapshot_db_path,
+        } => criner::backup::run_blocking(db_path, snapshot_db_path, hardlink_assets, verify),
+        Fsck { quarantine, db_path } => criner::fsck::run_blocking(db_path, quarantine),
         Mine {
             repository,
             db_path,
@@ -33,6 +42,9 @@ pub fn run_blocking(args: Args) -> criner::error::Result<()> {
             report_every,
             report_at_most,
             glob,
+            external_commands,
+            extraction_config,
+            waste_policy,
         } => criner::run::blocking(
             db_path,
             repository.unwrap_or_else(|| std::env::temp_dir().join("criner-crates-io-bare-index.git")),
@@ -57,11 +69,15 @@ pub fn run_blocking(args: Args) -> criner::error::Result<()> {
                 glob,
             },
             download_crates_io_database_every_24_hours_starting_at,
+            external_commands,
+            extraction_config,
+            waste_policy,
             criner::prodash::tree::root::Options {
                 message_buffer_capacity: progress_message_scrollback_buffer_size,
                 ..criner::prodash::tree::root::Options::default()
             }
-            .create().into(),
+            .create()
+            .into(),
             if no_gui {
                 None
             } else {
@@ -1,3 +1,5 @@
+#![allow(clippy::result_large_err)]
+
 use clap::Parser;
 
 fn main() -> criner::error::Result<()> {